@@ -0,0 +1,281 @@
+/*!
+This crate provides conversion routines between [`jiff`] and the temporal
+logical types of [Apache Arrow](https://arrow.apache.org), as implemented by
+[`arrow-rs`](https://docs.rs/arrow).
+
+Arrow represents most temporal values as plain integers whose meaning is
+given by the logical type of the array they're in. Since the same `i64` can
+be a `Date64`, a `Time64` or a `Timestamp`, this crate provides small value
+types for each logical type. These value types wrap the raw Arrow value
+(along with its unit and time zone, where applicable) and provide
+conversions to and from the corresponding Jiff types.
+
+Conversions without any extra parameters are implemented via conversion
+traits defined in this crate. The traits mirror the [`From`], [`Into`],
+[`TryFrom`] and [`TryInto`] traits from the standard library, just like in
+the `jiff-icu` crate.
+
+# Available conversions
+
+* [`jiff::civil::Date`] infallibly converts to [`Date32`] and [`Date64`].
+  The reverse is fallible.
+* [`jiff::civil::Time`] converts to [`Time64`] via [`Time64::from_jiff`],
+  which is fallible since it requires a unit of microseconds or nanoseconds.
+  The reverse is infallible via [`Time64::to_jiff`].
+* [`jiff::Timestamp`], [`jiff::Zoned`] and [`jiff::civil::DateTime`] convert
+  to [`Timestamp`] via [`Timestamp::from_timestamp`], [`Timestamp::from_zoned`]
+  and [`Timestamp::from_datetime`]. The reverse conversions are
+  [`Timestamp::to_timestamp`], [`Timestamp::to_zoned`] and
+  [`Timestamp::to_datetime`]. All of these are fallible.
+* [`jiff::Span`] fallibly converts to
+  [`IntervalMonthDayNano`](arrow_buffer::IntervalMonthDayNano). The reverse is
+  also fallible.
+
+# Example
+
+This shows how to build Arrow date values from Jiff dates and back:
+
+```
+use jiff::civil::date;
+use jiff_arrow::{ConvertFrom as _, ConvertTryFrom as _, Date32};
+
+let d = date(2025, 2, 20);
+let value = Date32::convert_from(d);
+assert_eq!(value.0, 20_139);
+assert_eq!(jiff::civil::Date::convert_try_from(value)?, d);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+#![deny(missing_docs)]
+
+use arrow_buffer::IntervalMonthDayNano;
+use jiff::{civil::Date as JiffDate, Span as JiffSpan};
+
+use self::error::err;
+pub use self::{
+    error::Error,
+    time::Time64,
+    timestamp::Timestamp,
+    traits::{ConvertFrom, ConvertInto, ConvertTryFrom, ConvertTryInto},
+};
+
+mod error;
+mod time;
+mod timestamp;
+mod traits;
+mod unit;
+
+/// The Unix epoch as a civil date.
+static UNIX_EPOCH_DATE: JiffDate = jiff::civil::date(1970, 1, 1);
+
+/// The number of milliseconds in a single day, which Arrow assumes is always
+/// 24 hours.
+static MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// A value of Arrow's `Date32` logical type.
+///
+/// This is the number of days since the Unix epoch.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Date32(pub i32);
+
+/// A value of Arrow's `Date64` logical type.
+///
+/// This is the number of milliseconds since the Unix epoch. Arrow requires
+/// that this is evenly divisible by `86,400,000`. That is, it must always
+/// refer to midnight.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Date64(pub i64);
+
+/// Converts from a [`jiff::civil::Date`] to a [`Date32`].
+///
+/// # Examples
+///
+/// ```
+/// use jiff_arrow::{ConvertFrom as _, Date32};
+///
+/// let value = Date32::convert_from(jiff::civil::date(1970, 1, 1));
+/// assert_eq!(value, Date32(0));
+///
+/// let value = Date32::convert_from(jiff::civil::date(1969, 12, 31));
+/// assert_eq!(value, Date32(-1));
+///
+/// let value = Date32::convert_from(jiff::civil::Date::MIN);
+/// assert_eq!(value, Date32(-4_371_587));
+/// ```
+impl ConvertFrom<JiffDate> for Date32 {
+    fn convert_from(v: JiffDate) -> Date32 {
+        Date32((v - UNIX_EPOCH_DATE).get_days())
+    }
+}
+
+/// Converts from a [`Date32`] to a [`jiff::civil::Date`].
+///
+/// # Examples
+///
+/// ```
+/// use jiff_arrow::{ConvertTryFrom as _, Date32};
+///
+/// let date = jiff::civil::Date::convert_try_from(Date32(20_139))?;
+/// assert_eq!(date.to_string(), "2025-02-20");
+///
+/// // Values outside Jiff's supported range return an error.
+/// assert!(jiff::civil::Date::convert_try_from(Date32(i32::MAX)).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<Date32> for JiffDate {
+    type Error = Error;
+
+    fn convert_try_from(v: Date32) -> Result<JiffDate, Error> {
+        let span = JiffSpan::new().try_days(v.0)?;
+        Ok(UNIX_EPOCH_DATE.checked_add(span)?)
+    }
+}
+
+/// Converts from a [`jiff::civil::Date`] to a [`Date64`].
+///
+/// # Examples
+///
+/// ```
+/// use jiff_arrow::{ConvertFrom as _, Date64};
+///
+/// let value = Date64::convert_from(jiff::civil::date(2025, 2, 20));
+/// assert_eq!(value, Date64(1_740_009_600_000));
+/// ```
+impl ConvertFrom<JiffDate> for Date64 {
+    fn convert_from(v: JiffDate) -> Date64 {
+        let Date32(days) = v.convert_into();
+        Date64(i64::from(days) * MILLIS_PER_DAY)
+    }
+}
+
+/// Converts from a [`Date64`] to a [`jiff::civil::Date`].
+///
+/// # Examples
+///
+/// ```
+/// use jiff_arrow::{ConvertTryFrom as _, Date64};
+///
+/// let date = jiff::civil::Date::convert_try_from(Date64(1_740_009_600_000))?;
+/// assert_eq!(date.to_string(), "2025-02-20");
+///
+/// // Values that don't refer to midnight are invalid.
+/// assert!(jiff::civil::Date::convert_try_from(Date64(1)).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<Date64> for JiffDate {
+    type Error = Error;
+
+    fn convert_try_from(v: Date64) -> Result<JiffDate, Error> {
+        if v.0 % MILLIS_PER_DAY != 0 {
+            return Err(err!(
+                "Date64 value {value} is not evenly divisible by \
+                 the number of milliseconds in a day",
+                value = v.0,
+            ));
+        }
+        let days = i32::try_from(v.0 / MILLIS_PER_DAY).map_err(|_| {
+            err!("Date64 value {value} is out of range", value = v.0)
+        })?;
+        JiffDate::convert_try_from(Date32(days))
+    }
+}
+
+/// Converts from a [`jiff::Span`] to an Arrow
+/// [`IntervalMonthDayNano`](arrow_buffer::IntervalMonthDayNano).
+///
+/// Years and months are folded into the months component, weeks and days
+/// are folded into the days component and all time units are folded into the
+/// nanoseconds component. Nothing is balanced between these three components,
+/// since doing so would require a relative datetime.
+///
+/// # Errors
+///
+/// This returns an error when the time units of the span, in total, don't
+/// fit into a 64-bit integer number of nanoseconds. (That is, roughly 292
+/// years.)
+///
+/// # Examples
+///
+/// ```
+/// use arrow_buffer::IntervalMonthDayNano;
+/// use jiff::ToSpan;
+/// use jiff_arrow::{ConvertTryFrom as _};
+///
+/// let span = 1.year().months(2).weeks(1).days(3).hours(4).nanoseconds(5);
+/// let interval = IntervalMonthDayNano::convert_try_from(span)?;
+/// assert_eq!(
+///     interval,
+///     IntervalMonthDayNano::new(14, 10, 14_400_000_000_005),
+/// );
+///
+/// let span = -1.month().minutes(30);
+/// let interval = IntervalMonthDayNano::convert_try_from(span)?;
+/// assert_eq!(interval, IntervalMonthDayNano::new(-1, 0, -1_800_000_000_000));
+///
+/// assert!(IntervalMonthDayNano::convert_try_from(3_000_000.hours()).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<JiffSpan> for IntervalMonthDayNano {
+    type Error = Error;
+
+    fn convert_try_from(v: JiffSpan) -> Result<IntervalMonthDayNano, Error> {
+        // None of these can overflow since the limits on each unit in a
+        // `Span` are small enough.
+        let months = i32::from(v.get_years()) * 12 + v.get_months();
+        let days = v.get_weeks() * 7 + v.get_days();
+        let nanos = i128::from(v.get_hours()) * 3_600_000_000_000
+            + i128::from(v.get_minutes()) * 60_000_000_000
+            + i128::from(v.get_seconds()) * 1_000_000_000
+            + i128::from(v.get_milliseconds()) * 1_000_000
+            + i128::from(v.get_microseconds()) * 1_000
+            + i128::from(v.get_nanoseconds());
+        let nanos = i64::try_from(nanos).map_err(|_| {
+            err!(
+                "time units in span {v} overflow the nanoseconds \
+                 of an Arrow interval",
+            )
+        })?;
+        Ok(IntervalMonthDayNano::new(months, days, nanos))
+    }
+}
+
+/// Converts from an Arrow
+/// [`IntervalMonthDayNano`](arrow_buffer::IntervalMonthDayNano) to a
+/// [`jiff::Span`].
+///
+/// # Errors
+///
+/// This returns an error when any of the interval's components exceeds the
+/// limits of the corresponding unit in a `Span`.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_buffer::IntervalMonthDayNano;
+/// use jiff::{Span, ToSpan};
+/// use jiff_arrow::{ConvertTryFrom as _};
+///
+/// let interval = IntervalMonthDayNano::new(14, 10, 5);
+/// let span = Span::convert_try_from(interval)?;
+/// assert_eq!(span.fieldwise(), 14.months().days(10).nanoseconds(5));
+///
+/// let interval = IntervalMonthDayNano::new(i32::MAX, 0, 0);
+/// assert!(Span::convert_try_from(interval).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<IntervalMonthDayNano> for JiffSpan {
+    type Error = Error;
+
+    fn convert_try_from(v: IntervalMonthDayNano) -> Result<JiffSpan, Error> {
+        Ok(JiffSpan::new()
+            .try_months(v.months)?
+            .try_days(v.days)?
+            .try_nanoseconds(v.nanoseconds)?)
+    }
+}
@@ -0,0 +1,101 @@
+use arrow_schema::TimeUnit;
+use jiff::civil;
+
+use crate::{
+    error::{err, Error},
+    unit,
+};
+
+/// A value of Arrow's `Time64` logical type.
+///
+/// A `Time64` value is a number of microseconds or nanoseconds since
+/// midnight. Arrow does not permit `Time64` values with a unit of seconds or
+/// milliseconds. (Those are represented by Arrow's `Time32` type instead.)
+///
+/// # Example
+///
+/// ```
+/// use arrow_schema::TimeUnit;
+/// use jiff::civil::time;
+/// use jiff_arrow::Time64;
+///
+/// let t = time(17, 30, 0, 123_456_789);
+/// let value = Time64::from_jiff(t, TimeUnit::Microsecond)?;
+/// assert_eq!(value.value(), 63_000_123_456);
+/// // Precision beyond the unit is truncated.
+/// assert_eq!(value.to_jiff(), time(17, 30, 0, 123_456_000));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Time64 {
+    value: i64,
+    unit: TimeUnit,
+}
+
+impl Time64 {
+    /// Creates a new `Time64` value from a raw Arrow value and its unit.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when `unit` is not [`TimeUnit::Microsecond`] or
+    /// [`TimeUnit::Nanosecond`], or when `value` is not in the range of a
+    /// single day (that is, negative or at least 24 hours).
+    pub fn new(value: i64, unit: TimeUnit) -> Result<Time64, Error> {
+        check_unit(unit)?;
+        let per_day = 86_400_000_000_000 / unit::nanos_per_unit(unit);
+        if !(0..per_day).contains(&i128::from(value)) {
+            return Err(err!(
+                "Time64 value {value} with unit {unit:?} is not \
+                 within a single day",
+            ));
+        }
+        Ok(Time64 { value, unit })
+    }
+
+    /// Converts a Jiff civil time to a `Time64` value with the given unit.
+    ///
+    /// Any precision beyond `unit` is truncated.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when `unit` is not [`TimeUnit::Microsecond`] or
+    /// [`TimeUnit::Nanosecond`].
+    pub fn from_jiff(
+        time: civil::Time,
+        unit: TimeUnit,
+    ) -> Result<Time64, Error> {
+        check_unit(unit)?;
+        let dur = time.duration_since(civil::Time::midnight());
+        let value = unit::from_duration(dur, unit)?;
+        Ok(Time64 { value, unit })
+    }
+
+    /// Returns the raw Arrow value.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Returns the unit of the raw Arrow value.
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    /// Converts this `Time64` value to a Jiff civil time.
+    pub fn to_jiff(&self) -> civil::Time {
+        let dur = unit::to_duration(self.value, self.unit);
+        // OK because the constructors guarantee that the value is within a
+        // single day.
+        civil::Time::midnight().checked_add(dur).unwrap()
+    }
+}
+
+fn check_unit(unit: TimeUnit) -> Result<(), Error> {
+    match unit {
+        TimeUnit::Microsecond | TimeUnit::Nanosecond => Ok(()),
+        TimeUnit::Second | TimeUnit::Millisecond => Err(err!(
+            "Time64 values must have a unit of microseconds or \
+             nanoseconds, but got {unit:?}",
+        )),
+    }
+}
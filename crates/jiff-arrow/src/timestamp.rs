@@ -0,0 +1,275 @@
+use std::sync::Arc;
+
+use arrow_schema::TimeUnit;
+use jiff::{
+    civil,
+    tz::{Offset, TimeZone},
+    Zoned,
+};
+
+use crate::{
+    error::{err, Error},
+    unit,
+};
+
+/// A value of Arrow's `Timestamp` logical type.
+///
+/// An Arrow timestamp is an integer number of `unit`s since the Unix epoch,
+/// along with an optional time zone.
+///
+/// When a time zone is present, the value refers to a precise instant in
+/// time, and the time zone says how to display it. This corresponds to a
+/// [`jiff::Timestamp`] or a [`jiff::Zoned`]. The time zone is either an IANA
+/// time zone identifier like `America/New_York` or a fixed offset like
+/// `+05:30`.
+///
+/// When a time zone is absent, the value is a civil "wall clock" datetime
+/// encoded as if it were in UTC. This corresponds to a
+/// [`jiff::civil::DateTime`].
+///
+/// # Example
+///
+/// This shows how to round trip a `Zoned` through an Arrow timestamp:
+///
+/// ```
+/// use arrow_schema::TimeUnit;
+/// use jiff_arrow::Timestamp;
+///
+/// let zdt: jiff::Zoned = "2025-02-20T17:00-05[America/New_York]".parse()?;
+/// let value = Timestamp::from_zoned(&zdt, TimeUnit::Millisecond)?;
+/// assert_eq!(value.value(), 1_740_088_800_000);
+/// assert_eq!(value.tz(), Some("America/New_York"));
+/// assert_eq!(value.to_zoned()?, zdt);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Timestamp {
+    value: i64,
+    unit: TimeUnit,
+    tz: Option<Arc<str>>,
+}
+
+impl Timestamp {
+    /// Creates a new Arrow timestamp from a raw Arrow value, its unit and its
+    /// optional time zone.
+    ///
+    /// The time zone is not validated until it's used by a conversion
+    /// routine like [`Timestamp::to_zoned`].
+    pub fn new(value: i64, unit: TimeUnit, tz: Option<Arc<str>>) -> Timestamp {
+        Timestamp { value, unit, tz }
+    }
+
+    /// Converts a Jiff timestamp to an Arrow timestamp in UTC with the given
+    /// unit.
+    ///
+    /// Any precision beyond `unit` is truncated toward negative infinity.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the timestamp can't be represented by a
+    /// 64-bit integer number of `unit`s. This can only happen with
+    /// [`TimeUnit::Nanosecond`].
+    pub fn from_timestamp(
+        ts: jiff::Timestamp,
+        unit: TimeUnit,
+    ) -> Result<Timestamp, Error> {
+        let value = unit::from_duration(ts.as_duration(), unit)?;
+        Ok(Timestamp { value, unit, tz: Some(Arc::from("UTC")) })
+    }
+
+    /// Converts a Jiff zoned datetime to an Arrow timestamp with the given
+    /// unit.
+    ///
+    /// The time zone of the Arrow timestamp is the IANA time zone identifier
+    /// of the zoned datetime's time zone. If it doesn't have one, then its
+    /// fixed offset is used.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same cases as
+    /// [`Timestamp::from_timestamp`]. This also returns an error when the
+    /// time zone has neither an IANA time zone identifier nor a fixed offset
+    /// (like a time zone created from a POSIX TZ string), or when the fixed
+    /// offset isn't a whole number of minutes.
+    pub fn from_zoned(
+        zdt: &Zoned,
+        unit: TimeUnit,
+    ) -> Result<Timestamp, Error> {
+        let tz = zdt.time_zone();
+        let tz: Arc<str> = match tz.iana_name() {
+            Some(name) => Arc::from(name),
+            None => Arc::from(format_offset(tz.to_fixed_offset()?)?),
+        };
+        let value = unit::from_duration(zdt.timestamp().as_duration(), unit)?;
+        Ok(Timestamp { value, unit, tz: Some(tz) })
+    }
+
+    /// Converts a Jiff civil datetime to an Arrow timestamp without a time
+    /// zone with the given unit.
+    ///
+    /// Any precision beyond `unit` is truncated toward negative infinity.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the datetime can't be represented by a
+    /// 64-bit integer number of `unit`s. This can only happen with
+    /// [`TimeUnit::Nanosecond`].
+    pub fn from_datetime(
+        dt: civil::DateTime,
+        unit: TimeUnit,
+    ) -> Result<Timestamp, Error> {
+        let ts = TimeZone::UTC.to_timestamp(dt)?;
+        let value = unit::from_duration(ts.as_duration(), unit)?;
+        Ok(Timestamp { value, unit, tz: None })
+    }
+
+    /// Returns the raw Arrow value.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Returns the unit of the raw Arrow value.
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    /// Returns the time zone of this Arrow timestamp, if present.
+    pub fn tz(&self) -> Option<&str> {
+        self.tz.as_deref()
+    }
+
+    /// Converts this Arrow timestamp to a Jiff timestamp.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when this Arrow timestamp doesn't have a time
+    /// zone, since it then doesn't refer to a precise instant in time. Use
+    /// [`Timestamp::to_datetime`] instead. This also returns an error when
+    /// the value is outside of Jiff's supported range.
+    pub fn to_timestamp(&self) -> Result<jiff::Timestamp, Error> {
+        if self.tz.is_none() {
+            return Err(err!(
+                "cannot convert Arrow timestamp without a time zone to \
+                 a Jiff timestamp, since it is a civil datetime",
+            ));
+        }
+        self.to_timestamp_unchecked()
+    }
+
+    /// Converts this Arrow timestamp to a Jiff zoned datetime.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same cases as
+    /// [`Timestamp::to_timestamp`]. This also returns an error when the time
+    /// zone isn't a valid fixed offset or a time zone that can be found in
+    /// the global time zone database.
+    ///
+    /// # Example
+    ///
+    /// Arrow time zones may be fixed offsets:
+    ///
+    /// ```
+    /// use arrow_schema::TimeUnit;
+    /// use jiff_arrow::Timestamp;
+    ///
+    /// let value = Timestamp::new(
+    ///     1_740_088_800,
+    ///     TimeUnit::Second,
+    ///     Some("+0530".into()),
+    /// );
+    /// assert_eq!(
+    ///     value.to_zoned()?.to_string(),
+    ///     "2025-02-21T03:30:00+05:30[+05:30]",
+    /// );
+    ///
+    /// let value = Timestamp::new(0, TimeUnit::Second, Some("+5:30".into()));
+    /// assert!(value.to_zoned().is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_zoned(&self) -> Result<Zoned, Error> {
+        let Some(ref tz) = self.tz else {
+            return Err(err!(
+                "cannot convert Arrow timestamp without a time zone to \
+                 a Jiff zoned datetime, since it is a civil datetime",
+            ));
+        };
+        let tz = parse_time_zone(tz)?;
+        Ok(self.to_timestamp_unchecked()?.to_zoned(tz))
+    }
+
+    /// Converts this Arrow timestamp to a Jiff civil datetime.
+    ///
+    /// When this Arrow timestamp doesn't have a time zone, then the value
+    /// is interpreted directly as a civil datetime. Otherwise, this returns
+    /// the civil datetime of the instant in the Arrow timestamp's time zone.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the value is outside of Jiff's supported
+    /// range, or in the same cases as [`Timestamp::to_zoned`] when a time
+    /// zone is present.
+    pub fn to_datetime(&self) -> Result<civil::DateTime, Error> {
+        match self.tz {
+            None => {
+                let ts = self.to_timestamp_unchecked()?;
+                Ok(TimeZone::UTC.to_datetime(ts))
+            }
+            Some(_) => Ok(self.to_zoned()?.datetime()),
+        }
+    }
+
+    fn to_timestamp_unchecked(&self) -> Result<jiff::Timestamp, Error> {
+        let dur = unit::to_duration(self.value, self.unit);
+        Ok(jiff::Timestamp::from_duration(dur)?)
+    }
+}
+
+/// Parses an Arrow time zone string.
+///
+/// Arrow permits either a fixed offset like `+05:30`, `+0530` or `+05`, or
+/// an IANA time zone identifier.
+fn parse_time_zone(tz: &str) -> Result<TimeZone, Error> {
+    if !tz.starts_with(['+', '-']) {
+        return Ok(TimeZone::get(tz)?);
+    }
+    let invalid = || err!("invalid fixed offset time zone `{tz}`");
+    let (sign, rest) = tz.split_at(1);
+    let (hours, minutes) = match rest.len() {
+        2 => (rest, "00"),
+        4 => rest.split_at(2),
+        5 if rest.as_bytes()[2] == b':' => (&rest[..2], &rest[3..]),
+        _ => return Err(invalid()),
+    };
+    if !hours.bytes().chain(minutes.bytes()).all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    // OK because we just checked that they are all ASCII digits.
+    let hours: i32 = hours.parse().unwrap();
+    let minutes: i32 = minutes.parse().unwrap();
+    if minutes >= 60 {
+        return Err(invalid());
+    }
+    let mut seconds = hours * 3600 + minutes * 60;
+    if sign == "-" {
+        seconds = -seconds;
+    }
+    let offset = Offset::from_seconds(seconds).map_err(|_| invalid())?;
+    Ok(TimeZone::fixed(offset))
+}
+
+/// Formats a fixed offset as an Arrow time zone string like `+05:30`.
+fn format_offset(offset: Offset) -> Result<String, Error> {
+    let seconds = offset.seconds();
+    if seconds % 60 != 0 {
+        return Err(err!(
+            "cannot use offset {offset} with non-zero seconds as an \
+             Arrow time zone",
+        ));
+    }
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.unsigned_abs() / 60;
+    Ok(format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60))
+}
@@ -0,0 +1,49 @@
+use arrow_schema::TimeUnit;
+use jiff::SignedDuration;
+
+use crate::error::{err, Error};
+
+/// Converts the given duration to an integer number of `unit`s.
+///
+/// This rounds toward negative infinity, so that the resulting Arrow value
+/// never refers to an instant after the one given. This is consistent with
+/// how Arrow's own temporal kernels interpret values before the Unix epoch.
+///
+/// This returns an error when the result does not fit into an `i64`. This
+/// can only happen for [`TimeUnit::Nanosecond`], which can only represent
+/// instants within roughly 292 years of the Unix epoch.
+pub(crate) fn from_duration(
+    dur: SignedDuration,
+    unit: TimeUnit,
+) -> Result<i64, Error> {
+    let value = dur.as_nanos().div_euclid(nanos_per_unit(unit));
+    i64::try_from(value).map_err(|_| {
+        err!(
+            "duration {dur:?} overflows a 64-bit integer \
+             number of {unit:?} units",
+        )
+    })
+}
+
+/// Converts the given integer number of `unit`s to a duration.
+///
+/// This never fails since every `i64` number of any unit fits into a
+/// `SignedDuration`.
+pub(crate) fn to_duration(value: i64, unit: TimeUnit) -> SignedDuration {
+    match unit {
+        TimeUnit::Second => SignedDuration::from_secs(value),
+        TimeUnit::Millisecond => SignedDuration::from_millis(value),
+        TimeUnit::Microsecond => SignedDuration::from_micros(value),
+        TimeUnit::Nanosecond => SignedDuration::from_nanos(value),
+    }
+}
+
+/// Returns the number of nanoseconds in a single `unit`.
+pub(crate) fn nanos_per_unit(unit: TimeUnit) -> i128 {
+    match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
@@ -39,6 +39,10 @@ express nullable values.][diesel-optional-wrapper-types]
 
 At present, MySQL, PostgreSQL and SQLite are supported.
 
+The [`Span`] and [`SignedDuration`] wrappers are only supported for
+PostgreSQL, since it is the only one of these databases with an `interval`
+type.
+
 # Future
 
 This crate exists because there are generally only three ways to implement
@@ -75,10 +79,12 @@ itself.
 #![deny(missing_docs)]
 
 pub use self::nullable::{
-    NullableDate, NullableDateTime, NullableSpan, NullableTime,
-    NullableTimestamp,
+    NullableDate, NullableDateTime, NullableSignedDuration, NullableSpan,
+    NullableTime, NullableTimestamp,
+};
+pub use self::wrappers::{
+    Date, DateTime, SignedDuration, Span, Time, Timestamp, ToDiesel,
 };
-pub use self::wrappers::{Date, DateTime, Span, Time, Timestamp, ToDiesel};
 
 #[cfg(feature = "mysql")]
 mod mysql;
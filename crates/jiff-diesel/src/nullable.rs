@@ -349,6 +349,11 @@ where
 /// This can be used when deriving [`diesel::deserialize::Queryable`]
 /// or [`diesel::deserialize::QueryableByName`] trait implementations.
 #[derive(Clone, Copy, Debug, diesel::deserialize::FromSqlRow)]
+#[cfg_attr(
+    feature = "postgres",
+    derive(diesel::expression::AsExpression),
+    diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Interval>),
+)]
 pub struct NullableSpan(Option<crate::Span>);
 
 impl NullableSpan {
@@ -378,6 +383,22 @@ impl From<NullableSpan> for Option<jiff::Span> {
     }
 }
 
+#[cfg(feature = "postgres")]
+impl<DB: Backend>
+    ToSql<diesel::sql_types::Nullable<diesel::sql_types::Interval>, DB>
+    for NullableSpan
+where
+    Option<crate::Span>:
+        ToSql<diesel::sql_types::Nullable<diesel::sql_types::Interval>, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
 impl<DB: Backend, ST> FromSql<ST, DB> for NullableSpan
 where
     Option<crate::Span>: FromSql<ST, DB>,
@@ -394,3 +415,75 @@ where
         FromSql::from_nullable_sql(bytes).map(NullableSpan)
     }
 }
+
+/// A wrapper type for `Option<jiff::SignedDuration>`.
+///
+/// This can be used when deriving [`diesel::deserialize::Queryable`]
+/// or [`diesel::deserialize::QueryableByName`] trait implementations.
+#[derive(Clone, Copy, Debug, diesel::deserialize::FromSqlRow)]
+#[cfg_attr(
+    feature = "postgres",
+    derive(diesel::expression::AsExpression),
+    diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Interval>),
+)]
+pub struct NullableSignedDuration(Option<crate::SignedDuration>);
+
+impl NullableSignedDuration {
+    /// Converts this wrapper to an `Option<jiff::SignedDuration>`.
+    pub fn to_jiff(self) -> Option<jiff::SignedDuration> {
+        self.into()
+    }
+}
+
+impl ToDiesel for Option<jiff::SignedDuration> {
+    type Target = NullableSignedDuration;
+
+    fn to_diesel(self) -> NullableSignedDuration {
+        NullableSignedDuration(self.map(ToDiesel::to_diesel))
+    }
+}
+
+impl From<Option<jiff::SignedDuration>> for NullableSignedDuration {
+    fn from(x: Option<jiff::SignedDuration>) -> Self {
+        Self(x.map(Into::into))
+    }
+}
+
+impl From<NullableSignedDuration> for Option<jiff::SignedDuration> {
+    fn from(x: NullableSignedDuration) -> Self {
+        x.0.map(Into::into)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<DB: Backend>
+    ToSql<diesel::sql_types::Nullable<diesel::sql_types::Interval>, DB>
+    for NullableSignedDuration
+where
+    Option<crate::SignedDuration>:
+        ToSql<diesel::sql_types::Nullable<diesel::sql_types::Interval>, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+impl<DB: Backend, ST> FromSql<ST, DB> for NullableSignedDuration
+where
+    Option<crate::SignedDuration>: FromSql<ST, DB>,
+{
+    fn from_sql(
+        bytes: <DB as Backend>::RawValue<'_>,
+    ) -> diesel::deserialize::Result<Self> {
+        FromSql::from_sql(bytes).map(NullableSignedDuration)
+    }
+
+    fn from_nullable_sql(
+        bytes: Option<<DB as Backend>::RawValue<'_>>,
+    ) -> diesel::deserialize::Result<Self> {
+        FromSql::from_nullable_sql(bytes).map(NullableSignedDuration)
+    }
+}
@@ -9,7 +9,7 @@ use diesel::{
 };
 use jiff::{civil, tz};
 
-use crate::{Date, DateTime, Span, Time, Timestamp, ToDiesel};
+use crate::{Date, DateTime, SignedDuration, Span, Time, Timestamp, ToDiesel};
 
 /// Apprently the actual format of values on the wire is not
 /// a documented guarantee of PostgreSQL.[1] Instead, I just `sqlx`'s
@@ -22,6 +22,7 @@ static POSTGRES_EPOCH_DATETIME: civil::DateTime =
     civil::date(2000, 1, 1).at(0, 0, 0, 0);
 static POSTGRES_EPOCH_TIMESTAMP: i64 = 946684800;
 static MIDNIGHT: civil::Time = civil::Time::midnight();
+static MICROS_PER_DAY: i64 = 86_400_000_000;
 static UTC: tz::TimeZone = tz::TimeZone::UTC;
 
 impl ToSql<sql_types::Timestamptz, Pg> for Timestamp {
@@ -129,6 +130,30 @@ impl FromSql<sql_types::Time, Pg> for Time {
     }
 }
 
+impl ToSql<sql_types::Interval, Pg> for Span {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, Pg>,
+    ) -> serialize::Result {
+        let span = self.to_jiff();
+        // None of these can overflow since the limits on each unit in a
+        // `Span` are small enough. For example, the maximum number of
+        // microseconds from all time units combined is less than 4e18.
+        let months = i32::from(span.get_years()) * 12 + span.get_months();
+        let days = span.get_weeks() * 7 + span.get_days();
+        let microseconds = i64::from(span.get_hours()) * 3_600_000_000
+            + span.get_minutes() * 60_000_000
+            + span.get_seconds() * 1_000_000
+            + span.get_milliseconds() * 1_000
+            + span.get_microseconds()
+            + span.get_nanoseconds() / 1_000;
+        ToSql::<sql_types::Interval, Pg>::to_sql(
+            &PgInterval { microseconds, days, months },
+            &mut out.reborrow(),
+        )
+    }
+}
+
 impl FromSql<sql_types::Interval, Pg> for Span {
     fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Span> {
         let interval: PgInterval =
@@ -140,3 +165,43 @@ impl FromSql<sql_types::Interval, Pg> for Span {
         Ok(span.to_diesel())
     }
 }
+
+impl ToSql<sql_types::Interval, Pg> for SignedDuration {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, Pg>,
+    ) -> serialize::Result {
+        // A `SignedDuration` is an exact duration, so we only ever use the
+        // microseconds component of an interval.
+        let microseconds =
+            i64::try_from(self.to_jiff().as_micros()).map_err(|_| {
+                "signed duration is too big to fit into a PostgreSQL interval"
+            })?;
+        ToSql::<sql_types::Interval, Pg>::to_sql(
+            &PgInterval { microseconds, days: 0, months: 0 },
+            &mut out.reborrow(),
+        )
+    }
+}
+
+impl FromSql<sql_types::Interval, Pg> for SignedDuration {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<SignedDuration> {
+        let interval: PgInterval =
+            FromSql::<sql_types::Interval, Pg>::from_sql(bytes)?;
+        if interval.months != 0 {
+            return Err("cannot decode PostgreSQL interval with non-zero \
+                        months into a signed duration"
+                .into());
+        }
+        // Days are always 24 hours, which is consistent with how
+        // `SignedDuration` treats days elsewhere in Jiff.
+        let days = jiff::SignedDuration::from_micros(
+            i64::from(interval.days) * MICROS_PER_DAY,
+        );
+        let micros = jiff::SignedDuration::from_micros(interval.microseconds);
+        let sdur = days
+            .checked_add(micros)
+            .ok_or("PostgreSQL interval overflows a signed duration")?;
+        Ok(sdur.to_diesel())
+    }
+}
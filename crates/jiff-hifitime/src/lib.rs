@@ -0,0 +1,222 @@
+/*!
+This crate provides conversion routines between [`jiff`] and
+[`hifitime`](https://docs.rs/hifitime).
+
+The main goal of this crate is to make it possible for Jiff and `hifitime` to
+coexist in the same program. For example, an application might use
+`hifitime` for its astrodynamics computations while using Jiff for
+everything that needs time zone support.
+
+Conversions are implemented via conversion traits defined in this crate. The
+traits mirror the [`From`], [`Into`], [`TryFrom`] and [`TryInto`] traits from
+the standard library, just like in the `jiff-icu` crate.
+
+# Leap seconds
+
+Jiff and `hifitime` have fundamentally different models of time. A
+[`jiff::Timestamp`] is a Unix timestamp, which means it pretends as if leap
+seconds don't exist. A [`hifitime::Epoch`], on the other hand, is a TAI
+based instant that is expressed in one of several time scales, and leap
+seconds are accounted for when moving between them.
+
+The conversions in this crate bridge these models by going through
+`hifitime`'s UTC time scale. That is, a Jiff timestamp is interpreted as a
+UTC instant, and `hifitime` uses its table of leap seconds to determine the
+corresponding TAI instant. Converting an `Epoch` in any time scale back to a
+Jiff timestamp does the reverse. So round tripping a Jiff timestamp through
+an `Epoch` always returns the original timestamp.
+
+An important consequence of this is that the elapsed time between two
+`Epoch` values in the TAI time scale may be different from the elapsed time
+between the corresponding Jiff timestamps:
+
+```
+use hifitime::{Epoch, TimeScale};
+use jiff::{SignedDuration, Timestamp};
+use jiff_hifitime::ConvertFrom as _;
+
+let ts1: Timestamp = "2016-12-31T23:59:59Z".parse()?;
+let ts2: Timestamp = "2017-01-01T00:00:00Z".parse()?;
+// Jiff doesn't know about the leap second inserted at the end of 2016.
+assert_eq!(ts2.duration_since(ts1), SignedDuration::from_secs(1));
+
+let e1 = Epoch::convert_from(ts1).to_time_scale(TimeScale::TAI);
+let e2 = Epoch::convert_from(ts2).to_time_scale(TimeScale::TAI);
+// But `hifitime` does!
+assert_eq!(
+    SignedDuration::convert_from(e2 - e1),
+    SignedDuration::from_secs(2),
+);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+# Available conversions
+
+* [`jiff::Timestamp`] infallibly converts to [`hifitime::Epoch`] in the UTC
+  time scale. The reverse is fallible, since `hifitime` supports a much
+  larger range of instants than Jiff.
+* [`hifitime::Duration`] infallibly converts to [`jiff::SignedDuration`]. The
+  reverse is fallible, since Jiff supports a larger range of durations than
+  `hifitime`.
+
+# Example
+
+This shows how to convert an `Epoch` in the GPS time scale to a Jiff zoned
+datetime:
+
+```
+use hifitime::Epoch;
+use jiff_hifitime::ConvertTryFrom as _;
+
+let epoch = Epoch::from_gpst_seconds(1_420_070_418.0);
+let ts = jiff::Timestamp::convert_try_from(epoch)?;
+let zdt = ts.in_tz("America/New_York")?;
+assert_eq!(zdt.to_string(), "2025-01-04T19:00:00-05:00[America/New_York]");
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+#![deny(missing_docs)]
+
+use hifitime::{Duration as HifiDuration, Epoch};
+use jiff::{SignedDuration, Timestamp};
+
+use self::error::err;
+pub use self::{
+    error::Error,
+    traits::{ConvertFrom, ConvertInto, ConvertTryFrom, ConvertTryInto},
+};
+
+mod error;
+mod traits;
+
+/// Converts from a [`jiff::Timestamp`] to a [`hifitime::Epoch`].
+///
+/// The `Epoch` returned is in the UTC time scale. Use
+/// [`Epoch::to_time_scale`] to convert it to a different time scale.
+///
+/// # Examples
+///
+/// ```
+/// use hifitime::{Epoch, TimeScale};
+/// use jiff_hifitime::ConvertFrom as _;
+///
+/// let ts: jiff::Timestamp = "2025-01-01T00:00:00Z".parse()?;
+/// let epoch = Epoch::convert_from(ts);
+/// assert_eq!(epoch.time_scale, TimeScale::UTC);
+/// assert_eq!(epoch, Epoch::from_gregorian_utc_at_midnight(2025, 1, 1));
+/// assert_eq!(
+///     epoch.to_time_scale(TimeScale::TAI).to_string(),
+///     "2025-01-01T00:00:37 TAI",
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertFrom<Timestamp> for Epoch {
+    fn convert_from(v: Timestamp) -> Epoch {
+        let dur = HifiDuration::from_total_nanoseconds(v.as_nanosecond());
+        Epoch::from_unix_duration(dur)
+    }
+}
+
+/// Converts from a [`hifitime::Epoch`] to a [`jiff::Timestamp`].
+///
+/// The `Epoch` may be in any time scale. It is converted to the UTC time
+/// scale before being converted to a Jiff timestamp.
+///
+/// # Errors
+///
+/// This returns an error when the instant is outside of Jiff's supported
+/// range.
+///
+/// # Examples
+///
+/// ```
+/// use hifitime::Epoch;
+/// use jiff_hifitime::ConvertTryFrom as _;
+///
+/// let epoch = Epoch::from_gregorian_tai_at_midnight(2025, 1, 1);
+/// let ts = jiff::Timestamp::convert_try_from(epoch)?;
+/// assert_eq!(ts.to_string(), "2024-12-31T23:59:23Z");
+///
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(10_000, 1, 1);
+/// assert!(jiff::Timestamp::convert_try_from(epoch).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<Epoch> for Timestamp {
+    type Error = Error;
+
+    fn convert_try_from(v: Epoch) -> Result<Timestamp, Error> {
+        let nanos = v.to_unix_duration().total_nanoseconds();
+        Ok(Timestamp::from_nanosecond(nanos)?)
+    }
+}
+
+/// Converts from a [`hifitime::Duration`] to a [`jiff::SignedDuration`].
+///
+/// # Examples
+///
+/// ```
+/// use hifitime::Duration;
+/// use jiff::SignedDuration;
+/// use jiff_hifitime::ConvertFrom as _;
+///
+/// let dur = Duration::from_seconds(-1.5);
+/// let sdur = SignedDuration::convert_from(dur);
+/// assert_eq!(sdur, SignedDuration::from_millis(-1_500));
+///
+/// let sdur = SignedDuration::convert_from(Duration::MAX);
+/// assert_eq!(sdur.as_nanos(), Duration::MAX.total_nanoseconds());
+/// ```
+impl ConvertFrom<HifiDuration> for SignedDuration {
+    fn convert_from(v: HifiDuration) -> SignedDuration {
+        let nanos = v.total_nanoseconds();
+        // OK because the largest `hifitime` duration is about 3.3 million
+        // years, which fits comfortably in a `SignedDuration`.
+        let secs = i64::try_from(nanos / 1_000_000_000).unwrap();
+        let subsec = i32::try_from(nanos % 1_000_000_000).unwrap();
+        SignedDuration::new(secs, subsec)
+    }
+}
+
+/// Converts from a [`jiff::SignedDuration`] to a [`hifitime::Duration`].
+///
+/// # Errors
+///
+/// This returns an error when the duration's magnitude exceeds what
+/// `hifitime` supports, which is about 32,768 centuries.
+///
+/// # Examples
+///
+/// ```
+/// use hifitime::Duration;
+/// use jiff::SignedDuration;
+/// use jiff_hifitime::ConvertTryFrom as _;
+///
+/// let sdur = SignedDuration::from_millis(-1_500);
+/// let dur = Duration::convert_try_from(sdur)?;
+/// assert_eq!(dur, Duration::from_seconds(-1.5));
+///
+/// assert!(Duration::convert_try_from(SignedDuration::MAX).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<SignedDuration> for HifiDuration {
+    type Error = Error;
+
+    fn convert_try_from(v: SignedDuration) -> Result<HifiDuration, Error> {
+        let nanos = v.as_nanos();
+        let min = HifiDuration::MIN.total_nanoseconds();
+        let max = HifiDuration::MAX.total_nanoseconds();
+        if !(min..=max).contains(&nanos) {
+            return Err(err!(
+                "signed duration {v:?} is outside the range supported by \
+                 hifitime durations",
+            ));
+        }
+        Ok(HifiDuration::from_total_nanoseconds(nanos))
+    }
+}
@@ -21,6 +21,94 @@ The reverse is fallible.
 [`icu::calendar::types::IsoWeekday`](icu_calendar::types::IsoWeekday).
 The reverse is also infallible.
 
+# Format a datetime with a skeleton
+
+When the `skeleton` crate feature is enabled, [`SkeletonFormatter`] formats
+Jiff datetimes directly, using a [skeleton] like `yMMMd jm` to pick the fields
+to show. ICU4X then picks the order and punctuation appropriate for the
+locale:
+
+```
+# #[cfg(feature = "skeleton")] {
+use icu::locid::locale;
+use jiff::civil::date;
+use jiff_icu::SkeletonFormatter;
+
+let dt = date(2024, 4, 1).at(14, 30, 0, 0);
+
+let formatter = SkeletonFormatter::try_new(&locale!("en-US").into(), "yMMMd jm")?;
+assert_eq!(formatter.format(dt), "Apr 1, 2024, 2:30\u{202f}PM");
+
+let formatter = SkeletonFormatter::try_new(&locale!("fr").into(), "yMMMd jm")?;
+assert_eq!(formatter.format(dt), "1 avr. 2024, 14:30");
+# }
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+[skeleton]: https://unicode.org/reports/tr35/tr35-dates.html#availableFormats_appendItems
+
+# Format a datetime with a length style
+
+When the `style` crate feature is enabled, [`StyleFormatter`] formats Jiff
+datetimes using the classic full, long, medium and short [`Style`]s for dates
+and times:
+
+```
+# #[cfg(feature = "style")] {
+use icu::locid::locale;
+use jiff::Zoned;
+use jiff_icu::{Style, StyleFormatter};
+
+let zdt: Zoned = "2024-04-01T14:30[America/New_York]".parse()?;
+
+let formatter = StyleFormatter::try_new(
+    &locale!("en-US").into(),
+    Some(Style::Long),
+    Some(Style::Long),
+)?;
+assert_eq!(
+    formatter.format_zoned(&zdt),
+    "April 1, 2024, 2:30:00\u{202f}PM EDT",
+);
+
+let formatter = StyleFormatter::try_new(
+    &locale!("ja").into(),
+    Some(Style::Short),
+    Some(Style::Short),
+)?;
+assert_eq!(formatter.format_zoned(&zdt), "2024/04/01 14:30");
+# }
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+# Name a time zone
+
+When the `names` crate feature is enabled, [`TimeZoneNames`] provides
+localized names for Jiff time zones, like `Pacific Standard Time` or
+`Los Angeles Time`:
+
+```
+# #[cfg(feature = "names")] {
+use icu::locid::locale;
+use jiff::{tz::TimeZone, Timestamp};
+use jiff_icu::TimeZoneNames;
+
+let tz = TimeZone::get("America/Los_Angeles")?;
+let ts: Timestamp = "2024-01-15T12:00Z".parse()?;
+
+let names = TimeZoneNames::try_new(&locale!("en-US").into())?;
+assert_eq!(names.specific_long(&tz, ts), "Pacific Standard Time");
+assert_eq!(names.generic_location(&tz, ts), "Los Angeles Time");
+
+let names = TimeZoneNames::try_new(&locale!("fr").into())?;
+assert_eq!(names.specific_long(&tz, ts), "heure normale du Pacifique nord-américain");
+# }
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
 # Format a datetime in another locale
 
 This example shows how one can bridge `jiff` to `icu` in order to format a
@@ -111,8 +199,23 @@ pub use self::{
     traits::{ConvertFrom, ConvertInto, ConvertTryFrom, ConvertTryInto},
 };
 
+#[cfg(feature = "names")]
+pub use self::names::TimeZoneNames;
+#[cfg(feature = "skeleton")]
+pub use self::skeleton::SkeletonFormatter;
+#[cfg(feature = "style")]
+pub use self::style::{Style, StyleFormatter};
+
 mod error;
+#[cfg(feature = "names")]
+mod names;
+#[cfg(feature = "skeleton")]
+mod skeleton;
+#[cfg(feature = "style")]
+mod style;
 mod traits;
+#[cfg(any(feature = "names", feature = "style"))]
+mod zone;
 
 /// Converts from a [`icu_calendar::DateTime<Iso>`](icu_calendar::DateTime) to
 /// a [`jiff::civil::DateTime`].
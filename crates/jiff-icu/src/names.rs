@@ -0,0 +1,441 @@
+use alloc::string::String;
+
+use icu_datetime::{
+    provider::time_zones::{
+        ExemplarCitiesV1Marker, MetazoneGenericNamesLongV1Marker,
+        MetazoneGenericNamesShortV1Marker, MetazoneSpecificNamesLongV1Marker,
+        MetazoneSpecificNamesShortV1Marker, TimeZoneFormatsV1Marker,
+    },
+    time_zone::TimeZoneFormatter,
+    DateTimeError,
+};
+use icu_provider::{DataLocale, DataPayload, DataProvider, DataRequest};
+use jiff::{tz::TimeZone, Timestamp};
+
+use crate::{error::err, zone::ZoneMapper, Error};
+
+/// Localized, human readable names for time zones.
+///
+/// A time zone can be named in a few different ways. Some names depend on
+/// whether daylight saving time is in effect, so most methods on this type
+/// require the instant at which to name the time zone:
+///
+/// | Method | Example (`en-US`) |
+/// | ------ | ----------------- |
+/// | [`TimeZoneNames::specific_long`] | `Pacific Daylight Time` |
+/// | [`TimeZoneNames::specific_short`] | `PDT` |
+/// | [`TimeZoneNames::generic_long`] | `Pacific Time` |
+/// | [`TimeZoneNames::generic_short`] | `PT` |
+/// | [`TimeZoneNames::generic_location`] | `Los Angeles Time` |
+/// | [`TimeZoneNames::exemplar_city`] | `Los Angeles` |
+///
+/// When a locale has no name of the requested kind for a time zone, the
+/// localized GMT format (like `GMT-07:00`) is used instead. This is also what
+/// happens for time zones without an IANA identifier, like fixed offsets.
+///
+/// By default, the names come from the data compiled into ICU4X. Use
+/// [`TimeZoneNames::try_new_unstable`] to load them from some other ICU4X
+/// data provider instead.
+///
+/// # Example
+///
+/// ```
+/// use icu::locid::locale;
+/// use jiff::{tz::TimeZone, Timestamp};
+/// use jiff_icu::TimeZoneNames;
+///
+/// let tz = TimeZone::get("America/Los_Angeles")?;
+/// let ts: Timestamp = "2024-01-15T12:00Z".parse()?;
+///
+/// let names = TimeZoneNames::try_new(&locale!("en-US").into())?;
+/// assert_eq!(names.specific_long(&tz, ts), "Pacific Standard Time");
+///
+/// let names = TimeZoneNames::try_new(&locale!("fr").into())?;
+/// assert_eq!(names.specific_long(&tz, ts), "heure normale du Pacifique nord-américain");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct TimeZoneNames {
+    specific_long: TimeZoneFormatter,
+    specific_short: TimeZoneFormatter,
+    generic_long: TimeZoneFormatter,
+    generic_short: TimeZoneFormatter,
+    generic_location: TimeZoneFormatter,
+    cities: DataPayload<ExemplarCitiesV1Marker>,
+    zones: ZoneMapper,
+}
+
+impl TimeZoneNames {
+    /// Creates time zone names for the given locale using the data compiled
+    /// into ICU4X.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when ICU4X doesn't have data for the locale
+    /// given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff_icu::TimeZoneNames;
+    ///
+    /// assert!(TimeZoneNames::try_new(&locale!("de").into()).is_ok());
+    /// ```
+    pub fn try_new(locale: &DataLocale) -> Result<TimeZoneNames, Error> {
+        TimeZoneNames::build(
+            &icu_datetime::provider::Baked,
+            locale,
+            ZoneMapper::new(),
+        )
+    }
+
+    /// Creates time zone names for the given locale using the ICU4X data
+    /// provider given.
+    ///
+    /// This makes it possible to plug in names from somewhere other than the
+    /// data compiled into ICU4X, for example, a data blob loaded at runtime
+    /// or a provider with names customized for a particular application.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the provider fails to load any of the data
+    /// needed for the locale given.
+    ///
+    /// # Example
+    ///
+    /// This defines a provider that forwards every request to the data
+    /// compiled into ICU4X, which is equivalent to using
+    /// [`TimeZoneNames::try_new`]. A real provider might instead load its
+    /// data from a blob, or override the names of some time zones.
+    ///
+    /// ```
+    /// use icu::{
+    ///     datetime::provider::time_zones::*,
+    ///     locid::locale,
+    ///     timezone::provider::{names::IanaToBcp47MapV2Marker, MetazonePeriodV1Marker},
+    /// };
+    /// use icu_provider::prelude::*;
+    /// use jiff::{tz::TimeZone, Timestamp};
+    /// use jiff_icu::TimeZoneNames;
+    ///
+    /// struct Compiled;
+    ///
+    /// macro_rules! forward {
+    ///     ($baked:expr => $($marker:ty),*) => {$(
+    ///         impl DataProvider<$marker> for Compiled {
+    ///             fn load(
+    ///                 &self,
+    ///                 req: DataRequest,
+    ///             ) -> Result<DataResponse<$marker>, DataError> {
+    ///                 $baked.load(req)
+    ///             }
+    ///         }
+    ///     )*};
+    /// }
+    ///
+    /// forward!(icu::datetime::provider::Baked =>
+    ///     TimeZoneFormatsV1Marker,
+    ///     MetazoneSpecificNamesLongV1Marker,
+    ///     MetazoneSpecificNamesShortV1Marker,
+    ///     MetazoneGenericNamesLongV1Marker,
+    ///     MetazoneGenericNamesShortV1Marker,
+    ///     ExemplarCitiesV1Marker
+    /// );
+    /// forward!(icu::timezone::provider::Baked =>
+    ///     IanaToBcp47MapV2Marker,
+    ///     MetazonePeriodV1Marker
+    /// );
+    ///
+    /// let names = TimeZoneNames::try_new_unstable(
+    ///     &Compiled,
+    ///     &locale!("en-US").into(),
+    /// )?;
+    ///
+    /// let tz = TimeZone::get("Europe/Paris")?;
+    /// let ts: Timestamp = "2024-07-01T12:00Z".parse()?;
+    /// assert_eq!(names.specific_long(&tz, ts), "Central European Summer Time");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new_unstable<P>(
+        provider: &P,
+        locale: &DataLocale,
+    ) -> Result<TimeZoneNames, Error>
+    where
+        P: DataProvider<TimeZoneFormatsV1Marker>
+            + DataProvider<MetazoneSpecificNamesLongV1Marker>
+            + DataProvider<MetazoneSpecificNamesShortV1Marker>
+            + DataProvider<MetazoneGenericNamesLongV1Marker>
+            + DataProvider<MetazoneGenericNamesShortV1Marker>
+            + DataProvider<ExemplarCitiesV1Marker>
+            + DataProvider<icu_timezone::provider::names::IanaToBcp47MapV2Marker>
+            + DataProvider<icu_timezone::provider::MetazonePeriodV1Marker>
+            + ?Sized,
+    {
+        let zones = ZoneMapper::try_new_unstable(provider)
+            .map_err(|e| err!("failed to load time zone data: {e}"))?;
+        TimeZoneNames::build(provider, locale, zones)
+    }
+
+    fn build<P>(
+        provider: &P,
+        locale: &DataLocale,
+        zones: ZoneMapper,
+    ) -> Result<TimeZoneNames, Error>
+    where
+        P: DataProvider<TimeZoneFormatsV1Marker>
+            + DataProvider<MetazoneSpecificNamesLongV1Marker>
+            + DataProvider<MetazoneSpecificNamesShortV1Marker>
+            + DataProvider<MetazoneGenericNamesLongV1Marker>
+            + DataProvider<MetazoneGenericNamesShortV1Marker>
+            + DataProvider<ExemplarCitiesV1Marker>
+            + ?Sized,
+    {
+        let fail = |e: DateTimeError| {
+            err!("failed to load time zone names for locale {locale}: {e}")
+        };
+        let new = || {
+            TimeZoneFormatter::try_new_unstable(
+                provider,
+                locale,
+                Default::default(),
+            )
+            .map_err(fail)
+        };
+
+        let mut specific_long = new()?;
+        specific_long
+            .load_specific_non_location_long(provider)
+            .map_err(fail)?;
+        let mut specific_short = new()?;
+        specific_short
+            .load_specific_non_location_short(provider)
+            .map_err(fail)?;
+        let mut generic_long = new()?;
+        generic_long.load_generic_non_location_long(provider).map_err(fail)?;
+        let mut generic_short = new()?;
+        generic_short
+            .load_generic_non_location_short(provider)
+            .map_err(fail)?;
+        let mut generic_location = new()?;
+        generic_location
+            .load_generic_location_format(provider)
+            .map_err(fail)?;
+        let cities = provider
+            .load(DataRequest { locale, metadata: Default::default() })
+            .and_then(|response| response.take_payload())
+            .map_err(|e| {
+                err!("failed to load exemplar cities for locale {locale}: {e}")
+            })?;
+        Ok(TimeZoneNames {
+            specific_long,
+            specific_short,
+            generic_long,
+            generic_short,
+            generic_location,
+            cities,
+            zones,
+        })
+    }
+
+    /// Returns the long specific name of the time zone at the given instant,
+    /// like `Pacific Standard Time` or `Pacific Daylight Time`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::{tz::TimeZone, Timestamp};
+    /// use jiff_icu::TimeZoneNames;
+    ///
+    /// let names = TimeZoneNames::try_new(&locale!("en-US").into())?;
+    /// let tz = TimeZone::get("America/New_York")?;
+    ///
+    /// let ts: Timestamp = "2024-01-15T12:00Z".parse()?;
+    /// assert_eq!(names.specific_long(&tz, ts), "Eastern Standard Time");
+    /// let ts: Timestamp = "2024-07-15T12:00Z".parse()?;
+    /// assert_eq!(names.specific_long(&tz, ts), "Eastern Daylight Time");
+    ///
+    /// // Fixed offsets have no name, so the GMT format is used.
+    /// let tz = TimeZone::fixed(jiff::tz::offset(-4));
+    /// assert_eq!(names.specific_long(&tz, ts), "GMT-04:00");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn specific_long(
+        &self,
+        tz: &TimeZone,
+        timestamp: Timestamp,
+    ) -> String {
+        self.format(&self.specific_long, tz, timestamp)
+    }
+
+    /// Returns the short specific name of the time zone at the given
+    /// instant, like `PST` or `PDT`.
+    ///
+    /// Many locales only have short names for a few time zones that are
+    /// commonly known by them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::{tz::TimeZone, Timestamp};
+    /// use jiff_icu::TimeZoneNames;
+    ///
+    /// let names = TimeZoneNames::try_new(&locale!("en-US").into())?;
+    /// let ts: Timestamp = "2024-07-15T12:00Z".parse()?;
+    ///
+    /// let tz = TimeZone::get("America/Los_Angeles")?;
+    /// assert_eq!(names.specific_short(&tz, ts), "PDT");
+    /// let tz = TimeZone::get("Asia/Tokyo")?;
+    /// assert_eq!(names.specific_short(&tz, ts), "GMT+09:00");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn specific_short(
+        &self,
+        tz: &TimeZone,
+        timestamp: Timestamp,
+    ) -> String {
+        self.format(&self.specific_short, tz, timestamp)
+    }
+
+    /// Returns the long generic name of the time zone, like `Pacific Time`.
+    ///
+    /// A generic name doesn't say whether daylight saving time is in effect.
+    /// The instant given is still needed, since the name of a time zone can
+    /// change over time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::{tz::TimeZone, Timestamp};
+    /// use jiff_icu::TimeZoneNames;
+    ///
+    /// let tz = TimeZone::get("America/Los_Angeles")?;
+    /// let ts: Timestamp = "2024-07-15T12:00Z".parse()?;
+    ///
+    /// let names = TimeZoneNames::try_new(&locale!("en-US").into())?;
+    /// assert_eq!(names.generic_long(&tz, ts), "Pacific Time");
+    /// let names = TimeZoneNames::try_new(&locale!("fr").into())?;
+    /// assert_eq!(names.generic_long(&tz, ts), "heure du Pacifique nord-américain");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn generic_long(&self, tz: &TimeZone, timestamp: Timestamp) -> String {
+        self.format(&self.generic_long, tz, timestamp)
+    }
+
+    /// Returns the short generic name of the time zone, like `PT`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::{tz::TimeZone, Timestamp};
+    /// use jiff_icu::TimeZoneNames;
+    ///
+    /// let names = TimeZoneNames::try_new(&locale!("en-US").into())?;
+    /// let tz = TimeZone::get("America/Los_Angeles")?;
+    /// let ts: Timestamp = "2024-07-15T12:00Z".parse()?;
+    /// assert_eq!(names.generic_short(&tz, ts), "PT");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn generic_short(
+        &self,
+        tz: &TimeZone,
+        timestamp: Timestamp,
+    ) -> String {
+        self.format(&self.generic_short, tz, timestamp)
+    }
+
+    /// Returns the generic location name of the time zone, like
+    /// `Los Angeles Time`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::{tz::TimeZone, Timestamp};
+    /// use jiff_icu::TimeZoneNames;
+    ///
+    /// let tz = TimeZone::get("Europe/Paris")?;
+    /// let ts: Timestamp = "2024-07-15T12:00Z".parse()?;
+    ///
+    /// let names = TimeZoneNames::try_new(&locale!("en-US").into())?;
+    /// assert_eq!(names.generic_location(&tz, ts), "Paris Time");
+    /// let names = TimeZoneNames::try_new(&locale!("de").into())?;
+    /// assert_eq!(names.generic_location(&tz, ts), "Paris (Ortszeit)");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn generic_location(
+        &self,
+        tz: &TimeZone,
+        timestamp: Timestamp,
+    ) -> String {
+        self.format(&self.generic_location, tz, timestamp)
+    }
+
+    /// Returns the localized name of the city that represents the given time
+    /// zone, like `Tokyo` for `Asia/Tokyo`.
+    ///
+    /// This returns `None` when the time zone has no IANA identifier, or
+    /// when the locale has no name for the city. Callers may want to fall
+    /// back to the last component of the IANA identifier in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::tz::TimeZone;
+    /// use jiff_icu::TimeZoneNames;
+    ///
+    /// let tz = TimeZone::get("Europe/Vienna")?;
+    ///
+    /// let names = TimeZoneNames::try_new(&locale!("en-US").into())?;
+    /// assert_eq!(names.exemplar_city(&tz), Some("Vienna"));
+    /// let names = TimeZoneNames::try_new(&locale!("de").into())?;
+    /// assert_eq!(names.exemplar_city(&tz), Some("Wien"));
+    ///
+    /// assert_eq!(names.exemplar_city(&TimeZone::UTC), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn exemplar_city(&self, tz: &TimeZone) -> Option<&str> {
+        let id = self.zones.to_bcp47(tz)?;
+        self.cities.get().0.get(&id)
+    }
+
+    fn format(
+        &self,
+        formatter: &TimeZoneFormatter,
+        tz: &TimeZone,
+        timestamp: Timestamp,
+    ) -> String {
+        formatter.format_to_string(&self.zones.to_icu(tz, timestamp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use icu::locid::locale;
+
+    use super::*;
+
+    #[test]
+    fn out_of_range_offset() {
+        let names = TimeZoneNames::try_new(&locale!("en-US").into()).unwrap();
+        let tz = TimeZone::fixed(jiff::tz::offset(25));
+        // ICU4X can't represent this offset, so it gets saturated.
+        assert_eq!(
+            names.specific_long(&tz, Timestamp::UNIX_EPOCH),
+            "GMT+18:00",
+        );
+    }
+}
@@ -0,0 +1,340 @@
+use alloc::string::{String, ToString};
+
+use icu_calendar::{DateTime as IcuDateTime, Gregorian};
+use icu_datetime::{
+    options::{
+        components::{self, Bag},
+        preferences::{self, HourCycle},
+    },
+    pattern::hour_cycle::CoarseHourCycle,
+    provider::{calendar::TimeLengthsV1Marker, Baked},
+    TypedDateTimeFormatter,
+};
+use icu_provider::{DataLocale, DataProvider, DataRequest};
+use jiff::civil::DateTime as JiffDateTime;
+
+use crate::{error::err, ConvertFrom, Error};
+
+/// A formatter for Jiff datetimes driven by a [Unicode skeleton].
+///
+/// A skeleton lists the fields that should appear in the output, like
+/// `yMMMd` for a year, an abbreviated month name and a day, without saying
+/// anything about their order or the punctuation between them. The locale
+/// given determines those, using the data compiled into ICU4X. This is
+/// usually what applications want when showing datetimes to end users, since
+/// explicit patterns (like `MMM d, y`) are only correct for some locales.
+///
+/// The following skeleton fields are supported. Whitespace in a skeleton is
+/// ignored, so `yMMMd jm` is the same as `yMMMdjm`.
+///
+/// | Field | Meaning |
+/// | ----- | ------- |
+/// | `G` to `GGGGG` | era, abbreviated, long or narrow |
+/// | `y`, `yy` | year, numeric or two digits |
+/// | `Y`, `YY` | week-based year, numeric or two digits |
+/// | `M` to `MMMMM` (or `L`) | month, numeric, two digits, abbreviated, long or narrow |
+/// | `w`, `ww` | week of year, numeric or two digits |
+/// | `W` | week of month |
+/// | `d`, `dd` | day of month, numeric or two digits |
+/// | `F` | day of week in month |
+/// | `E` to `EEEEE` | weekday, abbreviated, long or narrow |
+/// | `j`, `jj` | hour, using the hour cycle preferred by the locale |
+/// | `h`, `hh` | hour, using a 12-hour clock (1-12) |
+/// | `K`, `KK` | hour, using a 12-hour clock (0-11) |
+/// | `H`, `HH` | hour, using a 24-hour clock (0-23) |
+/// | `k`, `kk` | hour, using a 24-hour clock (1-24) |
+/// | `m`, `mm` | minute, numeric or two digits |
+/// | `s`, `ss` | second, numeric or two digits |
+/// | `S` to `SSSSSSSSS` | fractional seconds, with the given number of digits |
+///
+/// Time zone fields aren't supported. When formatting a [`jiff::Zoned`],
+/// its civil datetime is used.
+///
+/// This type is only available when the `skeleton` crate feature is enabled.
+///
+/// [Unicode skeleton]: https://unicode.org/reports/tr35/tr35-dates.html#availableFormats_appendItems
+///
+/// # Example
+///
+/// ```
+/// use icu::locid::locale;
+/// use jiff::Zoned;
+/// use jiff_icu::SkeletonFormatter;
+///
+/// let zdt: Zoned = "2024-04-01T14:30[Europe/Paris]".parse()?;
+///
+/// let formatter = SkeletonFormatter::try_new(&locale!("en-US").into(), "yMMMd jm")?;
+/// assert_eq!(formatter.format(&zdt), "Apr 1, 2024, 2:30\u{202f}PM");
+///
+/// let formatter = SkeletonFormatter::try_new(&locale!("fr").into(), "yMMMd jm")?;
+/// assert_eq!(formatter.format(&zdt), "1 avr. 2024, 14:30");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct SkeletonFormatter {
+    formatter: TypedDateTimeFormatter<Gregorian>,
+}
+
+impl SkeletonFormatter {
+    /// Creates a formatter for the given skeleton in the given locale.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the skeleton is empty, contains an
+    /// unsupported field or contains the same field more than once. It also
+    /// returns an error when ICU4X doesn't have data for the skeleton and
+    /// locale given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff_icu::SkeletonFormatter;
+    ///
+    /// let locale = locale!("en-US").into();
+    /// assert!(SkeletonFormatter::try_new(&locale, "yMMMd").is_ok());
+    /// assert!(SkeletonFormatter::try_new(&locale, "yMMMd yy").is_err());
+    /// assert!(SkeletonFormatter::try_new(&locale, "Hmz").is_err());
+    /// ```
+    pub fn try_new(
+        locale: &DataLocale,
+        skeleton: &str,
+    ) -> Result<SkeletonFormatter, Error> {
+        let mut bag = parse(skeleton)?;
+        if bag.hour.is_some() && bag.preferences.is_none() {
+            let hour_cycle = preferred_hour_cycle(locale).map_err(|e| {
+                err!("failed to load hour cycle for locale {locale}: {e}")
+            })?;
+            bag.preferences =
+                Some(preferences::Bag::from_hour_cycle(hour_cycle));
+        }
+        let formatter = TypedDateTimeFormatter::try_new_experimental(
+            locale,
+            bag.into(),
+        )
+        .map_err(|e| {
+            err!("failed to create formatter for skeleton {skeleton:?}: {e}")
+        })?;
+        Ok(SkeletonFormatter { formatter })
+    }
+
+    /// Formats the given datetime.
+    ///
+    /// This accepts anything that converts into a [`jiff::civil::DateTime`],
+    /// which includes [`jiff::civil::Date`] (at midnight) and
+    /// [`jiff::Zoned`] (using its civil datetime).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::civil::date;
+    /// use jiff_icu::SkeletonFormatter;
+    ///
+    /// let formatter = SkeletonFormatter::try_new(&locale!("de").into(), "yMMMMEEEEd")?;
+    /// assert_eq!(formatter.format(date(2024, 4, 1)), "Montag, 1. April 2024");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format(&self, datetime: impl Into<JiffDateTime>) -> String {
+        let datetime =
+            IcuDateTime::convert_from(datetime.into()).to_calendar(Gregorian);
+        self.formatter.format(&datetime).to_string()
+    }
+}
+
+/// Returns the hour cycle preferred by the given locale.
+///
+/// This is what the `j` field resolves to. ICU4X doesn't do this on its own
+/// for components, and instead always uses a 24-hour clock.
+fn preferred_hour_cycle(
+    locale: &DataLocale,
+) -> Result<HourCycle, icu_provider::DataError> {
+    let request = DataRequest { locale, metadata: Default::default() };
+    let response: icu_provider::DataResponse<TimeLengthsV1Marker> =
+        Baked.load(request)?;
+    let hour_cycle = match response.take_payload()?.get().preferred_hour_cycle
+    {
+        CoarseHourCycle::H11H12 => HourCycle::H12,
+        CoarseHourCycle::H23H24 => HourCycle::H23,
+    };
+    Ok(hour_cycle)
+}
+
+/// Parses a skeleton into a bag of ICU4X components.
+fn parse(skeleton: &str) -> Result<Bag, Error> {
+    let mut bag = Bag::default();
+    let mut rest = skeleton.as_bytes();
+    let mut empty = true;
+    while let Some(&letter) = rest.first() {
+        if letter.is_ascii_whitespace() {
+            rest = &rest[1..];
+            continue;
+        }
+        if !letter.is_ascii_alphabetic() {
+            // OK because we only ever skip over ASCII bytes, so `rest`
+            // always starts at a character boundary.
+            let offset = skeleton.len() - rest.len();
+            let ch = skeleton[offset..].chars().next().unwrap();
+            return Err(err!(
+                "found unsupported character {ch:?} in skeleton {skeleton:?}",
+            ));
+        }
+        let count = rest.iter().take_while(|&&b| b == letter).count();
+        rest = &rest[count..];
+        empty = false;
+        let duplicate = match field(&mut bag, letter, count) {
+            Some(duplicate) => duplicate,
+            None => {
+                let field = &skeleton[..skeleton.len() - rest.len()];
+                let field = &field[field.len() - count..];
+                return Err(err!(
+                    "found unsupported field `{field}` in skeleton \
+                     {skeleton:?}",
+                ));
+            }
+        };
+        if duplicate {
+            return Err(err!(
+                "found field `{letter}` more than once in skeleton \
+                 {skeleton:?}",
+                letter = char::from(letter),
+            ));
+        }
+    }
+    if empty {
+        return Err(err!("skeleton must contain at least one field"));
+    }
+    Ok(bag)
+}
+
+/// Sets the component corresponding to `count` repetitions of the given
+/// field letter.
+///
+/// This returns `None` when the field isn't supported, and otherwise whether
+/// the component was already set.
+fn field(bag: &mut Bag, letter: u8, count: usize) -> Option<bool> {
+    fn set<T>(slot: &mut Option<T>, value: T) -> bool {
+        slot.replace(value).is_some()
+    }
+
+    fn text(count: usize) -> Option<components::Text> {
+        Some(match count {
+            1..=3 => components::Text::Short,
+            4 => components::Text::Long,
+            5 => components::Text::Narrow,
+            _ => return None,
+        })
+    }
+
+    fn numeric(count: usize) -> Option<components::Numeric> {
+        Some(match count {
+            1 => components::Numeric::Numeric,
+            2 => components::Numeric::TwoDigit,
+            _ => return None,
+        })
+    }
+
+    let duplicate = match letter {
+        b'G' => set(&mut bag.era, text(count)?),
+        b'y' | b'Y' => {
+            let year = match (letter, count) {
+                (b'y', 1) => components::Year::Numeric,
+                (b'y', 2) => components::Year::TwoDigit,
+                (b'Y', 1) => components::Year::NumericWeekOf,
+                (b'Y', 2) => components::Year::TwoDigitWeekOf,
+                _ => return None,
+            };
+            set(&mut bag.year, year)
+        }
+        b'M' | b'L' => {
+            let month = match count {
+                1 => components::Month::Numeric,
+                2 => components::Month::TwoDigit,
+                3 => components::Month::Short,
+                4 => components::Month::Long,
+                5 => components::Month::Narrow,
+                _ => return None,
+            };
+            set(&mut bag.month, month)
+        }
+        b'w' | b'W' => {
+            let week = match (letter, count) {
+                (b'w', 1) => components::Week::NumericWeekOfYear,
+                (b'w', 2) => components::Week::TwoDigitWeekOfYear,
+                (b'W', 1) => components::Week::WeekOfMonth,
+                _ => return None,
+            };
+            set(&mut bag.week, week)
+        }
+        b'd' | b'F' => {
+            let day = match (letter, count) {
+                (b'd', 1) => components::Day::NumericDayOfMonth,
+                (b'd', 2) => components::Day::TwoDigitDayOfMonth,
+                (b'F', 1) => components::Day::DayOfWeekInMonth,
+                _ => return None,
+            };
+            set(&mut bag.day, day)
+        }
+        b'E' => set(&mut bag.weekday, text(count)?),
+        b'j' | b'h' | b'K' | b'H' | b'k' => {
+            let hour_cycle = match letter {
+                b'h' => Some(HourCycle::H12),
+                b'K' => Some(HourCycle::H11),
+                b'H' => Some(HourCycle::H23),
+                b'k' => Some(HourCycle::H24),
+                _ => None,
+            };
+            if let Some(hour_cycle) = hour_cycle {
+                bag.preferences =
+                    Some(preferences::Bag::from_hour_cycle(hour_cycle));
+            }
+            set(&mut bag.hour, numeric(count)?)
+        }
+        b'm' => set(&mut bag.minute, numeric(count)?),
+        b's' => set(&mut bag.second, numeric(count)?),
+        b'S' => {
+            if count > 9 {
+                return None;
+            }
+            // OK because `count` is at most 9.
+            set(&mut bag.fractional_second, u8::try_from(count).unwrap())
+        }
+        _ => return None,
+    };
+    Some(duplicate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn err_parse() {
+        let t = |skeleton: &str| parse(skeleton).unwrap_err().to_string();
+
+        assert_eq!(t(""), "skeleton must contain at least one field");
+        assert_eq!(t("  "), "skeleton must contain at least one field");
+        assert_eq!(
+            t("yMMMd jmz"),
+            r#"found unsupported field `z` in skeleton "yMMMd jmz""#,
+        );
+        assert_eq!(
+            t("yMMMMMMd"),
+            r#"found unsupported field `MMMMMM` in skeleton "yMMMMMMd""#,
+        );
+        assert_eq!(
+            t("yMMMd Hm h"),
+            r#"found field `h` more than once in skeleton "yMMMd Hm h""#,
+        );
+        assert_eq!(
+            t("y-MM-d"),
+            r#"found unsupported character '-' in skeleton "y-MM-d""#,
+        );
+        assert_eq!(
+            t("yMMMdé"),
+            r#"found unsupported character 'é' in skeleton "yMMMdé""#,
+        );
+    }
+}
@@ -0,0 +1,371 @@
+use alloc::string::{String, ToString};
+
+use icu_calendar::{DateTime as IcuDateTime, Gregorian};
+use icu_datetime::{
+    options::length, TypedDateTimeFormatter, TypedZonedDateTimeFormatter,
+};
+use icu_provider::DataLocale;
+use jiff::{
+    civil::{Date as JiffDate, DateTime as JiffDateTime, Time as JiffTime},
+    Zoned,
+};
+
+use crate::{error::err, zone::ZoneMapper, ConvertFrom, Error};
+
+/// The length of a localized date or time.
+///
+/// These correspond to the [CLDR date and time formats]. The exact output
+/// for each style depends on the locale. For example, in the `en-US` locale:
+///
+/// | Style | Date | Time |
+/// | ----- | ---- | ---- |
+/// | `Full` | `Monday, April 1, 2024` | `2:30:05 PM Eastern Daylight Time` |
+/// | `Long` | `April 1, 2024` | `2:30:05 PM EDT` |
+/// | `Medium` | `Apr 1, 2024` | `2:30:05 PM` |
+/// | `Short` | `4/1/24` | `2:30 PM` |
+///
+/// Note that the `Full` and `Long` time styles include the time zone, and
+/// so can only be used to format a [`jiff::Zoned`].
+///
+/// This type is only available when the `style` crate feature is enabled.
+///
+/// [CLDR date and time formats]: https://unicode.org/reports/tr35/tr35-dates.html#dateFormats
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Style {
+    /// The most detailed style, e.g., `Monday, April 1, 2024`.
+    Full,
+    /// A long style, e.g., `April 1, 2024`.
+    Long,
+    /// A medium style, e.g., `Apr 1, 2024`.
+    Medium,
+    /// The most compact style, e.g., `4/1/24`.
+    Short,
+}
+
+impl Style {
+    fn to_date_length(self) -> length::Date {
+        match self {
+            Style::Full => length::Date::Full,
+            Style::Long => length::Date::Long,
+            Style::Medium => length::Date::Medium,
+            Style::Short => length::Date::Short,
+        }
+    }
+
+    fn to_time_length(self) -> length::Time {
+        match self {
+            Style::Full => length::Time::Full,
+            Style::Long => length::Time::Long,
+            Style::Medium => length::Time::Medium,
+            Style::Short => length::Time::Short,
+        }
+    }
+
+    /// Returns true when this style, used for a time, shows a time zone.
+    fn has_time_zone(self) -> bool {
+        matches!(self, Style::Full | Style::Long)
+    }
+}
+
+/// A formatter for Jiff datetimes using one of the classic localized
+/// [`Style`]s for the date, the time or both.
+///
+/// This is meant for showing datetimes to end users in user interfaces. The
+/// order of the fields, the punctuation and the names of months, weekdays and
+/// time zones all come from the locale data compiled into ICU4X.
+///
+/// When formatting a [`jiff::Zoned`] with the `Full` or `Long` time style, the
+/// name of its time zone is looked up by its IANA identifier (and whether
+/// daylight saving time is in effect). If no name is known, then a name based
+/// on its offset (like `GMT-04:00`) is used instead.
+///
+/// This type is only available when the `style` crate feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// use icu::locid::locale;
+/// use jiff::Zoned;
+/// use jiff_icu::{Style, StyleFormatter};
+///
+/// let zdt: Zoned = "2024-04-01T14:30:05[America/New_York]".parse()?;
+///
+/// let formatter = StyleFormatter::try_new(
+///     &locale!("en-US").into(),
+///     Some(Style::Full),
+///     Some(Style::Full),
+/// )?;
+/// assert_eq!(
+///     formatter.format_zoned(&zdt),
+///     "Monday, April 1, 2024, 2:30:05\u{202f}PM Eastern Daylight Time",
+/// );
+///
+/// let formatter = StyleFormatter::try_new(
+///     &locale!("fr").into(),
+///     Some(Style::Medium),
+///     Some(Style::Short),
+/// )?;
+/// assert_eq!(formatter.format_zoned(&zdt), "1 avr. 2024, 14:30");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct StyleFormatter {
+    date: Option<Style>,
+    time: Option<Style>,
+    zoned: TypedZonedDateTimeFormatter<Gregorian>,
+    /// A formatter for civil datetimes. This is only present when the time
+    /// style doesn't require a time zone.
+    civil: Option<TypedDateTimeFormatter<Gregorian>>,
+    zones: ZoneMapper,
+}
+
+impl StyleFormatter {
+    /// Creates a formatter using the given date and time styles in the given
+    /// locale.
+    ///
+    /// When only a date style is given, only dates are formatted, and
+    /// similarly for times.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if neither a date style nor a time style is
+    /// given, or when ICU4X doesn't have data for the locale given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff_icu::{Style, StyleFormatter};
+    ///
+    /// let locale = locale!("en-US").into();
+    /// assert!(StyleFormatter::try_new(&locale, Some(Style::Short), None).is_ok());
+    /// assert!(StyleFormatter::try_new(&locale, None, None).is_err());
+    /// ```
+    pub fn try_new(
+        locale: &DataLocale,
+        date: Option<Style>,
+        time: Option<Style>,
+    ) -> Result<StyleFormatter, Error> {
+        let bag = match (date, time) {
+            (None, None) => {
+                return Err(err!(
+                    "style formatter requires a date style, \
+                     a time style or both",
+                ))
+            }
+            (Some(date), None) => {
+                length::Bag::from_date_style(date.to_date_length())
+            }
+            (None, Some(time)) => {
+                length::Bag::from_time_style(time.to_time_length())
+            }
+            (Some(date), Some(time)) => length::Bag::from_date_time_style(
+                date.to_date_length(),
+                time.to_time_length(),
+            ),
+        };
+        let fail = |e: icu_datetime::DateTimeError| {
+            err!("failed to create style formatter for locale {locale}: {e}")
+        };
+        let zoned = TypedZonedDateTimeFormatter::try_new(
+            locale,
+            bag.into(),
+            Default::default(),
+        )
+        .map_err(fail)?;
+        let civil = if time.is_some_and(Style::has_time_zone) {
+            None
+        } else {
+            Some(
+                TypedDateTimeFormatter::try_new(locale, bag.into())
+                    .map_err(fail)?,
+            )
+        };
+        Ok(StyleFormatter {
+            date,
+            time,
+            zoned,
+            civil,
+            zones: ZoneMapper::new(),
+        })
+    }
+
+    /// Formats the given zoned datetime.
+    ///
+    /// This never fails, since a zoned datetime has everything that any
+    /// combination of styles requires.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::Zoned;
+    /// use jiff_icu::{Style, StyleFormatter};
+    ///
+    /// let formatter = StyleFormatter::try_new(
+    ///     &locale!("en-GB").into(),
+    ///     None,
+    ///     Some(Style::Long),
+    /// )?;
+    ///
+    /// let zdt: Zoned = "2024-07-01T09:15[Europe/London]".parse()?;
+    /// assert_eq!(formatter.format_zoned(&zdt), "09:15:00 BST");
+    /// let zdt: Zoned = "2024-07-01T09:15-04[America/Santiago]".parse()?;
+    /// assert_eq!(formatter.format_zoned(&zdt), "09:15:00 GMT-04:00");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format_zoned(&self, zdt: &Zoned) -> String {
+        let tz = self.zones.to_icu(zdt.time_zone(), zdt.timestamp());
+        let datetime =
+            IcuDateTime::convert_from(zdt.datetime()).to_calendar(Gregorian);
+        self.zoned.format(&datetime, &tz).to_string()
+    }
+
+    /// Formats the given civil datetime.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if this formatter uses the `Full` or `Long` time
+    /// style, since those require a time zone. Use
+    /// [`StyleFormatter::format_zoned`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::civil::date;
+    /// use jiff_icu::{Style, StyleFormatter};
+    ///
+    /// let formatter = StyleFormatter::try_new(
+    ///     &locale!("de").into(),
+    ///     Some(Style::Long),
+    ///     Some(Style::Short),
+    /// )?;
+    /// let dt = date(2024, 4, 1).at(14, 30, 0, 0);
+    /// assert_eq!(formatter.format_datetime(dt)?, "1. April 2024, 14:30");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format_datetime(&self, dt: JiffDateTime) -> Result<String, Error> {
+        let Some(ref civil) = self.civil else {
+            return Err(err!(
+                "cannot format civil datetime {dt} with the {style:?} time \
+                 style since it requires a time zone",
+                // OK because `civil` is only absent when there is a time
+                // style.
+                style = self.time.unwrap(),
+            ));
+        };
+        let datetime = IcuDateTime::convert_from(dt).to_calendar(Gregorian);
+        Ok(civil.format(&datetime).to_string())
+    }
+
+    /// Formats the given civil date.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if this formatter has a time style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::civil::date;
+    /// use jiff_icu::{Style, StyleFormatter};
+    ///
+    /// let formatter = StyleFormatter::try_new(
+    ///     &locale!("en-US").into(),
+    ///     Some(Style::Short),
+    ///     None,
+    /// )?;
+    /// assert_eq!(formatter.format_date(date(2024, 4, 1))?, "4/1/24");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format_date(&self, date: JiffDate) -> Result<String, Error> {
+        if let Some(style) = self.time {
+            return Err(err!(
+                "cannot format civil date {date} since the formatter \
+                 has a {style:?} time style",
+            ));
+        }
+        self.format_datetime(date.to_datetime(JiffTime::midnight()))
+    }
+
+    /// Formats the given civil time.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if this formatter has a date style, or if it
+    /// uses the `Full` or `Long` time style, since those require a time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use jiff::civil::time;
+    /// use jiff_icu::{Style, StyleFormatter};
+    ///
+    /// let formatter = StyleFormatter::try_new(
+    ///     &locale!("en-US").into(),
+    ///     None,
+    ///     Some(Style::Medium),
+    /// )?;
+    /// assert_eq!(formatter.format_time(time(14, 30, 5, 0))?, "2:30:05\u{202f}PM");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format_time(&self, time: JiffTime) -> Result<String, Error> {
+        if let Some(style) = self.date {
+            return Err(err!(
+                "cannot format civil time {time} since the formatter \
+                 has a {style:?} date style",
+            ));
+        }
+        // The date is ignored since there is no date style.
+        self.format_datetime(JiffDate::constant(1970, 1, 1).to_datetime(time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::{date, time};
+
+    use super::*;
+
+    #[test]
+    fn err_civil_mismatch() {
+        let locale = icu_provider::DataLocale::default();
+        let f = |date: Option<Style>, time: Option<Style>| {
+            StyleFormatter::try_new(&locale, date, time).unwrap()
+        };
+        let dt = date(2024, 4, 1).at(14, 30, 0, 0);
+
+        assert_eq!(
+            f(None, Some(Style::Long))
+                .format_datetime(dt)
+                .unwrap_err()
+                .to_string(),
+            "cannot format civil datetime 2024-04-01T14:30:00 with the Long \
+             time style since it requires a time zone",
+        );
+        assert_eq!(
+            f(Some(Style::Short), Some(Style::Short))
+                .format_date(dt.date())
+                .unwrap_err()
+                .to_string(),
+            "cannot format civil date 2024-04-01 since the formatter has a \
+             Short time style",
+        );
+        assert_eq!(
+            f(Some(Style::Short), None)
+                .format_time(time(14, 30, 0, 0))
+                .unwrap_err()
+                .to_string(),
+            "cannot format civil time 14:30:00 since the formatter has a \
+             Short date style",
+        );
+    }
+}
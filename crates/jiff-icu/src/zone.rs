@@ -0,0 +1,95 @@
+use icu_calendar::{DateTime as IcuDateTime, Iso};
+use icu_timezone::{
+    CustomTimeZone, GmtOffset, MetazoneCalculator, TimeZoneIdMapper,
+    ZoneVariant,
+};
+use jiff::{tz::TimeZone, Timestamp};
+
+use crate::ConvertFrom;
+
+/// Converts Jiff time zones into ICU4X time zones, which is what ICU4X needs
+/// in order to look up localized time zone names.
+#[derive(Debug)]
+pub(crate) struct ZoneMapper {
+    ids: TimeZoneIdMapper,
+    metazones: MetazoneCalculator,
+}
+
+impl ZoneMapper {
+    /// Creates a new mapper using the data compiled into ICU4X.
+    pub(crate) fn new() -> ZoneMapper {
+        ZoneMapper {
+            ids: TimeZoneIdMapper::new(),
+            metazones: MetazoneCalculator::new(),
+        }
+    }
+
+    /// Creates a new mapper using the data provider given.
+    pub(crate) fn try_new_unstable<P>(
+        provider: &P,
+    ) -> Result<ZoneMapper, icu_provider::DataError>
+    where
+        P: icu_provider::DataProvider<
+                icu_timezone::provider::names::IanaToBcp47MapV2Marker,
+            > + icu_provider::DataProvider<
+                icu_timezone::provider::MetazonePeriodV1Marker,
+            > + ?Sized,
+    {
+        let ids = TimeZoneIdMapper::try_new_unstable(provider)?;
+        // The only error that can occur here is a data error.
+        let metazones = MetazoneCalculator::try_new_unstable(provider)
+            .map_err(|e| match e {
+                icu_timezone::TimeZoneError::Data(e) => e,
+                _ => icu_provider::DataError::custom(
+                    "failed to load metazone data",
+                ),
+            })?;
+        Ok(ZoneMapper { ids, metazones })
+    }
+
+    /// Returns the ICU4X time zone corresponding to the given Jiff time zone
+    /// at the given instant.
+    ///
+    /// Time zones without an IANA identifier (like fixed offsets) only get
+    /// an offset, which ICU4X uses to produce a name like `GMT-04:00`.
+    pub(crate) fn to_icu(
+        &self,
+        tz: &TimeZone,
+        timestamp: Timestamp,
+    ) -> CustomTimeZone {
+        let info = tz.to_offset_info(timestamp);
+        // Jiff supports offsets up to 25 hours, but ICU4X only supports up
+        // to 18 hours. No real time zone has offsets that big, and ICU4X
+        // can't format a time zone without an offset, so we saturate.
+        let seconds = info.offset().seconds().clamp(-18 * 3600, 18 * 3600);
+        let offset = GmtOffset::try_from_offset_seconds(seconds)
+            .expect("offset is clamped to ICU4X's supported range");
+        let mut icu = CustomTimeZone {
+            gmt_offset: Some(offset),
+            time_zone_id: None,
+            metazone_id: None,
+            zone_variant: None,
+        };
+        if let Some(name) = tz.iana_name() {
+            let datetime = IcuDateTime::<Iso>::convert_from(
+                info.offset().to_datetime(timestamp),
+            );
+            icu.time_zone_id = self.ids.as_borrowed().iana_to_bcp47(name);
+            icu.maybe_calculate_metazone(&self.metazones, &datetime);
+            icu.zone_variant = Some(if info.dst().is_dst() {
+                ZoneVariant::daylight()
+            } else {
+                ZoneVariant::standard()
+            });
+        }
+        icu
+    }
+
+    /// Returns the BCP-47 identifier of the given time zone, if it has one.
+    pub(crate) fn to_bcp47(
+        &self,
+        tz: &TimeZone,
+    ) -> Option<icu_timezone::TimeZoneBcp47Id> {
+        self.ids.as_borrowed().iana_to_bcp47(tz.iana_name()?)
+    }
+}
@@ -0,0 +1,83 @@
+/*!
+This crate provides integration points for [Jiff](jiff) and
+[`postgres-types`](postgres_types).
+
+`postgres-types` is the crate that defines the [`ToSql`](postgres_types::ToSql)
+and [`FromSql`](postgres_types::FromSql) traits used by both
+[`tokio-postgres`] and [`postgres`].
+
+# Organization
+
+This crates defines several types that wrap corresponding types in Jiff. Each
+wrapper type provides implementations of the [`postgres_types::ToSql`] and
+[`postgres_types::FromSql`] traits. The wrapper types own the logic for
+encoding and decoding the data in PostgreSQL's binary wire format.
+
+In order to the minimize the annoyance of wrapper types, the following
+conveniences are afforded:
+
+* A [`ToPostgres`] trait is provided. Several Jiff types implement this trait.
+  The trait provides easy conversion to the corresponding wrapper type in this
+  crate.
+* A concrete `to_jiff` method is provided on each wrapper type. For example,
+  [`Timestamp::to_jiff`]. This method is the reverse of `ToPostgres`. This
+  converts from the wrapper type to the corresponding Jiff type.
+* There are `From` trait implementations from the wrapper type to the
+  corresponding Jiff type, and vice versa.
+
+Since `postgres-types` already provides trait implementations for `Option<T>`,
+no additional wrapper types are needed for nullable values.
+
+# Type mapping
+
+| Jiff type                  | Wrapper type         | PostgreSQL type |
+| -------------------------- | -------------------- | --------------- |
+| [`jiff::Timestamp`]        | [`Timestamp`]        | `timestamptz`   |
+| [`jiff::civil::DateTime`]  | [`DateTime`]         | `timestamp`     |
+| [`jiff::civil::Date`]      | [`Date`]             | `date`          |
+| [`jiff::civil::Time`]      | [`Time`]             | `time`          |
+| [`jiff::Span`]             | [`Span`]             | `interval`      |
+| [`jiff::SignedDuration`]   | [`SignedDuration`]   | `interval`      |
+
+PostgreSQL stores datetimes and durations with microsecond precision. When
+encoding, any nanoseconds beyond microsecond precision are truncated.
+PostgreSQL's special `infinity` and `-infinity` values cannot be represented
+by Jiff's types and result in an error when decoding.
+
+Note that there is intentionally no wrapper for [`jiff::Zoned`]. A PostgreSQL
+`timestamptz` only stores an instant, so storing a `Zoned` in one would
+silently drop its time zone. Use [`jiff::Zoned::timestamp`] with the
+[`Timestamp`] wrapper instead, and attach a time zone after decoding.
+
+# Example
+
+This shows a round trip of a [`jiff::civil::DateTime`] through PostgreSQL's
+binary representation of a `timestamp`:
+
+```
+use bytes::BytesMut;
+use jiff::civil::date;
+use jiff_postgres::{DateTime, ToPostgres};
+use postgres_types::{FromSql, ToSql, Type};
+
+let dt = date(2025, 2, 20).at(17, 30, 0, 123_456_000);
+let mut buf = BytesMut::new();
+dt.to_postgres().to_sql(&Type::TIMESTAMP, &mut buf)?;
+let got = DateTime::from_sql(&Type::TIMESTAMP, &buf)?.to_jiff();
+assert_eq!(got, dt);
+
+# Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+```
+
+[`tokio-postgres`]: https://docs.rs/tokio-postgres
+[`postgres`]: https://docs.rs/postgres
+*/
+
+#![deny(missing_docs)]
+
+pub use self::wrappers::{
+    Date, DateTime, SignedDuration, Span, Time, Timestamp, ToPostgres,
+};
+
+mod postgres;
+mod wrappers;
@@ -0,0 +1,259 @@
+use std::error::Error;
+
+use bytes::{BufMut, BytesMut};
+use jiff::civil;
+use postgres_protocol::types as proto;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{
+    Date, DateTime, SignedDuration, Span, Time, Timestamp, ToPostgres,
+};
+
+type BoxDynError = Box<dyn Error + Sync + Send>;
+
+/// PostgreSQL's binary format for dates and times is relative to
+/// 2000-01-01 instead of the Unix epoch. Timestamps (both with and without
+/// a time zone) are encoded as the number of microseconds since this epoch,
+/// and dates are encoded as the number of days since this epoch.
+static POSTGRES_EPOCH_DATE: civil::Date = civil::date(2000, 1, 1);
+static POSTGRES_EPOCH_DATETIME: civil::DateTime =
+    civil::date(2000, 1, 1).at(0, 0, 0, 0);
+static POSTGRES_EPOCH_TIMESTAMP: i64 = 946684800;
+static MIDNIGHT: civil::Time = civil::Time::midnight();
+static MICROS_PER_DAY: i64 = 86_400_000_000;
+
+impl ToSql for Timestamp {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, BoxDynError> {
+        // OK because the timestamp is known to be valid and in range.
+        let epoch =
+            jiff::Timestamp::from_second(POSTGRES_EPOCH_TIMESTAMP).unwrap();
+        let micros = self.to_jiff().duration_since(epoch).as_micros();
+        // OK because the maximum duration between two Jiff timestamps
+        // is well under i64::MAX microseconds.
+        let micros = i64::try_from(micros).unwrap();
+        proto::timestamp_to_sql(micros, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(TIMESTAMPTZ);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Timestamp {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Timestamp, BoxDynError> {
+        let micros = proto::timestamp_from_sql(raw)?;
+        let micros = jiff::SignedDuration::from_micros(micros);
+        // OK because the timestamp is known to be valid and in range.
+        let epoch =
+            jiff::Timestamp::from_second(POSTGRES_EPOCH_TIMESTAMP).unwrap();
+        Ok(epoch.checked_add(micros)?.to_postgres())
+    }
+
+    accepts!(TIMESTAMPTZ);
+}
+
+impl ToSql for DateTime {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, BoxDynError> {
+        let micros =
+            self.to_jiff().duration_since(POSTGRES_EPOCH_DATETIME).as_micros();
+        // OK because the maximum duration between two Jiff civil datetimes
+        // is 631,107,417,599,999,999, which is less than i64::MAX.
+        let micros = i64::try_from(micros).unwrap();
+        proto::timestamp_to_sql(micros, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(TIMESTAMP);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for DateTime {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<DateTime, BoxDynError> {
+        let micros = proto::timestamp_from_sql(raw)?;
+        let micros = jiff::SignedDuration::from_micros(micros);
+        Ok(POSTGRES_EPOCH_DATETIME.checked_add(micros)?.to_postgres())
+    }
+
+    accepts!(TIMESTAMP);
+}
+
+impl ToSql for Date {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, BoxDynError> {
+        let days = (self.to_jiff() - POSTGRES_EPOCH_DATE).get_days();
+        proto::date_to_sql(days, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(DATE);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Date {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Date, BoxDynError> {
+        let days = proto::date_from_sql(raw)?;
+        let span = jiff::Span::new().try_days(days)?;
+        Ok(POSTGRES_EPOCH_DATE.checked_add(span)?.to_postgres())
+    }
+
+    accepts!(DATE);
+}
+
+impl ToSql for Time {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, BoxDynError> {
+        // The encoding is the number of *microseconds* since midnight.
+        let micros = self.to_jiff().duration_since(MIDNIGHT).as_micros();
+        // OK since the max number of microseconds here is
+        // 86399999999, which always fits into an `i64`.
+        let micros = i64::try_from(micros).unwrap();
+        proto::time_to_sql(micros, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(TIME);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Time {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Time, BoxDynError> {
+        let micros = proto::time_from_sql(raw)?;
+        let micros = jiff::SignedDuration::from_micros(micros);
+        Ok(MIDNIGHT.checked_add(micros)?.to_postgres())
+    }
+
+    accepts!(TIME);
+}
+
+impl ToSql for Span {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, BoxDynError> {
+        let span = self.to_jiff();
+        // None of these can overflow since the limits on each unit in a
+        // `Span` are small enough. For example, the maximum number of
+        // microseconds from all time units combined is less than 4e18.
+        let months = i32::from(span.get_years()) * 12 + span.get_months();
+        let days = span.get_weeks() * 7 + span.get_days();
+        let micros = i64::from(span.get_hours()) * 3_600_000_000
+            + span.get_minutes() * 60_000_000
+            + span.get_seconds() * 1_000_000
+            + span.get_milliseconds() * 1_000
+            + span.get_microseconds()
+            + span.get_nanoseconds() / 1_000;
+        interval_to_sql(Interval { months, days, micros }, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(INTERVAL);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Span {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Span, BoxDynError> {
+        let interval = interval_from_sql(raw)?;
+        let span = jiff::Span::new()
+            .try_months(interval.months)?
+            .try_days(interval.days)?
+            .try_microseconds(interval.micros)?;
+        Ok(span.to_postgres())
+    }
+
+    accepts!(INTERVAL);
+}
+
+impl ToSql for SignedDuration {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, BoxDynError> {
+        // A `SignedDuration` is an exact duration, so we only ever use the
+        // microseconds component of an interval.
+        let micros =
+            i64::try_from(self.to_jiff().as_micros()).map_err(|_| {
+                "signed duration is too big to fit into a PostgreSQL interval"
+            })?;
+        interval_to_sql(Interval { months: 0, days: 0, micros }, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(INTERVAL);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for SignedDuration {
+    fn from_sql(
+        _: &Type,
+        raw: &'a [u8],
+    ) -> Result<SignedDuration, BoxDynError> {
+        let interval = interval_from_sql(raw)?;
+        if interval.months != 0 {
+            return Err("cannot decode PostgreSQL interval with non-zero \
+                        months into a signed duration"
+                .into());
+        }
+        // Days are always 24 hours, which is consistent with how
+        // `SignedDuration` treats days elsewhere in Jiff.
+        let days = jiff::SignedDuration::from_micros(
+            i64::from(interval.days) * MICROS_PER_DAY,
+        );
+        let micros = jiff::SignedDuration::from_micros(interval.micros);
+        let sdur = days
+            .checked_add(micros)
+            .ok_or("PostgreSQL interval overflows a signed duration")?;
+        Ok(sdur.to_postgres())
+    }
+
+    accepts!(INTERVAL);
+}
+
+/// The components of a PostgreSQL interval.
+///
+/// `postgres-protocol` doesn't provide helpers for intervals, so we handle
+/// the binary format ourselves.
+struct Interval {
+    months: i32,
+    days: i32,
+    micros: i64,
+}
+
+/// Writes an interval in PostgreSQL's binary format.
+///
+/// The format is the microseconds component as a big endian `i64`, followed
+/// by the days component as a big endian `i32`, followed by the months
+/// component as a big endian `i32`.
+fn interval_to_sql(interval: Interval, out: &mut BytesMut) {
+    out.put_i64(interval.micros);
+    out.put_i32(interval.days);
+    out.put_i32(interval.months);
+}
+
+/// Reads an interval in PostgreSQL's binary format.
+///
+/// See `interval_to_sql` for a description of the format.
+fn interval_from_sql(raw: &[u8]) -> Result<Interval, BoxDynError> {
+    let raw: &[u8; 16] = raw
+        .try_into()
+        .map_err(|_| "invalid message length: interval size mismatch")?;
+    let micros = i64::from_be_bytes(raw[0..8].try_into().unwrap());
+    let days = i32::from_be_bytes(raw[8..12].try_into().unwrap());
+    let months = i32::from_be_bytes(raw[12..16].try_into().unwrap());
+    Ok(Interval { months, days, micros })
+}
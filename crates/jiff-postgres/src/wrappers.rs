@@ -0,0 +1,221 @@
+/// A trait for convenient conversions from Jiff types to `postgres-types`
+/// wrapper types.
+///
+/// # Example
+///
+/// This shows how to convert a [`jiff::Timestamp`] to a [`Timestamp`]:
+///
+/// ```
+/// use jiff_postgres::ToPostgres;
+///
+/// let ts: jiff::Timestamp = "2025-02-20T17:00-05".parse()?;
+/// let wrapper = ts.to_postgres();
+/// assert_eq!(format!("{wrapper:?}"), "Timestamp(2025-02-20T22:00:00Z)");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait ToPostgres {
+    /// The wrapper type to convert to.
+    type Target;
+
+    /// A conversion method that converts a Jiff type to a `postgres-types`
+    /// wrapper type.
+    fn to_postgres(self) -> Self::Target;
+}
+
+/// A wrapper type for [`jiff::Timestamp`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Timestamp(jiff::Timestamp);
+
+impl Timestamp {
+    /// Converts this wrapper to a [`jiff::Timestamp`].
+    pub fn to_jiff(self) -> jiff::Timestamp {
+        self.0
+    }
+}
+
+impl ToPostgres for jiff::Timestamp {
+    type Target = Timestamp;
+
+    fn to_postgres(self) -> Timestamp {
+        Timestamp(self)
+    }
+}
+
+impl From<jiff::Timestamp> for Timestamp {
+    fn from(x: jiff::Timestamp) -> Timestamp {
+        Timestamp(x)
+    }
+}
+
+impl From<Timestamp> for jiff::Timestamp {
+    fn from(x: Timestamp) -> jiff::Timestamp {
+        x.0
+    }
+}
+
+/// A wrapper type for [`jiff::civil::DateTime`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct DateTime(jiff::civil::DateTime);
+
+impl DateTime {
+    /// Converts this wrapper to a [`jiff::civil::DateTime`].
+    pub fn to_jiff(self) -> jiff::civil::DateTime {
+        self.0
+    }
+}
+
+impl ToPostgres for jiff::civil::DateTime {
+    type Target = DateTime;
+
+    fn to_postgres(self) -> DateTime {
+        DateTime(self)
+    }
+}
+
+impl From<jiff::civil::DateTime> for DateTime {
+    fn from(x: jiff::civil::DateTime) -> DateTime {
+        DateTime(x)
+    }
+}
+
+impl From<DateTime> for jiff::civil::DateTime {
+    fn from(x: DateTime) -> jiff::civil::DateTime {
+        x.0
+    }
+}
+
+/// A wrapper type for [`jiff::civil::Date`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Date(jiff::civil::Date);
+
+impl Date {
+    /// Converts this wrapper to a [`jiff::civil::Date`].
+    pub fn to_jiff(self) -> jiff::civil::Date {
+        self.0
+    }
+}
+
+impl ToPostgres for jiff::civil::Date {
+    type Target = Date;
+
+    fn to_postgres(self) -> Date {
+        Date(self)
+    }
+}
+
+impl From<jiff::civil::Date> for Date {
+    fn from(x: jiff::civil::Date) -> Date {
+        Date(x)
+    }
+}
+
+impl From<Date> for jiff::civil::Date {
+    fn from(x: Date) -> jiff::civil::Date {
+        x.0
+    }
+}
+
+/// A wrapper type for [`jiff::civil::Time`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Time(jiff::civil::Time);
+
+impl Time {
+    /// Converts this wrapper to a [`jiff::civil::Time`].
+    pub fn to_jiff(self) -> jiff::civil::Time {
+        self.0
+    }
+}
+
+impl ToPostgres for jiff::civil::Time {
+    type Target = Time;
+
+    fn to_postgres(self) -> Time {
+        Time(self)
+    }
+}
+
+impl From<jiff::civil::Time> for Time {
+    fn from(x: jiff::civil::Time) -> Time {
+        Time(x)
+    }
+}
+
+impl From<Time> for jiff::civil::Time {
+    fn from(x: Time) -> jiff::civil::Time {
+        x.0
+    }
+}
+
+/// A wrapper type for [`jiff::Span`].
+///
+/// A PostgreSQL interval is made up of months, days and microseconds. When
+/// encoding a `Span`, years and months are folded into the months component,
+/// weeks and days are folded into the days component and all time units are
+/// folded into the microseconds component. Nothing is balanced between these
+/// three components, since doing so would require a relative datetime.
+#[derive(Clone, Copy, Debug)]
+pub struct Span(jiff::Span);
+
+impl Span {
+    /// Converts this wrapper to a [`jiff::Span`].
+    pub fn to_jiff(self) -> jiff::Span {
+        self.0
+    }
+}
+
+impl ToPostgres for jiff::Span {
+    type Target = Span;
+
+    fn to_postgres(self) -> Span {
+        Span(self)
+    }
+}
+
+impl From<jiff::Span> for Span {
+    fn from(x: jiff::Span) -> Span {
+        Span(x)
+    }
+}
+
+impl From<Span> for jiff::Span {
+    fn from(x: Span) -> jiff::Span {
+        x.0
+    }
+}
+
+/// A wrapper type for [`jiff::SignedDuration`].
+///
+/// When decoding a PostgreSQL interval into a `SignedDuration`, the days
+/// component of the interval is interpreted as 24 hours. Intervals with a
+/// non-zero months component cannot be decoded into a `SignedDuration`,
+/// since the length of a month is not fixed. Use [`Span`] in that case.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct SignedDuration(jiff::SignedDuration);
+
+impl SignedDuration {
+    /// Converts this wrapper to a [`jiff::SignedDuration`].
+    pub fn to_jiff(self) -> jiff::SignedDuration {
+        self.0
+    }
+}
+
+impl ToPostgres for jiff::SignedDuration {
+    type Target = SignedDuration;
+
+    fn to_postgres(self) -> SignedDuration {
+        SignedDuration(self)
+    }
+}
+
+impl From<jiff::SignedDuration> for SignedDuration {
+    fn from(x: jiff::SignedDuration) -> SignedDuration {
+        SignedDuration(x)
+    }
+}
+
+impl From<SignedDuration> for jiff::SignedDuration {
+    fn from(x: SignedDuration) -> jiff::SignedDuration {
+        x.0
+    }
+}
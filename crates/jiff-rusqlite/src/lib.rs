@@ -0,0 +1,91 @@
+/*!
+This crate provides integration points for [Jiff](jiff) and
+[rusqlite](rusqlite).
+
+# Organization
+
+This crates defines several types that wrap corresponding types in Jiff. Each
+wrapper type provides implementations of the [`rusqlite::types::ToSql`] and
+[`rusqlite::types::FromSql`] traits.
+
+In order to the minimize the annoyance of wrapper types, the following
+conveniences are afforded:
+
+* A [`ToRusqlite`] trait is provided. Several Jiff types implement this trait.
+  The trait provides easy conversion to the corresponding wrapper type in this
+  crate.
+* A concrete `to_jiff` method is provided on each wrapper type. For example,
+  [`Timestamp::to_jiff`]. This method is the reverse of `ToRusqlite`. This
+  converts from the wrapper type to the corresponding Jiff type.
+* There are `From` trait implementations from the wrapper type to the
+  corresponding Jiff type, and vice versa.
+
+Since rusqlite already provides trait implementations for `Option<T>`, no
+additional wrapper types are needed for nullable values.
+
+# Storage formats
+
+SQLite doesn't have dedicated datetime types. Instead, this crate uses the
+following storage formats:
+
+| Jiff type                 | Wrapper type             | SQLite storage               |
+| ------------------------- | ------------------------ | ---------------------------- |
+| [`jiff::Timestamp`]       | [`Timestamp`]            | `TEXT` (RFC 3339, in UTC)    |
+| [`jiff::Timestamp`]       | [`TimestampMillisecond`] | `INTEGER` (Unix millis)      |
+| [`jiff::civil::DateTime`] | [`DateTime`]             | `TEXT` (ISO 8601)            |
+| [`jiff::civil::Date`]     | [`Date`]                 | `TEXT` (ISO 8601)            |
+| [`jiff::civil::Time`]     | [`Time`]                 | `TEXT` (ISO 8601)            |
+
+The `TEXT` formats are compatible with SQLite's own
+[date and time functions][sqlite-datetime]. Both timestamp wrappers accept
+either storage format when decoding, so the choice only affects how
+timestamps are written.
+
+# Example
+
+This shows how to write and read back timestamps and civil dates:
+
+```
+use jiff_rusqlite::{Date, Timestamp, TimestampMillisecond, ToRusqlite};
+use rusqlite::Connection;
+
+let conn = Connection::open_in_memory()?;
+conn.execute("CREATE TABLE events (at TEXT, at_ms INTEGER, day TEXT)", ())?;
+
+let ts: jiff::Timestamp = "2025-02-20T17:00:00.123-05".parse()?;
+conn.execute(
+    "INSERT INTO events (at, at_ms, day) VALUES (?1, ?2, ?3)",
+    (
+        ts.to_rusqlite(),
+        TimestampMillisecond::from(ts),
+        jiff::civil::date(2025, 2, 20).to_rusqlite(),
+    ),
+)?;
+
+let (at, at_ms, day): (String, i64, Date) = conn.query_row(
+    "SELECT at, at_ms, day FROM events",
+    (),
+    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+)?;
+assert_eq!(at, "2025-02-20T22:00:00.123Z");
+assert_eq!(at_ms, 1740088800123);
+assert_eq!(day.to_jiff(), jiff::civil::date(2025, 2, 20));
+
+let got: Timestamp =
+    conn.query_row("SELECT at_ms FROM events", (), |row| row.get(0))?;
+assert_eq!(got.to_jiff(), ts);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+[sqlite-datetime]: https://www.sqlite.org/lang_datefunc.html
+*/
+
+#![deny(missing_docs)]
+
+pub use self::wrappers::{
+    Date, DateTime, Time, Timestamp, TimestampMillisecond, ToRusqlite,
+};
+
+mod sqlite;
+mod wrappers;
@@ -0,0 +1,92 @@
+use jiff::fmt::temporal::DateTimeParser;
+use rusqlite::types::{
+    FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef,
+};
+
+use crate::{
+    Date, DateTime, Time, Timestamp, TimestampMillisecond, ToRusqlite,
+};
+
+static PARSER: DateTimeParser = DateTimeParser::new();
+
+impl ToSql for Timestamp {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_jiff().to_string()))
+    }
+}
+
+impl FromSql for Timestamp {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        decode_timestamp(value).map(ToRusqlite::to_rusqlite)
+    }
+}
+
+impl ToSql for TimestampMillisecond {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_jiff().as_millisecond()))
+    }
+}
+
+impl FromSql for TimestampMillisecond {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        decode_timestamp(value).map(TimestampMillisecond::from)
+    }
+}
+
+impl ToSql for DateTime {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_jiff().to_string()))
+    }
+}
+
+impl FromSql for DateTime {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let dt = PARSER.parse_datetime(value.as_bytes()?).map_err(other)?;
+        Ok(dt.to_rusqlite())
+    }
+}
+
+impl ToSql for Date {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_jiff().to_string()))
+    }
+}
+
+impl FromSql for Date {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let date = PARSER.parse_date(value.as_bytes()?).map_err(other)?;
+        Ok(date.to_rusqlite())
+    }
+}
+
+impl ToSql for Time {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_jiff().to_string()))
+    }
+}
+
+impl FromSql for Time {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let time = PARSER.parse_time(value.as_bytes()?).map_err(other)?;
+        Ok(time.to_rusqlite())
+    }
+}
+
+/// Decodes a timestamp from either an `INTEGER` number of milliseconds since
+/// the Unix epoch or a `TEXT` RFC 3339 timestamp.
+///
+/// This is shared by both timestamp wrappers so that changing how a
+/// timestamp is stored doesn't require migrating existing rows.
+fn decode_timestamp(value: ValueRef<'_>) -> FromSqlResult<jiff::Timestamp> {
+    match value {
+        ValueRef::Integer(millis) => {
+            jiff::Timestamp::from_millisecond(millis).map_err(other)
+        }
+        ValueRef::Text(text) => PARSER.parse_timestamp(text).map_err(other),
+        _ => Err(FromSqlError::InvalidType),
+    }
+}
+
+fn other(err: jiff::Error) -> FromSqlError {
+    FromSqlError::Other(Box::new(err))
+}
@@ -0,0 +1,195 @@
+/// A trait for convenient conversions from Jiff types to rusqlite wrapper
+/// types.
+///
+/// # Example
+///
+/// This shows how to convert a [`jiff::Timestamp`] to a [`Timestamp`]:
+///
+/// ```
+/// use jiff_rusqlite::ToRusqlite;
+///
+/// let ts: jiff::Timestamp = "2025-02-20T17:00-05".parse()?;
+/// let wrapper = ts.to_rusqlite();
+/// assert_eq!(format!("{wrapper:?}"), "Timestamp(2025-02-20T22:00:00Z)");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait ToRusqlite {
+    /// The wrapper type to convert to.
+    type Target;
+
+    /// A conversion method that converts a Jiff type to a rusqlite wrapper
+    /// type.
+    fn to_rusqlite(self) -> Self::Target;
+}
+
+/// A wrapper type for [`jiff::Timestamp`].
+///
+/// This is encoded as an RFC 3339 timestamp in UTC in a `TEXT` column. For
+/// example, `2025-02-20T22:00:00Z`. To store a timestamp as an integer number
+/// of milliseconds instead, use [`TimestampMillisecond`].
+///
+/// When decoding, both `TEXT` and `INTEGER` values are accepted. An `INTEGER`
+/// value is interpreted as the number of milliseconds since the Unix epoch.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Timestamp(jiff::Timestamp);
+
+impl Timestamp {
+    /// Converts this wrapper to a [`jiff::Timestamp`].
+    pub fn to_jiff(self) -> jiff::Timestamp {
+        self.0
+    }
+}
+
+impl ToRusqlite for jiff::Timestamp {
+    type Target = Timestamp;
+
+    fn to_rusqlite(self) -> Timestamp {
+        Timestamp(self)
+    }
+}
+
+impl From<jiff::Timestamp> for Timestamp {
+    fn from(x: jiff::Timestamp) -> Timestamp {
+        Timestamp(x)
+    }
+}
+
+impl From<Timestamp> for jiff::Timestamp {
+    fn from(x: Timestamp) -> jiff::Timestamp {
+        x.0
+    }
+}
+
+/// A wrapper type for [`jiff::Timestamp`] that is stored as an integer.
+///
+/// This is encoded as the number of milliseconds since the Unix epoch in an
+/// `INTEGER` column. Any precision beyond milliseconds is truncated. This is
+/// useful for interoperating with other applications that store timestamps
+/// as Unix milliseconds.
+///
+/// When decoding, both `TEXT` and `INTEGER` values are accepted, in the same
+/// way as [`Timestamp`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct TimestampMillisecond(jiff::Timestamp);
+
+impl TimestampMillisecond {
+    /// Converts this wrapper to a [`jiff::Timestamp`].
+    pub fn to_jiff(self) -> jiff::Timestamp {
+        self.0
+    }
+}
+
+impl From<jiff::Timestamp> for TimestampMillisecond {
+    fn from(x: jiff::Timestamp) -> TimestampMillisecond {
+        TimestampMillisecond(x)
+    }
+}
+
+impl From<TimestampMillisecond> for jiff::Timestamp {
+    fn from(x: TimestampMillisecond) -> jiff::Timestamp {
+        x.0
+    }
+}
+
+/// A wrapper type for [`jiff::civil::DateTime`].
+///
+/// This is encoded as an ISO 8601 datetime in a `TEXT` column. For example,
+/// `2025-02-20T17:00:00`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct DateTime(jiff::civil::DateTime);
+
+impl DateTime {
+    /// Converts this wrapper to a [`jiff::civil::DateTime`].
+    pub fn to_jiff(self) -> jiff::civil::DateTime {
+        self.0
+    }
+}
+
+impl ToRusqlite for jiff::civil::DateTime {
+    type Target = DateTime;
+
+    fn to_rusqlite(self) -> DateTime {
+        DateTime(self)
+    }
+}
+
+impl From<jiff::civil::DateTime> for DateTime {
+    fn from(x: jiff::civil::DateTime) -> DateTime {
+        DateTime(x)
+    }
+}
+
+impl From<DateTime> for jiff::civil::DateTime {
+    fn from(x: DateTime) -> jiff::civil::DateTime {
+        x.0
+    }
+}
+
+/// A wrapper type for [`jiff::civil::Date`].
+///
+/// This is encoded as an ISO 8601 date in a `TEXT` column. For example,
+/// `2025-02-20`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Date(jiff::civil::Date);
+
+impl Date {
+    /// Converts this wrapper to a [`jiff::civil::Date`].
+    pub fn to_jiff(self) -> jiff::civil::Date {
+        self.0
+    }
+}
+
+impl ToRusqlite for jiff::civil::Date {
+    type Target = Date;
+
+    fn to_rusqlite(self) -> Date {
+        Date(self)
+    }
+}
+
+impl From<jiff::civil::Date> for Date {
+    fn from(x: jiff::civil::Date) -> Date {
+        Date(x)
+    }
+}
+
+impl From<Date> for jiff::civil::Date {
+    fn from(x: Date) -> jiff::civil::Date {
+        x.0
+    }
+}
+
+/// A wrapper type for [`jiff::civil::Time`].
+///
+/// This is encoded as an ISO 8601 time in a `TEXT` column. For example,
+/// `17:00:00`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Time(jiff::civil::Time);
+
+impl Time {
+    /// Converts this wrapper to a [`jiff::civil::Time`].
+    pub fn to_jiff(self) -> jiff::civil::Time {
+        self.0
+    }
+}
+
+impl ToRusqlite for jiff::civil::Time {
+    type Target = Time;
+
+    fn to_rusqlite(self) -> Time {
+        Time(self)
+    }
+}
+
+impl From<jiff::civil::Time> for Time {
+    fn from(x: jiff::civil::Time) -> Time {
+        Time(x)
+    }
+}
+
+impl From<Time> for jiff::civil::Time {
+    fn from(x: Time) -> jiff::civil::Time {
+        x.0
+    }
+}
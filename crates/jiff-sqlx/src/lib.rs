@@ -36,6 +36,11 @@ At present, both PostgreSQL and SQLite are supported.
 Ideally, MySQL support would be present too, but it
 [appears impossible until SQLx exposes some APIs][sqlx-mysql-bunk].
 
+Note that there is intentionally no wrapper for [`jiff::Zoned`]. A PostgreSQL
+`TIMESTAMP WITH TIME ZONE` only stores an instant, so storing a `Zoned` in one
+would silently drop its time zone. Use [`jiff::Zoned::timestamp`] with the
+[`Timestamp`] wrapper instead, and attach a time zone after decoding.
+
 # Future
 
 This crate exists because there are generally only three ways to implement
@@ -72,7 +77,9 @@ itself.
 
 #![deny(missing_docs)]
 
-pub use self::wrappers::{Date, DateTime, Span, Time, Timestamp, ToSqlx};
+pub use self::wrappers::{
+    Date, DateTime, SignedDuration, Span, Time, Timestamp, ToSqlx,
+};
 
 #[cfg(feature = "postgres")]
 mod postgres;
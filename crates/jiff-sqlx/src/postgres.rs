@@ -11,7 +11,7 @@ use sqlx_postgres::{
     Postgres,
 };
 
-use crate::{Date, DateTime, Span, Time, Timestamp, ToSqlx};
+use crate::{Date, DateTime, SignedDuration, Span, Time, Timestamp, ToSqlx};
 
 /// Apprently the actual format of values on the wire is not
 /// a documented guarantee of PostgreSQL.[1] Instead, I just `sqlx`'s
@@ -24,6 +24,7 @@ static POSTGRES_EPOCH_DATETIME: civil::DateTime =
     civil::date(2000, 1, 1).at(0, 0, 0, 0);
 static POSTGRES_EPOCH_TIMESTAMP: i64 = 946684800;
 static MIDNIGHT: civil::Time = civil::Time::midnight();
+static MICROS_PER_DAY: i64 = 86_400_000_000;
 static UTC: tz::TimeZone = tz::TimeZone::UTC;
 
 // We currently don't support `Zoned` integration in this wrapper crate.
@@ -278,6 +279,28 @@ impl PgHasArrayType for Span {
     }
 }
 
+impl Encode<'_, Postgres> for Span {
+    fn encode_by_ref(
+        &self,
+        buf: &mut PgArgumentBuffer,
+    ) -> Result<IsNull, BoxDynError> {
+        let span = self.to_jiff();
+        // None of these can overflow since the limits on each unit in a
+        // `Span` are small enough. For example, the maximum number of
+        // microseconds from all time units combined is less than 4e18.
+        let months = i32::from(span.get_years()) * 12 + span.get_months();
+        let days = span.get_weeks() * 7 + span.get_days();
+        let microseconds = i64::from(span.get_hours()) * 3_600_000_000
+            + span.get_minutes() * 60_000_000
+            + span.get_seconds() * 1_000_000
+            + span.get_milliseconds() * 1_000
+            + span.get_microseconds()
+            + span.get_nanoseconds() / 1_000;
+        let interval = PgInterval { months, days, microseconds };
+        Encode::<Postgres>::encode(interval, buf)
+    }
+}
+
 impl<'r> Decode<'r, Postgres> for Span {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
         let interval: PgInterval = Decode::<Postgres>::decode(value)?;
@@ -288,3 +311,54 @@ impl<'r> Decode<'r, Postgres> for Span {
         Ok(span.to_sqlx())
     }
 }
+
+impl Type<Postgres> for SignedDuration {
+    fn type_info() -> PgTypeInfo {
+        // https://github.com/launchbadge/sqlx/blob/65229f7ff91ecd38be7c10fb61ff3e05bedabe87/sqlx-postgres/src/type_info.rs#L527
+        PgTypeInfo::with_oid(Oid(1186))
+    }
+}
+
+impl PgHasArrayType for SignedDuration {
+    fn array_type_info() -> PgTypeInfo {
+        // https://github.com/launchbadge/sqlx/blob/65229f7ff91ecd38be7c10fb61ff3e05bedabe87/sqlx-postgres/src/type_info.rs#L528
+        PgTypeInfo::with_oid(Oid(1187))
+    }
+}
+
+impl Encode<'_, Postgres> for SignedDuration {
+    fn encode_by_ref(
+        &self,
+        buf: &mut PgArgumentBuffer,
+    ) -> Result<IsNull, BoxDynError> {
+        // A `SignedDuration` is an exact duration, so we only ever use the
+        // microseconds component of an interval.
+        let microseconds =
+            i64::try_from(self.to_jiff().as_micros()).map_err(|_| {
+                "signed duration is too big to fit into a PostgreSQL interval"
+            })?;
+        let interval = PgInterval { months: 0, days: 0, microseconds };
+        Encode::<Postgres>::encode(interval, buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for SignedDuration {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let interval: PgInterval = Decode::<Postgres>::decode(value)?;
+        if interval.months != 0 {
+            return Err("cannot decode PostgreSQL interval with non-zero \
+                        months into a signed duration"
+                .into());
+        }
+        // Days are always 24 hours, which is consistent with how
+        // `SignedDuration` treats days elsewhere in Jiff.
+        let days = jiff::SignedDuration::from_micros(
+            i64::from(interval.days) * MICROS_PER_DAY,
+        );
+        let micros = jiff::SignedDuration::from_micros(interval.microseconds);
+        let sdur = days
+            .checked_add(micros)
+            .ok_or("PostgreSQL interval overflows a signed duration")?;
+        Ok(sdur.to_sqlx())
+    }
+}
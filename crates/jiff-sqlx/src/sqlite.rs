@@ -1,4 +1,4 @@
-use jiff::fmt::temporal::DateTimeParser;
+use jiff::fmt::temporal::{DateTimeParser, SpanParser};
 use sqlx_core::{
     decode::Decode,
     encode::{Encode, IsNull},
@@ -9,9 +9,10 @@ use sqlx_sqlite::{
     Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef,
 };
 
-use crate::{Date, DateTime, Time, Timestamp, ToSqlx};
+use crate::{Date, DateTime, SignedDuration, Span, Time, Timestamp, ToSqlx};
 
 static PARSER: DateTimeParser = DateTimeParser::new();
+static SPAN_PARSER: SpanParser = SpanParser::new();
 
 impl Type<Sqlite> for Timestamp {
     fn type_info() -> SqliteTypeInfo {
@@ -122,6 +123,54 @@ impl<'r> Decode<'r, Sqlite> for Time {
     }
 }
 
+impl Type<Sqlite> for Span {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+}
+
+impl Encode<'_, Sqlite> for Span {
+    fn encode_by_ref(
+        &self,
+        buf: &mut Vec<SqliteArgumentValue<'_>>,
+    ) -> Result<IsNull, BoxDynError> {
+        // SQLite has no interval type, so we store spans as ISO 8601
+        // durations.
+        Encode::<Sqlite>::encode(self.to_jiff().to_string(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for Span {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        let text = <&[u8] as Decode<Sqlite>>::decode(value)?;
+        let span = SPAN_PARSER.parse_span(text)?;
+        Ok(span.to_sqlx())
+    }
+}
+
+impl Type<Sqlite> for SignedDuration {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+}
+
+impl Encode<'_, Sqlite> for SignedDuration {
+    fn encode_by_ref(
+        &self,
+        buf: &mut Vec<SqliteArgumentValue<'_>>,
+    ) -> Result<IsNull, BoxDynError> {
+        Encode::<Sqlite>::encode(self.to_jiff().to_string(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for SignedDuration {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        let text = <&[u8] as Decode<Sqlite>>::decode(value)?;
+        let sdur = SPAN_PARSER.parse_duration(text)?;
+        Ok(sdur.to_sqlx())
+    }
+}
+
 fn julian_days_to_timestamp(
     days: f64,
 ) -> Result<jiff::Timestamp, BoxDynError> {
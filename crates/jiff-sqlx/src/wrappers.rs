@@ -229,13 +229,15 @@ impl From<Time> for jiff::civil::Time {
 
 /// A wrapper type for [`jiff::Span`].
 ///
-/// # PostgreSQL: Limited support
+/// # PostgreSQL: Unit mapping
 ///
-/// This type _only_ has a [`sqlx_core::decode::Decode`] trait implementation
-/// for PostgreSQL. The reason for this is that encoding an arbitrary
-/// `Span` into a PostgreSQL interval requires a relative datetime.
-/// Therefore, users wanting to store a `Span` will need to explicitly use a
-/// [`sqlx_postgres::types::PgInterval`] at least at encoding time.
+/// A PostgreSQL interval is made up of months, days and microseconds. When
+/// encoding a `Span`, years and months are folded into the months component,
+/// weeks and days are folded into the days component and all time units are
+/// folded into the microseconds component. Nothing is balanced between these
+/// three components, since doing so would require a relative datetime.
+/// Any nanoseconds beyond microsecond precision are truncated, which matches
+/// how the other wrapper types in this crate encode values for PostgreSQL.
 #[derive(Clone, Copy, Debug)]
 pub struct Span(jiff::Span);
 
@@ -265,3 +267,41 @@ impl From<Span> for jiff::Span {
         x.0
     }
 }
+
+/// A wrapper type for [`jiff::SignedDuration`].
+///
+/// # PostgreSQL: Days are 24 hours
+///
+/// When decoding a PostgreSQL interval into a `SignedDuration`, the days
+/// component of the interval is interpreted as 24 hours. Intervals with a
+/// non-zero months component cannot be decoded into a `SignedDuration`,
+/// since the length of a month is not fixed. Use [`Span`] in that case.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct SignedDuration(jiff::SignedDuration);
+
+impl SignedDuration {
+    /// Converts this wrapper to a [`jiff::SignedDuration`].
+    pub fn to_jiff(self) -> jiff::SignedDuration {
+        self.0
+    }
+}
+
+impl ToSqlx for jiff::SignedDuration {
+    type Target = SignedDuration;
+
+    fn to_sqlx(self) -> SignedDuration {
+        SignedDuration(self)
+    }
+}
+
+impl From<jiff::SignedDuration> for SignedDuration {
+    fn from(x: jiff::SignedDuration) -> SignedDuration {
+        SignedDuration(x)
+    }
+}
+
+impl From<SignedDuration> for jiff::SignedDuration {
+    fn from(x: SignedDuration) -> jiff::SignedDuration {
+        x.0
+    }
+}
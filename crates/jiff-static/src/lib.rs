@@ -1,15 +1,16 @@
 /*!
 This crate provides macros for defining `static` data structures for Jiff.
 
-The macros in this crate are re-exported in the [`jiff::tz`] sub-module.
-Users should _not_ depend on this crate directly or import from it. Instead,
-enable the `static` or `static-tz` features of Jiff and use the re-exports in
-`jiff::tz`.
+The macros in this crate are re-exported in the [`jiff::tz`] and
+[`jiff::fmt::strtime`] sub-modules. Users should _not_ depend on this crate
+directly or import from it. Instead, enable the `static` or `static-tz`
+features of Jiff and use the re-exports in Jiff.
 
-At present, the macros in this crate are limited to creating `TimeZone`
-in a `const` context. This works by reading TZif data (e.g., from
+Most of the macros in this crate create a `TimeZone` in a `const` context.
+This works by reading TZif data (e.g., from
 `/usr/share/zoneinfo/America/New_York` or from [`jiff-tzdb`]) at compile
-time and generating Rust source code that builds a `TimeZone`.
+time and generating Rust source code that builds a `TimeZone`. There is also
+a macro for checking a `strftime`/`strptime` format string at compile time.
 
 # Documentation
 
@@ -18,6 +19,7 @@ re-exports in Jiff:
 
 * `get` is documented at [`jiff::tz::get`].
 * `include` is documented at [`jiff::tz::include`].
+* `strtime_compile` is documented at [`jiff::fmt::strtime::compile`].
 
 # Compatibility
 
@@ -36,6 +38,8 @@ this crate. Everything should be managed through the `jiff` crate.
 [`jiff::tz`]: https://docs.rs/jiff/0.2/jiff/tz/index.html
 [`jiff::tz::get`]: https://docs.rs/jiff/0.2/jiff/tz/macro.get.html
 [`jiff::tz::include`]: https://docs.rs/jiff/0.2/jiff/tz/macro.include.html
+[`jiff::fmt::strtime`]: https://docs.rs/jiff/0.2/jiff/fmt/strtime/index.html
+[`jiff::fmt::strtime::compile`]: https://docs.rs/jiff/0.2/jiff/fmt/strtime/macro.compile.html
 */
 
 extern crate alloc;
@@ -62,6 +66,7 @@ use self::shared::{
 /// should be pretty small anyway.
 #[allow(dead_code)]
 mod shared;
+mod strtime;
 
 // Public API docs are in Jiff.
 #[proc_macro]
@@ -78,6 +83,13 @@ pub fn get(input: TokenStream) -> TokenStream {
     proc_macro::TokenStream::from(input.quote())
 }
 
+// Public API docs are in Jiff.
+#[proc_macro]
+pub fn strtime_compile(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as strtime::Compile);
+    proc_macro::TokenStream::from(input.quote())
+}
+
 /// The entry point for the `include!` macro.
 #[derive(Debug)]
 struct Include {
@@ -230,6 +242,8 @@ impl TzifFixed<String, Abbreviation> {
             checksum,
             ref designations,
             ref posix_tz,
+            last_recorded_transition,
+            expiration,
         } = *self;
         let name = name.as_ref().unwrap();
         let posix_tz = posix_tz
@@ -239,6 +253,12 @@ impl TzifFixed<String, Abbreviation> {
                 quote!(Some(#tz))
             })
             .unwrap_or_else(|| quote!(None));
+        let last_recorded_transition = last_recorded_transition
+            .map(|ts| quote!(Some(#ts)))
+            .unwrap_or_else(|| quote!(None));
+        let expiration = expiration
+            .map(|ts| quote!(Some(#ts)))
+            .unwrap_or_else(|| quote!(None));
         quote! {
             jiff::shared::TzifFixed {
                 name: Some(#name),
@@ -246,6 +266,8 @@ impl TzifFixed<String, Abbreviation> {
                 checksum: #checksum,
                 designations: #designations,
                 posix_tz: #posix_tz,
+                last_recorded_transition: #last_recorded_transition,
+                expiration: #expiration,
             }
         }
     }
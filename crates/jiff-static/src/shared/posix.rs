@@ -41,6 +41,9 @@ impl<ABBREV: AsRef<str>> PosixTimeZone<ABBREV> {
         if self.dst.is_none() {
             return std_offset;
         }
+        if let Some(dst) = self.permanent_dst() {
+            return dst.offset.to_ioffset();
+        }
 
         let dt = timestamp.to_datetime(IOffset::UTC);
         self.dst_info_utc(dt.date.year)
@@ -63,6 +66,9 @@ impl<ABBREV: AsRef<str>> PosixTimeZone<ABBREV> {
         if self.dst.is_none() {
             return (std_offset, self.std_abbrev.as_ref(), false);
         }
+        if let Some(dst) = self.permanent_dst() {
+            return (dst.offset.to_ioffset(), dst.abbrev.as_ref(), true);
+        }
 
         let dt = timestamp.to_datetime(IOffset::UTC);
         self.dst_info_utc(dt.date.year)
@@ -91,6 +97,10 @@ impl<ABBREV: AsRef<str>> PosixTimeZone<ABBREV> {
     pub(crate) fn to_ambiguous_kind(&self, dt: IDateTime) -> IAmbiguousOffset {
         let year = dt.date.year;
         let std_offset = self.std_offset.to_ioffset();
+        if let Some(dst) = self.permanent_dst() {
+            let offset = dst.offset.to_ioffset();
+            return IAmbiguousOffset::Unambiguous { offset };
+        }
         let Some(dst_info) = self.dst_info_wall(year) else {
             return IAmbiguousOffset::Unambiguous { offset: std_offset };
         };
@@ -174,6 +184,9 @@ impl<ABBREV: AsRef<str>> PosixTimeZone<ABBREV> {
         &self,
         timestamp: ITimestamp,
     ) -> Option<(ITimestamp, IOffset, &'_ str, bool)> {
+        if self.is_fixed() {
+            return None;
+        }
         let dt = timestamp.to_datetime(IOffset::UTC);
         let dst_info = self.dst_info_utc(dt.date.year)?;
         let (earlier, later) = dst_info.ordered();
@@ -204,6 +217,9 @@ impl<ABBREV: AsRef<str>> PosixTimeZone<ABBREV> {
         &self,
         timestamp: ITimestamp,
     ) -> Option<(ITimestamp, IOffset, &'_ str, bool)> {
+        if self.is_fixed() {
+            return None;
+        }
         let dt = timestamp.to_datetime(IOffset::UTC);
         let dst_info = self.dst_info_utc(dt.date.year)?;
         let (earlier, later) = dst_info.ordered();
@@ -228,6 +244,42 @@ impl<ABBREV: AsRef<str>> PosixTimeZone<ABBREV> {
         Some((timestamp, offset.to_ioffset(), abbrev, dst))
     }
 
+    /// Returns true if and only if this time zone always uses the same
+    /// offset. That is, it never has any transitions.
+    ///
+    /// This is the case when there is no DST rule, or when the DST rule
+    /// indicates that DST is in effect all year.
+    pub(crate) fn is_fixed(&self) -> bool {
+        self.dst.is_none() || self.permanent_dst().is_some()
+    }
+
+    /// Returns the DST rule only when DST is in effect all year.
+    ///
+    /// RFC 8536 says that "DST is considered to be in effect all year if it
+    /// starts January 1 at 00:00 and ends December 31 at 24:00 plus the
+    /// difference between daylight saving and standard time." For example,
+    /// `XXX3EDT4,0/0,J365/23`. Without special handling, such rules would
+    /// produce spurious transitions (along with gaps or folds) at the
+    /// boundary of each year.
+    fn permanent_dst(&self) -> Option<&PosixDst<ABBREV>> {
+        let dst = self.dst.as_ref()?;
+        let rule = &dst.rule;
+        let starts_year = matches!(
+            rule.start.date,
+            PosixDay::JulianOne(1) | PosixDay::JulianZero(0)
+        ) && rule.start.time.second == 0;
+        // The `J` form is used for the end because `365` (zero-based and
+        // counting leap days) only refers to December 31 in leap years.
+        let diff = dst.offset.second - self.std_offset.second;
+        let ends_year = matches!(rule.end.date, PosixDay::JulianOne(365))
+            && rule.end.time.second == 86400 + diff;
+        if starts_year && ends_year {
+            Some(dst)
+        } else {
+            None
+        }
+    }
+
     /// Returns the range in which DST occurs.
     ///
     /// The civil datetimes returned are in UTC. This is useful for determining
@@ -2529,6 +2581,63 @@ mod tests {
         assert_eq!(tz.dst_info_utc(2024), Some(dst_info));
     }
 
+    #[test]
+    fn permanent_dst() {
+        let ts = |second| ITimestamp { second, nanosecond: 0 };
+        let offset = |second| IOffset { second };
+
+        for (input, std, dst) in [
+            ("XXX3EDT4,0/0,J365/23", -3, -4),
+            ("EST5EDT,0/0,J365/25", -5, -4),
+            ("EST5EDT,J1/0,J365/25", -5, -4),
+        ] {
+            let tz = posix_time_zone(input);
+            assert!(tz.is_fixed(), "{input}");
+            assert_eq!(tz.std_offset.second, std * 3600, "{input}");
+            let dst = offset(dst * 3600);
+            // 2024-12-31T23:30:00Z, 2025-01-01T03:30:00Z and
+            // 2025-06-01T00:00:00Z.
+            for second in [1735687800, 1735702200, 1748736000] {
+                assert_eq!(tz.to_offset(ts(second)), dst, "{input}");
+                let (got, abbrev, is_dst) = tz.to_offset_info(ts(second));
+                assert_eq!(got, dst, "{input}");
+                assert_eq!(abbrev, "EDT", "{input}");
+                assert!(is_dst, "{input}");
+                assert_eq!(tz.next_transition(ts(second)), None, "{input}");
+                assert_eq!(
+                    tz.previous_transition(ts(second)),
+                    None,
+                    "{input}"
+                );
+            }
+            for dt in [
+                date(2024, 12, 31).at(22, 30, 0, 0),
+                date(2024, 12, 31).at(23, 30, 0, 0),
+                date(2025, 1, 1).at(0, 30, 0, 0),
+            ] {
+                assert_eq!(
+                    tz.to_ambiguous_kind(dt),
+                    IAmbiguousOffset::Unambiguous { offset: dst },
+                    "{input}",
+                );
+            }
+        }
+
+        // These are close to, but not quite, permanent DST.
+        for input in [
+            "EST5EDT,0/0,J365/24",
+            "EST5EDT,0/1,J365/25",
+            "EST5EDT,1/0,J365/25",
+            "EST5EDT,0/0,365/25",
+            "EST5EDT,M3.2.0,M11.1.0",
+        ] {
+            let tz = posix_time_zone(input);
+            assert!(!tz.is_fixed(), "{input}");
+            assert!(tz.next_transition(ts(1735687800)).is_some(), "{input}");
+        }
+        assert!(posix_time_zone("EST5").is_fixed());
+    }
+
     #[test]
     fn reasonable() {
         assert!(PosixTimeZone::parse(b"EST5").is_ok());
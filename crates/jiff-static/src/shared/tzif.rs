@@ -90,6 +90,11 @@ impl TzifOwned {
         } else {
             TzifOwned::parse64(name, header32, rest)?
         };
+        // Remember where the transitions in the TZif data end before
+        // fattening adds more. The first transition is always our dummy
+        // transition at the minimum timestamp, so it isn't included.
+        tzif.fixed.last_recorded_transition =
+            tzif.transitions.timestamps[1..].last().copied();
         tzif.fatten();
         // This should come after fattening, because fattening may add new
         // transitions and we want to add civil datetimes to those.
@@ -126,6 +131,9 @@ impl TzifOwned {
                 checksum: 0,
                 designations: String::new(),
                 posix_tz: None,
+                // filled in later
+                last_recorded_transition: None,
+                expiration: None,
             },
             types: vec![],
             transitions: TzifTransitions {
@@ -164,6 +172,9 @@ impl TzifOwned {
                 checksum: 0,
                 designations: String::new(),
                 posix_tz: None,
+                // filled in later
+                last_recorded_transition: None,
+                expiration: None,
             },
             types: vec![],
             transitions: TzifTransitions {
@@ -347,7 +358,8 @@ impl TzifOwned {
 
     /// This parses the leap second corrections in the TZif data.
     ///
-    /// Note that we only parse and verify them. We don't actually use them.
+    /// Note that we only parse and verify them. We don't actually use them,
+    /// other than to look for the expiration time of the leap second table.
     /// Jiff effectively ignores leap seconds.
     fn parse_leap_seconds<'b>(
         &mut self,
@@ -364,16 +376,35 @@ impl TzifOwned {
             .checked_add(4)
             .expect("time_size plus 4 fits in usize");
         let mut it = bytes.chunks_exact(chunk_len);
+        // The correction of the last leap second record, or `0` if there
+        // isn't one, along with the occurrence of the last record.
+        let mut prev_corr = 0;
+        let mut last = None;
         while let Some(chunk) = it.next() {
-            let (occur_bytes, _corr_bytes) = chunk.split_at(header.time_size);
+            let (occur_bytes, corr_bytes) = chunk.split_at(header.time_size);
             let occur = if header.is_32bit() {
                 i64::from(from_be_bytes_i32(occur_bytes))
             } else {
                 from_be_bytes_i64(occur_bytes)
             };
+            let corr = from_be_bytes_i32(corr_bytes);
+            last = Some((occur, corr == prev_corr));
+            prev_corr = corr;
             if !(TIMESTAMP_MIN <= occur && occur <= TIMESTAMP_MAX) {}
         }
         assert!(it.remainder().is_empty());
+        // RFC 9636 says: "If the last leap second record's correction is
+        // the same as that of the previous record (or zero if there is no
+        // previous record), the record indicates the expiration time of the
+        // leap second table instead of a leap second." This only applies to
+        // version 4 or later.
+        if header.version >= b'4' {
+            if let Some((occur, true)) = last {
+                if (TIMESTAMP_MIN..=TIMESTAMP_MAX).contains(&occur) {
+                    self.fixed.expiration = Some(occur);
+                }
+            }
+        }
         Ok(rest)
     }
 
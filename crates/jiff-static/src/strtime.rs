@@ -0,0 +1,305 @@
+/*!
+Compile time validation of `strftime`/`strptime` format strings.
+
+This is a small re-implementation of the directive scanner in Jiff's
+`fmt::strtime` module. We can't use Jiff itself here (it would be a circular
+dependency), and the scanner is small enough that copying it is simpler than
+moving it into `src/shared`. If Jiff's set of supported directives changes,
+then this module must be updated too.
+*/
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The entry point for the `strtime_compile!` macro.
+pub(crate) struct Compile {
+    format: syn::LitStr,
+}
+
+impl Compile {
+    pub(crate) fn quote(&self) -> proc_macro2::TokenStream {
+        let format = &self.format;
+        quote::quote! {
+            jiff::fmt::strtime::CompiledFormat::new(#format)
+                .expect("strtime format string was validated at compile time")
+        }
+    }
+}
+
+impl syn::parse::Parse for Compile {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Compile> {
+        let format = input.parse::<syn::LitStr>()?;
+        let mut target = None;
+        if input.lookahead1().peek(syn::Token![,]) {
+            input.parse::<syn::Token![,]>()?;
+            if !input.is_empty() {
+                target = Some(input.parse::<Target>()?);
+                // Permit optional trailing comma.
+                if input.lookahead1().peek(syn::Token![,]) {
+                    input.parse::<syn::Token![,]>()?;
+                }
+            }
+        }
+        validate(&format.value(), target).map_err(|msg| {
+            syn::Error::new(
+                format.span(),
+                format!("invalid strtime format string: {msg}"),
+            )
+        })?;
+        Ok(Compile { format })
+    }
+}
+
+/// The datetime type a format string is checked against.
+///
+/// This is given as the optional second parameter to the macro, and only the
+/// last segment of the path is looked at. So both `Date` and
+/// `jiff::civil::Date` work.
+#[derive(Clone, Copy, Debug)]
+enum Target {
+    Zoned,
+    Timestamp,
+    DateTime,
+    Date,
+    Time,
+}
+
+impl Target {
+    fn name(&self) -> &'static str {
+        match *self {
+            Target::Zoned => "Zoned",
+            Target::Timestamp => "Timestamp",
+            Target::DateTime => "DateTime",
+            Target::Date => "Date",
+            Target::Time => "Time",
+        }
+    }
+
+    /// Returns true when a `BrokenDownTime` created from this target has
+    /// everything the given requirement needs.
+    fn satisfies(&self, requirement: Requirement) -> bool {
+        use self::Requirement::*;
+
+        match *self {
+            Target::Zoned => true,
+            Target::Timestamp => !matches!(requirement, TimeZoneAbbreviation),
+            Target::DateTime => matches!(requirement, Nothing | Date | Time),
+            Target::Date => matches!(requirement, Nothing | Date),
+            Target::Time => matches!(requirement, Nothing | Time),
+        }
+    }
+}
+
+impl syn::parse::Parse for Target {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Target> {
+        let path = input.parse::<syn::Path>()?;
+        let Some(last) = path.segments.last() else {
+            return Err(syn::Error::new_spanned(path, "expected a type"));
+        };
+        let target = match last.ident.to_string().as_str() {
+            "Zoned" => Target::Zoned,
+            "Timestamp" => Target::Timestamp,
+            "DateTime" => Target::DateTime,
+            "Date" => Target::Date,
+            "Time" => Target::Time,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    "expected one of `Zoned`, `Timestamp`, `DateTime`, \
+                     `Date` or `Time`",
+                ))
+            }
+        };
+        Ok(target)
+    }
+}
+
+/// What a directive needs from a datetime in order to format it.
+#[derive(Clone, Copy, Debug)]
+enum Requirement {
+    Nothing,
+    Date,
+    Time,
+    Offset,
+    OffsetOrTimeZone,
+    TimeZoneAbbreviation,
+    Instant,
+}
+
+impl Requirement {
+    fn describe(&self) -> &'static str {
+        match *self {
+            Requirement::Nothing => "nothing",
+            Requirement::Date => "a date",
+            Requirement::Time => "a time",
+            Requirement::Offset => "an offset",
+            Requirement::OffsetOrTimeZone => "an offset or time zone",
+            Requirement::TimeZoneAbbreviation => "a time zone abbreviation",
+            Requirement::Instant => "an instant",
+        }
+    }
+}
+
+/// A single directive in a format string, like `%-d` or `%:z`.
+///
+/// Only the parts that matter for validation are kept. The flag and width
+/// are checked while scanning, but otherwise dropped.
+#[derive(Clone, Copy, Debug)]
+struct Directive {
+    prefix: Option<u8>,
+    specifier: u8,
+}
+
+impl Directive {
+    /// Returns what this directive requires, or `None` if it isn't supported.
+    fn requirement(&self) -> Option<Requirement> {
+        use self::Requirement::*;
+
+        let requirement = match (self.prefix, self.specifier) {
+            (None, b'%' | b'n' | b't') => Nothing,
+            (
+                None,
+                b'A' | b'a' | b'B' | b'b' | b'C' | b'D' | b'd' | b'e' | b'F'
+                | b'G' | b'g' | b'h' | b'j' | b'm' | b'U' | b'u' | b'V' | b'W'
+                | b'w' | b'x' | b'Y' | b'y',
+            ) => Date,
+            (
+                None,
+                b'f' | b'H' | b'I' | b'k' | b'l' | b'M' | b'P' | b'p' | b'R'
+                | b'S' | b'T' | b'X',
+            ) => Time,
+            (Some(b'.'), b'f') => Time,
+            (None | Some(b':'), b'z') => Offset,
+            (None | Some(b':'), b'Q') => OffsetOrTimeZone,
+            (None, b'Z') => TimeZoneAbbreviation,
+            (None, b's') => Instant,
+            _ => return None,
+        };
+        Some(requirement)
+    }
+}
+
+impl core::fmt::Display for Directive {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("%")?;
+        if let Some(prefix) = self.prefix {
+            write!(f, "{}", char::from(prefix))?;
+        }
+        write!(f, "{}", self.specifier.escape_ascii())
+    }
+}
+
+/// Validates the format string given, optionally against a target type.
+///
+/// The error returned is suitable for showing to the user after an
+/// `invalid strtime format string: ` prefix.
+fn validate(fmt: &str, target: Option<Target>) -> Result<(), String> {
+    let directives = scan(fmt.as_bytes())?;
+    let unsupported: Vec<String> = directives
+        .iter()
+        .filter(|d| d.requirement().is_none())
+        .map(|d| d.to_string())
+        .collect();
+    if !unsupported.is_empty() {
+        return Err(format!(
+            "found unsupported directives {}",
+            unsupported.join(", "),
+        ));
+    }
+    let Some(target) = target else { return Ok(()) };
+    let unsatisfied: Vec<String> = directives
+        .iter()
+        .filter_map(|d| {
+            let requirement = d.requirement()?;
+            if target.satisfies(requirement) {
+                return None;
+            }
+            Some(format!("{d} (requires {})", requirement.describe()))
+        })
+        .collect();
+    if !unsatisfied.is_empty() {
+        return Err(format!(
+            "found directives that cannot be used with `{}`: {}",
+            target.name(),
+            unsatisfied.join(", "),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns every directive in the given format string.
+///
+/// This returns an error in the same cases that Jiff's scanner does, for
+/// example, when the format string ends with a `%`.
+fn scan(mut fmt: &[u8]) -> Result<Vec<Directive>, String> {
+    let mut directives = Vec::new();
+    while !fmt.is_empty() {
+        if fmt[0] != b'%' {
+            fmt = &fmt[1..];
+            continue;
+        }
+        fmt = &fmt[1..];
+        if fmt.is_empty() {
+            return Err("expected byte after '%', but found end of format \
+                        string"
+                .to_string());
+        }
+        if matches!(fmt[0], b'_' | b'0' | b'-' | b'^' | b'#') {
+            let flag = fmt[0];
+            fmt = &fmt[1..];
+            if fmt.is_empty() {
+                return Err(format!(
+                    "expected to find specifier directive after flag \
+                     {:?}, but found end of format string",
+                    char::from(flag),
+                ));
+            }
+        }
+        fmt = scan_width(fmt)?;
+        let prefix = match fmt[0] {
+            b':' => {
+                fmt = &fmt[1..];
+                Some(b':')
+            }
+            b'.' => {
+                fmt = scan_width(&fmt[1..])?;
+                Some(b'.')
+            }
+            _ => None,
+        };
+        let Some(&specifier) = fmt.first() else {
+            return Err(format!(
+                "expected directive after '%{}'",
+                char::from(prefix.unwrap()),
+            ));
+        };
+        directives.push(Directive { prefix, specifier });
+        fmt = &fmt[1..];
+    }
+    Ok(directives)
+}
+
+/// Skips over an optional width, checking that it fits in a `u8` and that
+/// something follows it.
+fn scan_width(fmt: &[u8]) -> Result<&[u8], String> {
+    let digits = fmt.iter().take_while(|b| b.is_ascii_digit()).count();
+    if digits == 0 {
+        return Ok(fmt);
+    }
+    let (digits, fmt) = fmt.split_at(digits);
+    // OK because the bytes are all ASCII digits.
+    let digits = core::str::from_utf8(digits).unwrap();
+    if digits.parse::<u8>().is_err() {
+        return Err(format!("width {digits} is too big, max is {}", u8::MAX));
+    }
+    if fmt.is_empty() {
+        return Err(format!(
+            "expected to find specifier directive after width {digits}, \
+             but found end of format string",
+        ));
+    }
+    Ok(fmt)
+}
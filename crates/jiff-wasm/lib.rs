@@ -13,6 +13,51 @@ fn zoned_now_no_panic() {
     eprintln!("{zdt}");
 }
 
+#[wasm_bindgen_test]
+fn js_date_round_trip() {
+    let ts: jiff::Timestamp =
+        "2025-01-01T12:30:00.123456789Z".parse().unwrap();
+    let date = js_sys::Date::from(ts);
+    assert_eq!(date.get_time(), 1_735_734_600_123.0);
+    let got = jiff::Timestamp::try_from(date).unwrap();
+    assert_eq!(got.to_string(), "2025-01-01T12:30:00.123Z");
+
+    let invalid = js_sys::Date::new(&f64::NAN.into());
+    assert!(jiff::Timestamp::try_from(invalid).is_err());
+}
+
+#[wasm_bindgen_test]
+fn intl_time_zone_no_panic() {
+    let tz = jiff::wasm::intl_time_zone();
+    eprintln!("{tz:?}");
+}
+
+#[wasm_bindgen_test]
+fn temporal_round_trip() {
+    let zdt: jiff::Zoned =
+        "2025-03-09T03:00-04:00[America/New_York]".parse().unwrap();
+    // Not all hosts support Temporal yet, so only check the round trip when
+    // it's available.
+    match jiff::wasm::to_temporal_zoned_date_time(&zdt) {
+        Ok(temporal) => {
+            let got: jiff::Zoned =
+                jiff::wasm::from_temporal(&temporal).unwrap();
+            assert_eq!(got, zdt);
+        }
+        Err(err) => eprintln!("{err}"),
+    }
+
+    let span = jiff::ToSpan::hours(5).minutes(30);
+    match jiff::wasm::to_temporal_duration(span) {
+        Ok(temporal) => {
+            let got: jiff::Span =
+                jiff::wasm::from_temporal(&temporal).unwrap();
+            assert_eq!(got.fieldwise(), span.fieldwise());
+        }
+        Err(err) => eprintln!("{err}"),
+    }
+}
+
 // Not sure if this is needed? ---AG
 pub fn set_panic_hook() {
     // When the `console_error_panic_hook` feature is enabled, we can call the
@@ -0,0 +1,383 @@
+/*!
+Unstable hooks for differential testing of Jiff against other
+implementations.
+
+**This module is not part of Jiff's public API.** It is only available when
+the internal `__testing` crate feature is enabled, and it is exempt from
+semver. Anything in here may change or disappear in any release. It exists
+so that downstream distributions and the CI of projects depending on Jiff
+can certify Jiff's behavior on their platforms by comparing it against a
+reference:
+
+* [`localtime`] and [`mktime`] produce the results that the C library's
+  `localtime_r` and `mktime` are expected to produce for the same time zone.
+  The caller is responsible for calling the C library (with `TZ` set
+  appropriately) and comparing the results, for example via [`diff_tm`].
+* [`TEMPORAL_CORPUS`] is a corpus of inputs for the grammar described by
+  [`fmt::temporal`](crate::fmt::temporal), annotated with whether each input
+  is valid for a particular production. [`check_corpus`] verifies that Jiff
+  agrees with the annotations. The same corpus can be fed to a reference
+  implementation of the grammar (like a Temporal implementation) or used as
+  seeds for a fuzzer.
+*/
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    civil::{Date, DateTime, Time},
+    error::{err, ErrorContext},
+    tz::{AmbiguousOffset, TimeZone},
+    Error, SignedDuration, Span, Timestamp, Zoned,
+};
+
+/// The fields of a C `struct tm`, as filled in by `localtime_r`.
+///
+/// The field names and their meaning mirror the C definitions, including the
+/// GNU and BSD `tm_gmtoff` and `tm_zone` extensions. In particular,
+/// `tm_mon` is zero based, `tm_year` is the number of years since `1900`,
+/// `tm_wday` starts at Sunday as `0` and `tm_yday` is zero based.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Tm {
+    pub tm_sec: i32,
+    pub tm_min: i32,
+    pub tm_hour: i32,
+    pub tm_mday: i32,
+    pub tm_mon: i32,
+    pub tm_year: i32,
+    pub tm_wday: i32,
+    pub tm_yday: i32,
+    pub tm_isdst: i32,
+    pub tm_gmtoff: i64,
+    pub tm_zone: String,
+}
+
+/// Returns the `struct tm` that `localtime_r` is expected to produce for the
+/// given Unix time in the given time zone.
+///
+/// # Errors
+///
+/// This returns an error when the Unix time is outside of the range
+/// supported by [`Timestamp`].
+pub fn localtime(tz: &TimeZone, unix_seconds: i64) -> Result<Tm, Error> {
+    let ts = Timestamp::from_second(unix_seconds)?;
+    let info = tz.to_offset_info(ts);
+    let dt = info.offset().to_datetime(ts);
+    Ok(Tm {
+        tm_sec: dt.second().into(),
+        tm_min: dt.minute().into(),
+        tm_hour: dt.hour().into(),
+        tm_mday: dt.day().into(),
+        tm_mon: i32::from(dt.month()) - 1,
+        tm_year: i32::from(dt.year()) - 1900,
+        tm_wday: dt.weekday().to_sunday_zero_offset().into(),
+        tm_yday: i32::from(dt.day_of_year()) - 1,
+        tm_isdst: info.dst().is_dst().into(),
+        tm_gmtoff: info.offset().seconds().into(),
+        tm_zone: info.abbreviation().into(),
+    })
+}
+
+/// Returns the Unix time that `mktime` is expected to produce for the given
+/// `struct tm` in the given time zone.
+///
+/// Only the civil datetime fields and `tm_isdst` are used. When `tm_isdst`
+/// is negative, or when the civil datetime falls in a gap, the result is the
+/// same as [`Disambiguation::Compatible`](crate::tz::Disambiguation). When
+/// the civil datetime is ambiguous because it falls in a fold, a
+/// non-negative `tm_isdst` picks the candidate whose DST status matches it.
+///
+/// # Errors
+///
+/// Unlike `mktime`, this does not normalize out of range fields (like a
+/// `tm_mday` of `32`) and returns an error for them instead. An error is also
+/// returned for leap seconds and for results outside of the range supported
+/// by [`Timestamp`].
+pub fn mktime(tz: &TimeZone, tm: &Tm) -> Result<i64, Error> {
+    let year =
+        tm.tm_year.checked_add(1900).and_then(|y| i16::try_from(y).ok());
+    let field = |name: &str, value: i32| {
+        i8::try_from(value)
+            .map_err(|_| err!("struct tm field {name} is out of range"))
+    };
+    let dt = DateTime::new(
+        year.ok_or_else(|| err!("struct tm field tm_year is out of range"))?,
+        field("tm_mon", tm.tm_mon.saturating_add(1))?,
+        field("tm_mday", tm.tm_mday)?,
+        field("tm_hour", tm.tm_hour)?,
+        field("tm_min", tm.tm_min)?,
+        field("tm_sec", tm.tm_sec)?,
+        0,
+    )
+    .context("invalid civil datetime in struct tm")?;
+    let ambiguous = tz.to_ambiguous_timestamp(dt);
+    let ts = match ambiguous.offset() {
+        AmbiguousOffset::Fold { .. } if tm.tm_isdst >= 0 => {
+            let earlier = ambiguous.earlier()?;
+            let want_dst = tm.tm_isdst > 0;
+            if tz.to_offset_info(earlier).dst().is_dst() == want_dst {
+                earlier
+            } else {
+                ambiguous.later()?
+            }
+        }
+        _ => ambiguous.compatible()?,
+    };
+    Ok(ts.as_second())
+}
+
+/// Compares a `struct tm` computed by Jiff with one computed by a reference
+/// implementation.
+///
+/// # Errors
+///
+/// This returns an error naming every field that differs.
+pub fn diff_tm(jiff: &Tm, reference: &Tm) -> Result<(), Error> {
+    let mut diffs = Vec::new();
+    let mut check = |name: &str, jiff: i64, reference: i64| {
+        if jiff != reference {
+            diffs.push(alloc::format!(
+                "{name} (jiff: {jiff}, reference: {reference})"
+            ));
+        }
+    };
+    check("tm_sec", jiff.tm_sec.into(), reference.tm_sec.into());
+    check("tm_min", jiff.tm_min.into(), reference.tm_min.into());
+    check("tm_hour", jiff.tm_hour.into(), reference.tm_hour.into());
+    check("tm_mday", jiff.tm_mday.into(), reference.tm_mday.into());
+    check("tm_mon", jiff.tm_mon.into(), reference.tm_mon.into());
+    check("tm_year", jiff.tm_year.into(), reference.tm_year.into());
+    check("tm_wday", jiff.tm_wday.into(), reference.tm_wday.into());
+    check("tm_yday", jiff.tm_yday.into(), reference.tm_yday.into());
+    check("tm_isdst", jiff.tm_isdst.into(), reference.tm_isdst.into());
+    check("tm_gmtoff", jiff.tm_gmtoff, reference.tm_gmtoff);
+    if jiff.tm_zone != reference.tm_zone {
+        diffs.push(alloc::format!(
+            "tm_zone (jiff: {:?}, reference: {:?})",
+            jiff.tm_zone,
+            reference.tm_zone,
+        ));
+    }
+    if diffs.is_empty() {
+        return Ok(());
+    }
+    Err(err!("struct tm fields differ: {}", diffs.join(", ")))
+}
+
+/// A production of the grammar accepted by the `FromStr` implementations of
+/// Jiff's datetime and duration types.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Production {
+    /// An RFC 3339 timestamp, parsed as a [`Timestamp`].
+    Timestamp,
+    /// An RFC 9557 zoned datetime, parsed as a [`Zoned`].
+    Zoned,
+    /// A civil datetime, parsed as a [`DateTime`].
+    DateTime,
+    /// A civil date, parsed as a [`Date`].
+    Date,
+    /// A civil time, parsed as a [`Time`].
+    Time,
+    /// An ISO 8601 or "friendly" duration, parsed as a [`Span`].
+    Span,
+    /// An ISO 8601 or "friendly" duration, parsed as a [`SignedDuration`].
+    SignedDuration,
+}
+
+impl Production {
+    /// Returns whether Jiff accepts the given input for this production.
+    pub fn accepts(self, input: &str) -> bool {
+        match self {
+            Production::Timestamp => input.parse::<Timestamp>().is_ok(),
+            Production::Zoned => input.parse::<Zoned>().is_ok(),
+            Production::DateTime => input.parse::<DateTime>().is_ok(),
+            Production::Date => input.parse::<Date>().is_ok(),
+            Production::Time => input.parse::<Time>().is_ok(),
+            Production::Span => input.parse::<Span>().is_ok(),
+            Production::SignedDuration => {
+                input.parse::<SignedDuration>().is_ok()
+            }
+        }
+    }
+}
+
+/// An input in [`TEMPORAL_CORPUS`] along with its expected validity.
+#[derive(Clone, Copy, Debug)]
+pub struct CorpusEntry {
+    /// The input string.
+    pub input: &'static str,
+    /// The production the input is checked against.
+    pub production: Production,
+    /// Whether the input is valid for the production.
+    pub valid: bool,
+}
+
+const fn ok(input: &'static str, production: Production) -> CorpusEntry {
+    CorpusEntry { input, production, valid: true }
+}
+
+const fn bad(input: &'static str, production: Production) -> CorpusEntry {
+    CorpusEntry { input, production, valid: false }
+}
+
+/// A corpus of inputs for the grammar accepted by Jiff's `FromStr`
+/// implementations.
+///
+/// None of the inputs require a time zone database. Bracketed time zone
+/// annotations only use fixed offsets.
+pub static TEMPORAL_CORPUS: &[CorpusEntry] = {
+    use self::Production::*;
+
+    &[
+        ok("2024-07-14T21:14:00Z", Timestamp),
+        ok("2024-07-14t21:14:00z", Timestamp),
+        ok("2024-07-14 21:14:00Z", Timestamp),
+        ok("2024-07-14T21:14:00.123456789Z", Timestamp),
+        ok("2024-07-14T21:14:00,5Z", Timestamp),
+        ok("2024-07-14T21:14:00-04", Timestamp),
+        ok("2024-07-14T21:14:00-04:00", Timestamp),
+        ok("2024-07-14T21:14:00-0400", Timestamp),
+        ok("2024-07-14T21:14Z", Timestamp),
+        ok("2024-07-14T21Z", Timestamp),
+        ok("+002024-07-14T21:14:00Z", Timestamp),
+        ok("2024-07-14T21:14:00-04[-04:00]", Timestamp),
+        bad("2024-07-14T21:14:00", Timestamp),
+        bad("2024-07-14", Timestamp),
+        bad("2024-07-14T21:14:00.1234567890Z", Timestamp),
+        bad("2024-07-14T24:00:00Z", Timestamp),
+        bad("-000000-01-01T00:00:00Z", Timestamp),
+        bad("2024-07-14T21:14:00+", Timestamp),
+        ok("2024-07-14T21:14:00-04:00[-04:00]", Zoned),
+        ok("2024-07-14T21:14:00[-04:00]", Zoned),
+        ok("2024-07-14T21:14:00-04[!-04:00]", Zoned),
+        ok("2024-07-14T21:14:00-04[-04:00][u-ca=iso8601]", Zoned),
+        bad("2024-07-14T21:14:00-04:00", Zoned),
+        bad("2024-07-14T21:14:00-05:00[-04:00]", Zoned),
+        bad("2024-07-14T21:14:00-04[-04:00][!u-ca=gregory]", Zoned),
+        ok("2024-07-14T21:14:00", DateTime),
+        ok("2024-07-14 21:14", DateTime),
+        ok("20240714T211400", DateTime),
+        ok("2024-07-14", DateTime),
+        ok("2024-07-14T21:14:00-04:00", DateTime),
+        bad("2024-07-14T21:14:00Z", DateTime),
+        bad("2024-02-30T00:00:00", DateTime),
+        ok("2024-07-14T23:59:60", DateTime),
+        bad("2024-07-14T21:14:61", DateTime),
+        ok("2024-07-14", Date),
+        ok("20240714", Date),
+        ok("-009999-01-01", Date),
+        ok("2024-07-14T21:14:00", Date),
+        bad("2024-7-14", Date),
+        bad("2023-02-29", Date),
+        bad("10000-01-01", Date),
+        ok("21:14:00", Time),
+        ok("21:14", Time),
+        ok("211400", Time),
+        ok("T21:14:00", Time),
+        ok("21:14:00.000000001", Time),
+        ok("2024-07-14T21:14:00", Time),
+        bad("24:00", Time),
+        bad("21:60", Time),
+        bad("2024-07-14", Time),
+        ok("P1Y2M3W4DT5H6M7S", Span),
+        ok("-P1D", Span),
+        ok("PT1.5H", Span),
+        ok("p1dt2h", Span),
+        ok("1 day 2 hours", Span),
+        ok("1d 2h ago", Span),
+        bad("P", Span),
+        bad("PT", Span),
+        bad("P1H", Span),
+        bad("PT1.5H2M", Span),
+        ok("PT1H2M3.123456789S", SignedDuration),
+        ok("-PT1H", SignedDuration),
+        ok("1h 2m 3s", SignedDuration),
+        bad("P1D", SignedDuration),
+        bad("P1Y", SignedDuration),
+    ]
+};
+
+/// Checks that Jiff agrees with every annotation in [`TEMPORAL_CORPUS`].
+///
+/// # Errors
+///
+/// This returns an error describing every entry that Jiff disagrees with.
+pub fn check_corpus() -> Result<(), Error> {
+    let mismatches: Vec<String> = TEMPORAL_CORPUS
+        .iter()
+        .filter(|entry| entry.production.accepts(entry.input) != entry.valid)
+        .map(|entry| {
+            alloc::format!(
+                "{input:?} as {production:?} (expected {expected})",
+                input = entry.input,
+                production = entry.production,
+                expected = if entry.valid { "valid" } else { "invalid" },
+            )
+        })
+        .collect();
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+    Err(err!("corpus mismatches: {}", mismatches.join(", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corpus() {
+        check_corpus().unwrap();
+    }
+
+    #[test]
+    fn localtime_and_mktime() {
+        let tz = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+
+        // 2024-07-14T21:14:00-04:00
+        let tm = localtime(&tz, 1_721_006_040).unwrap();
+        let expected = Tm {
+            tm_sec: 0,
+            tm_min: 14,
+            tm_hour: 21,
+            tm_mday: 14,
+            tm_mon: 6,
+            tm_year: 124,
+            tm_wday: 0,
+            tm_yday: 195,
+            tm_isdst: 1,
+            tm_gmtoff: -4 * 60 * 60,
+            tm_zone: "EDT".into(),
+        };
+        diff_tm(&tm, &expected).unwrap();
+        assert_eq!(mktime(&tz, &tm).unwrap(), 1_721_006_040);
+
+        // 2024-11-03T01:30 is in a fold.
+        let mut tm = Tm {
+            tm_min: 30,
+            tm_hour: 1,
+            tm_mday: 3,
+            tm_mon: 10,
+            tm_year: 124,
+            tm_isdst: -1,
+            ..Tm::default()
+        };
+        assert_eq!(mktime(&tz, &tm).unwrap(), 1_730_611_800);
+        tm.tm_isdst = 1;
+        assert_eq!(mktime(&tz, &tm).unwrap(), 1_730_611_800);
+        tm.tm_isdst = 0;
+        assert_eq!(mktime(&tz, &tm).unwrap(), 1_730_615_400);
+
+        tm.tm_mday = 32;
+        assert!(mktime(&tz, &tm).is_err());
+    }
+
+    #[test]
+    fn diff_tm_reports_fields() {
+        let tm = Tm::default();
+        let other = Tm { tm_hour: 1, tm_zone: "UTC".into(), ..Tm::default() };
+        insta::assert_snapshot!(
+            diff_tm(&tm, &other).unwrap_err(),
+            @r#"struct tm fields differ: tm_hour (jiff: 0, reference: 1), tm_zone (jiff: "", reference: "UTC")"#,
+        );
+    }
+}
@@ -0,0 +1,521 @@
+/*!
+Support for zero-copy serialization with [`rkyv`].
+
+This module is only available when the `rkyv` crate feature is enabled. It
+provides the archived forms of Jiff's datetime, span and offset types. That
+is, when the `rkyv` crate feature is enabled, the following types implement
+`rkyv`'s `Archive`, `Serialize` and `Deserialize` traits:
+
+* [`Timestamp`] is archived as an [`ArchivedTimestamp`].
+* [`civil::Date`] is archived as an [`ArchivedDate`].
+* [`civil::Time`] is archived as an [`ArchivedTime`].
+* [`civil::DateTime`] is archived as an [`ArchivedDateTime`].
+* [`Span`] is archived as an [`ArchivedSpan`].
+* [`SignedDuration`] is archived as an [`ArchivedSignedDuration`].
+* [`tz::Offset`] is archived as an [`ArchivedOffset`].
+
+Each archived type is a fixed size collection of integers. When the bytes of
+an archive are checked (e.g., via `rkyv::access`), the invariants of each
+archived value are validated too. For example, an `ArchivedDate` for February
+30 is rejected. This makes it safe to access archived values from untrusted
+sources, like a memory-mapped file, without deserializing them first.
+
+Each archived value can be converted back to its original type with
+`TryFrom`. This can only fail when the archive wasn't checked (e.g., when it
+was accessed via `rkyv::access_unchecked`) and it contains an invalid value.
+
+Note that `rkyv` requires a newer version of Rust than Jiff's minimum
+supported Rust version.
+
+# Example
+
+```
+use jiff::{archive::ArchivedTimestamp, Timestamp};
+
+let ts: Timestamp = "2024-06-14T17:30:00.123Z".parse()?;
+let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&ts)?;
+
+let archived =
+    rkyv::access::<ArchivedTimestamp, rkyv::rancor::Error>(&bytes)?;
+assert_eq!(Timestamp::try_from(archived)?, ts);
+
+let got = rkyv::deserialize::<Timestamp, rkyv::rancor::Error>(archived)?;
+assert_eq!(got, ts);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+[`rkyv`]: https://docs.rs/rkyv
+[`Timestamp`]: crate::Timestamp
+[`civil::Date`]: crate::civil::Date
+[`civil::Time`]: crate::civil::Time
+[`civil::DateTime`]: crate::civil::DateTime
+[`Span`]: crate::Span
+[`SignedDuration`]: crate::SignedDuration
+[`tz::Offset`]: crate::tz::Offset
+*/
+
+use rkyv::{
+    bytecheck::{CheckBytes, Verify},
+    munge::munge,
+    primitive::{ArchivedI16, ArchivedI32, ArchivedI64},
+    rancor::{Fallible, Source},
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+use crate::{
+    civil::{Date, DateTime, Time},
+    error::{err, Error},
+    tz::Offset,
+    SignedDuration, Span, Timestamp,
+};
+
+/// An archived [`Timestamp`].
+///
+/// This is made up of the whole seconds since the Unix epoch and the
+/// fractional nanoseconds of the timestamp.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedTimestamp {
+    second: ArchivedI64,
+    nanosecond: ArchivedI32,
+}
+
+impl Archive for Timestamp {
+    type Archived = ArchivedTimestamp;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: (), out: Place<ArchivedTimestamp>) {
+        munge!(let ArchivedTimestamp { second, nanosecond } = out);
+        self.as_second().resolve((), second);
+        self.subsec_nanosecond().resolve((), nanosecond);
+    }
+}
+
+impl TryFrom<&ArchivedTimestamp> for Timestamp {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(archived: &ArchivedTimestamp) -> Result<Timestamp, Error> {
+        Timestamp::new(
+            archived.second.to_native(),
+            archived.nanosecond.to_native(),
+        )
+    }
+}
+
+/// An archived [`civil::Date`](Date).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedDate {
+    year: ArchivedI16,
+    month: i8,
+    day: i8,
+}
+
+impl Archive for Date {
+    type Archived = ArchivedDate;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: (), out: Place<ArchivedDate>) {
+        munge!(let ArchivedDate { year, month, day } = out);
+        self.year().resolve((), year);
+        self.month().resolve((), month);
+        self.day().resolve((), day);
+    }
+}
+
+impl TryFrom<&ArchivedDate> for Date {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(archived: &ArchivedDate) -> Result<Date, Error> {
+        Date::new(archived.year.to_native(), archived.month, archived.day)
+    }
+}
+
+/// An archived [`civil::Time`](Time).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedTime {
+    hour: i8,
+    minute: i8,
+    second: i8,
+    subsec_nanosecond: ArchivedI32,
+}
+
+impl Archive for Time {
+    type Archived = ArchivedTime;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: (), out: Place<ArchivedTime>) {
+        munge!(
+            let ArchivedTime { hour, minute, second, subsec_nanosecond } = out
+        );
+        self.hour().resolve((), hour);
+        self.minute().resolve((), minute);
+        self.second().resolve((), second);
+        self.subsec_nanosecond().resolve((), subsec_nanosecond);
+    }
+}
+
+impl TryFrom<&ArchivedTime> for Time {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(archived: &ArchivedTime) -> Result<Time, Error> {
+        Time::new(
+            archived.hour,
+            archived.minute,
+            archived.second,
+            archived.subsec_nanosecond.to_native(),
+        )
+    }
+}
+
+/// An archived [`civil::DateTime`](DateTime).
+///
+/// This is made up of an archived date and an archived time, which are each
+/// validated independently.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(C)]
+pub struct ArchivedDateTime {
+    date: ArchivedDate,
+    time: ArchivedTime,
+}
+
+impl Archive for DateTime {
+    type Archived = ArchivedDateTime;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: (), out: Place<ArchivedDateTime>) {
+        munge!(let ArchivedDateTime { date, time } = out);
+        self.date().resolve((), date);
+        self.time().resolve((), time);
+    }
+}
+
+impl TryFrom<&ArchivedDateTime> for DateTime {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(archived: &ArchivedDateTime) -> Result<DateTime, Error> {
+        let date = Date::try_from(&archived.date)?;
+        let time = Time::try_from(&archived.time)?;
+        Ok(DateTime::from_parts(date, time))
+    }
+}
+
+/// An archived [`Span`].
+///
+/// This is made up of the value of each unit of the span, from years down to
+/// nanoseconds. Every non-zero unit has the same sign as the span.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedSpan {
+    years: ArchivedI16,
+    months: ArchivedI32,
+    weeks: ArchivedI32,
+    days: ArchivedI32,
+    hours: ArchivedI32,
+    minutes: ArchivedI64,
+    seconds: ArchivedI64,
+    milliseconds: ArchivedI64,
+    microseconds: ArchivedI64,
+    nanoseconds: ArchivedI64,
+}
+
+impl Archive for Span {
+    type Archived = ArchivedSpan;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: (), out: Place<ArchivedSpan>) {
+        munge!(
+            let ArchivedSpan {
+                years,
+                months,
+                weeks,
+                days,
+                hours,
+                minutes,
+                seconds,
+                milliseconds,
+                microseconds,
+                nanoseconds,
+            } = out
+        );
+        self.get_years().resolve((), years);
+        self.get_months().resolve((), months);
+        self.get_weeks().resolve((), weeks);
+        self.get_days().resolve((), days);
+        self.get_hours().resolve((), hours);
+        self.get_minutes().resolve((), minutes);
+        self.get_seconds().resolve((), seconds);
+        self.get_milliseconds().resolve((), milliseconds);
+        self.get_microseconds().resolve((), microseconds);
+        self.get_nanoseconds().resolve((), nanoseconds);
+    }
+}
+
+impl TryFrom<&ArchivedSpan> for Span {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(archived: &ArchivedSpan) -> Result<Span, Error> {
+        Span::try_from_units([
+            i64::from(archived.years.to_native()),
+            i64::from(archived.months.to_native()),
+            i64::from(archived.weeks.to_native()),
+            i64::from(archived.days.to_native()),
+            i64::from(archived.hours.to_native()),
+            archived.minutes.to_native(),
+            archived.seconds.to_native(),
+            archived.milliseconds.to_native(),
+            archived.microseconds.to_native(),
+            archived.nanoseconds.to_native(),
+        ])
+    }
+}
+
+/// An archived [`SignedDuration`].
+///
+/// This is made up of the whole seconds and the fractional nanoseconds of
+/// the duration, which always have the same sign (ignoring zero values).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedSignedDuration {
+    secs: ArchivedI64,
+    nanos: ArchivedI32,
+}
+
+impl Archive for SignedDuration {
+    type Archived = ArchivedSignedDuration;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: (), out: Place<ArchivedSignedDuration>) {
+        munge!(let ArchivedSignedDuration { secs, nanos } = out);
+        self.as_secs().resolve((), secs);
+        self.subsec_nanos().resolve((), nanos);
+    }
+}
+
+impl TryFrom<&ArchivedSignedDuration> for SignedDuration {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(
+        archived: &ArchivedSignedDuration,
+    ) -> Result<SignedDuration, Error> {
+        let (secs, nanos) =
+            (archived.secs.to_native(), archived.nanos.to_native());
+        if nanos.unsigned_abs() >= 1_000_000_000 {
+            return Err(err!(
+                "fractional nanoseconds {nanos} of signed duration \
+                 must be less than one second in magnitude",
+            ));
+        }
+        if (secs > 0 && nanos < 0) || (secs < 0 && nanos > 0) {
+            return Err(err!(
+                "seconds {secs} and fractional nanoseconds {nanos} \
+                 of signed duration must have the same sign",
+            ));
+        }
+        Ok(SignedDuration::new(secs, nanos))
+    }
+}
+
+/// An archived [`tz::Offset`](Offset).
+///
+/// This is made up of the number of seconds the offset is ahead of UTC.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedOffset {
+    seconds: ArchivedI32,
+}
+
+impl Archive for Offset {
+    type Archived = ArchivedOffset;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: (), out: Place<ArchivedOffset>) {
+        munge!(let ArchivedOffset { seconds } = out);
+        self.seconds().resolve((), seconds);
+    }
+}
+
+impl TryFrom<&ArchivedOffset> for Offset {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(archived: &ArchivedOffset) -> Result<Offset, Error> {
+        Offset::from_seconds(archived.seconds.to_native())
+    }
+}
+
+/// Implements `Serialize`, `Deserialize` and (when applicable) `Verify` for
+/// an archived type in terms of its `Archive` and `TryFrom` implementations.
+macro_rules! impl_archived {
+    ($ty:ty, $archived:ty) => {
+        impl_archived!($ty, $archived, no_verify);
+
+        // SAFETY: Validating the invariants of a value doesn't change what
+        // bytes are valid for its type. Our `CheckBytes` implementation is
+        // derived, and this only rejects more values.
+        unsafe impl<C> Verify<C> for $archived
+        where
+            C: Fallible + ?Sized,
+            C::Error: Source,
+        {
+            #[inline]
+            fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+                <$ty>::try_from(self).map(|_| ()).map_err(invalid)
+            }
+        }
+    };
+    ($ty:ty, $archived:ty, no_verify) => {
+        impl<S: Fallible + ?Sized> Serialize<S> for $ty {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D> Deserialize<$ty, D> for $archived
+        where
+            D: Fallible + ?Sized,
+            D::Error: Source,
+        {
+            #[inline]
+            fn deserialize(&self, _: &mut D) -> Result<$ty, D::Error> {
+                <$ty>::try_from(self).map_err(invalid)
+            }
+        }
+    };
+}
+
+impl_archived!(Timestamp, ArchivedTimestamp);
+impl_archived!(Date, ArchivedDate);
+impl_archived!(Time, ArchivedTime);
+impl_archived!(DateTime, ArchivedDateTime, no_verify);
+impl_archived!(Span, ArchivedSpan);
+impl_archived!(SignedDuration, ArchivedSignedDuration);
+impl_archived!(Offset, ArchivedOffset);
+
+/// Converts a Jiff error into an `rkyv` error.
+fn invalid<E: Source>(err: Error) -> E {
+    E::new(InvalidArchive(err))
+}
+
+/// The error reported to `rkyv` when an archived value is invalid.
+///
+/// This exists because `rkyv` requires errors to implement
+/// `core::error::Error`, but Jiff's error type only implements
+/// `std::error::Error` when the `std` feature is enabled.
+#[derive(Debug)]
+struct InvalidArchive(Error);
+
+impl core::fmt::Display for InvalidArchive {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl core::error::Error for InvalidArchive {}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::{
+        api::high::{HighDeserializer, HighSerializer, HighValidator},
+        rancor,
+        ser::allocator::ArenaHandle,
+        util::AlignedVec,
+    };
+
+    use crate::{civil::date, tz::offset, ToSpan};
+
+    use super::*;
+
+    fn roundtrip<T>(value: &T)
+    where
+        T: Archive
+            + core::fmt::Debug
+            + PartialEq
+            + for<'a> Serialize<
+                HighSerializer<AlignedVec, ArenaHandle<'a>, rancor::Error>,
+            >,
+        T::Archived: for<'a> CheckBytes<HighValidator<'a, rancor::Error>>
+            + Deserialize<T, HighDeserializer<rancor::Error>>,
+    {
+        let bytes = rkyv::to_bytes::<rancor::Error>(value).unwrap();
+        let archived =
+            rkyv::access::<T::Archived, rancor::Error>(&bytes).unwrap();
+        let got = rkyv::deserialize::<T, rancor::Error>(archived).unwrap();
+        assert_eq!(&got, value);
+    }
+
+    #[test]
+    fn roundtrip_all() {
+        roundtrip(&Timestamp::MIN);
+        roundtrip(&Timestamp::MAX);
+        roundtrip(&Timestamp::new(-1, -1).unwrap());
+        roundtrip(&Date::MIN);
+        roundtrip(&Date::MAX);
+        roundtrip(&Time::MAX);
+        roundtrip(&date(2024, 2, 29).at(23, 59, 59, 999_999_999));
+        roundtrip(&SignedDuration::MIN);
+        roundtrip(&SignedDuration::MAX);
+        roundtrip(&offset(-5));
+        roundtrip(&Offset::MAX);
+
+        let span = -1.year().weeks(2).hours(3).nanoseconds(4);
+        let bytes = rkyv::to_bytes::<rancor::Error>(&span).unwrap();
+        let archived =
+            rkyv::access::<ArchivedSpan, rancor::Error>(&bytes).unwrap();
+        let got = Span::try_from(archived).unwrap();
+        assert_eq!(got.fieldwise(), span.fieldwise());
+    }
+
+    #[test]
+    fn invalid_archives_are_rejected() {
+        let bytes = rkyv::to_bytes::<rancor::Error>(&date(2024, 2, 28))
+            .unwrap()
+            .to_vec();
+        let mut invalid = AlignedVec::<16>::new();
+        invalid.extend_from_slice(&bytes);
+        // The day is the last byte of an archived date.
+        let last = invalid.len() - 1;
+        invalid[last] = 30;
+        let err =
+            rkyv::access::<ArchivedDate, rancor::Error>(&invalid).unwrap_err();
+        insta::assert_snapshot!(
+            err,
+            @"parameter 'day' with value 30 is not in the required range of 1..=29",
+        );
+
+        let bytes = rkyv::to_bytes::<rancor::Error>(&1.hour()).unwrap();
+        let mut invalid = AlignedVec::<16>::new();
+        invalid.extend_from_slice(&bytes);
+        let start = core::mem::offset_of!(ArchivedSpan, minutes);
+        invalid[start..start + 8].copy_from_slice(&(-1i64).to_le_bytes());
+        let err =
+            rkyv::access::<ArchivedSpan, rancor::Error>(&invalid).unwrap_err();
+        insta::assert_snapshot!(
+            err,
+            @"units of a span must all have the same sign",
+        );
+    }
+}
@@ -0,0 +1,480 @@
+use alloc::vec::Vec;
+
+use crate::{
+    civil::{Date, Time, Weekday},
+    error::{err, Error, ErrorContext},
+    tz::TimeZone,
+    SignedDuration, Span, Zoned,
+};
+
+/// A description of when a business is open, for doing arithmetic in units
+/// of "business hours."
+///
+/// A business calendar consists of opening hours for each day of the week
+/// along with a set of holidays on which the business is closed all day.
+/// Opening hours are given in civil time, and are interpreted in the time
+/// zone of the datetime given to [`BusinessCalendar::add_business_hours`].
+/// This means that business hours account for time zone transitions. For
+/// example, if a business is open from `01:00` to `05:00` on a day where
+/// the clocks move forward from `02:00` to `03:00`, then that day has only
+/// three business hours.
+///
+/// A new business calendar is closed on every day of the week. Use
+/// [`BusinessCalendar::hours`] to set opening hours.
+///
+/// This type is only available when the `alloc` crate feature is enabled.
+///
+/// # Example
+///
+/// This shows how to compute a deadline for a service level agreement that
+/// is measured in business hours:
+///
+/// ```
+/// use jiff::{civil::{date, time, Weekday}, BusinessCalendar, ToSpan};
+///
+/// let mut cal = BusinessCalendar::new();
+/// for wd in [
+///     Weekday::Monday,
+///     Weekday::Tuesday,
+///     Weekday::Wednesday,
+///     Weekday::Thursday,
+///     Weekday::Friday,
+/// ] {
+///     cal = cal.hours(wd, time(9, 0, 0, 0), time(17, 0, 0, 0));
+/// }
+/// // Christmas Day is a holiday.
+/// let cal = cal.holiday(date(2024, 12, 25));
+///
+/// // A ticket opened on Friday afternoon with a deadline of 16 business
+/// // hours is due on Tuesday, since the weekend is skipped.
+/// let opened = date(2024, 12, 20).at(15, 0, 0, 0).in_tz("America/New_York")?;
+/// let deadline = cal.add_business_hours(&opened, 16.hours())?;
+/// assert_eq!(
+///     deadline.to_string(),
+///     "2024-12-24T15:00:00-05:00[America/New_York]",
+/// );
+/// // With a deadline of 19 business hours, it's due on Thursday, since the
+/// // holiday is skipped too.
+/// let deadline = cal.add_business_hours(&opened, 19.hours())?;
+/// assert_eq!(
+///     deadline.to_string(),
+///     "2024-12-26T10:00:00-05:00[America/New_York]",
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BusinessCalendar {
+    /// Opening hours, indexed by the Monday-zero offset of each weekday.
+    hours: [Option<(Time, Time)>; 7],
+    /// Sorted and deduplicated.
+    holidays: Vec<Date>,
+}
+
+impl BusinessCalendar {
+    /// Creates a new business calendar that is closed on every day of the
+    /// week and has no holidays.
+    #[inline]
+    pub fn new() -> BusinessCalendar {
+        BusinessCalendar { hours: [None; 7], holidays: Vec::new() }
+    }
+
+    /// Sets the opening hours for the given day of the week.
+    ///
+    /// The business is open from `open` (inclusive) until `close`
+    /// (exclusive). Opening hours that span midnight are not supported.
+    /// This replaces any opening hours previously set for this weekday.
+    ///
+    /// Note that `open` must precede `close`. Otherwise, business hour
+    /// arithmetic with this calendar will return an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::{date, time, Weekday}, BusinessCalendar};
+    ///
+    /// let cal = BusinessCalendar::new()
+    ///     .hours(Weekday::Saturday, time(10, 0, 0, 0), time(14, 0, 0, 0));
+    /// let zdt = date(2024, 6, 15).at(11, 0, 0, 0).in_tz("Europe/Paris")?;
+    /// assert!(cal.is_open(&zdt));
+    /// let zdt = date(2024, 6, 15).at(14, 0, 0, 0).in_tz("Europe/Paris")?;
+    /// assert!(!cal.is_open(&zdt));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn hours(
+        mut self,
+        weekday: Weekday,
+        open: Time,
+        close: Time,
+    ) -> BusinessCalendar {
+        self.hours[weekday_index(weekday)] = Some((open, close));
+        self
+    }
+
+    /// Marks the given day of the week as closed.
+    ///
+    /// This undoes any opening hours previously set for this weekday.
+    #[inline]
+    pub fn closed(mut self, weekday: Weekday) -> BusinessCalendar {
+        self.hours[weekday_index(weekday)] = None;
+        self
+    }
+
+    /// Adds a holiday on which the business is closed all day.
+    #[inline]
+    pub fn holiday(self, date: Date) -> BusinessCalendar {
+        self.holidays(core::iter::once(date))
+    }
+
+    /// Adds many holidays on which the business is closed all day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::{date, time, Weekday}, BusinessCalendar};
+    ///
+    /// let cal = BusinessCalendar::new()
+    ///     .hours(Weekday::Monday, time(9, 0, 0, 0), time(17, 0, 0, 0))
+    ///     .holidays([date(2024, 1, 1), date(2024, 5, 27)]);
+    /// let zdt = date(2024, 5, 27).at(12, 0, 0, 0).in_tz("America/Chicago")?;
+    /// assert!(!cal.is_open(&zdt));
+    /// let zdt = date(2024, 6, 3).at(12, 0, 0, 0).in_tz("America/Chicago")?;
+    /// assert!(cal.is_open(&zdt));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn holidays<I: IntoIterator<Item = Date>>(
+        mut self,
+        dates: I,
+    ) -> BusinessCalendar {
+        self.holidays.extend(dates);
+        self.holidays.sort();
+        self.holidays.dedup();
+        self
+    }
+
+    /// Returns true if the business is open at the given datetime.
+    ///
+    /// This uses the civil datetime of the given zoned datetime. That is,
+    /// opening hours are interpreted in its time zone.
+    pub fn is_open(&self, zdt: &Zoned) -> bool {
+        let Some((open, close)) = self.hours_on(zdt.date()) else {
+            return false;
+        };
+        open <= zdt.time() && zdt.time() < close
+    }
+
+    /// Adds the given number of business hours to the given datetime.
+    ///
+    /// Time only elapses while the business is open. So if the given
+    /// datetime is outside of opening hours, then counting starts at the
+    /// next time the business opens. When the span is negative, business
+    /// hours are subtracted instead, and counting starts at the previous
+    /// time the business closed.
+    ///
+    /// The span given must not have any units of days or greater. Business
+    /// time is measured in actual elapsed time, so days spent closed or
+    /// time zone transitions that occur while open are accounted for.
+    ///
+    /// When the end of the span coincides with closing time, the closing
+    /// time is returned (instead of the opening time of the next business
+    /// day). Similarly, when subtracting, the opening time is returned.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the span has non-zero units of days or
+    /// greater, if the business is never open (i.e., no opening hours are
+    /// set), if any opening hours have an opening time that doesn't precede
+    /// their closing time, or if the result overflows Jiff's supported
+    /// range.
+    ///
+    /// # Example: time zone transitions
+    ///
+    /// This shows that business hours are measured in elapsed time, even
+    /// across a time zone transition:
+    ///
+    /// ```
+    /// use jiff::{civil::{date, time, Weekday}, BusinessCalendar, ToSpan};
+    ///
+    /// let cal = BusinessCalendar::new()
+    ///     .hours(Weekday::Sunday, time(0, 0, 0, 0), time(6, 0, 0, 0));
+    /// // Clocks moved forward at 02:00 on this day.
+    /// let start = date(2024, 3, 10).in_tz("America/New_York")?;
+    /// let end = cal.add_business_hours(&start, 3.hours())?;
+    /// assert_eq!(
+    ///     end.to_string(),
+    ///     "2024-03-10T04:00:00-04:00[America/New_York]",
+    /// );
+    /// // Only 5 business hours were available on this day, so the rest
+    /// // roll over to the next Sunday.
+    /// let end = cal.add_business_hours(&start, 6.hours())?;
+    /// assert_eq!(
+    ///     end.to_string(),
+    ///     "2024-03-17T01:00:00-04:00[America/New_York]",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Example: subtracting business hours
+    ///
+    /// ```
+    /// use jiff::{civil::{date, time, Weekday}, BusinessCalendar, ToSpan};
+    ///
+    /// let cal = BusinessCalendar::new()
+    ///     .hours(Weekday::Monday, time(9, 0, 0, 0), time(17, 0, 0, 0))
+    ///     .hours(Weekday::Friday, time(9, 0, 0, 0), time(12, 0, 0, 0));
+    /// let zdt = date(2024, 6, 17).at(10, 30, 0, 0).in_tz("Asia/Tokyo")?;
+    /// let got = cal.add_business_hours(&zdt, -2.hours())?;
+    /// assert_eq!(got.to_string(), "2024-06-14T11:30:00+09:00[Asia/Tokyo]");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_business_hours(
+        &self,
+        zdt: &Zoned,
+        span: Span,
+    ) -> Result<Zoned, Error> {
+        let duration = SignedDuration::try_from(span)
+            .context("failed to convert span to business hours")?;
+        self.validate()?;
+        if duration.is_negative() {
+            let duration = duration.checked_neg().ok_or_else(|| {
+                err!("failed to negate business hours {duration:?}")
+            })?;
+            self.sub_business_duration(zdt, duration)
+        } else {
+            self.add_business_duration(zdt, duration)
+        }
+    }
+
+    fn add_business_duration(
+        &self,
+        zdt: &Zoned,
+        mut remaining: SignedDuration,
+    ) -> Result<Zoned, Error> {
+        let tz = zdt.time_zone();
+        let mut cursor = zdt.clone();
+        loop {
+            let date = cursor.date();
+            if let Some((open, close)) = self.window_on(date, tz)? {
+                if cursor < open {
+                    cursor = open;
+                }
+                if cursor < close {
+                    let available = close.duration_since(&cursor);
+                    if remaining <= available {
+                        return cursor.checked_add(remaining);
+                    }
+                    remaining -= available;
+                }
+            }
+            cursor = date.tomorrow()?.to_zoned(tz.clone())?;
+        }
+    }
+
+    fn sub_business_duration(
+        &self,
+        zdt: &Zoned,
+        mut remaining: SignedDuration,
+    ) -> Result<Zoned, Error> {
+        let tz = zdt.time_zone();
+        let mut cursor = zdt.clone();
+        loop {
+            let date = cursor.date();
+            if let Some((open, close)) = self.window_on(date, tz)? {
+                if cursor > close {
+                    cursor = close;
+                }
+                if cursor > open {
+                    let available = cursor.duration_since(&open);
+                    if remaining <= available {
+                        return cursor.checked_sub(remaining);
+                    }
+                    remaining -= available;
+                }
+            }
+            cursor = date
+                .yesterday()?
+                .to_datetime(Time::MAX)
+                .to_zoned(tz.clone())?;
+        }
+    }
+
+    /// Returns the opening and closing times on the given date in the given
+    /// time zone, or `None` if the business is closed on that date.
+    fn window_on(
+        &self,
+        date: Date,
+        tz: &TimeZone,
+    ) -> Result<Option<(Zoned, Zoned)>, Error> {
+        let Some((open, close)) = self.hours_on(date) else {
+            return Ok(None);
+        };
+        let open = date.to_datetime(open).to_zoned(tz.clone())?;
+        let close = date.to_datetime(close).to_zoned(tz.clone())?;
+        Ok(Some((open, close)))
+    }
+
+    /// Returns the opening hours on the given date, or `None` if the
+    /// business is closed on that date.
+    fn hours_on(&self, date: Date) -> Option<(Time, Time)> {
+        if self.holidays.binary_search(&date).is_ok() {
+            return None;
+        }
+        self.hours[weekday_index(date.weekday())]
+    }
+
+    /// Checks that business hour arithmetic with this calendar terminates.
+    fn validate(&self) -> Result<(), Error> {
+        let mut any_open = false;
+        for (i, hours) in self.hours.iter().enumerate() {
+            let Some((open, close)) = *hours else { continue };
+            if open >= close {
+                // OK because `i` is always in the range `0..7`.
+                let weekday =
+                    Weekday::from_monday_zero_offset(i as i8).unwrap();
+                return Err(err!(
+                    "opening hours for {weekday:?} are invalid because \
+                     the opening time {open} does not precede the \
+                     closing time {close}",
+                ));
+            }
+            any_open = true;
+        }
+        if !any_open {
+            return Err(err!(
+                "business calendar has no opening hours on any day of \
+                 the week",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn weekday_index(weekday: Weekday) -> usize {
+    // OK because the offset is always in the range `0..7`.
+    usize::try_from(weekday.to_monday_zero_offset()).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{
+        civil::{date, time},
+        ToSpan,
+    };
+
+    use super::*;
+
+    fn weekdays_9_to_5() -> BusinessCalendar {
+        let mut cal = BusinessCalendar::new();
+        for wd in [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+        ] {
+            cal = cal.hours(wd, time(9, 0, 0, 0), time(17, 0, 0, 0));
+        }
+        cal
+    }
+
+    #[test]
+    fn add_business_hours() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let cal = weekdays_9_to_5().holiday(date(2024, 7, 4));
+        let add = |start: &str, span: Span| {
+            let start: Zoned = start.parse().unwrap();
+            cal.add_business_hours(&start, span).unwrap().to_string()
+        };
+
+        insta::assert_snapshot!(
+            add("2024-07-01T08:00[America/New_York]", 1.hour()),
+            @"2024-07-01T10:00:00-04:00[America/New_York]",
+        );
+        insta::assert_snapshot!(
+            add("2024-07-01T16:30[America/New_York]", 30.minutes()),
+            @"2024-07-01T17:00:00-04:00[America/New_York]",
+        );
+        insta::assert_snapshot!(
+            add("2024-07-01T16:30[America/New_York]", 31.minutes()),
+            @"2024-07-02T09:01:00-04:00[America/New_York]",
+        );
+        insta::assert_snapshot!(
+            add("2024-07-03T12:00[America/New_York]", 8.hours()),
+            @"2024-07-05T12:00:00-04:00[America/New_York]",
+        );
+        insta::assert_snapshot!(
+            add("2024-07-06T12:00[America/New_York]", 0.hours()),
+            @"2024-07-08T09:00:00-04:00[America/New_York]",
+        );
+        insta::assert_snapshot!(
+            add("2024-07-01T12:00[America/New_York]", 100.hours()),
+            @"2024-07-18T16:00:00-04:00[America/New_York]",
+        );
+        insta::assert_snapshot!(
+            add("2024-07-08T10:00[America/New_York]", -9.hours()),
+            @"2024-07-05T09:00:00-04:00[America/New_York]",
+        );
+        insta::assert_snapshot!(
+            add("2024-07-06T12:00[America/New_York]", -1.minute()),
+            @"2024-07-05T16:59:00-04:00[America/New_York]",
+        );
+        insta::assert_snapshot!(
+            add("2024-07-01T09:00[America/New_York]", -0.hours()),
+            @"2024-07-01T09:00:00-04:00[America/New_York]",
+        );
+    }
+
+    #[test]
+    fn add_business_hours_across_fold() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        // Clocks moved backward at 02:00 on this day, so the 1 o'clock hour
+        // happens twice.
+        let cal = BusinessCalendar::new().hours(
+            Weekday::Sunday,
+            time(0, 0, 0, 0),
+            time(4, 0, 0, 0),
+        );
+        let start: Zoned =
+            "2024-11-03T00:00[America/New_York]".parse().unwrap();
+        let got = cal.add_business_hours(&start, 4.hours()).unwrap();
+        insta::assert_snapshot!(got, @"2024-11-03T03:00:00-05:00[America/New_York]");
+        let got = cal.add_business_hours(&start, 5.hours()).unwrap();
+        insta::assert_snapshot!(got, @"2024-11-03T04:00:00-05:00[America/New_York]");
+    }
+
+    #[test]
+    fn err_add_business_hours() {
+        let start = date(2024, 7, 1).to_zoned(TimeZone::UTC).unwrap();
+
+        insta::assert_snapshot!(
+            BusinessCalendar::new()
+                .add_business_hours(&start, 1.hour())
+                .unwrap_err(),
+            @"business calendar has no opening hours on any day of the week",
+        );
+        insta::assert_snapshot!(
+            weekdays_9_to_5()
+                .hours(Weekday::Monday, time(17, 0, 0, 0), time(9, 0, 0, 0))
+                .add_business_hours(&start, 1.hour())
+                .unwrap_err(),
+            @"opening hours for Monday are invalid because the opening time 17:00:00 does not precede the closing time 09:00:00",
+        );
+        insta::assert_snapshot!(
+            weekdays_9_to_5().add_business_hours(&start, 1.day()).unwrap_err(),
+            @"failed to convert span to business hours: failed to convert span to duration without relative datetime (must use `Span::to_duration` instead): using unit 'day' in a span or configuration requires that either a relative reference time be given or `SpanRelativeTo::days_are_24_hours()` is used to indicate invariant 24-hour days, but neither were provided",
+        );
+    }
+}
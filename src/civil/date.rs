@@ -1,7 +1,12 @@
 use core::time::Duration as UnsignedDuration;
 
+#[cfg(feature = "rand")]
+use rand::distr::uniform::{
+    SampleBorrow, SampleUniform, UniformInt, UniformSampler,
+};
+
 use crate::{
-    civil::{DateTime, Era, ISOWeekDate, Time, Weekday},
+    civil::{DateTime, Era, ISOWeekDate, MonthGrid, Time, Weekday},
     duration::{Duration, SDuration},
     error::{err, Error, ErrorContext},
     fmt::{
@@ -17,6 +22,12 @@ use crate::{
     RoundMode, SignedDuration, Span, SpanRound, Unit, Zoned,
 };
 
+/// The Julian Day Number of the Unix epoch, `1970-01-01`.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+/// The Rata Die day number of the Unix epoch, `1970-01-01`.
+const UNIX_EPOCH_RATA_DIE: i64 = 719_163;
+
 /// A representation of a civil date in the Gregorian calendar.
 ///
 /// A `Date` value corresponds to a triple of year, month and day. Every `Date`
@@ -1340,6 +1351,222 @@ impl Date {
         )
     }
 
+    /// Returns an order preserving encoding of this date as a byte array.
+    ///
+    /// Comparing two encodings byte-wise (i.e., lexicographically) gives the
+    /// same result as comparing the dates themselves. This makes the encoding
+    /// suitable for use as a key in ordered key-value stores like LMDB or
+    /// RocksDB.
+    ///
+    /// The layout is 4 bytes: the year as an `i16` in big-endian byte order
+    /// with its sign bit flipped, followed by the month and the day as a
+    /// single byte each. Big-endian byte order is required because a
+    /// little-endian encoding cannot be sorted byte-wise. Flipping the sign
+    /// bit makes negative years sort before positive years.
+    ///
+    /// This layout is stable and will not change in semver compatible
+    /// releases. Use [`Date::from_sortable_bytes`] to decode it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// assert_eq!(date(2024, 6, 15).to_sortable_bytes(), [0x87, 0xE8, 6, 15]);
+    ///
+    /// // Byte-wise order matches the order of dates.
+    /// let dates = [date(-1, 12, 31), date(0, 1, 1), date(2024, 3, 1)];
+    /// for pair in dates.windows(2) {
+    ///     let (a, b) = (pair[0], pair[1]);
+    ///     assert!(a.to_sortable_bytes() < b.to_sortable_bytes());
+    /// }
+    /// ```
+    #[inline]
+    pub fn to_sortable_bytes(self) -> [u8; 4] {
+        let [y0, y1] = (self.year() ^ i16::MIN).to_be_bytes();
+        // OK because the month and day are always positive.
+        [y0, y1, self.month() as u8, self.day() as u8]
+    }
+
+    /// Creates a date from its order preserving encoding as a byte array.
+    ///
+    /// This is the inverse of [`Date::to_sortable_bytes`]. See its
+    /// documentation for a description of the layout.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the encoded year, month and day don't
+    /// correspond to a valid date. That is, in the same circumstances as
+    /// [`Date::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, Date};
+    ///
+    /// let d = date(-2024, 2, 29);
+    /// assert_eq!(Date::from_sortable_bytes(d.to_sortable_bytes())?, d);
+    ///
+    /// // Invalid dates are rejected.
+    /// assert!(Date::from_sortable_bytes([0x87, 0xE8, 2, 30]).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_sortable_bytes(bytes: [u8; 4]) -> Result<Date, Error> {
+        let [y0, y1, month, day] = bytes;
+        let year =
+            Year::try_new("year", i16::from_be_bytes([y0, y1]) ^ i16::MIN)?;
+        let month = Month::try_new("month", month)?;
+        let day = Day::try_new("day", day)?;
+        Date::new_ranged(year, month, day)
+    }
+
+    /// Returns the Julian Day Number (JDN) of this date.
+    ///
+    /// The Julian Day Number is a continuous count of days that is widely
+    /// used in astronomy and in scientific and historical datasets. Day `0`
+    /// is `-4713-11-24` in the proleptic Gregorian calendar (which is
+    /// January 1, 4713 BC in the proleptic Julian calendar). The Unix epoch,
+    /// `1970-01-01`, has a Julian Day Number of `2_440_588`.
+    ///
+    /// Strictly speaking, a Julian day starts at noon UTC. The number
+    /// returned here is the Julian Day Number of the Julian day that starts
+    /// at noon on this date. To get the fractional Julian Date of a precise
+    /// instant, use
+    /// [`Timestamp::to_julian_date`](crate::Timestamp::to_julian_date).
+    ///
+    /// Note that Jiff dates are always in the proleptic Gregorian calendar.
+    /// Dates in historical sources before the adoption of the Gregorian
+    /// calendar are often written in the Julian calendar. These can be
+    /// converted to a `Date` first with
+    /// [`GregorianCutover`](crate::civil::GregorianCutover).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// assert_eq!(date(1970, 1, 1).to_julian_day(), 2_440_588);
+    /// assert_eq!(date(2000, 1, 1).to_julian_day(), 2_451_545);
+    /// // The first day of the Gregorian calendar.
+    /// assert_eq!(date(1582, 10, 15).to_julian_day(), 2_299_161);
+    /// assert_eq!(date(-4713, 11, 24).to_julian_day(), 0);
+    /// ```
+    #[inline]
+    pub fn to_julian_day(self) -> i64 {
+        i64::from(self.to_unix_epoch_day().get()) + UNIX_EPOCH_JULIAN_DAY
+    }
+
+    /// Creates a date from its Julian Day Number (JDN).
+    ///
+    /// This is the inverse of [`Date::to_julian_day`]. See its documentation
+    /// for more details on Julian Day Numbers.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the date corresponding to the given Julian
+    /// Day Number is outside the range supported by Jiff. That is, when it's
+    /// less than `Date::MIN.to_julian_day()` or greater than
+    /// `Date::MAX.to_julian_day()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, Date};
+    ///
+    /// assert_eq!(Date::from_julian_day(2_460_677)?, date(2025, 1, 1));
+    /// assert_eq!(Date::from_julian_day(0)?, date(-4713, 11, 24));
+    ///
+    /// let too_big = Date::MAX.to_julian_day() + 1;
+    /// assert!(Date::from_julian_day(too_big).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_julian_day(jdn: i64) -> Result<Date, Error> {
+        let day = jdn
+            .checked_sub(UNIX_EPOCH_JULIAN_DAY)
+            .and_then(UnixEpochDay::new)
+            .ok_or_else(|| {
+                err!(
+                    "Julian Day Number {jdn} is not in the required range \
+                     of {min}..={max}",
+                    min = Date::MIN.to_julian_day(),
+                    max = Date::MAX.to_julian_day(),
+                )
+            })?;
+        Ok(Date::from_unix_epoch_day(day))
+    }
+
+    /// Returns the Rata Die (R.D.) day number of this date.
+    ///
+    /// Rata Die is a continuous count of days in the proleptic Gregorian
+    /// calendar, where `0001-01-01` is day `1` (and so `0000-12-31` is day
+    /// `0`). It is the "fixed date" used as a common interchange integer by
+    /// many calendrical algorithms, including those in _Calendrical
+    /// Calculations_ by Dershowitz and Reingold. It is also the same as the
+    /// proleptic Gregorian ordinal returned by Python's `date.toordinal()`.
+    ///
+    /// Dates before `0001-01-01` have a Rata Die number less than `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// assert_eq!(date(1, 1, 1).to_rata_die(), 1);
+    /// assert_eq!(date(0, 12, 31).to_rata_die(), 0);
+    /// assert_eq!(date(1970, 1, 1).to_rata_die(), 719_163);
+    /// assert_eq!(date(2025, 1, 1).to_rata_die(), 739_252);
+    /// ```
+    #[inline]
+    pub fn to_rata_die(self) -> i64 {
+        i64::from(self.to_unix_epoch_day().get()) + UNIX_EPOCH_RATA_DIE
+    }
+
+    /// Creates a date from its Rata Die (R.D.) day number.
+    ///
+    /// This is the inverse of [`Date::to_rata_die`]. See its documentation
+    /// for more details on Rata Die.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the date corresponding to the given Rata
+    /// Die number is outside the range supported by Jiff. That is, when it's
+    /// less than `Date::MIN.to_rata_die()` or greater than
+    /// `Date::MAX.to_rata_die()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, Date};
+    ///
+    /// assert_eq!(Date::from_rata_die(1)?, date(1, 1, 1));
+    /// assert_eq!(Date::from_rata_die(739_252)?, date(2025, 1, 1));
+    /// assert_eq!(Date::from_rata_die(-1)?, date(0, 12, 30));
+    ///
+    /// let too_big = Date::MAX.to_rata_die() + 1;
+    /// assert!(Date::from_rata_die(too_big).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_rata_die(rd: i64) -> Result<Date, Error> {
+        let day = rd
+            .checked_sub(UNIX_EPOCH_RATA_DIE)
+            .and_then(UnixEpochDay::new)
+            .ok_or_else(|| {
+                err!(
+                    "Rata Die day number {rd} is not in the required range \
+                     of {min}..={max}",
+                    min = Date::MIN.to_rata_die(),
+                    max = Date::MAX.to_rata_die(),
+                )
+            })?;
+        Ok(Date::from_unix_epoch_day(day))
+    }
+
     /// Add the given span of time to this date. If the sum would overflow the
     /// minimum or maximum date values, then an error is returned.
     ///
@@ -2026,6 +2253,49 @@ impl Date {
     pub fn series(self, period: Span) -> DateSeries {
         DateSeries { start: self, period, step: 0 }
     }
+
+    /// Returns the 6x7 grid of dates for the month in which this date
+    /// resides, as rendered by a typical calendar widget.
+    ///
+    /// Each row of the grid is a week starting on `week_start`. Cells before
+    /// the first day of the month and after the last day of the month are
+    /// filled with dates from the adjacent months, and are flagged as being
+    /// outside of the month. See [`MonthGrid`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any date in the grid would fall outside of
+    /// Jiff's supported range. This can only happen for the very first or
+    /// very last month supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// This shows a grid whose weeks span two years:
+    ///
+    /// ```
+    /// use jiff::civil::{date, Weekday};
+    ///
+    /// let grid = date(2021, 1, 15).month_grid(Weekday::Sunday)?;
+    /// let first_week: Vec<(i16, i8, bool)> = grid.weeks()[0]
+    ///     .iter()
+    ///     .map(|d| (d.date().year(), d.date().day(), d.in_month()))
+    ///     .collect();
+    /// assert_eq!(first_week, vec![
+    ///     (2020, 27, false),
+    ///     (2020, 28, false),
+    ///     (2020, 29, false),
+    ///     (2020, 30, false),
+    ///     (2020, 31, false),
+    ///     (2021, 1, true),
+    ///     (2021, 2, true),
+    /// ]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn month_grid(self, week_start: Weekday) -> Result<MonthGrid, Error> {
+        MonthGrid::new(self, week_start)
+    }
 }
 
 /// Parsing and formatting using a "printf"-style API.
@@ -2309,6 +2579,167 @@ impl<'a> From<&'a Zoned> for Date {
     }
 }
 
+/// Converts a Jiff civil date to a `chrono` naive date.
+///
+/// This never fails since `chrono` supports a superset of the dates
+/// supported by Jiff.
+#[cfg(feature = "chrono")]
+impl From<Date> for chrono::NaiveDate {
+    #[inline]
+    fn from(date: Date) -> chrono::NaiveDate {
+        let (year, month, day) = (
+            i32::from(date.year()),
+            u32::from(date.month().unsigned_abs()),
+            u32::from(date.day().unsigned_abs()),
+        );
+        // OK because every Jiff date is a valid `chrono` date.
+        chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
+
+/// Converts a `chrono` naive date to a Jiff civil date.
+///
+/// This returns an error when the date is outside of Jiff's supported range.
+/// That is, when its year is not in the range `-9999..=9999`.
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for Date {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(date: chrono::NaiveDate) -> Result<Date, Error> {
+        use chrono::Datelike;
+
+        let year = i16::try_from(date.year()).map_err(|_| {
+            err!("chrono date {date} is outside Jiff's supported range")
+        })?;
+        // OK because `chrono` guarantees that the month and day are valid.
+        let (month, day) = (date.month() as i8, date.day() as i8);
+        Date::new(year, month, day)
+    }
+}
+
+/// Converts a Jiff civil date to a `time` date.
+///
+/// This never fails since `time` supports a superset of the dates supported
+/// by Jiff.
+#[cfg(feature = "time")]
+impl From<Date> for ::time::Date {
+    #[inline]
+    fn from(date: Date) -> ::time::Date {
+        let (year, month, day) = (
+            i32::from(date.year()),
+            date.month().unsigned_abs(),
+            date.day().unsigned_abs(),
+        );
+        // OK because every Jiff date is a valid `time` date.
+        let month = ::time::Month::try_from(month).unwrap();
+        ::time::Date::from_calendar_date(year, month, day).unwrap()
+    }
+}
+
+/// Converts a `time` date to a Jiff civil date.
+///
+/// This returns an error when the date is outside of Jiff's supported range.
+/// This can only happen when the `large-dates` feature of the `time` crate
+/// is enabled.
+#[cfg(feature = "time")]
+impl TryFrom<::time::Date> for Date {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(date: ::time::Date) -> Result<Date, Error> {
+        let year = i16::try_from(date.year()).map_err(|_| {
+            err!("time date {date} is outside Jiff's supported range")
+        })?;
+        // OK because `time` guarantees that the month and day are valid.
+        let (month, day) = (u8::from(date.month()) as i8, date.day() as i8);
+        Date::new(year, month, day)
+    }
+}
+
+/// Samples dates uniformly at random from a range.
+///
+/// See [`DateUniform`] for more details.
+#[cfg(feature = "rand")]
+impl SampleUniform for Date {
+    type Sampler = DateUniform;
+}
+
+/// A sampler for picking civil dates uniformly at random from a range.
+///
+/// This is the [`UniformSampler`](rand::distr::uniform::UniformSampler)
+/// implementation for [`Date`]. It is available when the `rand` crate feature
+/// is enabled. Callers usually don't need to use it directly. Instead, use
+/// `rand::distr::Uniform` or `rand::RngExt::random_range` with a range of
+/// dates.
+///
+/// Every day in the range is equally likely to be picked.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::date;
+/// use rand::{rngs::StdRng, RngExt, SeedableRng};
+///
+/// let start = date(2024, 2, 1);
+/// let end = date(2024, 2, 29);
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// for _ in 0..100 {
+///     let d = rng.random_range(start..=end);
+///     assert_eq!((d.year(), d.month()), (2024, 2));
+/// }
+/// ```
+#[cfg(feature = "rand")]
+#[derive(Clone, Copy, Debug)]
+pub struct DateUniform {
+    days: UniformInt<i32>,
+}
+
+#[cfg(feature = "rand")]
+impl UniformSampler for DateUniform {
+    type X = Date;
+
+    #[inline]
+    fn new<B1, B2>(
+        low: B1,
+        high: B2,
+    ) -> Result<DateUniform, rand::distr::uniform::Error>
+    where
+        B1: SampleBorrow<Date> + Sized,
+        B2: SampleBorrow<Date> + Sized,
+    {
+        let days = UniformInt::new(
+            low.borrow().to_unix_epoch_day().get(),
+            high.borrow().to_unix_epoch_day().get(),
+        )?;
+        Ok(DateUniform { days })
+    }
+
+    #[inline]
+    fn new_inclusive<B1, B2>(
+        low: B1,
+        high: B2,
+    ) -> Result<DateUniform, rand::distr::uniform::Error>
+    where
+        B1: SampleBorrow<Date> + Sized,
+        B2: SampleBorrow<Date> + Sized,
+    {
+        let days = UniformInt::new_inclusive(
+            low.borrow().to_unix_epoch_day().get(),
+            high.borrow().to_unix_epoch_day().get(),
+        )?;
+        Ok(DateUniform { days })
+    }
+
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Date {
+        // OK because the sampled value is always between two valid dates.
+        let day = UnixEpochDay::new(self.days.sample(rng)).unwrap();
+        Date::from_unix_epoch_day(day)
+    }
+}
+
 /// Adds a span of time to a date.
 ///
 /// This uses checked arithmetic and panics on overflow. To handle overflow
@@ -2526,6 +2957,20 @@ impl<'de> serde::Deserialize<'de> for Date {
     }
 }
 
+#[cfg(feature = "utoipa")]
+impl utoipa::PartialSchema for Date {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::util::schema::string(
+            Some(utoipa::openapi::schema::KnownFormat::Date),
+            "A civil date, as an ISO 8601 calendar date.",
+            "2024-06-15",
+        )
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl utoipa::ToSchema for Date {}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for Date {
     fn arbitrary(g: &mut quickcheck::Gen) -> Date {
@@ -2546,6 +2991,22 @@ impl quickcheck::Arbitrary for Date {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Date {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Date> {
+        let year: Year = u.arbitrary()?;
+        let month: Month = u.arbitrary()?;
+        let day: Day = u.arbitrary()?;
+        Ok(Date::constrain_ranged(year, month, day))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(Year, Month, Day)>::size_hint(depth)
+    }
+}
+
 /// An iterator over periodic dates, created by [`Date::series`].
 ///
 /// It is exhausted when the next value would exceed a [`Span`] or [`Date`]
@@ -4029,6 +4490,12 @@ mod tests {
             let got = d2.checked_sub(span).unwrap();
             d1 == got
         }
+
+        fn prop_sortable_bytes(d1: Date, d2: Date) -> bool {
+            let (b1, b2) = (d1.to_sortable_bytes(), d2.to_sortable_bytes());
+            Date::from_sortable_bytes(b1).unwrap() == d1
+                && d1.cmp(&d2) == b1.cmp(&b2)
+        }
     }
 
     /// # `serde` deserializer compatibility test
@@ -4065,4 +4532,126 @@ mod tests {
         let d2 = d1.nth_weekday_of_month(5, Weekday::Saturday).unwrap();
         assert_eq!(d2, date(1998, 1, 31));
     }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_date() {
+        use alloc::string::ToString;
+
+        let to = |d: Date| chrono::NaiveDate::from(d);
+        let from = |y, m, d| {
+            Date::try_from(chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap())
+        };
+
+        assert_eq!(to(date(2024, 2, 29)).to_string(), "2024-02-29");
+        assert_eq!(from(2024, 2, 29).unwrap(), date(2024, 2, 29));
+        assert_eq!(Date::try_from(to(Date::MIN)).unwrap(), Date::MIN);
+        assert_eq!(Date::try_from(to(Date::MAX)).unwrap(), Date::MAX);
+        assert!(from(-10_000, 12, 31).is_err());
+        assert!(from(10_000, 1, 1).is_err());
+        assert!(from(100_000, 1, 1).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_date() {
+        use ::time::macros::date as tdate;
+
+        assert_eq!(
+            ::time::Date::from(date(2024, 2, 29)),
+            tdate!(2024 - 02 - 29)
+        );
+        assert_eq!(
+            Date::try_from(tdate!(2024 - 02 - 29)).unwrap(),
+            date(2024, 2, 29)
+        );
+        assert_eq!(
+            Date::try_from(::time::Date::from(Date::MIN)).unwrap(),
+            Date::MIN
+        );
+        assert_eq!(
+            Date::try_from(::time::Date::from(Date::MAX)).unwrap(),
+            Date::MAX
+        );
+    }
+
+    #[test]
+    fn rata_die() {
+        assert_eq!(date(1, 1, 1).to_rata_die(), 1);
+        assert_eq!(date(1970, 1, 1).to_rata_die(), 719_163);
+        // Rata Die and Julian Day Numbers differ by a constant.
+        for d in [Date::MIN, date(1, 1, 1), date(2024, 2, 29), Date::MAX] {
+            let rd = d.to_rata_die();
+            assert_eq!(Date::from_rata_die(rd).unwrap(), d);
+            assert_eq!(d.to_julian_day() - rd, 1_721_425);
+        }
+
+        insta::assert_snapshot!(
+            Date::from_rata_die(Date::MAX.to_rata_die() + 1).unwrap_err(),
+            @"Rata Die day number 3652060 is not in the required range of -3652424..=3652059",
+        );
+        insta::assert_snapshot!(
+            Date::from_rata_die(i64::MIN).unwrap_err(),
+            @"Rata Die day number -9223372036854775808 is not in the required range of -3652424..=3652059",
+        );
+    }
+
+    #[test]
+    fn julian_day() {
+        assert_eq!(date(1970, 1, 1).to_julian_day(), 2_440_588);
+        assert_eq!(date(-4713, 11, 24).to_julian_day(), 0);
+        assert_eq!(date(-4713, 11, 23).to_julian_day(), -1);
+        assert_eq!(date(2000, 1, 1).to_julian_day(), 2_451_545);
+
+        for d in [Date::MIN, date(-4713, 11, 24), date(2024, 2, 29), Date::MAX]
+        {
+            let jdn = d.to_julian_day();
+            assert_eq!(Date::from_julian_day(jdn).unwrap(), d);
+        }
+        // Consecutive dates have consecutive Julian Day Numbers.
+        let d = date(1999, 12, 31);
+        assert_eq!(
+            d.tomorrow().unwrap().to_julian_day(),
+            d.to_julian_day() + 1,
+        );
+
+        insta::assert_snapshot!(
+            Date::from_julian_day(Date::MIN.to_julian_day() - 1).unwrap_err(),
+            @"Julian Day Number -1931000 is not in the required range of -1930999..=5373484",
+        );
+        insta::assert_snapshot!(
+            Date::from_julian_day(i64::MIN).unwrap_err(),
+            @"Julian Day Number -9223372036854775808 is not in the required range of -1930999..=5373484",
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn rand_date() {
+        use rand::{
+            distr::{Distribution, Uniform},
+            rngs::StdRng,
+            RngExt, SeedableRng,
+        };
+
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let (start, end) = (date(2024, 2, 28), date(2024, 3, 1));
+        let (mut seen_start, mut seen_leap_day) = (false, false);
+        for _ in 0..100 {
+            let d = rng.random_range(start..end);
+            assert!(start <= d && d < end);
+            seen_start |= d == start;
+            seen_leap_day |= d == date(2024, 2, 29);
+        }
+        assert!(seen_start && seen_leap_day);
+
+        assert_eq!(rng.random_range(start..=start), start);
+        assert!(Uniform::new(start, start).is_err());
+
+        let dist = Uniform::new_inclusive(Date::MIN, Date::MAX).unwrap();
+        for _ in 0..100 {
+            let _ = dist.sample(&mut rng);
+        }
+    }
 }
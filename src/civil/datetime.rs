@@ -1572,6 +1572,196 @@ impl DateTime {
         Ok(Zoned::from_parts(ts, tz, offset, dt))
     }
 
+    /// Returns an order preserving encoding of this datetime as a byte array.
+    ///
+    /// Comparing two encodings byte-wise (i.e., lexicographically) gives the
+    /// same result as comparing the datetimes themselves. This makes the
+    /// encoding suitable for use as a key in ordered key-value stores like
+    /// LMDB or RocksDB.
+    ///
+    /// The layout is 11 bytes: the 4 bytes returned by
+    /// [`Date::to_sortable_bytes`], followed by the hour, minute and second
+    /// as a single byte each, followed by the fractional nanoseconds as a
+    /// `u32` in big-endian byte order. Big-endian byte order is required
+    /// because a little-endian encoding cannot be sorted byte-wise.
+    ///
+    /// This layout is stable and will not change in semver compatible
+    /// releases. Use [`DateTime::from_sortable_bytes`] to decode it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let dt = date(2024, 6, 15).at(7, 30, 59, 123);
+    /// assert_eq!(
+    ///     dt.to_sortable_bytes(),
+    ///     [0x87, 0xE8, 6, 15, 7, 30, 59, 0, 0, 0, 123],
+    /// );
+    ///
+    /// // Byte-wise order matches the order of datetimes.
+    /// let later = date(2024, 6, 15).at(7, 31, 0, 0);
+    /// assert!(dt.to_sortable_bytes() < later.to_sortable_bytes());
+    /// ```
+    #[inline]
+    pub fn to_sortable_bytes(self) -> [u8; 11] {
+        let [y0, y1, month, day] = self.date().to_sortable_bytes();
+        let [n0, n1, n2, n3] = self.subsec_nanosecond().to_be_bytes();
+        // OK because the hour, minute and second are never negative.
+        let (hour, minute, second) =
+            (self.hour() as u8, self.minute() as u8, self.second() as u8);
+        [y0, y1, month, day, hour, minute, second, n0, n1, n2, n3]
+    }
+
+    /// Creates a datetime from its order preserving encoding as a byte array.
+    ///
+    /// This is the inverse of [`DateTime::to_sortable_bytes`]. See its
+    /// documentation for a description of the layout.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the encoded date is invalid (as with
+    /// [`Date::from_sortable_bytes`]) or when any of the encoded time
+    /// components are out of range. That is, in the same circumstances as
+    /// [`DateTime::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, DateTime};
+    ///
+    /// let dt = date(-9999, 1, 1).at(23, 59, 59, 999_999_999);
+    /// let bytes = dt.to_sortable_bytes();
+    /// assert_eq!(DateTime::from_sortable_bytes(bytes)?, dt);
+    ///
+    /// // Out of range time components are rejected.
+    /// let bytes = [0x87, 0xE8, 6, 15, 24, 0, 0, 0, 0, 0, 0];
+    /// assert!(DateTime::from_sortable_bytes(bytes).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_sortable_bytes(bytes: [u8; 11]) -> Result<DateTime, Error> {
+        let [y0, y1, month, day, hour, minute, second, n0, n1, n2, n3] = bytes;
+        let date = Date::from_sortable_bytes([y0, y1, month, day])?;
+        let hour = t::Hour::try_new("hour", hour)?;
+        let minute = t::Minute::try_new("minute", minute)?;
+        let second = t::Second::try_new("second", second)?;
+        let subsec_nanosecond = t::SubsecNanosecond::try_new(
+            "subsec_nanosecond",
+            u32::from_be_bytes([n0, n1, n2, n3]),
+        )?;
+        let time = Time::new_ranged(hour, minute, second, subsec_nanosecond);
+        Ok(DateTime::from_parts(date, time))
+    }
+
+    /// Returns this datetime packed into the 16-bit date and 16-bit time
+    /// format used by MS-DOS.
+    ///
+    /// This format is used by ZIP archives and FAT file systems to record
+    /// file modification times. The date is packed as `YYYYYYYM MMMDDDDD`,
+    /// where the year is stored as an offset from `1980`. The time is packed
+    /// as `HHHHHMMM MMMSSSSS`, where the second is stored divided by `2`.
+    /// The date and time are returned in that order.
+    ///
+    /// Since the format only has 2 second resolution, an odd second is
+    /// truncated to the preceding even second, and any fractional second is
+    /// dropped.
+    ///
+    /// The format has no notion of time zones. By convention, it records
+    /// local time.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the year is outside of the range
+    /// `1980..=2107`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let dt = date(2024, 6, 15).at(7, 30, 59, 123);
+    /// let (dos_date, dos_time) = dt.to_dos_date_time()?;
+    /// assert_eq!(dos_date, ((2024 - 1980) << 9) | (6 << 5) | 15);
+    /// assert_eq!(dos_time, (7 << 11) | (30 << 5) | (58 / 2));
+    ///
+    /// let dt = date(1979, 12, 31).at(23, 59, 59, 0);
+    /// assert!(dt.to_dos_date_time().is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_dos_date_time(self) -> Result<(u16, u16), Error> {
+        let year = self.year();
+        if !(1980..=2107).contains(&year) {
+            return Err(err!(
+                "datetime {self} has year {year}, but only years in the \
+                 range 1980..=2107 can be represented as a DOS date",
+            ));
+        }
+        // OK because all of the components are non-negative and fit into
+        // their corresponding bit fields.
+        let date = (((year - 1980) as u16) << 9)
+            | ((self.month() as u16) << 5)
+            | (self.day() as u16);
+        let time = ((self.hour() as u16) << 11)
+            | ((self.minute() as u16) << 5)
+            | ((self.second() as u16) / 2);
+        Ok((date, time))
+    }
+
+    /// Creates a datetime from the 16-bit date and 16-bit time format used
+    /// by MS-DOS.
+    ///
+    /// This is the inverse of [`DateTime::to_dos_date_time`]. See its
+    /// documentation for a description of the format.
+    ///
+    /// The datetime returned always has an even second and no fractional
+    /// second.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the packed components are invalid.
+    /// For example, a month of `0` or a day of `31` in a month with only 30
+    /// days. In particular, a DOS date of `0` (which some ZIP archives use to
+    /// indicate a missing date) is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, DateTime};
+    ///
+    /// let dos_date = ((2024 - 1980) << 9) | (6 << 5) | 15;
+    /// let dos_time = (7 << 11) | (30 << 5) | (58 / 2);
+    /// assert_eq!(
+    ///     DateTime::from_dos_date_time(dos_date, dos_time)?,
+    ///     date(2024, 6, 15).at(7, 30, 58, 0),
+    /// );
+    ///
+    /// assert!(DateTime::from_dos_date_time(0, 0).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_dos_date_time(
+        date: u16,
+        time: u16,
+    ) -> Result<DateTime, Error> {
+        // OK because each bit field fits into the type it's cast to.
+        let year = 1980 + (date >> 9) as i16;
+        let month = ((date >> 5) & 0b1111) as i8;
+        let day = (date & 0b11111) as i8;
+        let hour = (time >> 11) as i8;
+        let minute = ((time >> 5) & 0b111111) as i8;
+        let second = ((time & 0b11111) * 2) as i8;
+        let date = Date::new(year, month, day)
+            .with_context(|| err!("invalid DOS date {date:#06X}"))?;
+        let time = Time::new(hour, minute, second, 0)
+            .with_context(|| err!("invalid DOS time {time:#06X}"))?;
+        Ok(DateTime::from_parts(date, time))
+    }
+
     /// Add the given span of time to this datetime. If the sum would overflow
     /// the minimum or maximum datetime values, then an error is returned.
     ///
@@ -2384,7 +2574,7 @@ impl DateTime {
     /// Converts this datetime to a nanosecond timestamp assuming a Zulu time
     /// zone offset and where all days are exactly 24 hours long.
     #[inline]
-    fn to_nanosecond(self) -> t::NoUnits128 {
+    pub(crate) fn to_nanosecond(self) -> t::NoUnits128 {
         let day_nano = self.date().to_unix_epoch_day();
         let time_nano = self.time().to_nanosecond();
         (t::NoUnits128::rfrom(day_nano) * t::NANOS_PER_CIVIL_DAY) + time_nano
@@ -2766,6 +2956,62 @@ impl core::ops::SubAssign<UnsignedDuration> for DateTime {
     }
 }
 
+/// Converts a Jiff civil datetime to a `chrono` naive datetime.
+///
+/// This never fails since `chrono` supports a superset of the datetimes
+/// supported by Jiff.
+#[cfg(feature = "chrono")]
+impl From<DateTime> for chrono::NaiveDateTime {
+    #[inline]
+    fn from(dt: DateTime) -> chrono::NaiveDateTime {
+        chrono::NaiveDateTime::new(dt.date().into(), dt.time().into())
+    }
+}
+
+/// Converts a `chrono` naive datetime to a Jiff civil datetime.
+///
+/// This returns an error when the datetime is outside of Jiff's supported
+/// range. A leap second is handled the same way as when converting from a
+/// `chrono::NaiveTime` to a [`Time`].
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDateTime> for DateTime {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(dt: chrono::NaiveDateTime) -> Result<DateTime, Error> {
+        let date = Date::try_from(dt.date())?;
+        Ok(DateTime::from_parts(date, Time::from(dt.time())))
+    }
+}
+
+/// Converts a Jiff civil datetime to a `time` primitive datetime.
+///
+/// This never fails since `time` supports a superset of the datetimes
+/// supported by Jiff.
+#[cfg(feature = "time")]
+impl From<DateTime> for ::time::PrimitiveDateTime {
+    #[inline]
+    fn from(dt: DateTime) -> ::time::PrimitiveDateTime {
+        ::time::PrimitiveDateTime::new(dt.date().into(), dt.time().into())
+    }
+}
+
+/// Converts a `time` primitive datetime to a Jiff civil datetime.
+///
+/// This returns an error when the datetime is outside of Jiff's supported
+/// range. This can only happen when the `large-dates` feature of the `time`
+/// crate is enabled.
+#[cfg(feature = "time")]
+impl TryFrom<::time::PrimitiveDateTime> for DateTime {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(dt: ::time::PrimitiveDateTime) -> Result<DateTime, Error> {
+        let date = Date::try_from(dt.date())?;
+        Ok(DateTime::from_parts(date, Time::from(dt.time())))
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for DateTime {
     #[inline]
@@ -2820,6 +3066,20 @@ impl<'de> serde::Deserialize<'de> for DateTime {
     }
 }
 
+#[cfg(feature = "utoipa")]
+impl utoipa::PartialSchema for DateTime {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::util::schema::string(
+            None,
+            "A civil datetime, as an ISO 8601 datetime without an offset.",
+            "2024-06-15T07:00:00",
+        )
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl utoipa::ToSchema for DateTime {}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for DateTime {
     fn arbitrary(g: &mut quickcheck::Gen) -> DateTime {
@@ -2837,6 +3097,21 @@ impl quickcheck::Arbitrary for DateTime {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DateTime {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<DateTime> {
+        let date: Date = u.arbitrary()?;
+        let time: Time = u.arbitrary()?;
+        Ok(DateTime::from_parts(date, time))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(Date, Time)>::size_hint(depth)
+    }
+}
+
 /// An iterator over periodic datetimes, created by [`DateTime::series`].
 ///
 /// It is exhausted when the next value would exceed a [`Span`] or [`DateTime`]
@@ -4384,6 +4659,94 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn sortable_bytes() {
+        let dts = [
+            DateTime::MIN,
+            date(-1, 12, 31).at(23, 59, 59, 999_999_999),
+            date(0, 1, 1).at(0, 0, 0, 0),
+            date(2024, 2, 29).at(0, 0, 0, 1),
+            date(2024, 2, 29).at(0, 0, 1, 0),
+            date(2024, 2, 29).at(1, 0, 0, 0),
+            date(2024, 3, 1).at(0, 0, 0, 0),
+            DateTime::MAX,
+        ];
+        for pair in dts.windows(2) {
+            assert!(
+                pair[0].to_sortable_bytes() < pair[1].to_sortable_bytes(),
+                "{pair:?}"
+            );
+        }
+        for dt in dts {
+            assert_eq!(
+                DateTime::from_sortable_bytes(dt.to_sortable_bytes()).unwrap(),
+                dt
+            );
+        }
+
+        let bytes = date(2024, 2, 29).at(0, 0, 0, 0).to_sortable_bytes();
+        let mut invalid = bytes;
+        invalid[7..].copy_from_slice(&1_000_000_000u32.to_be_bytes());
+        insta::assert_snapshot!(
+            DateTime::from_sortable_bytes(invalid).unwrap_err(),
+            @"parameter 'subsec_nanosecond' with value 1000000000 is not in the required range of 0..=999999999",
+        );
+        let mut invalid = bytes;
+        invalid[0..2].copy_from_slice(&u16::MAX.to_be_bytes());
+        insta::assert_snapshot!(
+            DateTime::from_sortable_bytes(invalid).unwrap_err(),
+            @"parameter 'year' with value 32767 is not in the required range of -9999..=9999",
+        );
+    }
+
+    #[test]
+    fn dos_date_time() {
+        let min = date(1980, 1, 1).at(0, 0, 0, 0);
+        let max = date(2107, 12, 31).at(23, 59, 58, 0);
+        assert_eq!(min.to_dos_date_time().unwrap(), (0x0021, 0x0000));
+        assert_eq!(max.to_dos_date_time().unwrap(), (0xFF9F, 0xBF7D));
+        for dt in [min, max, date(2024, 2, 29).at(12, 34, 56, 0)] {
+            let (d, t) = dt.to_dos_date_time().unwrap();
+            assert_eq!(DateTime::from_dos_date_time(d, t).unwrap(), dt);
+        }
+
+        // Odd seconds and fractional seconds are truncated.
+        let dt = date(2024, 2, 29).at(12, 34, 57, 999_999_999);
+        let (d, t) = dt.to_dos_date_time().unwrap();
+        assert_eq!(
+            DateTime::from_dos_date_time(d, t).unwrap(),
+            date(2024, 2, 29).at(12, 34, 56, 0),
+        );
+
+        insta::assert_snapshot!(
+            date(1979, 12, 31).at(23, 59, 59, 0).to_dos_date_time().unwrap_err(),
+            @"datetime 1979-12-31T23:59:59 has year 1979, but only years in the range 1980..=2107 can be represented as a DOS date",
+        );
+        insta::assert_snapshot!(
+            date(2108, 1, 1).at(0, 0, 0, 0).to_dos_date_time().unwrap_err(),
+            @"datetime 2108-01-01T00:00:00 has year 2108, but only years in the range 1980..=2107 can be represented as a DOS date",
+        );
+        insta::assert_snapshot!(
+            DateTime::from_dos_date_time(0, 0).unwrap_err(),
+            @"invalid DOS date 0x0000: parameter 'month' with value 0 is not in the required range of 1..=12",
+        );
+        // 2023-02-29 doesn't exist.
+        let d = ((2023 - 1980) << 9) | (2 << 5) | 29;
+        insta::assert_snapshot!(
+            DateTime::from_dos_date_time(d, 0).unwrap_err(),
+            @"invalid DOS date 0x565D: parameter 'day' with value 29 is not in the required range of 1..=28",
+        );
+        // A second field of 30 means 60 seconds.
+        insta::assert_snapshot!(
+            DateTime::from_dos_date_time(0x0021, 30).unwrap_err(),
+            @"invalid DOS time 0x001E: parameter 'second' with value 60 is not in the required range of 0..=59",
+        );
+        insta::assert_snapshot!(
+            DateTime::from_dos_date_time(0x0021, 24 << 11).unwrap_err(),
+            @"invalid DOS time 0xC000: parameter 'hour' with value 24 is not in the required range of 0..=23",
+        );
+    }
+
     #[test]
     fn from_temporal_docs() {
         let dt = DateTime::from_parts(
@@ -4548,4 +4911,37 @@ mod tests {
 
         assert_eq!(deserialized, expected);
     }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_datetime() {
+        use alloc::string::ToString;
+
+        let dt = date(2024, 2, 29).at(17, 30, 0, 1);
+        let ndt = chrono::NaiveDateTime::from(dt);
+        assert_eq!(ndt.to_string(), "2024-02-29 17:30:00.000000001");
+        assert_eq!(DateTime::try_from(ndt).unwrap(), dt);
+
+        let ndt = chrono::NaiveDateTime::from(DateTime::MIN);
+        assert_eq!(DateTime::try_from(ndt).unwrap(), DateTime::MIN);
+        let ndt = chrono::NaiveDateTime::from(DateTime::MAX);
+        assert_eq!(DateTime::try_from(ndt).unwrap(), DateTime::MAX);
+        assert!(DateTime::try_from(chrono::NaiveDateTime::MAX).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_datetime() {
+        use ::time::{macros::datetime as tdatetime, PrimitiveDateTime};
+
+        let dt = date(2024, 2, 29).at(17, 30, 0, 1);
+        let pdt = tdatetime!(2024-02-29 17:30:00.000_000_001);
+        assert_eq!(PrimitiveDateTime::from(dt), pdt);
+        assert_eq!(DateTime::try_from(pdt).unwrap(), dt);
+
+        let pdt = PrimitiveDateTime::from(DateTime::MIN);
+        assert_eq!(DateTime::try_from(pdt).unwrap(), DateTime::MIN);
+        let pdt = PrimitiveDateTime::from(DateTime::MAX);
+        assert_eq!(DateTime::try_from(pdt).unwrap(), DateTime::MAX);
+    }
 }
@@ -0,0 +1,578 @@
+use crate::{
+    civil::Date,
+    error::{err, Error, ErrorContext},
+    fmt,
+    tz::TimeZone,
+    util::t,
+};
+
+/// The date on which a region switched from the Julian calendar to the
+/// Gregorian calendar.
+///
+/// All of Jiff's civil types use the [proleptic Gregorian calendar]. That is,
+/// the rules of the Gregorian calendar are applied to all dates, even those
+/// that precede its introduction. However, historical records usually write
+/// dates in whichever calendar was in use at the time and place they were
+/// recorded. For example, in Great Britain and its colonies, the day after
+/// `1752-09-02` was `1752-09-14`. Before that, dates were written in the
+/// Julian calendar, which has a leap day in every fourth year (including
+/// `1700`, which isn't a leap year in the Gregorian calendar).
+///
+/// A `GregorianCutover` can be used to interpret such dates. Namely, dates
+/// before the cutover are interpreted as Julian calendar dates and dates on
+/// or after the cutover are interpreted as Gregorian calendar dates. Dates
+/// that fall into the days skipped by the cutover are rejected. In all
+/// cases, the result is a [`Date`] in the proleptic Gregorian calendar.
+/// Conversely, [`GregorianCutover::to_historical`] can be used to get the
+/// year, month and day of a `Date` as they would have been written in the
+/// historical calendar.
+///
+/// A number of presets are available as associated constants, and
+/// [`GregorianCutover::for_time_zone`] can select a preset for some time
+/// zones. The default is [`GregorianCutover::ROME`], which corresponds to
+/// the introduction of the Gregorian calendar in 1582.
+///
+/// [proleptic Gregorian calendar]: https://en.wikipedia.org/wiki/Proleptic_Gregorian_calendar
+///
+/// # Example
+///
+/// This shows how to interpret dates around the cutover in Great Britain:
+///
+/// ```
+/// use jiff::civil::{date, GregorianCutover};
+///
+/// let gb = GregorianCutover::GREAT_BRITAIN;
+/// // The last day of the Julian calendar corresponds to
+/// // 1752-09-13 in the proleptic Gregorian calendar.
+/// assert_eq!(gb.to_date(1752, 9, 2)?, date(1752, 9, 13));
+/// // The next day was the first day of the Gregorian calendar.
+/// assert_eq!(gb.to_date(1752, 9, 14)?, date(1752, 9, 14));
+/// // The days in between never occurred.
+/// assert!(gb.to_date(1752, 9, 3).is_err());
+/// assert!(gb.to_date(1752, 9, 13).is_err());
+/// // 1700 was a leap year in the Julian calendar.
+/// assert_eq!(gb.to_date(1700, 2, 29)?, date(1700, 3, 11));
+///
+/// // And going the other direction:
+/// let hd = gb.to_historical(date(1700, 3, 11));
+/// assert_eq!((hd.year(), hd.month(), hd.day()), (1700, 2, 29));
+/// assert!(hd.is_julian());
+/// assert_eq!(hd.to_string(), "1700-02-29");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Example: parsing historical dates
+///
+/// [`GregorianCutover::strptime`] can be used to parse dates that are
+/// written in the historical calendar:
+///
+/// ```
+/// use jiff::civil::{date, GregorianCutover};
+///
+/// let gb = GregorianCutover::GREAT_BRITAIN;
+/// // George Washington's birthday, as recorded at the time.
+/// let d = gb.strptime("%B %d, %Y", "February 11, 1731")?;
+/// assert_eq!(d, date(1731, 2, 22));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GregorianCutover {
+    first_gregorian: Date,
+}
+
+impl GregorianCutover {
+    /// The cutover used by the Papal States, Spain, Portugal and the
+    /// Polish-Lithuanian Commonwealth, where `1582-10-04` (Julian) was
+    /// followed by `1582-10-15` (Gregorian).
+    ///
+    /// This is when the Gregorian calendar was first introduced.
+    pub const ROME: GregorianCutover =
+        GregorianCutover { first_gregorian: Date::constant(1582, 10, 15) };
+
+    /// The cutover used by France, where `1582-12-09` (Julian) was followed
+    /// by `1582-12-20` (Gregorian).
+    pub const FRANCE: GregorianCutover =
+        GregorianCutover { first_gregorian: Date::constant(1582, 12, 20) };
+
+    /// The cutover used by Great Britain, Ireland and the British colonies,
+    /// where `1752-09-02` (Julian) was followed by `1752-09-14` (Gregorian).
+    pub const GREAT_BRITAIN: GregorianCutover =
+        GregorianCutover { first_gregorian: Date::constant(1752, 9, 14) };
+
+    /// The cutover used by Sweden (including Finland), where `1753-02-17`
+    /// (Julian) was followed by `1753-03-01` (Gregorian).
+    ///
+    /// Note that Sweden used its own calendar, which was neither Julian nor
+    /// Gregorian, from 1700 to 1712. This is not modeled.
+    pub const SWEDEN: GregorianCutover =
+        GregorianCutover { first_gregorian: Date::constant(1753, 3, 1) };
+
+    /// The cutover used by Russia, where `1918-01-31` (Julian) was followed
+    /// by `1918-02-14` (Gregorian).
+    pub const RUSSIA: GregorianCutover =
+        GregorianCutover { first_gregorian: Date::constant(1918, 2, 14) };
+
+    /// The cutover used by Greece, where `1923-02-15` (Julian) was followed
+    /// by `1923-03-01` (Gregorian).
+    pub const GREECE: GregorianCutover =
+        GregorianCutover { first_gregorian: Date::constant(1923, 3, 1) };
+
+    /// Creates a new cutover where the given date is the first day of the
+    /// Gregorian calendar. The day before it is the last day of the Julian
+    /// calendar.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given date is the minimum date supported
+    /// by Jiff, or if the cutover would result in a Julian date that is
+    /// written the same as, or after, the first Gregorian date. The latter
+    /// can only happen for cutovers before `0200-03-01`, where the Julian
+    /// calendar is not behind the Gregorian calendar.
+    ///
+    /// # Example
+    ///
+    /// This creates a cutover for Prussia:
+    ///
+    /// ```
+    /// use jiff::civil::{date, GregorianCutover};
+    ///
+    /// let prussia = GregorianCutover::new(date(1610, 9, 2))?;
+    /// assert_eq!(prussia.last_julian(), date(1610, 9, 1));
+    /// assert_eq!(prussia.to_date(1610, 8, 22)?, date(1610, 9, 1));
+    /// assert!(prussia.to_date(1610, 8, 23).is_err());
+    ///
+    /// assert!(GregorianCutover::new(date(100, 1, 1)).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(first_gregorian: Date) -> Result<GregorianCutover, Error> {
+        let last_julian = first_gregorian.yesterday().with_context(|| {
+            err!(
+                "failed to find last Julian date before Gregorian cutover \
+                 on {first_gregorian}",
+            )
+        })?;
+        let julian = HistoricalDate::julian(last_julian);
+        if julian.fields() >= fields(first_gregorian) {
+            return Err(err!(
+                "Gregorian cutover on {first_gregorian} is invalid because \
+                 the preceding Julian date, {julian}, would not be written \
+                 before it",
+            ));
+        }
+        Ok(GregorianCutover { first_gregorian })
+    }
+
+    /// Returns a cutover, if one is known, for the given time zone.
+    ///
+    /// This only recognizes a small number of IANA time zone identifiers
+    /// corresponding to the presets on this type, e.g., `Europe/London`
+    /// maps to [`GregorianCutover::GREAT_BRITAIN`]. For all other time zones
+    /// (including time zones without an IANA identifier), this returns
+    /// `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::GregorianCutover, tz::TimeZone};
+    ///
+    /// let tz = TimeZone::get("Europe/London")?;
+    /// assert_eq!(
+    ///     GregorianCutover::for_time_zone(&tz),
+    ///     Some(GregorianCutover::GREAT_BRITAIN),
+    /// );
+    /// assert_eq!(GregorianCutover::for_time_zone(&TimeZone::UTC), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn for_time_zone(tz: &TimeZone) -> Option<GregorianCutover> {
+        static PRESETS: &[(&str, GregorianCutover)] = &[
+            ("Europe/Rome", GregorianCutover::ROME),
+            ("Europe/Vatican", GregorianCutover::ROME),
+            ("Europe/Madrid", GregorianCutover::ROME),
+            ("Europe/Lisbon", GregorianCutover::ROME),
+            ("Europe/Warsaw", GregorianCutover::ROME),
+            ("Europe/Paris", GregorianCutover::FRANCE),
+            ("Europe/London", GregorianCutover::GREAT_BRITAIN),
+            ("Europe/Belfast", GregorianCutover::GREAT_BRITAIN),
+            ("Europe/Dublin", GregorianCutover::GREAT_BRITAIN),
+            ("GB", GregorianCutover::GREAT_BRITAIN),
+            ("GB-Eire", GregorianCutover::GREAT_BRITAIN),
+            ("Eire", GregorianCutover::GREAT_BRITAIN),
+            ("Europe/Stockholm", GregorianCutover::SWEDEN),
+            ("Europe/Helsinki", GregorianCutover::SWEDEN),
+            ("Europe/Moscow", GregorianCutover::RUSSIA),
+            ("W-SU", GregorianCutover::RUSSIA),
+            ("Europe/Athens", GregorianCutover::GREECE),
+        ];
+
+        let name = tz.iana_name()?;
+        PRESETS
+            .iter()
+            .find(|&&(preset, _)| preset.eq_ignore_ascii_case(name))
+            .map(|&(_, cutover)| cutover)
+    }
+
+    /// Returns the first day of the Gregorian calendar for this cutover.
+    #[inline]
+    pub fn first_gregorian(self) -> Date {
+        self.first_gregorian
+    }
+
+    /// Returns the last day of the Julian calendar for this cutover.
+    ///
+    /// The date returned is in the proleptic Gregorian calendar, like all
+    /// `Date` values. Use [`GregorianCutover::to_historical`] to get the
+    /// Julian calendar date.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, GregorianCutover};
+    ///
+    /// let gb = GregorianCutover::GREAT_BRITAIN;
+    /// assert_eq!(gb.last_julian(), date(1752, 9, 13));
+    /// assert_eq!(gb.to_historical(gb.last_julian()).to_string(), "1752-09-02");
+    /// ```
+    #[inline]
+    pub fn last_julian(self) -> Date {
+        // OK because construction guarantees this isn't `Date::MIN`.
+        self.first_gregorian.yesterday().unwrap()
+    }
+
+    /// Interprets the given year, month and day as a date in the historical
+    /// calendar described by this cutover, and returns the corresponding
+    /// date in the proleptic Gregorian calendar.
+    ///
+    /// Dates written before the first Gregorian date are interpreted as
+    /// Julian calendar dates. All other dates are interpreted as Gregorian
+    /// calendar dates.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given components do not correspond to a
+    /// valid date in the calendar used at that time, if the date falls into
+    /// the days skipped by the cutover or if the corresponding date is not
+    /// within Jiff's supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, GregorianCutover};
+    ///
+    /// let ru = GregorianCutover::RUSSIA;
+    /// // The October Revolution happened in November, according to the
+    /// // proleptic Gregorian calendar.
+    /// assert_eq!(ru.to_date(1917, 10, 25)?, date(1917, 11, 7));
+    ///
+    /// // Dates skipped by the cutover are rejected.
+    /// assert_eq!(
+    ///     ru.to_date(1918, 2, 1).unwrap_err().to_string(),
+    ///     "date 1918-02-01 does not exist because it falls in the days \
+    ///      skipped by the Gregorian cutover (1918-02-01 to 1918-02-13)",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_date(
+        self,
+        year: i16,
+        month: i8,
+        day: i8,
+    ) -> Result<Date, Error> {
+        if (year, month, day) >= fields(self.first_gregorian) {
+            return Date::new(year, month, day);
+        }
+        let date = julian_to_date(year, month, day)?;
+        if date >= self.first_gregorian {
+            // The first skipped date is written in the Julian calendar,
+            // while the last is written in the Gregorian calendar.
+            let first_skipped = HistoricalDate::julian(self.first_gregorian);
+            let last_skipped = self.last_julian();
+            return Err(err!(
+                "date {written} does not exist because it falls in the days \
+                 skipped by the Gregorian cutover ({first_skipped} to \
+                 {last_skipped})",
+                written = HistoricalDate::julian_from_fields(year, month, day),
+            ));
+        }
+        Ok(date)
+    }
+
+    /// Returns the given date as it would be written in the historical
+    /// calendar described by this cutover.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, GregorianCutover};
+    ///
+    /// let fr = GregorianCutover::FRANCE;
+    /// let hd = fr.to_historical(date(1582, 12, 19));
+    /// assert_eq!(hd.to_string(), "1582-12-09");
+    /// assert!(hd.is_julian());
+    /// let hd = fr.to_historical(date(1582, 12, 20));
+    /// assert_eq!(hd.to_string(), "1582-12-20");
+    /// assert!(!hd.is_julian());
+    /// ```
+    pub fn to_historical(self, date: Date) -> HistoricalDate {
+        if date >= self.first_gregorian {
+            let (year, month, day) = fields(date);
+            HistoricalDate { year, month, day, julian: false }
+        } else {
+            HistoricalDate::julian(date)
+        }
+    }
+
+    /// Parses a historical date according to the given `format`, and
+    /// returns the corresponding date in the proleptic Gregorian calendar.
+    ///
+    /// The format string uses the same syntax as [`Date::strptime`] (see the
+    /// [`fmt::strtime`] module). Only the year, month and day parsed
+    /// are used. In particular, they are interpreted by
+    /// [`GregorianCutover::to_date`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if parsing fails, if the year, month or day
+    /// is missing, or if [`GregorianCutover::to_date`] returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, GregorianCutover};
+    ///
+    /// let gb = GregorianCutover::GREAT_BRITAIN;
+    /// assert_eq!(gb.strptime("%Y-%m-%d", "1700-02-29")?, date(1700, 3, 11));
+    /// assert!(gb.strptime("%Y-%m-%d", "1752-09-10").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn strptime(
+        self,
+        format: impl AsRef<[u8]>,
+        input: impl AsRef<[u8]>,
+    ) -> Result<Date, Error> {
+        let tm = fmt::strtime::parse(format, input)?;
+        let (Some(year), Some(month), Some(day)) =
+            (tm.year(), tm.month(), tm.day())
+        else {
+            return Err(err!(
+                "parsing a historical date requires a year, month and day",
+            ));
+        };
+        self.to_date(year, month, day)
+    }
+}
+
+impl Default for GregorianCutover {
+    fn default() -> GregorianCutover {
+        GregorianCutover::ROME
+    }
+}
+
+/// A date as written in a historical calendar.
+///
+/// This is returned by [`GregorianCutover::to_historical`]. Its components
+/// are either a Julian calendar date or a Gregorian calendar date, depending
+/// on which side of the cutover the date falls. Unlike a [`Date`], the
+/// components may not correspond to a valid date in the Gregorian calendar
+/// (e.g., `1700-02-29`).
+///
+/// The `Display` implementation writes the date in the same format as
+/// `Date`, e.g., `1752-09-02`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HistoricalDate {
+    year: i16,
+    month: i8,
+    day: i8,
+    julian: bool,
+}
+
+impl HistoricalDate {
+    /// Returns the year as written in the historical calendar.
+    #[inline]
+    pub fn year(self) -> i16 {
+        self.year
+    }
+
+    /// Returns the month as written in the historical calendar.
+    #[inline]
+    pub fn month(self) -> i8 {
+        self.month
+    }
+
+    /// Returns the day as written in the historical calendar.
+    #[inline]
+    pub fn day(self) -> i8 {
+        self.day
+    }
+
+    /// Returns true if this date is written in the Julian calendar.
+    ///
+    /// Otherwise, it is written in the Gregorian calendar.
+    #[inline]
+    pub fn is_julian(self) -> bool {
+        self.julian
+    }
+
+    fn julian(date: Date) -> HistoricalDate {
+        let (year, month, day) = date_to_julian(date);
+        HistoricalDate { year, month, day, julian: true }
+    }
+
+    fn julian_from_fields(year: i16, month: i8, day: i8) -> HistoricalDate {
+        HistoricalDate { year, month, day, julian: true }
+    }
+
+    fn fields(self) -> (i16, i8, i8) {
+        (self.year, self.month, self.day)
+    }
+}
+
+impl core::fmt::Display for HistoricalDate {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.year >= 0 {
+            write!(f, "{:04}", self.year)?;
+        } else {
+            write!(f, "-{:06}", self.year.unsigned_abs())?;
+        }
+        write!(f, "-{:02}-{:02}", self.month, self.day)
+    }
+}
+
+/// The number of days from the Julian day number epoch to the Unix epoch.
+const UNIX_EPOCH_JULIAN_DAY_NUMBER: i64 = 2_440_588;
+
+fn fields(date: Date) -> (i16, i8, i8) {
+    (date.year(), date.month(), date.day())
+}
+
+/// Converts the given Julian calendar date to a proleptic Gregorian date.
+///
+/// This uses the algorithm from "Calendars" by L. E. Doggett, in the
+/// "Explanatory Supplement to the Astronomical Almanac," adapted to use
+/// Euclidean division so that it works for negative years.
+fn julian_to_date(year: i16, month: i8, day: i8) -> Result<Date, Error> {
+    let written = HistoricalDate::julian_from_fields(year, month, day);
+    if !(1..=12).contains(&month) {
+        return Err(err!(
+            "Julian date {written} is invalid because its month is \
+             not in the range 1..=12",
+        ));
+    }
+    let leap = year.rem_euclid(4) == 0;
+    let days_in_month = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    if !(1..=days_in_month).contains(&day) {
+        return Err(err!(
+            "Julian date {written} is invalid because its day is \
+             not in the range 1..={days_in_month}",
+        ));
+    }
+
+    let (year, month, day) =
+        (i64::from(year), i64::from(month), i64::from(day));
+    let a = (14 - month).div_euclid(12);
+    let y = year + 4800 - a;
+    let m = month + 12 * a - 3;
+    let jdn =
+        day + (153 * m + 2).div_euclid(5) + 365 * y + y.div_euclid(4) - 32083;
+    let epoch_day =
+        t::UnixEpochDay::try_new("day", jdn - UNIX_EPOCH_JULIAN_DAY_NUMBER)
+            .with_context(|| {
+                err!(
+                    "Julian date {written} is outside of the range \
+                     supported by Jiff",
+                )
+            })?;
+    Ok(Date::from_unix_epoch_day(epoch_day))
+}
+
+/// Converts the given proleptic Gregorian date to a Julian calendar date.
+///
+/// This is the inverse of `julian_to_date`.
+fn date_to_julian(date: Date) -> (i16, i8, i8) {
+    let jdn = i64::from(date.to_unix_epoch_day().get())
+        + UNIX_EPOCH_JULIAN_DAY_NUMBER;
+    let c = jdn + 32082;
+    let d = (4 * c + 3).div_euclid(1461);
+    let e = c - (1461 * d).div_euclid(4);
+    let m = (5 * e + 2).div_euclid(153);
+    let day = e - (153 * m + 2).div_euclid(5) + 1;
+    let month = m + 3 - 12 * m.div_euclid(10);
+    let year = d - 4800 + m.div_euclid(10);
+    // OK because Jiff's supported range of dates, when converted to the
+    // Julian calendar, fits in these types.
+    (year as i16, month as i8, day as i8)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{civil::date, ToSpan};
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let cutovers = [
+            GregorianCutover::ROME,
+            GregorianCutover::GREAT_BRITAIN,
+            GregorianCutover::RUSSIA,
+        ];
+        for cutover in cutovers {
+            for d in date(1500, 1, 1).series(1.day()).take(365 * 450) {
+                let hd = cutover.to_historical(d);
+                assert_eq!(hd.is_julian(), d < cutover.first_gregorian());
+                let got = cutover.to_date(hd.year(), hd.month(), hd.day());
+                assert_eq!(got.unwrap(), d, "{d} / {hd}");
+            }
+        }
+    }
+
+    #[test]
+    fn extremes() {
+        let cutover = GregorianCutover::ROME;
+        let hd = cutover.to_historical(Date::MIN);
+        insta::assert_snapshot!(hd, @"-009999-03-19");
+        assert_eq!(
+            cutover.to_date(hd.year(), hd.month(), hd.day()).unwrap(),
+            Date::MIN
+        );
+        let hd = cutover.to_historical(Date::MAX);
+        insta::assert_snapshot!(hd, @"9999-12-31");
+    }
+
+    #[test]
+    fn err_to_date() {
+        let gb = GregorianCutover::GREAT_BRITAIN;
+        let p = |y, m, d| gb.to_date(y, m, d).unwrap_err();
+
+        insta::assert_snapshot!(p(1752, 9, 3), @"date 1752-09-03 does not exist because it falls in the days skipped by the Gregorian cutover (1752-09-03 to 1752-09-13)");
+        insta::assert_snapshot!(p(1752, 9, 13), @"date 1752-09-13 does not exist because it falls in the days skipped by the Gregorian cutover (1752-09-03 to 1752-09-13)");
+        insta::assert_snapshot!(p(1700, 2, 30), @"Julian date 1700-02-30 is invalid because its day is not in the range 1..=29");
+        insta::assert_snapshot!(p(1701, 2, 29), @"Julian date 1701-02-29 is invalid because its day is not in the range 1..=28");
+        insta::assert_snapshot!(p(1700, 13, 1), @"Julian date 1700-13-01 is invalid because its month is not in the range 1..=12");
+        insta::assert_snapshot!(p(1800, 2, 29), @"parameter 'day' with value 29 is not in the required range of 1..=28");
+        insta::assert_snapshot!(p(-9999, 1, 1), @"Julian date -009999-01-01 is outside of the range supported by Jiff: parameter 'day' with value -4371664 is not in the required range of -4371587..=2932896");
+    }
+
+    #[test]
+    fn err_new() {
+        insta::assert_snapshot!(GregorianCutover::new(Date::MIN).unwrap_err(), @"failed to find last Julian date before Gregorian cutover on -009999-01-01: parameter 'year' with value 1 is not in the required range of -9999..=9999");
+        insta::assert_snapshot!(
+            GregorianCutover::new(date(200, 2, 28)).unwrap_err(),
+            @"Gregorian cutover on 0200-02-28 is invalid because the preceding Julian date, 0200-02-28, would not be written before it",
+        );
+        insta::assert_snapshot!(
+            GregorianCutover::new(date(200, 3, 2)).unwrap().last_julian(),
+            @"0200-03-01",
+        );
+    }
+}
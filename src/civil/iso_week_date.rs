@@ -1,6 +1,7 @@
 use crate::{
     civil::{Date, DateTime, Weekday},
     error::{err, Error},
+    fmt::temporal::{DEFAULT_DATETIME_PARSER, DEFAULT_DATETIME_PRINTER},
     util::{
         rangeint::RInto,
         t::{self, ISOWeek, ISOYear, C},
@@ -40,6 +41,26 @@ use crate::{
 /// For convenience, this type implements the `Default` trait. Its default
 /// value is the first day of the zeroth year. i.e., `0000-W1-1`.
 ///
+/// # Parsing and printing
+///
+/// The `Display` and `FromStr` trait implementations use the ISO 8601 week
+/// date format via [`fmt::temporal`](crate::fmt::temporal):
+///
+/// ```
+/// use jiff::civil::{ISOWeekDate, Weekday};
+///
+/// let weekdate: ISOWeekDate = "1994-W52-7".parse()?;
+/// assert_eq!(weekdate, ISOWeekDate::new(1994, 52, Weekday::Sunday)?);
+/// assert_eq!(weekdate.to_string(), "1994-W52-7");
+///
+/// // Any string that can be parsed into a `civil::Date` can also
+/// // be parsed into an `ISOWeekDate`.
+/// let weekdate: ISOWeekDate = "1995-01-01".parse()?;
+/// assert_eq!(weekdate.to_string(), "1994-W52-7");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
 /// # Example: sample dates
 ///
 /// This example shows a couple ISO 8601 week dates and their corresponding
@@ -747,6 +768,24 @@ impl core::fmt::Debug for ISOWeekDate {
     }
 }
 
+impl core::fmt::Display for ISOWeekDate {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use crate::fmt::StdFmtWrite;
+
+        DEFAULT_DATETIME_PRINTER
+            .print_iso_week_date(self, StdFmtWrite(f))
+            .map_err(|_| core::fmt::Error)
+    }
+}
+
+impl core::str::FromStr for ISOWeekDate {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<ISOWeekDate, Error> {
+        DEFAULT_DATETIME_PARSER.parse_iso_week_date(string)
+    }
+}
+
 impl Eq for ISOWeekDate {}
 
 impl PartialEq for ISOWeekDate {
@@ -78,6 +78,36 @@ assert_eq!(
 # Ok::<(), Box<dyn std::error::Error>>(())
 ```
 
+# Arithmetic with operators
+
+All of the civil types implement the `Add` and `Sub` traits for [`Span`],
+[`SignedDuration`] and [`std::time::Duration`], along with their `*Assign`
+counterparts. The behavior on overflow differs by type:
+
+* [`Time`] uses wrapping arithmetic, since that's how a clock on the wall
+  behaves. `23:00` plus two hours is `01:00`.
+* [`Date`] and [`DateTime`] use checked arithmetic and panic when the result
+  is out of Jiff's supported range.
+
+In every case, the `checked_add` and `checked_sub` methods (for example,
+[`Date::checked_add`]) return an error instead of wrapping or panicking. The
+`saturating_add` and `saturating_sub` methods clamp to the minimum or maximum
+value instead.
+
+```
+use jiff::{civil::{date, time}, SignedDuration, ToSpan};
+
+let two_hours = SignedDuration::from_hours(2);
+assert_eq!(time(23, 0, 0, 0) + two_hours, time(1, 0, 0, 0));
+assert!(time(23, 0, 0, 0).checked_add(two_hours).is_err());
+
+assert_eq!(date(2024, 2, 28) + 2.days(), date(2024, 3, 1));
+assert!(date(9999, 12, 31).checked_add(1.day()).is_err());
+```
+
+Note that [`Zoned`](crate::Zoned) also panics on overflow. Since it isn't
+`Copy`, its operators are implemented for both `&Zoned` and `Zoned`.
+
 # When should I use civil time?
 
 Here is a likely non-exhaustive list of reasons why you might want to use
@@ -95,6 +125,9 @@ you're in.
 saving time.
 * When interacting with legacy systems or systems that specifically do not
 support time zones.
+
+[`Span`]: crate::Span
+[`SignedDuration`]: crate::SignedDuration
 */
 
 pub use self::{
@@ -103,16 +136,23 @@ pub use self::{
         DateTime, DateTimeArithmetic, DateTimeDifference, DateTimeRound,
         DateTimeSeries, DateTimeWith,
     },
+    gregorian_cutover::{GregorianCutover, HistoricalDate},
     iso_week_date::ISOWeekDate,
+    month_grid::{MonthGrid, MonthGridDay},
     time::{
         Time, TimeArithmetic, TimeDifference, TimeRound, TimeSeries, TimeWith,
     },
     weekday::{Weekday, WeekdaysForward, WeekdaysReverse},
 };
 
+#[cfg(feature = "rand")]
+pub use self::date::DateUniform;
+
 mod date;
 mod datetime;
+mod gregorian_cutover;
 mod iso_week_date;
+mod month_grid;
 mod time;
 mod weekday;
 
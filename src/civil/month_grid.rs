@@ -0,0 +1,285 @@
+use crate::{
+    civil::{Date, Weekday},
+    error::{err, Error, ErrorContext},
+    ToSpan,
+};
+
+/// A 6x7 grid of dates covering a single month, as used by calendar widgets.
+///
+/// Each row in the grid is a week starting on the weekday given to
+/// [`Date::month_grid`]. The first row always contains the first day of the
+/// month, and any cells before it are filled with the trailing days of the
+/// previous month. Any cells after the last day of the month are filled with
+/// the leading days of the next month. Since every month spans at most 6
+/// distinct weeks, a grid with 6 rows can hold any month regardless of the
+/// weekday its first day falls on. Using a fixed number of rows means that
+/// the layout of a calendar doesn't change from month to month.
+///
+/// Each cell is a [`MonthGridDay`], which reports whether its date falls
+/// within the month the grid was created for.
+///
+/// # Example
+///
+/// This shows the grid for December 2024 with weeks starting on Monday. The
+/// first row starts in November and the last row runs into the next year:
+///
+/// ```
+/// use jiff::civil::{date, Weekday};
+///
+/// let grid = date(2024, 12, 25).month_grid(Weekday::Monday)?;
+/// let rows: Vec<String> = grid
+///     .weeks()
+///     .iter()
+///     .map(|week| {
+///         week.iter()
+///             .map(|day| {
+///                 if day.in_month() {
+///                     format!("{:>2}", day.date().day())
+///                 } else {
+///                     "  ".to_string()
+///                 }
+///             })
+///             .collect::<Vec<String>>()
+///             .join(" ")
+///     })
+///     .collect();
+/// assert_eq!(rows, vec![
+///     "                   1",
+///     " 2  3  4  5  6  7  8",
+///     " 9 10 11 12 13 14 15",
+///     "16 17 18 19 20 21 22",
+///     "23 24 25 26 27 28 29",
+///     "30 31               ",
+/// ]);
+///
+/// // The cells outside of the month are still available.
+/// assert_eq!(grid.weeks()[0][0].date(), date(2024, 11, 25));
+/// assert_eq!(grid.weeks()[5][6].date(), date(2025, 1, 5));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MonthGrid {
+    first: Date,
+    week_start: Weekday,
+    weeks: [[MonthGridDay; 7]; 6],
+}
+
+impl MonthGrid {
+    /// Creates the grid for the month containing `date`.
+    ///
+    /// This returns an error when the grid would contain a date outside of
+    /// Jiff's supported range. This can only happen for the first or last
+    /// month supported by Jiff.
+    pub(crate) fn new(
+        date: Date,
+        week_start: Weekday,
+    ) -> Result<MonthGrid, Error> {
+        let first = date.first_of_month();
+        let offset = first.weekday().since(week_start);
+        let mut cur = first
+            .checked_sub(i64::from(offset).days())
+            .and_then(|start| {
+                // Make sure the last cell is in range too, so that the loop
+                // below can't fail.
+                start.checked_add(41.days())?;
+                Ok(start)
+            })
+            .with_context(|| {
+                err!(
+                    "failed to create month grid for {year:04}-{month:02} \
+                     with weeks starting on {week_start:?}",
+                    year = first.year(),
+                    month = first.month(),
+                )
+            })?;
+
+        let blank = MonthGridDay { date: cur, in_month: false };
+        let mut weeks = [[blank; 7]; 6];
+        for (i, cell) in weeks.iter_mut().flatten().enumerate() {
+            *cell = MonthGridDay {
+                date: cur,
+                in_month: cur.first_of_month() == first,
+            };
+            if i < 41 {
+                // OK because we checked the last cell above.
+                cur = cur.tomorrow().unwrap();
+            }
+        }
+        Ok(MonthGrid { first, week_start, weeks })
+    }
+
+    /// Returns the weekday that each row in this grid starts on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, Weekday};
+    ///
+    /// let grid = date(2024, 6, 1).month_grid(Weekday::Sunday)?;
+    /// assert_eq!(grid.week_start(), Weekday::Sunday);
+    /// assert_eq!(grid.weeks()[0][0].date().weekday(), Weekday::Sunday);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    /// Returns the first day of the month this grid was created for.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, Weekday};
+    ///
+    /// let grid = date(2024, 6, 15).month_grid(Weekday::Monday)?;
+    /// assert_eq!(grid.first_of_month(), date(2024, 6, 1));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn first_of_month(&self) -> Date {
+        self.first
+    }
+
+    /// Returns the rows of this grid, where each row is a week.
+    ///
+    /// # Example
+    ///
+    /// This shows how to find the week that contains a specific date:
+    ///
+    /// ```
+    /// use jiff::civil::{date, Weekday};
+    ///
+    /// let grid = date(2024, 2, 1).month_grid(Weekday::Monday)?;
+    /// let row = grid
+    ///     .weeks()
+    ///     .iter()
+    ///     .position(|week| week.iter().any(|d| d.date() == date(2024, 2, 29)));
+    /// assert_eq!(row, Some(4));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn weeks(&self) -> &[[MonthGridDay; 7]; 6] {
+        &self.weeks
+    }
+
+    /// Returns the cell at the given row and column, if one exists.
+    ///
+    /// Rows are in the range `0..6` and columns are in the range `0..7`.
+    /// Column `0` always corresponds to [`MonthGrid::week_start`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{date, Weekday};
+    ///
+    /// let grid = date(2025, 1, 1).month_grid(Weekday::Sunday)?;
+    /// let day = grid.get(0, 0).unwrap();
+    /// assert_eq!(day.date(), date(2024, 12, 29));
+    /// assert!(!day.in_month());
+    ///
+    /// let day = grid.get(0, 3).unwrap();
+    /// assert_eq!(day.date(), date(2025, 1, 1));
+    /// assert!(day.in_month());
+    ///
+    /// assert_eq!(grid.get(6, 0), None);
+    /// assert_eq!(grid.get(0, 7), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn get(&self, row: usize, column: usize) -> Option<MonthGridDay> {
+        self.weeks.get(row)?.get(column).copied()
+    }
+}
+
+/// A single cell in a [`MonthGrid`].
+///
+/// This is a date along with whether it falls within the month that the
+/// grid was created for.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MonthGridDay {
+    date: Date,
+    in_month: bool,
+}
+
+impl MonthGridDay {
+    /// Returns the date of this cell.
+    #[inline]
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Returns true when the date of this cell falls within the month that
+    /// the grid was created for.
+    ///
+    /// Cells for which this returns false belong to the previous or next
+    /// month. Calendar widgets typically render them dimmed or not at all.
+    #[inline]
+    pub fn in_month(&self) -> bool {
+        self.in_month
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::civil::{date, Date};
+
+    use super::*;
+
+    #[test]
+    fn every_month() {
+        let weekdays = [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+            Weekday::Sunday,
+        ];
+        for year in [1999, 2000, 2023, 2024] {
+            for month in 1..=12 {
+                let first = date(year, month, 1);
+                for week_start in weekdays {
+                    let grid = first.month_grid(week_start).unwrap();
+                    assert_eq!(grid.first_of_month(), first);
+                    let days: alloc::vec::Vec<MonthGridDay> =
+                        grid.weeks().iter().flatten().copied().collect();
+                    assert_eq!(days[0].date().weekday(), week_start);
+                    assert_eq!(
+                        days.iter().filter(|d| d.in_month()).count(),
+                        usize::try_from(first.days_in_month()).unwrap(),
+                    );
+                    for pair in days.windows(2) {
+                        assert_eq!(
+                            pair[0].date().tomorrow().unwrap(),
+                            pair[1].date()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn boundaries() {
+        // -9999-01-01 is a Monday, so the grid fits when weeks start on
+        // Monday, but not otherwise.
+        assert_eq!(Date::MIN.weekday(), Weekday::Monday);
+        let grid = Date::MIN.month_grid(Weekday::Monday).unwrap();
+        assert_eq!(grid.weeks()[0][0].date(), Date::MIN);
+        insta::assert_snapshot!(
+            Date::MIN.month_grid(Weekday::Sunday).unwrap_err(),
+            @"failed to create month grid for -9999-01 with weeks starting on Sunday: parameter 'year' with value 1 is not in the required range of -9999..=9999",
+        );
+        insta::assert_snapshot!(
+            Date::MAX.month_grid(Weekday::Monday).unwrap_err(),
+            @"failed to create month grid for 9999-12 with weeks starting on Monday: parameter 'days' with value 41 is not in the required range of -4371587..=2932896",
+        );
+    }
+}
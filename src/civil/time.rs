@@ -2099,6 +2099,76 @@ impl<'a> From<&'a Zoned> for Time {
     }
 }
 
+/// Converts a Jiff civil time to a `chrono` naive time.
+#[cfg(feature = "chrono")]
+impl From<Time> for chrono::NaiveTime {
+    #[inline]
+    fn from(time: Time) -> chrono::NaiveTime {
+        let (hour, minute, second, nanosecond) = (
+            u32::from(time.hour().unsigned_abs()),
+            u32::from(time.minute().unsigned_abs()),
+            u32::from(time.second().unsigned_abs()),
+            time.subsec_nanosecond().unsigned_abs(),
+        );
+        // OK because every Jiff time is a valid `chrono` time.
+        chrono::NaiveTime::from_hms_nano_opt(hour, minute, second, nanosecond)
+            .unwrap()
+    }
+}
+
+/// Converts a `chrono` naive time to a Jiff civil time.
+///
+/// `chrono` represents a leap second by a fractional second of one second
+/// or more on the 59th second. Since Jiff doesn't support leap seconds, the
+/// extra second is dropped. For example, `23:59:60.5` becomes
+/// `23:59:59.5`. This is consistent with how Jiff clamps a leap second when
+/// parsing a time.
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveTime> for Time {
+    #[inline]
+    fn from(time: chrono::NaiveTime) -> Time {
+        use chrono::Timelike;
+
+        let (hour, minute, second, nanosecond) = (
+            time.hour() as i8,
+            time.minute() as i8,
+            time.second() as i8,
+            (time.nanosecond() % 1_000_000_000) as i32,
+        );
+        // OK because `chrono` guarantees every component is in range, and we
+        // dropped the leap second above.
+        Time::new(hour, minute, second, nanosecond).unwrap()
+    }
+}
+
+/// Converts a Jiff civil time to a `time` time.
+#[cfg(feature = "time")]
+impl From<Time> for ::time::Time {
+    #[inline]
+    fn from(time: Time) -> ::time::Time {
+        let (hour, minute, second, nanosecond) = (
+            time.hour().unsigned_abs(),
+            time.minute().unsigned_abs(),
+            time.second().unsigned_abs(),
+            time.subsec_nanosecond().unsigned_abs(),
+        );
+        // OK because every Jiff time is a valid `time` time.
+        ::time::Time::from_hms_nano(hour, minute, second, nanosecond).unwrap()
+    }
+}
+
+/// Converts a `time` time to a Jiff civil time.
+#[cfg(feature = "time")]
+impl From<::time::Time> for Time {
+    #[inline]
+    fn from(time: ::time::Time) -> Time {
+        let (hour, minute, second, nanosecond) = time.as_hms_nano();
+        // OK because `time` guarantees every component is in range.
+        Time::new(hour as i8, minute as i8, second as i8, nanosecond as i32)
+            .unwrap()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for Time {
     #[inline]
@@ -2150,6 +2220,20 @@ impl<'de> serde::Deserialize<'de> for Time {
     }
 }
 
+#[cfg(feature = "utoipa")]
+impl utoipa::PartialSchema for Time {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::util::schema::string(
+            None,
+            "A civil time, as an ISO 8601 time without an offset.",
+            "07:00:00",
+        )
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl utoipa::ToSchema for Time {}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for Time {
     fn arbitrary(g: &mut quickcheck::Gen) -> Time {
@@ -2183,6 +2267,23 @@ impl quickcheck::Arbitrary for Time {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Time {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Time> {
+        let hour: Hour = u.arbitrary()?;
+        let minute: Minute = u.arbitrary()?;
+        let second: Second = u.arbitrary()?;
+        let subsec_nanosecond: SubsecNanosecond = u.arbitrary()?;
+        Ok(Time::new_ranged(hour, minute, second, subsec_nanosecond))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(Hour, Minute, Second, SubsecNanosecond)>::size_hint(depth)
+    }
+}
+
 /// An iterator over periodic times, created by [`Time::series`].
 ///
 /// It is exhausted when the next value would exceed a [`Span`] or [`Time`]
@@ -3505,4 +3606,33 @@ mod tests {
 
         assert_eq!(deserialized, expected);
     }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_time() {
+        use alloc::string::ToString;
+
+        let nt = chrono::NaiveTime::from(time(17, 30, 59, 123_456_789));
+        assert_eq!(nt.to_string(), "17:30:59.123456789");
+        assert_eq!(Time::from(nt), time(17, 30, 59, 123_456_789));
+        assert_eq!(Time::from(chrono::NaiveTime::from(Time::MAX)), Time::MAX);
+
+        // Leap seconds are clamped to the 59th second.
+        let leap =
+            chrono::NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000)
+                .unwrap();
+        assert_eq!(Time::from(leap), time(23, 59, 59, 500_000_000));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_time() {
+        use ::time::macros::time as ttime;
+
+        let t = time(17, 30, 59, 123_456_789);
+        assert_eq!(::time::Time::from(t), ttime!(17:30:59.123_456_789));
+        assert_eq!(Time::from(ttime!(17:30:59.123_456_789)), t);
+        assert_eq!(Time::from(::time::Time::from(Time::MIN)), Time::MIN);
+        assert_eq!(Time::from(::time::Time::from(Time::MAX)), Time::MAX);
+    }
 }
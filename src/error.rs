@@ -179,7 +179,7 @@ impl Error {
     /// `FilePathError`.
     ///
     /// This is only available when the `std` feature is enabled.
-    #[cfg(feature = "tzdb-zoneinfo")]
+    #[cfg(feature = "std")]
     pub(crate) fn path(self, path: impl Into<std::path::PathBuf>) -> Error {
         let err = Error::from(ErrorKind::FilePath(FilePathError {
             path: path.into(),
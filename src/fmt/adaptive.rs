@@ -0,0 +1,389 @@
+/*!
+A printer that formats zoned datetimes with a granularity that adapts to how
+far away they are from "now."
+
+This is the style of datetime formatting commonly found in chat applications
+and email clients, where recent datetimes are written with just a time and
+older datetimes are written with just a date. Specifically, an
+[`AdaptivePrinter`] chooses one of four [`strtime`](super::strtime) format
+strings:
+
+| Tier | Default format | Example |
+| ---- | -------------- | ------- |
+| Same day as now | `%H:%M` | `14:32` |
+| Within the 6 days before today | `%a %H:%M` | `Tue 14:32` |
+| Same year as now | `%b %-d` | `Jun 3` |
+| Otherwise | `%Y-%m-%d` | `2019-06-03` |
+
+Each format can be changed via the corresponding method on `AdaptivePrinter`.
+
+The tier is chosen by comparing civil dates in the time zone of "now." That
+is, the datetime being printed is first converted to the time zone of "now."
+This means the output reflects the calendar of the person reading it.
+
+# Example
+
+```
+use jiff::{civil::date, fmt::adaptive::AdaptivePrinter};
+
+static PRINTER: AdaptivePrinter = AdaptivePrinter::new();
+
+let now = date(2024, 6, 13).at(18, 0, 0, 0).in_tz("America/New_York")?;
+let p = |zdt: &jiff::Zoned| PRINTER.zoned_to_string(zdt, &now).unwrap();
+
+let zdt = date(2024, 6, 13).at(14, 32, 0, 0).in_tz("America/New_York")?;
+assert_eq!(p(&zdt), "14:32");
+let zdt = date(2024, 6, 11).at(14, 32, 0, 0).in_tz("America/New_York")?;
+assert_eq!(p(&zdt), "Tue 14:32");
+let zdt = date(2024, 6, 3).at(14, 32, 0, 0).in_tz("America/New_York")?;
+assert_eq!(p(&zdt), "Jun 3");
+let zdt = date(2019, 6, 3).at(14, 32, 0, 0).in_tz("America/New_York")?;
+assert_eq!(p(&zdt), "2019-06-03");
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+use crate::{
+    civil::Date,
+    error::Error,
+    fmt::{strtime::BrokenDownTime, Write},
+    ToSpan, Zoned,
+};
+
+/// A printer that formats a [`Zoned`] relative to "now" with an adaptive
+/// granularity.
+///
+/// See the [module documentation](self) for a description of the tiers and
+/// their default formats.
+///
+/// Since "now" is passed to the printer explicitly, the printer doesn't need
+/// access to the system clock. This makes it usable without Jiff's `std`
+/// feature and makes its output deterministic in tests. Typically, callers
+/// will pass [`Zoned::now`] as "now."
+///
+/// # Example: custom tiers
+///
+/// This shows how to use 12-hour clock times and include the year in the
+/// last tier:
+///
+/// ```
+/// use jiff::{civil::date, fmt::adaptive::AdaptivePrinter};
+///
+/// static PRINTER: AdaptivePrinter = AdaptivePrinter::new()
+///     .today("%-I:%M %p")
+///     .this_week("%A %-I:%M %p")
+///     .older("%b %-d, %Y");
+///
+/// let now = date(2024, 6, 13).at(18, 0, 0, 0).in_tz("Europe/Paris")?;
+///
+/// let zdt = date(2024, 6, 13).at(9, 5, 0, 0).in_tz("Europe/Paris")?;
+/// assert_eq!(PRINTER.zoned_to_string(&zdt, &now)?, "9:05 AM");
+/// let zdt = date(2024, 6, 9).at(21, 5, 0, 0).in_tz("Europe/Paris")?;
+/// assert_eq!(PRINTER.zoned_to_string(&zdt, &now)?, "Sunday 9:05 PM");
+/// let zdt = date(2023, 12, 31).at(21, 5, 0, 0).in_tz("Europe/Paris")?;
+/// assert_eq!(PRINTER.zoned_to_string(&zdt, &now)?, "Dec 31, 2023");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Example: time zones
+///
+/// The tier is chosen in the time zone of "now," and the datetime is printed
+/// in that time zone too:
+///
+/// ```
+/// use jiff::{civil::date, fmt::adaptive::AdaptivePrinter};
+///
+/// static PRINTER: AdaptivePrinter = AdaptivePrinter::new();
+///
+/// let now = date(2024, 6, 13).at(8, 0, 0, 0).in_tz("Asia/Tokyo")?;
+/// // This is 2024-06-13T06:00 in Tokyo, so it's "today."
+/// let zdt = date(2024, 6, 12).at(17, 0, 0, 0).in_tz("America/New_York")?;
+/// assert_eq!(PRINTER.zoned_to_string(&zdt, &now)?, "06:00");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct AdaptivePrinter<'f> {
+    today: &'f str,
+    this_week: &'f str,
+    this_year: &'f str,
+    older: &'f str,
+}
+
+impl AdaptivePrinter<'static> {
+    /// Create a new adaptive printer with the default formats.
+    #[inline]
+    pub const fn new() -> AdaptivePrinter<'static> {
+        AdaptivePrinter {
+            today: "%H:%M",
+            this_week: "%a %H:%M",
+            this_year: "%b %-d",
+            older: "%Y-%m-%d",
+        }
+    }
+}
+
+impl<'f> AdaptivePrinter<'f> {
+    /// Set the format used for datetimes on the same day as "now."
+    ///
+    /// The default is `%H:%M`.
+    #[inline]
+    pub const fn today<'n>(self, format: &'n str) -> AdaptivePrinter<'n>
+    where
+        'f: 'n,
+    {
+        AdaptivePrinter {
+            today: format,
+            this_week: self.this_week,
+            this_year: self.this_year,
+            older: self.older,
+        }
+    }
+
+    /// Set the format used for datetimes within the 6 days before the day of
+    /// "now."
+    ///
+    /// Limiting this tier to 6 days means that a weekday name unambiguously
+    /// identifies a single day.
+    ///
+    /// The default is `%a %H:%M`.
+    #[inline]
+    pub const fn this_week<'n>(self, format: &'n str) -> AdaptivePrinter<'n>
+    where
+        'f: 'n,
+    {
+        AdaptivePrinter {
+            today: self.today,
+            this_week: format,
+            this_year: self.this_year,
+            older: self.older,
+        }
+    }
+
+    /// Set the format used for all other datetimes in the same year as
+    /// "now."
+    ///
+    /// This tier applies to datetimes after "now" too, except for those on
+    /// the same day as "now."
+    ///
+    /// The default is `%b %-d`.
+    #[inline]
+    pub const fn this_year<'n>(self, format: &'n str) -> AdaptivePrinter<'n>
+    where
+        'f: 'n,
+    {
+        AdaptivePrinter {
+            today: self.today,
+            this_week: self.this_week,
+            this_year: format,
+            older: self.older,
+        }
+    }
+
+    /// Set the format used for datetimes that don't fall into any other
+    /// tier.
+    ///
+    /// The default is `%Y-%m-%d`.
+    #[inline]
+    pub const fn older<'n>(self, format: &'n str) -> AdaptivePrinter<'n>
+    where
+        'f: 'n,
+    {
+        AdaptivePrinter {
+            today: self.today,
+            this_week: self.this_week,
+            this_year: self.this_year,
+            older: format,
+        }
+    }
+
+    /// Format a `Zoned` datetime relative to `now` into a string.
+    ///
+    /// This is a convenience routine for [`AdaptivePrinter::print_zoned`]
+    /// with a `String`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when formatting with the chosen format string
+    /// fails. See [`BrokenDownTime::format`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::adaptive::AdaptivePrinter, Zoned};
+    ///
+    /// static PRINTER: AdaptivePrinter = AdaptivePrinter::new();
+    ///
+    /// let now = Zoned::now();
+    /// let string = PRINTER.zoned_to_string(&now, &now)?;
+    /// assert_eq!(string, now.strftime("%H:%M").to_string());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn zoned_to_string(
+        &self,
+        zdt: &Zoned,
+        now: &Zoned,
+    ) -> Result<alloc::string::String, Error> {
+        let mut buf = alloc::string::String::with_capacity(4);
+        self.print_zoned(zdt, now, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Print a `Zoned` datetime relative to `now` to the given writer.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when writing to the given [`Write`]
+    /// implementation fails, or when formatting with the chosen format
+    /// string fails. See [`BrokenDownTime::format`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::adaptive::AdaptivePrinter};
+    ///
+    /// static PRINTER: AdaptivePrinter = AdaptivePrinter::new();
+    ///
+    /// let now = date(2025, 1, 2).at(9, 0, 0, 0).in_tz("UTC")?;
+    /// let zdt = date(2024, 12, 31).at(23, 0, 0, 0).in_tz("UTC")?;
+    ///
+    /// let mut buf = String::new();
+    /// PRINTER.print_zoned(&zdt, &now, &mut buf)?;
+    /// // Even though it's in a different year, it's within the last 6 days.
+    /// assert_eq!(buf, "Tue 23:00");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn print_zoned<W: Write>(
+        &self,
+        zdt: &Zoned,
+        now: &Zoned,
+        wtr: W,
+    ) -> Result<(), Error> {
+        let zdt = zdt.with_time_zone(now.time_zone().clone());
+        let format = self.format(Tier::new(zdt.date(), now.date()));
+        BrokenDownTime::from(&zdt).format(format, wtr)
+    }
+
+    /// Returns the format string for the given tier.
+    fn format(&self, tier: Tier) -> &'f str {
+        match tier {
+            Tier::Today => self.today,
+            Tier::ThisWeek => self.this_week,
+            Tier::ThisYear => self.this_year,
+            Tier::Older => self.older,
+        }
+    }
+}
+
+impl Default for AdaptivePrinter<'static> {
+    #[inline]
+    fn default() -> AdaptivePrinter<'static> {
+        AdaptivePrinter::new()
+    }
+}
+
+/// The tiers of granularity used by an [`AdaptivePrinter`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Tier {
+    Today,
+    ThisWeek,
+    ThisYear,
+    Older,
+}
+
+impl Tier {
+    /// Returns the tier that `date` falls into relative to `today`.
+    fn new(date: Date, today: Date) -> Tier {
+        if date == today {
+            return Tier::Today;
+        }
+        // The only way this can fail is if `today` is within the first 6
+        // days supported by Jiff, in which case every date before it is in
+        // this tier.
+        let week_start = today.checked_sub(6.days()).unwrap_or(Date::MIN);
+        if week_start <= date && date < today {
+            Tier::ThisWeek
+        } else if date.year() == today.year() {
+            Tier::ThisYear
+        } else {
+            Tier::Older
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod tests {
+    use crate::civil::date;
+
+    use super::*;
+
+    #[test]
+    fn tiers() {
+        let today = date(2024, 3, 6);
+        let tier = |date| Tier::new(date, today);
+
+        assert_eq!(tier(date(2024, 3, 6)), Tier::Today);
+        assert_eq!(tier(date(2024, 3, 5)), Tier::ThisWeek);
+        assert_eq!(tier(date(2024, 2, 29)), Tier::ThisWeek);
+        assert_eq!(tier(date(2024, 2, 28)), Tier::ThisYear);
+        assert_eq!(tier(date(2024, 3, 7)), Tier::ThisYear);
+        assert_eq!(tier(date(2024, 1, 1)), Tier::ThisYear);
+        assert_eq!(tier(date(2023, 12, 31)), Tier::Older);
+        assert_eq!(tier(date(2025, 3, 6)), Tier::Older);
+
+        // Weeks spanning a year boundary still use the weekly tier.
+        let today = date(2024, 1, 3);
+        assert_eq!(Tier::new(date(2023, 12, 28), today), Tier::ThisWeek);
+        assert_eq!(Tier::new(date(2023, 12, 27), today), Tier::Older);
+
+        assert_eq!(Tier::new(Date::MIN, Date::MIN), Tier::Today);
+        let tomorrow = Date::MIN.tomorrow().unwrap();
+        assert_eq!(Tier::new(Date::MIN, tomorrow), Tier::ThisWeek);
+    }
+
+    #[test]
+    fn print_across_dst() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let printer = AdaptivePrinter::new();
+        let now = date(2024, 3, 10)
+            .at(12, 0, 0, 0)
+            .in_tz("America/New_York")
+            .unwrap();
+        let p = |zdt: Zoned| printer.zoned_to_string(&zdt, &now).unwrap();
+
+        // 1:30 EST and 3:30 EDT are on the same day, across the gap.
+        insta::assert_snapshot!(
+            p(date(2024, 3, 10).at(1, 30, 0, 0).in_tz("America/New_York").unwrap()),
+            @"01:30",
+        );
+        insta::assert_snapshot!(
+            p(date(2024, 3, 10).at(3, 30, 0, 0).in_tz("America/New_York").unwrap()),
+            @"03:30",
+        );
+        // This is 2024-03-09T23:30 in New York.
+        insta::assert_snapshot!(
+            p(date(2024, 3, 10).at(4, 30, 0, 0).in_tz("UTC").unwrap()),
+            @"Sat 23:30",
+        );
+    }
+
+    #[test]
+    fn err_print() {
+        let printer = AdaptivePrinter::new().older("%Q %z %");
+        let now = date(2024, 3, 10).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+        let zdt = date(2020, 3, 10).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+        insta::assert_snapshot!(
+            printer.zoned_to_string(&zdt, &now).unwrap_err(),
+            @"strftime formatting failed: invalid format string, expected byte after '%', but found end of format string",
+        );
+    }
+}
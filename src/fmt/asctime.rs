@@ -0,0 +1,451 @@
+/*!
+Support for printing and parsing civil datetimes in the classic Unix
+`asctime` format.
+
+The format produced by the C functions `asctime` and `ctime` looks like
+`Wed Jun 30 21:49:08 2021`. Namely, an abbreviated weekday, an abbreviated
+month, a space padded day of the month, a time of day and a four digit
+year. It is still common in syslog-style log lines and some legacy
+protocols. It is also what `%c` produces in the `C` locale.
+
+The format carries no time zone information, so the parser and printer in
+this module only use [`civil::DateTime`](crate::civil::DateTime). Use APIs
+like [`Zoned::datetime`](crate::Zoned::datetime) or
+[`DateTime::to_zoned`](crate::civil::DateTime::to_zoned) to convert to and
+from a particular time zone.
+
+The grammar is case sensitive, and the parser rejects a weekday that is
+inconsistent with the date. While `ctime` appends a trailing newline, the
+parser does not accept one. Callers should trim it first.
+
+# Example
+
+```
+use jiff::{civil::date, fmt::asctime};
+
+let dt = date(2024, 6, 3).at(21, 49, 8, 0);
+assert_eq!(asctime::to_string(&dt)?, "Mon Jun  3 21:49:08 2024");
+assert_eq!(asctime::parse("Mon Jun  3 21:49:08 2024")?, dt);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+use crate::{
+    civil::DateTime,
+    error::{err, ErrorContext},
+    fmt::{
+        http::{
+            expect, new_date, parse_digits, parse_month, parse_time,
+            parse_weekday_abbrev, weekday_abbrev,
+        },
+        util::DecimalFormatter,
+        Parsed, Write, WriteExt,
+    },
+    Error,
+};
+
+/// The default date time parser that we use in this module.
+static DEFAULT_DATETIME_PARSER: DateTimeParser = DateTimeParser::new();
+
+/// The default date time printer that we use in this module.
+static DEFAULT_DATETIME_PRINTER: DateTimePrinter = DateTimePrinter::new();
+
+/// Convert a [`DateTime`] to a string in the `asctime` format.
+///
+/// This is a convenience function for using [`DateTimePrinter`]. In
+/// particular, this always creates and allocates a new `String`. For writing
+/// to an existing string, you'll need to use `DateTimePrinter`.
+///
+/// Since the `asctime` format does not support fractional seconds, the
+/// datetime is printed as if truncating any fractional seconds.
+///
+/// # Errors
+///
+/// This returns an error if the year of the datetime is negative, since it
+/// cannot be represented in the `asctime` format.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::date, fmt::asctime};
+///
+/// let dt = date(2024, 6, 30).at(21, 49, 8, 123_456_789);
+/// assert_eq!(asctime::to_string(&dt)?, "Sun Jun 30 21:49:08 2024");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_string(dt: &DateTime) -> Result<alloc::string::String, Error> {
+    let mut buf = alloc::string::String::new();
+    DEFAULT_DATETIME_PRINTER.print_datetime(dt, &mut buf)?;
+    Ok(buf)
+}
+
+/// Parse a string in the `asctime` format into a [`DateTime`].
+///
+/// This is a convenience function for using [`DateTimeParser`]. In
+/// particular, this takes a `&str` while the `DateTimeParser` accepts a
+/// `&[u8]`.
+///
+/// # Errors
+///
+/// This returns an error if the string given is not in the `asctime` format,
+/// or if the weekday is inconsistent with the date.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::date, fmt::asctime};
+///
+/// let dt = asctime::parse("Sun Jun 30 21:49:08 2024")?;
+/// assert_eq!(dt, date(2024, 6, 30).at(21, 49, 8, 0));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[inline]
+pub fn parse(string: &str) -> Result<DateTime, Error> {
+    DEFAULT_DATETIME_PARSER.parse_datetime(string)
+}
+
+/// A parser for the `asctime` format.
+///
+/// The day of the month may be space padded (e.g., `Jun  3`), which is what
+/// `asctime` produces, or zero padded (e.g., `Jun 03`). A leap second
+/// (`60`) is accepted and treated as `59`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::date, fmt::asctime::DateTimeParser};
+///
+/// static PARSER: DateTimeParser = DateTimeParser::new();
+///
+/// let dt = PARSER.parse_datetime("Mon Jun 03 21:49:08 2024")?;
+/// assert_eq!(dt, date(2024, 6, 3).at(21, 49, 8, 0));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct DateTimeParser {
+    // The asctime parser has no configuration at present.
+    _private: (),
+}
+
+impl DateTimeParser {
+    /// Create a new `asctime` parser with the default configuration.
+    #[inline]
+    pub const fn new() -> DateTimeParser {
+        DateTimeParser { _private: () }
+    }
+
+    /// Parse a string in the `asctime` format into a civil datetime.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the input is not in the `asctime` format,
+    /// if the weekday is inconsistent with the date, or if there is any
+    /// unparsed input remaining.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::asctime::DateTimeParser;
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let dt = PARSER.parse_datetime("Thu Feb 29 05:34:00 2024")?;
+    /// assert_eq!(dt.to_string(), "2024-02-29T05:34:00");
+    ///
+    /// // The weekday must match the date.
+    /// assert!(PARSER.parse_datetime("Fri Feb 29 05:34:00 2024").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_datetime<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<DateTime, Error> {
+        let input = input.as_ref();
+        let dt = self
+            .parse_datetime_internal(input)
+            .context("failed to parse asctime datetime")?
+            .into_full()?;
+        Ok(dt)
+    }
+
+    /// Parses an `asctime` datetime.
+    ///
+    /// Note that this doesn't check that the input has been completely
+    /// consumed.
+    fn parse_datetime_internal<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, DateTime>, Error> {
+        if input.is_empty() {
+            return Err(err!(
+                "expected asctime datetime, but got empty string"
+            ));
+        }
+        let (name, input) = input.split_at(input.len().min(3));
+        let wd = parse_weekday_abbrev(name)?;
+        let input = expect(input, b" ", "after weekday")?;
+        let Parsed { value: dt, input } = parse_after_weekday(input)?;
+        if wd != dt.weekday() {
+            return Err(err!(
+                "found parsed weekday of {parsed}, \
+                 but parsed datetime of {dt} has weekday \
+                 {has}",
+                parsed = weekday_abbrev(wd),
+                has = weekday_abbrev(dt.weekday()),
+            ));
+        }
+        Ok(Parsed { value: dt, input })
+    }
+}
+
+impl Default for DateTimeParser {
+    #[inline]
+    fn default() -> DateTimeParser {
+        DateTimeParser::new()
+    }
+}
+
+/// A printer for the `asctime` format.
+///
+/// The day of the month is always space padded, which matches the output of
+/// `asctime` and `ctime`. Unlike `ctime`, no trailing newline is written.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::date, fmt::asctime::DateTimePrinter};
+///
+/// static PRINTER: DateTimePrinter = DateTimePrinter::new();
+///
+/// let zdt = date(2024, 6, 3).at(21, 49, 8, 0).in_tz("America/New_York")?;
+/// let mut buf = String::new();
+/// PRINTER.print_datetime(&zdt.datetime(), &mut buf)?;
+/// assert_eq!(buf, "Mon Jun  3 21:49:08 2024");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct DateTimePrinter {
+    // The asctime printer has no configuration at present.
+    _private: (),
+}
+
+impl DateTimePrinter {
+    /// Create a new `asctime` printer with the default configuration.
+    #[inline]
+    pub const fn new() -> DateTimePrinter {
+        DateTimePrinter { _private: () }
+    }
+
+    /// Format a `DateTime` into a string in the `asctime` format.
+    ///
+    /// This is a convenience routine for [`DateTimePrinter::print_datetime`]
+    /// with a `String`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the year of the datetime is negative, since
+    /// it cannot be represented in the `asctime` format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::asctime::DateTimePrinter};
+    ///
+    /// static PRINTER: DateTimePrinter = DateTimePrinter::new();
+    ///
+    /// let dt = date(1970, 1, 1).at(0, 0, 1, 0);
+    /// assert_eq!(
+    ///     PRINTER.datetime_to_string(&dt)?,
+    ///     "Thu Jan  1 00:00:01 1970",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn datetime_to_string(
+        &self,
+        dt: &DateTime,
+    ) -> Result<alloc::string::String, Error> {
+        let mut buf = alloc::string::String::with_capacity(24);
+        self.print_datetime(dt, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Print a `DateTime` in the `asctime` format to the given writer.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when writing to the given [`Write`]
+    /// implementation would fail. This also returns an error if the year of
+    /// the datetime is negative, since it cannot be represented in the
+    /// `asctime` format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::asctime::DateTimePrinter};
+    ///
+    /// static PRINTER: DateTimePrinter = DateTimePrinter::new();
+    ///
+    /// let dt = date(2024, 12, 25).at(7, 0, 0, 0);
+    /// let mut buf = String::new();
+    /// PRINTER.print_datetime(&dt, &mut buf)?;
+    /// assert_eq!(buf, "Wed Dec 25 07:00:00 2024");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn print_datetime<W: Write>(
+        &self,
+        dt: &DateTime,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        static FMT_DAY: DecimalFormatter =
+            DecimalFormatter::new().padding(2).padding_byte(b' ');
+        static FMT_YEAR: DecimalFormatter = DecimalFormatter::new().padding(4);
+        static FMT_TIME_UNIT: DecimalFormatter =
+            DecimalFormatter::new().padding(2);
+
+        if dt.year() < 0 {
+            return Err(err!(
+                "datetime {dt} has negative year, \
+                 which cannot be formatted with asctime",
+            ));
+        }
+
+        wtr.write_str(weekday_abbrev(dt.weekday()))?;
+        wtr.write_str(" ")?;
+        wtr.write_str(month_abbrev(dt.month()))?;
+        wtr.write_str(" ")?;
+        wtr.write_int(&FMT_DAY, dt.day())?;
+        wtr.write_str(" ")?;
+        wtr.write_int(&FMT_TIME_UNIT, dt.hour())?;
+        wtr.write_str(":")?;
+        wtr.write_int(&FMT_TIME_UNIT, dt.minute())?;
+        wtr.write_str(":")?;
+        wtr.write_int(&FMT_TIME_UNIT, dt.second())?;
+        wtr.write_str(" ")?;
+        wtr.write_int(&FMT_YEAR, dt.year())?;
+        Ok(())
+    }
+}
+
+impl Default for DateTimePrinter {
+    #[inline]
+    fn default() -> DateTimePrinter {
+        DateTimePrinter::new()
+    }
+}
+
+/// Parses the remainder of an `asctime` datetime after the weekday and its
+/// trailing space, e.g., `Nov  6 08:49:37 1994`.
+///
+/// This is also used by the HTTP date parser, since RFC 9110 permits the
+/// `asctime` format.
+pub(super) fn parse_after_weekday<'i>(
+    input: &'i [u8],
+) -> Result<Parsed<'i, DateTime>, Error> {
+    let Parsed { value: month, input } = parse_month(input)?;
+    let input = expect(input, b" ", "after month")?;
+    // The day is either two digits or a space followed by one digit.
+    let Parsed { value: day, input } = match input.strip_prefix(b" ") {
+        Some(input) => parse_digits(input, 1, "day")?,
+        None => parse_digits(input, 2, "day")?,
+    };
+    let input = expect(input, b" ", "after day")?;
+    let Parsed { value: time, input } = parse_time(input)?;
+    let input = expect(input, b" ", "after time")?;
+    let Parsed { value: year, input } = parse_digits(input, 4, "year")?;
+    let date = new_date(year, month, day)?;
+    Ok(Parsed { value: DateTime::from_parts(date, time), input })
+}
+
+fn month_abbrev(month: i8) -> &'static str {
+    match month {
+        1 => "Jan",
+        2 => "Feb",
+        3 => "Mar",
+        4 => "Apr",
+        5 => "May",
+        6 => "Jun",
+        7 => "Jul",
+        8 => "Aug",
+        9 => "Sep",
+        10 => "Oct",
+        11 => "Nov",
+        12 => "Dec",
+        _ => unreachable!("invalid month value {month}"),
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::civil::date;
+
+    use super::*;
+
+    #[test]
+    fn ok_parse() {
+        let p = |input: &str| parse(input).unwrap().to_string();
+
+        insta::assert_snapshot!(p("Wed Jun 30 21:49:08 2021"), @"2021-06-30T21:49:08");
+        insta::assert_snapshot!(p("Mon Jun  3 21:49:08 2024"), @"2024-06-03T21:49:08");
+        insta::assert_snapshot!(p("Mon Jun 03 21:49:08 2024"), @"2024-06-03T21:49:08");
+        insta::assert_snapshot!(p("Thu Feb 29 23:59:60 2024"), @"2024-02-29T23:59:59");
+        insta::assert_snapshot!(p("Mon Jan  1 00:00:00 0001"), @"0001-01-01T00:00:00");
+        insta::assert_snapshot!(p("Fri Dec 31 23:59:59 9999"), @"9999-12-31T23:59:59");
+    }
+
+    #[test]
+    fn err_parse() {
+        let p = |input: &str| parse(input).unwrap_err();
+
+        insta::assert_snapshot!(p(""), @"failed to parse asctime datetime: expected asctime datetime, but got empty string");
+        insta::assert_snapshot!(p("Wed"), @r###"failed to parse asctime datetime: expected " " after weekday, but found """###);
+        insta::assert_snapshot!(p("wed Jun 30 21:49:08 2021"), @r###"failed to parse asctime datetime: did not recognize "wed" as a valid weekday abbreviation"###);
+        insta::assert_snapshot!(p("Wed jun 30 21:49:08 2021"), @r###"failed to parse asctime datetime: expected abbreviated month name, but did not recognize "jun" as a valid month"###);
+        insta::assert_snapshot!(p("Thu Jun 30 21:49:08 2021"), @"failed to parse asctime datetime: found parsed weekday of Thu, but parsed datetime of 2021-06-30T21:49:08 has weekday Wed");
+        insta::assert_snapshot!(p("Mon Jun 3 21:49:08 2024"), @r###"failed to parse asctime datetime: failed to parse "3 " as day (a 2 digit integer): invalid digit, expected 0-9 but got"###);
+        insta::assert_snapshot!(p("Wed Jun 31 21:49:08 2021"), @"failed to parse asctime datetime: invalid date: parameter 'day' with value 31 is not in the required range of 1..=30");
+        insta::assert_snapshot!(p("Wed Jun 30 21:49 2021"), @r###"failed to parse asctime datetime: expected ":" after minute, but found " ""###);
+        insta::assert_snapshot!(p("Wed Jun 30 21:49:08 21"), @"failed to parse asctime datetime: expected 4 digit year, but found end of input");
+        insta::assert_snapshot!(p("Wed Jun 30 21:49:08 2021\n"), @r###"parsed value '2021-06-30T21:49:08', but unparsed input "\n" remains (expected no unparsed input)"###);
+        insta::assert_snapshot!(p("Wed, Jun 30 21:49:08 2021"), @r###"failed to parse asctime datetime: expected " " after weekday, but found ",""###);
+    }
+
+    #[test]
+    fn ok_print() {
+        let p = |dt: DateTime| to_string(&dt).unwrap();
+
+        insta::assert_snapshot!(p(date(2021, 6, 30).at(21, 49, 8, 0)), @"Wed Jun 30 21:49:08 2021");
+        insta::assert_snapshot!(p(date(2024, 6, 3).at(1, 2, 3, 999)), @"Mon Jun  3 01:02:03 2024");
+        insta::assert_snapshot!(p(date(1, 1, 1).at(0, 0, 0, 0)), @"Mon Jan  1 00:00:00 0001");
+        insta::assert_snapshot!(p(DateTime::MAX), @"Fri Dec 31 23:59:59 9999");
+        insta::assert_snapshot!(
+            to_string(&DateTime::MIN).unwrap_err(),
+            @"datetime -009999-01-01T00:00:00 has negative year, which cannot be formatted with asctime",
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for dt in [
+            date(1970, 1, 1).at(0, 0, 0, 0),
+            date(1994, 11, 6).at(8, 49, 37, 0),
+            date(2024, 2, 29).at(23, 59, 59, 0),
+            DateTime::MAX.with().subsec_nanosecond(0).build().unwrap(),
+        ] {
+            assert_eq!(parse(&to_string(&dt).unwrap()).unwrap(), dt);
+        }
+    }
+}
@@ -0,0 +1,544 @@
+/*!
+Lenient parsing of times of day as a human might write them.
+
+The parser in this module is meant for interactive input, like a "remind me
+at" field or a command line flag, where users type things like `9`, `9:30`,
+`0930` or `9pm` and expect them to mean something reasonable. It produces a
+[`civil::Time`](crate::civil::Time). There is no printer, since the
+[`Display`](core::fmt::Display) implementation on `civil::Time` and the
+[`strtime`](crate::fmt::strtime) module already cover printing.
+
+The following forms are accepted, with surrounding whitespace ignored:
+
+* A bare hour, like `9` or `21`. Minutes and seconds are assumed to be `0`.
+* An hour and minute separated by a colon, like `9:30` or `21:30`,
+  optionally followed by seconds, like `21:30:15`.
+* Digits with no separator, like `930`, `0930` or `213015`. The last two
+  (or four) digits are always the minutes (and seconds).
+* Any of the above followed by a meridiem, like `9pm`, `9:30 am` or
+  `9 p.m.`. Meridiems are case insensitive and the `m` is optional, so `9P`
+  is also accepted.
+* The words `noon` and `midnight`, case insensitively.
+
+When no meridiem is given, the hour is interpreted on a 24-hour clock by
+default. See [`HourInference`] for inferring a meridiem instead.
+
+# Example
+
+```
+use jiff::{civil::time, fmt::clock};
+
+assert_eq!(clock::parse("9")?, time(9, 0, 0, 0));
+assert_eq!(clock::parse("9:30")?, time(9, 30, 0, 0));
+assert_eq!(clock::parse("0930")?, time(9, 30, 0, 0));
+assert_eq!(clock::parse("9pm")?, time(21, 0, 0, 0));
+assert_eq!(clock::parse("12:15 a.m.")?, time(0, 15, 0, 0));
+assert_eq!(clock::parse("Noon")?, time(12, 0, 0, 0));
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+use crate::{
+    civil::Time,
+    error::{err, ErrorContext},
+    fmt::Parsed,
+    util::escape,
+    Error,
+};
+
+/// The default time parser that we use in this module.
+static DEFAULT_TIME_PARSER: TimeParser = TimeParser::new();
+
+/// Parse a loosely written time of day into a [`Time`].
+///
+/// This is a convenience function for using [`TimeParser`] with its default
+/// configuration. In particular, bare hours are accepted and hours without
+/// a meridiem are interpreted on a 24-hour clock.
+///
+/// # Errors
+///
+/// This returns an error if the input is not in one of the forms described
+/// in the [module documentation](self), or if any component is out of range.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::time, fmt::clock};
+///
+/// assert_eq!(clock::parse("  7:05pm ")?, time(19, 5, 0, 0));
+/// assert_eq!(clock::parse("1905")?, time(19, 5, 0, 0));
+/// assert!(clock::parse("25:00").is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[inline]
+pub fn parse(string: &str) -> Result<Time, Error> {
+    DEFAULT_TIME_PARSER.parse_time(string)
+}
+
+/// A lenient parser for times of day.
+///
+/// See the [module documentation](self) for the forms accepted.
+///
+/// # Example
+///
+/// This shows how to configure a parser for a scheduling form where bare
+/// hours like `3` are more likely to mean the afternoon than the night.
+///
+/// ```
+/// use jiff::{
+///     civil::time,
+///     fmt::clock::{HourInference, TimeParser},
+/// };
+///
+/// static PARSER: TimeParser = TimeParser::new()
+///     .hour_inference(HourInference::StartingAt(time(7, 0, 0, 0)));
+///
+/// assert_eq!(PARSER.parse_time("9")?, time(9, 0, 0, 0));
+/// assert_eq!(PARSER.parse_time("3")?, time(15, 0, 0, 0));
+/// assert_eq!(PARSER.parse_time("3:30am")?, time(3, 30, 0, 0));
+/// assert_eq!(PARSER.parse_time("0330")?, time(3, 30, 0, 0));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct TimeParser {
+    hour_only: bool,
+    inference: HourInference,
+}
+
+impl TimeParser {
+    /// Create a new clock time parser with the default configuration.
+    #[inline]
+    pub const fn new() -> TimeParser {
+        TimeParser {
+            hour_only: true,
+            inference: HourInference::TwentyFourHour,
+        }
+    }
+
+    /// Whether to accept a bare hour, like `9`, with no minutes and no
+    /// meridiem.
+    ///
+    /// When enabled (the default), the minutes and seconds of a bare hour
+    /// are assumed to be `0`. When disabled, a bare hour is rejected since
+    /// it may just as well be a typo or a quantity that isn't a time at all.
+    /// An hour with a meridiem, like `9pm`, is accepted either way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, fmt::clock::TimeParser};
+    ///
+    /// static PARSER: TimeParser = TimeParser::new().hour_only(false);
+    ///
+    /// assert!(PARSER.parse_time("9").is_err());
+    /// assert_eq!(PARSER.parse_time("9pm")?, time(21, 0, 0, 0));
+    /// assert_eq!(PARSER.parse_time("9:00")?, time(9, 0, 0, 0));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn hour_only(self, yes: bool) -> TimeParser {
+        TimeParser { hour_only: yes, ..self }
+    }
+
+    /// Set how an hour is interpreted when no meridiem is given.
+    ///
+    /// By default, this is [`HourInference::TwentyFourHour`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     civil::time,
+    ///     fmt::clock::{HourInference, TimeParser},
+    /// };
+    ///
+    /// static PARSER: TimeParser = TimeParser::new()
+    ///     .hour_inference(HourInference::StartingAt(time(12, 0, 0, 0)));
+    ///
+    /// assert_eq!(PARSER.parse_time("9:30")?, time(21, 30, 0, 0));
+    /// // Hours outside of 1 through 12 are never ambiguous.
+    /// assert_eq!(PARSER.parse_time("13:30")?, time(13, 30, 0, 0));
+    /// // Neither are zero padded hours.
+    /// assert_eq!(PARSER.parse_time("09:30")?, time(9, 30, 0, 0));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn hour_inference(self, inference: HourInference) -> TimeParser {
+        TimeParser { inference, ..self }
+    }
+
+    /// Parse a loosely written time of day into a civil time.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the input is not in one of the forms
+    /// described in the [module documentation](self), if any component is
+    /// out of range, or if there is any unparsed input remaining.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, fmt::clock::TimeParser};
+    ///
+    /// static PARSER: TimeParser = TimeParser::new();
+    ///
+    /// assert_eq!(PARSER.parse_time(b"11:59:59 PM")?, time(23, 59, 59, 0));
+    /// assert!(PARSER.parse_time("13pm").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_time<I: AsRef<[u8]>>(&self, input: I) -> Result<Time, Error> {
+        let input = input.as_ref();
+        self.parse_time_internal(trim_whitespace(input)).with_context(|| {
+            err!(
+                "failed to parse {input:?} as a clock time",
+                input = escape::Bytes(input),
+            )
+        })
+    }
+
+    /// Parses a clock time from input that has already had its surrounding
+    /// whitespace trimmed.
+    fn parse_time_internal(&self, input: &[u8]) -> Result<Time, Error> {
+        if input.is_empty() {
+            return Err(err!("expected clock time, but got empty string"));
+        }
+        if input.eq_ignore_ascii_case(b"noon") {
+            return Ok(Time::constant(12, 0, 0, 0));
+        }
+        if input.eq_ignore_ascii_case(b"midnight") {
+            return Ok(Time::midnight());
+        }
+        let Parsed { value: clock, input } = parse_clock(input)?;
+        let input = trim_whitespace(input);
+        let Parsed { value: pm, input } = parse_meridiem(input)?;
+        if !input.is_empty() {
+            return Err(err!(
+                "found unrecognized trailing input {input:?} after time",
+                input = escape::Bytes(input),
+            ));
+        }
+        let Some(pm) = pm else { return self.resolve_without_meridiem(clock) };
+        if !(1 <= clock.hour && clock.hour <= 12) {
+            return Err(err!(
+                "hour {hour} is not valid with a meridiem \
+                 (expected an hour in the range 1..=12)",
+                hour = clock.hour,
+            ));
+        }
+        let hour = (clock.hour % 12) + if pm { 12 } else { 0 };
+        Time::new(hour, clock.minute, clock.second, 0)
+    }
+
+    /// Converts a clock time with no meridiem into a civil time according
+    /// to this parser's configuration.
+    fn resolve_without_meridiem(&self, clock: Clock) -> Result<Time, Error> {
+        if !clock.has_minute && !self.hour_only {
+            return Err(err!(
+                "expected minutes or a meridiem after hour {hour}",
+                hour = clock.hour,
+            ));
+        }
+        let start = match self.inference {
+            HourInference::TwentyFourHour => None,
+            HourInference::StartingAt(start) => Some(start),
+        };
+        let Some(start) = start.filter(|_| clock.is_ambiguous()) else {
+            return Time::new(clock.hour, clock.minute, clock.second, 0);
+        };
+        let am = Time::new(clock.hour % 12, clock.minute, clock.second, 0)?;
+        let pm = Time::new(am.hour() + 12, clock.minute, clock.second, 0)?;
+        if am >= start {
+            Ok(am)
+        } else if pm >= start {
+            Ok(pm)
+        } else {
+            Ok(am)
+        }
+    }
+}
+
+impl Default for TimeParser {
+    fn default() -> TimeParser {
+        TimeParser::new()
+    }
+}
+
+/// How to interpret an hour written without a meridiem.
+///
+/// This is used with [`TimeParser::hour_inference`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum HourInference {
+    /// Interpret the hour on a 24-hour clock. That is, `9` is `09:00` and
+    /// `21` is `21:00`.
+    ///
+    /// This is the default.
+    #[default]
+    TwentyFourHour,
+    /// Interpret the hour on a 12-hour clock, picking whichever of the two
+    /// candidate times is the first at or after the given time of day. If
+    /// neither is, then the clock wraps around midnight and the earlier
+    /// candidate is used.
+    ///
+    /// For example, with a start of `07:00`, `9` is `09:00` while `3` is
+    /// `15:00`. With a start of `12:00`, `12` is noon, but with a start of
+    /// `00:00`, `12` is midnight.
+    ///
+    /// Only hours in the range `1..=12` written without a leading zero are
+    /// inferred. Other hours, like `0`, `09` or `13`, are always interpreted
+    /// on a 24-hour clock.
+    StartingAt(Time),
+}
+
+/// The components of a clock time before any meridiem is applied.
+#[derive(Debug)]
+struct Clock {
+    hour: i8,
+    minute: i8,
+    second: i8,
+    /// Whether minutes were written explicitly.
+    has_minute: bool,
+    /// Whether the hour was written with a leading zero, e.g., `09`.
+    padded: bool,
+}
+
+impl Clock {
+    /// Returns true when this clock time could be on a 12-hour clock.
+    fn is_ambiguous(&self) -> bool {
+        !self.padded && 1 <= self.hour && self.hour <= 12
+    }
+}
+
+/// Parses the hour, minute and second of a clock time.
+///
+/// The minute and second may be separated by colons, or be written as the
+/// trailing digits of a single run of digits.
+fn parse_clock(input: &[u8]) -> Result<Parsed<'_, Clock>, Error> {
+    let (digits, input) = split_digits(input);
+    if digits.is_empty() {
+        return Err(err!(
+            "expected hour, but found {input:?}",
+            input = escape::Bytes(input),
+        ));
+    }
+    if input.first() == Some(&b':') {
+        if digits.len() > 2 {
+            return Err(err!(
+                "expected one or two digit hour before ':', but found {:?}",
+                escape::Bytes(digits),
+            ));
+        }
+        let (minute, input) = parse_two_digits(&input[1..], "minute")?;
+        let (second, input) = match input.first() {
+            Some(&b':') => parse_two_digits(&input[1..], "second")?,
+            _ => (0, input),
+        };
+        let clock = Clock {
+            hour: digits_value(digits),
+            minute,
+            second,
+            has_minute: true,
+            padded: is_padded(digits),
+        };
+        return Ok(Parsed { value: clock, input });
+    }
+    let hour_len = match digits.len() {
+        1 | 2 => digits.len(),
+        3 | 4 => digits.len() - 2,
+        5 | 6 => digits.len() - 4,
+        _ => {
+            return Err(err!(
+                "expected at most 6 digits for a clock time, but found {:?}",
+                escape::Bytes(digits),
+            ))
+        }
+    };
+    let (hour, rest) = digits.split_at(hour_len);
+    let (minute, second) = rest.split_at(rest.len().min(2));
+    let clock = Clock {
+        hour: digits_value(hour),
+        minute: digits_value(minute),
+        second: digits_value(second),
+        has_minute: !minute.is_empty(),
+        padded: is_padded(hour),
+    };
+    Ok(Parsed { value: clock, input })
+}
+
+/// Parses an optional meridiem, returning true for `pm`.
+///
+/// The `m` and any periods are optional, so `a`, `am`, `a.m` and `a.m.` are
+/// all accepted.
+fn parse_meridiem(input: &[u8]) -> Result<Parsed<'_, Option<bool>>, Error> {
+    let pm = match input.first().map(u8::to_ascii_lowercase) {
+        None => return Ok(Parsed { value: None, input }),
+        Some(b'a') => false,
+        Some(b'p') => true,
+        Some(_) => {
+            return Err(err!(
+                "expected 'am' or 'pm' after time, but found {input:?}",
+                input = escape::Bytes(input),
+            ))
+        }
+    };
+    let mut input = skip_byte(&input[1..], b'.');
+    if input.first().map(u8::to_ascii_lowercase) == Some(b'm') {
+        input = skip_byte(&input[1..], b'.');
+    }
+    Ok(Parsed { value: Some(pm), input })
+}
+
+/// Parses exactly two ASCII digits.
+fn parse_two_digits<'i>(
+    input: &'i [u8],
+    what: &'static str,
+) -> Result<(i8, &'i [u8]), Error> {
+    let (digits, rest) = split_digits(input);
+    if digits.len() != 2 {
+        return Err(err!(
+            "expected two digit {what}, but found {input:?}",
+            input = escape::Bytes(input),
+        ));
+    }
+    Ok((digits_value(digits), rest))
+}
+
+/// Splits the input into its leading run of ASCII digits and the rest.
+fn split_digits(input: &[u8]) -> (&[u8], &[u8]) {
+    let len = input.iter().take_while(|b| b.is_ascii_digit()).count();
+    input.split_at(len)
+}
+
+/// Returns the value of at most two ASCII digits. Empty input is `0`.
+fn digits_value(digits: &[u8]) -> i8 {
+    debug_assert!(digits.len() <= 2);
+    digits.iter().fold(0, |acc, &b| acc * 10 + (b - b'0') as i8)
+}
+
+/// Returns true when the given hour digits have a leading zero.
+fn is_padded(digits: &[u8]) -> bool {
+    digits.len() == 2 && digits[0] == b'0'
+}
+
+/// Skips the given byte if it's the first byte of the input.
+fn skip_byte(input: &[u8], byte: u8) -> &[u8] {
+    match input.split_first() {
+        Some((&first, rest)) if first == byte => rest,
+        _ => input,
+    }
+}
+
+/// Trims leading and trailing ASCII whitespace.
+fn trim_whitespace(mut input: &[u8]) -> &[u8] {
+    while let Some((first, rest)) = input.split_first() {
+        if !first.is_ascii_whitespace() {
+            break;
+        }
+        input = rest;
+    }
+    while let Some((last, rest)) = input.split_last() {
+        if !last.is_ascii_whitespace() {
+            break;
+        }
+        input = rest;
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::civil::time;
+
+    use super::*;
+
+    #[test]
+    fn ok_parse() {
+        let p = |input: &str| parse(input).unwrap().to_string();
+
+        insta::assert_snapshot!(p("9"), @"09:00:00");
+        insta::assert_snapshot!(p("09"), @"09:00:00");
+        insta::assert_snapshot!(p("0"), @"00:00:00");
+        insta::assert_snapshot!(p("23"), @"23:00:00");
+        insta::assert_snapshot!(p("9:30"), @"09:30:00");
+        insta::assert_snapshot!(p("21:30:15"), @"21:30:15");
+        insta::assert_snapshot!(p("930"), @"09:30:00");
+        insta::assert_snapshot!(p("0930"), @"09:30:00");
+        insta::assert_snapshot!(p("93015"), @"09:30:15");
+        insta::assert_snapshot!(p("213015"), @"21:30:15");
+        insta::assert_snapshot!(p("9pm"), @"21:00:00");
+        insta::assert_snapshot!(p("9 PM"), @"21:00:00");
+        insta::assert_snapshot!(p("9p"), @"21:00:00");
+        insta::assert_snapshot!(p("9:30 a.m."), @"09:30:00");
+        insta::assert_snapshot!(p("930P.M"), @"21:30:00");
+        insta::assert_snapshot!(p("12am"), @"00:00:00");
+        insta::assert_snapshot!(p("12:30pm"), @"12:30:00");
+        insta::assert_snapshot!(p(" noon "), @"12:00:00");
+        insta::assert_snapshot!(p("MIDNIGHT"), @"00:00:00");
+    }
+
+    #[test]
+    fn err_parse() {
+        let p = |input: &str| parse(input).unwrap_err();
+
+        insta::assert_snapshot!(p(""), @r###"failed to parse "" as a clock time: expected clock time, but got empty string"###);
+        insta::assert_snapshot!(p("   "), @r###"failed to parse "   " as a clock time: expected clock time, but got empty string"###);
+        insta::assert_snapshot!(p("pm"), @r###"failed to parse "pm" as a clock time: expected hour, but found "pm""###);
+        insta::assert_snapshot!(p("24"), @r###"failed to parse "24" as a clock time: parameter 'hour' with value 24 is not in the required range of 0..=23"###);
+        insta::assert_snapshot!(p("9:3"), @r###"failed to parse "9:3" as a clock time: expected two digit minute, but found "3""###);
+        insta::assert_snapshot!(p("9:60"), @r###"failed to parse "9:60" as a clock time: parameter 'minute' with value 60 is not in the required range of 0..=59"###);
+        insta::assert_snapshot!(p("930:00"), @r###"failed to parse "930:00" as a clock time: expected one or two digit hour before ':', but found "930""###);
+        insta::assert_snapshot!(p("1234567"), @r###"failed to parse "1234567" as a clock time: expected at most 6 digits for a clock time, but found "1234567""###);
+        insta::assert_snapshot!(p("0pm"), @r###"failed to parse "0pm" as a clock time: hour 0 is not valid with a meridiem (expected an hour in the range 1..=12)"###);
+        insta::assert_snapshot!(p("13pm"), @r###"failed to parse "13pm" as a clock time: hour 13 is not valid with a meridiem (expected an hour in the range 1..=12)"###);
+        insta::assert_snapshot!(p("9 o'clock"), @r###"failed to parse "9 o\'clock" as a clock time: expected 'am' or 'pm' after time, but found "o\'clock""###);
+        insta::assert_snapshot!(p("9amx"), @r###"failed to parse "9amx" as a clock time: found unrecognized trailing input "x" after time"###);
+        insta::assert_snapshot!(p("9:30:60"), @r###"failed to parse "9:30:60" as a clock time: parameter 'second' with value 60 is not in the required range of 0..=59"###);
+    }
+
+    #[test]
+    fn hour_only() {
+        let parser = TimeParser::new().hour_only(false);
+        let p = |input: &str| parser.parse_time(input);
+
+        insta::assert_snapshot!(p("9").unwrap_err(), @r###"failed to parse "9" as a clock time: expected minutes or a meridiem after hour 9"###);
+        assert_eq!(p("9am").unwrap(), time(9, 0, 0, 0));
+        assert_eq!(p("900").unwrap(), time(9, 0, 0, 0));
+    }
+
+    #[test]
+    fn hour_inference() {
+        let at = |start: Time, input: &str| {
+            TimeParser::new()
+                .hour_inference(HourInference::StartingAt(start))
+                .parse_time(input)
+                .unwrap()
+        };
+
+        let morning = time(7, 0, 0, 0);
+        assert_eq!(at(morning, "7"), time(7, 0, 0, 0));
+        assert_eq!(at(morning, "6:59"), time(18, 59, 0, 0));
+        assert_eq!(at(morning, "12"), time(12, 0, 0, 0));
+        assert_eq!(at(morning, "11:30"), time(11, 30, 0, 0));
+        assert_eq!(at(morning, "0600"), time(6, 0, 0, 0));
+        assert_eq!(at(morning, "600"), time(18, 0, 0, 0));
+        assert_eq!(at(morning, "6am"), time(6, 0, 0, 0));
+        assert_eq!(at(morning, "0"), time(0, 0, 0, 0));
+        assert_eq!(at(morning, "19"), time(19, 0, 0, 0));
+
+        let evening = time(20, 0, 0, 0);
+        assert_eq!(at(evening, "7"), time(7, 0, 0, 0));
+        assert_eq!(at(evening, "8"), time(20, 0, 0, 0));
+
+        let midnight = Time::midnight();
+        assert_eq!(at(midnight, "12"), time(0, 0, 0, 0));
+        assert_eq!(at(midnight, "1:15"), time(1, 15, 0, 0));
+    }
+}
@@ -0,0 +1,355 @@
+/*!
+Support for classifying a string by the datetime or duration format it
+appears to use.
+
+Jiff supports a number of different formats, and each format has its own
+parser. When a program accepts datetimes or durations from users in more than
+one format, it needs to decide which parser to use. Trying each parser in turn
+works, but when every parser fails, it's hard to report an error that is
+useful to the user, since it isn't clear which error is the relevant one.
+
+The [`detect`] function in this module instead inspects the _shape_ of a
+string and classifies it as a [`Format`] without fully parsing it. The caller
+can then branch on the detected format and use the corresponding parser. If
+that parser fails, then its error is almost certainly the relevant one.
+
+Detection is a heuristic: a string classified as some format may still fail
+to parse in that format. For example, `2024-02-30T00:00Z` is classified as
+[`Format::Rfc3339`] even though February 30 doesn't exist. Detection never
+fails though. If a string doesn't look like any of the supported formats,
+then [`detect`] returns `None`.
+
+# Example
+
+This shows how to accept either a timestamp or a duration, and report an
+error specific to the detected format:
+
+```
+use jiff::{fmt::detect::{detect, Format}, Span, Timestamp};
+
+fn describe(input: &str) -> String {
+    match detect(input) {
+        Some(Format::Rfc3339 | Format::Rfc9557) => {
+            match input.parse::<Timestamp>() {
+                Ok(ts) => format!("timestamp {ts}"),
+                Err(err) => format!("invalid timestamp: {err}"),
+            }
+        }
+        Some(Format::IsoDuration | Format::FriendlyDuration) => {
+            match input.parse::<Span>() {
+                Ok(span) => format!("duration {span:#}"),
+                Err(err) => format!("invalid duration: {err}"),
+            }
+        }
+        Some(format) => format!("unsupported format: {format:?}"),
+        None => format!("unrecognized input"),
+    }
+}
+
+assert_eq!(describe("2024-06-15T07:00Z"), "timestamp 2024-06-15T07:00:00Z");
+assert_eq!(describe("PT5M"), "duration 5m");
+assert_eq!(describe("5 minutes ago"), "duration 5m ago");
+assert!(describe("2024-13-15T07:00Z").starts_with("invalid timestamp:"));
+assert_eq!(describe("1718434800"), "unsupported format: EpochInteger");
+assert_eq!(describe("next tuesday"), "unrecognized input");
+```
+*/
+
+/// A datetime or duration format that a string appears to use.
+///
+/// This is returned by [`detect`]. See its documentation for how each
+/// format is recognized.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Format {
+    /// An [RFC 3339] timestamp with an offset and without any bracketed
+    /// annotations, like `2024-06-15T07:00:00-04:00` or
+    /// `2024-06-15T11:00:00Z`.
+    ///
+    /// This can be parsed as a [`Timestamp`](crate::Timestamp).
+    ///
+    /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+    Rfc3339,
+    /// An [RFC 9557] datetime with at least one bracketed annotation, like
+    /// `2024-06-15T07:00:00-04:00[America/New_York]`.
+    ///
+    /// This can be parsed as a [`Zoned`](crate::Zoned), and as a
+    /// [`Timestamp`](crate::Timestamp) if it also has an offset.
+    ///
+    /// [RFC 9557]: https://datatracker.ietf.org/doc/html/rfc9557
+    Rfc9557,
+    /// An [ISO 8601] civil datetime, date or time without an offset or
+    /// annotations, like `2024-06-15T07:00`, `2024-06-15` or `07:00:00`.
+    ///
+    /// This can be parsed as a [`civil::DateTime`](crate::civil::DateTime),
+    /// [`civil::Date`](crate::civil::Date) or
+    /// [`civil::Time`](crate::civil::Time), respectively.
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    Iso8601,
+    /// An [RFC 2822] datetime, like `Sat, 15 Jun 2024 07:00:00 -0400`.
+    ///
+    /// This can be parsed with [`rfc2822::parse`](crate::fmt::rfc2822::parse).
+    ///
+    /// [RFC 2822]: https://datatracker.ietf.org/doc/html/rfc2822
+    Rfc2822,
+    /// An [ISO 8601] duration, like `P1Y2M3DT4H5M6S` or `-PT30M`.
+    ///
+    /// This can be parsed as a [`Span`](crate::Span), and as a
+    /// [`SignedDuration`](crate::SignedDuration) if it has no units bigger
+    /// than hours.
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    IsoDuration,
+    /// A duration in Jiff's [friendly](crate::fmt::friendly) format, like
+    /// `1 year 2 months`, `5m30s` or `2h ago`.
+    ///
+    /// This can be parsed as a [`Span`](crate::Span), and as a
+    /// [`SignedDuration`](crate::SignedDuration) if it has no units bigger
+    /// than hours.
+    FriendlyDuration,
+    /// An integer, optionally signed, like `1718434800` or `-86400`.
+    ///
+    /// This is typically a number of seconds (or milliseconds, and so on)
+    /// since the Unix epoch. Since the unit is context dependent, Jiff
+    /// doesn't parse these directly. Instead, parse the integer and use a
+    /// routine like [`Timestamp::from_second`](crate::Timestamp::from_second).
+    EpochInteger,
+}
+
+/// Classifies the given input by the datetime or duration format it appears
+/// to use.
+///
+/// This only inspects the shape of the input. It doesn't check whether the
+/// input is actually valid in the detected format. If the input doesn't look
+/// like any supported format, then this returns `None`.
+///
+/// Detection works as follows, after an optional leading `+` or `-` sign:
+///
+/// * Input that starts with `P` is an [`Format::IsoDuration`].
+/// * Input that is entirely ASCII digits is an [`Format::EpochInteger`].
+/// * Input that starts with an abbreviated weekday and a comma, or with a
+///   day number followed by whitespace and a month abbreviation, is an
+///   [`Format::Rfc2822`] datetime.
+/// * Input that starts with a date like `2024-06-15` (or `+002024-06-15`),
+///   or a time like `07:00` or `T07:00`, is an [`Format::Rfc9557`]
+///   datetime if it contains a bracketed annotation, an [`Format::Rfc3339`]
+///   datetime if it has an offset, or an [`Format::Iso8601`] civil datetime
+///   otherwise.
+/// * Input that starts with a number followed by a unit designator, like
+///   `5m` or `1.5 hours`, is a [`Format::FriendlyDuration`].
+///
+/// Leading and trailing whitespace is not permitted by any of Jiff's parsers,
+/// so input with leading whitespace is never classified.
+///
+/// # Example
+///
+/// ```
+/// use jiff::fmt::detect::{detect, Format};
+///
+/// assert_eq!(detect("2024-06-15T07:00:00Z"), Some(Format::Rfc3339));
+/// assert_eq!(detect("2024-06-15 07:00-04"), Some(Format::Rfc3339));
+/// assert_eq!(
+///     detect("2024-06-15T07:00-04[America/New_York]"),
+///     Some(Format::Rfc9557),
+/// );
+/// assert_eq!(detect("2024-06-15T07:00"), Some(Format::Iso8601));
+/// assert_eq!(detect("2024-06-15"), Some(Format::Iso8601));
+/// assert_eq!(detect("07:00:00"), Some(Format::Iso8601));
+/// assert_eq!(
+///     detect("Sat, 15 Jun 2024 07:00:00 -0400"),
+///     Some(Format::Rfc2822),
+/// );
+/// assert_eq!(detect("P1Y2M3DT4H"), Some(Format::IsoDuration));
+/// assert_eq!(detect("1 year 2 months"), Some(Format::FriendlyDuration));
+/// assert_eq!(detect("-1718434800"), Some(Format::EpochInteger));
+///
+/// assert_eq!(detect(""), None);
+/// assert_eq!(detect("yesterday"), None);
+/// ```
+pub fn detect<I: AsRef<[u8]>>(input: I) -> Option<Format> {
+    let input = input.as_ref();
+    let unsigned = match input.first()? {
+        b'+' | b'-' => &input[1..],
+        _ => input,
+    };
+    let first = *unsigned.first()?;
+    if first == b'P' || first == b'p' {
+        return Some(Format::IsoDuration);
+    }
+    if unsigned.iter().all(u8::is_ascii_digit) {
+        return Some(Format::EpochInteger);
+    }
+    if is_rfc2822(input) {
+        return Some(Format::Rfc2822);
+    }
+    if let Some(rest) = datetime_remainder(unsigned) {
+        return Some(classify_datetime(rest));
+    }
+    if is_friendly(unsigned) {
+        return Some(Format::FriendlyDuration);
+    }
+    None
+}
+
+/// Returns true when the input starts like an RFC 2822 datetime.
+///
+/// That is, it starts with `Day,` (like `Sat,`) or a one or two digit day
+/// followed by whitespace and a month abbreviation (like `15 Jun `).
+fn is_rfc2822(input: &[u8]) -> bool {
+    if input.len() >= 4
+        && input[..3].iter().all(u8::is_ascii_alphabetic)
+        && input[3] == b','
+    {
+        return true;
+    }
+    let digits = leading_digits(input);
+    if !(1..=2).contains(&digits) {
+        return false;
+    }
+    let rest = &input[digits..];
+    let spaces = rest.iter().take_while(|b| b.is_ascii_whitespace()).count();
+    if spaces == 0 {
+        return false;
+    }
+    let rest = &rest[spaces..];
+    let Some(month) = rest.get(..3) else { return false };
+    let is_month = MONTHS.iter().any(|m| m.eq_ignore_ascii_case(month));
+    is_month && rest.get(3).is_some_and(u8::is_ascii_whitespace)
+}
+
+/// The month abbreviations used by RFC 2822.
+static MONTHS: [&[u8]; 12] = [
+    b"Jan", b"Feb", b"Mar", b"Apr", b"May", b"Jun", b"Jul", b"Aug", b"Sep",
+    b"Oct", b"Nov", b"Dec",
+];
+
+/// If the input starts with a date or time, then this returns everything
+/// after the date (or the entire input when it starts with a time).
+///
+/// A date is `YYYY-` or `YYYYYY-` (the latter being a sign-less extended
+/// year). A time is `T` followed by digits, or two digits followed by `:`.
+fn datetime_remainder(input: &[u8]) -> Option<&[u8]> {
+    let digits = leading_digits(input);
+    if (digits == 4 || digits == 6) && input.get(digits) == Some(&b'-') {
+        return Some(&input[digits..]);
+    }
+    if digits == 2 && input.get(2) == Some(&b':') {
+        return Some(input);
+    }
+    if matches!(input.first(), Some(b'T' | b't'))
+        && input.get(1).is_some_and(u8::is_ascii_digit)
+    {
+        return Some(input);
+    }
+    None
+}
+
+/// Classifies the remainder of a string that starts with a date or time.
+fn classify_datetime(rest: &[u8]) -> Format {
+    let (datetime, annotated) = match rest.iter().position(|&b| b == b'[') {
+        None => (rest, false),
+        Some(i) => (&rest[..i], true),
+    };
+    if annotated {
+        return Format::Rfc9557;
+    }
+    // An offset can only appear after a time, and a time is always
+    // introduced by a `:` (or by `T` for times like `T07`). Any `-` before
+    // that belongs to the date.
+    let time_start = datetime
+        .iter()
+        .position(|&b| matches!(b, b'T' | b't' | b' ' | b':'))
+        .unwrap_or(datetime.len());
+    let has_offset = datetime[time_start..]
+        .iter()
+        .any(|&b| matches!(b, b'Z' | b'z' | b'+' | b'-'));
+    if has_offset {
+        Format::Rfc3339
+    } else {
+        Format::Iso8601
+    }
+}
+
+/// Returns true when the input starts like a friendly duration.
+///
+/// That is, a number (with an optional fraction) followed by optional
+/// whitespace and then a letter.
+fn is_friendly(input: &[u8]) -> bool {
+    let mut i = leading_digits(input);
+    if i == 0 {
+        return false;
+    }
+    if matches!(input.get(i), Some(b'.' | b',')) {
+        i += 1;
+        i += leading_digits(&input[i..]);
+    }
+    let rest = &input[i..];
+    let spaces = rest.iter().take_while(|&&b| b == b' ').count();
+    rest.get(spaces).is_some_and(u8::is_ascii_alphabetic)
+}
+
+/// Returns the number of ASCII digits at the start of the input.
+fn leading_digits(input: &[u8]) -> usize {
+    input.iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ok_detect() {
+        let tests: &[(&str, Format)] = &[
+            ("2024-06-15T07:00:00Z", Format::Rfc3339),
+            ("2024-06-15t07:00:00z", Format::Rfc3339),
+            ("2024-06-15T07:00:00.123-04:00", Format::Rfc3339),
+            ("2024-06-15 07+05", Format::Rfc3339),
+            ("+002024-06-15T07:00Z", Format::Rfc3339),
+            ("-000001-06-15T07:00-01", Format::Rfc3339),
+            ("2024-06-15T07:00[America/New_York]", Format::Rfc9557),
+            ("2024-06-15T07:00-04[America/New_York]", Format::Rfc9557),
+            ("2024-06-15T07:00Z[u-ca=iso8601]", Format::Rfc9557),
+            ("2024-06-15T07:00", Format::Iso8601),
+            ("2024-06-15", Format::Iso8601),
+            ("-000001-06-15", Format::Iso8601),
+            ("07:00", Format::Iso8601),
+            ("T07", Format::Iso8601),
+            ("Sat, 15 Jun 2024 07:00:00 -0400", Format::Rfc2822),
+            ("15 Jun 2024 07:00:00 GMT", Format::Rfc2822),
+            ("5 Jun 2024 07:00:00 GMT", Format::Rfc2822),
+            ("P1D", Format::IsoDuration),
+            ("-PT30M", Format::IsoDuration),
+            ("pt1h", Format::IsoDuration),
+            ("5m", Format::FriendlyDuration),
+            ("1.5 hours", Format::FriendlyDuration),
+            ("-1d 2h", Format::FriendlyDuration),
+            ("+100 years", Format::FriendlyDuration),
+            ("5 minutes ago", Format::FriendlyDuration),
+            ("1 mar", Format::FriendlyDuration),
+            ("0", Format::EpochInteger),
+            ("-86400", Format::EpochInteger),
+            ("1718434800000", Format::EpochInteger),
+        ];
+        for &(input, expected) in tests {
+            assert_eq!(detect(input), Some(expected), "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn err_detect() {
+        for input in [
+            "",
+            "+",
+            "-",
+            " 2024-06-15",
+            "yesterday",
+            "24-06-15",
+            "1.5",
+            "12345-06-15",
+            "\u{2212}5m",
+        ] {
+            assert_eq!(detect(input), None, "input: {input:?}");
+        }
+    }
+}
@@ -66,7 +66,10 @@ for (string, span) in spans {
 Note that for a `SignedDuration`, only units up to hours are supported. If you
 need to support bigger units, then you'll need to convert it to a `Span` before
 printing to the friendly format (or parse into a `Span` and then convert to a
-`SignedDuration`).
+`SignedDuration`). Alternatively, if 24-hour days are appropriate for your use
+case, then [`SpanPrinter::duration_days`] and [`SpanParser::duration_days`]
+can be used to print and parse day units for a `SignedDuration`. For example,
+`1d 03:04:05.123`.
 
 # Integration points
 
@@ -163,8 +166,11 @@ then ISO 8601 is probably the right choice.
 
 # Rounding
 
-The printer in this module has no options for rounding. Instead, it is intended
-for users to round a [`Span`](crate::Span) first, and then print it. The idea
+The printer in this module has very limited options for rounding. (The only
+exception is [`SpanPrinter::max_units`], which rounds whatever units it
+omits, but it can only do so without a reference date.) Instead, it is
+intended for users to round a [`Span`](crate::Span) first, and then print it.
+The idea
 is that printing a `Span` is a relatively "dumb" operation that just emits
 whatever units are non-zero in the `Span`. This is possible with a `Span`
 because it represents each unit distinctly. (With a [`std::time::Duration`] or
@@ -81,16 +81,14 @@ use crate::{
 /// ```
 #[derive(Clone, Debug, Default)]
 pub struct SpanParser {
-    _private: (),
+    duration_days: bool,
 }
 
 impl SpanParser {
     /// Creates a new parser for the "friendly" duration format.
     ///
-    /// The parser returned uses the default configuration. (Although, at time
-    /// of writing, there are no available configuration options for this
-    /// parser.) This is identical to `SpanParser::default`, but it can be used
-    /// in a `const` context.
+    /// The parser returned uses the default configuration. This is identical
+    /// to `SpanParser::default`, but it can be used in a `const` context.
     ///
     /// # Example
     ///
@@ -112,7 +110,54 @@ impl SpanParser {
     /// ```
     #[inline]
     pub const fn new() -> SpanParser {
-        SpanParser { _private: () }
+        SpanParser { duration_days: false }
+    }
+
+    /// Whether to permit day units when parsing a [`SignedDuration`].
+    ///
+    /// When enabled, days are always interpreted as 24 hours when parsing
+    /// into a `SignedDuration`. This makes it possible to parse durations
+    /// written in a "days plus `HH:MM:SS`" style, like `1d 03:04:05.123`,
+    /// which is common in logs and operational tooling. This style is
+    /// what is printed by [`SpanPrinter`](super::SpanPrinter) when both
+    /// [`SpanPrinter::hours_minutes_seconds`](super::SpanPrinter::hours_minutes_seconds)
+    /// and [`SpanPrinter::duration_days`](super::SpanPrinter::duration_days)
+    /// are enabled.
+    ///
+    /// This is disabled by default, since days are not always 24 hours
+    /// long in the presence of time zone transitions. This setting has no
+    /// effect when parsing a [`Span`], since day units are always permitted
+    /// there.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::friendly::SpanParser, SignedDuration};
+    ///
+    /// static PARSER: SpanParser = SpanParser::new().duration_days(true);
+    ///
+    /// assert_eq!(
+    ///     PARSER.parse_duration("1d 03:04:05.123")?,
+    ///     SignedDuration::new(27 * 60 * 60 + 4 * 60 + 5, 123_000_000),
+    /// );
+    /// assert_eq!(
+    ///     PARSER.parse_duration("2 days, 5 hours")?,
+    ///     SignedDuration::from_hours(53),
+    /// );
+    /// // Hours aren't limited to 24, with or without this setting.
+    /// assert_eq!(
+    ///     PARSER.parse_duration("27:15:00")?,
+    ///     SignedDuration::from_secs(27 * 60 * 60 + 15 * 60),
+    /// );
+    ///
+    /// // Without this setting, day units are rejected.
+    /// assert!(SpanParser::new().parse_duration("1d 03:04:05").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn duration_days(self, yes: bool) -> SpanParser {
+        SpanParser { duration_days: yes }
     }
 
     /// Run the parser on the given string (which may be plain bytes) and,
@@ -334,8 +379,16 @@ impl SpanParser {
             let parsed = self.parse_suffix_sign(sign, input)?;
             (parsed.value, parsed.input)
         };
-        if sign < C(0) {
-            sdur = -sdur;
+        // The duration is accumulated as a negative value. See
+        // `parse_units_to_duration` for why.
+        if sign > C(0) {
+            sdur = sdur.checked_neg().ok_or_else(|| {
+                err!(
+                    "duration is too big to fit into a `SignedDuration` \
+                     (the maximum is {max:?})",
+                    max = SignedDuration::MAX,
+                )
+            })?;
         }
 
         Ok(Parsed { value: sdur, input })
@@ -465,6 +518,10 @@ impl SpanParser {
         let mut parsed_any_after_comma = true;
         let mut prev_unit: Option<Unit> = None;
         let mut value = first_unit_value;
+        // We accumulate the duration as a negative value and negate it at
+        // the end if necessary. Since the magnitude of `SignedDuration::MIN`
+        // is bigger than the magnitude of `SignedDuration::MAX`, this lets us
+        // parse every possible `SignedDuration` value.
         let mut sdur = SignedDuration::ZERO;
         loop {
             let parsed = self.parse_hms_maybe(input, value)?;
@@ -481,7 +538,7 @@ impl SpanParser {
                     }
                 }
                 sdur = sdur
-                    .checked_add(duration_unit_value(Unit::Hour, hms.hour)?)
+                    .checked_sub(duration_unit_value(Unit::Hour, hms.hour)?)
                     .ok_or_else(|| {
                         err!(
                             "accumulated `SignedDuration` overflowed when \
@@ -489,7 +546,7 @@ impl SpanParser {
                         )
                     })?;
                 sdur = sdur
-                    .checked_add(duration_unit_value(
+                    .checked_sub(duration_unit_value(
                         Unit::Minute,
                         hms.minute,
                     )?)
@@ -500,7 +557,7 @@ impl SpanParser {
                         )
                     })?;
                 sdur = sdur
-                    .checked_add(duration_unit_value(
+                    .checked_sub(duration_unit_value(
                         Unit::Second,
                         hms.second,
                     )?)
@@ -513,7 +570,7 @@ impl SpanParser {
                 if let Some(f) = hms.fraction {
                     // nanos += fractional_time_to_nanos(Unit::Second, fraction)?;
                     let f = fractional_time_to_duration(Unit::Second, f)?;
-                    sdur = sdur.checked_add(f).ok_or_else(|| err!(""))?;
+                    sdur = sdur.checked_sub(f).ok_or_else(|| err!(""))?;
                 };
                 break;
             }
@@ -559,7 +616,7 @@ impl SpanParser {
             prev_unit = Some(unit);
 
             sdur = sdur
-                .checked_add(duration_unit_value(unit, value)?)
+                .checked_sub(self.duration_unit_value(unit, value)?)
                 .ok_or_else(|| {
                     err!(
                         "accumulated `SignedDuration` overflowed when adding \
@@ -569,7 +626,7 @@ impl SpanParser {
                 })?;
             if let Some(f) = fraction {
                 let f = fractional_time_to_duration(unit, f)?;
-                sdur = sdur.checked_add(f).ok_or_else(|| err!(""))?;
+                sdur = sdur.checked_sub(f).ok_or_else(|| err!(""))?;
                 // Once we see a fraction, we are done. We don't permit parsing
                 // any more units. That is, a fraction can only occur on the
                 // lowest unit of time.
@@ -602,6 +659,24 @@ impl SpanParser {
         Ok(Parsed { value: sdur, input })
     }
 
+    /// Like the free function `duration_unit_value`, but also supports day
+    /// units (as 24 hours) when `duration_days` is enabled.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn duration_unit_value(
+        &self,
+        unit: Unit,
+        value: t::NoUnits,
+    ) -> Result<SignedDuration, Error> {
+        if unit == Unit::Day && self.duration_days {
+            let seconds = value.try_checked_mul(
+                "days-to-seconds",
+                t::SECONDS_PER_CIVIL_DAY,
+            )?;
+            return Ok(SignedDuration::from_secs(seconds.get()));
+        }
+        duration_unit_value(unit, value)
+    }
+
     /// This possibly parses a `HH:MM:SS[.fraction]`.
     ///
     /// This expects that a unit value has been parsed and looks for a `:`
@@ -1255,6 +1330,52 @@ mod tests {
         insta::assert_snapshot!(p("999:999:999"), @"PT1015H55M39S");
     }
 
+    #[test]
+    fn parse_duration_days() {
+        let parser = SpanParser::new().duration_days(true);
+        let p = |s: &str| parser.parse_duration(s).unwrap();
+        let perr = |s: &str| parser.parse_duration(s).unwrap_err();
+
+        insta::assert_snapshot!(p("1d 03:04:05.123"), @"PT27H4M5.123S");
+        insta::assert_snapshot!(p("1d 03:04:05.123 ago"), @"-PT27H4M5.123S");
+        insta::assert_snapshot!(p("-2d 01:00:00"), @"-PT49H");
+        insta::assert_snapshot!(p("2 days, 1 hour"), @"PT49H");
+        insta::assert_snapshot!(p("27:15:00"), @"PT27H15M");
+        insta::assert_snapshot!(
+            p("106751991167300d 15:30:08.999999999 ago"),
+            @"-PT2562047788015215H30M8.999999999S",
+        );
+        insta::assert_snapshot!(
+            perr("1.5d"),
+            @r###"failed to parse "1.5d" in the "friendly" format: fractional day units are not allowed"###,
+        );
+        insta::assert_snapshot!(
+            perr("1w 1d"),
+            @r###"failed to parse "1w 1d" in the "friendly" format: parsing week units into a `SignedDuration` is not supported (perhaps try parsing into a `Span` instead)"###,
+        );
+        insta::assert_snapshot!(
+            SpanParser::new().parse_duration("1d 03:04:05").unwrap_err(),
+            @r###"failed to parse "1d 03:04:05" in the "friendly" format: parsing day units into a `SignedDuration` is not supported (perhaps try parsing into a `Span` instead)"###,
+        );
+    }
+
+    #[test]
+    fn parse_accumulated_hours() {
+        let ps = |s: &str| SpanParser::new().parse_span(s).unwrap();
+        let pd = |s: &str| SpanParser::new().parse_duration(s).unwrap();
+
+        insta::assert_snapshot!(ps("52:30:00"), @"PT52H30M");
+        insta::assert_snapshot!(ps("-52:30:00"), @"-PT52H30M");
+        insta::assert_snapshot!(ps("52:30:00 ago"), @"-PT52H30M");
+        insta::assert_snapshot!(ps("1mo 49:00:00"), @"P1MT49H");
+        insta::assert_snapshot!(pd("52:30:00"), @"PT52H30M");
+        insta::assert_snapshot!(pd("192:00:01.5"), @"PT192H1.5S");
+        insta::assert_snapshot!(
+            pd("2562047788015215:30:07.999999999"),
+            @"PT2562047788015215H30M7.999999999S",
+        );
+    }
+
     #[test]
     fn parse_duration_negate() {
         let p = |s: &str| SpanParser::new().parse_duration(s).unwrap();
@@ -1280,6 +1401,20 @@ mod tests {
             perr("-9223372036854775808s"),
             @r###"failed to parse "-9223372036854775808s" in the "friendly" format: number '9223372036854775808' too big to parse into 64-bit integer"###,
         );
+        // But the minimum and maximum durations as emitted by the friendly
+        // printer must round trip.
+        insta::assert_snapshot!(
+            p("2562047788015215h 30m 8s 999ms 999µs 999ns ago"),
+            @"-PT2562047788015215H30M8.999999999S",
+        );
+        insta::assert_snapshot!(
+            p("2562047788015215h 30m 7s 999ms 999µs 999ns"),
+            @"PT2562047788015215H30M7.999999999S",
+        );
+        insta::assert_snapshot!(
+            perr("2562047788015215h 30m 8s"),
+            @r###"failed to parse "2562047788015215h 30m 8s" in the "friendly" format: duration is too big to fit into a `SignedDuration` (the maximum is 2562047788015215h 30m 7s 999ms 999µs 999ns)"###,
+        );
     }
 
     #[test]
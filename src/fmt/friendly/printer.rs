@@ -3,12 +3,15 @@ use crate::{
         util::{DecimalFormatter, FractionalFormatter},
         Write, WriteExt,
     },
-    Error, SignedDuration, Span, Unit,
+    util::t,
+    Error, RoundMode, SignedDuration, SignedDurationRound, Span, Unit,
 };
 
+const SECS_PER_DAY: i64 = HOURS_PER_DAY * SECS_PER_HOUR;
 const SECS_PER_HOUR: i64 = MINS_PER_HOUR * SECS_PER_MIN;
 const SECS_PER_MIN: i64 = 60;
 const MINS_PER_HOUR: i64 = 60;
+const HOURS_PER_DAY: i64 = 24;
 const NANOS_PER_HOUR: i128 =
     (SECS_PER_MIN * MINS_PER_HOUR * NANOS_PER_SEC) as i128;
 const NANOS_PER_MIN: i128 = (SECS_PER_MIN * NANOS_PER_SEC) as i128;
@@ -131,10 +134,11 @@ pub enum Designator {
 pub enum Spacing {
     /// Does not insert any ASCII whitespace.
     ///
-    /// Except in the case that [`SpanPrinter::hours_minutes_seconds`] is
-    /// enabled and one is formatting a span with non-zero calendar units, then
-    /// an ASCII whitespace is inserted between the calendar and non-calendar
-    /// units even when `Spacing::None` is used.
+    /// Except in the case that [`SpanPrinter::hours_minutes_seconds`] (or
+    /// [`SpanPrinter::accumulated_hours`]) is enabled and one is formatting a
+    /// span with non-zero calendar units, then an ASCII whitespace is inserted
+    /// between the calendar and non-calendar units even when `Spacing::None`
+    /// is used.
     None,
     /// Inserts one ASCII whitespace between the unit designator and the next
     /// unit value.
@@ -242,7 +246,9 @@ impl Direction {
                 Spacing::BetweenUnits
                 | Spacing::BetweenUnitsAndDesignators => {
                     if signum < 0 {
-                        if printer.hms && !has_calendar {
+                        if (printer.hms || printer.accumulated_hours)
+                            && !has_calendar
+                        {
                             Some(DirectionSign::Prefix("-"))
                         } else {
                             Some(DirectionSign::Suffix(" ago"))
@@ -460,9 +466,13 @@ pub struct SpanPrinter {
     fractional: Option<FractionalUnit>,
     comma_after_designator: bool,
     hms: bool,
+    accumulated_hours: bool,
+    duration_days: bool,
     padding: Option<u8>,
     precision: Option<u8>,
     zero_unit: Unit,
+    max_units: Option<u8>,
+    max_units_mode: RoundMode,
 }
 
 impl SpanPrinter {
@@ -496,9 +506,13 @@ impl SpanPrinter {
             fractional: None,
             comma_after_designator: false,
             hms: false,
+            accumulated_hours: false,
+            duration_days: false,
             padding: None,
             precision: None,
             zero_unit: Unit::Second,
+            max_units: None,
+            max_units_mode: RoundMode::HalfExpand,
         }
     }
 
@@ -811,6 +825,126 @@ impl SpanPrinter {
         SpanPrinter { hms: yes, ..self }
     }
 
+    /// Formats the span or duration as accumulated hours in a
+    /// `[h]:mm:ss[.fffffffff]` format.
+    ///
+    /// This is like [`SpanPrinter::hours_minutes_seconds`], except that all
+    /// units of weeks or smaller are folded into the hours component. Every
+    /// day is considered to be 24 hours long and every week 7 days long. The
+    /// minutes and seconds components are always balanced. So `2 days, 4
+    /// hours and 30 minutes` is written as `52:30:00`. This matches the
+    /// "accumulated time" style used by spreadsheet exports of timesheets.
+    ///
+    /// Since years and months have no invariant length, any non-zero year
+    /// or month units in a `Span` are written with their designators, in the
+    /// same way as [`SpanPrinter::hours_minutes_seconds`] does for calendar
+    /// units.
+    ///
+    /// When printing a [`SignedDuration`], this implies
+    /// [`SpanPrinter::hours_minutes_seconds`] and overrides
+    /// [`SpanPrinter::duration_days`].
+    ///
+    /// Durations written in this style can be parsed back by a
+    /// [`SpanParser`](super::SpanParser) without any additional
+    /// configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::friendly::SpanPrinter, SignedDuration, ToSpan};
+    ///
+    /// static PRINTER: SpanPrinter =
+    ///     SpanPrinter::new().accumulated_hours(true);
+    ///
+    /// let span = 2.days().hours(4).minutes(30);
+    /// assert_eq!(PRINTER.span_to_string(&span), "52:30:00");
+    /// // Unlike the `HH:MM:SS` format, the time units are balanced.
+    /// assert_eq!(PRINTER.span_to_string(&3_150.minutes()), "52:30:00");
+    /// assert_eq!(PRINTER.span_to_string(&-span), "-52:30:00");
+    ///
+    /// let duration = SignedDuration::from_mins(3_150);
+    /// assert_eq!(PRINTER.duration_to_string(&duration), "52:30:00");
+    /// ```
+    ///
+    /// # Example: round trip
+    ///
+    /// ```
+    /// use jiff::{fmt::friendly::{SpanParser, SpanPrinter}, ToSpan};
+    ///
+    /// static PRINTER: SpanPrinter =
+    ///     SpanPrinter::new().accumulated_hours(true);
+    /// static PARSER: SpanParser = SpanParser::new();
+    ///
+    /// let span = 1.week().hours(3).seconds(7);
+    /// let printed = PRINTER.span_to_string(&span);
+    /// assert_eq!(printed, "171:00:07");
+    ///
+    /// let parsed = PARSER.parse_span(&printed)?;
+    /// assert_eq!(parsed, 171.hours().seconds(7).fieldwise());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn accumulated_hours(self, yes: bool) -> SpanPrinter {
+        SpanPrinter { accumulated_hours: yes, ..self }
+    }
+
+    /// Whether to write whole days when printing a [`SignedDuration`].
+    ///
+    /// When enabled, every 24 hours in a `SignedDuration` is written as a
+    /// single day unit. This is useful when combined with
+    /// [`SpanPrinter::hours_minutes_seconds`] to write durations in a
+    /// "days plus `HH:MM:SS`" style, like `1d 03:04:05.123`, which is common
+    /// in logs and operational tooling. It also works with the default
+    /// format using unit designators.
+    ///
+    /// This is disabled by default, since days are not always 24 hours long
+    /// in the presence of time zone transitions. When this is disabled, the
+    /// largest unit used for a `SignedDuration` is hours. (And, in the
+    /// hours-minutes-seconds format, the hours component may exceed `24`.)
+    ///
+    /// This setting has no effect when printing a [`Span`], since a `Span`
+    /// keeps track of its day units separately from its hour units.
+    ///
+    /// To parse durations written in this style back into a
+    /// `SignedDuration`, use
+    /// [`SpanParser::duration_days`](super::SpanParser::duration_days).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::friendly::SpanPrinter, SignedDuration};
+    ///
+    /// let duration = SignedDuration::new(
+    ///     27 * 60 * 60 + 4 * 60 + 5,
+    ///     123_000_000,
+    /// );
+    ///
+    /// let printer = SpanPrinter::new().hours_minutes_seconds(true);
+    /// assert_eq!(printer.duration_to_string(&duration), "27:04:05.123");
+    /// let printer = printer.duration_days(true);
+    /// assert_eq!(printer.duration_to_string(&duration), "1d 03:04:05.123");
+    /// // As with spans, the sign is written as a suffix by default when
+    /// // a day unit is present.
+    /// assert_eq!(
+    ///     printer.duration_to_string(&-duration),
+    ///     "1d 03:04:05.123 ago",
+    /// );
+    /// // When there are no whole days, no day unit is written.
+    /// assert_eq!(
+    ///     printer.duration_to_string(&SignedDuration::from_mins(90)),
+    ///     "01:30:00",
+    /// );
+    ///
+    /// // This also works with unit designators.
+    /// let printer = SpanPrinter::new().duration_days(true);
+    /// assert_eq!(printer.duration_to_string(&duration), "1d 3h 4m 5s 123ms");
+    /// ```
+    #[inline]
+    pub const fn duration_days(self, yes: bool) -> SpanPrinter {
+        SpanPrinter { duration_days: yes, ..self }
+    }
+
     /// The padding to use when writing unit values.
     ///
     /// If a unit value has fewer digits than specified here, it is padded to
@@ -968,6 +1102,92 @@ impl SpanPrinter {
         SpanPrinter { zero_unit: unit, ..self }
     }
 
+    /// Sets the maximum number of non-zero units to write.
+    ///
+    /// When a span or duration has more non-zero units than the maximum
+    /// given, then only the biggest units are written. The remaining smaller
+    /// units are used to round the smallest unit written according to the
+    /// mode set by [`SpanPrinter::max_units_mode`]. By default, this uses
+    /// [`RoundMode::HalfExpand`].
+    ///
+    /// This is useful in user interfaces where the full precision of a
+    /// duration is usually too much detail. For example, `1y 2mo` is often
+    /// preferred over `1y 2mo 3d 4h 5m`.
+    ///
+    /// A value of `Some(0)` is treated as if it were `Some(1)`. The default
+    /// is `None`, which means all non-zero units are written.
+    ///
+    /// This setting is ignored when [`SpanPrinter::hours_minutes_seconds`]
+    /// is enabled.
+    ///
+    /// # Rounding without a reference date
+    ///
+    /// Since the printer has no reference date, rounding only incorporates
+    /// units that have an invariant relationship with the smallest unit
+    /// written. Namely, years are always 12 months, weeks are always 7 days
+    /// and days are always 24 hours. Since months do not have an invariant
+    /// number of days, any weeks or days (or smaller units) following a month
+    /// unit are truncated. If you need precise rounding of calendar units,
+    /// then use [`Span::round`] with a relative date before printing.
+    ///
+    /// When rounding results in a unit value that overflows into the next
+    /// biggest unit written, it is carried over. For example, `1h 59m 45s`
+    /// with a maximum of two units is written as `2h`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::friendly::SpanPrinter, SignedDuration, ToSpan};
+    ///
+    /// let printer = SpanPrinter::new().max_units(Some(2));
+    ///
+    /// let span = 1.year().months(2).days(3).hours(4);
+    /// assert_eq!(printer.span_to_string(&span), "1y 2mo");
+    ///
+    /// // The remainder is used for rounding.
+    /// let span = 3.days().hours(4).minutes(31);
+    /// assert_eq!(printer.span_to_string(&span), "3d 5h");
+    /// let span = 1.year().months(11).days(3);
+    /// assert_eq!(printer.span_to_string(&span), "1y 11mo");
+    /// let span = 1.year().months(6);
+    /// assert_eq!(printer.span_to_string(&span), "1y 6mo");
+    ///
+    /// // Carrying into a bigger unit may decrease the number of units.
+    /// let dur = SignedDuration::new(59 * 60 + 59, 999_999_999);
+    /// assert_eq!(printer.duration_to_string(&dur), "1h");
+    /// ```
+    #[inline]
+    pub const fn max_units(self, max: Option<u8>) -> SpanPrinter {
+        SpanPrinter { max_units: max, ..self }
+    }
+
+    /// Sets the rounding mode used when [`SpanPrinter::max_units`] results
+    /// in units being omitted.
+    ///
+    /// This defaults to [`RoundMode::HalfExpand`]. Use [`RoundMode::Trunc`]
+    /// to drop the omitted units without any rounding.
+    ///
+    /// This setting has no effect if `max_units` is not set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::friendly::SpanPrinter, RoundMode, ToSpan};
+    ///
+    /// let span = 2.hours().minutes(59).seconds(59);
+    ///
+    /// let printer = SpanPrinter::new().max_units(Some(1));
+    /// assert_eq!(printer.span_to_string(&span), "3h");
+    ///
+    /// let printer = printer.max_units_mode(RoundMode::Trunc);
+    /// assert_eq!(printer.span_to_string(&span), "2h");
+    /// assert_eq!(printer.span_to_string(&-span), "2h ago");
+    /// ```
+    #[inline]
+    pub const fn max_units_mode(self, mode: RoundMode) -> SpanPrinter {
+        SpanPrinter { max_units_mode: mode, ..self }
+    }
+
     /// Format a `Span` into a string using the "friendly" format.
     ///
     /// This is a convenience routine for [`SpanPrinter::print_span`] with a
@@ -1063,6 +1283,9 @@ impl SpanPrinter {
         span: &Span,
         wtr: W,
     ) -> Result<(), Error> {
+        if self.accumulated_hours {
+            return self.print_span_accumulated_hours(span, wtr);
+        }
         if self.hms {
             return self.print_span_hms(span, wtr);
         }
@@ -1106,7 +1329,7 @@ impl SpanPrinter {
         duration: &SignedDuration,
         wtr: W,
     ) -> Result<(), Error> {
-        if self.hms {
+        if self.hms || self.accumulated_hours {
             return self.print_duration_hms(duration, wtr);
         }
         self.print_duration_designators(duration, wtr)
@@ -1117,6 +1340,7 @@ impl SpanPrinter {
         span: &Span,
         mut wtr: W,
     ) -> Result<(), Error> {
+        let span = &self.limit_span_units(span);
         let mut wtr =
             DesignatorWriter::new(self, &mut wtr, false, span.signum());
         wtr.maybe_write_prefix_sign()?;
@@ -1235,14 +1459,44 @@ impl SpanPrinter {
         Ok(())
     }
 
+    fn print_span_accumulated_hours<W: Write>(
+        &self,
+        span: &Span,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        // Only years and months lack an invariant length. Everything else is
+        // folded into the hours component.
+        let span_cal = span.without_lower(Unit::Month);
+        let dur = span.only_lower(Unit::Month).to_duration_invariant();
+        let has_cal = !span_cal.is_zero();
+
+        let mut wtr =
+            DesignatorWriter::new(self, &mut wtr, has_cal, span.signum());
+        wtr.maybe_write_prefix_sign()?;
+        if has_cal {
+            self.print_span_designators_non_fraction(&span_cal, &mut wtr)?;
+            wtr.finish_preceding()?;
+            // See the comment in `print_span_hms` for why this is needed.
+            if matches!(self.spacing, Spacing::None) {
+                wtr.wtr.write_str(" ")?;
+            }
+        }
+        self.print_hms_duration(&dur, &mut wtr)?;
+        wtr.maybe_write_suffix_sign()?;
+        Ok(())
+    }
+
     fn print_duration_designators<W: Write>(
         &self,
         dur: &SignedDuration,
         mut wtr: W,
     ) -> Result<(), Error> {
+        let dur = &self.limit_duration_units(dur);
         let mut wtr =
             DesignatorWriter::new(self, &mut wtr, false, dur.signum());
         wtr.maybe_write_prefix_sign()?;
+        let (days, dur) = &self.split_duration_days(dur);
+        wtr.write(Unit::Day, days.abs())?;
         match self.fractional {
             None => {
                 let mut secs = dur.as_secs();
@@ -1332,17 +1586,35 @@ impl SpanPrinter {
         // and then format the `Span` as-is. But this doesn't work
         // because the range of a `SignedDuration` is much bigger.
 
+        let (days, rest) = self.split_duration_days(dur);
+        let mut wtr =
+            DesignatorWriter::new(self, &mut wtr, days != 0, dur.signum());
+        wtr.maybe_write_prefix_sign()?;
+        if days != 0 {
+            wtr.write(Unit::Day, days.abs())?;
+            wtr.finish_preceding()?;
+            // See the comment in `print_span_hms` for why this is needed.
+            if matches!(self.spacing, Spacing::None) {
+                wtr.wtr.write_str(" ")?;
+            }
+        }
+
+        self.print_hms_duration(&rest, &mut wtr)?;
+        wtr.maybe_write_suffix_sign()?;
+        Ok(())
+    }
+
+    /// Writes the given duration as `[h]:mm:ss[.fffffffff]`, with the hours
+    /// component unbounded. The sign of the duration is ignored.
+    fn print_hms_duration<'p, 'w, W: Write>(
+        &self,
+        dur: &SignedDuration,
+        wtr: &mut DesignatorWriter<'p, 'w, W>,
+    ) -> Result<(), Error> {
         let fmtint =
             DecimalFormatter::new().padding(self.padding.unwrap_or(2));
         let fmtfraction = FractionalFormatter::new().precision(self.precision);
 
-        if dur.is_negative() {
-            if !matches!(self.direction, Direction::Suffix) {
-                wtr.write_str("-")?;
-            }
-        } else if let Direction::ForceSign = self.direction {
-            wtr.write_str("+")?;
-        }
         let mut secs = dur.as_secs();
         // OK because guaranteed to be bigger than i64::MIN.
         let hours = (secs / (MINS_PER_HOUR * SECS_PER_MIN)).abs();
@@ -1352,10 +1624,10 @@ impl SpanPrinter {
         // OK because guaranteed to be bigger than i64::MIN.
         secs = (secs % SECS_PER_MIN).abs();
 
-        wtr.write_int(&fmtint, hours)?;
-        wtr.write_str(":")?;
-        wtr.write_int(&fmtint, minutes)?;
-        wtr.write_str(":")?;
+        wtr.wtr.write_int(&fmtint, hours)?;
+        wtr.wtr.write_str(":")?;
+        wtr.wtr.write_int(&fmtint, minutes)?;
+        wtr.wtr.write_str(":")?;
         let fp = FractionalPrinter::from_duration(
             // OK because -999_999_999 <= nanos <= 999_999_999 and secs < 60.
             &SignedDuration::new(secs, dur.subsec_nanos().abs()),
@@ -1363,13 +1635,142 @@ impl SpanPrinter {
             fmtint,
             fmtfraction,
         );
-        fp.print(&mut wtr)?;
-        if dur.is_negative() {
-            if matches!(self.direction, Direction::Suffix) {
-                wtr.write_str(" ago")?;
+        fp.print(&mut wtr.wtr)?;
+        Ok(())
+    }
+
+    /// Splits the given duration into a number of whole 24-hour days and
+    /// the remaining duration (which is always less than 24 hours).
+    ///
+    /// When `duration_days` isn't enabled (or is overridden by
+    /// `accumulated_hours`), then this always returns zero days and the
+    /// duration given.
+    fn split_duration_days(
+        &self,
+        dur: &SignedDuration,
+    ) -> (i64, SignedDuration) {
+        if !self.duration_days || self.accumulated_hours {
+            return (0, *dur);
+        }
+        let secs = dur.as_secs();
+        // OK because the whole seconds and nanoseconds are guaranteed to
+        // have the same sign, and the remaining seconds are less than a
+        // day.
+        let rest =
+            SignedDuration::new(secs % SECS_PER_DAY, dur.subsec_nanos());
+        (secs / SECS_PER_DAY, rest)
+    }
+
+    /// Returns a span with at most `max_units` non-zero units, where any
+    /// omitted units are used to round the smallest unit kept.
+    ///
+    /// If `max_units` isn't set or if the span has few enough non-zero units,
+    /// then the span given is returned unchanged.
+    fn limit_span_units(&self, span: &Span) -> Span {
+        let Some(max) = self.max_units else { return *span };
+        let max = usize::from(max.max(1));
+        let abs = span.abs();
+        let mut values = [0i64; 10];
+        for unit in ALL_UNITS {
+            values[unit as usize] = span_unit_value(&abs, unit);
+        }
+        let mut nonzero = (0..values.len()).rev().filter(|&i| values[i] != 0);
+        let Some(smallest) = nonzero.nth(max - 1) else { return *span };
+        if nonzero.next().is_none() {
+            return *span;
+        }
+
+        let (quantity, increment) = match ALL_UNITS[smallest] {
+            Unit::Year => (
+                i128::from(values[Unit::Year as usize]) * 12
+                    + i128::from(values[Unit::Month as usize]),
+                12,
+            ),
+            // Nothing below a month has an invariant relationship with it,
+            // so there's nothing to round.
+            Unit::Month => (i128::from(values[Unit::Month as usize]), 1),
+            unit => {
+                let quantity = ALL_UNITS[..=smallest]
+                    .iter()
+                    .map(|&u| {
+                        i128::from(values[u as usize]) * u.nanoseconds().get()
+                    })
+                    .sum::<i128>();
+                (quantity, unit.nanoseconds().get())
             }
+        };
+        let signed =
+            t::NoUnits128::new_unchecked(quantity * i128::from(span.signum()));
+        let increment = t::NoUnits128::new_unchecked(increment);
+        let rounded = self.max_units_mode.round(signed, increment).get().abs()
+            / increment.get();
+        // OK because rounding changes the value by at most one unit.
+        values[smallest] = i64::try_from(rounded).unwrap_or(i64::MAX);
+        for value in values[..smallest].iter_mut() {
+            *value = 0;
         }
-        Ok(())
+        // Carry into bigger units that are written, but only when the
+        // relationship between the units is invariant.
+        for i in smallest..values.len() - 1 {
+            let Some(factor) = invariant_factor(ALL_UNITS[i]) else { break };
+            if values[i + 1] == 0 || values[i] < factor {
+                break;
+            }
+            values[i] -= factor;
+            values[i + 1] += 1;
+        }
+
+        let mut limited = Span::new();
+        for unit in ALL_UNITS {
+            let value = t::NoUnits::new_unchecked(values[unit as usize]);
+            limited = match limited.try_units_ranged(unit, value) {
+                Ok(limited) => limited,
+                // If rounding pushed a unit out of bounds, then we just
+                // give up and return the original span unchanged.
+                Err(_) => return *span,
+            };
+        }
+        if span.is_negative() {
+            limited = limited.negate();
+        }
+        limited
+    }
+
+    /// Like `limit_span_units`, but for a `SignedDuration`. Since a duration
+    /// is always balanced up to hours, this is a straight-forward rounding
+    /// to the smallest unit kept.
+    fn limit_duration_units(&self, dur: &SignedDuration) -> SignedDuration {
+        let Some(max) = self.max_units else { return *dur };
+        let max = usize::from(max.max(1));
+        let secs = dur.as_secs().unsigned_abs();
+        let nanos = dur.subsec_nanos().unsigned_abs();
+        let values = [
+            (Unit::Hour, secs / SECS_PER_HOUR as u64),
+            (Unit::Minute, (secs / SECS_PER_MIN as u64) % 60),
+            (Unit::Second, secs % SECS_PER_MIN as u64),
+            (Unit::Millisecond, u64::from(nanos / NANOS_PER_MILLI as u32)),
+            (
+                Unit::Microsecond,
+                u64::from((nanos / NANOS_PER_MICRO as u32) % 1_000),
+            ),
+            (Unit::Nanosecond, u64::from(nanos % NANOS_PER_MICRO as u32)),
+        ];
+        let Some(&(smallest, _)) =
+            values.iter().filter(|&&(_, v)| v != 0).nth(max)
+        else {
+            return *dur;
+        };
+        // OK because there are more than `max` non-zero units and
+        // `smallest` is the first unit to be omitted.
+        let smallest = smallest.next().unwrap();
+        let options = SignedDurationRound::new()
+            .smallest(smallest)
+            .mode(self.max_units_mode);
+        // Rounding can only fail when the result overflows. In that case,
+        // truncating is always possible.
+        dur.round(options)
+            .or_else(|_| dur.round(options.mode(RoundMode::Trunc)))
+            .unwrap_or(*dur)
     }
 }
 
@@ -1379,6 +1780,52 @@ impl Default for SpanPrinter {
     }
 }
 
+/// All units, indexed by their discriminant.
+const ALL_UNITS: [Unit; 10] = [
+    Unit::Nanosecond,
+    Unit::Microsecond,
+    Unit::Millisecond,
+    Unit::Second,
+    Unit::Minute,
+    Unit::Hour,
+    Unit::Day,
+    Unit::Week,
+    Unit::Month,
+    Unit::Year,
+];
+
+/// Returns the value of the given unit in the span.
+fn span_unit_value(span: &Span, unit: Unit) -> i64 {
+    match unit {
+        Unit::Year => i64::from(span.get_years()),
+        Unit::Month => i64::from(span.get_months()),
+        Unit::Week => i64::from(span.get_weeks()),
+        Unit::Day => i64::from(span.get_days()),
+        Unit::Hour => i64::from(span.get_hours()),
+        Unit::Minute => span.get_minutes(),
+        Unit::Second => span.get_seconds(),
+        Unit::Millisecond => span.get_milliseconds(),
+        Unit::Microsecond => span.get_microseconds(),
+        Unit::Nanosecond => span.get_nanoseconds(),
+    }
+}
+
+/// Returns the number of the given unit in the next biggest unit, but only
+/// when that relationship is invariant. (For example, the number of days in
+/// a month is not invariant, and so `Unit::Week` returns `None`.)
+fn invariant_factor(unit: Unit) -> Option<i64> {
+    match unit {
+        Unit::Nanosecond | Unit::Microsecond | Unit::Millisecond => {
+            Some(1_000)
+        }
+        Unit::Second | Unit::Minute => Some(60),
+        Unit::Hour => Some(24),
+        Unit::Day => Some(7),
+        Unit::Week | Unit::Year => None,
+        Unit::Month => Some(12),
+    }
+}
+
 /// A type that represents the designator choice.
 ///
 /// Basically, whether we want verbose, short or compact designators. This in
@@ -2881,6 +3328,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn print_span_designator_max_units() {
+        let printer = |max| SpanPrinter::new().max_units(Some(max));
+        let p = |max, span| printer(max).span_to_string(&span);
+
+        insta::assert_snapshot!(p(2, 1.year().months(2).days(3)), @"1y 2mo");
+        insta::assert_snapshot!(p(1, 1.year().months(6)), @"2y");
+        insta::assert_snapshot!(p(1, 1.year().months(5)), @"1y");
+        insta::assert_snapshot!(p(1, 1.month().days(20)), @"1mo");
+        insta::assert_snapshot!(p(1, 1.week().days(4)), @"2w");
+        insta::assert_snapshot!(p(2, 1.day().hours(23).minutes(30)), @"2d");
+        insta::assert_snapshot!(p(2, 23.hours().minutes(59).seconds(30)), @"24h");
+        insta::assert_snapshot!(p(2, 1.hour().seconds(30)), @"1h 30s");
+        insta::assert_snapshot!(
+            p(2, 1.hour().seconds(30).milliseconds(500)),
+            @"1h 31s",
+        );
+        insta::assert_snapshot!(p(0, 5.minutes().seconds(29)), @"5m");
+        insta::assert_snapshot!(
+            p(2, -1.hour().minutes(2).seconds(31)),
+            @"1h 3m ago",
+        );
+        insta::assert_snapshot!(p(3, 1.hour().minutes(2)), @"1h 2m");
+        insta::assert_snapshot!(
+            p(3, 1.second().milliseconds(2).microseconds(500).nanoseconds(600)),
+            @"1s 2ms 501µs",
+        );
+
+        let p = |max, span| {
+            printer(max).max_units_mode(RoundMode::Trunc).span_to_string(&span)
+        };
+        insta::assert_snapshot!(p(1, 1.year().months(11)), @"1y");
+        insta::assert_snapshot!(
+            p(2, -1.hour().minutes(2).seconds(59)),
+            @"1h 2m ago",
+        );
+    }
+
+    #[test]
+    fn print_duration_designator_max_units() {
+        let printer = |max| SpanPrinter::new().max_units(Some(max));
+        let p = |max, secs, nanos| {
+            printer(max).duration_to_string(&SignedDuration::new(secs, nanos))
+        };
+
+        insta::assert_snapshot!(p(1, 3_599, 500_000_000), @"1h");
+        insta::assert_snapshot!(p(2, 3_661, 0), @"1h 1m");
+        insta::assert_snapshot!(p(1, 3_600, 1), @"1h");
+        insta::assert_snapshot!(p(2, -90, -500_000_000), @"1m 31s ago");
+        insta::assert_snapshot!(p(6, 3_661, 1), @"1h 1m 1s 1ns");
+        insta::assert_snapshot!(p(1, 0, 0), @"0s");
+        insta::assert_snapshot!(
+            printer(1).duration_to_string(&SignedDuration::MAX),
+            @"2562047788015215h",
+        );
+    }
+
     #[test]
     fn print_span_hms() {
         let printer = || SpanPrinter::new().hours_minutes_seconds(true);
@@ -3135,6 +3639,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn print_duration_hms_days() {
+        let printer = || {
+            SpanPrinter::new().hours_minutes_seconds(true).duration_days(true)
+        };
+        let p = |secs, nanos| {
+            printer().duration_to_string(&SignedDuration::new(secs, nanos))
+        };
+
+        insta::assert_snapshot!(p(0, 0), @"00:00:00");
+        insta::assert_snapshot!(p(23 * 60 * 60, 0), @"23:00:00");
+        insta::assert_snapshot!(p(24 * 60 * 60, 0), @"1d 00:00:00");
+        insta::assert_snapshot!(
+            p(27 * 60 * 60 + 4 * 60 + 5, 123_000_000),
+            @"1d 03:04:05.123",
+        );
+        insta::assert_snapshot!(
+            p(-(27 * 60 * 60 + 4 * 60 + 5), -123_000_000),
+            @"1d 03:04:05.123 ago",
+        );
+        insta::assert_snapshot!(
+            printer()
+                .direction(Direction::Sign)
+                .duration_to_string(&SignedDuration::from_hours(-49)),
+            @"-2d 01:00:00",
+        );
+        insta::assert_snapshot!(
+            printer()
+                .spacing(Spacing::None)
+                .duration_to_string(&SignedDuration::from_hours(49)),
+            @"2d 01:00:00",
+        );
+        insta::assert_snapshot!(
+            printer().duration_to_string(&SignedDuration::MIN),
+            @"106751991167300d 15:30:08.999999999 ago",
+        );
+        insta::assert_snapshot!(
+            printer().duration_to_string(&SignedDuration::MAX),
+            @"106751991167300d 15:30:07.999999999",
+        );
+    }
+
+    #[test]
+    fn print_duration_designator_days() {
+        let printer = || SpanPrinter::new().duration_days(true);
+        let p = |secs| {
+            printer().duration_to_string(&SignedDuration::from_secs(secs))
+        };
+
+        insta::assert_snapshot!(p(0), @"0s");
+        insta::assert_snapshot!(p(23 * 60 * 60), @"23h");
+        insta::assert_snapshot!(p(24 * 60 * 60), @"1d");
+        insta::assert_snapshot!(p(-(49 * 60 * 60 + 1)), @"2d 1h 1s ago");
+        insta::assert_snapshot!(
+            printer()
+                .fractional(Some(FractionalUnit::Hour))
+                .duration_to_string(&SignedDuration::from_mins(27 * 60 + 15)),
+            @"1d 3.25h",
+        );
+    }
+
+    #[test]
+    fn print_accumulated_hours() {
+        let printer = || SpanPrinter::new().accumulated_hours(true);
+        let p = |span| printer().span_to_string(&span);
+        let d = |secs| {
+            printer().duration_to_string(&SignedDuration::from_secs(secs))
+        };
+
+        insta::assert_snapshot!(p(0.seconds()), @"00:00:00");
+        insta::assert_snapshot!(p(52.hours().minutes(30)), @"52:30:00");
+        insta::assert_snapshot!(p(2.days().hours(4).minutes(30)), @"52:30:00");
+        insta::assert_snapshot!(p(1.week().days(1).seconds(1)), @"192:00:01");
+        insta::assert_snapshot!(p(90.minutes().milliseconds(1_500)), @"01:30:01.5");
+        insta::assert_snapshot!(p(-2.days().hours(4)), @"-52:00:00");
+        insta::assert_snapshot!(p(1.month().days(2).hours(1)), @"1mo 49:00:00");
+        insta::assert_snapshot!(
+            printer()
+                .direction(Direction::Suffix)
+                .span_to_string(&-3_000.minutes()),
+            @"50:00:00 ago",
+        );
+        insta::assert_snapshot!(
+            printer().padding(3).span_to_string(&5.hours()),
+            @"005:000:000",
+        );
+
+        insta::assert_snapshot!(d(0), @"00:00:00");
+        insta::assert_snapshot!(d(52 * 60 * 60 + 30 * 60), @"52:30:00");
+        insta::assert_snapshot!(
+            printer()
+                .duration_days(true)
+                .duration_to_string(&SignedDuration::from_hours(49)),
+            @"49:00:00",
+        );
+        insta::assert_snapshot!(
+            printer().duration_to_string(&SignedDuration::MAX),
+            @"2562047788015215:30:07.999999999",
+        );
+    }
+
     #[test]
     fn print_duration_hms_sign() {
         let printer = |direction| {
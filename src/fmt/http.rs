@@ -0,0 +1,977 @@
+/*!
+Support for printing and parsing instants using the HTTP date format.
+
+The HTTP date format is defined by [RFC 9110] (which obsoletes RFC 7231). It
+is used in HTTP headers such as `Date`, `Last-Modified`, `Expires` and
+`If-Modified-Since`. HTTP dates are always expressed in UTC, so the parser and
+printer in this module only use [`Timestamp`].
+
+RFC 9110 requires that senders use the "IMF-fixdate" format, which is what
+the printer in this module writes. However, recipients are required to
+accept two obsolete formats as well. The parser in this module supports all
+three:
+
+| Format | Example |
+| ------ | ------- |
+| IMF-fixdate | `Sun, 06 Nov 1994 08:49:37 GMT` |
+| rfc850-date | `Sunday, 06-Nov-94 08:49:37 GMT` |
+| asctime-date | `Sun Nov  6 08:49:37 1994` |
+
+The grammar for each format is case sensitive, and the parser rejects a
+weekday that is inconsistent with the date.
+
+[RFC 9110]: https://datatracker.ietf.org/doc/html/rfc9110#section-5.6.7
+
+# Two-digit years
+
+The rfc850-date format uses a two-digit year. RFC 9110 says that recipients
+must interpret a two-digit year that appears to be more than 50 years in the
+future as the most recent year in the past with the same last two digits. By
+default, "the future" is determined relative to the current time of the
+system, which requires Jiff's `std` feature. Otherwise, a reference time must
+be provided via [`DateTimeParser::relative_to`].
+
+# Retry-After
+
+The `Retry-After` header is either an HTTP date or a non-negative number of
+seconds. The [`parse_retry_after`] function handles both and returns a
+[`RetryAfter`], which can be converted to either an absolute [`Timestamp`]
+or a relative [`SignedDuration`] given the current time.
+
+# Example
+
+This shows how to parse each of the supported formats and print the result:
+
+```
+use jiff::{fmt::http, Timestamp};
+
+let expected: Timestamp = "1994-11-06T08:49:37Z".parse()?;
+for string in [
+    "Sun, 06 Nov 1994 08:49:37 GMT",
+    "Sunday, 06-Nov-94 08:49:37 GMT",
+    "Sun Nov  6 08:49:37 1994",
+] {
+    assert_eq!(http::parse(string)?, expected);
+}
+assert_eq!(http::to_string(&expected)?, "Sun, 06 Nov 1994 08:49:37 GMT");
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+use crate::{
+    civil::{Date, DateTime, Time, Weekday},
+    error::{err, ErrorContext},
+    fmt::{asctime, rfc2822, Parsed, Write},
+    tz::{Offset, TimeZone},
+    util::{escape, parse, t},
+    Error, SignedDuration, Timestamp,
+};
+
+/// The default date time parser that we use in this module.
+static DEFAULT_DATETIME_PARSER: DateTimeParser = DateTimeParser::new();
+
+/// The default date time printer that we use in this module.
+static DEFAULT_DATETIME_PRINTER: DateTimePrinter = DateTimePrinter::new();
+
+/// Convert a [`Timestamp`] to an HTTP date string in the IMF-fixdate format.
+///
+/// This is a convenience function for using [`DateTimePrinter`]. In
+/// particular, this always creates and allocates a new `String`. For writing
+/// to an existing string, you'll need to use `DateTimePrinter`.
+///
+/// Since HTTP dates do not support fractional seconds, the timestamp is
+/// printed as if truncating any fractional seconds.
+///
+/// # Errors
+///
+/// This returns an error if the year corresponding to this timestamp cannot be
+/// represented in the HTTP date format. For example, a negative year.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{fmt::http, Timestamp};
+///
+/// let ts = Timestamp::from_second(1_000_000_000)?;
+/// assert_eq!(http::to_string(&ts)?, "Sun, 09 Sep 2001 01:46:40 GMT");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_string(
+    timestamp: &Timestamp,
+) -> Result<alloc::string::String, Error> {
+    let mut buf = alloc::string::String::new();
+    DEFAULT_DATETIME_PRINTER.print_timestamp(timestamp, &mut buf)?;
+    Ok(buf)
+}
+
+/// Parse an HTTP date string into a [`Timestamp`].
+///
+/// This accepts any of the three formats permitted by RFC 9110: the
+/// IMF-fixdate, rfc850-date and asctime-date formats.
+///
+/// This is a convenience function for using [`DateTimeParser`]. In particular,
+/// this takes a `&str` while the `DateTimeParser` accepts a `&[u8]`.
+/// Moreover, a `DateTimeParser` is needed to resolve two-digit years relative
+/// to something other than the current time.
+///
+/// # Errors
+///
+/// This returns an error if the string given is not a valid HTTP date, or if
+/// the weekday is inconsistent with the date. This also returns an error
+/// when parsing an rfc850-date without Jiff's `std` feature enabled, since
+/// the current time is needed to resolve its two-digit year.
+///
+/// # Example
+///
+/// ```
+/// use jiff::fmt::http;
+///
+/// let ts = http::parse("Sun, 06 Nov 1994 08:49:37 GMT")?;
+/// assert_eq!(ts.to_string(), "1994-11-06T08:49:37Z");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[inline]
+pub fn parse(string: &str) -> Result<Timestamp, Error> {
+    DEFAULT_DATETIME_PARSER.parse_timestamp(string)
+}
+
+/// Parse the value of an HTTP `Retry-After` header.
+///
+/// The value is either an HTTP date (in any of the formats accepted by
+/// [`parse`]) or a non-negative integer number of seconds. Use
+/// [`RetryAfter::to_timestamp`] or [`RetryAfter::to_duration`] to handle both
+/// cases with a single code path.
+///
+/// This is a convenience function for using
+/// [`DateTimeParser::parse_retry_after`].
+///
+/// # Errors
+///
+/// This returns an error if the string given is neither a valid HTTP date
+/// nor a valid number of seconds.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{fmt::http, SignedDuration, Timestamp};
+///
+/// let now: Timestamp = "2024-06-15T07:00:00Z".parse()?;
+///
+/// let retry = http::parse_retry_after("120")?;
+/// assert_eq!(retry.to_timestamp(now)?.to_string(), "2024-06-15T07:02:00Z");
+/// assert_eq!(retry.to_duration(now), SignedDuration::from_secs(120));
+///
+/// let retry = http::parse_retry_after("Sat, 15 Jun 2024 07:05:00 GMT")?;
+/// assert_eq!(retry.to_timestamp(now)?.to_string(), "2024-06-15T07:05:00Z");
+/// assert_eq!(retry.to_duration(now), SignedDuration::from_mins(5));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[inline]
+pub fn parse_retry_after(string: &str) -> Result<RetryAfter, Error> {
+    DEFAULT_DATETIME_PARSER.parse_retry_after(string)
+}
+
+/// A parser for HTTP dates.
+///
+/// This parser accepts the three formats permitted by [RFC 9110]:
+/// IMF-fixdate (e.g., `Sun, 06 Nov 1994 08:49:37 GMT`), rfc850-date (e.g.,
+/// `Sunday, 06-Nov-94 08:49:37 GMT`) and asctime-date (e.g.,
+/// `Sun Nov  6 08:49:37 1994`).
+///
+/// [RFC 9110]: https://datatracker.ietf.org/doc/html/rfc9110#section-5.6.7
+///
+/// # Example
+///
+/// ```
+/// use jiff::fmt::http::DateTimeParser;
+///
+/// static PARSER: DateTimeParser = DateTimeParser::new();
+///
+/// let ts = PARSER.parse_timestamp("Sun Nov  6 08:49:37 1994")?;
+/// assert_eq!(ts.to_string(), "1994-11-06T08:49:37Z");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct DateTimeParser {
+    relative_to: Option<Timestamp>,
+}
+
+impl DateTimeParser {
+    /// Create a new HTTP date parser with the default configuration.
+    #[inline]
+    pub const fn new() -> DateTimeParser {
+        DateTimeParser { relative_to: None }
+    }
+
+    /// Set the time used to resolve the two-digit years of the rfc850-date
+    /// format.
+    ///
+    /// A two-digit year is resolved to the year with the same last two
+    /// digits that is closest to the year of the timestamp given, except
+    /// that a year more than 50 years after it is moved back by a century.
+    ///
+    /// When this isn't set, the current time of the system is used. When
+    /// Jiff's `std` feature is disabled, the current time isn't available,
+    /// and so parsing an rfc850-date without setting this returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::http::DateTimeParser, Timestamp};
+    ///
+    /// let reference: Timestamp = "2024-06-15T00:00:00Z".parse()?;
+    /// let parser = DateTimeParser::new().relative_to(reference);
+    ///
+    /// let ts = parser.parse_timestamp("Sunday, 06-Nov-94 08:49:37 GMT")?;
+    /// assert_eq!(ts.to_string(), "1994-11-06T08:49:37Z");
+    /// let ts = parser.parse_timestamp("Friday, 14-Jun-24 08:49:37 GMT")?;
+    /// assert_eq!(ts.to_string(), "2024-06-14T08:49:37Z");
+    /// let ts = parser.parse_timestamp("Sunday, 24-Jun-74 08:49:37 GMT")?;
+    /// assert_eq!(ts.to_string(), "2074-06-24T08:49:37Z");
+    /// let ts = parser.parse_timestamp("Tuesday, 24-Jun-75 08:49:37 GMT")?;
+    /// assert_eq!(ts.to_string(), "1975-06-24T08:49:37Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn relative_to(self, timestamp: Timestamp) -> DateTimeParser {
+        DateTimeParser { relative_to: Some(timestamp) }
+    }
+
+    /// Parse an HTTP date string into a [`Timestamp`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the string given is not a valid HTTP date,
+    /// if the weekday is inconsistent with the date, or if the date is
+    /// outside of Jiff's supported range. This also returns an error when
+    /// parsing an rfc850-date without a reference time, as described by
+    /// [`DateTimeParser::relative_to`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::http::DateTimeParser;
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let ts = PARSER.parse_timestamp("Thu, 29 Feb 2024 05:34:00 GMT")?;
+    /// assert_eq!(ts.to_string(), "2024-02-29T05:34:00Z");
+    ///
+    /// // Only UTC is supported.
+    /// assert!(PARSER.parse_timestamp("Thu, 29 Feb 2024 05:34:00 EST").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_timestamp<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<Timestamp, Error> {
+        let input = input.as_ref();
+        let ts = self
+            .parse_timestamp_internal(input)
+            .context("failed to parse HTTP date into Jiff timestamp")?
+            .into_full()?;
+        Ok(ts)
+    }
+
+    /// Parse the value of an HTTP `Retry-After` header.
+    ///
+    /// The value is either an HTTP date or a non-negative integer number of
+    /// seconds (called "delay-seconds" in RFC 9110). Input starting with a
+    /// letter is parsed as an HTTP date, and input consisting entirely of
+    /// ASCII digits is parsed as a number of seconds.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the input is neither a valid HTTP date nor a
+    /// valid number of seconds. A number of seconds that can't be
+    /// represented by a [`SignedDuration`] also returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     fmt::http::{DateTimeParser, RetryAfter},
+    ///     SignedDuration,
+    /// };
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// assert_eq!(
+    ///     PARSER.parse_retry_after("3600")?,
+    ///     RetryAfter::Delay(SignedDuration::from_hours(1)),
+    /// );
+    /// assert_eq!(
+    ///     PARSER.parse_retry_after("Thu, 01 Jan 1970 00:00:01 GMT")?,
+    ///     RetryAfter::Date(jiff::Timestamp::from_second(1)?),
+    /// );
+    /// // Negative delays aren't allowed.
+    /// assert!(PARSER.parse_retry_after("-5").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_retry_after<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<RetryAfter, Error> {
+        let input = input.as_ref();
+        if input.first().is_some_and(|b| b.is_ascii_alphabetic()) {
+            let ts = self
+                .parse_timestamp(input)
+                .context("failed to parse Retry-After value")?;
+            return Ok(RetryAfter::Date(ts));
+        }
+        if input.is_empty() || !input.iter().all(u8::is_ascii_digit) {
+            return Err(err!(
+                "expected Retry-After value to be an HTTP date or a \
+                 non-negative number of seconds, but found {input:?}",
+                input = escape::Bytes(input),
+            ));
+        }
+        let secs = parse::i64(input).with_context(|| {
+            err!(
+                "failed to parse Retry-After value {input:?} \
+                 as a number of seconds",
+                input = escape::Bytes(input),
+            )
+        })?;
+        Ok(RetryAfter::Delay(SignedDuration::from_secs(secs)))
+    }
+
+    /// Parses an HTTP date as a timestamp.
+    ///
+    /// Note that this doesn't check that the input has been completely
+    /// consumed.
+    fn parse_timestamp_internal<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, Timestamp>, Error> {
+        let Parsed { value: dt, input } = self.parse_datetime(input)?;
+        let ts = Offset::UTC
+            .to_timestamp(dt)
+            .context("HTTP date out of Jiff's range")?;
+        Ok(Parsed { value: ts, input })
+    }
+
+    /// Parses any of the three HTTP date formats into a civil datetime in
+    /// UTC.
+    ///
+    /// The format is determined by what follows the weekday: a comma after
+    /// an abbreviated weekday is an IMF-fixdate, a comma after a full
+    /// weekday name is an rfc850-date and a space after an abbreviated
+    /// weekday is an asctime-date.
+    fn parse_datetime<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, DateTime>, Error> {
+        if input.is_empty() {
+            return Err(err!("expected HTTP date, but got empty string"));
+        }
+        let name_len =
+            input.iter().take_while(|b| b.is_ascii_alphabetic()).count();
+        let (name, input) = input.split_at(name_len);
+        let (wd, dt, input) = if name.len() == 3 {
+            let wd = parse_weekday_abbrev(name)?;
+            if let Some(input) = input.strip_prefix(b", ") {
+                let Parsed { value: dt, input } = self.parse_imf(input)?;
+                (wd, dt, input)
+            } else if let Some(input) = input.strip_prefix(b" ") {
+                let Parsed { value: dt, input } =
+                    asctime::parse_after_weekday(input)?;
+                (wd, dt, input)
+            } else {
+                return Err(err!(
+                    "expected comma or space after weekday {name:?}",
+                    name = escape::Bytes(name),
+                ));
+            }
+        } else {
+            let wd = parse_weekday_full(name)?;
+            let input = input.strip_prefix(b", ").ok_or_else(|| {
+                err!(
+                    "expected comma and space after weekday {name:?}",
+                    name = escape::Bytes(name),
+                )
+            })?;
+            let Parsed { value: dt, input } = self.parse_rfc850(input)?;
+            (wd, dt, input)
+        };
+        if wd != dt.weekday() {
+            return Err(err!(
+                "found parsed weekday of {parsed}, \
+                 but parsed datetime of {dt} has weekday \
+                 {has}",
+                parsed = weekday_abbrev(wd),
+                has = weekday_abbrev(dt.weekday()),
+            ));
+        }
+        Ok(Parsed { value: dt, input })
+    }
+
+    /// Parses the remainder of an IMF-fixdate after the weekday, e.g.,
+    /// `06 Nov 1994 08:49:37 GMT`.
+    fn parse_imf<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, DateTime>, Error> {
+        let Parsed { value: day, input } = parse_digits(input, 2, "day")?;
+        let input = expect(input, b" ", "after day")?;
+        let Parsed { value: month, input } = parse_month(input)?;
+        let input = expect(input, b" ", "after month")?;
+        let Parsed { value: year, input } = parse_digits(input, 4, "year")?;
+        let input = expect(input, b" ", "after year")?;
+        let Parsed { value: time, input } = parse_time(input)?;
+        let input = expect(input, b" GMT", "after time")?;
+        let date = new_date(year, month, day)?;
+        Ok(Parsed { value: DateTime::from_parts(date, time), input })
+    }
+
+    /// Parses the remainder of an rfc850-date after the weekday, e.g.,
+    /// `06-Nov-94 08:49:37 GMT`.
+    fn parse_rfc850<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, DateTime>, Error> {
+        let Parsed { value: day, input } = parse_digits(input, 2, "day")?;
+        let input = expect(input, b"-", "after day")?;
+        let Parsed { value: month, input } = parse_month(input)?;
+        let input = expect(input, b"-", "after month")?;
+        let Parsed { value: year, input } = parse_digits(input, 2, "year")?;
+        let input = expect(input, b" ", "after year")?;
+        let Parsed { value: time, input } = parse_time(input)?;
+        let input = expect(input, b" GMT", "after time")?;
+        let year = self.resolve_two_digit_year(year)?;
+        let date = new_date(year, month, day)?;
+        Ok(Parsed { value: DateTime::from_parts(date, time), input })
+    }
+
+    /// Resolves a two-digit year according to RFC 9110, which says that a
+    /// year that appears to be more than 50 years in the future is
+    /// interpreted as the most recent year in the past with the same last
+    /// two digits.
+    fn resolve_two_digit_year(&self, year: i64) -> Result<i64, Error> {
+        let reference = match self.relative_to {
+            Some(ts) => ts,
+            None => now().ok_or_else(|| {
+                err!(
+                    "cannot resolve two-digit year {year:02} without \
+                     a reference time (the current time is unavailable \
+                     because Jiff's `std` feature is disabled)",
+                )
+            })?,
+        };
+        let current = i64::from(TimeZone::UTC.to_datetime(reference).year());
+        let mut resolved = current - current.rem_euclid(100) + year;
+        if resolved > current + 50 {
+            resolved -= 100;
+        }
+        Ok(resolved)
+    }
+}
+
+impl Default for DateTimeParser {
+    #[inline]
+    fn default() -> DateTimeParser {
+        DateTimeParser::new()
+    }
+}
+
+/// The value of an HTTP `Retry-After` header.
+///
+/// This is returned by [`parse_retry_after`] and
+/// [`DateTimeParser::parse_retry_after`]. Since most HTTP clients only care
+/// about when to retry (or how long to wait), the [`RetryAfter::to_timestamp`]
+/// and [`RetryAfter::to_duration`] methods convert either variant given the
+/// current time.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{fmt::http, SignedDuration, Timestamp};
+///
+/// let now: Timestamp = "2024-06-15T07:00:00Z".parse()?;
+/// for value in ["30", "Sat, 15 Jun 2024 07:00:30 GMT"] {
+///     let wait = http::parse_retry_after(value)?.to_duration(now);
+///     assert_eq!(wait, SignedDuration::from_secs(30));
+/// }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RetryAfter {
+    /// An HTTP date at which to retry.
+    Date(Timestamp),
+    /// A delay after which to retry.
+    ///
+    /// When parsed, this is never negative.
+    Delay(SignedDuration),
+}
+
+impl RetryAfter {
+    /// Returns the time at which to retry, where delays are relative to the
+    /// given time.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if adding a delay to the given time overflows
+    /// Jiff's supported range of timestamps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::http::RetryAfter, SignedDuration, Timestamp};
+    ///
+    /// let now: Timestamp = "2024-06-15T07:00:00Z".parse()?;
+    /// let retry = RetryAfter::Delay(SignedDuration::from_secs(90));
+    /// let ts = retry.to_timestamp(now)?;
+    /// assert_eq!(ts.to_string(), "2024-06-15T07:01:30Z");
+    ///
+    /// let retry = RetryAfter::Delay(SignedDuration::from_secs(90));
+    /// assert!(retry.to_timestamp(Timestamp::MAX).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_timestamp(self, now: Timestamp) -> Result<Timestamp, Error> {
+        match self {
+            RetryAfter::Date(ts) => Ok(ts),
+            RetryAfter::Delay(dur) => now.checked_add(dur),
+        }
+    }
+
+    /// Returns how long to wait before retrying, where dates are relative to
+    /// the given time.
+    ///
+    /// When the date is before the given time, the duration returned is
+    /// negative. Callers typically treat that the same as zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::http::RetryAfter, SignedDuration, Timestamp};
+    ///
+    /// let now: Timestamp = "2024-06-15T07:00:00Z".parse()?;
+    /// let retry = RetryAfter::Date("2024-06-15T06:59:00Z".parse()?);
+    /// assert_eq!(retry.to_duration(now), SignedDuration::from_secs(-60));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_duration(self, now: Timestamp) -> SignedDuration {
+        match self {
+            RetryAfter::Date(ts) => ts.duration_since(now),
+            RetryAfter::Delay(dur) => dur,
+        }
+    }
+}
+
+/// A printer for HTTP dates.
+///
+/// This printer always writes the IMF-fixdate format required by [RFC 9110].
+/// It is equivalent to
+/// [`rfc2822::DateTimePrinter::print_timestamp_rfc9110`].
+///
+/// [RFC 9110]: https://datatracker.ietf.org/doc/html/rfc9110#section-5.6.7
+///
+/// # Example
+///
+/// ```
+/// use jiff::{fmt::http::DateTimePrinter, Timestamp};
+///
+/// static PRINTER: DateTimePrinter = DateTimePrinter::new();
+///
+/// let ts: Timestamp = "2024-06-15T07:00:00.123Z".parse()?;
+/// let mut buf = String::new();
+/// PRINTER.print_timestamp(&ts, &mut buf)?;
+/// assert_eq!(buf, "Sat, 15 Jun 2024 07:00:00 GMT");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct DateTimePrinter {
+    // The HTTP date printer has no configuration at present.
+    _private: (),
+}
+
+impl DateTimePrinter {
+    /// Create a new HTTP date printer with the default configuration.
+    #[inline]
+    pub const fn new() -> DateTimePrinter {
+        DateTimePrinter { _private: () }
+    }
+
+    /// Format a `Timestamp` into an HTTP date string.
+    ///
+    /// This is a convenience routine for [`DateTimePrinter::print_timestamp`]
+    /// with a `String`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the year corresponding to this timestamp
+    /// cannot be represented in the HTTP date format. For example, a negative
+    /// year.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::http::DateTimePrinter, Timestamp};
+    ///
+    /// static PRINTER: DateTimePrinter = DateTimePrinter::new();
+    ///
+    /// let ts = Timestamp::from_second(1)?;
+    /// assert_eq!(
+    ///     PRINTER.timestamp_to_string(&ts)?,
+    ///     "Thu, 01 Jan 1970 00:00:01 GMT",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn timestamp_to_string(
+        &self,
+        timestamp: &Timestamp,
+    ) -> Result<alloc::string::String, Error> {
+        let mut buf = alloc::string::String::with_capacity(4);
+        self.print_timestamp(timestamp, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Print a `Timestamp` in the HTTP date format to the given writer.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when writing to the given [`Write`]
+    /// implementation would fail. This also returns an error if the year
+    /// corresponding to this timestamp cannot be represented in the HTTP date
+    /// format. For example, a negative year.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::http::DateTimePrinter, Timestamp};
+    ///
+    /// static PRINTER: DateTimePrinter = DateTimePrinter::new();
+    ///
+    /// let ts = Timestamp::from_second(1)?;
+    /// let mut buf = String::new();
+    /// PRINTER.print_timestamp(&ts, &mut buf)?;
+    /// assert_eq!(buf, "Thu, 01 Jan 1970 00:00:01 GMT");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn print_timestamp<W: Write>(
+        &self,
+        timestamp: &Timestamp,
+        wtr: W,
+    ) -> Result<(), Error> {
+        rfc2822::DateTimePrinter::new().print_timestamp_rfc9110(timestamp, wtr)
+    }
+}
+
+impl Default for DateTimePrinter {
+    #[inline]
+    fn default() -> DateTimePrinter {
+        DateTimePrinter::new()
+    }
+}
+
+/// Returns the current time, if it's available.
+#[cfg(feature = "std")]
+fn now() -> Option<Timestamp> {
+    Some(Timestamp::now())
+}
+
+/// Returns the current time, if it's available.
+#[cfg(not(feature = "std"))]
+fn now() -> Option<Timestamp> {
+    None
+}
+
+/// Parses exactly `len` ASCII digits as an integer.
+pub(super) fn parse_digits<'i>(
+    input: &'i [u8],
+    len: usize,
+    what: &'static str,
+) -> Result<Parsed<'i, i64>, Error> {
+    let (digits, input) = parse::split(input, len).ok_or_else(|| {
+        err!("expected {len} digit {what}, but found end of input")
+    })?;
+    let value = parse::i64(digits).with_context(|| {
+        err!(
+            "failed to parse {digits:?} as {what} (a {len} digit integer)",
+            digits = escape::Bytes(digits),
+        )
+    })?;
+    Ok(Parsed { value, input })
+}
+
+/// Parses a `HH:MM:SS` time of day.
+///
+/// A leap second (`60`) is accepted and treated as `59`.
+pub(super) fn parse_time<'i>(
+    input: &'i [u8],
+) -> Result<Parsed<'i, Time>, Error> {
+    let Parsed { value: hour, input } = parse_digits(input, 2, "hour")?;
+    let input = expect(input, b":", "after hour")?;
+    let Parsed { value: minute, input } = parse_digits(input, 2, "minute")?;
+    let input = expect(input, b":", "after minute")?;
+    let Parsed { value: mut second, input } =
+        parse_digits(input, 2, "second")?;
+    if second == 60 {
+        second = 59;
+    }
+    let hour = t::Hour::try_new("hour", hour).context("hour is not valid")?;
+    let minute =
+        t::Minute::try_new("minute", minute).context("minute is not valid")?;
+    let second =
+        t::Second::try_new("second", second).context("second is not valid")?;
+    let time =
+        Time::new_ranged(hour, minute, second, t::SubsecNanosecond::N::<0>());
+    Ok(Parsed { value: time, input })
+}
+
+/// Parses a case sensitive abbreviated month name, e.g., `Nov`.
+pub(super) fn parse_month<'i>(
+    input: &'i [u8],
+) -> Result<Parsed<'i, i64>, Error> {
+    let (name, input) = parse::split(input, 3).ok_or_else(|| {
+        err!("expected abbreviated month name, but found end of input")
+    })?;
+    let month = match name {
+        b"Jan" => 1,
+        b"Feb" => 2,
+        b"Mar" => 3,
+        b"Apr" => 4,
+        b"May" => 5,
+        b"Jun" => 6,
+        b"Jul" => 7,
+        b"Aug" => 8,
+        b"Sep" => 9,
+        b"Oct" => 10,
+        b"Nov" => 11,
+        b"Dec" => 12,
+        _ => {
+            return Err(err!(
+                "expected abbreviated month name, \
+                 but did not recognize {name:?} as a valid month",
+                name = escape::Bytes(name),
+            ));
+        }
+    };
+    Ok(Parsed { value: month, input })
+}
+
+/// Parses a case sensitive abbreviated weekday name, e.g., `Sun`.
+pub(super) fn parse_weekday_abbrev(name: &[u8]) -> Result<Weekday, Error> {
+    let wd = match name {
+        b"Sun" => Weekday::Sunday,
+        b"Mon" => Weekday::Monday,
+        b"Tue" => Weekday::Tuesday,
+        b"Wed" => Weekday::Wednesday,
+        b"Thu" => Weekday::Thursday,
+        b"Fri" => Weekday::Friday,
+        b"Sat" => Weekday::Saturday,
+        _ => {
+            return Err(err!(
+                "did not recognize {name:?} as a valid weekday abbreviation",
+                name = escape::Bytes(name),
+            ));
+        }
+    };
+    Ok(wd)
+}
+
+/// Parses a case sensitive full weekday name, e.g., `Sunday`.
+fn parse_weekday_full(name: &[u8]) -> Result<Weekday, Error> {
+    let wd = match name {
+        b"Sunday" => Weekday::Sunday,
+        b"Monday" => Weekday::Monday,
+        b"Tuesday" => Weekday::Tuesday,
+        b"Wednesday" => Weekday::Wednesday,
+        b"Thursday" => Weekday::Thursday,
+        b"Friday" => Weekday::Friday,
+        b"Saturday" => Weekday::Saturday,
+        _ => {
+            return Err(err!(
+                "did not recognize {name:?} as a valid weekday name",
+                name = escape::Bytes(name),
+            ));
+        }
+    };
+    Ok(wd)
+}
+
+/// Strips the given literal from the beginning of the input, or returns an
+/// error mentioning where it was expected.
+pub(super) fn expect<'i>(
+    input: &'i [u8],
+    literal: &'static [u8],
+    context: &'static str,
+) -> Result<&'i [u8], Error> {
+    input.strip_prefix(literal).ok_or_else(|| {
+        err!(
+            "expected {literal:?} {context}, but found {found:?}",
+            literal = escape::Bytes(literal),
+            found = escape::Bytes(&input[..input.len().min(literal.len())]),
+        )
+    })
+}
+
+pub(super) fn new_date(
+    year: i64,
+    month: i64,
+    day: i64,
+) -> Result<Date, Error> {
+    let year = t::Year::try_new("year", year).context("year is not valid")?;
+    let month =
+        t::Month::try_new("month", month).context("month is not valid")?;
+    let day = t::Day::try_new("day", day).context("day is not valid")?;
+    Date::new_ranged(year, month, day).context("invalid date")
+}
+
+pub(super) fn weekday_abbrev(wd: Weekday) -> &'static str {
+    match wd {
+        Weekday::Sunday => "Sun",
+        Weekday::Monday => "Mon",
+        Weekday::Tuesday => "Tue",
+        Weekday::Wednesday => "Wed",
+        Weekday::Thursday => "Thu",
+        Weekday::Friday => "Fri",
+        Weekday::Saturday => "Sat",
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn reference() -> Timestamp {
+        "2024-06-15T00:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn ok_parse() {
+        let p = |input: &str| {
+            DateTimeParser::new()
+                .relative_to(reference())
+                .parse_timestamp(input)
+                .unwrap()
+                .to_string()
+        };
+
+        insta::assert_snapshot!(p("Sun, 06 Nov 1994 08:49:37 GMT"), @"1994-11-06T08:49:37Z");
+        insta::assert_snapshot!(p("Sunday, 06-Nov-94 08:49:37 GMT"), @"1994-11-06T08:49:37Z");
+        insta::assert_snapshot!(p("Sun Nov  6 08:49:37 1994"), @"1994-11-06T08:49:37Z");
+        insta::assert_snapshot!(p("Thu Feb 29 23:59:60 2024"), @"2024-02-29T23:59:59Z");
+        insta::assert_snapshot!(p("Thu, 01 Jan 1970 00:00:00 GMT"), @"1970-01-01T00:00:00Z");
+        insta::assert_snapshot!(p("Wednesday, 31-Dec-25 00:00:00 GMT"), @"2025-12-31T00:00:00Z");
+        insta::assert_snapshot!(p("Monday, 31-Dec-74 00:00:00 GMT"), @"2074-12-31T00:00:00Z");
+        insta::assert_snapshot!(p("Wednesday, 31-Dec-75 00:00:00 GMT"), @"1975-12-31T00:00:00Z");
+    }
+
+    #[test]
+    fn err_parse() {
+        let p = |input: &str| {
+            DateTimeParser::new()
+                .relative_to(reference())
+                .parse_timestamp(input)
+                .unwrap_err()
+        };
+
+        insta::assert_snapshot!(p(""), @"failed to parse HTTP date into Jiff timestamp: expected HTTP date, but got empty string");
+        insta::assert_snapshot!(p("Sun, 06 Nov 1994 08:49:37 UTC"), @r###"failed to parse HTTP date into Jiff timestamp: expected " GMT" after time, but found " UTC""###);
+        insta::assert_snapshot!(p("Sun, 6 Nov 1994 08:49:37 GMT"), @r###"failed to parse HTTP date into Jiff timestamp: failed to parse "6 " as day (a 2 digit integer): invalid digit, expected 0-9 but got"###);
+        insta::assert_snapshot!(p("sun, 06 Nov 1994 08:49:37 GMT"), @r###"failed to parse HTTP date into Jiff timestamp: did not recognize "sun" as a valid weekday abbreviation"###);
+        insta::assert_snapshot!(p("Sun, 06 nov 1994 08:49:37 GMT"), @r###"failed to parse HTTP date into Jiff timestamp: expected abbreviated month name, but did not recognize "nov" as a valid month"###);
+        insta::assert_snapshot!(p("Mon, 06 Nov 1994 08:49:37 GMT"), @"failed to parse HTTP date into Jiff timestamp: found parsed weekday of Mon, but parsed datetime of 1994-11-06T08:49:37 has weekday Sun");
+        insta::assert_snapshot!(p("Sun, 06 Nov 1994 08:49:37 GMT "), @r###"parsed value '1994-11-06T08:49:37Z', but unparsed input " " remains (expected no unparsed input)"###);
+        insta::assert_snapshot!(p("Sun, 31 Nov 1994 08:49:37 GMT"), @"failed to parse HTTP date into Jiff timestamp: invalid date: parameter 'day' with value 31 is not in the required range of 1..=30");
+        insta::assert_snapshot!(p("Sun, 06 Nov 1994 24:00:00 GMT"), @"failed to parse HTTP date into Jiff timestamp: hour is not valid: parameter 'hour' with value 24 is not in the required range of 0..=23");
+        insta::assert_snapshot!(p("Sun,06 Nov 1994 08:49:37 GMT"), @r###"failed to parse HTTP date into Jiff timestamp: expected comma or space after weekday "Sun""###);
+        insta::assert_snapshot!(p("Sun, 06-Nov-94 08:49:37 GMT"), @r###"failed to parse HTTP date into Jiff timestamp: expected " " after day, but found "-""###);
+        insta::assert_snapshot!(p("Sunday, 06 Nov 1994 08:49:37 GMT"), @r###"failed to parse HTTP date into Jiff timestamp: expected "-" after day, but found " ""###);
+        insta::assert_snapshot!(p("Sun Nov 6 08:49:37 1994"), @r###"failed to parse HTTP date into Jiff timestamp: failed to parse "6 " as day (a 2 digit integer): invalid digit, expected 0-9 but got"###);
+        insta::assert_snapshot!(p("Sun Nov  6 08:49:37 94"), @"failed to parse HTTP date into Jiff timestamp: expected 4 digit year, but found end of input");
+    }
+
+    #[test]
+    fn retry_after() {
+        let p = |input: &str| {
+            DateTimeParser::new()
+                .relative_to(reference())
+                .parse_retry_after(input)
+        };
+
+        insta::assert_debug_snapshot!(p("0").unwrap(), @"Delay(
+    0s,
+)");
+        insta::assert_debug_snapshot!(p("007").unwrap(), @"Delay(
+    7s,
+)");
+        insta::assert_debug_snapshot!(p("9223372036").unwrap(), @"Delay(
+    9223372036s,
+)");
+        insta::assert_debug_snapshot!(
+            p("Sun, 06 Nov 1994 08:49:37 GMT").unwrap(),
+            @"Date(
+    1994-11-06T08:49:37Z,
+)",
+        );
+        insta::assert_debug_snapshot!(
+            p("Sunday, 06-Nov-94 08:49:37 GMT").unwrap(),
+            @"Date(
+    1994-11-06T08:49:37Z,
+)",
+        );
+
+        insta::assert_snapshot!(p("").unwrap_err(), @r###"expected Retry-After value to be an HTTP date or a non-negative number of seconds, but found """###);
+        insta::assert_snapshot!(p("-1").unwrap_err(), @r###"expected Retry-After value to be an HTTP date or a non-negative number of seconds, but found "-1""###);
+        insta::assert_snapshot!(p(" 120").unwrap_err(), @r###"expected Retry-After value to be an HTTP date or a non-negative number of seconds, but found " 120""###);
+        insta::assert_snapshot!(p("1.5").unwrap_err(), @r###"expected Retry-After value to be an HTTP date or a non-negative number of seconds, but found "1.5""###);
+        insta::assert_snapshot!(p("99999999999999999999").unwrap_err(), @r###"failed to parse Retry-After value "99999999999999999999" as a number of seconds: number '99999999999999999999' too big to parse into 64-bit integer"###);
+    }
+
+    #[test]
+    fn ok_print() {
+        let p = |ts: &str| {
+            DateTimePrinter::new()
+                .timestamp_to_string(&ts.parse().unwrap())
+                .unwrap()
+        };
+
+        insta::assert_snapshot!(p("1994-11-06T08:49:37Z"), @"Sun, 06 Nov 1994 08:49:37 GMT");
+        insta::assert_snapshot!(p("0001-01-01T00:00:00.999Z"), @"Mon, 01 Jan 0001 00:00:00 GMT");
+        insta::assert_snapshot!(p("9999-12-30T22:00:00Z"), @"Thu, 30 Dec 9999 22:00:00 GMT");
+        insta::assert_snapshot!(
+            DateTimePrinter::new()
+                .timestamp_to_string(&Timestamp::MIN)
+                .unwrap_err(),
+            @"datetime -009999-01-02T01:59:59 has negative year, which cannot be formatted with RFC 2822",
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for s in [
+            "1970-01-01T00:00:00Z",
+            "1994-11-06T08:49:37Z",
+            "2024-02-29T23:59:59Z",
+            "9999-12-30T22:00:00Z",
+        ] {
+            let ts: Timestamp = s.parse().unwrap();
+            let got = parse(&to_string(&ts).unwrap()).unwrap();
+            assert_eq!(got, ts);
+        }
+    }
+}
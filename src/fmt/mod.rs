@@ -114,6 +114,36 @@ and features.)
 | `Thu, 29 Feb 2024 05:34 -0500` | [`rfc2822::parse`] and [`rfc2822::to_string`] |
 | `Thu, 01 Jan 1970 00:00:01 GMT` | [`DateTimePrinter::timestamp_to_rfc9110_string`] |
 
+## HTTP date parsing and printing
+
+[RFC-9110] HTTP date support is provided by the [`http`] sub-module.
+
+| Example | Links |
+| ------- | ----- |
+| `Sun, 06 Nov 1994 08:49:37 GMT` | [`http::parse`] and [`http::to_string`] |
+| `Sunday, 06-Nov-94 08:49:37 GMT` | [`http::parse`] |
+| `Sun Nov  6 08:49:37 1994` | [`http::parse`] |
+| `120` (as a `Retry-After` value) | [`http::parse_retry_after`] |
+
+## asctime parsing and printing
+
+Support for the classic Unix `asctime` and `ctime` format is provided by the
+[`asctime`] sub-module.
+
+| Example | Links |
+| ------- | ----- |
+| `Wed Jun 30 21:49:08 2021` | [`asctime::parse`] and [`asctime::to_string`] |
+
+## Lenient clock time parsing
+
+Parsing of loosely written times of day, like the ones a human might type
+into a form, is provided by the [`clock`] sub-module.
+
+| Example | Links |
+| ------- | ----- |
+| `9`, `9:30`, `0930`, `21:30:15` | [`clock::parse`] |
+| `9pm`, `9:30 a.m.`, `noon` | [`clock::parse`] |
+
 [Temporal]: https://tc39.es/proposal-temporal/#sec-temporal-iso8601grammar
 [ISO-8601]: https://www.iso.org/iso-8601-date-and-time-format.html
 [RFC-3339]: https://www.rfc-editor.org/rfc/rfc3339
@@ -126,6 +156,9 @@ and features.)
 [`friendly`]: crate::fmt::friendly
 [`temporal`]: crate::fmt::temporal
 [`rfc2822`]: crate::fmt::rfc2822
+[`http`]: crate::fmt::http
+[`asctime`]: crate::fmt::asctime
+[`clock`]: crate::fmt::clock
 [`strtime`]: crate::fmt::strtime
 [`civil::DateTime`]: crate::civil::DateTime
 [`civil::Date`]: crate::civil::Date
@@ -161,6 +194,12 @@ and features.)
 [`strtime::format`]: crate::fmt::strtime::format
 
 [`rfc2822::parse`]: crate::fmt::rfc2822::parse
+[`http::parse`]: crate::fmt::http::parse
+[`http::to_string`]: crate::fmt::http::to_string
+[`http::parse_retry_after`]: crate::fmt::http::parse_retry_after
+[`asctime::parse`]: crate::fmt::asctime::parse
+[`asctime::to_string`]: crate::fmt::asctime::to_string
+[`clock::parse`]: crate::fmt::clock::parse
 [`rfc2822::to_string`]: crate::fmt::rfc2822::to_string
 [`DateTimePrinter::timestamp_to_rfc9110_string`]: crate::fmt::rfc2822::DateTimePrinter::timestamp_to_rfc9110_string
 */
@@ -172,7 +211,12 @@ use crate::{
 
 use self::util::{Decimal, DecimalFormatter, Fractional, FractionalFormatter};
 
+pub mod adaptive;
+pub mod asctime;
+pub mod clock;
+pub mod detect;
 pub mod friendly;
+pub mod http;
 mod offset;
 pub mod rfc2822;
 mod rfc9557;
@@ -181,6 +225,67 @@ pub mod serde;
 pub mod strtime;
 pub mod temporal;
 mod util;
+pub mod validate;
+
+/// How parsers handle a leap second, i.e., a seconds value of `60`.
+///
+/// Jiff's datetime types cannot represent a leap second, but datetime strings
+/// with a seconds value of `60` do occur in the wild. For example, logs from
+/// NTP-disciplined systems might contain `2016-12-31T23:59:60Z`. This
+/// configuration controls what happens when such a string is parsed. It can
+/// be set via [`temporal::DateTimeParser::leap_second`] and
+/// [`strtime::Config::leap_second`].
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::time, fmt::{temporal::DateTimeParser, LeapSecond}};
+///
+/// let input = "23:59:60.5";
+///
+/// let t = DateTimeParser::new().parse_time(input)?;
+/// assert_eq!(t, time(23, 59, 59, 500_000_000));
+///
+/// let p = DateTimeParser::new().leap_second(LeapSecond::Clamp);
+/// assert_eq!(p.parse_time(input)?, time(23, 59, 59, 999_999_999));
+///
+/// let p = DateTimeParser::new().leap_second(LeapSecond::Reject);
+/// assert!(p.parse_time(input).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum LeapSecond {
+    /// Constrain a leap second to the 59th second, keeping any fractional
+    /// seconds. For example, `23:59:60.5` is parsed as `23:59:59.5`.
+    ///
+    /// This is the default, and matches the behavior of Temporal.
+    #[default]
+    Constrain,
+    /// Clamp a leap second to the last nanosecond of the 59th second,
+    /// dropping any fractional seconds. For example, `23:59:60.5` is parsed
+    /// as `23:59:59.999999999`.
+    ///
+    /// Unlike `Constrain`, this ensures that a sequence of increasing
+    /// datetimes that includes a leap second remains in order (although not
+    /// strictly) after parsing.
+    Clamp,
+    /// Return an error when a leap second is found.
+    Reject,
+    /// Preserve a leap second when parsing into a value that can represent
+    /// it, and return an error otherwise.
+    ///
+    /// A leap second can be represented by a [`tai::Timestamp`], which
+    /// can be parsed via [`temporal::DateTimeParser::parse_tai_timestamp`]
+    /// or [`strtime::BrokenDownTime::to_tai_timestamp`]. In both cases, a
+    /// leap second table is used to check that the leap second actually
+    /// occurred. A [`strtime::BrokenDownTime`] also reports a preserved leap
+    /// second as a seconds value of `60`.
+    ///
+    /// [`tai::Timestamp`]: crate::tai::Timestamp
+    Preserve,
+}
 
 /// The result of parsing a value out of a slice of bytes.
 ///
@@ -293,12 +293,18 @@ pub(crate) struct Parser {
     zulu: bool,
     subminute: bool,
     subsecond: bool,
+    single_digit_hours: bool,
 }
 
 impl Parser {
     /// Create a new UTC offset parser with the default configuration.
     pub(crate) const fn new() -> Parser {
-        Parser { zulu: true, subminute: true, subsecond: true }
+        Parser {
+            zulu: true,
+            subminute: true,
+            subsecond: true,
+            single_digit_hours: false,
+        }
     }
 
     /// When enabled, the `z` and `Z` designators are recognized as a "zulu"
@@ -336,6 +342,14 @@ impl Parser {
         Parser { subsecond: yes, ..self }
     }
 
+    /// When enabled, the hours component may be a single digit when it isn't
+    /// followed by another digit, e.g., `+5:30` or `-4`.
+    ///
+    /// This is disabled by default.
+    pub(crate) const fn single_digit_hours(self, yes: bool) -> Parser {
+        Parser { single_digit_hours: yes, ..self }
+    }
+
     /// Parse an offset from the beginning of `input`.
     ///
     /// If no offset could be found or it was otherwise invalid, then an error
@@ -558,7 +572,11 @@ impl Parser {
         &self,
         input: &'i [u8],
     ) -> Result<Parsed<'i, ParsedOffsetHours>, Error> {
-        let (hours, input) = parse::split(input, 2).ok_or_else(|| {
+        let single = self.single_digit_hours
+            && input.first().is_some_and(u8::is_ascii_digit)
+            && !input.get(1).is_some_and(u8::is_ascii_digit);
+        let len = if single { 1 } else { 2 };
+        let (hours, input) = parse::split(input, len).ok_or_else(|| {
             err!("expected two digit hour after sign, but found end of input",)
         })?;
         let hours = parse::i64(hours).with_context(|| {
@@ -11,15 +11,14 @@ inexact time, you can get it from [`Zoned`] via [`Zoned::datetime`].
 
 # Incomplete support
 
-The RFC 2822 support in this crate is technically incomplete. Specifically,
-it does not support parsing comments within folding whitespace. It will parse
-comments after the datetime itself (including nested comments). See [Issue
-#39][issue39] for an example. If you find a real world use case for parsing
-comments within whitespace at any point in the datetime string, please file
-an issue. That is, the main reason it isn't currently supported is because
-it didn't seem worth the implementation complexity to account for it. But if
-there are real world use cases that need it, then that would be sufficient
-justification for adding it.
+The RFC 2822 support in this crate is technically incomplete by default.
+Specifically, it does not support parsing comments within folding whitespace.
+It will parse comments after the datetime itself (including nested comments).
+See [Issue #39][issue39] for an example. Comments anywhere else in the
+datetime string, along with the other obsolete syntax that permits comments
+and whitespace around the `,` following the weekday and the `:` separating
+time components, are only supported when
+[`DateTimeParser::lenient`] is enabled.
 
 RFC 2822 support should otherwise be complete, including support for parsing
 obselete offsets.
@@ -44,7 +43,10 @@ general interchange format for new applications.
 use crate::{
     civil::{Date, DateTime, Time, Weekday},
     error::{err, ErrorContext},
-    fmt::{util::DecimalFormatter, Parsed, Write, WriteExt},
+    fmt::{
+        util::{military_time_zone_offset, DecimalFormatter},
+        Parsed, Write, WriteExt,
+    },
     tz::{Offset, TimeZone},
     util::{
         escape, parse,
@@ -218,13 +220,14 @@ pub fn parse(string: &str) -> Result<Zoned, Error> {
 #[derive(Debug)]
 pub struct DateTimeParser {
     relaxed_weekday: bool,
+    lenient: bool,
 }
 
 impl DateTimeParser {
     /// Create a new RFC 2822 datetime parser with the default configuration.
     #[inline]
     pub const fn new() -> DateTimeParser {
-        DateTimeParser { relaxed_weekday: false }
+        DateTimeParser { relaxed_weekday: false, lenient: false }
     }
 
     /// When enabled, parsing will permit the weekday to be inconsistent with
@@ -265,6 +268,58 @@ impl DateTimeParser {
         DateTimeParser { relaxed_weekday: yes, ..self }
     }
 
+    /// When enabled, parsing will accept the obsolete syntax for comments
+    /// and folding whitespace described in [RFC 2822 section 4].
+    ///
+    /// Specifically, this permits parenthesized comments (which may be
+    /// nested) anywhere whitespace is permitted, including in place of
+    /// whitespace. It also permits optional whitespace and comments before
+    /// and after the comma following a weekday and around the `:` separating
+    /// the components of a time. Finally, a comment may immediately follow an
+    /// obsolete time zone abbreviation like `EST` without any whitespace in
+    /// between.
+    ///
+    /// This syntax is obsolete and may not be generated by conforming
+    /// implementations, but it still appears in the wild, for example, in
+    /// email archives.
+    ///
+    /// Note that obsolete time zone abbreviations (like `EST`, `PDT`, `GMT`
+    /// and single letter military zones) and two or three digit years are
+    /// always supported, regardless of whether this option is enabled.
+    /// However, lenient mode changes how single letter military zones are
+    /// interpreted. RFC 2822 says they should be treated as `-0000` (which
+    /// Jiff treats as UTC), since their definition in RFC 822 was botched.
+    /// When lenient mode is enabled, they are instead mapped to the fixed
+    /// offsets they were originally defined as: `Z` is UTC, `A` through `I`
+    /// are `+01` through `+09`, `K` through `M` are `+10` through `+12` and
+    /// `N` through `Y` are `-01` through `-12`.
+    ///
+    /// This is disabled by default.
+    ///
+    /// [RFC 2822 section 4]: https://datatracker.ietf.org/doc/html/rfc2822#section-4
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::rfc2822;
+    ///
+    /// let string = "Fri (Friday) , 21 Nov 97 09 : 55 : 06 EST(Eastern)";
+    /// // The above normally results in an error, since it uses obsolete
+    /// // syntax:
+    /// assert!(rfc2822::parse(string).is_err());
+    /// // But lenient mode accepts it:
+    /// static P: rfc2822::DateTimeParser = rfc2822::DateTimeParser::new()
+    ///     .lenient(true);
+    /// let zdt = P.parse_zoned(string)?;
+    /// assert_eq!(zdt.to_string(), "1997-11-21T09:55:06-05:00[-05:00]");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn lenient(self, yes: bool) -> DateTimeParser {
+        DateTimeParser { lenient: yes, ..self }
+    }
+
     /// Parse a datetime string into a [`Zoned`] value.
     ///
     /// Note that RFC 2822 does not support time zone annotations. The zoned
@@ -348,14 +403,73 @@ impl DateTimeParser {
         &self,
         input: I,
     ) -> Result<Timestamp, Error> {
-        let input = input.as_ref();
-        let ts = self
-            .parse_timestamp_internal(input)
-            .context("failed to parse RFC 2822 datetime into Jiff timestamp")?
-            .into_full()?;
+        let (ts, _) = self.parse_timestamp_with_offset(input)?;
         Ok(ts)
     }
 
+    /// Parse an RFC 2822 datetime string into a [`Timestamp`] along with its
+    /// offset to local time, if it's known.
+    ///
+    /// This is like [`DateTimeParser::parse_timestamp`], except it also
+    /// returns the offset parsed. RFC 2822 uses an offset of `-0000` to
+    /// indicate that a datetime is in UTC, but that the offset to local time
+    /// is unknown. This is distinct from `+0000`, which indicates that UTC is
+    /// the preferred reference point. When the offset to local time is
+    /// unknown, `None` is returned for the offset.
+    ///
+    /// RFC 2822 also says that obsolete single letter military time zones
+    /// and unrecognized alphabetic time zone abbreviations should be treated
+    /// as `-0000`. So `None` is returned for those too. (Except when
+    /// [`DateTimeParser::lenient`] is enabled, in which case all military
+    /// time zones other than `Z` are mapped to their original fixed
+    /// offsets.)
+    ///
+    /// This is the inverse of [`DateTimePrinter::print_zoned`] (for known
+    /// offsets) and [`DateTimePrinter::print_timestamp`] (for unknown
+    /// offsets).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`DateTimeParser::parse_timestamp`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::rfc2822::DateTimeParser, tz::Offset};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let (ts, offset) =
+    ///     PARSER.parse_timestamp_with_offset("Thu, 29 Feb 2024 05:34 -0500")?;
+    /// assert_eq!(ts.to_string(), "2024-02-29T10:34:00Z");
+    /// assert_eq!(offset, Some(Offset::constant(-5)));
+    ///
+    /// let (ts, offset) =
+    ///     PARSER.parse_timestamp_with_offset("Thu, 29 Feb 2024 10:34 +0000")?;
+    /// assert_eq!(ts.to_string(), "2024-02-29T10:34:00Z");
+    /// assert_eq!(offset, Some(Offset::UTC));
+    ///
+    /// let (ts, offset) =
+    ///     PARSER.parse_timestamp_with_offset("Thu, 29 Feb 2024 10:34 -0000")?;
+    /// assert_eq!(ts.to_string(), "2024-02-29T10:34:00Z");
+    /// assert_eq!(offset, None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_timestamp_with_offset<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<(Timestamp, Option<Offset>), Error> {
+        let input = input.as_ref();
+        let Parsed { value: (ts, offset), input } =
+            self.parse_timestamp_internal(input).context(
+                "failed to parse RFC 2822 datetime into Jiff timestamp",
+            )?;
+        let ts = Parsed { value: ts, input }.into_full()?;
+        Ok((ts, offset))
+    }
+
     /// Parses an RFC 2822 datetime as a zoned datetime.
     ///
     /// Note that this doesn't check that the input has been completely
@@ -367,6 +481,7 @@ impl DateTimeParser {
     ) -> Result<Parsed<'i, Zoned>, Error> {
         let Parsed { value: (dt, offset), input } =
             self.parse_datetime_offset(input)?;
+        let offset = offset.unwrap_or(Offset::UTC);
         let ts = offset
             .to_timestamp(dt)
             .context("RFC 2822 datetime out of Jiff's range")?;
@@ -374,7 +489,8 @@ impl DateTimeParser {
         Ok(Parsed { value: zdt, input })
     }
 
-    /// Parses an RFC 2822 datetime as a timestamp.
+    /// Parses an RFC 2822 datetime as a timestamp, along with its offset if
+    /// it's known.
     ///
     /// Note that this doesn't check that the input has been completely
     /// consumed.
@@ -382,28 +498,32 @@ impl DateTimeParser {
     fn parse_timestamp_internal<'i>(
         &self,
         input: &'i [u8],
-    ) -> Result<Parsed<'i, Timestamp>, Error> {
+    ) -> Result<Parsed<'i, (Timestamp, Option<Offset>)>, Error> {
         let Parsed { value: (dt, offset), input } =
             self.parse_datetime_offset(input)?;
         let ts = offset
+            .unwrap_or(Offset::UTC)
             .to_timestamp(dt)
             .context("RFC 2822 datetime out of Jiff's range")?;
-        Ok(Parsed { value: ts, input })
+        Ok(Parsed { value: (ts, offset), input })
     }
 
     /// Parse the entirety of the given input into RFC 2822 components: a civil
     /// datetime and its offset.
     ///
+    /// The offset is `None` when it indicates that the offset to local time
+    /// is unknown (e.g., `-0000`). Such datetimes are in UTC.
+    ///
     /// This also consumes any trailing (superfluous) whitespace.
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn parse_datetime_offset<'i>(
         &self,
         input: &'i [u8],
-    ) -> Result<Parsed<'i, (DateTime, Offset)>, Error> {
+    ) -> Result<Parsed<'i, (DateTime, Option<Offset>)>, Error> {
         let input = input.as_ref();
         let Parsed { value: dt, input } = self.parse_datetime(input)?;
         let Parsed { value: offset, input } = self.parse_offset(input)?;
-        let Parsed { input, .. } = self.skip_whitespace(input);
+        let Parsed { input, .. } = self.skip_cfws(input)?;
         let input = if input.is_empty() {
             input
         } else {
@@ -429,7 +549,7 @@ impl DateTimeParser {
                 "expected RFC 2822 datetime, but got empty string"
             ));
         }
-        let Parsed { input, .. } = self.skip_whitespace(input);
+        let Parsed { input, .. } = self.skip_cfws(input)?;
         if input.is_empty() {
             return Err(err!(
                 "expected RFC 2822 datetime, but got empty string after \
@@ -444,7 +564,8 @@ impl DateTimeParser {
         let Parsed { value: hour, input } = self.parse_hour(input)?;
         let Parsed { input, .. } = self.parse_time_separator(input)?;
         let Parsed { value: minute, input } = self.parse_minute(input)?;
-        let (second, input) = if !input.starts_with(b":") {
+        let Parsed { input: rest, .. } = self.skip_obsolete_cfws(input)?;
+        let (second, input) = if !rest.starts_with(b":") {
             (t::Second::N::<0>(), input)
         } else {
             let Parsed { input, .. } = self.parse_time_separator(input)?;
@@ -535,6 +656,9 @@ impl DateTimeParser {
                 ));
             }
         };
+        if self.lenient {
+            return self.parse_weekday_obsolete(wd, input);
+        }
         if input[3] != b',' {
             return Err(err!(
                 "expected day at beginning of RFC 2822 datetime \
@@ -556,6 +680,40 @@ impl DateTimeParser {
         Ok(Parsed { value: Some(wd), input })
     }
 
+    /// Parses the remainder of a weekday using the obsolete syntax, which
+    /// permits optional whitespace and comments on either side of the comma.
+    ///
+    /// This assumes `input` begins with the 3 letter weekday abbreviation
+    /// corresponding to `wd`.
+    #[inline(never)]
+    fn parse_weekday_obsolete<'i>(
+        &self,
+        wd: Weekday,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, Option<Weekday>>, Error> {
+        let Parsed { input: rest, .. } = self.skip_cfws(&input[3..])?;
+        match rest.first() {
+            Some(&b',') => {}
+            None => {
+                return Err(err!(
+                    "expected comma after parsed weekday {wd:?}, \
+                     but found end of input",
+                    wd = escape::Bytes(&input[..3]),
+                ));
+            }
+            Some(&got) => {
+                return Err(err!(
+                    "expected comma after parsed weekday {wd:?}, \
+                     but found {got:?}",
+                    wd = escape::Bytes(&input[..3]),
+                    got = escape::Byte(got),
+                ));
+            }
+        }
+        let Parsed { input, .. } = self.skip_cfws(&rest[1..])?;
+        Ok(Parsed { value: Some(wd), input })
+    }
+
     /// Parses a 1 or 2 digit day.
     ///
     /// This assumes the input starts with what must be an ASCII digit (or it
@@ -774,13 +932,17 @@ impl DateTimeParser {
 
     /// Parses a time zone offset (including obsolete offsets like EDT).
     ///
+    /// This returns `None` for `-0000` and for obsolete offsets that RFC 2822
+    /// says should be treated as `-0000`. That is, the offset to local time
+    /// is unknown.
+    ///
     /// This assumes the offset must begin at the beginning of `input`. That
     /// is, any leading whitespace should already have been trimmed.
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn parse_offset<'i>(
         &self,
         input: &'i [u8],
-    ) -> Result<Parsed<'i, Offset>, Error> {
+    ) -> Result<Parsed<'i, Option<Offset>>, Error> {
         type ParsedOffsetHours = ri8<0, { t::SpanZoneOffsetHours::MAX }>;
         type ParsedOffsetMinutes = ri8<0, { t::SpanZoneOffsetMinutes::MAX }>;
 
@@ -829,7 +991,18 @@ impl DateTimeParser {
 
         let seconds = hh * C(3_600) + mm * C(60);
         let offset = Offset::from_seconds_ranged(seconds * sign);
-        Ok(Parsed { value: offset, input })
+        // Section 3.3 says:
+        //
+        // > The form "+0000" SHOULD be used to indicate a time zone at
+        // > Universal Time. Though "-0000" also indicates Universal Time, it
+        // > is used to indicate that the time was generated on a system that
+        // > may be in a local time zone other than Universal Time and
+        // > therefore indicates that the date-time contains no information
+        // > about the local time zone.
+        if offset.is_zero() && sign < C(0) {
+            return Ok(Parsed { value: None, input });
+        }
+        Ok(Parsed { value: Some(offset), input })
     }
 
     /// Parses an obsolete time zone offset.
@@ -837,12 +1010,13 @@ impl DateTimeParser {
     fn parse_offset_obsolete<'i>(
         &self,
         input: &'i [u8],
-    ) -> Result<Parsed<'i, Offset>, Error> {
+    ) -> Result<Parsed<'i, Option<Offset>>, Error> {
         let mut letters = [0; 5];
         let mut len = 0;
         while len <= 4
             && !input[len..].is_empty()
             && !is_whitespace(input[len])
+            && (!self.lenient || input[len] != b'(')
         {
             letters[len] = input[len].to_ascii_lowercase();
             len += 1;
@@ -855,15 +1029,15 @@ impl DateTimeParser {
             ));
         }
         let offset = match &letters[..len] {
-            b"ut" | b"gmt" | b"z" => Offset::UTC,
-            b"est" => Offset::constant(-5),
-            b"edt" => Offset::constant(-4),
-            b"cst" => Offset::constant(-6),
-            b"cdt" => Offset::constant(-5),
-            b"mst" => Offset::constant(-7),
-            b"mdt" => Offset::constant(-6),
-            b"pst" => Offset::constant(-8),
-            b"pdt" => Offset::constant(-7),
+            b"ut" | b"gmt" => Some(Offset::UTC),
+            b"est" => Some(Offset::constant(-5)),
+            b"edt" => Some(Offset::constant(-4)),
+            b"cst" => Some(Offset::constant(-6)),
+            b"cdt" => Some(Offset::constant(-5)),
+            b"mst" => Some(Offset::constant(-7)),
+            b"mdt" => Some(Offset::constant(-6)),
+            b"pst" => Some(Offset::constant(-8)),
+            b"pdt" => Some(Offset::constant(-7)),
             name => {
                 if name.len() == 1
                     && matches!(name[0], b'a'..=b'i' | b'k'..=b'z')
@@ -883,8 +1057,15 @@ impl DateTimeParser {
                     // > equivalent to "-0000" unless there is out-of-band
                     // > information confirming their meaning.
                     //
-                    // So just treat them as UTC.
-                    Offset::UTC
+                    // So just treat them as `-0000`, unless the caller opted
+                    // into lenient parsing, in which case we use the
+                    // original definitions. We treat `Z` as `-0000` either
+                    // way, since that's what `Z` means in RFC 3339.
+                    if self.lenient && name[0] != b'z' {
+                        military_time_zone_offset(name[0])
+                    } else {
+                        None
+                    }
                 } else if name.len() >= 3
                     && name.iter().all(|&b| matches!(b, b'a'..=b'z'))
                 {
@@ -897,7 +1078,7 @@ impl DateTimeParser {
                     // > known SHOULD be considered equivalent to "-0000"
                     // > unless there is out-of-band information confirming
                     // > their meaning.
-                    Offset::UTC
+                    None
                 } else {
                     // But anything else we throw our hands up I guess.
                     return Err(err!(
@@ -918,6 +1099,7 @@ impl DateTimeParser {
         &self,
         input: &'i [u8],
     ) -> Result<Parsed<'i, ()>, Error> {
+        let Parsed { input, .. } = self.skip_obsolete_cfws(input)?;
         if input.is_empty() {
             return Err(err!(
                 "expected time separator of ':', but found end of input",
@@ -929,7 +1111,7 @@ impl DateTimeParser {
                 got = escape::Byte(input[0]),
             ));
         }
-        Ok(Parsed { value: (), input: &input[1..] })
+        self.skip_obsolete_cfws(&input[1..])
     }
 
     /// Parses at least one whitespace character. If no whitespace was found,
@@ -940,7 +1122,7 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ()>, Error> {
         let oldlen = input.len();
-        let parsed = self.skip_whitespace(input);
+        let parsed = self.skip_cfws(input)?;
         let newlen = parsed.input.len();
         if oldlen == newlen {
             return Err(err!(
@@ -962,6 +1144,38 @@ impl DateTimeParser {
         Parsed { value: (), input }
     }
 
+    /// Skips over any whitespace at the beginning of `input`. When lenient
+    /// parsing is enabled, this also skips over any comments interleaved with
+    /// the whitespace.
+    ///
+    /// This returns an error if a comment is unbalanced.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn skip_cfws<'i>(&self, input: &'i [u8]) -> Result<Parsed<'i, ()>, Error> {
+        let Parsed { mut input, .. } = self.skip_whitespace(input);
+        if self.lenient {
+            while input.starts_with(b"(") {
+                input = self.skip_comment(input)?.input;
+            }
+        }
+        Ok(Parsed { value: (), input })
+    }
+
+    /// Skips over any whitespace and comments at the beginning of `input`, but
+    /// only when lenient parsing is enabled. This is used in places where the
+    /// obsolete syntax permits whitespace but the current syntax does not.
+    ///
+    /// When lenient parsing is disabled, this returns the input unchanged.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn skip_obsolete_cfws<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, ()>, Error> {
+        if !self.lenient {
+            return Ok(Parsed { value: (), input });
+        }
+        self.skip_cfws(input)
+    }
+
     /// This attempts to parse and skip any trailing "comment" in an RFC 2822
     /// datetime.
     ///
@@ -1086,15 +1300,125 @@ impl DateTimeParser {
 /// ```
 #[derive(Debug)]
 pub struct DateTimePrinter {
-    // The RFC 2822 printer has no configuration at present.
-    _private: (),
+    utc_as_gmt: bool,
+    weekday: bool,
+    pad_day: bool,
 }
 
 impl DateTimePrinter {
     /// Create a new RFC 2822 datetime printer with the default configuration.
     #[inline]
     pub const fn new() -> DateTimePrinter {
-        DateTimePrinter { _private: () }
+        DateTimePrinter { utc_as_gmt: false, weekday: true, pad_day: false }
+    }
+
+    /// When enabled, the obsolete `GMT` zone name is written instead of a
+    /// numeric offset whenever the offset is zero.
+    ///
+    /// This applies to zoned datetimes with an offset of `+0000` and to
+    /// timestamps, which are otherwise written with a `-0000` offset.
+    ///
+    /// While RFC 2822 says `GMT` is obsolete and must not be generated,
+    /// some implementations (including HTTP, which also uses `GMT` in its
+    /// preferred format) expect it.
+    ///
+    /// This is disabled by default.
+    ///
+    /// This option has no effect on
+    /// [`DateTimePrinter::print_timestamp_rfc9110`], which always writes
+    /// `GMT`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::rfc2822::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter =
+    ///     DateTimePrinter::new().utc_as_gmt(true);
+    ///
+    /// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("UTC")?;
+    /// assert_eq!(
+    ///     PRINTER.zoned_to_string(&zdt)?,
+    ///     "Sat, 15 Jun 2024 07:00:00 GMT",
+    /// );
+    /// assert_eq!(
+    ///     PRINTER.timestamp_to_string(&zdt.timestamp())?,
+    ///     "Sat, 15 Jun 2024 07:00:00 GMT",
+    /// );
+    /// // Non-zero offsets are unaffected.
+    /// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("Europe/London")?;
+    /// assert_eq!(
+    ///     PRINTER.zoned_to_string(&zdt)?,
+    ///     "Sat, 15 Jun 2024 07:00:00 +0100",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn utc_as_gmt(self, yes: bool) -> DateTimePrinter {
+        DateTimePrinter { utc_as_gmt: yes, ..self }
+    }
+
+    /// When disabled, the leading day of the week (e.g., `Sat, `) is
+    /// omitted.
+    ///
+    /// The day of the week is optional in RFC 2822. Since it is entirely
+    /// determined by the date, omitting it loses no information.
+    ///
+    /// This is enabled by default.
+    ///
+    /// This option has no effect on
+    /// [`DateTimePrinter::print_timestamp_rfc9110`], which always writes the
+    /// day of the week.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::rfc2822::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new().weekday(false);
+    ///
+    /// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(
+    ///     PRINTER.zoned_to_string(&zdt)?,
+    ///     "15 Jun 2024 07:00:00 -0400",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn weekday(self, yes: bool) -> DateTimePrinter {
+        DateTimePrinter { weekday: yes, ..self }
+    }
+
+    /// When enabled, the day of the month is always written with two digits,
+    /// padding with a leading zero when necessary.
+    ///
+    /// RFC 2822 permits either one or two digits for days less than 10.
+    /// By default, the day is written without padding.
+    ///
+    /// This option has no effect on
+    /// [`DateTimePrinter::print_timestamp_rfc9110`], which always pads the
+    /// day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::rfc2822::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new().pad_day(true);
+    ///
+    /// let zdt = date(2024, 6, 1).at(7, 0, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(
+    ///     PRINTER.zoned_to_string(&zdt)?,
+    ///     "Sat, 01 Jun 2024 07:00:00 -0400",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn pad_day(self, yes: bool) -> DateTimePrinter {
+        DateTimePrinter { pad_day: yes, ..self }
     }
 
     /// Format a `Zoned` datetime into a string.
@@ -1157,9 +1481,10 @@ impl DateTimePrinter {
 
     /// Format a `Timestamp` datetime into a string.
     ///
-    /// This always emits `-0000` as the offset in the RFC 2822 format. If you
-    /// desire a `+0000` offset, use [`DateTimePrinter::print_zoned`] with a
-    /// zoned datetime with [`TimeZone::UTC`].
+    /// This always emits `-0000` as the offset in the RFC 2822 format, unless
+    /// [`DateTimePrinter::utc_as_gmt`] is enabled. If you desire a `+0000`
+    /// offset, use [`DateTimePrinter::print_zoned`] with a zoned datetime
+    /// with [`TimeZone::UTC`].
     ///
     /// Moreover, since RFC 2822 does not support fractional seconds, this
     /// routine prints the timestamp as if truncating any fractional seconds.
@@ -1302,9 +1627,10 @@ impl DateTimePrinter {
 
     /// Print a `Timestamp` datetime to the given writer.
     ///
-    /// This always emits `-0000` as the offset in the RFC 2822 format. If you
-    /// desire a `+0000` offset, use [`DateTimePrinter::print_zoned`] with a
-    /// zoned datetime with [`TimeZone::UTC`].
+    /// This always emits `-0000` as the offset in the RFC 2822 format, unless
+    /// [`DateTimePrinter::utc_as_gmt`] is enabled. If you desire a `+0000`
+    /// offset, use [`DateTimePrinter::print_zoned`] with a zoned datetime
+    /// with [`TimeZone::UTC`].
     ///
     /// Moreover, since RFC 2822 does not support fractional seconds, this
     /// routine prints the timestamp as if truncating any fractional seconds.
@@ -1394,6 +1720,8 @@ impl DateTimePrinter {
         mut wtr: W,
     ) -> Result<(), Error> {
         static FMT_DAY: DecimalFormatter = DecimalFormatter::new();
+        static FMT_DAY_PADDED: DecimalFormatter =
+            DecimalFormatter::new().padding(2);
         static FMT_YEAR: DecimalFormatter = DecimalFormatter::new().padding(4);
         static FMT_TIME_UNIT: DecimalFormatter =
             DecimalFormatter::new().padding(2);
@@ -1408,9 +1736,12 @@ impl DateTimePrinter {
             ));
         }
 
-        wtr.write_str(weekday_abbrev(dt.weekday()))?;
-        wtr.write_str(", ")?;
-        wtr.write_int(&FMT_DAY, dt.day())?;
+        if self.weekday {
+            wtr.write_str(weekday_abbrev(dt.weekday()))?;
+            wtr.write_str(", ")?;
+        }
+        let fmt_day = if self.pad_day { &FMT_DAY_PADDED } else { &FMT_DAY };
+        wtr.write_int(fmt_day, dt.day())?;
         wtr.write_str(" ")?;
         wtr.write_str(month_name(dt.month()))?;
         wtr.write_str(" ")?;
@@ -1423,6 +1754,10 @@ impl DateTimePrinter {
         wtr.write_int(&FMT_TIME_UNIT, dt.second())?;
         wtr.write_str(" ")?;
 
+        if self.utc_as_gmt && offset.map_or(true, |o| o.is_zero()) {
+            wtr.write_str("GMT")?;
+            return Ok(());
+        }
         let Some(offset) = offset else {
             wtr.write_str("-0000")?;
             return Ok(());
@@ -1655,6 +1990,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ok_parse_timestamp_with_offset() {
+        let p = |input| {
+            let (_, offset) = DateTimeParser::new()
+                .parse_timestamp_with_offset(input)
+                .unwrap();
+            offset
+        };
+        let lenient = |input| {
+            let (_, offset) = DateTimeParser::new()
+                .lenient(true)
+                .parse_timestamp_with_offset(input)
+                .unwrap();
+            offset
+        };
+
+        assert_eq!(
+            p("10 Jan 2024 05:34:45 -0500"),
+            Some(-Offset::constant(5))
+        );
+        assert_eq!(p("10 Jan 2024 05:34:45 +0000"), Some(Offset::UTC));
+        assert_eq!(p("10 Jan 2024 05:34:45 GMT"), Some(Offset::UTC));
+        assert_eq!(p("10 Jan 2024 05:34:45 UT"), Some(Offset::UTC));
+        assert_eq!(p("10 Jan 2024 05:34:45 EST"), Some(-Offset::constant(5)));
+
+        // Things that say nothing about the local offset.
+        assert_eq!(p("10 Jan 2024 05:34:45 -0000"), None);
+        assert_eq!(p("10 Jan 2024 05:34:45 Z"), None);
+        assert_eq!(p("10 Jan 2024 05:34:45 A"), None);
+        assert_eq!(p("10 Jan 2024 05:34:45 XXX"), None);
+
+        assert_eq!(
+            lenient("10 Jan 2024 05:34:45 A"),
+            Some(Offset::constant(1))
+        );
+        assert_eq!(lenient("10 Jan 2024 05:34:45 Z"), None);
+    }
+
     // whyyyyyyyyyyyyy
     #[test]
     fn ok_parse_comment() {
@@ -1828,6 +2201,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ok_parse_lenient() {
+        let p = |input| {
+            DateTimeParser::new().lenient(true).parse_zoned(input).unwrap()
+        };
+
+        insta::assert_debug_snapshot!(
+            p("Fri, 21 Nov 1997 09(comment):   55  :  06 -0600"),
+            @"1997-11-21T09:55:06-06:00[-06:00]",
+        );
+        insta::assert_debug_snapshot!(
+            p("(lead) Fri (Friday) ,21 (x(y)) Nov\r\n 1997 09 : 55 (z) -0600"),
+            @"1997-11-21T09:55:00-06:00[-06:00]",
+        );
+        insta::assert_debug_snapshot!(
+            p("Fri,21 Nov 97 09:55:06 EST(Eastern Standard Time)"),
+            @"1997-11-21T09:55:06-05:00[-05:00]",
+        );
+        insta::assert_debug_snapshot!(
+            p("21 Nov 1997 09:55:06(a)GMT(b) (c)"),
+            @"1997-11-21T09:55:06+00:00[UTC]",
+        );
+        insta::assert_debug_snapshot!(
+            p("Fri, 21 Nov 1997 09:55:06 -0600"),
+            @"1997-11-21T09:55:06-06:00[-06:00]",
+        );
+
+        // Military time zones get their original definitions.
+        insta::assert_debug_snapshot!(
+            p("Fri, 21 Nov 1997 09:55:06 A"),
+            @"1997-11-21T09:55:06+01:00[+01:00]",
+        );
+        insta::assert_debug_snapshot!(
+            p("Fri, 21 Nov 1997 09:55:06 m(Mike)"),
+            @"1997-11-21T09:55:06+12:00[+12:00]",
+        );
+        insta::assert_debug_snapshot!(
+            p("Fri, 21 Nov 1997 09:55:06 Y"),
+            @"1997-11-21T09:55:06-12:00[-12:00]",
+        );
+        insta::assert_debug_snapshot!(
+            p("Fri, 21 Nov 1997 09:55:06 Z"),
+            @"1997-11-21T09:55:06+00:00[UTC]",
+        );
+    }
+
+    #[test]
+    fn err_parse_lenient() {
+        let p = |input| {
+            DateTimeParser::new()
+                .lenient(true)
+                .parse_zoned(input)
+                .unwrap_err()
+                .to_string()
+        };
+
+        insta::assert_snapshot!(
+            p("Fri (Friday) 21 Nov 1997 09:55:06 -0600"),
+            @r###"failed to parse RFC 2822 datetime into Jiff zoned datetime: expected comma after parsed weekday "Fri", but found "2""###,
+        );
+        insta::assert_snapshot!(
+            p("Fri (Friday"),
+            @"failed to parse RFC 2822 datetime into Jiff zoned datetime: found opening parenthesis in comment with no matching closing parenthesis",
+        );
+        insta::assert_snapshot!(
+            p("Fri, 21 Nov 1997 09 (:55:06 -0600"),
+            @"failed to parse RFC 2822 datetime into Jiff zoned datetime: found opening parenthesis in comment with no matching closing parenthesis",
+        );
+        insta::assert_snapshot!(
+            p("Fri, 21 Nov 1997 09:55:06 -0600 (a) b"),
+            @r###"parsed value '1997-11-21T09:55:06-06:00[-06:00]', but unparsed input "b" remains (expected no unparsed input)"###,
+        );
+
+        // Without lenient mode, none of the obsolete syntax is accepted.
+        let p = |input| {
+            DateTimeParser::new().parse_zoned(input).unwrap_err().to_string()
+        };
+        insta::assert_snapshot!(
+            p("Fri, 21 Nov 1997 09 : 55 : 06 -0600"),
+            @"failed to parse RFC 2822 datetime into Jiff zoned datetime: expected time separator of ':', but found",
+        );
+        insta::assert_snapshot!(
+            p("Fri, 21 Nov 1997 (x) 09:55:06 -0600"),
+            @r###"failed to parse RFC 2822 datetime into Jiff zoned datetime: failed to parse "(x" as hour (a two digit integer): invalid digit, expected 0-9 but got ("###,
+        );
+        insta::assert_snapshot!(
+            p("Fri, 21 Nov 1997 09:55:06 EST(Eastern)"),
+            @r###"failed to parse RFC 2822 datetime into Jiff zoned datetime: expected obsolete RFC 2822 time zone abbreviation, but found "EST(E""###,
+        );
+    }
+
     #[test]
     fn ok_print_zoned() {
         if crate::tz::db().is_definitively_empty() {
@@ -1866,6 +2330,48 @@ mod tests {
         insta::assert_snapshot!(p(&zdt), @"Tue, 5 Mar 2024 05:34:45 +0000");
     }
 
+    #[test]
+    fn ok_print_options() {
+        let zdt = date(2024, 3, 5)
+            .at(5, 34, 45, 0)
+            .to_zoned(TimeZone::fixed(crate::tz::offset(-5)))
+            .unwrap();
+        let utc = zdt.with_time_zone(TimeZone::UTC);
+        let p = |printer: DateTimePrinter| -> String {
+            let mut buf = String::new();
+            printer.print_zoned(&zdt, &mut buf).unwrap();
+            buf.push_str(" | ");
+            printer.print_zoned(&utc, &mut buf).unwrap();
+            buf.push_str(" | ");
+            printer.print_timestamp(&utc.timestamp(), &mut buf).unwrap();
+            buf
+        };
+
+        insta::assert_snapshot!(
+            p(DateTimePrinter::new()),
+            @"Tue, 5 Mar 2024 05:34:45 -0500 | Tue, 5 Mar 2024 10:34:45 +0000 | Tue, 5 Mar 2024 10:34:45 -0000",
+        );
+        insta::assert_snapshot!(
+            p(DateTimePrinter::new().utc_as_gmt(true)),
+            @"Tue, 5 Mar 2024 05:34:45 -0500 | Tue, 5 Mar 2024 10:34:45 GMT | Tue, 5 Mar 2024 10:34:45 GMT",
+        );
+        insta::assert_snapshot!(
+            p(DateTimePrinter::new().weekday(false)),
+            @"5 Mar 2024 05:34:45 -0500 | 5 Mar 2024 10:34:45 +0000 | 5 Mar 2024 10:34:45 -0000",
+        );
+        insta::assert_snapshot!(
+            p(DateTimePrinter::new().pad_day(true)),
+            @"Tue, 05 Mar 2024 05:34:45 -0500 | Tue, 05 Mar 2024 10:34:45 +0000 | Tue, 05 Mar 2024 10:34:45 -0000",
+        );
+        insta::assert_snapshot!(
+            p(DateTimePrinter::new()
+                .utc_as_gmt(true)
+                .weekday(false)
+                .pad_day(true)),
+            @"05 Mar 2024 05:34:45 -0500 | 05 Mar 2024 10:34:45 GMT | 05 Mar 2024 10:34:45 GMT",
+        );
+    }
+
     #[test]
     fn ok_print_timestamp() {
         if crate::tz::db().is_definitively_empty() {
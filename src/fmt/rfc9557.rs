@@ -91,6 +91,10 @@ including by returning an error if it isn't supported.
 //   a b c d e f g h i j k l m n o p q r s t u v w x y z
 //
 // # N.B. This is handled by src/format/offset.rs, so we don't expand it here.
+// #
+// # Jiff also accepts a seconds component here (but not fractional seconds),
+// # so that a fixed offset time zone with a subminute offset can round-trip.
+// # This is only emitted by Jiff's printer when asked for.
 // TimeZoneUTCOffsetName :::
 //   UTCOffsetMinutePrecision
 
@@ -256,8 +260,10 @@ impl Parser {
         // an IANA annotation nor a generic key/value annotation can begin with
         // a `+` or a `-`.
         if input.starts_with(b"+") || input.starts_with(b"-") {
-            const P: offset::Parser =
-                offset::Parser::new().zulu(false).subminute(false);
+            const P: offset::Parser = offset::Parser::new()
+                .zulu(false)
+                .subminute(true)
+                .subsecond(false);
 
             let Parsed { value: offset, input } = P.parse(input)?;
             let Parsed { input, .. } =
@@ -865,6 +871,24 @@ mod tests {
             input: "",
         }
         "###);
+        insta::assert_debug_snapshot!(p(b"[-05:12:34]"), @r###"
+        Parsed {
+            value: ParsedAnnotations {
+                input: "[-05:12:34]",
+                time_zone: Some(
+                    Offset {
+                        critical: false,
+                        offset: ParsedOffset {
+                            kind: Numeric(
+                                -05:12:34,
+                            ),
+                        },
+                    },
+                ),
+            },
+            input: "",
+        }
+        "###);
     }
 
     #[test]
@@ -916,13 +940,9 @@ mod tests {
             Parser::new().parse(b"[-26]").unwrap_err(),
             @r###"failed to parse hours in UTC numeric offset "-26]": offset hours are not valid: parameter 'hours' with value 26 is not in the required range of 0..=25"###,
         );
-        insta::assert_snapshot!(
-            Parser::new().parse(b"[+05:12:34]").unwrap_err(),
-            @r###"subminute precision for UTC numeric offset "+05:12:34]" is not enabled in this context (must provide only integral minutes)"###,
-        );
         insta::assert_snapshot!(
             Parser::new().parse(b"[+05:12:34.123456789]").unwrap_err(),
-            @r###"subminute precision for UTC numeric offset "+05:12:34.123456789]" is not enabled in this context (must provide only integral minutes)"###,
+            @r###"subsecond precision for UTC numeric offset "+05:12:34.123456789]" is not enabled in this context (must provide only integral minutes or seconds)"###,
         );
     }
 
@@ -14,31 +14,116 @@ usable with Serde's attributes. For each leaf, the full path is spelled out for
 easy copy & paste.
 
 * [`duration`]
+    * [`iso8601`](self::duration::iso8601)
+        * [`jiff::fmt::serde::duration::iso8601::required`](self::duration::iso8601::required)
+        * [`jiff::fmt::serde::duration::iso8601::optional`](self::duration::iso8601::optional)
     * [`friendly`](self::duration::friendly)
+        * [`jiff::fmt::serde::duration::friendly::required`](self::duration::friendly::required)
+        * [`jiff::fmt::serde::duration::friendly::optional`](self::duration::friendly::optional)
         * [`compact`](self::duration::friendly::compact)
             * [`jiff::fmt::serde::duration::friendly::compact::required`](self::duration::friendly::compact::required)
             * [`jiff::fmt::serde::duration::friendly::compact::optional`](self::duration::friendly::compact::optional)
+    * [`compact`](self::duration::compact)
+        * [`jiff::fmt::serde::duration::compact::required`](self::duration::compact::required)
+        * [`jiff::fmt::serde::duration::compact::optional`](self::duration::compact::optional)
 * [`span`]
+    * [`iso8601`](self::span::iso8601)
+        * [`jiff::fmt::serde::span::iso8601::required`](self::span::iso8601::required)
+        * [`jiff::fmt::serde::span::iso8601::optional`](self::span::iso8601::optional)
     * [`friendly`](self::span::friendly)
+        * [`jiff::fmt::serde::span::friendly::required`](self::span::friendly::required)
+        * [`jiff::fmt::serde::span::friendly::optional`](self::span::friendly::optional)
         * [`compact`](self::span::friendly::compact)
             * [`jiff::fmt::serde::span::friendly::compact::required`](self::span::friendly::compact::required)
             * [`jiff::fmt::serde::span::friendly::compact::optional`](self::span::friendly::compact::optional)
+    * [`compact`](self::span::compact)
+        * [`jiff::fmt::serde::span::compact::required`](self::span::compact::required)
+        * [`jiff::fmt::serde::span::compact::optional`](self::span::compact::optional)
 * [`timestamp`]
     * [`second`](self::timestamp::second)
         * [`jiff::fmt::serde::timestamp::second::required`](self::timestamp::second::required)
         * [`jiff::fmt::serde::timestamp::second::optional`](self::timestamp::second::optional)
+        * [`f64`](self::timestamp::second::f64)
+            * [`jiff::fmt::serde::timestamp::second::f64::required`](self::timestamp::second::f64::required)
+            * [`jiff::fmt::serde::timestamp::second::f64::optional`](self::timestamp::second::f64::optional)
+        * [`decimal`](self::timestamp::second::decimal)
+            * [`jiff::fmt::serde::timestamp::second::decimal::required`](self::timestamp::second::decimal::required)
+            * [`jiff::fmt::serde::timestamp::second::decimal::optional`](self::timestamp::second::decimal::optional)
     * [`millisecond`](self::timestamp::millisecond)
         * [`jiff::fmt::serde::timestamp::millisecond::required`](self::timestamp::millisecond::required)
         * [`jiff::fmt::serde::timestamp::millisecond::optional`](self::timestamp::millisecond::optional)
+        * [`f64`](self::timestamp::millisecond::f64)
+            * [`jiff::fmt::serde::timestamp::millisecond::f64::required`](self::timestamp::millisecond::f64::required)
+            * [`jiff::fmt::serde::timestamp::millisecond::f64::optional`](self::timestamp::millisecond::f64::optional)
     * [`microsecond`](self::timestamp::millisecond)
         * [`jiff::fmt::serde::timestamp::microsecond::required`](self::timestamp::microsecond::required)
         * [`jiff::fmt::serde::timestamp::microsecond::optional`](self::timestamp::microsecond::optional)
     * [`nanosecond`](self::timestamp::millisecond)
         * [`jiff::fmt::serde::timestamp::nanosecond::required`](self::timestamp::nanosecond::required)
         * [`jiff::fmt::serde::timestamp::nanosecond::optional`](self::timestamp::nanosecond::optional)
+    * [`flexible`](self::timestamp::flexible)
+        * [`auto`](self::timestamp::flexible::auto)
+            * [`jiff::fmt::serde::timestamp::flexible::auto::required`](self::timestamp::flexible::auto::required)
+            * [`jiff::fmt::serde::timestamp::flexible::auto::optional`](self::timestamp::flexible::auto::optional)
+        * [`second`](self::timestamp::flexible::second)
+            * [`jiff::fmt::serde::timestamp::flexible::second::required`](self::timestamp::flexible::second::required)
+            * [`jiff::fmt::serde::timestamp::flexible::second::optional`](self::timestamp::flexible::second::optional)
+        * [`millisecond`](self::timestamp::flexible::millisecond)
+            * [`jiff::fmt::serde::timestamp::flexible::millisecond::required`](self::timestamp::flexible::millisecond::required)
+            * [`jiff::fmt::serde::timestamp::flexible::millisecond::optional`](self::timestamp::flexible::millisecond::optional)
+        * [`microsecond`](self::timestamp::flexible::microsecond)
+            * [`jiff::fmt::serde::timestamp::flexible::microsecond::required`](self::timestamp::flexible::microsecond::required)
+            * [`jiff::fmt::serde::timestamp::flexible::microsecond::optional`](self::timestamp::flexible::microsecond::optional)
+        * [`nanosecond`](self::timestamp::flexible::nanosecond)
+            * [`jiff::fmt::serde::timestamp::flexible::nanosecond::required`](self::timestamp::flexible::nanosecond::required)
+            * [`jiff::fmt::serde::timestamp::flexible::nanosecond::optional`](self::timestamp::flexible::nanosecond::optional)
+    * [`compact`](self::timestamp::compact)
+        * [`jiff::fmt::serde::timestamp::compact::required`](self::timestamp::compact::required)
+        * [`jiff::fmt::serde::timestamp::compact::optional`](self::timestamp::compact::optional)
+* [`civil`]
+    * [`date`](self::civil::date)
+        * [`unix_epoch_day`](self::civil::date::unix_epoch_day)
+            * [`jiff::fmt::serde::civil::date::unix_epoch_day::required`](self::civil::date::unix_epoch_day::required)
+            * [`jiff::fmt::serde::civil::date::unix_epoch_day::optional`](self::civil::date::unix_epoch_day::optional)
+    * [`time`](self::civil::time)
+        * [`nanosecond`](self::civil::time::nanosecond)
+            * [`jiff::fmt::serde::civil::time::nanosecond::required`](self::civil::time::nanosecond::required)
+            * [`jiff::fmt::serde::civil::time::nanosecond::optional`](self::civil::time::nanosecond::optional)
+    * [`datetime`](self::civil::datetime)
+        * [`compact`](self::civil::datetime::compact)
+            * [`jiff::fmt::serde::civil::datetime::compact::required`](self::civil::datetime::compact::required)
+            * [`jiff::fmt::serde::civil::datetime::compact::optional`](self::civil::datetime::compact::optional)
+* [`datetime`]
+    * [`assume_utc`](self::datetime::assume_utc)
+        * [`timestamp`](self::datetime::assume_utc::timestamp)
+            * [`jiff::fmt::serde::datetime::assume_utc::timestamp::required`](self::datetime::assume_utc::timestamp::required)
+            * [`jiff::fmt::serde::datetime::assume_utc::timestamp::optional`](self::datetime::assume_utc::timestamp::optional)
+        * [`zoned`](self::datetime::assume_utc::zoned)
+            * [`jiff::fmt::serde::datetime::assume_utc::zoned::required`](self::datetime::assume_utc::zoned::required)
+            * [`jiff::fmt::serde::datetime::assume_utc::zoned::optional`](self::datetime::assume_utc::zoned::optional)
+* [`zoned`]
+    * [`rfc3339`](self::zoned::rfc3339)
+        * [`offset`](self::zoned::rfc3339::offset)
+            * [`jiff::fmt::serde::zoned::rfc3339::offset::required`](self::zoned::rfc3339::offset::required)
+            * [`jiff::fmt::serde::zoned::rfc3339::offset::optional`](self::zoned::rfc3339::offset::optional)
+        * [`system`](self::zoned::rfc3339::system)
+            * [`jiff::fmt::serde::zoned::rfc3339::system::required`](self::zoned::rfc3339::system::required)
+            * [`jiff::fmt::serde::zoned::rfc3339::system::optional`](self::zoned::rfc3339::system::optional)
+    * [`compact`](self::zoned::compact)
+        * [`jiff::fmt::serde::zoned::compact::required`](self::zoned::compact::required)
+        * [`jiff::fmt::serde::zoned::compact::optional`](self::zoned::compact::optional)
 * [`tz`]
     * [`jiff::fmt::serde::tz::required`](self::tz::required)
     * [`jiff::fmt::serde::tz::optional`](self::tz::optional)
+    * [`iana`](self::tz::iana)
+        * [`jiff::fmt::serde::tz::iana::required`](self::tz::iana::required)
+        * [`jiff::fmt::serde::tz::iana::optional`](self::tz::iana::optional)
+    * [`posix`](self::tz::posix)
+        * [`jiff::fmt::serde::tz::posix::required`](self::tz::posix::required)
+        * [`jiff::fmt::serde::tz::posix::optional`](self::tz::posix::optional)
+    * [`tzif`](self::tz::tzif)
+        * [`jiff::fmt::serde::tz::tzif::required`](self::tz::tzif::required)
+        * [`jiff::fmt::serde::tz::tzif::optional`](self::tz::tzif::optional)
 
 # Example: timestamps as an integer
 
@@ -128,16 +213,24 @@ assert_eq!(serde_json::to_string(&got).unwrap(), expected);
 ["friendly" duration format]: crate::fmt::friendly
 */
 
-/// Convenience routines for serializing [`SignedDuration`](crate::SignedDuration)
-/// values.
+/// Convenience routines for (de)serializing
+/// [`SignedDuration`](crate::SignedDuration) values.
 ///
 /// These convenience routines exist because the `Serialize` implementation for
 /// `SignedDuration` always uses the ISO 8601 duration format. These routines
 /// provide a way to use the "[friendly](crate::fmt::friendly)" format.
 ///
-/// Only serialization routines are provided because a `SignedDuration`'s
-/// `Deserialize` implementation automatically handles both the ISO 8601
-/// duration format and the "friendly" format.
+/// The `required` and `optional` modules in
+/// [`iso8601`](self::duration::iso8601) and
+/// [`friendly`](self::duration::friendly) are meant to be used with Serde's
+/// [`with` attribute], which makes it possible to choose the format on a
+/// per-field basis. They all deserialize both the ISO 8601 duration format and
+/// the "friendly" format, just like a `SignedDuration`'s `Deserialize`
+/// implementation. The other routines in this module only serialize, and are
+/// meant to be used with Serde's [`serialize_with` attribute].
+///
+/// [`with` attribute]: https://serde.rs/field-attrs.html#with
+/// [`serialize_with` attribute]: https://serde.rs/field-attrs.html#serialize_with
 ///
 /// # Advice
 ///
@@ -216,9 +309,119 @@ assert_eq!(serde_json::to_string(&got).unwrap(), expected);
 /// for this. Namely, deserialization automatically supports parsing all
 /// configuration options for serialization unconditionally.
 pub mod duration {
-    /// Serialize a `Span` in the [`friendly`](crate::fmt::friendly) duration
-    /// format.
+    /// (De)serialize a `SignedDuration` in the
+    /// [ISO 8601](crate::fmt::temporal) duration format.
+    ///
+    /// This serializes the same way as the `Serialize` implementation on
+    /// `SignedDuration`, but is still useful for making the format of a field
+    /// explicit.
+    pub mod iso8601 {
+        /// (De)serialize a required `SignedDuration` in the ISO 8601 duration
+        /// format.
+        pub mod required {
+            /// Serialize a required `SignedDuration` in the ISO 8601 duration
+            /// format.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                duration: &crate::SignedDuration,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                se.collect_str(duration)
+            }
+
+            /// Deserialize a required `SignedDuration` in either the ISO 8601
+            /// or the "friendly" duration format.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::SignedDuration, D::Error> {
+                serde::Deserialize::deserialize(de)
+            }
+        }
+
+        /// (De)serialize an optional `SignedDuration` in the ISO 8601 duration
+        /// format.
+        pub mod optional {
+            /// Serialize an optional `SignedDuration` in the ISO 8601 duration
+            /// format.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                duration: &Option<crate::SignedDuration>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                match *duration {
+                    None => se.serialize_none(),
+                    Some(ref duration) => {
+                        super::required::serialize(duration, se)
+                    }
+                }
+            }
+
+            /// Deserialize an optional `SignedDuration` in either the ISO 8601
+            /// or the "friendly" duration format.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::SignedDuration>, D::Error> {
+                serde::Deserialize::deserialize(de)
+            }
+        }
+    }
+
+    /// (De)serialize a `SignedDuration` in the
+    /// [`friendly`](crate::fmt::friendly) duration format.
     pub mod friendly {
+        /// (De)serialize a required `SignedDuration` in the "friendly"
+        /// duration format.
+        pub mod required {
+            /// Serialize a required `SignedDuration` in the "friendly"
+            /// duration format.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                duration: &crate::SignedDuration,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                se.collect_str(&format_args!("{:#}", duration))
+            }
+
+            /// Deserialize a required `SignedDuration` in either the ISO 8601
+            /// or the "friendly" duration format.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::SignedDuration, D::Error> {
+                serde::Deserialize::deserialize(de)
+            }
+        }
+
+        /// (De)serialize an optional `SignedDuration` in the "friendly"
+        /// duration format.
+        pub mod optional {
+            /// Serialize an optional `SignedDuration` in the "friendly"
+            /// duration format.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                duration: &Option<crate::SignedDuration>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                match *duration {
+                    None => se.serialize_none(),
+                    Some(ref duration) => {
+                        super::required::serialize(duration, se)
+                    }
+                }
+            }
+
+            /// Deserialize an optional `SignedDuration` in either the ISO 8601
+            /// or the "friendly" duration format.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::SignedDuration>, D::Error> {
+                serde::Deserialize::deserialize(de)
+            }
+        }
+
         /// Serialize a `SignedDuration` in the
         /// [`friendly`](crate::fmt::friendly) duration format using compact
         /// designators.
@@ -265,17 +468,151 @@ pub mod duration {
             }
         }
     }
+
+    /// (De)serialize a `SignedDuration` as a compact tuple of integers.
+    ///
+    /// This uses a `(i64, i32)` tuple of whole seconds and fractional
+    /// nanoseconds, where both values have the same sign as the duration.
+    /// This is meant for binary formats like [bincode] or [postcard], where
+    /// a string is a comparatively expensive representation. (This is
+    /// unrelated to [`friendly::compact`], which uses a string.)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::SignedDuration;
+    ///
+    /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    /// struct Data {
+    ///     #[serde(with = "jiff::fmt::serde::duration::compact::required")]
+    ///     duration: SignedDuration,
+    /// }
+    ///
+    /// let json = r#"{"duration":[-90,-500]}"#;
+    /// let got: Data = serde_json::from_str(&json)?;
+    /// assert_eq!(got.duration, SignedDuration::new(-90, -500));
+    /// assert_eq!(serde_json::to_string(&got)?, json);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [bincode]: https://docs.rs/bincode
+    /// [postcard]: https://docs.rs/postcard
+    pub mod compact {
+        use serde::de;
+
+        type Parts = (i64, i32);
+
+        fn to_parts(dur: &crate::SignedDuration) -> Parts {
+            (dur.as_secs(), dur.subsec_nanos())
+        }
+
+        fn from_parts<E: de::Error>(
+            (secs, nanos): Parts,
+        ) -> Result<crate::SignedDuration, E> {
+            if nanos.unsigned_abs() >= 1_000_000_000 {
+                return Err(de::Error::custom(format_args!(
+                    "fractional nanoseconds {nanos} for signed duration \
+                     must be less than one second in magnitude",
+                )));
+            }
+            Ok(crate::SignedDuration::new(secs, nanos))
+        }
+
+        /// (De)serialize a required `SignedDuration` as a compact tuple of
+        /// integers.
+        pub mod required {
+            /// Serialize a required `SignedDuration` as a compact tuple of
+            /// integers.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                duration: &crate::SignedDuration,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&super::to_parts(duration), se)
+            }
+
+            /// Deserialize a required `SignedDuration` from a compact tuple
+            /// of integers.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::SignedDuration, D::Error> {
+                super::from_parts(serde::Deserialize::deserialize(de)?)
+            }
+        }
+
+        /// (De)serialize an optional `SignedDuration` as a compact tuple of
+        /// integers.
+        pub mod optional {
+            /// Serialize an optional `SignedDuration` as a compact tuple of
+            /// integers.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                duration: &Option<crate::SignedDuration>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                let parts = duration.as_ref().map(super::to_parts);
+                serde::Serialize::serialize(&parts, se)
+            }
+
+            /// Deserialize an optional `SignedDuration` from a compact tuple
+            /// of integers.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::SignedDuration>, D::Error> {
+                let parts: Option<super::Parts> =
+                    serde::Deserialize::deserialize(de)?;
+                parts.map(super::from_parts).transpose()
+            }
+        }
+    }
 }
 
-/// Convenience routines for serializing [`Span`](crate::Span) values.
+/// Convenience routines for (de)serializing [`Span`](crate::Span) values.
 ///
 /// These convenience routines exist because the `Serialize` implementation for
 /// `Span` always uses the ISO 8601 duration format. These routines provide a
 /// way to use the "[friendly](crate::fmt::friendly)" format.
 ///
-/// Only serialization routines are provided because a `Span`'s `Deserialize`
-/// implementation automatically handles both the ISO 8601 duration format and
-/// the "friendly" format.
+/// The `required` and `optional` modules in [`iso8601`](self::span::iso8601)
+/// and [`friendly`](self::span::friendly) are meant to be used with Serde's
+/// [`with` attribute], which makes it possible to choose the format on a
+/// per-field basis. They all deserialize both the ISO 8601 duration format and
+/// the "friendly" format, just like a `Span`'s `Deserialize` implementation.
+/// The other routines in this module only serialize, and are meant to be used
+/// with Serde's [`serialize_with` attribute].
+///
+/// # Example
+///
+/// ```
+/// use jiff::{Span, ToSpan};
+///
+/// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+/// struct Record {
+///     #[serde(with = "jiff::fmt::serde::span::iso8601::required")]
+///     timeout: Span,
+///     #[serde(with = "jiff::fmt::serde::span::friendly::optional")]
+///     backoff: Option<Span>,
+/// }
+///
+/// let json = r#"{"timeout":"1h 30m","backoff":"PT5M"}"#;
+/// let got: Record = serde_json::from_str(&json)?;
+/// assert_eq!(got.timeout, 1.hour().minutes(30).fieldwise());
+/// assert_eq!(
+///     got.backoff.map(Span::fieldwise),
+///     Some(5.minutes().fieldwise()),
+/// );
+///
+/// let expected = r#"{"timeout":"PT1H30M","backoff":"5m"}"#;
+/// assert_eq!(serde_json::to_string(&got)?, expected);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`with` attribute]: https://serde.rs/field-attrs.html#with
+/// [`serialize_with` attribute]: https://serde.rs/field-attrs.html#serialize_with
 ///
 /// # Advice
 ///
@@ -361,9 +698,107 @@ pub mod duration {
 /// for this. Namely, deserialization automatically supports parsing all
 /// configuration options for serialization unconditionally.
 pub mod span {
-    /// Serialize a `Span` in the [`friendly`](crate::fmt::friendly) duration
-    /// format.
+    /// (De)serialize a `Span` in the [ISO 8601](crate::fmt::temporal)
+    /// duration format.
+    ///
+    /// This serializes the same way as the `Serialize` implementation on
+    /// `Span`, but is still useful for making the format of a field
+    /// explicit.
+    pub mod iso8601 {
+        /// (De)serialize a required `Span` in the ISO 8601 duration format.
+        pub mod required {
+            /// Serialize a required `Span` in the ISO 8601 duration format.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                span: &crate::Span,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                se.collect_str(span)
+            }
+
+            /// Deserialize a required `Span` in either the ISO 8601 or the
+            /// "friendly" duration format.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::Span, D::Error> {
+                serde::Deserialize::deserialize(de)
+            }
+        }
+
+        /// (De)serialize an optional `Span` in the ISO 8601 duration format.
+        pub mod optional {
+            /// Serialize an optional `Span` in the ISO 8601 duration format.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                span: &Option<crate::Span>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                match *span {
+                    None => se.serialize_none(),
+                    Some(ref span) => super::required::serialize(span, se),
+                }
+            }
+
+            /// Deserialize an optional `Span` in either the ISO 8601 or the
+            /// "friendly" duration format.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::Span>, D::Error> {
+                serde::Deserialize::deserialize(de)
+            }
+        }
+    }
+
+    /// (De)serialize a `Span` in the [`friendly`](crate::fmt::friendly)
+    /// duration format.
     pub mod friendly {
+        /// (De)serialize a required `Span` in the "friendly" duration format.
+        pub mod required {
+            /// Serialize a required `Span` in the "friendly" duration format.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                span: &crate::Span,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                se.collect_str(&format_args!("{:#}", span))
+            }
+
+            /// Deserialize a required `Span` in either the ISO 8601 or the
+            /// "friendly" duration format.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::Span, D::Error> {
+                serde::Deserialize::deserialize(de)
+            }
+        }
+
+        /// (De)serialize an optional `Span` in the "friendly" duration format.
+        pub mod optional {
+            /// Serialize an optional `Span` in the "friendly" duration format.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                span: &Option<crate::Span>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                match *span {
+                    None => se.serialize_none(),
+                    Some(ref span) => super::required::serialize(span, se),
+                }
+            }
+
+            /// Deserialize an optional `Span` in either the ISO 8601 or the
+            /// "friendly" duration format.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::Span>, D::Error> {
+                serde::Deserialize::deserialize(de)
+            }
+        }
+
         /// Serialize a `Span` in the [`friendly`](crate::fmt::friendly)
         /// duration format using compact designators.
         pub mod compact {
@@ -409,14 +844,141 @@ pub mod span {
             }
         }
     }
+
+    /// (De)serialize a `Span` as a compact tuple of integers.
+    ///
+    /// This uses a tuple of the value of each unit of the span, from years
+    /// down to nanoseconds, where every value has the same sign as the span.
+    /// This is meant for binary formats like [bincode] or [postcard], where
+    /// a string is a comparatively expensive representation. (This is
+    /// unrelated to [`friendly::compact`], which uses a string.)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{Span, ToSpan};
+    ///
+    /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    /// struct Data {
+    ///     #[serde(with = "jiff::fmt::serde::span::compact::required")]
+    ///     span: Span,
+    /// }
+    ///
+    /// let json = r#"{"span":[1,0,0,2,0,0,0,0,0,3]}"#;
+    /// let got: Data = serde_json::from_str(&json)?;
+    /// assert_eq!(got.span.fieldwise(), 1.year().days(2).nanoseconds(3));
+    /// assert_eq!(serde_json::to_string(&got)?, json);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [bincode]: https://docs.rs/bincode
+    /// [postcard]: https://docs.rs/postcard
+    pub mod compact {
+        use serde::de;
+
+        type Parts = (i16, i32, i32, i32, i32, i64, i64, i64, i64, i64);
+
+        fn to_parts(span: &crate::Span) -> Parts {
+            (
+                span.get_years(),
+                span.get_months(),
+                span.get_weeks(),
+                span.get_days(),
+                span.get_hours(),
+                span.get_minutes(),
+                span.get_seconds(),
+                span.get_milliseconds(),
+                span.get_microseconds(),
+                span.get_nanoseconds(),
+            )
+        }
+
+        fn from_parts<E: de::Error>(
+            (
+                years,
+                months,
+                weeks,
+                days,
+                hours,
+                minutes,
+                seconds,
+                millis,
+                micros,
+                nanos,
+            ): Parts,
+        ) -> Result<crate::Span, E> {
+            crate::Span::try_from_units([
+                i64::from(years),
+                i64::from(months),
+                i64::from(weeks),
+                i64::from(days),
+                i64::from(hours),
+                minutes,
+                seconds,
+                millis,
+                micros,
+                nanos,
+            ])
+            .map_err(de::Error::custom)
+        }
+
+        /// (De)serialize a required `Span` as a compact tuple of integers.
+        pub mod required {
+            /// Serialize a required `Span` as a compact tuple of integers.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                span: &crate::Span,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&super::to_parts(span), se)
+            }
+
+            /// Deserialize a required `Span` from a compact tuple of
+            /// integers.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::Span, D::Error> {
+                super::from_parts(serde::Deserialize::deserialize(de)?)
+            }
+        }
+
+        /// (De)serialize an optional `Span` as a compact tuple of integers.
+        pub mod optional {
+            /// Serialize an optional `Span` as a compact tuple of integers.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                span: &Option<crate::Span>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                let parts = span.as_ref().map(super::to_parts);
+                serde::Serialize::serialize(&parts, se)
+            }
+
+            /// Deserialize an optional `Span` from a compact tuple of
+            /// integers.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::Span>, D::Error> {
+                let parts: Option<super::Parts> =
+                    serde::Deserialize::deserialize(de)?;
+                parts.map(super::from_parts).transpose()
+            }
+        }
+    }
 }
 
 /// Convenience routines for (de)serializing [`Timestamp`](crate::Timestamp) as
-/// raw integer values.
+/// raw numeric values.
 ///
 /// At present, the helpers are limited to serializing and deserializing
 /// [`Timestamp`](crate::Timestamp) values as an integer number of seconds,
-/// milliseconds, microseconds or nanoseconds.
+/// milliseconds, microseconds or nanoseconds, or as a floating point number
+/// of seconds or milliseconds (via the `f64` sub-modules). The
+/// [`flexible`](self::timestamp::flexible) helpers additionally accept
+/// RFC 3339 strings when deserializing.
 ///
 /// # Advice
 ///
@@ -430,6 +992,9 @@ pub mod span {
 pub mod timestamp {
     use serde::de;
 
+    #[cfg(not(feature = "std"))]
+    use crate::util::libm::Float;
+
     /// A generic visitor for `Option<Timestamp>`.
     struct OptionalVisitor<V>(V);
 
@@ -463,36 +1028,156 @@ pub mod timestamp {
         }
     }
 
-    /// (De)serialize an integer number of seconds from the Unix epoch.
-    pub mod second {
-        use serde::de;
-
-        struct Visitor;
-
-        impl<'de> de::Visitor<'de> for Visitor {
-            type Value = crate::Timestamp;
-
-            fn expecting(
-                &self,
-                f: &mut core::fmt::Formatter,
-            ) -> core::fmt::Result {
-                f.write_str("an integer number of seconds from the Unix epoch")
+    /// A visitor for a timestamp given as a floating point number of seconds
+    /// or milliseconds from the Unix epoch.
+    struct FloatVisitor(crate::Unit);
+
+    impl FloatVisitor {
+        /// Converts a floating point number of units from the Unix epoch
+        /// into a timestamp, rounding to the nearest nanosecond.
+        fn float<E: de::Error>(&self, v: f64) -> Result<crate::Timestamp, E> {
+            if self.0 == crate::Unit::Second {
+                return crate::SignedDuration::try_from_secs_f64(v)
+                    .and_then(crate::Timestamp::from_duration)
+                    .map_err(de::Error::custom);
             }
-
-            #[inline]
-            fn visit_i8<E: de::Error>(
-                self,
-                v: i8,
-            ) -> Result<crate::Timestamp, E> {
-                self.visit_i64(i64::from(v))
+            if !v.is_finite() || v.abs() >= i64::MAX as f64 {
+                return Err(de::Error::custom(format_args!(
+                    "got floating point milliseconds {v}, \
+                     which cannot be converted to a Jiff `Timestamp`",
+                )));
             }
+            let millis = v.trunc() as i64;
+            let nanos = (v.fract() * 1_000_000.0).round() as i64;
+            crate::Timestamp::from_millisecond(millis)
+                .and_then(|ts| {
+                    ts.checked_add(crate::SignedDuration::from_nanos(nanos))
+                })
+                .map_err(de::Error::custom)
+        }
 
-            #[inline]
-            fn visit_u8<E: de::Error>(
-                self,
-                v: u8,
-            ) -> Result<crate::Timestamp, E> {
-                self.visit_i64(i64::from(v))
+        /// Converts an integer number of units from the Unix epoch into a
+        /// timestamp.
+        fn integer<E: de::Error>(
+            &self,
+            v: i64,
+        ) -> Result<crate::Timestamp, E> {
+            if self.0 == crate::Unit::Second {
+                crate::Timestamp::from_second(v).map_err(de::Error::custom)
+            } else {
+                crate::Timestamp::from_millisecond(v)
+                    .map_err(de::Error::custom)
+            }
+        }
+    }
+
+    impl<'de> de::Visitor<'de> for FloatVisitor {
+        type Value = crate::Timestamp;
+
+        fn expecting(
+            &self,
+            f: &mut core::fmt::Formatter,
+        ) -> core::fmt::Result {
+            write!(
+                f,
+                "a floating point number of {unit} from the Unix epoch",
+                unit = self.0.plural(),
+            )
+        }
+
+        #[inline]
+        fn visit_i64<E: de::Error>(
+            self,
+            v: i64,
+        ) -> Result<crate::Timestamp, E> {
+            self.integer(v)
+        }
+
+        #[inline]
+        fn visit_u64<E: de::Error>(
+            self,
+            v: u64,
+        ) -> Result<crate::Timestamp, E> {
+            let v = i64::try_from(v).map_err(|_| {
+                de::Error::custom(format_args!(
+                    "got unsigned integer {v} {unit}, \
+                     which is too big to fit in a Jiff `Timestamp`",
+                    unit = self.0.plural(),
+                ))
+            })?;
+            self.integer(v)
+        }
+
+        #[inline]
+        fn visit_f64<E: de::Error>(
+            self,
+            v: f64,
+        ) -> Result<crate::Timestamp, E> {
+            self.float(v)
+        }
+    }
+
+    /// A visitor for an optional timestamp given as a floating point number
+    /// of seconds or milliseconds from the Unix epoch.
+    struct OptionalFloatVisitor(crate::Unit);
+
+    impl<'de> de::Visitor<'de> for OptionalFloatVisitor {
+        type Value = Option<crate::Timestamp>;
+
+        fn expecting(
+            &self,
+            f: &mut core::fmt::Formatter,
+        ) -> core::fmt::Result {
+            FloatVisitor(self.0).expecting(f)?;
+            f.write_str(" or `None`")
+        }
+
+        #[inline]
+        fn visit_some<D: de::Deserializer<'de>>(
+            self,
+            de: D,
+        ) -> Result<Option<crate::Timestamp>, D::Error> {
+            de.deserialize_f64(FloatVisitor(self.0)).map(Some)
+        }
+
+        #[inline]
+        fn visit_none<E: de::Error>(
+            self,
+        ) -> Result<Option<crate::Timestamp>, E> {
+            Ok(None)
+        }
+    }
+
+    /// (De)serialize an integer number of seconds from the Unix epoch.
+    pub mod second {
+        use serde::de;
+
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = crate::Timestamp;
+
+            fn expecting(
+                &self,
+                f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+                f.write_str("an integer number of seconds from the Unix epoch")
+            }
+
+            #[inline]
+            fn visit_i8<E: de::Error>(
+                self,
+                v: i8,
+            ) -> Result<crate::Timestamp, E> {
+                self.visit_i64(i64::from(v))
+            }
+
+            #[inline]
+            fn visit_u8<E: de::Error>(
+                self,
+                v: u8,
+            ) -> Result<crate::Timestamp, E> {
+                self.visit_i64(i64::from(v))
             }
 
             #[inline]
@@ -628,6 +1313,449 @@ pub mod timestamp {
                 ))
             }
         }
+
+        /// (De)serialize a floating point number of seconds from the Unix
+        /// epoch.
+        ///
+        /// This is a common convention for representing timestamps in JSON.
+        /// For example, it's what Python's `time.time()` returns.
+        ///
+        /// Since a `f64` only has about 15 significant decimal digits, the
+        /// fractional part of a timestamp near the present is only preserved
+        /// to about microsecond precision. When deserializing, the timestamp
+        /// is rounded to the nearest nanosecond. Integers are accepted too.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use jiff::{Timestamp, Unit};
+        ///
+        /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        /// struct Record {
+        ///     #[serde(
+        ///         with = "jiff::fmt::serde::timestamp::second::f64::required"
+        ///     )]
+        ///     timestamp: Timestamp,
+        /// }
+        ///
+        /// let json = r#"{"timestamp":1517644800.123456}"#;
+        /// let got: Record = serde_json::from_str(&json)?;
+        /// // The nanosecond digits aren't meaningful, since they are beyond
+        /// // the precision of a `f64` for a timestamp this close to now.
+        /// assert_eq!(
+        ///     got.timestamp.round(Unit::Microsecond)?,
+        ///     Timestamp::from_microsecond(1517644800_123456)?,
+        /// );
+        /// assert_eq!(serde_json::to_string(&got)?, json);
+        ///
+        /// # Ok::<(), Box<dyn std::error::Error>>(())
+        /// ```
+        pub mod f64 {
+            /// (De)serialize a required floating point number of seconds
+            /// from the Unix epoch.
+            pub mod required {
+                /// Serialize a required floating point number of seconds
+                /// since the Unix epoch.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &crate::Timestamp,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    se.serialize_f64(timestamp.as_duration().as_secs_f64())
+                }
+
+                /// Deserialize a required floating point number of seconds
+                /// since the Unix epoch.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Timestamp, D::Error> {
+                    de.deserialize_f64(super::super::super::FloatVisitor(
+                        crate::Unit::Second,
+                    ))
+                }
+            }
+
+            /// (De)serialize an optional floating point number of seconds
+            /// from the Unix epoch.
+            pub mod optional {
+                /// Serialize an optional floating point number of seconds
+                /// since the Unix epoch.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &Option<crate::Timestamp>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    match *timestamp {
+                        None => se.serialize_none(),
+                        Some(ts) => {
+                            se.serialize_f64(ts.as_duration().as_secs_f64())
+                        }
+                    }
+                }
+
+                /// Deserialize an optional floating point number of seconds
+                /// since the Unix epoch.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Timestamp>, D::Error>
+                {
+                    de.deserialize_option(
+                        super::super::super::OptionalFloatVisitor(
+                            crate::Unit::Second,
+                        ),
+                    )
+                }
+            }
+        }
+        /// (De)serialize an exact decimal number of seconds from the Unix
+        /// epoch.
+        ///
+        /// Unlike [`f64`](mod@f64), this never rounds. A timestamp is
+        /// serialized as a number with up to 9 fractional digits (e.g.,
+        /// `1517644800.123456789`), and deserializing such a number recovers
+        /// the original timestamp exactly. This is useful in contexts like
+        /// financial systems where floating point rounding is not allowed.
+        ///
+        /// This relies on `serde_json`'s `arbitrary_precision` feature, which
+        /// must be enabled for these helpers to work as intended with
+        /// `serde_json`. Without it, serializing writes a map instead of a
+        /// number, and deserializing a number with a fractional part fails
+        /// (rather than silently losing precision). Other formats aren't
+        /// supported in general, although integers and strings containing a
+        /// decimal number (e.g., `"1517644800.123456789"`) are accepted by
+        /// any self-describing format when deserializing.
+        ///
+        /// Numbers in exponential notation (e.g., `1.5e9`) are not accepted.
+        /// Trailing zeros after the ninth fractional digit are allowed, but
+        /// any other digits beyond nanosecond precision result in an error.
+        ///
+        /// # Example
+        ///
+        /// This example requires `serde_json`'s `arbitrary_precision`
+        /// feature to be enabled.
+        ///
+        /// ```no_run
+        /// use jiff::Timestamp;
+        ///
+        /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        /// struct Record {
+        ///     #[serde(
+        ///         with = "jiff::fmt::serde::timestamp::second::decimal::required"
+        ///     )]
+        ///     timestamp: Timestamp,
+        /// }
+        ///
+        /// let json = r#"{"timestamp":1517644800.123456789}"#;
+        /// let got: Record = serde_json::from_str(&json)?;
+        /// assert_eq!(
+        ///     got.timestamp,
+        ///     Timestamp::from_nanosecond(1517644800_123456789)?,
+        /// );
+        /// assert_eq!(serde_json::to_string(&got)?, json);
+        ///
+        /// # Ok::<(), Box<dyn std::error::Error>>(())
+        /// ```
+        pub mod decimal {
+            use core::fmt::Write;
+
+            use serde::{de, ser::SerializeStruct};
+
+            use crate::{
+                error::{err, Error, ErrorContext},
+                fmt::util::FractionalFormatter,
+                util::{array_str::ArrayStr, escape::Bytes, parse},
+            };
+
+            /// The name `serde_json` uses for the single field struct that
+            /// represents a number when `arbitrary_precision` is enabled.
+            ///
+            /// Its value is the number's string representation.
+            const TOKEN: &str = "$serde_json::private::Number";
+
+            /// A wrapper for serializing a timestamp as a decimal number.
+            struct Decimal(crate::Timestamp);
+
+            impl serde::Serialize for Decimal {
+                #[inline]
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let number = format(self.0);
+                    let mut st = se.serialize_struct(TOKEN, 1)?;
+                    st.serialize_field(TOKEN, number.as_str())?;
+                    st.end()
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for Decimal {
+                #[inline]
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Decimal, D::Error> {
+                    de.deserialize_str(Visitor).map(Decimal)
+                }
+            }
+
+            /// The key of the single field in `serde_json`'s representation
+            /// of an arbitrary precision number.
+            struct Key;
+
+            impl<'de> serde::Deserialize<'de> for Key {
+                #[inline]
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Key, D::Error> {
+                    de.deserialize_identifier(Key)
+                }
+            }
+
+            impl<'de> de::Visitor<'de> for Key {
+                type Value = Key;
+
+                fn expecting(
+                    &self,
+                    f: &mut core::fmt::Formatter,
+                ) -> core::fmt::Result {
+                    f.write_str("an arbitrary precision number")
+                }
+
+                #[inline]
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<Key, E> {
+                    if v == TOKEN {
+                        Ok(Key)
+                    } else {
+                        Err(de::Error::invalid_value(
+                            de::Unexpected::Str(v),
+                            &self,
+                        ))
+                    }
+                }
+            }
+
+            struct Visitor;
+
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = crate::Timestamp;
+
+                fn expecting(
+                    &self,
+                    f: &mut core::fmt::Formatter,
+                ) -> core::fmt::Result {
+                    f.write_str(
+                        "a decimal number of seconds from the Unix epoch",
+                    )
+                }
+
+                #[inline]
+                fn visit_i64<E: de::Error>(
+                    self,
+                    v: i64,
+                ) -> Result<crate::Timestamp, E> {
+                    crate::Timestamp::from_second(v).map_err(de::Error::custom)
+                }
+
+                #[inline]
+                fn visit_u64<E: de::Error>(
+                    self,
+                    v: u64,
+                ) -> Result<crate::Timestamp, E> {
+                    let v = i64::try_from(v).map_err(|_| {
+                        de::Error::custom(format_args!(
+                            "got unsigned integer {v} seconds, \
+                             which is too big to fit in a Jiff `Timestamp`",
+                        ))
+                    })?;
+                    self.visit_i64(v)
+                }
+
+                #[inline]
+                fn visit_f64<E: de::Error>(
+                    self,
+                    v: f64,
+                ) -> Result<crate::Timestamp, E> {
+                    Err(de::Error::custom(format_args!(
+                        "got floating point number {v}, but an exact \
+                         decimal number is required (is `serde_json`'s \
+                         `arbitrary_precision` feature enabled?)",
+                    )))
+                }
+
+                #[inline]
+                fn visit_str<E: de::Error>(
+                    self,
+                    v: &str,
+                ) -> Result<crate::Timestamp, E> {
+                    parse(v.as_bytes()).map_err(de::Error::custom)
+                }
+
+                #[inline]
+                fn visit_map<A: de::MapAccess<'de>>(
+                    self,
+                    mut map: A,
+                ) -> Result<crate::Timestamp, A::Error> {
+                    if map.next_key::<Key>()?.is_none() {
+                        return Err(de::Error::invalid_length(0, &self));
+                    }
+                    let Decimal(ts) = map.next_value()?;
+                    Ok(ts)
+                }
+            }
+
+            /// Formats a timestamp as an exact decimal number of seconds.
+            ///
+            /// The fractional part is omitted when it's zero, and trailing
+            /// zeros are never written.
+            fn format(timestamp: crate::Timestamp) -> ArrayStr<32> {
+                let dur = timestamp.as_duration();
+                let mut buf = ArrayStr::new("").unwrap();
+                if dur.is_negative() {
+                    buf.push_str("-");
+                }
+                // OK because the longest possible number is 23 bytes.
+                write!(buf, "{}", dur.as_secs().unsigned_abs()).unwrap();
+                let nanos = i64::from(dur.subsec_nanos().unsigned_abs());
+                if nanos != 0 {
+                    let fraction = FractionalFormatter::new().format(nanos);
+                    buf.push_str(".");
+                    buf.push_str(fraction.as_str());
+                }
+                buf
+            }
+
+            /// Parses an exact decimal number of seconds into a timestamp.
+            fn parse(bytes: &[u8]) -> Result<crate::Timestamp, Error> {
+                parse_decimal(bytes).with_context(|| {
+                    err!(
+                        "failed to parse '{}' as a decimal number of seconds",
+                        Bytes(bytes),
+                    )
+                })
+            }
+
+            fn parse_decimal(bytes: &[u8]) -> Result<crate::Timestamp, Error> {
+                let (negative, bytes) = match bytes.split_first() {
+                    Some((&b'-', rest)) => (true, rest),
+                    _ => (false, bytes),
+                };
+                let (int, fraction) =
+                    match bytes.iter().position(|&b| b == b'.') {
+                        None => (bytes, None),
+                        Some(i) => (&bytes[..i], Some(&bytes[i + 1..])),
+                    };
+                let secs = parse::i64(int)?;
+                let nanos = match fraction {
+                    None => 0,
+                    Some(fraction) => {
+                        // Trailing zeros don't change the value, so they're
+                        // stripped to permit digits beyond nanosecond
+                        // precision when they're all zero. One digit is
+                        // kept so that an empty fraction is still an error.
+                        let end = fraction
+                            .iter()
+                            .rposition(|&b| b != b'0')
+                            .map_or(1.min(fraction.len()), |i| i + 1);
+                        parse::fraction(&fraction[..end], 9)?
+                    }
+                };
+                // OK because `nanos` is always less than one second.
+                let mut dur = crate::SignedDuration::new(secs, nanos as i32);
+                if negative {
+                    dur = dur.checked_neg().ok_or_else(|| {
+                        err!("decimal number of seconds is too small")
+                    })?;
+                }
+                crate::Timestamp::from_duration(dur)
+            }
+
+            /// (De)serialize a required exact decimal number of seconds from
+            /// the Unix epoch.
+            pub mod required {
+                /// Serialize a required exact decimal number of seconds since
+                /// the Unix epoch.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &crate::Timestamp,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    serde::Serialize::serialize(
+                        &super::Decimal(*timestamp),
+                        se,
+                    )
+                }
+
+                /// Deserialize a required exact decimal number of seconds
+                /// since the Unix epoch.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Timestamp, D::Error> {
+                    de.deserialize_any(super::Visitor)
+                }
+            }
+
+            /// (De)serialize an optional exact decimal number of seconds from
+            /// the Unix epoch.
+            pub mod optional {
+                use serde::de;
+
+                struct OptionalVisitor;
+
+                impl<'de> de::Visitor<'de> for OptionalVisitor {
+                    type Value = Option<crate::Timestamp>;
+
+                    fn expecting(
+                        &self,
+                        f: &mut core::fmt::Formatter,
+                    ) -> core::fmt::Result {
+                        de::Visitor::expecting(&super::Visitor, f)?;
+                        f.write_str(" or `None`")
+                    }
+
+                    #[inline]
+                    fn visit_some<D: de::Deserializer<'de>>(
+                        self,
+                        de: D,
+                    ) -> Result<Option<crate::Timestamp>, D::Error>
+                    {
+                        de.deserialize_any(super::Visitor).map(Some)
+                    }
+
+                    #[inline]
+                    fn visit_none<E: de::Error>(
+                        self,
+                    ) -> Result<Option<crate::Timestamp>, E>
+                    {
+                        Ok(None)
+                    }
+                }
+
+                /// Serialize an optional exact decimal number of seconds
+                /// since the Unix epoch.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &Option<crate::Timestamp>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    match *timestamp {
+                        None => se.serialize_none(),
+                        Some(ts) => se.serialize_some(&super::Decimal(ts)),
+                    }
+                }
+
+                /// Deserialize an optional exact decimal number of seconds
+                /// since the Unix epoch.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Timestamp>, D::Error>
+                {
+                    de.deserialize_option(OptionalVisitor)
+                }
+            }
+        }
     }
 
     /// (De)serialize an integer number of milliseconds from the Unix epoch.
@@ -798,6 +1926,103 @@ pub mod timestamp {
                 ))
             }
         }
+
+        /// (De)serialize a floating point number of milliseconds from the Unix
+        /// epoch.
+        ///
+        /// This is a common convention for representing timestamps in JSON.
+        /// For example, it's what Python's `time.time()` returns, multiplied
+        /// by `1000`.
+        ///
+        /// Since a `f64` only has about 15 significant decimal digits, the
+        /// fractional part of a timestamp near the present is only preserved
+        /// to about microsecond precision. When deserializing, the timestamp
+        /// is rounded to the nearest nanosecond. Integers are accepted too.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use jiff::{Timestamp, Unit};
+        ///
+        /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        /// struct Record {
+        ///     #[serde(
+        ///         with = "jiff::fmt::serde::timestamp::millisecond::f64::required"
+        ///     )]
+        ///     timestamp: Timestamp,
+        /// }
+        ///
+        /// let json = r#"{"timestamp":1517644800123.456}"#;
+        /// let got: Record = serde_json::from_str(&json)?;
+        /// // The nanosecond digits aren't meaningful, since they are beyond
+        /// // the precision of a `f64` for a timestamp this close to now.
+        /// assert_eq!(
+        ///     got.timestamp.round(Unit::Microsecond)?,
+        ///     Timestamp::from_microsecond(1517644800_123456)?,
+        /// );
+        /// assert_eq!(serde_json::to_string(&got)?, json);
+        ///
+        /// # Ok::<(), Box<dyn std::error::Error>>(())
+        /// ```
+        pub mod f64 {
+            /// (De)serialize a required floating point number of milliseconds
+            /// from the Unix epoch.
+            pub mod required {
+                /// Serialize a required floating point number of
+                /// milliseconds since the Unix epoch.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &crate::Timestamp,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    se.serialize_f64(timestamp.as_duration().as_millis_f64())
+                }
+
+                /// Deserialize a required floating point number of
+                /// milliseconds since the Unix epoch.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Timestamp, D::Error> {
+                    de.deserialize_f64(super::super::super::FloatVisitor(
+                        crate::Unit::Millisecond,
+                    ))
+                }
+            }
+
+            /// (De)serialize an optional floating point number of milliseconds
+            /// from the Unix epoch.
+            pub mod optional {
+                /// Serialize an optional floating point number of
+                /// milliseconds since the Unix epoch.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &Option<crate::Timestamp>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    match *timestamp {
+                        None => se.serialize_none(),
+                        Some(ts) => {
+                            se.serialize_f64(ts.as_duration().as_millis_f64())
+                        }
+                    }
+                }
+
+                /// Deserialize an optional floating point number of
+                /// milliseconds since the Unix epoch.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Timestamp>, D::Error>
+                {
+                    de.deserialize_option(
+                        super::super::super::OptionalFloatVisitor(
+                            crate::Unit::Millisecond,
+                        ),
+                    )
+                }
+            }
+        }
     }
 
     /// (De)serialize an integer number of microseconds from the Unix epoch.
@@ -1078,305 +2303,3036 @@ pub mod timestamp {
             }
         }
     }
-}
 
-/// Convenience routines for (de)serializing [`TimeZone`](crate::tz::TimeZone)
-/// values.
-///
-/// The `required` and `optional` sub-modules each provide serialization and
-/// deserialization routines. They are meant to be used with Serde's
-/// [`with` attribute].
-///
-/// # Advice
-///
-/// Serializing time zones is useful when you want to accept user configuration
-/// selecting a time zone to use. This might be beneficial when one cannot rely
-/// on a system's time zone.
-///
-/// Note that when deserializing time zones that are IANA time zone
-/// identifiers, Jiff will automatically use the implicit global database to
-/// resolve the identifier to an actual time zone. If you do not want to use
-/// Jiff's global time zone database for this, you'll need to write your own
-/// Serde integration.
-///
-/// [`with` attribute]: https://serde.rs/field-attrs.html#with
-///
-/// # Example
-///
-/// ```
-/// use jiff::tz::TimeZone;
-///
-/// #[derive(Debug, serde::Deserialize, serde::Serialize)]
-/// struct Record {
-///     #[serde(with = "jiff::fmt::serde::tz::required")]
-///     tz: TimeZone,
-/// }
-///
-/// let json = r#"{"tz":"America/Nuuk"}"#;
-/// let got: Record = serde_json::from_str(&json)?;
-/// assert_eq!(got.tz, TimeZone::get("America/Nuuk")?);
-/// assert_eq!(serde_json::to_string(&got)?, json);
-///
-/// # Ok::<(), Box<dyn std::error::Error>>(())
-/// ```
-///
-/// # Example: serializing an unknown `TimeZone` works
-///
-/// For example, when a time zone was created from
-/// [`TimeZone::system`](crate::tz::TimeZone::system) and a system configured
-/// time zone could not be found. One can artifically create this situation
-/// with [`TimeZone::unknown`](crate::tz::TimeZone::unknown):
-///
-/// ```
-/// use jiff::tz::TimeZone;
-///
-/// #[derive(Debug, serde::Deserialize, serde::Serialize)]
-/// struct Record {
-///     #[serde(with = "jiff::fmt::serde::tz::required")]
-///     tz: TimeZone,
-/// }
-///
-/// let record = Record { tz: TimeZone::unknown() };
-/// assert_eq!(
-///     serde_json::to_string(&record)?,
-///     r#"{"tz":"Etc/Unknown"}"#,
-/// );
-///
-/// # Ok::<(), Box<dyn std::error::Error>>(())
-/// ```
+    /// (De)serialize a timestamp from either an integer or an RFC 3339
+    /// string.
+    ///
+    /// Many real world APIs represent timestamps as an integer number of
+    /// seconds (or milliseconds) from the Unix epoch in some places and as
+    /// an [RFC 3339] timestamp string in others, sometimes even within the
+    /// same field. The routines in this module accept both. When
+    /// deserializing an integer, the sub-module used determines its unit,
+    /// except for [`auto`](self::flexible::auto), which guesses the unit based on the
+    /// integer's magnitude. Strings that look like an integer (e.g.,
+    /// `"1517644800"`) are treated the same as integers. All other strings
+    /// are parsed as timestamps via the [Temporal](crate::fmt::temporal)
+    /// format.
+    ///
+    /// Serialization always emits an RFC 3339 timestamp string, since that
+    /// is unambiguous. To serialize an integer instead, use Serde's
+    /// `serialize_with` and `deserialize_with` attributes to combine the
+    /// routines from, e.g., [`second`] and this module.
+    ///
+    /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    /// struct Record {
+    ///     #[serde(
+    ///         with = "jiff::fmt::serde::timestamp::flexible::auto::required"
+    ///     )]
+    ///     timestamp: Timestamp,
+    /// }
+    ///
+    /// let expected = Timestamp::from_second(1517644800)?;
+    /// for json in [
+    ///     r#"{"timestamp":1517644800}"#,
+    ///     r#"{"timestamp":1517644800000}"#,
+    ///     r#"{"timestamp":"1517644800"}"#,
+    ///     r#"{"timestamp":"2018-02-03T08:00:00Z"}"#,
+    ///     r#"{"timestamp":"2018-02-03T03:00:00-05:00"}"#,
+    /// ] {
+    ///     let got: Record = serde_json::from_str(json)?;
+    ///     assert_eq!(got.timestamp, expected);
+    /// }
+    ///
+    /// let got = Record { timestamp: expected };
+    /// assert_eq!(
+    ///     serde_json::to_string(&got)?,
+    ///     r#"{"timestamp":"2018-02-03T08:00:00Z"}"#,
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub mod flexible {
+        use serde::de;
+
+        /// The unit of an integer number of units from the Unix epoch.
+        ///
+        /// `None` means the unit is determined from the integer's magnitude.
+        type Unit = Option<crate::Unit>;
+
+        /// A visitor for a timestamp given as an integer or a string.
+        struct Visitor(Unit);
+
+        impl Visitor {
+            /// Converts an integer number of units from the Unix epoch into a
+            /// timestamp.
+            fn integer<E: de::Error>(
+                &self,
+                v: i128,
+            ) -> Result<crate::Timestamp, E> {
+                let unit = self.0.unwrap_or_else(|| guess_unit(v));
+                let nanos = match unit {
+                    crate::Unit::Second => v.checked_mul(1_000_000_000),
+                    crate::Unit::Millisecond => v.checked_mul(1_000_000),
+                    crate::Unit::Microsecond => v.checked_mul(1_000),
+                    _ => Some(v),
+                };
+                let nanos = nanos.ok_or_else(|| {
+                    de::Error::custom(format_args!(
+                        "got integer {v} {unit}, \
+                         which is too big to fit in a Jiff `Timestamp`",
+                        unit = unit.plural(),
+                    ))
+                })?;
+                crate::Timestamp::from_nanosecond(nanos)
+                    .map_err(de::Error::custom)
+            }
+        }
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = crate::Timestamp;
+
+            fn expecting(
+                &self,
+                f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+                match self.0 {
+                    None => f.write_str(
+                        "an integer number of seconds, milliseconds, \
+                         microseconds or nanoseconds",
+                    ),
+                    Some(unit) => write!(
+                        f,
+                        "an integer number of {unit}",
+                        unit = unit.plural(),
+                    ),
+                }?;
+                f.write_str(
+                    " from the Unix epoch or an RFC 3339 timestamp string",
+                )
+            }
+
+            #[inline]
+            fn visit_i64<E: de::Error>(
+                self,
+                v: i64,
+            ) -> Result<crate::Timestamp, E> {
+                self.integer(i128::from(v))
+            }
+
+            #[inline]
+            fn visit_u64<E: de::Error>(
+                self,
+                v: u64,
+            ) -> Result<crate::Timestamp, E> {
+                self.integer(i128::from(v))
+            }
+
+            #[inline]
+            fn visit_i128<E: de::Error>(
+                self,
+                v: i128,
+            ) -> Result<crate::Timestamp, E> {
+                self.integer(v)
+            }
+
+            #[inline]
+            fn visit_u128<E: de::Error>(
+                self,
+                v: u128,
+            ) -> Result<crate::Timestamp, E> {
+                let v = i128::try_from(v).map_err(|_| {
+                    de::Error::custom(format_args!(
+                        "got unsigned integer {v}, \
+                         which is too big to fit in a Jiff `Timestamp`",
+                    ))
+                })?;
+                self.integer(v)
+            }
+
+            #[inline]
+            fn visit_bytes<E: de::Error>(
+                self,
+                value: &[u8],
+            ) -> Result<crate::Timestamp, E> {
+                if let Some(v) = parse_integer(value) {
+                    return self.integer(v);
+                }
+                crate::fmt::temporal::DEFAULT_DATETIME_PARSER
+                    .parse_timestamp(value)
+                    .map_err(de::Error::custom)
+            }
+
+            #[inline]
+            fn visit_str<E: de::Error>(
+                self,
+                value: &str,
+            ) -> Result<crate::Timestamp, E> {
+                self.visit_bytes(value.as_bytes())
+            }
+        }
+
+        /// A visitor for an optional timestamp given as an integer or a
+        /// string.
+        struct OptionalVisitor(Unit);
+
+        impl<'de> de::Visitor<'de> for OptionalVisitor {
+            type Value = Option<crate::Timestamp>;
+
+            fn expecting(
+                &self,
+                f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+                Visitor(self.0).expecting(f)?;
+                f.write_str(" or `None`")
+            }
+
+            #[inline]
+            fn visit_some<D: de::Deserializer<'de>>(
+                self,
+                de: D,
+            ) -> Result<Option<crate::Timestamp>, D::Error> {
+                de.deserialize_any(Visitor(self.0)).map(Some)
+            }
+
+            #[inline]
+            fn visit_none<E: de::Error>(
+                self,
+            ) -> Result<Option<crate::Timestamp>, E> {
+                Ok(None)
+            }
+
+            #[inline]
+            fn visit_unit<E: de::Error>(
+                self,
+            ) -> Result<Option<crate::Timestamp>, E> {
+                Ok(None)
+            }
+        }
+
+        /// Guesses the unit of an integer number of units from the Unix
+        /// epoch.
+        ///
+        /// An integer is interpreted as seconds when it's within about 3,000
+        /// years of the Unix epoch as seconds. Otherwise, the next smaller
+        /// unit that puts it within that range is used.
+        fn guess_unit(v: i128) -> crate::Unit {
+            // 100 billion seconds is a little over 3,000 years.
+            const LIMIT: u128 = 100_000_000_000;
+
+            let v = v.unsigned_abs();
+            if v < LIMIT {
+                crate::Unit::Second
+            } else if v < LIMIT * 1_000 {
+                crate::Unit::Millisecond
+            } else if v < LIMIT * 1_000_000 {
+                crate::Unit::Microsecond
+            } else {
+                crate::Unit::Nanosecond
+            }
+        }
+
+        /// Parses a string consisting entirely of an optional sign followed
+        /// by ASCII digits as an integer.
+        ///
+        /// This returns `None` for anything else, including integers that
+        /// are too big to fit in an `i128`.
+        fn parse_integer(bytes: &[u8]) -> Option<i128> {
+            let (negative, digits) = match bytes.split_first()? {
+                (b'-', rest) => (true, rest),
+                (b'+', rest) => (false, rest),
+                _ => (false, bytes),
+            };
+            if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+                return None;
+            }
+            let mut n: i128 = 0;
+            for &byte in digits {
+                n = n.checked_mul(10)?.checked_add(i128::from(byte - b'0'))?;
+            }
+            Some(if negative { -n } else { n })
+        }
+
+        /// Serialize a timestamp as an RFC 3339 string.
+        fn serialize<S: serde::Serializer>(
+            timestamp: &crate::Timestamp,
+            se: S,
+        ) -> Result<S::Ok, S::Error> {
+            se.collect_str(timestamp)
+        }
+
+        /// Serialize an optional timestamp as an RFC 3339 string.
+        fn serialize_optional<S: serde::Serializer>(
+            timestamp: &Option<crate::Timestamp>,
+            se: S,
+        ) -> Result<S::Ok, S::Error> {
+            match *timestamp {
+                None => se.serialize_none(),
+                Some(ref ts) => se.serialize_some(&SerializeTimestamp(ts)),
+            }
+        }
+
+        /// A wrapper for serializing a timestamp via [`serialize`].
+        struct SerializeTimestamp<'a>(&'a crate::Timestamp);
+
+        impl<'a> serde::Serialize for SerializeTimestamp<'a> {
+            #[inline]
+            fn serialize<S: serde::Serializer>(
+                &self,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                serialize(self.0, se)
+            }
+        }
+
+        /// Integers are interpreted in a unit guessed from their magnitude.
+        ///
+        /// Integers within about 3,000 years of the Unix epoch when
+        /// interpreted as seconds are treated as seconds. Otherwise, they are
+        /// treated as milliseconds, microseconds or nanoseconds, whichever is
+        /// the largest unit that puts them in that range. This means that
+        /// timestamps within about 3 years of the Unix epoch are ambiguous
+        /// when given in milliseconds, and are treated as seconds.
+        pub mod auto {
+            /// (De)serialize a required timestamp, guessing the unit of
+            /// integers.
+            pub mod required {
+                /// Serialize a required timestamp as an RFC 3339 string.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &crate::Timestamp,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize(timestamp, se)
+                }
+
+                /// Deserialize a required timestamp from an integer or an
+                /// RFC 3339 string.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Timestamp, D::Error> {
+                    de.deserialize_any(super::super::Visitor(None))
+                }
+            }
+
+            /// (De)serialize an optional timestamp, guessing the unit of
+            /// integers.
+            pub mod optional {
+                /// Serialize an optional timestamp as an RFC 3339 string.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &Option<crate::Timestamp>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize_optional(timestamp, se)
+                }
+
+                /// Deserialize an optional timestamp from an integer or an
+                /// RFC 3339 string.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Timestamp>, D::Error>
+                {
+                    de.deserialize_option(super::super::OptionalVisitor(None))
+                }
+            }
+        }
+
+        /// Integers are interpreted as a number of seconds from the Unix
+        /// epoch.
+        pub mod second {
+            /// (De)serialize a required timestamp, with integers in seconds.
+            pub mod required {
+                /// Serialize a required timestamp as an RFC 3339 string.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &crate::Timestamp,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize(timestamp, se)
+                }
+
+                /// Deserialize a required timestamp from an integer or an
+                /// RFC 3339 string.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Timestamp, D::Error> {
+                    de.deserialize_any(super::super::Visitor(Some(
+                        crate::Unit::Second,
+                    )))
+                }
+            }
+
+            /// (De)serialize an optional timestamp, with integers in seconds.
+            pub mod optional {
+                /// Serialize an optional timestamp as an RFC 3339 string.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &Option<crate::Timestamp>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize_optional(timestamp, se)
+                }
+
+                /// Deserialize an optional timestamp from an integer or an
+                /// RFC 3339 string.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Timestamp>, D::Error>
+                {
+                    de.deserialize_option(super::super::OptionalVisitor(Some(
+                        crate::Unit::Second,
+                    )))
+                }
+            }
+        }
+
+        /// Integers are interpreted as a number of milliseconds from the Unix
+        /// epoch.
+        pub mod millisecond {
+            /// (De)serialize a required timestamp, with integers in
+            /// milliseconds.
+            pub mod required {
+                /// Serialize a required timestamp as an RFC 3339 string.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &crate::Timestamp,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize(timestamp, se)
+                }
+
+                /// Deserialize a required timestamp from an integer or an
+                /// RFC 3339 string.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Timestamp, D::Error> {
+                    de.deserialize_any(super::super::Visitor(Some(
+                        crate::Unit::Millisecond,
+                    )))
+                }
+            }
+
+            /// (De)serialize an optional timestamp, with integers in
+            /// milliseconds.
+            pub mod optional {
+                /// Serialize an optional timestamp as an RFC 3339 string.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &Option<crate::Timestamp>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize_optional(timestamp, se)
+                }
+
+                /// Deserialize an optional timestamp from an integer or an
+                /// RFC 3339 string.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Timestamp>, D::Error>
+                {
+                    de.deserialize_option(super::super::OptionalVisitor(Some(
+                        crate::Unit::Millisecond,
+                    )))
+                }
+            }
+        }
+
+        /// Integers are interpreted as a number of microseconds from the Unix
+        /// epoch.
+        pub mod microsecond {
+            /// (De)serialize a required timestamp, with integers in
+            /// microseconds.
+            pub mod required {
+                /// Serialize a required timestamp as an RFC 3339 string.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &crate::Timestamp,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize(timestamp, se)
+                }
+
+                /// Deserialize a required timestamp from an integer or an
+                /// RFC 3339 string.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Timestamp, D::Error> {
+                    de.deserialize_any(super::super::Visitor(Some(
+                        crate::Unit::Microsecond,
+                    )))
+                }
+            }
+
+            /// (De)serialize an optional timestamp, with integers in
+            /// microseconds.
+            pub mod optional {
+                /// Serialize an optional timestamp as an RFC 3339 string.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &Option<crate::Timestamp>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize_optional(timestamp, se)
+                }
+
+                /// Deserialize an optional timestamp from an integer or an
+                /// RFC 3339 string.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Timestamp>, D::Error>
+                {
+                    de.deserialize_option(super::super::OptionalVisitor(Some(
+                        crate::Unit::Microsecond,
+                    )))
+                }
+            }
+        }
+
+        /// Integers are interpreted as a number of nanoseconds from the Unix
+        /// epoch.
+        pub mod nanosecond {
+            /// (De)serialize a required timestamp, with integers in
+            /// nanoseconds.
+            pub mod required {
+                /// Serialize a required timestamp as an RFC 3339 string.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &crate::Timestamp,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize(timestamp, se)
+                }
+
+                /// Deserialize a required timestamp from an integer or an
+                /// RFC 3339 string.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Timestamp, D::Error> {
+                    de.deserialize_any(super::super::Visitor(Some(
+                        crate::Unit::Nanosecond,
+                    )))
+                }
+            }
+
+            /// (De)serialize an optional timestamp, with integers in
+            /// nanoseconds.
+            pub mod optional {
+                /// Serialize an optional timestamp as an RFC 3339 string.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &Option<crate::Timestamp>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize_optional(timestamp, se)
+                }
+
+                /// Deserialize an optional timestamp from an integer or an
+                /// RFC 3339 string.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Timestamp>, D::Error>
+                {
+                    de.deserialize_option(super::super::OptionalVisitor(Some(
+                        crate::Unit::Nanosecond,
+                    )))
+                }
+            }
+        }
+    }
+
+    /// (De)serialize a `Timestamp` as a compact tuple of integers.
+    ///
+    /// This uses a `(i64, i32)` tuple of whole seconds since the Unix epoch
+    /// and fractional nanoseconds, where both values have the same sign as
+    /// the timestamp. This is meant for binary formats like [bincode] or
+    /// [postcard], where a string is a comparatively expensive
+    /// representation. Unlike [`nanosecond`], it doesn't require 128-bit
+    /// integer support from the format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    /// struct Data {
+    ///     #[serde(with = "jiff::fmt::serde::timestamp::compact::required")]
+    ///     timestamp: Timestamp,
+    /// }
+    ///
+    /// let json = r#"{"timestamp":[1700000000,123]}"#;
+    /// let got: Data = serde_json::from_str(&json)?;
+    /// assert_eq!(got.timestamp, Timestamp::new(1_700_000_000, 123)?);
+    /// assert_eq!(serde_json::to_string(&got)?, json);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [bincode]: https://docs.rs/bincode
+    /// [postcard]: https://docs.rs/postcard
+    pub mod compact {
+        use serde::de;
+
+        pub(crate) type Parts = (i64, i32);
+
+        pub(crate) fn to_parts(ts: &crate::Timestamp) -> Parts {
+            (ts.as_second(), ts.subsec_nanosecond())
+        }
+
+        pub(crate) fn from_parts<E: de::Error>(
+            (second, nanosecond): Parts,
+        ) -> Result<crate::Timestamp, E> {
+            crate::Timestamp::new(second, nanosecond)
+                .map_err(de::Error::custom)
+        }
+
+        /// (De)serialize a required `Timestamp` as a compact tuple of
+        /// integers.
+        pub mod required {
+            /// Serialize a required `Timestamp` as a compact tuple of
+            /// integers.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                timestamp: &crate::Timestamp,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&super::to_parts(timestamp), se)
+            }
+
+            /// Deserialize a required `Timestamp` from a compact tuple of
+            /// integers.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::Timestamp, D::Error> {
+                super::from_parts(serde::Deserialize::deserialize(de)?)
+            }
+        }
+
+        /// (De)serialize an optional `Timestamp` as a compact tuple of
+        /// integers.
+        pub mod optional {
+            /// Serialize an optional `Timestamp` as a compact tuple of
+            /// integers.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                timestamp: &Option<crate::Timestamp>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                let parts = timestamp.as_ref().map(super::to_parts);
+                serde::Serialize::serialize(&parts, se)
+            }
+
+            /// Deserialize an optional `Timestamp` from a compact tuple of
+            /// integers.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::Timestamp>, D::Error> {
+                let parts: Option<super::Parts> =
+                    serde::Deserialize::deserialize(de)?;
+                parts.map(super::from_parts).transpose()
+            }
+        }
+    }
+}
+
+/// Convenience routines for (de)serializing civil dates and times as
+/// integers.
 ///
-/// And it deserializes as well:
+/// These encodings are compact, which is useful for columnar formats and
+/// message schemas that store dates and times as plain integers:
+///
+/// * [`date::unix_epoch_day`](self::civil::date::unix_epoch_day) represents
+///   a [`civil::Date`](crate::civil::Date) as the number of days since
+///   `1970-01-01`. Dates before `1970-01-01` are negative.
+/// * [`time::nanosecond`](self::civil::time::nanosecond) represents a
+///   [`civil::Time`](crate::civil::Time) as the number of nanoseconds since
+///   midnight.
+/// * [`datetime::compact`](self::civil::datetime::compact) represents a
+///   [`civil::DateTime`](crate::civil::DateTime) as a tuple of the above two
+///   integers.
+///
+/// # Example
 ///
 /// ```
-/// use jiff::tz::TimeZone;
+/// use jiff::civil::{date, time, Date, Time};
 ///
 /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
 /// struct Record {
-///     #[serde(with = "jiff::fmt::serde::tz::required")]
-///     tz: TimeZone,
+///     #[serde(with = "jiff::fmt::serde::civil::date::unix_epoch_day::required")]
+///     date: Date,
+///     #[serde(with = "jiff::fmt::serde::civil::time::nanosecond::required")]
+///     time: Time,
 /// }
 ///
-/// let json = r#"{"tz":"Etc/Unknown"}"#;
+/// let json = r#"{"date":19814,"time":45296000000123}"#;
 /// let got: Record = serde_json::from_str(&json)?;
-/// assert!(got.tz.is_unknown());
+/// assert_eq!(got.date, date(2024, 4, 1));
+/// assert_eq!(got.time, time(12, 34, 56, 123));
+/// assert_eq!(serde_json::to_string(&got)?, json);
 ///
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-///
-/// An unknown time zone is "allowed" to percolate through Jiff because it's
-/// usually not desirable to return an error and completely fail if a system
-/// time zone could not be detected. On the other hand, by using a special
-/// `Etc/Unknown` identifier for this case, it still surfaces the fact that
-/// something has gone wrong.
-pub mod tz {
-    use serde::de;
+pub mod civil {
+    /// Convenience routines for (de)serializing a
+    /// [`civil::Date`](crate::civil::Date) as an integer.
+    pub mod date {
+        /// (De)serialize an integer number of days since the Unix epoch.
+        pub mod unix_epoch_day {
+            use serde::de;
+
+            struct Visitor;
+
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = crate::civil::Date;
+
+                fn expecting(
+                    &self,
+                    f: &mut core::fmt::Formatter,
+                ) -> core::fmt::Result {
+                    f.write_str(
+                        "an integer number of days from the Unix epoch",
+                    )
+                }
+
+                #[inline]
+                fn visit_i64<E: de::Error>(
+                    self,
+                    v: i64,
+                ) -> Result<crate::civil::Date, E> {
+                    let span = crate::Span::new()
+                        .try_days(v)
+                        .map_err(de::Error::custom)?;
+                    crate::civil::Date::constant(1970, 1, 1)
+                        .checked_add(span)
+                        .map_err(de::Error::custom)
+                }
+
+                #[inline]
+                fn visit_u64<E: de::Error>(
+                    self,
+                    v: u64,
+                ) -> Result<crate::civil::Date, E> {
+                    let v = i64::try_from(v).map_err(|_| {
+                        de::Error::custom(format_args!(
+                            "got unsigned integer {v} days, \
+                             which is too big to fit in a Jiff `Date`",
+                        ))
+                    })?;
+                    self.visit_i64(v)
+                }
+            }
+
+            struct OptionalVisitor;
+
+            impl<'de> de::Visitor<'de> for OptionalVisitor {
+                type Value = Option<crate::civil::Date>;
+
+                fn expecting(
+                    &self,
+                    f: &mut core::fmt::Formatter,
+                ) -> core::fmt::Result {
+                    f.write_str(
+                        "an integer number of days from the Unix epoch \
+                         or `None`",
+                    )
+                }
+
+                #[inline]
+                fn visit_some<D: de::Deserializer<'de>>(
+                    self,
+                    de: D,
+                ) -> Result<Option<crate::civil::Date>, D::Error>
+                {
+                    de.deserialize_i32(Visitor).map(Some)
+                }
+
+                #[inline]
+                fn visit_none<E: de::Error>(
+                    self,
+                ) -> Result<Option<crate::civil::Date>, E> {
+                    Ok(None)
+                }
+            }
+
+            /// (De)serialize a required integer number of days since the
+            /// Unix epoch.
+            pub mod required {
+                /// Serialize a required integer number of days since the Unix
+                /// epoch.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    date: &crate::civil::Date,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    se.serialize_i32(date.to_unix_epoch_day().get())
+                }
+
+                /// Deserialize a required integer number of days since the
+                /// Unix epoch.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::civil::Date, D::Error> {
+                    de.deserialize_i32(super::Visitor)
+                }
+            }
+
+            /// (De)serialize an optional integer number of days since the
+            /// Unix epoch.
+            pub mod optional {
+                /// Serialize an optional integer number of days since the
+                /// Unix epoch.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    date: &Option<crate::civil::Date>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    match *date {
+                        None => se.serialize_none(),
+                        Some(ref date) => super::required::serialize(date, se),
+                    }
+                }
+
+                /// Deserialize an optional integer number of days since the
+                /// Unix epoch.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::civil::Date>, D::Error>
+                {
+                    de.deserialize_option(super::OptionalVisitor)
+                }
+            }
+        }
+    }
+
+    /// Convenience routines for (de)serializing a
+    /// [`civil::Time`](crate::civil::Time) as an integer.
+    pub mod time {
+        /// (De)serialize an integer number of nanoseconds since midnight.
+        pub mod nanosecond {
+            use serde::de;
+
+            struct Visitor;
+
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = crate::civil::Time;
+
+                fn expecting(
+                    &self,
+                    f: &mut core::fmt::Formatter,
+                ) -> core::fmt::Result {
+                    f.write_str(
+                        "an integer number of nanoseconds from midnight",
+                    )
+                }
+
+                #[inline]
+                fn visit_i64<E: de::Error>(
+                    self,
+                    v: i64,
+                ) -> Result<crate::civil::Time, E> {
+                    let nanos = crate::util::t::CivilDayNanosecond::try_new(
+                        "nanoseconds since midnight",
+                        v,
+                    )
+                    .map_err(de::Error::custom)?;
+                    Ok(crate::civil::Time::from_nanosecond(nanos))
+                }
+
+                #[inline]
+                fn visit_u64<E: de::Error>(
+                    self,
+                    v: u64,
+                ) -> Result<crate::civil::Time, E> {
+                    let v = i64::try_from(v).map_err(|_| {
+                        de::Error::custom(format_args!(
+                            "got unsigned integer {v} nanoseconds, \
+                             which is too big to fit in a Jiff `Time`",
+                        ))
+                    })?;
+                    self.visit_i64(v)
+                }
+            }
+
+            struct OptionalVisitor;
+
+            impl<'de> de::Visitor<'de> for OptionalVisitor {
+                type Value = Option<crate::civil::Time>;
+
+                fn expecting(
+                    &self,
+                    f: &mut core::fmt::Formatter,
+                ) -> core::fmt::Result {
+                    f.write_str(
+                        "an integer number of nanoseconds from midnight \
+                         or `None`",
+                    )
+                }
+
+                #[inline]
+                fn visit_some<D: de::Deserializer<'de>>(
+                    self,
+                    de: D,
+                ) -> Result<Option<crate::civil::Time>, D::Error>
+                {
+                    de.deserialize_i64(Visitor).map(Some)
+                }
+
+                #[inline]
+                fn visit_none<E: de::Error>(
+                    self,
+                ) -> Result<Option<crate::civil::Time>, E> {
+                    Ok(None)
+                }
+            }
+
+            /// (De)serialize a required integer number of nanoseconds since
+            /// midnight.
+            pub mod required {
+                /// Serialize a required integer number of nanoseconds since
+                /// midnight.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    time: &crate::civil::Time,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    se.serialize_i64(time.to_nanosecond().get())
+                }
+
+                /// Deserialize a required integer number of nanoseconds
+                /// since midnight.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::civil::Time, D::Error> {
+                    de.deserialize_i64(super::Visitor)
+                }
+            }
+
+            /// (De)serialize an optional integer number of nanoseconds since
+            /// midnight.
+            pub mod optional {
+                /// Serialize an optional integer number of nanoseconds since
+                /// midnight.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    time: &Option<crate::civil::Time>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    match *time {
+                        None => se.serialize_none(),
+                        Some(ref time) => super::required::serialize(time, se),
+                    }
+                }
+
+                /// Deserialize an optional integer number of nanoseconds
+                /// since midnight.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::civil::Time>, D::Error>
+                {
+                    de.deserialize_option(super::OptionalVisitor)
+                }
+            }
+        }
+    }
+
+    /// Convenience routines for (de)serializing a
+    /// [`civil::DateTime`](crate::civil::DateTime) as integers.
+    pub mod datetime {
+        /// (De)serialize a tuple of an integer number of days since the Unix
+        /// epoch and an integer number of nanoseconds since midnight.
+        ///
+        /// Each value uses the same representation as
+        /// [`date::unix_epoch_day`](super::date::unix_epoch_day) and
+        /// [`time::nanosecond`](super::time::nanosecond), respectively.
+        pub mod compact {
+            use serde::de;
+
+            type Parts = (i32, i64);
+
+            fn to_parts(dt: &crate::civil::DateTime) -> Parts {
+                (
+                    dt.date().to_unix_epoch_day().get(),
+                    dt.time().to_nanosecond().get(),
+                )
+            }
+
+            fn from_parts<E: de::Error>(
+                (day, nanosecond): Parts,
+            ) -> Result<crate::civil::DateTime, E> {
+                let day = crate::util::t::UnixEpochDay::try_new(
+                    "days since the Unix epoch",
+                    day,
+                )
+                .map_err(de::Error::custom)?;
+                let nanosecond = crate::util::t::CivilDayNanosecond::try_new(
+                    "nanoseconds since midnight",
+                    nanosecond,
+                )
+                .map_err(de::Error::custom)?;
+                Ok(crate::civil::DateTime::from_parts(
+                    crate::civil::Date::from_unix_epoch_day(day),
+                    crate::civil::Time::from_nanosecond(nanosecond),
+                ))
+            }
+
+            /// (De)serialize a required datetime as a tuple of integers.
+            pub mod required {
+                /// Serialize a required datetime as a tuple of integers.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    datetime: &crate::civil::DateTime,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    serde::Serialize::serialize(&super::to_parts(datetime), se)
+                }
+
+                /// Deserialize a required datetime from a tuple of integers.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::civil::DateTime, D::Error> {
+                    super::from_parts(serde::Deserialize::deserialize(de)?)
+                }
+            }
+
+            /// (De)serialize an optional datetime as a tuple of integers.
+            pub mod optional {
+                /// Serialize an optional datetime as a tuple of integers.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    datetime: &Option<crate::civil::DateTime>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let parts = datetime.as_ref().map(super::to_parts);
+                    serde::Serialize::serialize(&parts, se)
+                }
+
+                /// Deserialize an optional datetime from a tuple of integers.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::civil::DateTime>, D::Error>
+                {
+                    let parts: Option<super::Parts> =
+                        serde::Deserialize::deserialize(de)?;
+                    parts.map(super::from_parts).transpose()
+                }
+            }
+        }
+    }
+}
+
+/// Convenience routines for deserializing datetimes without an offset into
+/// [`Timestamp`](crate::Timestamp) or [`Zoned`](crate::Zoned) values.
+///
+/// Many "legacy" APIs transmit instants in time as a civil datetime without
+/// any offset, like `2024-04-01T12:00:00`, with the time zone documented
+/// out-of-band (most commonly, UTC). Deserializing such a value into a
+/// `Timestamp` or a `Zoned` fails by default, since converting it into an
+/// instant requires an assumption about its time zone. The routines in this
+/// module make that assumption explicit.
+///
+/// When the string being deserialized _does_ include an offset (including
+/// `Z`), then that offset is respected and the assumed time zone is only used
+/// to set the time zone of a `Zoned` value. Strings without a time, like
+/// `2024-04-01`, are interpreted as the first instant of that day.
+///
+/// The [`assume_utc`](self::datetime::assume_utc) modules are meant to be used
+/// with Serde's [`with` attribute]. They serialize the civil datetime in UTC
+/// without an offset, so that values round-trip. To assume a time zone other
+/// than UTC, write a `deserialize_with` routine that calls
+/// [`timestamp_in`](self::datetime::timestamp_in) or
+/// [`zoned_in`](self::datetime::zoned_in).
+///
+/// # Example: assuming UTC
+///
+/// ```
+/// use jiff::Timestamp;
+///
+/// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+/// struct Record {
+///     #[serde(with = "jiff::fmt::serde::datetime::assume_utc::timestamp::required")]
+///     timestamp: Timestamp,
+/// }
+///
+/// let json = r#"{"timestamp":"2024-04-01T12:00:00"}"#;
+/// let got: Record = serde_json::from_str(&json)?;
+/// assert_eq!(got.timestamp, "2024-04-01T12:00:00Z".parse()?);
+/// assert_eq!(serde_json::to_string(&got)?, json);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Example: assuming a named time zone
+///
+/// ```
+/// use jiff::{tz, Zoned};
+///
+/// fn in_new_york<'de, D: serde::Deserializer<'de>>(
+///     de: D,
+/// ) -> Result<Zoned, D::Error> {
+///     let tz = tz::db()
+///         .get("America/New_York")
+///         .map_err(serde::de::Error::custom)?;
+///     jiff::fmt::serde::datetime::zoned_in(de, tz)
+/// }
+///
+/// #[derive(Debug, serde::Deserialize)]
+/// struct Record {
+///     #[serde(deserialize_with = "in_new_york")]
+///     at: Zoned,
+/// }
+///
+/// let json = r#"{"at":"2024-04-01T12:00:00"}"#;
+/// let got: Record = serde_json::from_str(&json)?;
+/// assert_eq!(
+///     got.at.to_string(),
+///     "2024-04-01T12:00:00-04:00[America/New_York]",
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`with` attribute]: https://serde.rs/field-attrs.html#with
+pub mod datetime {
+    use serde::de;
+
+    /// A visitor for a datetime string, interpreted as an instant using the
+    /// given time zone when the string has no offset.
+    struct Visitor<'a>(&'a crate::tz::TimeZone);
+
+    impl<'a, 'de> de::Visitor<'de> for Visitor<'a> {
+        type Value = crate::Timestamp;
+
+        fn expecting(
+            &self,
+            f: &mut core::fmt::Formatter,
+        ) -> core::fmt::Result {
+            f.write_str("a datetime string with an optional offset")
+        }
+
+        #[inline]
+        fn visit_bytes<E: de::Error>(
+            self,
+            value: &[u8],
+        ) -> Result<crate::Timestamp, E> {
+            let pieces = crate::fmt::temporal::Pieces::parse(value)
+                .map_err(de::Error::custom)?;
+            let time = pieces.time().unwrap_or(crate::civil::Time::midnight());
+            let dt = pieces.date().to_datetime(time);
+            let result = match pieces.to_numeric_offset() {
+                Some(offset) => offset.to_timestamp(dt),
+                None => self.0.to_timestamp(dt),
+            };
+            result.map_err(de::Error::custom)
+        }
+
+        #[inline]
+        fn visit_str<E: de::Error>(
+            self,
+            value: &str,
+        ) -> Result<crate::Timestamp, E> {
+            self.visit_bytes(value.as_bytes())
+        }
+    }
+
+    /// A visitor for an optional datetime string.
+    struct OptionalVisitor<'a>(&'a crate::tz::TimeZone);
+
+    impl<'a, 'de> de::Visitor<'de> for OptionalVisitor<'a> {
+        type Value = Option<crate::Timestamp>;
+
+        fn expecting(
+            &self,
+            f: &mut core::fmt::Formatter,
+        ) -> core::fmt::Result {
+            f.write_str("a datetime string with an optional offset or `None`")
+        }
+
+        #[inline]
+        fn visit_some<D: de::Deserializer<'de>>(
+            self,
+            de: D,
+        ) -> Result<Option<crate::Timestamp>, D::Error> {
+            de.deserialize_str(Visitor(self.0)).map(Some)
+        }
+
+        #[inline]
+        fn visit_none<E: de::Error>(
+            self,
+        ) -> Result<Option<crate::Timestamp>, E> {
+            Ok(None)
+        }
+    }
+
+    /// Deserialize a timestamp from a datetime string, assuming the given
+    /// time zone when the string has no offset.
+    ///
+    /// This is meant to be called from a routine used with Serde's
+    /// `deserialize_with` attribute. When the civil datetime is ambiguous in
+    /// the time zone given (i.e., it falls in a gap or a fold), then it is
+    /// resolved using the
+    /// [`compatible`](crate::tz::Disambiguation::Compatible) strategy.
+    #[inline]
+    pub fn timestamp_in<'de, D: serde::Deserializer<'de>>(
+        de: D,
+        tz: &crate::tz::TimeZone,
+    ) -> Result<crate::Timestamp, D::Error> {
+        de.deserialize_str(Visitor(tz))
+    }
+
+    /// Deserialize a zoned datetime from a datetime string, assuming the
+    /// given time zone when the string has no offset.
+    ///
+    /// The zoned datetime returned is always in the time zone given, even
+    /// when the string has an offset. See [`timestamp_in`] for how ambiguous
+    /// civil datetimes are handled.
+    #[inline]
+    pub fn zoned_in<'de, D: serde::Deserializer<'de>>(
+        de: D,
+        tz: crate::tz::TimeZone,
+    ) -> Result<crate::Zoned, D::Error> {
+        timestamp_in(de, &tz).map(|ts| ts.to_zoned(tz))
+    }
+
+    /// Serialize a timestamp as a civil datetime in UTC without an offset.
+    fn serialize_utc<S: serde::Serializer>(
+        timestamp: &crate::Timestamp,
+        se: S,
+    ) -> Result<S::Ok, S::Error> {
+        se.collect_str(&crate::tz::Offset::UTC.to_datetime(*timestamp))
+    }
+
+    /// (De)serialize datetimes without an offset by assuming they are in
+    /// UTC.
+    pub mod assume_utc {
+        /// (De)serialize a [`Timestamp`](crate::Timestamp) from a datetime
+        /// string in UTC without an offset.
+        pub mod timestamp {
+            /// (De)serialize a required timestamp as a datetime string in
+            /// UTC without an offset.
+            pub mod required {
+                /// Serialize a required timestamp as a datetime string in UTC
+                /// without an offset.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &crate::Timestamp,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::super::serialize_utc(timestamp, se)
+                }
+
+                /// Deserialize a required timestamp from a datetime string,
+                /// assuming UTC when the string has no offset.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Timestamp, D::Error> {
+                    super::super::super::timestamp_in(
+                        de,
+                        &crate::tz::TimeZone::UTC,
+                    )
+                }
+            }
+
+            /// (De)serialize an optional timestamp as a datetime string in
+            /// UTC without an offset.
+            pub mod optional {
+                /// Serialize an optional timestamp as a datetime string in
+                /// UTC without an offset.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    timestamp: &Option<crate::Timestamp>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    match *timestamp {
+                        None => se.serialize_none(),
+                        Some(ref ts) => super::required::serialize(ts, se),
+                    }
+                }
+
+                /// Deserialize an optional timestamp from a datetime string,
+                /// assuming UTC when the string has no offset.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Timestamp>, D::Error>
+                {
+                    de.deserialize_option(
+                        super::super::super::OptionalVisitor(
+                            &crate::tz::TimeZone::UTC,
+                        ),
+                    )
+                }
+            }
+        }
+
+        /// (De)serialize a [`Zoned`](crate::Zoned) in UTC from a datetime
+        /// string in UTC without an offset.
+        ///
+        /// When serializing, zoned datetimes in a time zone other than UTC
+        /// are converted to UTC first.
+        pub mod zoned {
+            /// (De)serialize a required zoned datetime as a datetime string
+            /// in UTC without an offset.
+            pub mod required {
+                /// Serialize a required zoned datetime as a datetime string
+                /// in UTC without an offset.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    zoned: &crate::Zoned,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::super::serialize_utc(&zoned.timestamp(), se)
+                }
+
+                /// Deserialize a required zoned datetime in UTC from a
+                /// datetime string, assuming UTC when the string has no
+                /// offset.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Zoned, D::Error> {
+                    super::super::super::zoned_in(de, crate::tz::TimeZone::UTC)
+                }
+            }
+
+            /// (De)serialize an optional zoned datetime as a datetime string
+            /// in UTC without an offset.
+            pub mod optional {
+                /// Serialize an optional zoned datetime as a datetime string
+                /// in UTC without an offset.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    zoned: &Option<crate::Zoned>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    match *zoned {
+                        None => se.serialize_none(),
+                        Some(ref zdt) => super::required::serialize(zdt, se),
+                    }
+                }
+
+                /// Deserialize an optional zoned datetime in UTC from a
+                /// datetime string, assuming UTC when the string has no
+                /// offset.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Zoned>, D::Error> {
+                    let tz = crate::tz::TimeZone::UTC;
+                    let ts = de.deserialize_option(
+                        super::super::super::OptionalVisitor(&tz),
+                    )?;
+                    Ok(ts.map(|ts| ts.to_zoned(tz)))
+                }
+            }
+        }
+    }
+}
+
+/// Convenience routines for (de)serializing [`Zoned`](crate::Zoned) values as
+/// [RFC 3339] timestamps without a time zone annotation.
+///
+/// The default `Serialize` implementation for `Zoned` uses [RFC 9557], which
+/// appends a bracketed time zone annotation like `[America/New_York]` to an
+/// RFC 3339 timestamp. Some consumers reject these annotations. The modules
+/// in [`rfc3339`](self::zoned::rfc3339) serialize only the datetime and its
+/// offset, e.g., `2024-03-10T01:30:00-05:00`.
+///
+/// Since the time zone is not serialized, deserialization needs to pick a
+/// time zone for the `Zoned` value. There are two strategies available:
+///
+/// * [`offset`](self::zoned::rfc3339::offset) uses a fixed offset time zone
+///   with the offset in the string being deserialized. This always
+///   round-trips the serialized string, but subsequent arithmetic on the
+///   `Zoned` value won't account for daylight saving time.
+/// * [`system`](self::zoned::rfc3339::system) converts the instant described
+///   by the string to the [system time zone](crate::tz::TimeZone::system).
+///
+/// In both cases, a string with a time zone annotation is still accepted, but
+/// the annotation is ignored. To convert to some other time zone, write a
+/// `deserialize_with` routine that calls
+/// [`datetime::zoned_in`].
+///
+/// # Example
+///
+/// ```
+/// use jiff::Zoned;
+///
+/// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+/// struct Record {
+///     #[serde(with = "jiff::fmt::serde::zoned::rfc3339::offset::required")]
+///     zdt: Zoned,
+/// }
+///
+/// let zdt: Zoned = "2024-03-10T01:30-05[America/New_York]".parse()?;
+/// let json = serde_json::to_string(&Record { zdt })?;
+/// assert_eq!(json, r#"{"zdt":"2024-03-10T01:30:00-05:00"}"#);
+///
+/// let got: Record = serde_json::from_str(&json)?;
+/// assert_eq!(got.zdt.to_string(), "2024-03-10T01:30:00-05:00[-05:00]");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+/// [RFC 9557]: https://datatracker.ietf.org/doc/html/rfc9557
+pub mod zoned {
+    /// (De)serialize a [`Zoned`](crate::Zoned) as an RFC 3339 timestamp
+    /// without a time zone annotation.
+    pub mod rfc3339 {
+        use serde::de;
+
+        /// A visitor for an RFC 3339 timestamp that returns a zoned datetime
+        /// in a fixed offset time zone.
+        struct OffsetVisitor;
+
+        impl<'de> de::Visitor<'de> for OffsetVisitor {
+            type Value = crate::Zoned;
+
+            fn expecting(
+                &self,
+                f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+                f.write_str("an RFC 3339 timestamp string")
+            }
+
+            #[inline]
+            fn visit_bytes<E: de::Error>(
+                self,
+                value: &[u8],
+            ) -> Result<crate::Zoned, E> {
+                let pieces = crate::fmt::temporal::Pieces::parse(value)
+                    .map_err(de::Error::custom)?;
+                let Some(offset) = pieces.to_numeric_offset() else {
+                    return Err(de::Error::custom(
+                        "RFC 3339 timestamp is missing an offset",
+                    ));
+                };
+                let time =
+                    pieces.time().unwrap_or(crate::civil::Time::midnight());
+                let dt = pieces.date().to_datetime(time);
+                let ts = offset.to_timestamp(dt).map_err(de::Error::custom)?;
+                Ok(ts.to_zoned(crate::tz::TimeZone::fixed(offset)))
+            }
+
+            #[inline]
+            fn visit_str<E: de::Error>(
+                self,
+                value: &str,
+            ) -> Result<crate::Zoned, E> {
+                self.visit_bytes(value.as_bytes())
+            }
+        }
+
+        /// A visitor for an optional RFC 3339 timestamp.
+        struct OptionalOffsetVisitor;
+
+        impl<'de> de::Visitor<'de> for OptionalOffsetVisitor {
+            type Value = Option<crate::Zoned>;
+
+            fn expecting(
+                &self,
+                f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+                f.write_str("an RFC 3339 timestamp string or `None`")
+            }
+
+            #[inline]
+            fn visit_some<D: de::Deserializer<'de>>(
+                self,
+                de: D,
+            ) -> Result<Option<crate::Zoned>, D::Error> {
+                de.deserialize_str(OffsetVisitor).map(Some)
+            }
+
+            #[inline]
+            fn visit_none<E: de::Error>(
+                self,
+            ) -> Result<Option<crate::Zoned>, E> {
+                Ok(None)
+            }
+        }
+
+        /// Serialize a zoned datetime as an RFC 3339 timestamp with its
+        /// offset.
+        fn serialize<S: serde::Serializer>(
+            zoned: &crate::Zoned,
+            se: S,
+        ) -> Result<S::Ok, S::Error> {
+            se.collect_str(
+                &zoned.timestamp().display_with_offset(zoned.offset()),
+            )
+        }
+
+        /// Serialize an optional zoned datetime as an RFC 3339 timestamp
+        /// with its offset.
+        fn serialize_optional<S: serde::Serializer>(
+            zoned: &Option<crate::Zoned>,
+            se: S,
+        ) -> Result<S::Ok, S::Error> {
+            match *zoned {
+                None => se.serialize_none(),
+                Some(ref zdt) => serialize(zdt, se),
+            }
+        }
+
+        /// (De)serialize a zoned datetime as an RFC 3339 timestamp,
+        /// deserializing into a fixed offset time zone.
+        ///
+        /// A round trip through this format preserves the datetime and
+        /// offset of a zoned datetime, but not its time zone.
+        pub mod offset {
+            /// (De)serialize a required zoned datetime as an RFC 3339
+            /// timestamp, deserializing into a fixed offset time zone.
+            pub mod required {
+                /// Serialize a required zoned datetime as an RFC 3339
+                /// timestamp with its offset.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    zoned: &crate::Zoned,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize(zoned, se)
+                }
+
+                /// Deserialize a required zoned datetime from an RFC 3339
+                /// timestamp into a fixed offset time zone.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Zoned, D::Error> {
+                    de.deserialize_str(super::super::OffsetVisitor)
+                }
+            }
+
+            /// (De)serialize an optional zoned datetime as an RFC 3339
+            /// timestamp, deserializing into a fixed offset time zone.
+            pub mod optional {
+                /// Serialize an optional zoned datetime as an RFC 3339
+                /// timestamp with its offset.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    zoned: &Option<crate::Zoned>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize_optional(zoned, se)
+                }
+
+                /// Deserialize an optional zoned datetime from an RFC 3339
+                /// timestamp into a fixed offset time zone.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Zoned>, D::Error> {
+                    de.deserialize_option(super::super::OptionalOffsetVisitor)
+                }
+            }
+        }
+
+        /// (De)serialize a zoned datetime as an RFC 3339 timestamp,
+        /// deserializing into the system time zone.
+        ///
+        /// The instant in time described by the string is preserved, but its
+        /// civil datetime and offset may change when the system time zone is
+        /// different from the one used to serialize it.
+        pub mod system {
+            /// (De)serialize a required zoned datetime as an RFC 3339
+            /// timestamp, deserializing into the system time zone.
+            pub mod required {
+                /// Serialize a required zoned datetime as an RFC 3339
+                /// timestamp with its offset.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    zoned: &crate::Zoned,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize(zoned, se)
+                }
+
+                /// Deserialize a required zoned datetime from an RFC 3339
+                /// timestamp into the system time zone.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<crate::Zoned, D::Error> {
+                    let zdt =
+                        de.deserialize_str(super::super::OffsetVisitor)?;
+                    Ok(zdt.timestamp().to_zoned(crate::tz::TimeZone::system()))
+                }
+            }
+
+            /// (De)serialize an optional zoned datetime as an RFC 3339
+            /// timestamp, deserializing into the system time zone.
+            pub mod optional {
+                /// Serialize an optional zoned datetime as an RFC 3339
+                /// timestamp with its offset.
+                #[inline]
+                pub fn serialize<S: serde::Serializer>(
+                    zoned: &Option<crate::Zoned>,
+                    se: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::serialize_optional(zoned, se)
+                }
+
+                /// Deserialize an optional zoned datetime from an RFC 3339
+                /// timestamp into the system time zone.
+                #[inline]
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    de: D,
+                ) -> Result<Option<crate::Zoned>, D::Error> {
+                    let zdt = de.deserialize_option(
+                        super::super::OptionalOffsetVisitor,
+                    )?;
+                    let tz = crate::tz::TimeZone::system();
+                    Ok(zdt.map(|zdt| zdt.timestamp().to_zoned(tz)))
+                }
+            }
+        }
+    }
+
+    /// (De)serialize a [`Zoned`](crate::Zoned) as a compact tuple.
+    ///
+    /// This uses a tuple of the timestamp (in the same representation as
+    /// [`timestamp::compact`](super::timestamp::compact)), the offset in
+    /// seconds and the IANA time zone identifier, if one exists. This is
+    /// meant for binary formats like [bincode] or [postcard], where a string
+    /// is a comparatively expensive representation.
+    ///
+    /// Like the RFC 9557 string representation used by the `Serialize`
+    /// implementation on `Zoned`, a time zone without an IANA time zone
+    /// identifier is deserialized as a fixed offset time zone. IANA time zone
+    /// identifiers are resolved using Jiff's global time zone database.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Zoned;
+    ///
+    /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    /// struct Record {
+    ///     #[serde(with = "jiff::fmt::serde::zoned::compact::required")]
+    ///     zdt: Zoned,
+    /// }
+    ///
+    /// let json = r#"{"zdt":[[1700000000,0],-18000,"America/New_York"]}"#;
+    /// let got: Record = serde_json::from_str(&json)?;
+    /// assert_eq!(
+    ///     got.zdt.to_string(),
+    ///     "2023-11-14T17:13:20-05:00[America/New_York]",
+    /// );
+    /// assert_eq!(serde_json::to_string(&got)?, json);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [bincode]: https://docs.rs/bincode
+    /// [postcard]: https://docs.rs/postcard
+    pub mod compact {
+        use serde::de;
+
+        use crate::fmt::serde::timestamp::compact as timestamp;
+
+        type Parts<Name> = (timestamp::Parts, i32, Option<Name>);
+
+        /// A time zone deserialized from an IANA time zone identifier by
+        /// looking it up in the global time zone database.
+        struct IanaTimeZone(crate::tz::TimeZone);
+
+        impl<'de> serde::Deserialize<'de> for IanaTimeZone {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<IanaTimeZone, D::Error> {
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = IanaTimeZone;
+
+                    fn expecting(
+                        &self,
+                        f: &mut core::fmt::Formatter,
+                    ) -> core::fmt::Result {
+                        f.write_str("an IANA time zone identifier")
+                    }
+
+                    #[inline]
+                    fn visit_str<E: de::Error>(
+                        self,
+                        value: &str,
+                    ) -> Result<IanaTimeZone, E> {
+                        crate::tz::db()
+                            .get(value)
+                            .map(IanaTimeZone)
+                            .map_err(de::Error::custom)
+                    }
+                }
+
+                de.deserialize_str(Visitor)
+            }
+        }
+
+        fn to_parts(zdt: &crate::Zoned) -> Parts<&str> {
+            let tz = zdt.time_zone();
+            let name = if tz.is_unknown() {
+                Some("Etc/Unknown")
+            } else {
+                tz.iana_name()
+            };
+            (
+                timestamp::to_parts(&zdt.timestamp()),
+                zdt.offset().seconds(),
+                name,
+            )
+        }
+
+        fn from_parts<E: de::Error>(
+            (timestamp, offset, name): Parts<IanaTimeZone>,
+        ) -> Result<crate::Zoned, E> {
+            let timestamp = timestamp::from_parts::<E>(timestamp)?;
+            let tz = match name {
+                Some(IanaTimeZone(tz)) => tz,
+                None => {
+                    let offset = crate::tz::Offset::from_seconds(offset)
+                        .map_err(de::Error::custom)?;
+                    crate::tz::TimeZone::fixed(offset)
+                }
+            };
+            Ok(timestamp.to_zoned(tz))
+        }
+
+        /// (De)serialize a required `Zoned` as a compact tuple.
+        pub mod required {
+            /// Serialize a required `Zoned` as a compact tuple.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                zdt: &crate::Zoned,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&super::to_parts(zdt), se)
+            }
+
+            /// Deserialize a required `Zoned` from a compact tuple.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::Zoned, D::Error> {
+                super::from_parts(serde::Deserialize::deserialize(de)?)
+            }
+        }
+
+        /// (De)serialize an optional `Zoned` as a compact tuple.
+        pub mod optional {
+            /// Serialize an optional `Zoned` as a compact tuple.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                zdt: &Option<crate::Zoned>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                let parts = zdt.as_ref().map(super::to_parts);
+                serde::Serialize::serialize(&parts, se)
+            }
+
+            /// Deserialize an optional `Zoned` from a compact tuple.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::Zoned>, D::Error> {
+                let parts: Option<super::Parts<super::IanaTimeZone>> =
+                    serde::Deserialize::deserialize(de)?;
+                parts.map(super::from_parts).transpose()
+            }
+        }
+    }
+}
+
+/// Convenience routines for (de)serializing [`TimeZone`](crate::tz::TimeZone)
+/// values.
+///
+/// The `required` and `optional` sub-modules each provide serialization and
+/// deserialization routines. They are meant to be used with Serde's
+/// [`with` attribute].
+///
+/// The routines in `required` and `optional` serialize a time zone
+/// using the most succinct string representation available: an IANA time
+/// zone identifier, a fixed offset or a POSIX time zone string. When you need
+/// more control over how a time zone is represented, there are also a few
+/// other strategies available, each with their own `required` and `optional`
+/// sub-modules:
+///
+/// * [`iana`](tz::iana) only permits IANA time zone identifiers, which are
+///   resolved using Jiff's global time zone database when deserializing.
+/// * [`posix`](tz::posix) always uses a POSIX time zone string. This is
+///   lossy for IANA time zones, since only their current rule is kept.
+/// * [`tzif`](tz::tzif) embeds the full TZif data of a time zone, which
+///   permits shipping time zone rules between processes without relying on
+///   each having the same copy of the time zone database.
+///
+/// # Advice
+///
+/// Serializing time zones is useful when you want to accept user configuration
+/// selecting a time zone to use. This might be beneficial when one cannot rely
+/// on a system's time zone.
+///
+/// Note that when deserializing time zones that are IANA time zone
+/// identifiers, Jiff will automatically use the implicit global database to
+/// resolve the identifier to an actual time zone. If you do not want to use
+/// Jiff's global time zone database for this, you'll need to write your own
+/// Serde integration.
+///
+/// [`with` attribute]: https://serde.rs/field-attrs.html#with
+///
+/// # Example
+///
+/// ```
+/// use jiff::tz::TimeZone;
+///
+/// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+/// struct Record {
+///     #[serde(with = "jiff::fmt::serde::tz::required")]
+///     tz: TimeZone,
+/// }
+///
+/// let json = r#"{"tz":"America/Nuuk"}"#;
+/// let got: Record = serde_json::from_str(&json)?;
+/// assert_eq!(got.tz, TimeZone::get("America/Nuuk")?);
+/// assert_eq!(serde_json::to_string(&got)?, json);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Example: serializing an unknown `TimeZone` works
+///
+/// For example, when a time zone was created from
+/// [`TimeZone::system`](crate::tz::TimeZone::system) and a system configured
+/// time zone could not be found. One can artifically create this situation
+/// with [`TimeZone::unknown`](crate::tz::TimeZone::unknown):
+///
+/// ```
+/// use jiff::tz::TimeZone;
+///
+/// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+/// struct Record {
+///     #[serde(with = "jiff::fmt::serde::tz::required")]
+///     tz: TimeZone,
+/// }
+///
+/// let record = Record { tz: TimeZone::unknown() };
+/// assert_eq!(
+///     serde_json::to_string(&record)?,
+///     r#"{"tz":"Etc/Unknown"}"#,
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// And it deserializes as well:
+///
+/// ```
+/// use jiff::tz::TimeZone;
+///
+/// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+/// struct Record {
+///     #[serde(with = "jiff::fmt::serde::tz::required")]
+///     tz: TimeZone,
+/// }
+///
+/// let json = r#"{"tz":"Etc/Unknown"}"#;
+/// let got: Record = serde_json::from_str(&json)?;
+/// assert!(got.tz.is_unknown());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// An unknown time zone is "allowed" to percolate through Jiff because it's
+/// usually not desirable to return an error and completely fail if a system
+/// time zone could not be detected. On the other hand, by using a special
+/// `Etc/Unknown` identifier for this case, it still surfaces the fact that
+/// something has gone wrong.
+pub mod tz {
+    use serde::de;
+
+    use crate::fmt::{temporal, StdFmtWrite};
+
+    struct TemporalTimeZone<'a>(&'a crate::tz::TimeZone);
+
+    impl<'a> core::fmt::Display for TemporalTimeZone<'a> {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            static PRINTER: temporal::DateTimePrinter =
+                temporal::DateTimePrinter::new();
+            PRINTER
+                .print_time_zone(self.0, StdFmtWrite(f))
+                .map_err(|_| core::fmt::Error)
+        }
+    }
+
+    /// A required visitor for `TimeZone`.
+    struct Visitor;
+
+    impl<'de> de::Visitor<'de> for Visitor {
+        type Value = crate::tz::TimeZone;
+
+        fn expecting(
+            &self,
+            f: &mut core::fmt::Formatter,
+        ) -> core::fmt::Result {
+            f.write_str(
+                "a string representing a time zone via an \
+                 IANA time zone identifier, fixed offset from UTC \
+                 or a POSIX time zone string",
+            )
+        }
+
+        #[inline]
+        fn visit_bytes<E: de::Error>(
+            self,
+            value: &[u8],
+        ) -> Result<crate::tz::TimeZone, E> {
+            static PARSER: temporal::DateTimeParser =
+                temporal::DateTimeParser::new();
+            PARSER.parse_time_zone(value).map_err(de::Error::custom)
+        }
+
+        #[inline]
+        fn visit_str<E: de::Error>(
+            self,
+            value: &str,
+        ) -> Result<crate::tz::TimeZone, E> {
+            self.visit_bytes(value.as_bytes())
+        }
+    }
+
+    /// A generic optional visitor for `TimeZone`.
+    struct OptionalVisitor<V>(V);
+
+    impl<'de, V: de::Visitor<'de, Value = crate::tz::TimeZone>>
+        de::Visitor<'de> for OptionalVisitor<V>
+    {
+        type Value = Option<crate::tz::TimeZone>;
+
+        fn expecting(
+            &self,
+            f: &mut core::fmt::Formatter,
+        ) -> core::fmt::Result {
+            self.0.expecting(f)
+        }
+
+        #[inline]
+        fn visit_some<D: de::Deserializer<'de>>(
+            self,
+            de: D,
+        ) -> Result<Option<crate::tz::TimeZone>, D::Error> {
+            de.deserialize_str(self.0).map(Some)
+        }
+
+        #[inline]
+        fn visit_none<E: de::Error>(
+            self,
+        ) -> Result<Option<crate::tz::TimeZone>, E> {
+            Ok(None)
+        }
+    }
+
+    /// (De)serialize a required [`TimeZone`](crate::tz::TimeZone).
+    pub mod required {
+        /// Serialize a required [`TimeZone`](crate::tz::TimeZone).
+        ///
+        /// This will result in an IANA time zone identifier, fixed offset or a
+        /// POSIX time zone string.
+        ///
+        /// This can return an error in some cases when the `TimeZone` has no
+        /// succinct string representation. For example, when the `TimeZone` is
+        /// derived from a system `/etc/localtime` for which no IANA time zone
+        /// identifier could be found.
+        #[inline]
+        pub fn serialize<S: serde::Serializer>(
+            tz: &crate::tz::TimeZone,
+            se: S,
+        ) -> Result<S::Ok, S::Error> {
+            if !tz.has_succinct_serialization() {
+                return Err(<S::Error as serde::ser::Error>::custom(
+                    "time zones without IANA identifiers that aren't either \
+                     fixed offsets or a POSIX time zone can't be serialized \
+                     (this typically occurs when this is a system time zone \
+                      derived from `/etc/localtime` on Unix systems that \
+                      isn't symlinked to an entry in `/usr/share/zoneinfo)",
+                ));
+            }
+            se.collect_str(&super::TemporalTimeZone(tz))
+        }
+
+        /// Deserialize a required [`TimeZone`](crate::tz::TimeZone).
+        ///
+        /// This will attempt to parse an IANA time zone identifier, a fixed
+        /// offset or a POSIX time zone string.
+        #[inline]
+        pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+            de: D,
+        ) -> Result<crate::tz::TimeZone, D::Error> {
+            de.deserialize_str(super::Visitor)
+        }
+    }
+
+    /// (De)serialize an optional [`TimeZone`](crate::tz::TimeZone).
+    pub mod optional {
+        /// Serialize an optional [`TimeZone`](crate::tz::TimeZone).
+        ///
+        /// This will result in an IANA time zone identifier, fixed offset or a
+        /// POSIX time zone string.
+        ///
+        /// This can return an error in some cases when the `TimeZone` has no
+        /// succinct string representation. For example, when the `TimeZone` is
+        /// derived from a system `/etc/localtime` for which no IANA time zone
+        /// identifier could be found.
+        #[inline]
+        pub fn serialize<S: serde::Serializer>(
+            tz: &Option<crate::tz::TimeZone>,
+            se: S,
+        ) -> Result<S::Ok, S::Error> {
+            match *tz {
+                None => se.serialize_none(),
+                Some(ref tz) => super::required::serialize(tz, se),
+            }
+        }
+
+        /// Deserialize an optional [`TimeZone`](crate::tz::TimeZone).
+        ///
+        /// This will attempt to parse an IANA time zone identifier, a fixed
+        /// offset or a POSIX time zone string.
+        #[inline]
+        pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+            de: D,
+        ) -> Result<Option<crate::tz::TimeZone>, D::Error> {
+            de.deserialize_option(super::OptionalVisitor(super::Visitor))
+        }
+    }
+
+    /// (De)serialize a [`TimeZone`](crate::tz::TimeZone) as an IANA time zone
+    /// identifier only.
+    ///
+    /// Unlike [`tz::required`](self::required) and
+    /// [`tz::optional`](self::optional), this refuses to serialize time zones
+    /// that don't have an IANA time zone identifier (like fixed offset or
+    /// POSIX time zones), and refuses to deserialize anything other than an
+    /// IANA time zone identifier. Deserialization resolves the identifier
+    /// using Jiff's global time zone database.
+    ///
+    /// This is useful when all processes communicating with one another
+    /// are expected to have the same copy of the time zone database, and
+    /// you want to guarantee that only time zones from that database are
+    /// exchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::tz::{self, TimeZone};
+    ///
+    /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    /// struct Record {
+    ///     #[serde(with = "jiff::fmt::serde::tz::iana::required")]
+    ///     tz: TimeZone,
+    /// }
+    ///
+    /// let json = r#"{"tz":"America/Nuuk"}"#;
+    /// let got: Record = serde_json::from_str(&json)?;
+    /// assert_eq!(got.tz, TimeZone::get("America/Nuuk")?);
+    /// assert_eq!(serde_json::to_string(&got)?, json);
+    ///
+    /// // Fixed offsets are not IANA time zone identifiers.
+    /// let record = Record { tz: TimeZone::fixed(tz::offset(-5)) };
+    /// assert!(serde_json::to_string(&record).is_err());
+    /// assert!(serde_json::from_str::<Record>(r#"{"tz":"-05"}"#).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub mod iana {
+        use serde::de;
+
+        /// A required visitor for `TimeZone` IANA identifiers.
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = crate::tz::TimeZone;
+
+            fn expecting(
+                &self,
+                f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+                f.write_str("a string containing an IANA time zone identifier")
+            }
+
+            #[inline]
+            fn visit_bytes<E: de::Error>(
+                self,
+                value: &[u8],
+            ) -> Result<crate::tz::TimeZone, E> {
+                let name = core::str::from_utf8(value).map_err(|_| {
+                    de::Error::invalid_value(
+                        de::Unexpected::Bytes(value),
+                        &self,
+                    )
+                })?;
+                self.visit_str(name)
+            }
+
+            #[inline]
+            fn visit_str<E: de::Error>(
+                self,
+                value: &str,
+            ) -> Result<crate::tz::TimeZone, E> {
+                crate::tz::db().get(value).map_err(de::Error::custom)
+            }
+        }
+
+        /// (De)serialize a required [`TimeZone`](crate::tz::TimeZone) as an
+        /// IANA time zone identifier.
+        pub mod required {
+            /// Serialize a required [`TimeZone`](crate::tz::TimeZone) as an
+            /// IANA time zone identifier.
+            ///
+            /// This returns an error if the `TimeZone` has no IANA time zone
+            /// identifier.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                tz: &crate::tz::TimeZone,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                let Some(name) = tz.iana_name() else {
+                    return Err(<S::Error as serde::ser::Error>::custom(
+                        format_args!(
+                            "time zone {tz} has no IANA time zone \
+                             identifier, and so can't be serialized as one",
+                            tz = tz.diagnostic_name(),
+                        ),
+                    ));
+                };
+                se.serialize_str(name)
+            }
+
+            /// Deserialize a required [`TimeZone`](crate::tz::TimeZone) from
+            /// an IANA time zone identifier.
+            ///
+            /// The identifier is resolved using Jiff's global time zone
+            /// database.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::tz::TimeZone, D::Error> {
+                de.deserialize_str(super::Visitor)
+            }
+        }
+
+        /// (De)serialize an optional [`TimeZone`](crate::tz::TimeZone) as an
+        /// IANA time zone identifier.
+        pub mod optional {
+            /// Serialize an optional [`TimeZone`](crate::tz::TimeZone) as an
+            /// IANA time zone identifier.
+            ///
+            /// This returns an error if the `TimeZone` has no IANA time zone
+            /// identifier.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                tz: &Option<crate::tz::TimeZone>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                match *tz {
+                    None => se.serialize_none(),
+                    Some(ref tz) => super::required::serialize(tz, se),
+                }
+            }
+
+            /// Deserialize an optional [`TimeZone`](crate::tz::TimeZone) from
+            /// an IANA time zone identifier.
+            ///
+            /// The identifier is resolved using Jiff's global time zone
+            /// database.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::tz::TimeZone>, D::Error> {
+                de.deserialize_option(super::super::OptionalVisitor(
+                    super::Visitor,
+                ))
+            }
+        }
+    }
+
+    /// (De)serialize a [`TimeZone`](crate::tz::TimeZone) as a POSIX time zone
+    /// string.
+    ///
+    /// Serialization succeeds for UTC, fixed offset and POSIX time zones. It
+    /// also succeeds for time zones backed by TZif data (like IANA time
+    /// zones) that have a POSIX time zone string in their footer. In that
+    /// case, **only** the POSIX time zone string is serialized, and so all
+    /// historical transitions are lost. Serialization fails for all other
+    /// time zones, including [`TimeZone::unknown`](crate::tz::TimeZone::unknown).
+    ///
+    /// Fixed offset time zones are serialized with the offset as the
+    /// abbreviation. For example, `-05:00` is serialized as `<-05>5`.
+    ///
+    /// Deserialization always produces a POSIX time zone. It does not consult
+    /// any time zone database.
+    ///
+    /// This is useful when time zone context needs to be shipped to systems
+    /// that may not have a time zone database (or may have a different copy
+    /// of one), and only current and future rules matter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::{self, TimeZone}};
+    ///
+    /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    /// struct Record {
+    ///     #[serde(with = "jiff::fmt::serde::tz::posix::required")]
+    ///     tz: TimeZone,
+    /// }
+    ///
+    /// let record = Record { tz: TimeZone::get("America/New_York")? };
+    /// let json = serde_json::to_string(&record)?;
+    /// assert_eq!(json, r#"{"tz":"EST5EDT,M3.2.0,M11.1.0"}"#);
+    ///
+    /// let got: Record = serde_json::from_str(&json)?;
+    /// assert_eq!(got.tz.iana_name(), None);
+    /// let zdt = date(2025, 7, 1).at(12, 0, 0, 0).to_zoned(got.tz)?;
+    /// assert_eq!(zdt.offset(), tz::offset(-4));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub mod posix {
+        use serde::de;
+
+        /// A required visitor for POSIX time zone strings.
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = crate::tz::TimeZone;
+
+            fn expecting(
+                &self,
+                f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+                f.write_str("a string containing a POSIX time zone")
+            }
+
+            #[inline]
+            fn visit_bytes<E: de::Error>(
+                self,
+                value: &[u8],
+            ) -> Result<crate::tz::TimeZone, E> {
+                let posix = core::str::from_utf8(value).map_err(|_| {
+                    de::Error::invalid_value(
+                        de::Unexpected::Bytes(value),
+                        &self,
+                    )
+                })?;
+                self.visit_str(posix)
+            }
+
+            #[inline]
+            fn visit_str<E: de::Error>(
+                self,
+                value: &str,
+            ) -> Result<crate::tz::TimeZone, E> {
+                crate::tz::TimeZone::posix(value).map_err(de::Error::custom)
+            }
+        }
+
+        /// (De)serialize a required [`TimeZone`](crate::tz::TimeZone) as a
+        /// POSIX time zone string.
+        pub mod required {
+            /// Serialize a required [`TimeZone`](crate::tz::TimeZone) as a
+            /// POSIX time zone string.
+            ///
+            /// This returns an error if the `TimeZone` has no POSIX time zone
+            /// representation.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                tz: &crate::tz::TimeZone,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                let Some(posix) = tz.to_posix_string() else {
+                    return Err(<S::Error as serde::ser::Error>::custom(
+                        format_args!(
+                            "time zone {tz} has no POSIX time zone \
+                             representation, and so can't be serialized \
+                             as one",
+                            tz = tz.diagnostic_name(),
+                        ),
+                    ));
+                };
+                se.serialize_str(&posix)
+            }
+
+            /// Deserialize a required [`TimeZone`](crate::tz::TimeZone) from
+            /// a POSIX time zone string.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::tz::TimeZone, D::Error> {
+                de.deserialize_str(super::Visitor)
+            }
+        }
+
+        /// (De)serialize an optional [`TimeZone`](crate::tz::TimeZone) as a
+        /// POSIX time zone string.
+        pub mod optional {
+            /// Serialize an optional [`TimeZone`](crate::tz::TimeZone) as a
+            /// POSIX time zone string.
+            ///
+            /// This returns an error if the `TimeZone` has no POSIX time zone
+            /// representation.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                tz: &Option<crate::tz::TimeZone>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                match *tz {
+                    None => se.serialize_none(),
+                    Some(ref tz) => super::required::serialize(tz, se),
+                }
+            }
+
+            /// Deserialize an optional [`TimeZone`](crate::tz::TimeZone) from
+            /// a POSIX time zone string.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::tz::TimeZone>, D::Error> {
+                de.deserialize_option(super::super::OptionalVisitor(
+                    super::Visitor,
+                ))
+            }
+        }
+    }
+
+    /// (De)serialize a [`TimeZone`](crate::tz::TimeZone) as TZif binary
+    /// data.
+    ///
+    /// A time zone is serialized as a pair of its IANA time zone identifier
+    /// (if it has one) and its full [TZif] data, including all historical
+    /// transitions. Deserialization uses the TZif data as-is and does not
+    /// consult any time zone database. This means the time zone deserialized
+    /// always has precisely the same rules as the one serialized, even when
+    /// the processes on either end have different copies of the time zone
+    /// database.
+    ///
+    /// This also works for TZif-backed time zones without an IANA time zone
+    /// identifier, like a system time zone derived from a `/etc/localtime`
+    /// that isn't a symlink. Such time zones can't be serialized by
+    /// [`tz::required`](self::required).
+    ///
+    /// Serialization fails for time zones that aren't backed by TZif data,
+    /// like UTC, fixed offset and POSIX time zones.
+    ///
+    /// [TZif]: https://datatracker.ietf.org/doc/html/rfc8536
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tz::TimeZone, Timestamp};
+    ///
+    /// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    /// struct Record {
+    ///     #[serde(with = "jiff::fmt::serde::tz::tzif::required")]
+    ///     tz: TimeZone,
+    /// }
+    ///
+    /// let tz = TimeZone::get("America/New_York")?;
+    /// let json = serde_json::to_string(&Record { tz: tz.clone() })?;
+    /// let got: Record = serde_json::from_str(&json)?;
+    /// assert_eq!(got.tz.iana_name(), Some("America/New_York"));
+    ///
+    /// let ts: Timestamp = "1970-01-01T00:00Z".parse()?;
+    /// assert_eq!(got.tz.to_offset(ts), tz.to_offset(ts));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub mod tzif {
+        use alloc::{string::String, vec::Vec};
+
+        use serde::de;
+
+        /// Serializes TZif data as a byte string.
+        struct Bytes<'a>(&'a [u8]);
+
+        impl<'a> serde::Serialize for Bytes<'a> {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                se.serialize_bytes(self.0)
+            }
+        }
+
+        /// Deserializes TZif data from either a byte string or a sequence of
+        /// bytes. (The latter is how formats like JSON represent bytes.)
+        struct ByteBuf(Vec<u8>);
+
+        impl<'de> serde::Deserialize<'de> for ByteBuf {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<ByteBuf, D::Error> {
+                de.deserialize_bytes(ByteBufVisitor)
+            }
+        }
+
+        struct ByteBufVisitor;
+
+        impl<'de> de::Visitor<'de> for ByteBufVisitor {
+            type Value = ByteBuf;
+
+            fn expecting(
+                &self,
+                f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+                f.write_str("TZif binary data")
+            }
 
-    use crate::fmt::{temporal, StdFmtWrite};
+            #[inline]
+            fn visit_bytes<E: de::Error>(
+                self,
+                value: &[u8],
+            ) -> Result<ByteBuf, E> {
+                Ok(ByteBuf(value.to_vec()))
+            }
 
-    struct TemporalTimeZone<'a>(&'a crate::tz::TimeZone);
+            #[inline]
+            fn visit_byte_buf<E: de::Error>(
+                self,
+                value: Vec<u8>,
+            ) -> Result<ByteBuf, E> {
+                Ok(ByteBuf(value))
+            }
 
-    impl<'a> core::fmt::Display for TemporalTimeZone<'a> {
-        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-            static PRINTER: temporal::DateTimePrinter =
-                temporal::DateTimePrinter::new();
-            PRINTER
-                .print_time_zone(self.0, StdFmtWrite(f))
-                .map_err(|_| core::fmt::Error)
+            #[inline]
+            fn visit_seq<A: de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<ByteBuf, A::Error> {
+                let mut bytes =
+                    Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+                while let Some(byte) = seq.next_element::<u8>()? {
+                    bytes.push(byte);
+                }
+                Ok(ByteBuf(bytes))
+            }
         }
-    }
 
-    /// A required visitor for `TimeZone`.
-    struct Visitor;
+        /// A required visitor for a `TimeZone` identifier and TZif data pair.
+        struct Visitor;
 
-    impl<'de> de::Visitor<'de> for Visitor {
-        type Value = crate::tz::TimeZone;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = crate::tz::TimeZone;
 
-        fn expecting(
-            &self,
-            f: &mut core::fmt::Formatter,
-        ) -> core::fmt::Result {
-            f.write_str(
-                "a string representing a time zone via an \
-                 IANA time zone identifier, fixed offset from UTC \
-                 or a POSIX time zone string",
-            )
+            fn expecting(
+                &self,
+                f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+                f.write_str(
+                    "a pair of an optional IANA time zone identifier \
+                     and TZif binary data",
+                )
+            }
+
+            #[inline]
+            fn visit_seq<A: de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<crate::tz::TimeZone, A::Error> {
+                let name: Option<String> = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let ByteBuf(data) = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let result = match name {
+                    None => crate::tz::TimeZone::tzif_system(&data),
+                    Some(ref name) => crate::tz::TimeZone::tzif(name, &data),
+                };
+                result.map_err(de::Error::custom)
+            }
         }
 
-        #[inline]
-        fn visit_bytes<E: de::Error>(
-            self,
-            value: &[u8],
-        ) -> Result<crate::tz::TimeZone, E> {
-            static PARSER: temporal::DateTimeParser =
-                temporal::DateTimeParser::new();
-            PARSER.parse_time_zone(value).map_err(de::Error::custom)
+        /// An optional visitor for a `TimeZone` identifier and TZif data pair.
+        struct OptionalVisitor;
+
+        impl<'de> de::Visitor<'de> for OptionalVisitor {
+            type Value = Option<crate::tz::TimeZone>;
+
+            fn expecting(
+                &self,
+                f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+                Visitor.expecting(f)
+            }
+
+            #[inline]
+            fn visit_some<D: de::Deserializer<'de>>(
+                self,
+                de: D,
+            ) -> Result<Option<crate::tz::TimeZone>, D::Error> {
+                de.deserialize_tuple(2, Visitor).map(Some)
+            }
+
+            #[inline]
+            fn visit_none<E: de::Error>(
+                self,
+            ) -> Result<Option<crate::tz::TimeZone>, E> {
+                Ok(None)
+            }
         }
 
-        #[inline]
-        fn visit_str<E: de::Error>(
-            self,
-            value: &str,
-        ) -> Result<crate::tz::TimeZone, E> {
-            self.visit_bytes(value.as_bytes())
+        /// (De)serialize a required [`TimeZone`](crate::tz::TimeZone) as TZif
+        /// binary data.
+        pub mod required {
+            use serde::ser::SerializeTuple;
+
+            /// Serialize a required [`TimeZone`](crate::tz::TimeZone) as a
+            /// pair of its IANA time zone identifier (if present) and its
+            /// TZif binary data.
+            ///
+            /// This returns an error if the `TimeZone` isn't backed by TZif
+            /// data.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                tz: &crate::tz::TimeZone,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                let Some(data) = tz.to_tzif_bytes() else {
+                    return Err(<S::Error as serde::ser::Error>::custom(
+                        format_args!(
+                            "time zone {tz} is not backed by TZif data, \
+                             and so can't be serialized as TZif",
+                            tz = tz.diagnostic_name(),
+                        ),
+                    ));
+                };
+                let mut tup = se.serialize_tuple(2)?;
+                tup.serialize_element(&tz.iana_name())?;
+                tup.serialize_element(&super::Bytes(&data))?;
+                tup.end()
+            }
+
+            /// Deserialize a required [`TimeZone`](crate::tz::TimeZone) from
+            /// a pair of an optional IANA time zone identifier and TZif
+            /// binary data.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<crate::tz::TimeZone, D::Error> {
+                de.deserialize_tuple(2, super::Visitor)
+            }
+        }
+
+        /// (De)serialize an optional [`TimeZone`](crate::tz::TimeZone) as TZif
+        /// binary data.
+        pub mod optional {
+            /// Serialize an optional [`TimeZone`](crate::tz::TimeZone) as a
+            /// pair of its IANA time zone identifier (if present) and its
+            /// TZif binary data.
+            ///
+            /// This returns an error if the `TimeZone` isn't backed by TZif
+            /// data.
+            #[inline]
+            pub fn serialize<S: serde::Serializer>(
+                tz: &Option<crate::tz::TimeZone>,
+                se: S,
+            ) -> Result<S::Ok, S::Error> {
+                match *tz {
+                    None => se.serialize_none(),
+                    Some(ref tz) => super::required::serialize(tz, se),
+                }
+            }
+
+            /// Deserialize an optional [`TimeZone`](crate::tz::TimeZone) from
+            /// a pair of an optional IANA time zone identifier and TZif
+            /// binary data.
+            #[inline]
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                de: D,
+            ) -> Result<Option<crate::tz::TimeZone>, D::Error> {
+                de.deserialize_option(super::OptionalVisitor)
+            }
         }
     }
+}
 
-    /// A generic optional visitor for `TimeZone`.
-    struct OptionalVisitor<V>(V);
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
 
-    impl<'de, V: de::Visitor<'de, Value = crate::tz::TimeZone>>
-        de::Visitor<'de> for OptionalVisitor<V>
-    {
-        type Value = Option<crate::tz::TimeZone>;
+    use crate::{
+        span::span_eq, tz::TimeZone, SignedDuration, Span, SpanFieldwise,
+        Timestamp, ToSpan,
+    };
 
-        fn expecting(
-            &self,
-            f: &mut core::fmt::Formatter,
-        ) -> core::fmt::Result {
-            f.write_str(
-                "a string representing a time zone via an \
-                 IANA time zone identifier, fixed offset from UTC \
-                 or a POSIX time zone string",
-            )
+    #[test]
+    fn duration_friendly_compact_required() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(
+                serialize_with = "crate::fmt::serde::duration::friendly::compact::required"
+            )]
+            duration: SignedDuration,
         }
 
-        #[inline]
-        fn visit_some<D: de::Deserializer<'de>>(
-            self,
-            de: D,
-        ) -> Result<Option<crate::tz::TimeZone>, D::Error> {
-            de.deserialize_str(self.0).map(Some)
+        let json = r#"{"duration":"36 hours 1100ms"}"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            got.duration,
+            SignedDuration::new(36 * 60 * 60 + 1, 100_000_000)
+        );
+
+        let expected = r#"{"duration":"36h 1s 100ms"}"#;
+        assert_eq!(serde_json::to_string(&got).unwrap(), expected);
+    }
+
+    #[test]
+    fn duration_friendly_compact_optional() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(
+                serialize_with = "crate::fmt::serde::duration::friendly::compact::optional"
+            )]
+            duration: Option<SignedDuration>,
         }
 
-        #[inline]
-        fn visit_none<E: de::Error>(
-            self,
-        ) -> Result<Option<crate::tz::TimeZone>, E> {
-            Ok(None)
+        let json = r#"{"duration":"36 hours 1100ms"}"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            got.duration,
+            Some(SignedDuration::new(36 * 60 * 60 + 1, 100_000_000))
+        );
+
+        let expected = r#"{"duration":"36h 1s 100ms"}"#;
+        assert_eq!(serde_json::to_string(&got).unwrap(), expected);
+    }
+
+    #[test]
+    fn duration_iso8601_and_friendly() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(with = "crate::fmt::serde::duration::iso8601::required")]
+            iso: SignedDuration,
+            #[serde(with = "crate::fmt::serde::duration::friendly::required")]
+            friendly: SignedDuration,
+            #[serde(with = "crate::fmt::serde::duration::iso8601::optional")]
+            iso_opt: Option<SignedDuration>,
+            #[serde(with = "crate::fmt::serde::duration::friendly::optional")]
+            friendly_opt: Option<SignedDuration>,
         }
+
+        let json = r#"{
+            "iso": "1h 30m",
+            "friendly": "PT1H30M",
+            "iso_opt": null,
+            "friendly_opt": "-2.5s"
+        }"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.iso, SignedDuration::from_mins(90));
+        assert_eq!(got.friendly, SignedDuration::from_mins(90));
+        assert_eq!(got.iso_opt, None);
+        assert_eq!(got.friendly_opt, Some(SignedDuration::from_millis(-2500)));
+        insta::assert_snapshot!(serde_json::to_string(&got).unwrap(), @r###"{"iso":"PT1H30M","friendly":"1h 30m","iso_opt":null,"friendly_opt":"2s 500ms ago"}"###);
+
+        let got = Data { iso_opt: got.friendly_opt, ..got };
+        insta::assert_snapshot!(serde_json::to_string(&got).unwrap(), @r###"{"iso":"PT1H30M","friendly":"1h 30m","iso_opt":"-PT2.5S","friendly_opt":"2s 500ms ago"}"###);
     }
 
-    /// (De)serialize a required [`TimeZone`](crate::tz::TimeZone).
-    pub mod required {
-        /// Serialize a required [`TimeZone`](crate::tz::TimeZone).
-        ///
-        /// This will result in an IANA time zone identifier, fixed offset or a
-        /// POSIX time zone string.
-        ///
-        /// This can return an error in some cases when the `TimeZone` has no
-        /// succinct string representation. For example, when the `TimeZone` is
-        /// derived from a system `/etc/localtime` for which no IANA time zone
-        /// identifier could be found.
-        #[inline]
-        pub fn serialize<S: serde::Serializer>(
-            tz: &crate::tz::TimeZone,
-            se: S,
-        ) -> Result<S::Ok, S::Error> {
-            if !tz.has_succinct_serialization() {
-                return Err(<S::Error as serde::ser::Error>::custom(
-                    "time zones without IANA identifiers that aren't either \
-                     fixed offsets or a POSIX time zone can't be serialized \
-                     (this typically occurs when this is a system time zone \
-                      derived from `/etc/localtime` on Unix systems that \
-                      isn't symlinked to an entry in `/usr/share/zoneinfo)",
-                ));
-            }
-            se.collect_str(&super::TemporalTimeZone(tz))
+    #[test]
+    fn span_iso8601_and_friendly() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(with = "crate::fmt::serde::span::iso8601::required")]
+            iso: Span,
+            #[serde(with = "crate::fmt::serde::span::friendly::required")]
+            friendly: Span,
+            #[serde(with = "crate::fmt::serde::span::iso8601::optional")]
+            iso_opt: Option<Span>,
+            #[serde(with = "crate::fmt::serde::span::friendly::optional")]
+            friendly_opt: Option<Span>,
         }
 
-        /// Deserialize a required [`TimeZone`](crate::tz::TimeZone).
-        ///
-        /// This will attempt to parse an IANA time zone identifier, a fixed
-        /// offset or a POSIX time zone string.
-        #[inline]
-        pub fn deserialize<'de, D: serde::Deserializer<'de>>(
-            de: D,
-        ) -> Result<crate::tz::TimeZone, D::Error> {
-            de.deserialize_str(super::Visitor)
+        let json = r#"{
+            "iso": "1 year 2 months 1100ms",
+            "friendly": "P1Y2MT1.1S",
+            "iso_opt": "P1W",
+            "friendly_opt": null
+        }"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        span_eq!(got.iso, 1.year().months(2).milliseconds(1100));
+        span_eq!(
+            got.friendly,
+            1.year().months(2).seconds(1).milliseconds(100)
+        );
+        span_eq!(got.iso_opt.unwrap(), 1.week());
+        assert!(got.friendly_opt.is_none());
+        insta::assert_snapshot!(serde_json::to_string(&got).unwrap(), @r###"{"iso":"P1Y2MT1.1S","friendly":"1y 2mo 1s 100ms","iso_opt":"P1W","friendly_opt":null}"###);
+    }
+
+    #[test]
+    fn datetime_assume_utc() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(
+                with = "crate::fmt::serde::datetime::assume_utc::timestamp::required"
+            )]
+            ts: Timestamp,
+            #[serde(
+                with = "crate::fmt::serde::datetime::assume_utc::timestamp::optional"
+            )]
+            ts_opt: Option<Timestamp>,
+            #[serde(
+                with = "crate::fmt::serde::datetime::assume_utc::zoned::required"
+            )]
+            zdt: crate::Zoned,
+            #[serde(
+                with = "crate::fmt::serde::datetime::assume_utc::zoned::optional"
+            )]
+            zdt_opt: Option<crate::Zoned>,
         }
+
+        let json = r#"{
+            "ts": "2024-04-01T12:00:00",
+            "ts_opt": "2024-04-01T12:00:00-04",
+            "zdt": "2024-04-01",
+            "zdt_opt": null
+        }"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.ts, "2024-04-01T12:00:00Z".parse().unwrap());
+        assert_eq!(got.ts_opt, Some("2024-04-01T16:00:00Z".parse().unwrap()),);
+        assert_eq!(got.zdt.to_string(), "2024-04-01T00:00:00+00:00[UTC]");
+        assert!(got.zdt_opt.is_none());
+        insta::assert_snapshot!(
+            serde_json::to_string(&got).unwrap(),
+            @r###"{"ts":"2024-04-01T12:00:00","ts_opt":"2024-04-01T16:00:00","zdt":"2024-04-01T00:00:00","zdt_opt":null}"###,
+        );
+
+        let json = r#"{"ts":"nope","ts_opt":null,"zdt":"2024-04-01"}"#;
+        assert!(serde_json::from_str::<Data>(json).is_err());
     }
 
-    /// (De)serialize an optional [`TimeZone`](crate::tz::TimeZone).
-    pub mod optional {
-        /// Serialize an optional [`TimeZone`](crate::tz::TimeZone).
-        ///
-        /// This will result in an IANA time zone identifier, fixed offset or a
-        /// POSIX time zone string.
-        ///
-        /// This can return an error in some cases when the `TimeZone` has no
-        /// succinct string representation. For example, when the `TimeZone` is
-        /// derived from a system `/etc/localtime` for which no IANA time zone
-        /// identifier could be found.
-        #[inline]
-        pub fn serialize<S: serde::Serializer>(
-            tz: &Option<crate::tz::TimeZone>,
-            se: S,
-        ) -> Result<S::Ok, S::Error> {
-            match *tz {
-                None => se.serialize_none(),
-                Some(ref tz) => super::required::serialize(tz, se),
-            }
+    #[test]
+    fn datetime_zoned_in() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Data {
+            #[serde(deserialize_with = "in_fixed")]
+            zdt: crate::Zoned,
         }
 
-        /// Deserialize an optional [`TimeZone`](crate::tz::TimeZone).
-        ///
-        /// This will attempt to parse an IANA time zone identifier, a fixed
-        /// offset or a POSIX time zone string.
-        #[inline]
-        pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        fn in_fixed<'de, D: serde::Deserializer<'de>>(
             de: D,
-        ) -> Result<Option<crate::tz::TimeZone>, D::Error> {
-            de.deserialize_option(super::OptionalVisitor(super::Visitor))
+        ) -> Result<crate::Zoned, D::Error> {
+            let tz = TimeZone::fixed(crate::tz::offset(-5));
+            crate::fmt::serde::datetime::zoned_in(de, tz)
         }
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        span::span_eq, SignedDuration, Span, SpanFieldwise, Timestamp, ToSpan,
-    };
+        let json = r#"{"zdt":"2024-04-01T12:00:00"}"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.zdt.to_string(), "2024-04-01T12:00:00-05:00[-05:00]");
+
+        // An offset in the string is respected, but the time zone given is
+        // still used for the result.
+        let json = r#"{"zdt":"2024-04-01T12:00:00Z"}"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.zdt.to_string(), "2024-04-01T07:00:00-05:00[-05:00]");
+    }
 
     #[test]
-    fn duration_friendly_compact_required() {
+    fn zoned_rfc3339() {
         #[derive(Debug, serde::Deserialize, serde::Serialize)]
         struct Data {
             #[serde(
-                serialize_with = "crate::fmt::serde::duration::friendly::compact::required"
+                with = "crate::fmt::serde::zoned::rfc3339::offset::required"
             )]
-            duration: SignedDuration,
+            offset: crate::Zoned,
+            #[serde(
+                with = "crate::fmt::serde::zoned::rfc3339::offset::optional"
+            )]
+            offset_opt: Option<crate::Zoned>,
+            #[serde(
+                with = "crate::fmt::serde::zoned::rfc3339::system::required"
+            )]
+            system: crate::Zoned,
+            #[serde(
+                with = "crate::fmt::serde::zoned::rfc3339::system::optional"
+            )]
+            system_opt: Option<crate::Zoned>,
         }
 
-        let json = r#"{"duration":"36 hours 1100ms"}"#;
+        let json = r#"{
+            "offset": "2024-03-10T01:30:00-05:00[America/New_York]",
+            "offset_opt": null,
+            "system": "2024-03-10T06:30:00Z",
+            "system_opt": "2024-03-10T01:30:00.5-05:00"
+        }"#;
         let got: Data = serde_json::from_str(&json).unwrap();
         assert_eq!(
-            got.duration,
-            SignedDuration::new(36 * 60 * 60 + 1, 100_000_000)
+            got.offset.to_string(),
+            "2024-03-10T01:30:00-05:00[-05:00]",
+        );
+        assert!(got.offset_opt.is_none());
+        let expected: Timestamp = "2024-03-10T06:30:00Z".parse().unwrap();
+        assert_eq!(got.system.timestamp(), expected);
+        assert_eq!(got.system.time_zone(), &TimeZone::system());
+        assert_eq!(
+            got.system_opt.as_ref().map(|zdt| zdt.timestamp()),
+            Some("2024-03-10T06:30:00.5Z".parse().unwrap()),
         );
 
-        let expected = r#"{"duration":"36h 1s 100ms"}"#;
-        assert_eq!(serde_json::to_string(&got).unwrap(), expected);
+        let data = Data {
+            offset: got.offset.clone(),
+            offset_opt: Some(got.offset.clone()),
+            system: got.offset.with_time_zone(TimeZone::UTC),
+            system_opt: None,
+        };
+        insta::assert_snapshot!(
+            serde_json::to_string(&data).unwrap(),
+            @r###"{"offset":"2024-03-10T01:30:00-05:00","offset_opt":"2024-03-10T01:30:00-05:00","system":"2024-03-10T06:30:00+00:00","system_opt":null}"###,
+        );
+
+        let json = r#"{
+            "offset": "2024-03-10T01:30:00",
+            "offset_opt": null,
+            "system": "2024-03-10T06:30:00Z",
+            "system_opt": null
+        }"#;
+        insta::assert_snapshot!(
+            serde_json::from_str::<Data>(json).unwrap_err(),
+            @"RFC 3339 timestamp is missing an offset at line 2 column 43",
+        );
     }
 
     #[test]
-    fn duration_friendly_compact_optional() {
+    fn civil_date_and_time_integers() {
+        use crate::civil::{date, time, Date, Time};
+
         #[derive(Debug, serde::Deserialize, serde::Serialize)]
         struct Data {
             #[serde(
-                serialize_with = "crate::fmt::serde::duration::friendly::compact::optional"
+                with = "crate::fmt::serde::civil::date::unix_epoch_day::required"
             )]
-            duration: Option<SignedDuration>,
+            date: Date,
+            #[serde(
+                with = "crate::fmt::serde::civil::date::unix_epoch_day::optional"
+            )]
+            date_opt: Option<Date>,
+            #[serde(
+                with = "crate::fmt::serde::civil::time::nanosecond::required"
+            )]
+            time: Time,
+            #[serde(
+                with = "crate::fmt::serde::civil::time::nanosecond::optional"
+            )]
+            time_opt: Option<Time>,
         }
 
-        let json = r#"{"duration":"36 hours 1100ms"}"#;
+        let json = r#"{
+            "date": -1,
+            "date_opt": 2932896,
+            "time": 86399999999999,
+            "time_opt": null
+        }"#;
         let got: Data = serde_json::from_str(&json).unwrap();
-        assert_eq!(
-            got.duration,
-            Some(SignedDuration::new(36 * 60 * 60 + 1, 100_000_000))
+        assert_eq!(got.date, date(1969, 12, 31));
+        assert_eq!(got.date_opt, Some(Date::MAX));
+        assert_eq!(got.time, Time::MAX);
+        assert_eq!(got.time_opt, None);
+
+        let data = Data {
+            date: Date::MIN,
+            date_opt: None,
+            time: Time::MIN,
+            time_opt: Some(time(1, 2, 3, 4)),
+        };
+        insta::assert_snapshot!(
+            serde_json::to_string(&data).unwrap(),
+            @r###"{"date":-4371587,"date_opt":null,"time":0,"time_opt":3723000000004}"###,
         );
 
-        let expected = r#"{"duration":"36h 1s 100ms"}"#;
-        assert_eq!(serde_json::to_string(&got).unwrap(), expected);
+        let json = r#"{
+            "date": 2932897,
+            "date_opt": null,
+            "time": 0,
+            "time_opt": null
+        }"#;
+        insta::assert_snapshot!(
+            serde_json::from_str::<Data>(json).unwrap_err(),
+            @"parameter 'days' with value 2932897 is not in the required range of -4371587..=2932896 at line 2 column 27",
+        );
+
+        let json = r#"{
+            "date": 0,
+            "date_opt": null,
+            "time": 86400000000000,
+            "time_opt": null
+        }"#;
+        insta::assert_snapshot!(
+            serde_json::from_str::<Data>(json).unwrap_err(),
+            @"parameter 'nanoseconds since midnight' with value 86400000000000 is not in the required range of 0..=86399999999999 at line 4 column 34",
+        );
+    }
+
+    #[test]
+    fn compact_tuples() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        use crate::civil::{date, time, DateTime};
+
+        #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(with = "crate::fmt::serde::timestamp::compact::required")]
+            ts: Timestamp,
+            #[serde(with = "crate::fmt::serde::duration::compact::optional")]
+            dur: Option<SignedDuration>,
+            #[serde(
+                with = "crate::fmt::serde::civil::datetime::compact::required"
+            )]
+            dt: DateTime,
+        }
+
+        let data = Data {
+            ts: Timestamp::new(1_700_000_000, 123).unwrap(),
+            dur: Some(SignedDuration::new(-5, -500)),
+            dt: DateTime::from_parts(date(1969, 12, 31), time(0, 0, 1, 5)),
+        };
+        assert_tokens(
+            &data,
+            &[
+                Token::Struct { name: "Data", len: 3 },
+                Token::Str("ts"),
+                Token::Tuple { len: 2 },
+                Token::I64(1_700_000_000),
+                Token::I32(123),
+                Token::TupleEnd,
+                Token::Str("dur"),
+                Token::Some,
+                Token::Tuple { len: 2 },
+                Token::I64(-5),
+                Token::I32(-500),
+                Token::TupleEnd,
+                Token::Str("dt"),
+                Token::Tuple { len: 2 },
+                Token::I32(-1),
+                Token::I64(1_000_000_005),
+                Token::TupleEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        #[serde(transparent)]
+        struct CompactSpan(
+            #[serde(with = "crate::fmt::serde::span::compact::required")] Span,
+        );
+
+        impl PartialEq for CompactSpan {
+            fn eq(&self, other: &CompactSpan) -> bool {
+                self.0.fieldwise() == other.0.fieldwise()
+            }
+        }
+
+        let mut tokens = [
+            Token::Tuple { len: 10 },
+            Token::I16(-1),
+            Token::I32(0),
+            Token::I32(0),
+            Token::I32(0),
+            Token::I32(-2),
+            Token::I64(0),
+            Token::I64(0),
+            Token::I64(0),
+            Token::I64(0),
+            Token::I64(-3),
+            Token::TupleEnd,
+        ];
+        assert_tokens(
+            &CompactSpan(-1.year().hours(2).nanoseconds(3)),
+            &tokens,
+        );
+        tokens[10] = Token::I64(3);
+        assert_de_tokens_error::<CompactSpan>(
+            &tokens,
+            "units of a span must all have the same sign",
+        );
+
+        #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[serde(transparent)]
+        struct CompactZoned(
+            #[serde(with = "crate::fmt::serde::zoned::compact::required")]
+            crate::Zoned,
+        );
+
+        let ts = data.ts;
+        assert_tokens(
+            &CompactZoned(ts.to_zoned(TimeZone::fixed(crate::tz::offset(-5)))),
+            &[
+                Token::Tuple { len: 3 },
+                Token::Tuple { len: 2 },
+                Token::I64(1_700_000_000),
+                Token::I32(123),
+                Token::TupleEnd,
+                Token::I32(-5 * 60 * 60),
+                Token::None,
+                Token::TupleEnd,
+            ],
+        );
+        assert_tokens(
+            &CompactZoned(ts.to_zoned(TimeZone::UTC)),
+            &[
+                Token::Tuple { len: 3 },
+                Token::Tuple { len: 2 },
+                Token::I64(1_700_000_000),
+                Token::I32(123),
+                Token::TupleEnd,
+                Token::I32(0),
+                Token::Some,
+                Token::Str("UTC"),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    /// The default `Serialize` implementations always use a string, even for
+    /// formats that aren't human readable.
+    #[test]
+    fn default_is_string_for_compact_formats() {
+        use serde_test::{assert_tokens, Configure, Token};
+
+        assert_tokens(
+            &Timestamp::new(1_700_000_000, 123).unwrap().compact(),
+            &[Token::Str("2023-11-14T22:13:20.000000123Z")],
+        );
+        assert_tokens(
+            &SignedDuration::from_secs(90).compact(),
+            &[Token::Str("PT1M30S")],
+        );
     }
 
     #[test]
@@ -1607,4 +5563,524 @@ mod tests {
         );
         assert_eq!(serde_json::to_string(&got).unwrap(), json);
     }
+
+    #[test]
+    fn timestamp_second_f64_optional() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(
+                with = "crate::fmt::serde::timestamp::second::f64::optional"
+            )]
+            ts: Option<Timestamp>,
+        }
+
+        let p = |json: &str| -> Option<Timestamp> {
+            serde_json::from_str::<Data>(json).unwrap().ts
+        };
+        let roundtrip = |json: &str| {
+            let got: Data = serde_json::from_str(json).unwrap();
+            assert_eq!(serde_json::to_string(&got).unwrap(), json);
+        };
+
+        assert_eq!(
+            p(r#"{"ts":1517644800.5}"#),
+            Some(Timestamp::from_millisecond(1517644800_500).unwrap()),
+        );
+        assert_eq!(
+            p(r#"{"ts":-1.25}"#),
+            Some(Timestamp::from_millisecond(-1_250).unwrap()),
+        );
+        assert_eq!(
+            p(r#"{"ts":1517644800}"#),
+            Some(Timestamp::from_second(1517644800).unwrap()),
+        );
+        assert_eq!(p(r#"{"ts":null}"#), None);
+        roundtrip(r#"{"ts":1517644800.5}"#);
+        roundtrip(r#"{"ts":1517644800.123456}"#);
+        roundtrip(r#"{"ts":-1.25}"#);
+        roundtrip(r#"{"ts":null}"#);
+
+        let got = Data { ts: Some(Timestamp::from_second(5).unwrap()) };
+        assert_eq!(serde_json::to_string(&got).unwrap(), r#"{"ts":5.0}"#);
+
+        let err = serde_json::from_str::<Data>(r#"{"ts":1e20}"#).unwrap_err();
+        assert!(err.to_string().contains("overflows"), "{err}");
+    }
+
+    #[test]
+    fn timestamp_millisecond_f64_required() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(
+                with = "crate::fmt::serde::timestamp::millisecond::f64::required"
+            )]
+            ts: Timestamp,
+        }
+
+        let p = |json: &str| -> Timestamp {
+            serde_json::from_str::<Data>(json).unwrap().ts
+        };
+        let roundtrip = |json: &str| {
+            let got: Data = serde_json::from_str(json).unwrap();
+            assert_eq!(serde_json::to_string(&got).unwrap(), json);
+        };
+
+        assert_eq!(
+            p(r#"{"ts":1517644800123.5}"#),
+            Timestamp::from_microsecond(1517644800123_500).unwrap(),
+        );
+        assert_eq!(
+            p(r#"{"ts":-1.5}"#),
+            Timestamp::from_microsecond(-1_500).unwrap(),
+        );
+        assert_eq!(
+            p(r#"{"ts":1517644800123}"#),
+            Timestamp::from_millisecond(1517644800123).unwrap(),
+        );
+        roundtrip(r#"{"ts":1517644800123.5}"#);
+        roundtrip(r#"{"ts":1517644800123.456}"#);
+        roundtrip(r#"{"ts":-1.5}"#);
+
+        let err = serde_json::from_str::<Data>(r#"{"ts":1e20}"#).unwrap_err();
+        insta::assert_snapshot!(err, @"got floating point milliseconds 100000000000000000000, which cannot be converted to a Jiff `Timestamp` at line 1 column 10");
+        let err = serde_json::from_str::<Data>(r#"{"ts":"1"}"#).unwrap_err();
+        insta::assert_snapshot!(err, @r###"invalid type: string "1", expected a floating point number of milliseconds from the Unix epoch at line 1 column 9"###);
+    }
+
+    #[test]
+    fn timestamp_second_decimal_required() {
+        use serde_test::{assert_de_tokens, assert_ser_tokens, Token};
+
+        const NUMBER: &str = "$serde_json::private::Number";
+
+        #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(
+                with = "crate::fmt::serde::timestamp::second::decimal::required"
+            )]
+            ts: Timestamp,
+        }
+
+        let tokens = |ts: Timestamp, number: &'static str| {
+            let data = Data { ts };
+            assert_ser_tokens(
+                &data,
+                &[
+                    Token::Struct { name: "Data", len: 1 },
+                    Token::Str("ts"),
+                    Token::Struct { name: NUMBER, len: 1 },
+                    Token::Str(NUMBER),
+                    Token::Str(number),
+                    Token::StructEnd,
+                    Token::StructEnd,
+                ],
+            );
+            assert_de_tokens(
+                &data,
+                &[
+                    Token::Struct { name: "Data", len: 1 },
+                    Token::Str("ts"),
+                    Token::Map { len: Some(1) },
+                    Token::Str(NUMBER),
+                    Token::Str(number),
+                    Token::MapEnd,
+                    Token::StructEnd,
+                ],
+            );
+        };
+        let ns = |n: i128| Timestamp::from_nanosecond(n).unwrap();
+        tokens(ns(1517644800_123456789), "1517644800.123456789");
+        tokens(ns(1517644800_500000000), "1517644800.5");
+        tokens(ns(1517644800_000000000), "1517644800");
+        tokens(ns(-1_500_000_000), "-1.5");
+        tokens(ns(-250_000_000), "-0.25");
+        tokens(ns(-1), "-0.000000001");
+        tokens(ns(0), "0");
+        tokens(Timestamp::MIN, "-377705023201");
+        tokens(Timestamp::MAX, "253402207200.999999999");
+
+        let p = |json: &str| -> Timestamp {
+            serde_json::from_str::<Data>(json).unwrap().ts
+        };
+        let err = |json: &str| -> String {
+            serde_json::from_str::<Data>(json).unwrap_err().to_string()
+        };
+
+        assert_eq!(p(r#"{"ts":1517644800}"#), ns(1517644800_000000000));
+        assert_eq!(p(r#"{"ts":-1}"#), ns(-1_000_000_000));
+        assert_eq!(
+            p(r#"{"ts":"1517644800.123456789"}"#),
+            ns(1517644800_123456789),
+        );
+        assert_eq!(p(r#"{"ts":"-0.5"}"#), ns(-500_000_000));
+        assert_eq!(p(r#"{"ts":"1.1234567890000"}"#), ns(1_123456789));
+        assert_eq!(p(r#"{"ts":"1.000"}"#), ns(1_000_000_000));
+
+        insta::assert_snapshot!(
+            err(r#"{"ts":1.5}"#),
+            @"got floating point number 1.5, but an exact decimal number is required (is `serde_json`'s `arbitrary_precision` feature enabled?) at line 1 column 9",
+        );
+        insta::assert_snapshot!(
+            err(r#"{"ts":"1.1234567891"}"#),
+            @"failed to parse '1.1234567891' as a decimal number of seconds: invalid fraction, too many digits (at most 9 are allowed at line 1 column 20",
+        );
+        insta::assert_snapshot!(
+            err(r#"{"ts":"1.5e9"}"#),
+            @"failed to parse '1.5e9' as a decimal number of seconds: invalid fractional digit, expected 0-9 but got e at line 1 column 13",
+        );
+        insta::assert_snapshot!(
+            err(r#"{"ts":"1."}"#),
+            @"failed to parse '1.' as a decimal number of seconds: invalid fraction, no digits found at line 1 column 10",
+        );
+        insta::assert_snapshot!(
+            err(r#"{"ts":"-"}"#),
+            @"failed to parse '-' as a decimal number of seconds: invalid number, no digits found at line 1 column 9",
+        );
+        insta::assert_snapshot!(
+            err(r#"{"ts":"253402207201"}"#),
+            @"failed to parse '253402207201' as a decimal number of seconds: parameter 'second' with value 253402207201 is not in the required range of -377705023201..=253402207200 at line 1 column 20",
+        );
+    }
+
+    #[test]
+    fn timestamp_second_decimal_optional() {
+        use serde_test::{assert_tokens, Token};
+
+        const NUMBER: &str = "$serde_json::private::Number";
+
+        #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(
+                with = "crate::fmt::serde::timestamp::second::decimal::optional"
+            )]
+            ts: Option<Timestamp>,
+        }
+
+        let data =
+            Data { ts: Some(Timestamp::from_millisecond(-1_250).unwrap()) };
+        serde_test::assert_ser_tokens(
+            &data,
+            &[
+                Token::Struct { name: "Data", len: 1 },
+                Token::Str("ts"),
+                Token::Some,
+                Token::Struct { name: NUMBER, len: 1 },
+                Token::Str(NUMBER),
+                Token::Str("-1.25"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &data,
+            &[
+                Token::Struct { name: "Data", len: 1 },
+                Token::Str("ts"),
+                Token::Some,
+                Token::Map { len: Some(1) },
+                Token::Str(NUMBER),
+                Token::Str("-1.25"),
+                Token::MapEnd,
+                Token::StructEnd,
+            ],
+        );
+        assert_tokens(
+            &Data { ts: None },
+            &[
+                Token::Struct { name: "Data", len: 1 },
+                Token::Str("ts"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+
+        let p = |json: &str| -> Option<Timestamp> {
+            serde_json::from_str::<Data>(json).unwrap().ts
+        };
+        assert_eq!(
+            p(r#"{"ts":"1517644800.123456789"}"#),
+            Some(Timestamp::from_nanosecond(1517644800_123456789).unwrap()),
+        );
+        assert_eq!(p(r#"{"ts":5}"#), Some(Timestamp::from_second(5).unwrap()),);
+        assert_eq!(p(r#"{"ts":null}"#), None);
+    }
+
+    #[test]
+    fn timestamp_flexible_auto_required() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(
+                with = "crate::fmt::serde::timestamp::flexible::auto::required"
+            )]
+            ts: Timestamp,
+        }
+
+        let p = |json: &str| -> Timestamp {
+            serde_json::from_str::<Data>(json).unwrap().ts
+        };
+        let expected = Timestamp::from_second(1517644800).unwrap();
+        assert_eq!(p(r#"{"ts":1517644800}"#), expected);
+        assert_eq!(p(r#"{"ts":1517644800000}"#), expected);
+        assert_eq!(p(r#"{"ts":1517644800000000}"#), expected);
+        assert_eq!(p(r#"{"ts":1517644800000000000}"#), expected);
+        assert_eq!(p(r#"{"ts":"1517644800"}"#), expected);
+        assert_eq!(p(r#"{"ts":"+1517644800000"}"#), expected);
+        assert_eq!(p(r#"{"ts":"2018-02-03T08:00:00Z"}"#), expected);
+        assert_eq!(p(r#"{"ts":"2018-02-03T03:00:00-05:00"}"#), expected);
+        assert_eq!(
+            p(r#"{"ts":-1517644800000}"#),
+            Timestamp::from_second(-1517644800).unwrap(),
+        );
+        assert_eq!(
+            p(r#"{"ts":1517644800123}"#),
+            Timestamp::from_millisecond(1517644800_123).unwrap(),
+        );
+        // Small millisecond values are ambiguous and treated as seconds.
+        assert_eq!(p(r#"{"ts":86400000}"#).as_second(), 86400000);
+
+        let got = Data { ts: expected };
+        assert_eq!(
+            serde_json::to_string(&got).unwrap(),
+            r#"{"ts":"2018-02-03T08:00:00Z"}"#,
+        );
+
+        let err = |json: &str| -> String {
+            serde_json::from_str::<Data>(json).unwrap_err().to_string()
+        };
+        insta::assert_snapshot!(
+            err(r#"{"ts":"nope"}"#),
+            @r###"failed to parse year in date "nope": failed to parse "nope" as year (a four digit integer): invalid digit, expected 0-9 but got n at line 1 column 12"###,
+        );
+        insta::assert_snapshot!(
+            err(r#"{"ts":true}"#),
+            @"invalid type: boolean `true`, expected an integer number of seconds, milliseconds, microseconds or nanoseconds from the Unix epoch or an RFC 3339 timestamp string at line 1 column 10",
+        );
+        insta::assert_snapshot!(
+            err(r#"{"ts":"99999999999999999999999999999999"}"#),
+            @"parameter 'nanosecond timestamp' with value 99999999999999999999999999999999 is not in the required range of -377705023201000000000..=253402207200999999999 at line 1 column 40",
+        );
+        insta::assert_snapshot!(
+            err(r#"{"ts":99999999999999999999}"#),
+            @"invalid type: floating point `1e+20`, expected an integer number of seconds, milliseconds, microseconds or nanoseconds from the Unix epoch or an RFC 3339 timestamp string at line 1 column 26",
+        );
+    }
+
+    #[test]
+    fn timestamp_flexible_millisecond_optional() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(
+                default,
+                with = "crate::fmt::serde::timestamp::flexible::millisecond::optional"
+            )]
+            ts: Option<Timestamp>,
+        }
+
+        let p = |json: &str| -> Option<Timestamp> {
+            serde_json::from_str::<Data>(json).unwrap().ts
+        };
+        let expected = Timestamp::from_millisecond(1517644800_123).unwrap();
+        assert_eq!(p(r#"{"ts":1517644800123}"#), Some(expected));
+        assert_eq!(p(r#"{"ts":"1517644800123"}"#), Some(expected));
+        assert_eq!(p(r#"{"ts":"2018-02-03T08:00:00.123Z"}"#), Some(expected));
+        assert_eq!(
+            p(r#"{"ts":86400000}"#),
+            Some(Timestamp::from_second(86400).unwrap()),
+        );
+        assert_eq!(p(r#"{"ts":null}"#), None);
+        assert_eq!(p(r#"{}"#), None);
+
+        let got = Data { ts: Some(expected) };
+        assert_eq!(
+            serde_json::to_string(&got).unwrap(),
+            r#"{"ts":"2018-02-03T08:00:00.123Z"}"#,
+        );
+        let got = Data { ts: None };
+        assert_eq!(serde_json::to_string(&got).unwrap(), r#"{"ts":null}"#);
+    }
+
+    #[test]
+    fn tz_iana() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(with = "crate::fmt::serde::tz::iana::required")]
+            tz: TimeZone,
+            #[serde(with = "crate::fmt::serde::tz::iana::optional")]
+            maybe: Option<TimeZone>,
+        }
+
+        let json = r#"{"tz":"America/New_York","maybe":null}"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.tz, TimeZone::get("America/New_York").unwrap());
+        assert_eq!(got.maybe, None);
+        assert_eq!(serde_json::to_string(&got).unwrap(), json);
+
+        let json = r#"{"tz":"UTC","maybe":"Europe/Paris"}"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.tz, TimeZone::UTC);
+        assert_eq!(got.maybe, Some(TimeZone::get("Europe/Paris").unwrap()));
+        assert_eq!(serde_json::to_string(&got).unwrap(), json);
+
+        let json = r#"{"tz":"EST5EDT,M3.2.0,M11.1.0","maybe":null}"#;
+        assert!(serde_json::from_str::<Data>(&json).is_err());
+        let json = r#"{"tz":"+05:30","maybe":null}"#;
+        assert!(serde_json::from_str::<Data>(&json).is_err());
+
+        let data = Data { tz: TimeZone::unknown(), maybe: None };
+        insta::assert_snapshot!(
+            serde_json::to_string(&data).unwrap_err(),
+            @"time zone Etc/Unknown has no IANA time zone identifier, and so can't be serialized as one",
+        );
+        let data = Data {
+            tz: TimeZone::UTC,
+            maybe: Some(TimeZone::fixed(crate::tz::offset(-5))),
+        };
+        insta::assert_snapshot!(
+            serde_json::to_string(&data).unwrap_err(),
+            @"time zone -05 has no IANA time zone identifier, and so can't be serialized as one",
+        );
+    }
+
+    #[test]
+    fn tz_posix() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(with = "crate::fmt::serde::tz::posix::required")]
+            tz: TimeZone,
+            #[serde(with = "crate::fmt::serde::tz::posix::optional")]
+            maybe: Option<TimeZone>,
+        }
+
+        let roundtrip = |tz: TimeZone| -> String {
+            let data = Data { tz, maybe: None };
+            let json = serde_json::to_string(&data).unwrap();
+            let got: Data = serde_json::from_str(&json).unwrap();
+            assert_eq!(serde_json::to_string(&got).unwrap(), json);
+            json
+        };
+
+        insta::assert_snapshot!(roundtrip(TimeZone::UTC), @r###"{"tz":"UTC0","maybe":null}"###);
+        insta::assert_snapshot!(
+            roundtrip(TimeZone::fixed(crate::tz::offset(-5))),
+            @r###"{"tz":"<-05>5","maybe":null}"###,
+        );
+        insta::assert_snapshot!(
+            roundtrip(TimeZone::fixed(
+                crate::tz::Offset::from_seconds(5 * 60 * 60 + 30 * 60 + 15)
+                    .unwrap()
+            )),
+            @r###"{"tz":"<+053015>-5:30:15","maybe":null}"###,
+        );
+        insta::assert_snapshot!(
+            roundtrip(TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap()),
+            @r###"{"tz":"EST5EDT,M3.2.0,M11.1.0","maybe":null}"###,
+        );
+
+        let data =
+            Data { tz: TimeZone::UTC, maybe: Some(TimeZone::unknown()) };
+        insta::assert_snapshot!(
+            serde_json::to_string(&data).unwrap_err(),
+            @"time zone Etc/Unknown has no POSIX time zone representation, and so can't be serialized as one",
+        );
+        let json = r#"{"tz":"America/New_York","maybe":null}"#;
+        assert!(serde_json::from_str::<Data>(&json).is_err());
+    }
+
+    #[test]
+    fn tz_posix_from_tzif() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(with = "crate::fmt::serde::tz::posix::required")]
+            tz: TimeZone,
+        }
+
+        let data = Data { tz: TimeZone::get("America/New_York").unwrap() };
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(json, r#"{"tz":"EST5EDT,M3.2.0,M11.1.0"}"#);
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.tz, TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap(),);
+    }
+
+    #[test]
+    fn tz_tzif() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(with = "crate::fmt::serde::tz::tzif::required")]
+            tz: TimeZone,
+            #[serde(with = "crate::fmt::serde::tz::tzif::optional")]
+            maybe: Option<TimeZone>,
+        }
+
+        let assert_same = |tz1: &TimeZone, tz2: &TimeZone| {
+            assert_eq!(tz1.iana_name(), tz2.iana_name());
+            assert_eq!(
+                tz1.projection_reliable_until(),
+                tz2.projection_reliable_until(),
+            );
+            let mut ts = Timestamp::from_second(-2_208_988_800).unwrap();
+            let end = Timestamp::from_second(4_102_444_800).unwrap();
+            while ts < end {
+                let (i1, i2) =
+                    (tz1.to_offset_info(ts), tz2.to_offset_info(ts));
+                assert_eq!(i1.offset(), i2.offset());
+                assert_eq!(i1.dst(), i2.dst());
+                assert_eq!(i1.abbreviation(), i2.abbreviation());
+                ts = ts
+                    .checked_add(SignedDuration::from_hours(24 * 7))
+                    .unwrap();
+            }
+            assert_eq!(
+                tz1.following(Timestamp::UNIX_EPOCH)
+                    .take(200)
+                    .map(|t| (t.timestamp(), t.offset()))
+                    .collect::<alloc::vec::Vec<_>>(),
+                tz2.following(Timestamp::UNIX_EPOCH)
+                    .take(200)
+                    .map(|t| (t.timestamp(), t.offset()))
+                    .collect::<alloc::vec::Vec<_>>(),
+            );
+        };
+
+        for name in [
+            "America/New_York",
+            "Europe/London",
+            "Australia/Sydney",
+            "Pacific/Honolulu",
+            "Asia/Kolkata",
+            "America/Sao_Paulo",
+        ] {
+            let tz = TimeZone::get(name).unwrap();
+            for tz in [tz.clone(), tz.without_lmt()] {
+                let data = Data { tz: tz.clone(), maybe: Some(tz.clone()) };
+                let json = serde_json::to_string(&data).unwrap();
+                let got: Data = serde_json::from_str(&json).unwrap();
+                assert_same(&tz, &got.tz);
+                assert_same(&tz, got.maybe.as_ref().unwrap());
+            }
+        }
+
+        let json = r#"{"tz":[null,[84,90]],"maybe":null}"#;
+        insta::assert_snapshot!(
+            serde_json::from_str::<Data>(&json).unwrap_err(),
+            @"failed to parse 32-bit header: invalid header: too short at line 1 column 20",
+        );
+
+        let data = Data { tz: TimeZone::UTC, maybe: None };
+        insta::assert_snapshot!(
+            serde_json::to_string(&data).unwrap_err(),
+            @"time zone UTC is not backed by TZif data, and so can't be serialized as TZif",
+        );
+    }
 }
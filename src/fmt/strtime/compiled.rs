@@ -0,0 +1,656 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::{
+    error::{err, ErrorContext},
+    fmt::{
+        strtime::{
+            format::Formatter, parse::Parser, BrokenDownTime, CLocale, Config,
+            Directive, Locale, DEFAULT_TWO_DIGIT_YEAR_BASE,
+        },
+        LeapSecond, Write,
+    },
+    util::{escape, parse},
+    Error,
+};
+
+/// A `strftime`/`strptime` format string that has been parsed ahead of time.
+///
+/// The routines in this module that accept a format string, like
+/// [`BrokenDownTime::format`] and [`BrokenDownTime::parse`], scan the format
+/// string for directives every time they are called. A `CompiledFormat`
+/// does that scan once, up front, and then formats or parses any number of
+/// values without repeating it. This is useful when the same format is used
+/// in a hot loop, like when processing log lines.
+///
+/// Compiling a format string also checks that every directive in it is
+/// supported by Jiff. When some aren't, the error returned lists all of
+/// them. In contrast, the routines accepting a format string only report an
+/// unsupported directive when formatting or parsing reaches it.
+///
+/// Otherwise, formatting and parsing with a `CompiledFormat` behaves exactly
+/// the same as with the format string it was compiled from. See the
+/// [module documentation](crate::fmt::strtime) for details on what's supported.
+///
+/// This type is only available when the `alloc` crate feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::date, fmt::strtime::{BrokenDownTime, CompiledFormat}};
+///
+/// let format = CompiledFormat::new("%Y-%m-%d %H:%M:%S %:z")?;
+///
+/// let lines = [
+///     "2024-07-14 21:14:00 -04:00",
+///     "2024-07-14 21:15:30 -04:00",
+/// ];
+/// let mut timestamps = vec![];
+/// for line in lines {
+///     timestamps.push(format.parse(line)?.to_timestamp()?);
+/// }
+/// assert_eq!(timestamps[1].as_second() - timestamps[0].as_second(), 90);
+///
+/// let zdt = date(2024, 7, 14).at(21, 14, 0, 0).in_tz("America/New_York")?;
+/// let tm = BrokenDownTime::from(&zdt);
+/// assert_eq!(format.to_string(&tm)?, "2024-07-14 21:14:00 -04:00");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Example: unsupported directives are reported when compiling
+///
+/// ```
+/// use jiff::fmt::strtime::CompiledFormat;
+///
+/// let err = CompiledFormat::new("%c and %Y and %:y").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "invalid strtime format string: \
+///      found unsupported directives %c, %:y",
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct CompiledFormat {
+    fmt: Box<[u8]>,
+    items: Box<[Item]>,
+}
+
+/// A single piece of a compiled format string.
+#[derive(Clone, Debug)]
+enum Item {
+    /// A range into the format string containing literal bytes. It never
+    /// contains a `%`.
+    Literal { start: usize, end: usize },
+    /// A directive, like `%-d` or `%:z`.
+    Directive(Directive),
+}
+
+impl CompiledFormat {
+    /// Compiles the given format string.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the format string is invalid, for example,
+    /// when it ends with a `%` or contains a width that is too big. This also
+    /// returns an error listing every directive in the format string that
+    /// isn't supported.
+    ///
+    /// Note that invalid UTF-8 in the literal parts of the format string is
+    /// not an error when compiling, since it is permitted when parsing.
+    /// Formatting with such a format string returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::strtime::CompiledFormat};
+    ///
+    /// let format = CompiledFormat::new("%A, %B %-d, %Y")?;
+    /// assert_eq!(
+    ///     format.to_string(&date(2024, 7, 14).into())?,
+    ///     "Sunday, July 14, 2024",
+    /// );
+    ///
+    /// assert!(CompiledFormat::new("%Y-%m-%").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(format: impl AsRef<[u8]>) -> Result<CompiledFormat, Error> {
+        CompiledFormat::new_mono(format.as_ref())
+            .context("invalid strtime format string")
+    }
+
+    /// Compiles the given [Unicode LDML] date pattern, like
+    /// `yyyy-MM-dd HH:mm`.
+    ///
+    /// LDML patterns are used by Java's `DateTimeFormatter`, ICU and many
+    /// JavaScript libraries. This routine translates the pattern into the
+    /// equivalent `strftime` format string, and then compiles that. The
+    /// translated format string is available via
+    /// [`CompiledFormat::as_bytes`].
+    ///
+    /// Text between single quotes is literal, and two consecutive single
+    /// quotes are a literal single quote. All other ASCII letters are pattern
+    /// fields, and everything else is literal. The fields supported are:
+    ///
+    /// | Field | Example | strftime | Description |
+    /// | ----- | ------- | -------- | ----------- |
+    /// | `y` | `2024` | `%-Y` | Year, without padding. |
+    /// | `yy` | `24` | `%y` | Two digit year. |
+    /// | `yyy`, `yyyy` | `2024` | `%03Y`, `%Y` | Year, padded. |
+    /// | `YY`, `YYYY` | `24`, `2024` | `%g`, `%G` | ISO 8601 week-based year. |
+    /// | `M`, `MM` | `7`, `07` | `%-m`, `%m` | Month number. |
+    /// | `MMM`, `MMMM` | `Jul`, `July` | `%b`, `%B` | Month name. |
+    /// | `w`, `ww` | `2`, `02` | `%-V`, `%V` | ISO 8601 week number. |
+    /// | `d`, `dd` | `5`, `05` | `%-d`, `%d` | Day of the month. |
+    /// | `D`, `DD`, `DDD` | `5`, `05`, `005` | `%-j`, `%02j`, `%j` | Day of the year. |
+    /// | `E`, `EE`, `EEE` | `Sun` | `%a` | Abbreviated weekday name. |
+    /// | `EEEE` | `Sunday` | `%A` | Full weekday name. |
+    /// | `a` | `PM` | `%p` | AM or PM. |
+    /// | `h`, `hh` | `9`, `09` | `%-I`, `%I` | Hour on a 12-hour clock. |
+    /// | `H`, `HH` | `9`, `09` | `%-H`, `%H` | Hour on a 24-hour clock. |
+    /// | `m`, `mm` | `5`, `05` | `%-M`, `%M` | Minute. |
+    /// | `s`, `ss` | `5`, `05` | `%-S`, `%S` | Second. |
+    /// | `S` up to `SSSSSSSSS` | `123` | `%1f` up to `%9f` | Fractional seconds. |
+    /// | `z`, `zz`, `zzz` | `EDT` | `%Z` | Time zone abbreviation. |
+    /// | `Z`, `ZZ`, `ZZZ`, `xx` | `-0400` | `%z` | Time zone offset. |
+    /// | `xxx` | `-04:00` | `%:z` | Time zone offset with a colon. |
+    /// | `VV` | `America/New_York` | `%Q` | IANA time zone identifier. |
+    ///
+    /// `L` is accepted as a synonym for `M`. For the weekday names, `eee`,
+    /// `eeee`, `ccc` and `cccc` are accepted as synonyms for `EEE` and
+    /// `EEEE`.
+    ///
+    /// Note that `w` and `Y` are always interpreted as ISO 8601 weeks, since
+    /// Jiff has no locale data for determining the first day of the week.
+    ///
+    /// [Unicode LDML]: https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the pattern contains a field that isn't
+    /// supported (for example, eras with `G`, narrow names like `MMMMM` or
+    /// offsets that use `Z` for UTC like `XXX`) or when a quote is unclosed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::strtime::CompiledFormat};
+    ///
+    /// let format = CompiledFormat::from_ldml("EEE, d MMM y 'at' HH:mm")?;
+    /// assert_eq!(format.as_bytes(), b"%a, %-d %b %-Y at %H:%M");
+    ///
+    /// let dt = date(2024, 7, 14).at(21, 14, 0, 0);
+    /// assert_eq!(format.to_string(&dt.into())?, "Sun, 14 Jul 2024 at 21:14");
+    /// assert_eq!(
+    ///     format.parse("Sun, 14 Jul 2024 at 21:14")?.to_datetime()?,
+    ///     dt,
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_ldml(
+        pattern: impl AsRef<[u8]>,
+    ) -> Result<CompiledFormat, Error> {
+        let fmt = super::ldml::to_strtime(pattern.as_ref())
+            .context("invalid LDML pattern")?;
+        CompiledFormat::new(fmt)
+    }
+
+    fn new_mono(fmt: &[u8]) -> Result<CompiledFormat, Error> {
+        let mut items = Vec::new();
+        let mut unsupported = Vec::new();
+        let mut rest = fmt;
+        while !rest.is_empty() {
+            let start = fmt.len() - rest.len();
+            if rest[0] != b'%' {
+                let len =
+                    rest.iter().position(|&b| b == b'%').unwrap_or(rest.len());
+                items.push(Item::Literal { start, end: start + len });
+                rest = &rest[len..];
+                continue;
+            }
+            if rest.len() == 1 {
+                return Err(err!(
+                    "invalid format string, expected byte after '%', \
+                     but found end of format string",
+                ));
+            }
+            let (directive, remaining) = Directive::parse(&rest[1..])?;
+            if !directive.is_supported() {
+                unsupported.push(directive);
+            }
+            items.push(Item::Directive(directive));
+            rest = remaining;
+        }
+        if !unsupported.is_empty() {
+            let mut list = String::new();
+            for (i, directive) in unsupported.iter().enumerate() {
+                if i > 0 {
+                    list.push_str(", ");
+                }
+                list.push_str(&alloc::format!("{directive}"));
+            }
+            return Err(err!("found unsupported directives {list}"));
+        }
+        Ok(CompiledFormat { fmt: fmt.into(), items: items.into() })
+    }
+
+    /// Returns the format string this was compiled from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::strtime::CompiledFormat;
+    ///
+    /// let format = CompiledFormat::new("%F %T")?;
+    /// assert_eq!(format.as_bytes(), b"%F %T");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.fmt
+    }
+
+    /// Parse the given `input` according to this format.
+    ///
+    /// This is like [`BrokenDownTime::parse`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the input didn't match this format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil, fmt::strtime::CompiledFormat};
+    ///
+    /// let format = CompiledFormat::new("%m/%d/%y")?;
+    /// let tm = format.parse("7/14/24")?;
+    /// assert_eq!(tm.to_date()?, civil::date(2024, 7, 14));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn parse(
+        &self,
+        input: impl AsRef<[u8]>,
+    ) -> Result<BrokenDownTime, Error> {
+        self.parse_full(
+            None,
+            DEFAULT_TWO_DIGIT_YEAR_BASE,
+            LeapSecond::Constrain,
+            input.as_ref(),
+        )
+    }
+
+    /// Parse a prefix of the given `input` according to this format. The
+    /// offset returned corresponds to the number of bytes parsed.
+    ///
+    /// This is like [`BrokenDownTime::parse_prefix`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the input didn't match this format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil, fmt::strtime::CompiledFormat};
+    ///
+    /// let format = CompiledFormat::new("%Y-%m-%d")?;
+    /// let input = "2024-01-20T17:55Z";
+    /// let (tm, offset) = format.parse_prefix(input)?;
+    /// assert_eq!(tm.to_date()?, civil::date(2024, 1, 20));
+    /// assert_eq!(&input[offset..], "T17:55Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn parse_prefix(
+        &self,
+        input: impl AsRef<[u8]>,
+    ) -> Result<(BrokenDownTime, usize), Error> {
+        let inp = input.as_ref();
+        let mkoffset = parse::offseter(inp);
+        let (tm, remaining) = self.parse_items(
+            None,
+            DEFAULT_TWO_DIGIT_YEAR_BASE,
+            LeapSecond::Constrain,
+            inp,
+        )?;
+        Ok((tm, mkoffset(remaining)))
+    }
+
+    /// Parse the given `input` according to this format, using the names
+    /// provided by the given configuration's [`Locale`].
+    ///
+    /// This is like [`BrokenDownTime::parse_with_config`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the input didn't match this format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil, fmt::strtime::{CompiledFormat, Config}};
+    ///
+    /// let format = CompiledFormat::new("%b %d %Y")?;
+    /// let tm = format.parse_with_config(&Config::new(), "Jul 14 2024")?;
+    /// assert_eq!(tm.to_date()?, civil::date(2024, 7, 14));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn parse_with_config<L: Locale>(
+        &self,
+        config: &Config<L>,
+        input: impl AsRef<[u8]>,
+    ) -> Result<BrokenDownTime, Error> {
+        self.parse_full(
+            Some(&config.locale),
+            config.two_digit_year_base,
+            config.leap_second,
+            input.as_ref(),
+        )
+    }
+
+    fn parse_full(
+        &self,
+        locale: Option<&dyn Locale>,
+        two_digit_year_base: i16,
+        leap_second: LeapSecond,
+        inp: &[u8],
+    ) -> Result<BrokenDownTime, Error> {
+        let (tm, remaining) =
+            self.parse_items(locale, two_digit_year_base, leap_second, inp)?;
+        if !remaining.is_empty() {
+            return Err(err!(
+                "strptime expects to consume the entire input, but \
+                 {remaining:?} remains unparsed",
+                remaining = escape::Bytes(remaining),
+            ));
+        }
+        Ok(tm)
+    }
+
+    fn parse_items<'i>(
+        &self,
+        locale: Option<&dyn Locale>,
+        two_digit_year_base: i16,
+        leap_second: LeapSecond,
+        inp: &'i [u8],
+    ) -> Result<(BrokenDownTime, &'i [u8]), Error> {
+        let mut tm = BrokenDownTime::default();
+        let mut p = Parser {
+            locale,
+            two_digit_year_base,
+            leap_second,
+            fmt: b"",
+            inp,
+            tm: &mut tm,
+        };
+        for item in self.items.iter() {
+            match *item {
+                Item::Literal { start, end } => {
+                    p.fmt = &self.fmt[start..end];
+                    p.parse().context("strptime parsing failed")?;
+                }
+                Item::Directive(directive) => {
+                    p.parse_directive(directive)
+                        .context("strptime parsing failed")?;
+                }
+            }
+        }
+        let remaining = p.inp;
+        Ok((tm, remaining))
+    }
+
+    /// Format the given broken down time according to this format.
+    ///
+    /// This is like [`BrokenDownTime::format`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when formatting requires a field in
+    /// `BrokenDownTime` to be set that isn't, when the literal parts of this
+    /// format aren't valid UTF-8, or when writing to the given writer fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::strtime::CompiledFormat};
+    ///
+    /// let format = CompiledFormat::new("%a %b %e %Y")?;
+    /// let mut buf = String::new();
+    /// for day in [8, 9] {
+    ///     format.format(&date(2024, 7, day).into(), &mut buf)?;
+    ///     buf.push('\n');
+    /// }
+    /// assert_eq!(buf, "Mon Jul  8 2024\nTue Jul  9 2024\n");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn format<W: Write>(
+        &self,
+        tm: &BrokenDownTime,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        self.format_with_config(&Config::new(), tm, &mut wtr)
+    }
+
+    /// Format the given broken down time according to this format, using
+    /// the names provided by the given configuration's [`Locale`].
+    ///
+    /// This is like [`BrokenDownTime::format_with_config`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same cases as
+    /// [`CompiledFormat::format`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::strtime::{CompiledFormat, Config}};
+    ///
+    /// let format = CompiledFormat::new("%B %Y")?;
+    /// let mut buf = String::new();
+    /// format.format_with_config(
+    ///     &Config::new(),
+    ///     &date(2024, 7, 14).into(),
+    ///     &mut buf,
+    /// )?;
+    /// assert_eq!(buf, "July 2024");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn format_with_config<L: Locale, W: Write>(
+        &self,
+        config: &Config<L>,
+        tm: &BrokenDownTime,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        self.format_items(&config.locale, tm, &mut wtr)
+            .context("strftime formatting failed")
+    }
+
+    fn format_items<W: Write>(
+        &self,
+        locale: &dyn Locale,
+        tm: &BrokenDownTime,
+        wtr: &mut W,
+    ) -> Result<(), Error> {
+        let mut f = Formatter { locale, fmt: b"", tm, wtr };
+        for item in self.items.iter() {
+            match *item {
+                Item::Literal { start, end } => {
+                    f.fmt = &self.fmt[start..end];
+                    f.format()?;
+                }
+                Item::Directive(directive) => f.format_directive(directive)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Format the given broken down time according to this format into a
+    /// new `String`.
+    ///
+    /// This is like [`BrokenDownTime::to_string`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when formatting requires a field in
+    /// `BrokenDownTime` to be set that isn't, or when the literal parts of
+    /// this format aren't valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, fmt::strtime::CompiledFormat};
+    ///
+    /// let format = CompiledFormat::new("%-I:%M %p")?;
+    /// assert_eq!(format.to_string(&time(21, 14, 0, 0).into())?, "9:14 PM");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_string(&self, tm: &BrokenDownTime) -> Result<String, Error> {
+        let mut buf = String::new();
+        self.format_items(&CLocale, tm, &mut buf)
+            .context("strftime formatting failed")?;
+        Ok(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{
+        civil::date,
+        fmt::strtime::{self, BrokenDownTime},
+    };
+
+    use super::*;
+
+    #[test]
+    fn same_as_uncompiled() {
+        let zdt = date(2024, 7, 14)
+            .at(21, 14, 5, 123_000_000)
+            .to_zoned(crate::tz::TimeZone::fixed(crate::tz::offset(-4)))
+            .unwrap();
+        let tm = BrokenDownTime::from(&zdt);
+        let formats = [
+            "%Y-%m-%dT%H:%M:%S%.f%:z",
+            "%a, %-d %b %Y %T %z",
+            "%A %B %e %I:%M:%S %p %Z %%",
+            "%^10A|%-j|%_5d|%#P|%3f|%.6f",
+            "%D %x %X %R %F %s %u %w %V %U %W %G %g %C %y %k %l",
+            "literal ☃ only",
+            "",
+        ];
+        for format in formats {
+            let compiled = CompiledFormat::new(format).unwrap();
+            let expected = tm.to_string(format).unwrap();
+            assert_eq!(compiled.to_string(&tm).unwrap(), expected);
+            assert_eq!(compiled.as_bytes(), format.as_bytes());
+
+            let parsed = compiled.parse(&expected);
+            let expected_parsed = strtime::parse(format, &expected);
+            match (parsed, expected_parsed) {
+                (Ok(got), Ok(expected)) => {
+                    assert_eq!(
+                        alloc::format!("{got:?}"),
+                        alloc::format!("{expected:?}"),
+                    );
+                }
+                (Err(got), Err(expected)) => {
+                    assert_eq!(got.to_string(), expected.to_string());
+                }
+                (got, expected) => {
+                    panic!("mismatch for {format:?}: {got:?} != {expected:?}")
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_prefix_and_whitespace() {
+        let compiled = CompiledFormat::new("%Y %m %d").unwrap();
+        let input = "2024  07\t14xyz";
+        let (tm, offset) = compiled.parse_prefix(input).unwrap();
+        assert_eq!(tm.to_date().unwrap(), date(2024, 7, 14));
+        assert_eq!(&input[offset..], "xyz");
+        let (_, expected) =
+            BrokenDownTime::parse_prefix("%Y %m %d", input).unwrap();
+        assert_eq!(offset, expected);
+    }
+
+    #[test]
+    fn err_compile() {
+        let c = |format: &str| CompiledFormat::new(format).unwrap_err();
+
+        insta::assert_snapshot!(c("%c"), @"invalid strtime format string: found unsupported directives %c");
+        insta::assert_snapshot!(c("%Y %E %O %+ %r"), @"invalid strtime format string: found unsupported directives %E, %O, %+, %r");
+        insta::assert_snapshot!(c("%:y %.a %Ez"), @"invalid strtime format string: found unsupported directives %:y, %.a, %E");
+        insta::assert_snapshot!(c("%Y%"), @"invalid strtime format string: invalid format string, expected byte after '%', but found end of format string");
+        insta::assert_snapshot!(c("%:"), @"invalid strtime format string: invalid format string, expected directive after '%:'");
+        insta::assert_snapshot!(c("%-"), @r###"invalid strtime format string: expected to find specifier directive after flag "-", but found end of format string"###);
+        insta::assert_snapshot!(c("%999d"), @"invalid strtime format string: 999 is too big, max is 255");
+    }
+
+    #[test]
+    fn err_format_and_parse() {
+        let compiled = CompiledFormat::new("%Y-%m-%d %z").unwrap();
+        insta::assert_snapshot!(
+            compiled.to_string(&date(2024, 7, 14).into()).unwrap_err(),
+            @"strftime formatting failed: %z failed: requires offset to format time zone offset",
+        );
+        insta::assert_snapshot!(
+            compiled.parse("2024-07-14 +0400 extra").unwrap_err(),
+            @r###"strptime expects to consume the entire input, but " extra" remains unparsed"###,
+        );
+        insta::assert_snapshot!(
+            compiled.parse("2024-07-14").unwrap_err(),
+            @"strptime parsing failed: expected non-empty input for directive %z, but found end of input",
+        );
+
+        let compiled = CompiledFormat::new(b"%Y \xFF").unwrap();
+        insta::assert_snapshot!(
+            compiled.to_string(&date(2024, 7, 14).into()).unwrap_err(),
+            @r###"strftime formatting failed: found invalid UTF-8 byte "\xFF" in format string (format strings must be valid UTF-8)"###,
+        );
+        assert_eq!(compiled.parse(b"2024 \xFF").unwrap().year(), Some(2024));
+    }
+
+    #[test]
+    fn ldml_roundtrip() {
+        let zdt = date(2024, 7, 4)
+            .at(9, 5, 3, 123_456_789)
+            .to_zoned(crate::tz::TimeZone::fixed(crate::tz::offset(-4)))
+            .unwrap();
+        let tm = BrokenDownTime::from(&zdt);
+        for pattern in [
+            "yyyy-MM-dd'T'HH:mm:ss.SSSSSSSSSxxx",
+            "EEEE, MMMM d, y h:mm:ss.SSSSSSSSS a ZZ",
+            "YYYY-'W'ww-EEE HH:mm:ss.SSSSSSSSS Z",
+        ] {
+            let compiled = CompiledFormat::from_ldml(pattern).unwrap();
+            let printed = compiled.to_string(&tm).unwrap();
+            let parsed = compiled.parse(&printed).unwrap();
+            assert_eq!(parsed.to_timestamp().unwrap(), zdt.timestamp());
+        }
+
+        let compiled =
+            CompiledFormat::from_ldml("d/M/yy H:mm:ss.SSS").unwrap();
+        insta::assert_snapshot!(compiled.to_string(&tm).unwrap(), @"4/7/24 9:05:03.123");
+        insta::assert_snapshot!(
+            CompiledFormat::from_ldml("EEEEE").unwrap_err(),
+            @"invalid LDML pattern: found unsupported LDML field `EEEEE` in pattern",
+        );
+    }
+}
@@ -2,8 +2,8 @@ use crate::{
     error::{err, ErrorContext},
     fmt::{
         strtime::{
-            month_name_abbrev, month_name_full, weekday_name_abbrev,
-            weekday_name_full, BrokenDownTime, Extension, Flag,
+            BrokenDownTime, Directive, Extension, Flag, Locale, Meridiem,
+            Prefix,
         },
         util::{DecimalFormatter, FractionalFormatter},
         Write, WriteExt,
@@ -13,13 +13,14 @@ use crate::{
     Error,
 };
 
-pub(super) struct Formatter<'f, 't, 'w, W> {
+pub(super) struct Formatter<'c, 'f, 't, 'w, W> {
+    pub(super) locale: &'c dyn Locale,
     pub(super) fmt: &'f [u8],
     pub(super) tm: &'t BrokenDownTime,
     pub(super) wtr: &'w mut W,
 }
 
-impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
+impl<'c, 'f, 't, 'w, W: Write> Formatter<'c, 'f, 't, 'w, W> {
     pub(super) fn format(&mut self) -> Result<(), Error> {
         while !self.fmt.is_empty() {
             if self.f() != b'%' {
@@ -38,9 +39,21 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
                      but found end of format string",
                 ));
             }
-            // Parse extensions like padding/case options and padding width.
-            let ext = self.parse_extension()?;
-            match self.f() {
+            let (directive, fmt) = Directive::parse(self.fmt)?;
+            self.fmt = fmt;
+            self.format_directive(directive)?;
+        }
+        Ok(())
+    }
+
+    /// Formats a single directive, like `%-d` or `%:z`.
+    pub(super) fn format_directive(
+        &mut self,
+        directive: Directive,
+    ) -> Result<(), Error> {
+        let ext = directive.ext;
+        match directive.prefix {
+            None => match directive.specifier {
                 b'%' => self.wtr.write_str("%").context("%% failed")?,
                 b'A' => self.fmt_weekday_full(ext).context("%A failed")?,
                 b'a' => self.fmt_weekday_abbrev(ext).context("%a failed")?,
@@ -76,62 +89,38 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
                 b'V' => self.fmt_week_iso(ext).context("%V failed")?,
                 b'W' => self.fmt_week_mon(ext).context("%W failed")?,
                 b'w' => self.fmt_weekday_sun(ext).context("%w failed")?,
+                b'X' => self.fmt_clock_secs(ext).context("%X failed")?,
+                b'x' => self.fmt_american_date(ext).context("%x failed")?,
                 b'Y' => self.fmt_year(ext).context("%Y failed")?,
                 b'y' => self.fmt_year2(ext).context("%y failed")?,
                 b'Z' => self.fmt_tzabbrev(ext).context("%Z failed")?,
                 b'z' => self.fmt_offset_nocolon().context("%z failed")?,
-                b':' => {
-                    if !self.bump_fmt() {
-                        return Err(err!(
-                            "invalid format string, expected directive \
-                             after '%:'",
-                        ));
-                    }
-                    match self.f() {
-                        b'Q' => self.fmt_iana_colon().context("%:Q failed")?,
-                        b'z' => {
-                            self.fmt_offset_colon().context("%:z failed")?
-                        }
-                        unk => {
-                            return Err(err!(
-                                "found unrecognized directive %{unk} \
-                                 following %:",
-                                unk = escape::Byte(unk),
-                            ));
-                        }
-                    }
+                unk => {
+                    return Err(err!(
+                        "found unrecognized specifier directive %{unk}",
+                        unk = escape::Byte(unk),
+                    ));
                 }
-                b'.' => {
-                    if !self.bump_fmt() {
-                        return Err(err!(
-                            "invalid format string, expected directive \
-                             after '%.'",
-                        ));
-                    }
-                    // Parse precision settings after the `.`, effectively
-                    // overriding any digits that came before it.
-                    let ext = Extension { width: self.parse_width()?, ..ext };
-                    match self.f() {
-                        b'f' => self
-                            .fmt_dot_fractional(ext)
-                            .context("%.f failed")?,
-                        unk => {
-                            return Err(err!(
-                                "found unrecognized directive %{unk} \
-                                 following %.",
-                                unk = escape::Byte(unk),
-                            ));
-                        }
-                    }
+            },
+            Some(Prefix::Colon) => match directive.specifier {
+                b'Q' => self.fmt_iana_colon().context("%:Q failed")?,
+                b'z' => self.fmt_offset_colon().context("%:z failed")?,
+                unk => {
+                    return Err(err!(
+                        "found unrecognized directive %{unk} following %:",
+                        unk = escape::Byte(unk),
+                    ));
                 }
+            },
+            Some(Prefix::Dot) => match directive.specifier {
+                b'f' => self.fmt_dot_fractional(ext).context("%.f failed")?,
                 unk => {
                     return Err(err!(
-                        "found unrecognized specifier directive %{unk}",
+                        "found unrecognized directive %{unk} following %.",
                         unk = escape::Byte(unk),
                     ));
                 }
-            }
-            self.bump_fmt();
+            },
         }
         Ok(())
     }
@@ -183,36 +172,15 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
         }
     }
 
-    /// Parses optional extensions before a specifier directive. That is, right
-    /// after the `%`. If any extensions are parsed, the parser is bumped
-    /// to the next byte. (If no next byte exists, then an error is returned.)
-    fn parse_extension(&mut self) -> Result<Extension, Error> {
-        let flag = self.parse_flag()?;
-        let width = self.parse_width()?;
-        Ok(Extension { flag, width })
-    }
-
-    /// Parses an optional flag. And if one is parsed, the parser is bumped
-    /// to the next byte. (If no next byte exists, then an error is returned.)
-    fn parse_flag(&mut self) -> Result<Option<Flag>, Error> {
-        let (flag, fmt) = Extension::parse_flag(self.fmt)?;
-        self.fmt = fmt;
-        Ok(flag)
-    }
-
-    /// Parses an optional width that comes after a (possibly absent) flag and
-    /// before the specifier directive itself. And if a width is parsed, the
-    /// parser is bumped to the next byte. (If no next byte exists, then an
-    /// error is returned.)
-    ///
-    /// Note that this is also used to parse precision settings for `%f` and
-    /// `%.f`. In the former case, the width is just re-interpreted as a
-    /// precision setting. In the latter case, something like `%5.9f` is
-    /// technically valid, but the `5` is ignored.
-    fn parse_width(&mut self) -> Result<Option<u8>, Error> {
-        let (width, fmt) = Extension::parse_width(self.fmt)?;
-        self.fmt = fmt;
-        Ok(width)
+    /// Returns whether the broken down time is before or after noon, for
+    /// `%p` and `%P`.
+    fn meridiem(&self) -> Result<Meridiem, Error> {
+        let hour = self
+            .tm
+            .hour
+            .ok_or_else(|| err!("requires time to format AM/PM"))?
+            .get();
+        Ok(if hour < 12 { Meridiem::AM } else { Meridiem::PM })
     }
 
     // These are the formatting functions. They are pretty much responsible
@@ -222,30 +190,28 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
 
     /// %P
     fn fmt_ampm_lower(&mut self, ext: Extension) -> Result<(), Error> {
-        let hour = self
-            .tm
-            .hour
-            .ok_or_else(|| err!("requires time to format AM/PM"))?
-            .get();
-        ext.write_str(
-            Case::AsIs,
-            if hour < 12 { "am" } else { "pm" },
-            self.wtr,
-        )
+        let meridiem = self.meridiem()?;
+        // Like glibc, `%P` is always lowercase unless uppercase is
+        // explicitly requested. Swapping case has no effect.
+        let ext = match ext.flag {
+            Some(Flag::Swapcase) => Extension { flag: None, ..ext },
+            _ => ext,
+        };
+        ext.write_str(Case::Lower, self.locale.meridiem(meridiem), self.wtr)
     }
 
     /// %p
     fn fmt_ampm_upper(&mut self, ext: Extension) -> Result<(), Error> {
-        let hour = self
-            .tm
-            .hour
-            .ok_or_else(|| err!("requires time to format AM/PM"))?
-            .get();
-        ext.write_str(
-            Case::Upper,
-            if hour < 12 { "AM" } else { "PM" },
-            self.wtr,
-        )
+        let meridiem = self.meridiem()?;
+        // Like glibc, swapping the case of `%p` always lowercases it, since
+        // the locale's AM/PM designation is written as is by default.
+        let (default, ext) = match ext.flag {
+            Some(Flag::Swapcase) => {
+                (Case::Lower, Extension { flag: None, ..ext })
+            }
+            _ => (Case::AsIs, ext),
+        };
+        ext.write_str(default, self.locale.meridiem(meridiem), self.wtr)
     }
 
     /// %D
@@ -386,7 +352,8 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
             .month
             .or_else(|| self.tm.to_date().ok().map(|d| d.month_ranged()))
             .ok_or_else(|| err!("requires date to format month"))?;
-        ext.write_str(Case::AsIs, month_name_full(month), self.wtr)
+        let name = self.locale.month_name_full(month.get());
+        ext.write_str(Case::AsIs, name, self.wtr)
     }
 
     /// %b, %h
@@ -396,7 +363,8 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
             .month
             .or_else(|| self.tm.to_date().ok().map(|d| d.month_ranged()))
             .ok_or_else(|| err!("requires date to format month"))?;
-        ext.write_str(Case::AsIs, month_name_abbrev(month), self.wtr)
+        let name = self.locale.month_name_abbrev(month.get());
+        ext.write_str(Case::AsIs, name, self.wtr)
     }
 
     /// %Q
@@ -449,9 +417,8 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
     fn fmt_second(&mut self, ext: Extension) -> Result<(), Error> {
         let second = self
             .tm
-            .second
-            .ok_or_else(|| err!("requires time to format second"))?
-            .get();
+            .second()
+            .ok_or_else(|| err!("requires time to format second"))?;
         ext.write_int(b'0', Some(2), second, self.wtr)
     }
 
@@ -494,7 +461,7 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
         if subsec == C(0) && ext.width.is_none() || ext.width == Some(0) {
             return Ok(());
         }
-        ext.write_str(Case::AsIs, ".", self.wtr)?;
+        self.wtr.write_str(".")?;
         ext.write_fractional_seconds(subsec, self.wtr)?;
         Ok(())
     }
@@ -514,7 +481,8 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
             .weekday
             .or_else(|| self.tm.to_date().ok().map(|d| d.weekday()))
             .ok_or_else(|| err!("requires date to format weekday"))?;
-        ext.write_str(Case::AsIs, weekday_name_full(weekday), self.wtr)
+        let name = self.locale.weekday_name_full(weekday);
+        ext.write_str(Case::AsIs, name, self.wtr)
     }
 
     /// %a
@@ -524,7 +492,8 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
             .weekday
             .or_else(|| self.tm.to_date().ok().map(|d| d.weekday()))
             .ok_or_else(|| err!("requires date to format weekday"))?;
-        ext.write_str(Case::AsIs, weekday_name_abbrev(weekday), self.wtr)
+        let name = self.locale.weekday_name_abbrev(weekday);
+        ext.write_str(Case::AsIs, name, self.wtr)
     }
 
     /// %u
@@ -755,6 +724,10 @@ fn write_offset<W: Write>(
 impl Extension {
     /// Writes the given string using the default case rule provided, unless
     /// an option in this extension config overrides the default case.
+    ///
+    /// When a width is given, the string is padded to the left with spaces
+    /// (or zeros with the `0` flag) until it's at least that many characters
+    /// long. The `-` flag disables padding.
     fn write_str<W: Write>(
         self,
         default: Case,
@@ -766,6 +739,19 @@ impl Extension {
             Some(Flag::Swapcase) => default.swap(),
             _ => default,
         };
+        if let Some(width) = self.width {
+            let pad = match self.flag {
+                Some(Flag::NoPad) => None,
+                Some(Flag::PadZero) => Some('0'),
+                _ => Some(' '),
+            };
+            if let Some(pad) = pad {
+                let len = case.len(string);
+                for _ in len..usize::from(width) {
+                    wtr.write_char(pad)?;
+                }
+            }
+        }
         match case {
             Case::AsIs => {
                 wtr.write_str(string)?;
@@ -849,14 +835,24 @@ impl Case {
             Case::Lower => Case::Upper,
         }
     }
+
+    /// Returns the number of characters in the given string after
+    /// converting it to this case.
+    fn len(self, string: &str) -> usize {
+        match self {
+            Case::AsIs => string.chars().count(),
+            Case::Upper => string.chars().flat_map(char::to_uppercase).count(),
+            Case::Lower => string.chars().flat_map(char::to_lowercase).count(),
+        }
+    }
 }
 
 #[cfg(feature = "alloc")]
 #[cfg(test)]
 mod tests {
     use crate::{
-        civil::{date, time, Date, DateTime, Time},
-        fmt::strtime::format,
+        civil::{date, time, Date, DateTime, Time, Weekday},
+        fmt::strtime::{format, BrokenDownTime, Config, Locale, Meridiem},
         Timestamp, Zoned,
     };
 
@@ -868,6 +864,8 @@ mod tests {
         insta::assert_snapshot!(f("%-D", date(2024, 7, 9)), @"7/9/24");
         insta::assert_snapshot!(f("%3D", date(2024, 7, 9)), @"007/009/024");
         insta::assert_snapshot!(f("%03D", date(2024, 7, 9)), @"007/009/024");
+        insta::assert_snapshot!(f("%x", date(2024, 7, 9)), @"07/09/24");
+        insta::assert_snapshot!(f("%-x", date(2024, 7, 9)), @"7/9/24");
     }
 
     #[test]
@@ -885,6 +883,7 @@ mod tests {
         insta::assert_snapshot!(f("%H%p", time(0, 0, 0, 0)), @"00AM");
 
         insta::assert_snapshot!(f("%H%#p", time(9, 0, 0, 0)), @"09am");
+        insta::assert_snapshot!(f("%H%4p", time(9, 0, 0, 0)), @"09  AM");
     }
 
     #[test]
@@ -893,6 +892,7 @@ mod tests {
 
         insta::assert_snapshot!(f("%R", time(23, 59, 8, 0)), @"23:59");
         insta::assert_snapshot!(f("%T", time(23, 59, 8, 0)), @"23:59:08");
+        insta::assert_snapshot!(f("%X", time(23, 59, 8, 0)), @"23:59:08");
     }
 
     #[test]
@@ -1103,6 +1103,14 @@ mod tests {
         insta::assert_snapshot!(f("%#A", date(2024, 7, 14)), @"Sunday");
         insta::assert_snapshot!(f("%^A", date(2024, 7, 14)), @"SUNDAY");
 
+        insta::assert_snapshot!(f("%10A", date(2024, 7, 14)), @"    Sunday");
+        insta::assert_snapshot!(f("%_10A", date(2024, 7, 14)), @"    Sunday");
+        insta::assert_snapshot!(f("%010A", date(2024, 7, 14)), @"0000Sunday");
+        insta::assert_snapshot!(f("%-10A", date(2024, 7, 14)), @"Sunday");
+        insta::assert_snapshot!(f("%^5a", date(2024, 7, 14)), @"  SUN");
+        insta::assert_snapshot!(f("%2A", date(2024, 7, 14)), @"Sunday");
+        insta::assert_snapshot!(f("%5b %3e", date(2024, 7, 4)), @"  Jul   4");
+
         insta::assert_snapshot!(f("%u", date(2024, 7, 14)), @"7");
         insta::assert_snapshot!(f("%w", date(2024, 7, 14)), @"0");
     }
@@ -1197,4 +1205,66 @@ mod tests {
             @"strftime formatting failed: %s failed: requires instant (a date, time and offset) to format Unix timestamp",
         );
     }
+
+    #[test]
+    fn ok_format_locale() {
+        struct German;
+
+        impl Locale for German {
+            fn month_name_full(&self, month: i8) -> &str {
+                ["Januar", "Februar", "März"]
+                    [usize::try_from(month - 1).unwrap().min(2)]
+            }
+
+            fn month_name_abbrev(&self, month: i8) -> &str {
+                ["Jan", "Feb", "Mär"]
+                    [usize::try_from(month - 1).unwrap().min(2)]
+            }
+
+            fn weekday_name_full(&self, weekday: Weekday) -> &str {
+                match weekday {
+                    Weekday::Sunday => "Sonntag",
+                    _ => "Werktag",
+                }
+            }
+
+            fn weekday_name_abbrev(&self, weekday: Weekday) -> &str {
+                match weekday {
+                    Weekday::Sunday => "So",
+                    _ => "Wt",
+                }
+            }
+
+            fn meridiem(&self, meridiem: Meridiem) -> &str {
+                match meridiem {
+                    Meridiem::AM => "vorm.",
+                    Meridiem::PM => "nachm.",
+                }
+            }
+        }
+
+        let config = Config::new().locale(German);
+        let f = |fmt: &str, tm: BrokenDownTime| {
+            tm.to_string_with_config(&config, fmt).unwrap()
+        };
+
+        let dt = date(2024, 3, 10).at(15, 0, 0, 0);
+        insta::assert_snapshot!(f("%A, %d. %B %Y", dt.into()), @"Sonntag, 10. März 2024");
+        insta::assert_snapshot!(f("%a %b", dt.into()), @"So Mär");
+        insta::assert_snapshot!(f("%^A %^B %#a", dt.into()), @"SONNTAG MÄRZ So");
+        insta::assert_snapshot!(f("%10B|%-10B|%010b", dt.into()), @"      März|März|0000000Mär");
+        insta::assert_snapshot!(f("%I %p", dt.into()), @"03 nachm.");
+        insta::assert_snapshot!(f("%I %P", dt.into()), @"03 nachm.");
+        insta::assert_snapshot!(f("%#p %#P %^p %^P", dt.into()), @"nachm. nachm. NACHM. NACHM.");
+        insta::assert_snapshot!(f("%8p|", dt.into()), @"  nachm.|");
+
+        // The default configuration is the same as not using one at all.
+        let tm = BrokenDownTime::from(date(2024, 7, 9).at(9, 0, 0, 0));
+        let fmt = "%A %a %B %b %p %P %#p %#P %^p %^P %^a %#B";
+        assert_eq!(
+            tm.to_string_with_config(&Config::new(), fmt).unwrap(),
+            tm.to_string(fmt).unwrap(),
+        );
+        insta::assert_snapshot!(tm.to_string(fmt).unwrap(), @"Tuesday Tue July Jul AM am am am AM AM TUE July");
+    }
 }
@@ -0,0 +1,179 @@
+/*!
+Translation of Unicode LDML date patterns into `strftime` format strings.
+
+LDML patterns (like `yyyy-MM-dd HH:mm`) are what Java's `DateTimeFormatter`,
+ICU and a number of JavaScript libraries use. Rather than implementing a
+second formatter and parser, we translate each pattern field into the
+equivalent `strftime` directive and let the usual machinery do the work.
+
+The reference for the syntax is here:
+<https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table>
+*/
+
+use alloc::string::String;
+
+use crate::{error::err, util::escape, Error};
+
+/// Translates the given LDML pattern into a `strftime` format string.
+///
+/// Literal text in the pattern is escaped such that it is never interpreted
+/// as a directive.
+pub(super) fn to_strtime(pattern: &[u8]) -> Result<String, Error> {
+    let mut out = String::new();
+    let mut rest = pattern;
+    while let Some(&byte) = rest.first() {
+        if byte == b'\'' {
+            rest = quoted(&rest[1..], &mut out)?;
+            continue;
+        }
+        if !byte.is_ascii_alphabetic() {
+            // Multi-byte UTF-8 sequences never contain ASCII bytes, so this
+            // never splits a codepoint.
+            let len = rest
+                .iter()
+                .position(|&b| b == b'\'' || b.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            literal(&rest[..len], &mut out)?;
+            rest = &rest[len..];
+            continue;
+        }
+        let count = rest.iter().take_while(|&&b| b == byte).count();
+        out.push_str(field(byte, count)?);
+        rest = &rest[count..];
+    }
+    Ok(out)
+}
+
+/// Handles quoted literal text. `rest` should start immediately after the
+/// opening quote, and the remaining pattern after the closing quote is
+/// returned.
+///
+/// Two consecutive quotes (inside or outside of quoted text) are a literal
+/// quote.
+fn quoted<'p>(
+    mut rest: &'p [u8],
+    out: &mut String,
+) -> Result<&'p [u8], Error> {
+    if rest.first() == Some(&b'\'') {
+        out.push('\'');
+        return Ok(&rest[1..]);
+    }
+    loop {
+        let Some(end) = rest.iter().position(|&b| b == b'\'') else {
+            return Err(err!(
+                "found unclosed quote in LDML pattern, \
+                 expected a closing `'`",
+            ));
+        };
+        literal(&rest[..end], out)?;
+        rest = &rest[end + 1..];
+        if rest.first() != Some(&b'\'') {
+            return Ok(rest);
+        }
+        out.push('\'');
+        rest = &rest[1..];
+    }
+}
+
+/// Copies literal text into the format string, escaping `%`.
+fn literal(text: &[u8], out: &mut String) -> Result<(), Error> {
+    let text = core::str::from_utf8(text).map_err(|_| {
+        err!(
+            "found invalid UTF-8 in LDML pattern literal {text:?}",
+            text = escape::Bytes(text),
+        )
+    })?;
+    for ch in text.chars() {
+        if ch == '%' {
+            out.push_str("%%");
+        } else {
+            out.push(ch);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the `strftime` directive for `count` repetitions of the given
+/// LDML field letter.
+fn field(letter: u8, count: usize) -> Result<&'static str, Error> {
+    let directive = match (letter, count) {
+        (b'y', 1) => "%-Y",
+        (b'y', 2) => "%y",
+        (b'y', 3) => "%03Y",
+        (b'y', 4) => "%Y",
+        (b'Y', 2) => "%g",
+        (b'Y', 4) => "%G",
+        (b'M' | b'L', 1) => "%-m",
+        (b'M' | b'L', 2) => "%m",
+        (b'M' | b'L', 3) => "%b",
+        (b'M' | b'L', 4) => "%B",
+        (b'w', 1) => "%-V",
+        (b'w', 2) => "%V",
+        (b'd', 1) => "%-d",
+        (b'd', 2) => "%d",
+        (b'D', 1) => "%-j",
+        (b'D', 2) => "%02j",
+        (b'D', 3) => "%j",
+        (b'E', 1..=3) | (b'e' | b'c', 3) => "%a",
+        (b'E' | b'e' | b'c', 4) => "%A",
+        (b'a', 1..=3) => "%p",
+        (b'h', 1) => "%-I",
+        (b'h', 2) => "%I",
+        (b'H', 1) => "%-H",
+        (b'H', 2) => "%H",
+        (b'm', 1) => "%-M",
+        (b'm', 2) => "%M",
+        (b's', 1) => "%-S",
+        (b's', 2) => "%S",
+        (b'S', 1) => "%1f",
+        (b'S', 2) => "%2f",
+        (b'S', 3) => "%3f",
+        (b'S', 4) => "%4f",
+        (b'S', 5) => "%5f",
+        (b'S', 6) => "%6f",
+        (b'S', 7) => "%7f",
+        (b'S', 8) => "%8f",
+        (b'S', 9) => "%9f",
+        (b'z', 1..=3) => "%Z",
+        (b'Z', 1..=3) | (b'x', 2) => "%z",
+        (b'x', 3) => "%:z",
+        (b'V', 2) => "%Q",
+        _ => {
+            let field: String =
+                core::iter::repeat(char::from(letter)).take(count).collect();
+            return Err(err!(
+                "found unsupported LDML field `{field}` in pattern",
+            ));
+        }
+    };
+    Ok(directive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ok_to_strtime() {
+        let t = |pattern: &str| to_strtime(pattern.as_bytes()).unwrap();
+
+        insta::assert_snapshot!(t("yyyy-MM-dd HH:mm"), @"%Y-%m-%d %H:%M");
+        insta::assert_snapshot!(t("EEE, d MMM y"), @"%a, %-d %b %-Y");
+        insta::assert_snapshot!(t("EEEE, MMMM d, yyyy h:mm a"), @"%A, %B %-d, %Y %-I:%M %p");
+        insta::assert_snapshot!(t("yyyy-MM-dd'T'HH:mm:ss.SSSxxx"), @"%Y-%m-%dT%H:%M:%S.%3f%:z");
+        insta::assert_snapshot!(t("YYYY-'W'ww"), @"%G-W%V");
+        insta::assert_snapshot!(t("h 'o''clock' a, zzz"), @"%-I o'clock %p, %Z");
+        insta::assert_snapshot!(t("''yy 100%"), @"'%y 100%%");
+        insta::assert_snapshot!(t("d. MMMM yyyy 'à' HH'h'"), @"%-d. %B %Y à %Hh");
+    }
+
+    #[test]
+    fn err_to_strtime() {
+        let t = |pattern: &str| to_strtime(pattern.as_bytes()).unwrap_err();
+
+        insta::assert_snapshot!(t("G yyyy"), @"found unsupported LDML field `G` in pattern");
+        insta::assert_snapshot!(t("MMMMM"), @"found unsupported LDML field `MMMMM` in pattern");
+        insta::assert_snapshot!(t("yyyy-MM-dd XXX"), @"found unsupported LDML field `XXX` in pattern");
+        insta::assert_snapshot!(t("'T"), @"found unclosed quote in LDML pattern, expected a closing `'`");
+    }
+}
@@ -8,9 +8,11 @@ for the formatting machinery to precisely match POSIX semantics.
 If there is a conversion specifier you need that Jiff doesn't support, please
 [create a new issue][create-issue].
 
-The formatting and parsing in this module does not currently support any
-form of localization. Please see [this issue][locale] about the topic of
-localization in Jiff.
+By default, the formatting and parsing in this module uses English names
+for months, weekdays and AM/PM. Other names can be provided via a [`Locale`]
+implementation. See [Locales](#locales) for details. Otherwise, this module
+does not support any other form of localization. Please see
+[this issue][locale] about the topic of localization in Jiff.
 
 [create-issue]: https://github.com/BurntSushi/jiff/issues/new
 [locale]: https://github.com/BurntSushi/jiff/issues/4
@@ -191,9 +193,11 @@ strings, the strings are matched without regard to ASCII case.
 | `%V` | `05` | Week number in the [ISO 8601 week-based] calendar. Zero padded. |
 | `%W` | `03` | Week number. Week 1 is the first week starting with a Monday. Zero padded. |
 | `%w` | `0` | The day of the week beginning with Sunday at `0`. |
+| `%X` | `23:30:59` | The time in the `C` locale. Equivalent to `%H:%M:%S`. |
+| `%x` | `07/14/24` | The date in the `C` locale. Equivalent to `%m/%d/%y`. |
 | `%Y` | `2024` | A full year, including century. Zero padded to 4 digits. |
-| `%y` | `24` | A two-digit year. Represents only 1969-2068. Zero padded. |
-| `%Z` | `EDT` | A time zone abbreviation. Supported when formatting only. |
+| `%y` | `24` | A two-digit year. Represents only 1969-2068, unless parsing with a different [`Config::two_digit_year_base`]. Zero padded. |
+| `%Z` | `EDT` | A time zone abbreviation. See below for parsing. |
 | `%z` | `+0530` | A time zone offset in the format `[+-]HHMM[SS]`. |
 | `%:z` | `+05:30` | A time zone offset in the format `[+-]HH:MM[:SS]`. |
 
@@ -216,12 +220,17 @@ by any flags.
 Moreover, any number of decimal digits can be inserted after the (possibly
 absent) flag and before the directive, so long as the parsed number is less
 than 256. The number formed by these digits will correspond to the minimum
-amount of padding (to the left).
+amount of padding (to the left). This also applies to specifiers that emit
+strings, like `%A`, `%b`, `%p` and `%Z`, which are padded with spaces by
+default (or zeros with the `0` flag). For example, `%10A` formats `Friday` as
+`    Friday`, just like GNU `date` does.
 
 The flags and padding amount above may be used when parsing as well. Most
 settings are ignored during parsing except for padding. For example, if one
 wanted to parse `003` as the day `3`, then one should use `%03d`. Otherwise, by
-default, `%d` will only try to consume at most 2 digits.
+default, `%d` will only try to consume at most 2 digits. Similarly, when a
+width is given for a specifier that parses a string, like `%10A`, up to that
+many padding bytes are skipped before the string.
 
 The `%f` and `%.f` flags also support specifying the precision, up to
 nanoseconds. For example, `%3f` and `%.3f` will both always print a fractional
@@ -235,6 +244,164 @@ is instead interpreted as a precision setting). When using a precision setting,
 truncation is used. If you need a different rounding mode, you should use
 higher level APIs like [`Timestamp::round`] or [`Zoned::round`].
 
+# Parsing time zone abbreviations
+
+Time zone abbreviations like `EST` or `CST` are ambiguous. For example, `CST`
+is used for Central Standard Time in North America, China Standard Time and
+Cuba Standard Time. Because of this, parsing with `%Z` only records the
+abbreviation. It does not, on its own, determine an offset or time zone.
+
+To use a parsed abbreviation, callers must provide their own mapping from
+abbreviations to offsets or IANA time zone identifiers via
+[`TimeZoneAbbreviations`], and then call
+[`BrokenDownTime::resolve_tz_abbreviation`]:
+
+```
+use jiff::fmt::strtime::{
+    AbbreviationTarget, BrokenDownTime, TimeZoneAbbreviations,
+};
+
+static ABBREVIATIONS: TimeZoneAbbreviations = TimeZoneAbbreviations::new(&[
+    ("CET", AbbreviationTarget::Offset(jiff::tz::offset(1))),
+    ("CEST", AbbreviationTarget::Offset(jiff::tz::offset(2))),
+    ("PT", AbbreviationTarget::TimeZone("America/Los_Angeles")),
+]);
+
+let mut tm = BrokenDownTime::parse("%F %T %Z", "2024-07-14 21:14:00 CEST")?;
+tm.resolve_tz_abbreviation(&ABBREVIATIONS)?;
+assert_eq!(tm.to_zoned()?.to_string(), "2024-07-14T21:14:00+02:00[+02:00]");
+
+let mut tm = BrokenDownTime::parse("%F %T %Z", "2024-07-14 21:14:00 PT")?;
+tm.resolve_tz_abbreviation(&ABBREVIATIONS)?;
+assert_eq!(
+    tm.to_zoned()?.to_string(),
+    "2024-07-14T21:14:00-07:00[America/Los_Angeles]",
+);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+For single letter military time zones like `Z` or `Q`, Jiff provides
+[`TimeZoneAbbreviations::MILITARY`].
+
+# Locales
+
+The names written by `%A`, `%a`, `%B`, `%b`, `%h`, `%p` and `%P` (and
+recognized when parsing them) come from a [`Locale`]. The default,
+[`CLocale`], uses English names. A different locale can be set on a
+[`Config`] and passed to routines like [`BrokenDownTime::format_with_config`]
+or [`BrokenDownTime::parse_with_config`]:
+
+```
+use jiff::{
+    civil::{date, Weekday},
+    fmt::strtime::{BrokenDownTime, Config, Locale, Meridiem},
+};
+
+struct French;
+
+impl Locale for French {
+    fn month_name_full(&self, month: i8) -> &str {
+        [
+            "janvier", "février", "mars", "avril", "mai", "juin", "juillet",
+            "août", "septembre", "octobre", "novembre", "décembre",
+        ][usize::try_from(month - 1).unwrap()]
+    }
+
+    fn month_name_abbrev(&self, month: i8) -> &str {
+        [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.",
+            "août", "sept.", "oct.", "nov.", "déc.",
+        ][usize::try_from(month - 1).unwrap()]
+    }
+
+    fn weekday_name_full(&self, weekday: Weekday) -> &str {
+        [
+            "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi",
+            "samedi",
+        ][usize::try_from(weekday.to_sunday_zero_offset()).unwrap()]
+    }
+
+    fn weekday_name_abbrev(&self, weekday: Weekday) -> &str {
+        [
+            "dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam.",
+        ][usize::try_from(weekday.to_sunday_zero_offset()).unwrap()]
+    }
+
+    fn meridiem(&self, meridiem: Meridiem) -> &str {
+        match meridiem {
+            Meridiem::AM => "AM",
+            Meridiem::PM => "PM",
+        }
+    }
+}
+
+let config = Config::new().locale(French);
+
+let tm = BrokenDownTime::from(date(2024, 8, 14));
+let string = tm.to_string_with_config(&config, "%A %-d %B %Y")?;
+assert_eq!(string, "mercredi 14 août 2024");
+
+let tm = BrokenDownTime::parse_with_config(
+    &config,
+    "%a %d %b %Y",
+    "mer. 14 août 2024",
+)?;
+assert_eq!(tm.to_date()?, date(2024, 8, 14));
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+When parsing, names are matched without regard to ASCII case. If more than
+one name matches, then the longest one is used.
+
+Note that a locale only controls the names listed above. In particular, `%x`
+and `%X` always use the `C` locale.
+
+# Compiled formats
+
+When the same format string is used many times, for example, when parsing
+every line in a log file, it can be compiled once into a [`CompiledFormat`].
+This avoids scanning the format string for directives on every call, and
+also reports all unsupported directives up front:
+
+```
+use jiff::{civil::date, fmt::strtime::CompiledFormat};
+
+let format = CompiledFormat::new("%b %e %T")?;
+for (line, day) in [("Jul  8 09:14:00", 8), ("Jul 14 21:14:00", 14)] {
+    let tm = format.parse(line)?;
+    assert_eq!(tm.day(), Some(day));
+}
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+When the `static` or `static-tz` crate feature is enabled, the [`compile!`]
+macro can be used to check a format string at compile time instead.
+
+# LDML patterns
+
+Format strings written for Java's `DateTimeFormatter`, ICU or JavaScript
+libraries use [Unicode LDML] patterns like `yyyy-MM-dd HH:mm` instead of
+`strftime` directives. Rather than translating these by hand, which is an
+easy way to introduce bugs (like `mm` being minutes and not months), use
+[`CompiledFormat::from_ldml`]:
+
+```
+use jiff::{civil::date, fmt::strtime::CompiledFormat};
+
+let format = CompiledFormat::from_ldml("EEEE, MMMM d, yyyy h:mm a")?;
+assert_eq!(format.as_bytes(), b"%A, %B %-d, %Y %-I:%M %p");
+
+let dt = date(2024, 7, 14).at(21, 14, 0, 0);
+assert_eq!(format.to_string(&dt.into())?, "Sunday, July 14, 2024 9:14 PM");
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+[Unicode LDML]: https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+
 # Conditionally unsupported
 
 Jiff does not support `%Q` or `%:Q` (IANA time zone identifier) when the
@@ -248,8 +415,9 @@ The following things are currently unsupported:
 
 * Parsing or formatting fractional seconds in the time time zone offset.
 * Locale oriented conversion specifiers, such as `%c`, `%r` and `%+`, are not
-  supported by Jiff. For locale oriented datetime formatting, please use the
-  [`icu`] crate via [`jiff-icu`].
+  supported by Jiff. (`%x` and `%X` are supported, but always use the `C`
+  locale.) Beyond the names provided by a [`Locale`], for locale oriented
+  datetime formatting, please use the [`icu`] crate via [`jiff-icu`].
 
 [`strftime`]: https://pubs.opengroup.org/onlinepubs/009695399/functions/strftime.html
 [`strptime`]: https://pubs.opengroup.org/onlinepubs/009695399/functions/strptime.html
@@ -263,8 +431,9 @@ use crate::{
     error::{err, ErrorContext},
     fmt::{
         strtime::{format::Formatter, parse::Parser},
-        Write,
+        LeapSecond, Write,
     },
+    tai::{self, LeapSecondTable},
     tz::{Offset, OffsetConflict, TimeZone, TimeZoneDatabase},
     util::{
         self,
@@ -276,7 +445,91 @@ use crate::{
     Error, Timestamp, Zoned,
 };
 
+#[cfg(feature = "alloc")]
+pub use self::compiled::CompiledFormat;
+
+/// Create a [`CompiledFormat`] from a format string that is checked at
+/// compile time.
+///
+/// This is like [`CompiledFormat::new`], except an invalid format string
+/// is a compile error instead of a runtime error. This includes format
+/// strings that contain unsupported directives.
+///
+/// This macro is only available when the `static` or `static-tz` crate
+/// feature is enabled, in addition to the `alloc` crate feature.
+///
+/// # Input
+///
+/// This macro takes a literal format string and, optionally, the type of
+/// the datetime that will be formatted with it. The type must be one of
+/// [`Zoned`], [`Timestamp`], [`DateTime`], [`Date`] or [`Time`]. It may
+/// be written as a path, e.g., `civil::Date`, but only the last segment of
+/// the path is looked at.
+///
+/// When a type is given, directives that require data the type doesn't have
+/// are also a compile error. For example, `%H` requires a time, and so can't
+/// be used with [`Date`]. And `%z` requires an offset, and so can't be used
+/// with [`DateTime`]. Note that no check is done for parsing, since parsing
+/// only fails based on what the input contains.
+///
+/// # Return type
+///
+/// This macro returns a value with type `CompiledFormat`. The format string
+/// is still compiled at runtime, but it is guaranteed to succeed.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::{date, Date}, fmt::strtime};
+///
+/// let format = strtime::compile!("%A, %B %-d, %Y", Date);
+/// assert_eq!(
+///     format.to_string(&date(2024, 7, 14).into())?,
+///     "Sunday, July 14, 2024",
+/// );
+///
+/// // The format string can be used for parsing too.
+/// assert_eq!(
+///     format.parse("Sunday, July 14, 2024")?.to_date()?,
+///     date(2024, 7, 14),
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Example: invalid format strings fail to compile
+///
+/// This fails to compile because `%c` isn't supported by Jiff:
+///
+/// ```compile_fail
+/// use jiff::fmt::strtime;
+///
+/// let format = strtime::compile!("%c");
+/// ```
+///
+/// This fails to compile because a [`Date`] has no time:
+///
+/// ```compile_fail
+/// use jiff::{civil::Date, fmt::strtime};
+///
+/// let format = strtime::compile!("%Y-%m-%d %H:%M:%S", Date);
+/// ```
+///
+/// This fails to compile because a [`DateTime`] has no offset:
+///
+/// ```compile_fail
+/// use jiff::{civil::DateTime, fmt::strtime};
+///
+/// let format = strtime::compile!("%Y-%m-%dT%H:%M:%S%:z", DateTime);
+/// ```
+#[cfg(all(feature = "alloc", feature = "static-tz"))]
+pub use jiff_static::strtime_compile as compile;
+
+#[cfg(feature = "alloc")]
+mod compiled;
 mod format;
+#[cfg(feature = "alloc")]
+mod ldml;
 mod parse;
 
 /// Parse the given `input` according to the given `format` string.
@@ -485,6 +738,9 @@ pub struct BrokenDownTime {
     minute: Option<t::Minute>,
     second: Option<t::Second>,
     subsec: Option<t::SubsecNanosecond>,
+    // Set when a leap second was parsed with a policy that is applied when
+    // converting to a civil time. In this case, `second` is `59`.
+    leap_second: Option<LeapSecond>,
     offset: Option<Offset>,
     // Used to confirm that it is consistent
     // with the date given. It usually isn't
@@ -539,8 +795,106 @@ impl BrokenDownTime {
 
     #[inline]
     fn parse_mono(fmt: &[u8], inp: &[u8]) -> Result<BrokenDownTime, Error> {
+        BrokenDownTime::parse_locale_mono(
+            None,
+            DEFAULT_TWO_DIGIT_YEAR_BASE,
+            LeapSecond::Constrain,
+            fmt,
+            inp,
+        )
+    }
+
+    /// Parse the given `input` according to the given `format` string, using
+    /// the names provided by the given configuration's [`Locale`].
+    ///
+    /// This is like [`BrokenDownTime::parse`], except `%A`, `%a`, `%B`, `%b`,
+    /// `%h`, `%p` and `%P` recognize the locale's names instead of English
+    /// names. See the [module documentation](self#locales) for details.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when parsing failed. This might happen because
+    /// the format string itself was invalid, or because the input didn't match
+    /// the format string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     civil::{date, Weekday},
+    ///     fmt::strtime::{BrokenDownTime, CLocale, Config, Locale, Meridiem},
+    /// };
+    ///
+    /// /// English, except with lowercase AM/PM designations.
+    /// struct LowerMeridiem;
+    ///
+    /// impl Locale for LowerMeridiem {
+    ///     fn month_name_full(&self, month: i8) -> &str {
+    ///         CLocale.month_name_full(month)
+    ///     }
+    ///
+    ///     fn month_name_abbrev(&self, month: i8) -> &str {
+    ///         CLocale.month_name_abbrev(month)
+    ///     }
+    ///
+    ///     fn weekday_name_full(&self, weekday: Weekday) -> &str {
+    ///         CLocale.weekday_name_full(weekday)
+    ///     }
+    ///
+    ///     fn weekday_name_abbrev(&self, weekday: Weekday) -> &str {
+    ///         CLocale.weekday_name_abbrev(weekday)
+    ///     }
+    ///
+    ///     fn meridiem(&self, meridiem: Meridiem) -> &str {
+    ///         match meridiem {
+    ///             Meridiem::AM => "a.m.",
+    ///             Meridiem::PM => "p.m.",
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let config = Config::new().locale(LowerMeridiem);
+    /// let tm = BrokenDownTime::parse_with_config(
+    ///     &config,
+    ///     "%B %d, %Y %I:%M %p",
+    ///     "July 14, 2024 9:14 p.m.",
+    /// )?;
+    /// assert_eq!(tm.to_datetime()?, date(2024, 7, 14).at(21, 14, 0, 0));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn parse_with_config<L: Locale>(
+        config: &Config<L>,
+        format: impl AsRef<[u8]>,
+        input: impl AsRef<[u8]>,
+    ) -> Result<BrokenDownTime, Error> {
+        BrokenDownTime::parse_locale_mono(
+            Some(&config.locale),
+            config.two_digit_year_base,
+            config.leap_second,
+            format.as_ref(),
+            input.as_ref(),
+        )
+    }
+
+    #[inline]
+    fn parse_locale_mono(
+        locale: Option<&dyn Locale>,
+        two_digit_year_base: i16,
+        leap_second: LeapSecond,
+        fmt: &[u8],
+        inp: &[u8],
+    ) -> Result<BrokenDownTime, Error> {
         let mut pieces = BrokenDownTime::default();
-        let mut p = Parser { fmt, inp, tm: &mut pieces };
+        let mut p = Parser {
+            locale,
+            two_digit_year_base,
+            leap_second,
+            fmt,
+            inp,
+            tm: &mut pieces,
+        };
         p.parse().context("strptime parsing failed")?;
         if !p.inp.is_empty() {
             return Err(err!(
@@ -646,10 +1000,78 @@ impl BrokenDownTime {
     fn parse_prefix_mono(
         fmt: &[u8],
         inp: &[u8],
+    ) -> Result<(BrokenDownTime, usize), Error> {
+        BrokenDownTime::parse_prefix_locale_mono(
+            None,
+            DEFAULT_TWO_DIGIT_YEAR_BASE,
+            LeapSecond::Constrain,
+            fmt,
+            inp,
+        )
+    }
+
+    /// Parse a prefix of the given `input` according to the given `format`
+    /// string, using the names provided by the given configuration's
+    /// [`Locale`].
+    ///
+    /// This is like [`BrokenDownTime::parse_prefix`], but with names
+    /// recognized in the same way as [`BrokenDownTime::parse_with_config`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when parsing failed. This might happen because
+    /// the format string itself was invalid, or because the input didn't match
+    /// the format string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil, fmt::strtime::{BrokenDownTime, Config}};
+    ///
+    /// let input = "Jul 14 2024 and then some";
+    /// let (tm, offset) = BrokenDownTime::parse_prefix_with_config(
+    ///     &Config::new(),
+    ///     "%b %d %Y",
+    ///     input,
+    /// )?;
+    /// assert_eq!(tm.to_date()?, civil::date(2024, 7, 14));
+    /// assert_eq!(&input[offset..], " and then some");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn parse_prefix_with_config<L: Locale>(
+        config: &Config<L>,
+        format: impl AsRef<[u8]>,
+        input: impl AsRef<[u8]>,
+    ) -> Result<(BrokenDownTime, usize), Error> {
+        BrokenDownTime::parse_prefix_locale_mono(
+            Some(&config.locale),
+            config.two_digit_year_base,
+            config.leap_second,
+            format.as_ref(),
+            input.as_ref(),
+        )
+    }
+
+    #[inline]
+    fn parse_prefix_locale_mono(
+        locale: Option<&dyn Locale>,
+        two_digit_year_base: i16,
+        leap_second: LeapSecond,
+        fmt: &[u8],
+        inp: &[u8],
     ) -> Result<(BrokenDownTime, usize), Error> {
         let mkoffset = util::parse::offseter(inp);
         let mut pieces = BrokenDownTime::default();
-        let mut p = Parser { fmt, inp, tm: &mut pieces };
+        let mut p = Parser {
+            locale,
+            two_digit_year_base,
+            leap_second,
+            fmt,
+            inp,
+            tm: &mut pieces,
+        };
         p.parse().context("strptime parsing failed")?;
         let remainder = mkoffset(p.inp);
         Ok((pieces, remainder))
@@ -676,10 +1098,11 @@ impl BrokenDownTime {
     ///
     /// # Example
     ///
-    /// This example shows a formatting option, `%Z`, that isn't available
-    /// during parsing. Namely, `%Z` inserts a time zone abbreviation. This
-    /// is generally only intended for display purposes, since it can be
-    /// ambiguous when parsing.
+    /// This example shows a formatting option, `%Z`, that is generally only
+    /// intended for display purposes. Namely, `%Z` inserts a time zone
+    /// abbreviation. While it can be parsed, it is ambiguous and must be
+    /// resolved via [`BrokenDownTime::resolve_tz_abbreviation`] before it
+    /// can be used.
     ///
     /// ```
     /// use jiff::{civil::date, fmt::strtime::BrokenDownTime};
@@ -699,9 +1122,82 @@ impl BrokenDownTime {
         &self,
         format: impl AsRef<[u8]>,
         mut wtr: W,
+    ) -> Result<(), Error> {
+        self.format_with_config(&Config::new(), format, &mut wtr)
+    }
+
+    /// Format this broken down time using the format string given, using
+    /// the names provided by the given configuration's [`Locale`].
+    ///
+    /// This is like [`BrokenDownTime::format`], except `%A`, `%a`, `%B`,
+    /// `%b`, `%h`, `%p` and `%P` write the locale's names instead of English
+    /// names. See the [module documentation](self#locales) for details.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same cases as
+    /// [`BrokenDownTime::format`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     civil::{date, Weekday},
+    ///     fmt::strtime::{BrokenDownTime, CLocale, Config, Locale, Meridiem},
+    /// };
+    ///
+    /// /// English, except with Dutch weekday names.
+    /// struct DutchWeekdays;
+    ///
+    /// impl Locale for DutchWeekdays {
+    ///     fn month_name_full(&self, month: i8) -> &str {
+    ///         CLocale.month_name_full(month)
+    ///     }
+    ///
+    ///     fn month_name_abbrev(&self, month: i8) -> &str {
+    ///         CLocale.month_name_abbrev(month)
+    ///     }
+    ///
+    ///     fn weekday_name_full(&self, weekday: Weekday) -> &str {
+    ///         match weekday {
+    ///             Weekday::Sunday => "zondag",
+    ///             Weekday::Monday => "maandag",
+    ///             Weekday::Tuesday => "dinsdag",
+    ///             Weekday::Wednesday => "woensdag",
+    ///             Weekday::Thursday => "donderdag",
+    ///             Weekday::Friday => "vrijdag",
+    ///             Weekday::Saturday => "zaterdag",
+    ///         }
+    ///     }
+    ///
+    ///     fn weekday_name_abbrev(&self, weekday: Weekday) -> &str {
+    ///         &self.weekday_name_full(weekday)[..2]
+    ///     }
+    ///
+    ///     fn meridiem(&self, meridiem: Meridiem) -> &str {
+    ///         CLocale.meridiem(meridiem)
+    ///     }
+    /// }
+    ///
+    /// let config = Config::new().locale(DutchWeekdays);
+    /// let tm = BrokenDownTime::from(date(2024, 7, 9));
+    ///
+    /// let mut buf = String::new();
+    /// tm.format_with_config(&config, "%^a %A", &mut buf)?;
+    /// assert_eq!(buf, "DI dinsdag");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn format_with_config<L: Locale, W: Write>(
+        &self,
+        config: &Config<L>,
+        format: impl AsRef<[u8]>,
+        mut wtr: W,
     ) -> Result<(), Error> {
         let fmt = format.as_ref();
-        let mut formatter = Formatter { fmt, tm: self, wtr: &mut wtr };
+        let mut formatter =
+            Formatter { locale: &config.locale, fmt, tm: self, wtr: &mut wtr };
         formatter.format().context("strftime formatting failed")?;
         Ok(())
     }
@@ -726,10 +1222,11 @@ impl BrokenDownTime {
     ///
     /// # Example
     ///
-    /// This example shows a formatting option, `%Z`, that isn't available
-    /// during parsing. Namely, `%Z` inserts a time zone abbreviation. This
-    /// is generally only intended for display purposes, since it can be
-    /// ambiguous when parsing.
+    /// This example shows a formatting option, `%Z`, that is generally only
+    /// intended for display purposes. Namely, `%Z` inserts a time zone
+    /// abbreviation. While it can be parsed, it is ambiguous and must be
+    /// resolved via [`BrokenDownTime::resolve_tz_abbreviation`] before it
+    /// can be used.
     ///
     /// ```
     /// use jiff::{civil::date, fmt::strtime::BrokenDownTime};
@@ -752,6 +1249,149 @@ impl BrokenDownTime {
         Ok(buf)
     }
 
+    /// Format this broken down time using the format string given into a new
+    /// `String`, using the names provided by the given configuration's
+    /// [`Locale`].
+    ///
+    /// This is like [`BrokenDownTime::format_with_config`], but always uses a
+    /// `String` to format the time into.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same cases as
+    /// [`BrokenDownTime::to_string`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::strtime::{BrokenDownTime, Config}};
+    ///
+    /// let tm = BrokenDownTime::from(date(2024, 7, 9).at(16, 24, 0, 0));
+    /// let string = tm.to_string_with_config(&Config::new(), "%a %b %e %P")?;
+    /// assert_eq!(string, "Tue Jul  9 pm");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_string_with_config<L: Locale>(
+        &self,
+        config: &Config<L>,
+        format: impl AsRef<[u8]>,
+    ) -> Result<alloc::string::String, Error> {
+        let mut buf = alloc::string::String::new();
+        self.format_with_config(config, format, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Resolves the time zone abbreviation in this broken down time (usually
+    /// parsed via `%Z`) to an offset or an IANA time zone identifier using
+    /// the mapping given.
+    ///
+    /// When the abbreviation resolves to an offset, then this sets the
+    /// offset on this broken down time. When it resolves to an IANA time
+    /// zone identifier, then this sets the IANA time zone identifier
+    /// instead. In the latter case, the offset is determined by the time
+    /// zone when converting to a [`Zoned`], which uses
+    /// [`Disambiguation::Compatible`](crate::tz::Disambiguation::Compatible)
+    /// if the datetime is ambiguous.
+    ///
+    /// If this broken down time has no time zone abbreviation, then this is
+    /// a no-op.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the abbreviation isn't in the mapping
+    /// given, or when it is ambiguous and the mapping's
+    /// [`AbbreviationAmbiguity`] policy rejects ambiguous abbreviations.
+    ///
+    /// This also returns an error when an offset or IANA time zone
+    /// identifier is already set (for example, by `%z` or `%Q`) and it is
+    /// inconsistent with the resolved abbreviation.
+    ///
+    /// When Jiff's `alloc` crate feature is disabled, resolving an
+    /// abbreviation to an IANA time zone identifier always returns an error.
+    ///
+    /// # Example
+    ///
+    /// This shows how to handle the ambiguity of `CST` by choosing the first
+    /// matching entry in the mapping:
+    ///
+    /// ```
+    /// use jiff::{
+    ///     fmt::strtime::{
+    ///         AbbreviationAmbiguity, AbbreviationTarget, BrokenDownTime,
+    ///         TimeZoneAbbreviations,
+    ///     },
+    ///     tz,
+    /// };
+    ///
+    /// static ENTRIES: &[(&str, AbbreviationTarget)] = &[
+    ///     ("CST", AbbreviationTarget::Offset(tz::offset(-6))),
+    ///     ("CST", AbbreviationTarget::Offset(tz::offset(8))),
+    /// ];
+    ///
+    /// let mut tm =
+    ///     BrokenDownTime::parse("%F %T %Z", "2024-01-05 12:00:00 CST")?;
+    /// // By default, ambiguous abbreviations are rejected.
+    /// let abbrevs = TimeZoneAbbreviations::new(ENTRIES);
+    /// assert!(tm.resolve_tz_abbreviation(&abbrevs).is_err());
+    /// // But we can opt into picking the first one:
+    /// let abbrevs = abbrevs.ambiguity(AbbreviationAmbiguity::First);
+    /// tm.resolve_tz_abbreviation(&abbrevs)?;
+    /// assert_eq!(tm.offset(), Some(tz::offset(-6)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn resolve_tz_abbreviation(
+        &mut self,
+        abbreviations: &TimeZoneAbbreviations<'_>,
+    ) -> Result<(), Error> {
+        let Some(ref abbrev) = self.tzabbrev else { return Ok(()) };
+        let abbrev = abbrev.as_str();
+        match abbreviations.resolve(abbrev)? {
+            AbbreviationTarget::Offset(offset) => {
+                if let Some(parsed) = self.offset {
+                    if parsed != offset {
+                        return Err(err!(
+                            "time zone abbreviation {abbrev:?} resolved \
+                             to offset {offset}, but parsed offset \
+                             {parsed} is different",
+                        ));
+                    }
+                }
+                self.offset = Some(offset);
+                Ok(())
+            }
+            AbbreviationTarget::TimeZone(name) => {
+                #[cfg(not(feature = "alloc"))]
+                {
+                    Err(err!(
+                        "cannot resolve time zone abbreviation {abbrev:?} \
+                         to IANA time zone identifier {name:?} without \
+                         Jiff's `alloc` feature enabled",
+                    ))
+                }
+                #[cfg(feature = "alloc")]
+                {
+                    use alloc::string::ToString;
+
+                    if let Some(ref parsed) = self.iana {
+                        if parsed != name {
+                            return Err(err!(
+                                "time zone abbreviation {abbrev:?} resolved \
+                                 to IANA time zone identifier {name:?}, but \
+                                 parsed identifier {parsed:?} is different",
+                            ));
+                        }
+                    }
+                    self.iana = Some(name.to_string());
+                    Ok(())
+                }
+            }
+        }
+    }
+
     /// Extracts a zoned datetime from this broken down time.
     ///
     /// When an IANA time zone identifier is
@@ -883,10 +1523,22 @@ impl BrokenDownTime {
             .to_datetime()
             .context("datetime required to parse zoned datetime")?;
         match (self.offset, self.iana_time_zone()) {
-            (None, None) => Err(err!(
-                "either offset (from %z) or IANA time zone identifier \
-                 (from %Q) is required for parsing zoned datetime",
-            )),
+            (None, None) => {
+                if let Some(ref abbrev) = self.tzabbrev {
+                    return Err(err!(
+                        "either offset (from %z) or IANA time zone \
+                         identifier (from %Q) is required for parsing \
+                         zoned datetime, but only time zone abbreviation \
+                         {abbrev:?} (from %Z) was found, which must be \
+                         resolved to an offset or time zone first",
+                        abbrev = abbrev.as_str(),
+                    ));
+                }
+                Err(err!(
+                    "either offset (from %z) or IANA time zone identifier \
+                     (from %Q) is required for parsing zoned datetime",
+                ))
+            }
             (Some(offset), None) => {
                 let ts = offset.to_timestamp(dt).with_context(|| {
                     err!(
@@ -951,9 +1603,83 @@ impl BrokenDownTime {
         })
     }
 
+    /// Extracts a TAI timestamp from this broken down time using the given
+    /// leap second table.
+    ///
+    /// This requires the same components as [`BrokenDownTime::to_timestamp`].
+    /// The timestamp is converted to TAI via [`LeapSecondTable::utc_to_tai`].
+    ///
+    /// When this broken down time was parsed with [`LeapSecond::Preserve`]
+    /// and contains a leap second, then the leap second is preserved in the
+    /// TAI timestamp returned. In this case, the leap second table is used to
+    /// check that a leap second actually occurred at the instant parsed.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`BrokenDownTime::to_timestamp`], or if the timestamp couldn't be
+    /// converted to TAI. This also returns an error when a leap second is
+    /// preserved, but the leap second table doesn't have a leap second at
+    /// the instant parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     fmt::{strtime::{BrokenDownTime, Config}, LeapSecond},
+    ///     tai::LeapSecondTable,
+    /// };
+    ///
+    /// let config = Config::new().leap_second(LeapSecond::Preserve);
+    /// let tm = BrokenDownTime::parse_with_config(
+    ///     &config,
+    ///     "%Y-%m-%d %H:%M:%S %z",
+    ///     "2015-06-30 23:59:60 +0000",
+    /// )?;
+    /// assert_eq!(tm.second(), Some(60));
+    ///
+    /// let tai = tm.to_tai_timestamp(&LeapSecondTable::builtin())?;
+    /// assert_eq!(tai.to_string(), "2015-07-01T00:00:35 TAI");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_tai_timestamp(
+        &self,
+        table: &LeapSecondTable,
+    ) -> Result<tai::Timestamp, Error> {
+        if self.leap_second != Some(LeapSecond::Preserve) {
+            return table.utc_to_tai(self.to_timestamp()?);
+        }
+        let date =
+            self.to_date().context("date required to parse timestamp")?;
+        let time = self
+            .to_time_constrained()
+            .context("time required to parse timestamp")?;
+        let offset =
+            self.to_offset().context("offset required to parse timestamp")?;
+        let dt = DateTime::from_parts(date, time);
+        let timestamp = offset.to_timestamp(dt).with_context(|| {
+            err!(
+                "parsed datetime {dt} and offset {offset}, \
+                 but combining them into a timestamp is outside \
+                 Jiff's supported timestamp range",
+            )
+        })?;
+        table.leap_second_to_tai(timestamp)
+    }
+
     #[inline]
     fn to_offset(&self) -> Result<Offset, Error> {
         let Some(offset) = self.offset else {
+            if let Some(ref abbrev) = self.tzabbrev {
+                return Err(err!(
+                    "parsing format did not include time zone offset \
+                     directive, and time zone abbreviation {abbrev:?} \
+                     (from %Z) was not resolved to an offset",
+                    abbrev = abbrev.as_str(),
+                ));
+            }
             return Err(err!(
                 "parsing format did not include time zone offset directive",
             ));
@@ -1279,6 +2005,27 @@ impl BrokenDownTime {
     /// ```
     #[inline]
     pub fn to_time(&self) -> Result<Time, Error> {
+        let time = self.to_time_constrained()?;
+        match self.leap_second {
+            Some(LeapSecond::Clamp) => Ok(Time::new_ranged(
+                time.hour_ranged(),
+                time.minute_ranged(),
+                time.second_ranged(),
+                t::SubsecNanosecond::MAX_SELF,
+            )),
+            Some(LeapSecond::Preserve) => Err(err!(
+                "cannot preserve parsed leap second since it cannot be \
+                 represented by a civil time or Unix timestamp \
+                 (convert it to a TAI timestamp instead)",
+            )),
+            _ => Ok(time),
+        }
+    }
+
+    /// Like `to_time`, but always treats a leap second as if it were the
+    /// 59th second, regardless of the leap second policy used for parsing.
+    #[inline]
+    fn to_time_constrained(&self) -> Result<Time, Error> {
         let Some(hour) = self.hour_ranged() else {
             if self.minute.is_some() {
                 return Err(err!(
@@ -1339,7 +2086,8 @@ impl BrokenDownTime {
     /// Returns the parsed year, if available.
     ///
     /// This is also set when a 2 digit year is parsed. (But that's limited to
-    /// the years 1969 to 2068, inclusive.)
+    /// the years 1969 to 2068, inclusive, unless a different
+    /// [`Config::two_digit_year_base`] is used.)
     ///
     /// # Example
     ///
@@ -1515,7 +2263,8 @@ impl BrokenDownTime {
     /// Returns the parsed ISO 8601 week-based year, if available.
     ///
     /// This is also set when a 2 digit ISO 8601 week-based year is parsed.
-    /// (But that's limited to the years 1969 to 2068, inclusive.)
+    /// (But that's limited to the years 1969 to 2068, inclusive, unless a
+    /// different [`Config::two_digit_year_base`] is used.)
     ///
     /// # Example
     ///
@@ -1710,6 +2459,9 @@ impl BrokenDownTime {
     /// ```
     #[inline]
     pub fn second(&self) -> Option<i8> {
+        if self.leap_second == Some(LeapSecond::Preserve) {
+            return Some(60);
+        }
         self.second.map(|x| x.get())
     }
 
@@ -1819,6 +2571,28 @@ impl BrokenDownTime {
         }
     }
 
+    /// Returns the time zone abbreviation, if available.
+    ///
+    /// When parsing, this is set by the `%Z` directive. A parsed
+    /// abbreviation does not determine an offset or time zone until it is
+    /// resolved via [`BrokenDownTime::resolve_tz_abbreviation`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::strtime::BrokenDownTime;
+    ///
+    /// let tm = BrokenDownTime::parse("%H:%M %Z", "21:14 PDT")?;
+    /// assert_eq!(tm.tz_abbreviation(), Some("PDT"));
+    /// assert_eq!(tm.offset(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn tz_abbreviation(&self) -> Option<&str> {
+        self.tzabbrev.as_ref().map(|abbrev| abbrev.as_str())
+    }
+
     /// Returns the parsed weekday, if available.
     ///
     /// # Example
@@ -2241,6 +3015,7 @@ impl BrokenDownTime {
     /// ```
     #[inline]
     pub fn set_second(&mut self, second: Option<i8>) -> Result<(), Error> {
+        self.leap_second = None;
         self.second = match second {
             None => None,
             Some(second) => Some(t::Second::try_new("second", second)?),
@@ -2647,6 +3422,472 @@ impl From<Time> for Meridiem {
     }
 }
 
+/// The first year of the window that `%y` and `%g` resolve two-digit years
+/// into when no other base is configured.
+///
+/// This makes `69` through `99` refer to `1969` through `1999`, and `00`
+/// through `68` refer to `2000` through `2068`, as specified by POSIX.
+const DEFAULT_TWO_DIGIT_YEAR_BASE: i16 = 1969;
+
+/// Configuration for parsing and formatting with [`BrokenDownTime`].
+///
+/// This carries the [`Locale`] providing the names of months, weekdays and
+/// AM/PM, and the [base year](Config::two_digit_year_base) used to interpret
+/// two-digit years when parsing. It is used with routines like
+/// [`BrokenDownTime::format_with_config`] and
+/// [`BrokenDownTime::parse_with_config`].
+///
+/// # Example
+///
+/// See the [module documentation](self#locales) for a complete example.
+#[derive(Clone, Debug)]
+pub struct Config<L> {
+    locale: L,
+    two_digit_year_base: i16,
+    leap_second: LeapSecond,
+}
+
+impl Config<CLocale> {
+    /// Creates a new default configuration, which uses the [`CLocale`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::strtime::{BrokenDownTime, Config}};
+    ///
+    /// let tm = BrokenDownTime::from(date(2024, 7, 9));
+    /// let string = tm.to_string_with_config(&Config::new(), "%A %B")?;
+    /// assert_eq!(string, "Tuesday July");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn new() -> Config<CLocale> {
+        Config {
+            locale: CLocale,
+            two_digit_year_base: DEFAULT_TWO_DIGIT_YEAR_BASE,
+            leap_second: LeapSecond::Constrain,
+        }
+    }
+}
+
+impl<L> Config<L> {
+    /// Sets the locale providing the names of months, weekdays and AM/PM.
+    ///
+    /// Any existing locale on this configuration is dropped.
+    ///
+    /// # Example
+    ///
+    /// See the [module documentation](self#locales) for a complete example.
+    #[inline]
+    pub fn locale<M: Locale>(self, locale: M) -> Config<M> {
+        Config {
+            locale,
+            two_digit_year_base: self.two_digit_year_base,
+            leap_second: self.leap_second,
+        }
+    }
+
+    /// Sets the first year of the 100 year window that two-digit years are
+    /// interpreted in when parsing `%y` and `%g`.
+    ///
+    /// A two-digit year is resolved to the unique year in the range
+    /// `base..=base+99` that ends in the same two digits. The default base
+    /// is `1969`, which means `69` through `99` refer to `1969` through
+    /// `1999`, while `00` through `68` refer to `2000` through `2068`. This
+    /// matches the behavior specified by POSIX.
+    ///
+    /// The base given is clamped to the range `-9999..=9900`, so that every
+    /// year in the window is supported by Jiff.
+    ///
+    /// This has no effect on formatting.
+    ///
+    /// # Example
+    ///
+    /// This shows how to interpret two-digit years as being in the 20th
+    /// century, which is common for birth dates in older records:
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::strtime::{BrokenDownTime, Config}};
+    ///
+    /// let config = Config::new().two_digit_year_base(1900);
+    /// let tm =
+    ///     BrokenDownTime::parse_with_config(&config, "%m/%d/%y", "7/14/24")?;
+    /// assert_eq!(tm.to_date()?, date(1924, 7, 14));
+    ///
+    /// // By default, `24` refers to `2024`.
+    /// let tm = BrokenDownTime::parse("%m/%d/%y", "7/14/24")?;
+    /// assert_eq!(tm.to_date()?, date(2024, 7, 14));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Example: a sliding window relative to now
+    ///
+    /// Some data sources resolve two-digit years relative to the current
+    /// date instead of a fixed year. For example, one common convention is
+    /// to choose the year within 80 years before or 20 years after today.
+    /// This can be done by computing the base from the current year:
+    ///
+    /// ```
+    /// use jiff::{fmt::strtime::{BrokenDownTime, Config}, Zoned};
+    ///
+    /// let this_year = Zoned::now().year();
+    /// let config = Config::new().two_digit_year_base(this_year - 80);
+    ///
+    /// let tm = BrokenDownTime::parse_with_config(&config, "%y", "99")?;
+    /// let year = tm.year().unwrap();
+    /// assert!(this_year - 80 <= year && year <= this_year + 19);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn two_digit_year_base(self, base: i16) -> Config<L> {
+        Config { two_digit_year_base: base.clamp(-9999, 9900), ..self }
+    }
+
+    /// Sets how a leap second, i.e., a seconds value of `60` parsed by `%S`,
+    /// is handled.
+    ///
+    /// By default, [`LeapSecond::Constrain`] is used, which parses a leap
+    /// second as if it were the 59th second. With [`LeapSecond::Clamp`] and
+    /// [`LeapSecond::Preserve`], the policy is applied when the broken down
+    /// time is converted to a civil time (or a value containing one). See
+    /// [`LeapSecond`] for more details on each policy.
+    ///
+    /// This has no effect on formatting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     civil::time,
+    ///     fmt::{strtime::{BrokenDownTime, Config}, LeapSecond},
+    /// };
+    ///
+    /// let config = Config::new().leap_second(LeapSecond::Clamp);
+    /// let tm = BrokenDownTime::parse_with_config(&config, "%T", "23:59:60")?;
+    /// assert_eq!(tm.to_time()?, time(23, 59, 59, 999_999_999));
+    ///
+    /// let config = Config::new().leap_second(LeapSecond::Reject);
+    /// assert!(BrokenDownTime::parse_with_config(&config, "%T", "23:59:60")
+    ///     .is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn leap_second(self, policy: LeapSecond) -> Config<L> {
+        Config { leap_second: policy, ..self }
+    }
+}
+
+impl Default for Config<CLocale> {
+    fn default() -> Config<CLocale> {
+        Config::new()
+    }
+}
+
+/// The names used by `%A`, `%a`, `%B`, `%b`, `%h`, `%p` and `%P`.
+///
+/// Implementations of this trait can be set on a [`Config`] to format and
+/// parse these names in languages other than English. The [`CLocale`] is
+/// used by default.
+///
+/// When parsing, names are matched without regard to ASCII case. If more
+/// than one name matches, then the longest one is used. Empty names never
+/// match.
+///
+/// # Example
+///
+/// See the [module documentation](self#locales) for a complete example.
+pub trait Locale {
+    /// Returns the full name of the given month, for `%B`.
+    ///
+    /// The month given is always in the range `1..=12`.
+    fn month_name_full(&self, month: i8) -> &str;
+
+    /// Returns the abbreviated name of the given month, for `%b` and `%h`.
+    ///
+    /// The month given is always in the range `1..=12`.
+    fn month_name_abbrev(&self, month: i8) -> &str;
+
+    /// Returns the full name of the given weekday, for `%A`.
+    fn weekday_name_full(&self, weekday: Weekday) -> &str;
+
+    /// Returns the abbreviated name of the given weekday, for `%a`.
+    fn weekday_name_abbrev(&self, weekday: Weekday) -> &str;
+
+    /// Returns the designation for before or after noon, for `%p`.
+    ///
+    /// `%p` writes this designation as is, while `%P` lowercases it.
+    fn meridiem(&self, meridiem: Meridiem) -> &str;
+}
+
+impl<L: Locale + ?Sized> Locale for &L {
+    #[inline]
+    fn month_name_full(&self, month: i8) -> &str {
+        (**self).month_name_full(month)
+    }
+
+    #[inline]
+    fn month_name_abbrev(&self, month: i8) -> &str {
+        (**self).month_name_abbrev(month)
+    }
+
+    #[inline]
+    fn weekday_name_full(&self, weekday: Weekday) -> &str {
+        (**self).weekday_name_full(weekday)
+    }
+
+    #[inline]
+    fn weekday_name_abbrev(&self, weekday: Weekday) -> &str {
+        (**self).weekday_name_abbrev(weekday)
+    }
+
+    #[inline]
+    fn meridiem(&self, meridiem: Meridiem) -> &str {
+        (**self).meridiem(meridiem)
+    }
+}
+
+/// The `C` (or `POSIX`) locale, which uses English names.
+///
+/// This is the locale used by default. For example, it formats the month of
+/// July as `July` with `%B` and `Jul` with `%b`, and the AM/PM designations
+/// as `AM` and `PM`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::Weekday, fmt::strtime::{CLocale, Locale, Meridiem}};
+///
+/// assert_eq!(CLocale.month_name_full(7), "July");
+/// assert_eq!(CLocale.weekday_name_abbrev(Weekday::Tuesday), "Tue");
+/// assert_eq!(CLocale.meridiem(Meridiem::PM), "PM");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CLocale;
+
+impl Locale for CLocale {
+    #[inline]
+    fn month_name_full(&self, month: i8) -> &str {
+        month_name_full(month)
+    }
+
+    #[inline]
+    fn month_name_abbrev(&self, month: i8) -> &str {
+        month_name_abbrev(month)
+    }
+
+    #[inline]
+    fn weekday_name_full(&self, weekday: Weekday) -> &str {
+        weekday_name_full(weekday)
+    }
+
+    #[inline]
+    fn weekday_name_abbrev(&self, weekday: Weekday) -> &str {
+        weekday_name_abbrev(weekday)
+    }
+
+    #[inline]
+    fn meridiem(&self, meridiem: Meridiem) -> &str {
+        match meridiem {
+            Meridiem::AM => "AM",
+            Meridiem::PM => "PM",
+        }
+    }
+}
+
+/// A mapping from time zone abbreviations to offsets or IANA time zone
+/// identifiers.
+///
+/// This is used with [`BrokenDownTime::resolve_tz_abbreviation`] to resolve
+/// an abbreviation parsed by `%Z`. Other than
+/// [`TimeZoneAbbreviations::MILITARY`], Jiff does not provide any mapping of
+/// its own, since the meaning of an abbreviation depends on where the data
+/// came from. See the [module documentation](self#parsing-time-zone-abbreviations)
+/// for more details.
+///
+/// Abbreviations are matched without regard to ASCII case. When more than one
+/// distinct entry matches an abbreviation, then the
+/// [`AbbreviationAmbiguity`] policy (set via
+/// [`TimeZoneAbbreviations::ambiguity`]) determines what happens.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{
+///     fmt::strtime::{AbbreviationTarget, TimeZoneAbbreviations},
+///     tz,
+/// };
+///
+/// static ABBREVIATIONS: TimeZoneAbbreviations = TimeZoneAbbreviations::new(&[
+///     ("EST", AbbreviationTarget::Offset(tz::offset(-5))),
+///     ("EDT", AbbreviationTarget::Offset(tz::offset(-4))),
+///     ("ET", AbbreviationTarget::TimeZone("America/New_York")),
+/// ]);
+///
+/// assert_eq!(
+///     ABBREVIATIONS.resolve("edt")?,
+///     AbbreviationTarget::Offset(tz::offset(-4)),
+/// );
+/// assert!(ABBREVIATIONS.resolve("CST").is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct TimeZoneAbbreviations<'a> {
+    entries: &'a [(&'a str, AbbreviationTarget<'a>)],
+    ambiguity: AbbreviationAmbiguity,
+}
+
+impl<'a> TimeZoneAbbreviations<'a> {
+    /// Create a new mapping from the given abbreviation entries.
+    ///
+    /// The order of the entries only matters when using
+    /// [`AbbreviationAmbiguity::First`].
+    #[inline]
+    pub const fn new(
+        entries: &'a [(&'a str, AbbreviationTarget<'a>)],
+    ) -> TimeZoneAbbreviations<'a> {
+        TimeZoneAbbreviations {
+            entries,
+            ambiguity: AbbreviationAmbiguity::Reject,
+        }
+    }
+
+    /// Set the policy for abbreviations matching more than one distinct
+    /// entry.
+    ///
+    /// The default is [`AbbreviationAmbiguity::Reject`].
+    #[inline]
+    pub const fn ambiguity(
+        self,
+        policy: AbbreviationAmbiguity,
+    ) -> TimeZoneAbbreviations<'a> {
+        TimeZoneAbbreviations { ambiguity: policy, ..self }
+    }
+
+    /// Returns the target for the given abbreviation.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the abbreviation isn't in this mapping, or
+    /// when it matches more than one distinct entry and the ambiguity policy
+    /// is [`AbbreviationAmbiguity::Reject`].
+    pub fn resolve(
+        &self,
+        abbreviation: &str,
+    ) -> Result<AbbreviationTarget<'a>, Error> {
+        let mut matches = self
+            .entries
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case(abbreviation))
+            .map(|&(_, target)| target);
+        let Some(first) = matches.next() else {
+            return Err(err!(
+                "unrecognized time zone abbreviation {abbreviation:?}",
+            ));
+        };
+        if self.ambiguity == AbbreviationAmbiguity::Reject {
+            if let Some(other) = matches.find(|&target| target != first) {
+                return Err(err!(
+                    "time zone abbreviation {abbreviation:?} is ambiguous, \
+                     since it could refer to {first} or {other}",
+                ));
+            }
+        }
+        Ok(first)
+    }
+}
+
+impl TimeZoneAbbreviations<'static> {
+    /// A mapping for the single letter military time zones.
+    ///
+    /// `Z` is UTC, `A` through `I` are `+01` through `+09`, `K` through `M`
+    /// are `+10` through `+12` and `N` through `Y` are `-01` through `-12`.
+    /// `J`, which refers to the observer's local time, is not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::strtime::{BrokenDownTime, TimeZoneAbbreviations};
+    ///
+    /// let mut tm = BrokenDownTime::parse("%H%M%Z", "0930Q")?;
+    /// tm.resolve_tz_abbreviation(&TimeZoneAbbreviations::MILITARY)?;
+    /// assert_eq!(tm.offset(), Some(jiff::tz::offset(-4)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const MILITARY: TimeZoneAbbreviations<'static> =
+        TimeZoneAbbreviations::new(&[
+            ("Z", AbbreviationTarget::Offset(Offset::constant(0))),
+            ("A", AbbreviationTarget::Offset(Offset::constant(1))),
+            ("B", AbbreviationTarget::Offset(Offset::constant(2))),
+            ("C", AbbreviationTarget::Offset(Offset::constant(3))),
+            ("D", AbbreviationTarget::Offset(Offset::constant(4))),
+            ("E", AbbreviationTarget::Offset(Offset::constant(5))),
+            ("F", AbbreviationTarget::Offset(Offset::constant(6))),
+            ("G", AbbreviationTarget::Offset(Offset::constant(7))),
+            ("H", AbbreviationTarget::Offset(Offset::constant(8))),
+            ("I", AbbreviationTarget::Offset(Offset::constant(9))),
+            ("K", AbbreviationTarget::Offset(Offset::constant(10))),
+            ("L", AbbreviationTarget::Offset(Offset::constant(11))),
+            ("M", AbbreviationTarget::Offset(Offset::constant(12))),
+            ("N", AbbreviationTarget::Offset(Offset::constant(-1))),
+            ("O", AbbreviationTarget::Offset(Offset::constant(-2))),
+            ("P", AbbreviationTarget::Offset(Offset::constant(-3))),
+            ("Q", AbbreviationTarget::Offset(Offset::constant(-4))),
+            ("R", AbbreviationTarget::Offset(Offset::constant(-5))),
+            ("S", AbbreviationTarget::Offset(Offset::constant(-6))),
+            ("T", AbbreviationTarget::Offset(Offset::constant(-7))),
+            ("U", AbbreviationTarget::Offset(Offset::constant(-8))),
+            ("V", AbbreviationTarget::Offset(Offset::constant(-9))),
+            ("W", AbbreviationTarget::Offset(Offset::constant(-10))),
+            ("X", AbbreviationTarget::Offset(Offset::constant(-11))),
+            ("Y", AbbreviationTarget::Offset(Offset::constant(-12))),
+        ]);
+}
+
+/// The target of a time zone abbreviation in [`TimeZoneAbbreviations`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AbbreviationTarget<'a> {
+    /// The abbreviation refers to a fixed offset from UTC.
+    Offset(Offset),
+    /// The abbreviation refers to the IANA time zone with this identifier.
+    ///
+    /// The identifier is looked up in a time zone database when converting
+    /// to a [`Zoned`].
+    TimeZone(&'a str),
+}
+
+impl<'a> core::fmt::Display for AbbreviationTarget<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match *self {
+            AbbreviationTarget::Offset(offset) => {
+                write!(f, "offset {offset}")
+            }
+            AbbreviationTarget::TimeZone(name) => {
+                write!(f, "time zone {name}")
+            }
+        }
+    }
+}
+
+/// The policy for resolving a time zone abbreviation that matches more than
+/// one distinct entry in [`TimeZoneAbbreviations`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AbbreviationAmbiguity {
+    /// Return an error when an abbreviation is ambiguous.
+    ///
+    /// This is the default.
+    #[default]
+    Reject,
+    /// Pick the first matching entry.
+    First,
+}
+
 /// These are "extensions" to the standard `strftime` conversion specifiers.
 ///
 /// Basically, these provide control over padding (zeros, spaces or none),
@@ -2721,6 +3962,132 @@ impl Extension {
     }
 }
 
+/// A single conversion specification following a `%` in a format string.
+///
+/// For example, `%-d` has no prefix, a `-` flag and the `d` specifier, while
+/// `%:z` has a `:` prefix and the `z` specifier.
+#[derive(Clone, Copy, Debug)]
+struct Directive {
+    ext: Extension,
+    prefix: Option<Prefix>,
+    specifier: u8,
+}
+
+impl Directive {
+    /// Parses a directive from the beginning of `fmt`, which should be
+    /// positioned immediately after a `%`. This assumes `fmt` is not empty.
+    ///
+    /// The slice returned has the entire directive removed from it.
+    ///
+    /// This does not check whether the specifier is supported. That is left
+    /// to the routines that actually format or parse the directive.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn parse(fmt: &[u8]) -> Result<(Directive, &[u8]), Error> {
+        let (flag, fmt) = Extension::parse_flag(fmt)?;
+        let (width, fmt) = Extension::parse_width(fmt)?;
+        let mut ext = Extension { flag, width };
+        let (prefix, fmt) = match fmt[0] {
+            b':' => {
+                if fmt.len() == 1 {
+                    return Err(err!(
+                        "invalid format string, expected directive \
+                         after '%:'",
+                    ));
+                }
+                (Some(Prefix::Colon), &fmt[1..])
+            }
+            b'.' => {
+                if fmt.len() == 1 {
+                    return Err(err!(
+                        "invalid format string, expected directive \
+                         after '%.'",
+                    ));
+                }
+                // Parse precision settings after the `.`, effectively
+                // overriding any digits that came before it.
+                let (width, fmt) = Extension::parse_width(&fmt[1..])?;
+                ext = Extension { width, ..ext };
+                (Some(Prefix::Dot), fmt)
+            }
+            _ => (None, fmt),
+        };
+        let directive = Directive { ext, prefix, specifier: fmt[0] };
+        Ok((directive, &fmt[1..]))
+    }
+
+    /// Returns true when this directive is supported by both formatting and
+    /// parsing.
+    #[cfg(feature = "alloc")]
+    fn is_supported(&self) -> bool {
+        match self.prefix {
+            None => matches!(
+                self.specifier,
+                b'%' | b'A'
+                    | b'a'
+                    | b'B'
+                    | b'b'
+                    | b'C'
+                    | b'D'
+                    | b'd'
+                    | b'e'
+                    | b'F'
+                    | b'f'
+                    | b'G'
+                    | b'g'
+                    | b'H'
+                    | b'h'
+                    | b'I'
+                    | b'j'
+                    | b'k'
+                    | b'l'
+                    | b'M'
+                    | b'm'
+                    | b'n'
+                    | b'P'
+                    | b'p'
+                    | b'Q'
+                    | b'R'
+                    | b'S'
+                    | b's'
+                    | b'T'
+                    | b't'
+                    | b'U'
+                    | b'u'
+                    | b'V'
+                    | b'W'
+                    | b'w'
+                    | b'X'
+                    | b'x'
+                    | b'Y'
+                    | b'y'
+                    | b'Z'
+                    | b'z'
+            ),
+            Some(Prefix::Colon) => matches!(self.specifier, b'Q' | b'z'),
+            Some(Prefix::Dot) => self.specifier == b'f',
+        }
+    }
+}
+
+impl core::fmt::Display for Directive {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("%")?;
+        match self.prefix {
+            None => {}
+            Some(Prefix::Colon) => f.write_str(":")?,
+            Some(Prefix::Dot) => f.write_str(".")?,
+        }
+        write!(f, "{}", escape::Byte(self.specifier))
+    }
+}
+
+/// A prefix that may come before a specifier, like the `:` in `%:z`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Prefix {
+    Colon,
+    Dot,
+}
+
 /// The different flags one can set. They are mutually exclusive.
 #[derive(Clone, Copy, Debug)]
 enum Flag {
@@ -2758,8 +4125,8 @@ fn weekday_name_abbrev(wd: Weekday) -> &'static str {
 }
 
 /// Returns the "full" month name.
-fn month_name_full(month: t::Month) -> &'static str {
-    match month.get() {
+fn month_name_full(month: i8) -> &'static str {
+    match month {
         1 => "January",
         2 => "February",
         3 => "March",
@@ -2777,8 +4144,8 @@ fn month_name_full(month: t::Month) -> &'static str {
 }
 
 /// Returns the abbreviated month name.
-fn month_name_abbrev(month: t::Month) -> &'static str {
-    match month.get() {
+fn month_name_abbrev(month: i8) -> &'static str {
+    match month {
         1 => "Jan",
         2 => "Feb",
         3 => "Mar",
@@ -3,9 +3,16 @@ use core::fmt::Write;
 use crate::{
     civil::Weekday,
     error::{err, ErrorContext},
-    fmt::strtime::{BrokenDownTime, Extension, Flag, Meridiem},
+    fmt::{
+        strtime::{
+            BrokenDownTime, Directive, Extension, Flag, Locale, Meridiem,
+            Prefix,
+        },
+        LeapSecond,
+    },
     tz::Offset,
     util::{
+        array_str::Abbreviation,
         escape, parse,
         rangeint::{ri8, RFrom},
         t::{self, C},
@@ -20,13 +27,21 @@ type ParsedOffsetHours = ri8<0, { t::SpanZoneOffsetHours::MAX }>;
 type ParsedOffsetMinutes = ri8<0, { t::SpanZoneOffsetMinutes::MAX }>;
 type ParsedOffsetSeconds = ri8<0, { t::SpanZoneOffsetSeconds::MAX }>;
 
-pub(super) struct Parser<'f, 'i, 't> {
+pub(super) struct Parser<'c, 'f, 'i, 't> {
+    /// The locale providing names for months, weekdays and AM/PM. When
+    /// absent, the `C` locale is used via specialized routines.
+    pub(super) locale: Option<&'c dyn Locale>,
+    /// The first year of the 100 year window that `%y` and `%g` resolve
+    /// two-digit years into.
+    pub(super) two_digit_year_base: i16,
+    /// How to handle a seconds value of `60`.
+    pub(super) leap_second: LeapSecond,
     pub(super) fmt: &'f [u8],
     pub(super) inp: &'i [u8],
     pub(super) tm: &'t mut BrokenDownTime,
 }
 
-impl<'f, 'i, 't> Parser<'f, 'i, 't> {
+impl<'c, 'f, 'i, 't> Parser<'c, 'f, 'i, 't> {
     pub(super) fn parse(&mut self) -> Result<(), Error> {
         while !self.fmt.is_empty() {
             if self.f() != b'%' {
@@ -39,23 +54,38 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                      but found end of format string",
                 ));
             }
-            // We don't check this for `%.` since that currently always
-            // must lead to `%.f` which can actually parse the empty string!
-            if self.inp.is_empty() && self.f() != b'.' {
-                return Err(err!(
-                    "expected non-empty input for directive %{directive}, \
-                     but found end of input",
-                    directive = escape::Byte(self.f()),
-                ));
-            }
-            // Parse extensions like padding/case options and padding width.
-            let ext = self.parse_extension()?;
-            match self.f() {
+            let (directive, fmt) = Directive::parse(self.fmt)?;
+            self.fmt = fmt;
+            self.parse_directive(directive)?;
+        }
+        Ok(())
+    }
+
+    /// Parses a single directive, like `%-d` or `%:z`.
+    pub(super) fn parse_directive(
+        &mut self,
+        directive: Directive,
+    ) -> Result<(), Error> {
+        // We don't check this for `%.` since that currently always
+        // must lead to `%.f` which can actually parse the empty string!
+        if self.inp.is_empty() && directive.prefix != Some(Prefix::Dot) {
+            return Err(err!(
+                "expected non-empty input for directive {directive}, \
+                 but found end of input",
+            ));
+        }
+        let ext = directive.ext;
+        match directive.prefix {
+            None => match directive.specifier {
                 b'%' => self.parse_percent().context("%% failed")?,
-                b'A' => self.parse_weekday_full().context("%A failed")?,
-                b'a' => self.parse_weekday_abbrev().context("%a failed")?,
-                b'B' => self.parse_month_name_full().context("%B failed")?,
-                b'b' => self.parse_month_name_abbrev().context("%b failed")?,
+                b'A' => self.parse_weekday_full(ext).context("%A failed")?,
+                b'a' => self.parse_weekday_abbrev(ext).context("%a failed")?,
+                b'B' => {
+                    self.parse_month_name_full(ext).context("%B failed")?
+                }
+                b'b' => {
+                    self.parse_month_name_abbrev(ext).context("%b failed")?
+                }
                 b'C' => self.parse_century(ext).context("%C failed")?,
                 b'D' => self.parse_american_date().context("%D failed")?,
                 b'd' => self.parse_day(ext).context("%d failed")?,
@@ -65,7 +95,9 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                 b'G' => self.parse_iso_week_year(ext).context("%G failed")?,
                 b'g' => self.parse_iso_week_year2(ext).context("%g failed")?,
                 b'H' => self.parse_hour24(ext).context("%H failed")?,
-                b'h' => self.parse_month_name_abbrev().context("%h failed")?,
+                b'h' => {
+                    self.parse_month_name_abbrev(ext).context("%h failed")?
+                }
                 b'I' => self.parse_hour12(ext).context("%I failed")?,
                 b'j' => self.parse_day_of_year(ext).context("%j failed")?,
                 b'k' => self.parse_hour24(ext).context("%k failed")?,
@@ -73,8 +105,8 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                 b'M' => self.parse_minute(ext).context("%M failed")?,
                 b'm' => self.parse_month(ext).context("%m failed")?,
                 b'n' => self.parse_whitespace().context("%n failed")?,
-                b'P' => self.parse_ampm().context("%P failed")?,
-                b'p' => self.parse_ampm().context("%p failed")?,
+                b'P' => self.parse_ampm(ext).context("%P failed")?,
+                b'p' => self.parse_ampm(ext).context("%p failed")?,
                 b'Q' => self.parse_iana_nocolon().context("%Q failed")?,
                 b'R' => self.parse_clock_nosecs().context("%R failed")?,
                 b'S' => self.parse_second(ext).context("%S failed")?,
@@ -86,67 +118,40 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                 b'V' => self.parse_week_iso(ext).context("%V failed")?,
                 b'W' => self.parse_week_mon(ext).context("%W failed")?,
                 b'w' => self.parse_weekday_sun(ext).context("%w failed")?,
+                b'X' => self.parse_clock_secs().context("%X failed")?,
+                b'x' => self.parse_american_date().context("%x failed")?,
                 b'Y' => self.parse_year(ext).context("%Y failed")?,
                 b'y' => self.parse_year2(ext).context("%y failed")?,
                 b'z' => self.parse_offset_nocolon().context("%z failed")?,
-                b':' => {
-                    if !self.bump_fmt() {
-                        return Err(err!(
-                            "invalid format string, expected directive \
-                             after '%:'",
-                        ));
-                    }
-                    match self.f() {
-                        b'Q' => {
-                            self.parse_iana_colon().context("%:Q failed")?
-                        }
-                        b'z' => {
-                            self.parse_offset_colon().context("%:z failed")?
-                        }
-                        unk => {
-                            return Err(err!(
-                                "found unrecognized directive %{unk} \
-                                 following %:",
-                                unk = escape::Byte(unk),
-                            ));
-                        }
-                    }
+                b'Z' => self.parse_tzabbrev(ext).context("%Z failed")?,
+                unk => {
+                    return Err(err!(
+                        "found unrecognized directive %{unk}",
+                        unk = escape::Byte(unk),
+                    ));
                 }
-                b'Z' => {
-                    return Err(err!("cannot parse time zone abbreviations"));
+            },
+            Some(Prefix::Colon) => match directive.specifier {
+                b'Q' => self.parse_iana_colon().context("%:Q failed")?,
+                b'z' => self.parse_offset_colon().context("%:z failed")?,
+                unk => {
+                    return Err(err!(
+                        "found unrecognized directive %{unk} following %:",
+                        unk = escape::Byte(unk),
+                    ));
                 }
-                b'.' => {
-                    if !self.bump_fmt() {
-                        return Err(err!(
-                            "invalid format string, expected directive \
-                             after '%.'",
-                        ));
-                    }
-                    // Skip over any precision settings that might be here.
-                    // This is a specific special format supported by `%.f`.
-                    let (width, fmt) = Extension::parse_width(self.fmt)?;
-                    let ext = Extension { width, ..ext };
-                    self.fmt = fmt;
-                    match self.f() {
-                        b'f' => self
-                            .parse_dot_fractional(ext)
-                            .context("%.f failed")?,
-                        unk => {
-                            return Err(err!(
-                                "found unrecognized directive %{unk} \
-                                 following %.",
-                                unk = escape::Byte(unk),
-                            ));
-                        }
-                    }
+            },
+            Some(Prefix::Dot) => match directive.specifier {
+                b'f' => {
+                    self.parse_dot_fractional(ext).context("%.f failed")?
                 }
                 unk => {
                     return Err(err!(
-                        "found unrecognized directive %{unk}",
+                        "found unrecognized directive %{unk} following %.",
                         unk = escape::Byte(unk),
                     ));
                 }
-            }
+            },
         }
         Ok(())
     }
@@ -187,16 +192,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         !self.inp.is_empty()
     }
 
-    /// Parses optional extensions before a specifier directive. That is, right
-    /// after the `%`. If any extensions are parsed, the parser is bumped
-    /// to the next byte. (If no next byte exists, then an error is returned.)
-    fn parse_extension(&mut self) -> Result<Extension, Error> {
-        let (flag, fmt) = Extension::parse_flag(self.fmt)?;
-        let (width, fmt) = Extension::parse_width(fmt)?;
-        self.fmt = fmt;
-        Ok(Extension { flag, width })
-    }
-
     // We write out a parsing routine for each directive below. Each parsing
     // routine assumes that the parser is positioned immediately after the
     // `%` for the current directive, and that there is at least one unconsumed
@@ -240,7 +235,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         if !self.inp.is_empty() {
             while self.i().is_ascii_whitespace() && self.bump_input() {}
         }
-        self.bump_fmt();
         Ok(())
     }
 
@@ -253,17 +247,22 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                 byte = escape::Byte(self.inp[0]),
             ));
         }
-        self.bump_fmt();
         self.bump_input();
         Ok(())
     }
 
     /// Parses `%D`, which is equivalent to `%m/%d/%y`.
     fn parse_american_date(&mut self) -> Result<(), Error> {
-        let mut p = Parser { fmt: b"%m/%d/%y", inp: self.inp, tm: self.tm };
+        let mut p = Parser {
+            locale: self.locale,
+            two_digit_year_base: self.two_digit_year_base,
+            leap_second: self.leap_second,
+            fmt: b"%m/%d/%y",
+            inp: self.inp,
+            tm: self.tm,
+        };
         p.parse()?;
         self.inp = p.inp;
-        self.bump_fmt();
         Ok(())
     }
 
@@ -272,8 +271,16 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
     /// This is generally only useful with `%I`. If, say, `%H` is used, then
     /// the AM/PM moniker will be validated, but it doesn't actually influence
     /// the clock time.
-    fn parse_ampm(&mut self) -> Result<(), Error> {
-        let (index, inp) = parse_ampm(self.inp)?;
+    fn parse_ampm(&mut self, ext: Extension) -> Result<(), Error> {
+        self.inp = ext.skip_text_padding(self.inp);
+        let (index, inp) = match self.locale {
+            None => parse_ampm(self.inp)?,
+            Some(locale) => {
+                let names = [Meridiem::AM, Meridiem::PM]
+                    .map(|meridiem| locale.meridiem(meridiem));
+                parse_locale_name(self.inp, "AM or PM", &names)?
+            }
+        };
         self.inp = inp;
 
         self.tm.meridiem = Some(match index {
@@ -282,25 +289,36 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
             // OK because 0 <= index <= 1.
             index => unreachable!("unknown AM/PM index {index}"),
         });
-        self.bump_fmt();
         Ok(())
     }
 
     /// Parses `%T`, which is equivalent to `%H:%M:%S`.
     fn parse_clock_secs(&mut self) -> Result<(), Error> {
-        let mut p = Parser { fmt: b"%H:%M:%S", inp: self.inp, tm: self.tm };
+        let mut p = Parser {
+            locale: self.locale,
+            two_digit_year_base: self.two_digit_year_base,
+            leap_second: self.leap_second,
+            fmt: b"%H:%M:%S",
+            inp: self.inp,
+            tm: self.tm,
+        };
         p.parse()?;
         self.inp = p.inp;
-        self.bump_fmt();
         Ok(())
     }
 
     /// Parses `%R`, which is equivalent to `%H:%M`.
     fn parse_clock_nosecs(&mut self) -> Result<(), Error> {
-        let mut p = Parser { fmt: b"%H:%M", inp: self.inp, tm: self.tm };
+        let mut p = Parser {
+            locale: self.locale,
+            two_digit_year_base: self.two_digit_year_base,
+            leap_second: self.leap_second,
+            fmt: b"%H:%M",
+            inp: self.inp,
+            tm: self.tm,
+        };
         p.parse()?;
         self.inp = p.inp;
-        self.bump_fmt();
         Ok(())
     }
 
@@ -316,7 +334,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let day =
             t::Day::try_new("day", day).context("day number is invalid")?;
         self.tm.day = Some(day);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -332,7 +349,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let day = t::DayOfYear::try_new("day-of-year", day)
             .context("day of year number is invalid")?;
         self.tm.day_of_year = Some(day);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -346,7 +362,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let hour = t::Hour::try_new("hour", hour)
             .context("hour number is invalid")?;
         self.tm.hour = Some(hour);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -362,16 +377,21 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let hour =
             Hour12::try_new("hour", hour).context("hour number is invalid")?;
         self.tm.hour = Some(t::Hour::rfrom(hour));
-        self.bump_fmt();
         Ok(())
     }
 
     /// Parses `%F`, which is equivalent to `%Y-%m-%d`.
     fn parse_iso_date(&mut self) -> Result<(), Error> {
-        let mut p = Parser { fmt: b"%Y-%m-%d", inp: self.inp, tm: self.tm };
+        let mut p = Parser {
+            locale: self.locale,
+            two_digit_year_base: self.two_digit_year_base,
+            leap_second: self.leap_second,
+            fmt: b"%Y-%m-%d",
+            inp: self.inp,
+            tm: self.tm,
+        };
         p.parse()?;
         self.inp = p.inp;
-        self.bump_fmt();
         Ok(())
     }
 
@@ -385,7 +405,19 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let minute = t::Minute::try_new("minute", minute)
             .context("minute number is invalid")?;
         self.tm.minute = Some(minute);
-        self.bump_fmt();
+        Ok(())
+    }
+
+    /// Parse `%Z`, which is a time zone abbreviation.
+    ///
+    /// The abbreviation is only recorded. It doesn't set an offset or time
+    /// zone until it is resolved via
+    /// `BrokenDownTime::resolve_tz_abbreviation`.
+    fn parse_tzabbrev(&mut self, ext: Extension) -> Result<(), Error> {
+        self.inp = ext.skip_text_padding(self.inp);
+        let (abbrev, inp) = parse_tzabbrev(self.inp)?;
+        self.inp = inp;
+        self.tm.tzabbrev = Some(abbrev);
         Ok(())
     }
 
@@ -408,7 +440,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
             let (iana, inp) = parse_iana(self.inp)?;
             self.inp = inp;
             self.tm.iana = Some(iana.to_string());
-            self.bump_fmt();
             Ok(())
         }
     }
@@ -432,7 +463,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
             let (iana, inp) = parse_iana(self.inp)?;
             self.inp = inp;
             self.tm.iana = Some(iana.to_string());
-            self.bump_fmt();
             Ok(())
         }
     }
@@ -500,7 +530,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let offset = Offset::from_seconds_ranged(seconds * sign);
         self.tm.offset = Some(offset);
         self.inp = inp;
-        self.bump_fmt();
 
         Ok(())
     }
@@ -576,7 +605,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let offset = Offset::from_seconds_ranged(seconds * sign);
         self.tm.offset = Some(offset);
         self.inp = inp;
-        self.bump_fmt();
 
         Ok(())
     }
@@ -588,16 +616,29 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
             .context("failed to parse second")?;
         self.inp = inp;
 
-        // As with other parses in Jiff, and like Temporal,
-        // we constrain `60` seconds to `59` because we don't
-        // support leap seconds.
+        // As with other parses in Jiff, and like Temporal, we
+        // constrain `60` seconds to `59` by default because we
+        // don't support leap seconds. Other policies are applied
+        // when the broken down time is converted to a civil time.
+        self.tm.leap_second = None;
         if second == 60 {
+            match self.leap_second {
+                LeapSecond::Constrain => {}
+                LeapSecond::Reject => {
+                    return Err(err!(
+                        "leap second is not allowed \
+                         (found second value of 60)",
+                    ));
+                }
+                LeapSecond::Clamp | LeapSecond::Preserve => {
+                    self.tm.leap_second = Some(self.leap_second);
+                }
+            }
             second = 59;
         }
         let second = t::Second::try_new("second", second)
             .context("second number is invalid")?;
         self.tm.second = Some(second);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -641,7 +682,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         self.tm.subsec = Some(t.subsec_nanosecond_ranged());
         self.tm.meridiem = Some(Meridiem::from(t));
 
-        self.bump_fmt();
         Ok(())
     }
 
@@ -685,7 +725,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                 |err| err!("fractional nanoseconds are not valid: {err}"),
             )?;
         self.tm.subsec = Some(nanoseconds);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -694,7 +733,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
     /// dot, then this successfully parses the empty string.
     fn parse_dot_fractional(&mut self, ext: Extension) -> Result<(), Error> {
         if !self.inp.starts_with(b".") {
-            self.bump_fmt();
             return Ok(());
         }
         self.inp = &self.inp[1..];
@@ -711,24 +749,33 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let month = t::Month::try_new("month", month)
             .context("month number is invalid")?;
         self.tm.month = Some(month);
-        self.bump_fmt();
         Ok(())
     }
 
     /// Parse `%b` or `%h`, which is an abbreviated month name.
-    fn parse_month_name_abbrev(&mut self) -> Result<(), Error> {
-        let (index, inp) = parse_month_name_abbrev(self.inp)?;
+    fn parse_month_name_abbrev(
+        &mut self,
+        ext: Extension,
+    ) -> Result<(), Error> {
+        self.inp = ext.skip_text_padding(self.inp);
+        let (index, inp) = match self.locale {
+            None => parse_month_name_abbrev(self.inp)?,
+            Some(locale) => {
+                let names = month_names(|m| locale.month_name_abbrev(m));
+                parse_locale_name(self.inp, "month name abbreviation", &names)?
+            }
+        };
         self.inp = inp;
 
         // Both are OK because 0 <= index <= 11.
         let index = i8::try_from(index).unwrap();
         self.tm.month = Some(t::Month::new(index + 1).unwrap());
-        self.bump_fmt();
         Ok(())
     }
 
     /// Parse `%B`, which is a full month name.
-    fn parse_month_name_full(&mut self) -> Result<(), Error> {
+    fn parse_month_name_full(&mut self, ext: Extension) -> Result<(), Error> {
+        self.inp = ext.skip_text_padding(self.inp);
         static CHOICES: &'static [&'static [u8]] = &[
             b"January",
             b"February",
@@ -744,32 +791,44 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
             b"December",
         ];
 
-        let (index, inp) = parse_choice(self.inp, CHOICES)
-            .context("unrecognized month name")?;
+        let (index, inp) = match self.locale {
+            None => parse_choice(self.inp, CHOICES)
+                .context("unrecognized month name")?,
+            Some(locale) => {
+                let names = month_names(|m| locale.month_name_full(m));
+                parse_locale_name(self.inp, "month name", &names)?
+            }
+        };
         self.inp = inp;
 
         // Both are OK because 0 <= index <= 11.
         let index = i8::try_from(index).unwrap();
         self.tm.month = Some(t::Month::new(index + 1).unwrap());
-        self.bump_fmt();
         Ok(())
     }
 
     /// Parse `%a`, which is an abbreviated weekday.
-    fn parse_weekday_abbrev(&mut self) -> Result<(), Error> {
-        let (index, inp) = parse_weekday_abbrev(self.inp)?;
+    fn parse_weekday_abbrev(&mut self, ext: Extension) -> Result<(), Error> {
+        self.inp = ext.skip_text_padding(self.inp);
+        let (index, inp) = match self.locale {
+            None => parse_weekday_abbrev(self.inp)?,
+            Some(locale) => {
+                let names = weekday_names(|wd| locale.weekday_name_abbrev(wd));
+                parse_locale_name(self.inp, "weekday abbreviation", &names)?
+            }
+        };
         self.inp = inp;
 
         // Both are OK because 0 <= index <= 6.
         let index = i8::try_from(index).unwrap();
         self.tm.weekday =
             Some(Weekday::from_sunday_zero_offset(index).unwrap());
-        self.bump_fmt();
         Ok(())
     }
 
     /// Parse `%A`, which is a full weekday name.
-    fn parse_weekday_full(&mut self) -> Result<(), Error> {
+    fn parse_weekday_full(&mut self, ext: Extension) -> Result<(), Error> {
+        self.inp = ext.skip_text_padding(self.inp);
         static CHOICES: &'static [&'static [u8]] = &[
             b"Sunday",
             b"Monday",
@@ -780,15 +839,20 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
             b"Saturday",
         ];
 
-        let (index, inp) = parse_choice(self.inp, CHOICES)
-            .context("unrecognized weekday abbreviation")?;
+        let (index, inp) = match self.locale {
+            None => parse_choice(self.inp, CHOICES)
+                .context("unrecognized weekday abbreviation")?,
+            Some(locale) => {
+                let names = weekday_names(|wd| locale.weekday_name_full(wd));
+                parse_locale_name(self.inp, "weekday name", &names)?
+            }
+        };
         self.inp = inp;
 
         // Both are OK because 0 <= index <= 6.
         let index = i8::try_from(index).unwrap();
         self.tm.weekday =
             Some(Weekday::from_sunday_zero_offset(index).unwrap());
-        self.bump_fmt();
         Ok(())
     }
 
@@ -806,7 +870,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let weekday = Weekday::from_monday_one_offset(weekday)
             .context("weekday number is invalid")?;
         self.tm.weekday = Some(weekday);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -823,7 +886,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let weekday = Weekday::from_sunday_zero_offset(weekday)
             .context("weekday number is invalid")?;
         self.tm.weekday = Some(weekday);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -838,7 +900,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let week = t::WeekNum::try_new("week", week)
             .context("Sunday-based week number is invalid")?;
         self.tm.week_sun = Some(week);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -852,7 +913,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let week = t::ISOWeek::try_new("week", week)
             .context("ISO 8601 week number is invalid")?;
         self.tm.iso_week = Some(week);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -867,7 +927,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let week = t::WeekNum::try_new("week", week)
             .context("Monday-based week number is invalid")?;
         self.tm.week_mon = Some(week);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -885,13 +944,13 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let year = t::Year::try_new("year", year)
             .context("year number is invalid")?;
         self.tm.year = Some(year);
-        self.bump_fmt();
         Ok(())
     }
 
     /// Parses `%y`, which is equivalent to a 2-digit year.
     ///
-    /// The numbers 69-99 refer to 1969-1999, while 00-68 refer to 2000-2068.
+    /// By default, the numbers 69-99 refer to 1969-1999, while 00-68 refer to
+    /// 2000-2068. This can be changed via `Config::two_digit_year_base`.
     fn parse_year2(&mut self, ext: Extension) -> Result<(), Error> {
         type Year2Digit = ri8<0, 99>;
 
@@ -902,14 +961,10 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
 
         let year = Year2Digit::try_new("year (2 digits)", year)
             .context("year number is invalid")?;
-        let mut year = t::Year::rfrom(year);
-        if year <= C(68) {
-            year += C(2000);
-        } else {
-            year += C(1900);
-        }
+        let year = resolve_two_digit_year(self.two_digit_year_base, year);
+        let year = t::Year::try_new("year", year)
+            .context("year number is invalid")?;
         self.tm.year = Some(year);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -932,7 +987,6 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let year = t::Year::try_new("year", year)
             .context("year number (from century) is invalid")?;
         self.tm.year = Some(year);
-        self.bump_fmt();
         Ok(())
     }
 
@@ -950,13 +1004,12 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let year = t::ISOYear::try_new("year", year)
             .context("ISO 8601 week-based year number is invalid")?;
         self.tm.iso_week_year = Some(year);
-        self.bump_fmt();
         Ok(())
     }
 
     /// Parses `%g`, which is equivalent to a 2-digit ISO 8601 week-based year.
     ///
-    /// The numbers 69-99 refer to 1969-1999, while 00-68 refer to 2000-2068.
+    /// This resolves two-digit years in the same way as `%y`.
     fn parse_iso_week_year2(&mut self, ext: Extension) -> Result<(), Error> {
         type Year2Digit = ri8<0, 99>;
 
@@ -967,19 +1020,36 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
 
         let year = Year2Digit::try_new("year (2 digits)", year)
             .context("ISO 8601 week-based year number is invalid")?;
-        let mut year = t::ISOYear::rfrom(year);
-        if year <= C(68) {
-            year += C(2000);
-        } else {
-            year += C(1900);
-        }
+        let year = resolve_two_digit_year(self.two_digit_year_base, year);
+        let year = t::ISOYear::try_new("year", year)
+            .context("ISO 8601 week-based year number is invalid")?;
         self.tm.iso_week_year = Some(year);
-        self.bump_fmt();
         Ok(())
     }
 }
 
 impl Extension {
+    /// Skips the padding written before a string value when a width is
+    /// given, e.g., the leading spaces in `    Friday` for `%10A`.
+    ///
+    /// At most `width` bytes are skipped. Spaces are skipped by default, or
+    /// zeros when the `0` flag is used. Nothing is skipped when there is no
+    /// width or when the `-` flag is used.
+    fn skip_text_padding(self, inp: &[u8]) -> &[u8] {
+        let Some(width) = self.width else { return inp };
+        let pad_byte = match self.flag {
+            Some(Flag::NoPad) => return inp,
+            Some(Flag::PadZero) => b'0',
+            _ => b' ',
+        };
+        let len = inp
+            .iter()
+            .take(usize::from(width))
+            .take_while(|&&b| b == pad_byte)
+            .count();
+        &inp[len..]
+    }
+
     /// Parse an integer with the given default padding and flag settings.
     ///
     /// The default padding is usually 2 (4 for %Y) and the default flag is
@@ -1048,6 +1118,17 @@ impl Extension {
     }
 }
 
+/// Resolves a two-digit year to the unique year in `base..=base+99` that
+/// ends in the same two digits. (More precisely, the year that is equivalent
+/// to it modulo 100, which matters for negative years.)
+///
+/// `base` must be in the range `-9999..=9900`, which guarantees that the
+/// year returned is in Jiff's supported range.
+fn resolve_two_digit_year(base: i16, year: ri8<0, 99>) -> i64 {
+    let base = i64::from(base);
+    base + (year.get() as i64 - base).rem_euclid(100)
+}
+
 /// Parses an optional sign from the beginning of the input. If one isn't
 /// found, then the sign returned is positive.
 ///
@@ -1129,6 +1210,58 @@ fn parse_choice<'i>(
     }
 }
 
+/// Parses one of the names given, as provided by a `Locale`, from the
+/// beginning of the input. On success, the index of the matching name is
+/// returned along with any remaining unparsed input.
+///
+/// Names are compared without regard to ASCII case, and the longest matching
+/// name wins. Empty names never match.
+///
+/// `what` is used in error messages to describe the kind of name expected.
+fn parse_locale_name<'i>(
+    input: &'i [u8],
+    what: &str,
+    names: &[&str],
+) -> Result<(usize, &'i [u8]), Error> {
+    let mut found: Option<(usize, usize)> = None;
+    for (i, name) in names.iter().enumerate() {
+        let name = name.as_bytes();
+        if name.is_empty() || input.len() < name.len() {
+            continue;
+        }
+        if !input[..name.len()].eq_ignore_ascii_case(name) {
+            continue;
+        }
+        if found.map_or(true, |(_, len)| name.len() > len) {
+            found = Some((i, name.len()));
+        }
+    }
+    let Some((index, len)) = found else {
+        return Err(err!(
+            "expected to find {what} from the configured locale at the \
+             beginning of {input:?}, but none matched",
+            input = escape::Bytes(input),
+        ));
+    };
+    Ok((index, &input[len..]))
+}
+
+/// Returns the names of each month, in order, as given by `name`.
+fn month_names<'l>(name: impl Fn(i8) -> &'l str) -> [&'l str; 12] {
+    // OK because 0 <= i <= 11.
+    core::array::from_fn(|i| name(i8::try_from(i).unwrap() + 1))
+}
+
+/// Returns the names of each weekday, in order starting with Sunday, as
+/// given by `name`.
+fn weekday_names<'l>(name: impl Fn(Weekday) -> &'l str) -> [&'l str; 7] {
+    core::array::from_fn(|i| {
+        // OK because 0 <= i <= 6.
+        let i = i8::try_from(i).unwrap();
+        name(Weekday::from_sunday_zero_offset(i).unwrap())
+    })
+}
+
 /// Like `parse_choice`, but specialized for AM/PM.
 ///
 /// This exists because AM/PM is common and we can take advantage of the fact
@@ -1247,6 +1380,45 @@ fn parse_month_name_abbrev<'i>(
 }
 
 #[cfg_attr(feature = "perf-inline", inline(always))]
+/// Parses a time zone abbreviation, like `EST` or `+0530`, from the start of
+/// `input`.
+///
+/// This accepts any non-empty sequence of ASCII letters, ASCII digits, `+` and
+/// `-`, which covers the abbreviations found in the tz database. The
+/// abbreviation must fit in an `Abbreviation`.
+fn parse_tzabbrev(input: &[u8]) -> Result<(Abbreviation, &[u8]), Error> {
+    let mut len = 0;
+    while len < input.len()
+        && matches!(input[len], b'+' | b'-' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z')
+    {
+        len += 1;
+    }
+    if len == 0 {
+        return Err(match input.first() {
+            None => err!(
+                "expected to find time zone abbreviation, \
+                 but found end of input",
+            ),
+            Some(&byte) => err!(
+                "expected to find time zone abbreviation, \
+                 but found {byte:?} instead",
+                byte = escape::Byte(byte),
+            ),
+        });
+    }
+    let (abbrev, input) = input.split_at(len);
+    // OK because we only matched ASCII bytes above.
+    let abbrev = core::str::from_utf8(abbrev).expect("ASCII");
+    let abbrev = Abbreviation::new(abbrev).ok_or_else(|| {
+        err!(
+            "time zone abbreviation {abbrev:?} is too long \
+             (maximum length is {max})",
+            max = Abbreviation::capacity(),
+        )
+    })?;
+    Ok((abbrev, input))
+}
+
 fn parse_iana<'i>(input: &'i [u8]) -> Result<(&'i str, &'i [u8]), Error> {
     let mkiana = parse::slicer(input);
     let (_, mut input) = parse_iana_component(input)?;
@@ -1303,6 +1475,11 @@ fn parse_iana_component<'i>(
 mod tests {
     use alloc::string::ToString;
 
+    use crate::fmt::strtime::{
+        AbbreviationAmbiguity, AbbreviationTarget, CLocale, CompiledFormat,
+        Config, TimeZoneAbbreviations,
+    };
+
     use super::*;
 
     #[test]
@@ -1449,6 +1626,10 @@ mod tests {
             p("%D", "1/1/99"),
             @"1999-01-01",
         );
+        insta::assert_debug_snapshot!(
+            p("%x", "1/1/99"),
+            @"1999-01-01",
+        );
         insta::assert_debug_snapshot!(
             p("%m/%d/%Y", "1/1/0099"),
             @"0099-01-01",
@@ -1582,6 +1763,19 @@ mod tests {
             p("%Y-%W-%u", "2025-01-2"),
             @"2025-01-07",
         );
+
+        insta::assert_debug_snapshot!(
+            p("%10A, %5b %_3e %Y", "  Thursday,   Jul   4 2024"),
+            @"2024-07-04",
+        );
+        insta::assert_debug_snapshot!(
+            p("%05a %Y-%m-%d", "00Thu 2024-07-04"),
+            @"2024-07-04",
+        );
+        insta::assert_debug_snapshot!(
+            p("%-10a %F", "Thu 2024-07-04"),
+            @"2024-07-04",
+        );
     }
 
     #[test]
@@ -1609,6 +1803,10 @@ mod tests {
             p("%T", "15:48:59"),
             @"15:48:59",
         );
+        insta::assert_debug_snapshot!(
+            p("%X", "15:48:59"),
+            @"15:48:59",
+        );
         insta::assert_debug_snapshot!(
             p("%R", "15:48"),
             @"15:48:00",
@@ -1775,6 +1973,281 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ok_parse_tzabbrev() {
+        static ABBREVS: TimeZoneAbbreviations = TimeZoneAbbreviations::new(&[
+            ("EST", AbbreviationTarget::Offset(crate::tz::offset(-5))),
+            ("EDT", AbbreviationTarget::Offset(crate::tz::offset(-4))),
+            ("CST", AbbreviationTarget::Offset(crate::tz::offset(-6))),
+            ("CST", AbbreviationTarget::Offset(crate::tz::offset(8))),
+            ("UTC", AbbreviationTarget::Offset(crate::tz::offset(0))),
+            ("UTC", AbbreviationTarget::Offset(crate::tz::offset(0))),
+            ("ET", AbbreviationTarget::TimeZone("America/New_York")),
+        ]);
+        let p = |fmt: &str, input: &str, abbrevs: &TimeZoneAbbreviations| {
+            let mut tm =
+                BrokenDownTime::parse_mono(fmt.as_bytes(), input.as_bytes())
+                    .unwrap();
+            tm.resolve_tz_abbreviation(abbrevs).unwrap();
+            tm.to_zoned().unwrap()
+        };
+
+        insta::assert_debug_snapshot!(
+            p("%F %T %Z", "2024-07-14 21:14:00 EDT", &ABBREVS),
+            @"2024-07-14T21:14:00-04:00[-04:00]",
+        );
+        insta::assert_debug_snapshot!(
+            p("%F %T %Z", "2024-07-14 21:14:00 est", &ABBREVS),
+            @"2024-07-14T21:14:00-05:00[-05:00]",
+        );
+        // Duplicate entries with the same target aren't ambiguous.
+        insta::assert_debug_snapshot!(
+            p("%F %T %Z", "2024-07-14 21:14:00 UTC", &ABBREVS),
+            @"2024-07-14T21:14:00+00:00[UTC]",
+        );
+        insta::assert_debug_snapshot!(
+            p(
+                "%F %T %Z",
+                "2024-07-14 21:14:00 CST",
+                &ABBREVS.ambiguity(AbbreviationAmbiguity::First),
+            ),
+            @"2024-07-14T21:14:00-06:00[-06:00]",
+        );
+        insta::assert_debug_snapshot!(
+            p("%F %T %Z %z", "2024-07-14 21:14:00 EDT -0400", &ABBREVS),
+            @"2024-07-14T21:14:00-04:00[-04:00]",
+        );
+        let ist = Offset::from_seconds(5 * 60 * 60 + 30 * 60).unwrap();
+        let entries = [("+0530", AbbreviationTarget::Offset(ist))];
+        insta::assert_debug_snapshot!(
+            p(
+                "%FT%T%Z",
+                "2024-07-14T21:14:00+0530",
+                &TimeZoneAbbreviations::new(&entries),
+            ),
+            @"2024-07-14T21:14:00+05:30[+05:30]",
+        );
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+        insta::assert_debug_snapshot!(
+            p("%F %T %Z", "2024-07-14 21:14:00 ET", &ABBREVS),
+            @"2024-07-14T21:14:00-04:00[America/New_York]",
+        );
+    }
+
+    #[test]
+    fn ok_parse_tzabbrev_military() {
+        let p = |input: &str| {
+            let mut tm =
+                BrokenDownTime::parse_mono(b"%F %H%M%Z", input.as_bytes())
+                    .unwrap();
+            tm.resolve_tz_abbreviation(&TimeZoneAbbreviations::MILITARY)
+                .unwrap();
+            tm.to_zoned().unwrap()
+        };
+
+        insta::assert_debug_snapshot!(
+            p("2024-07-14 2114Z"),
+            @"2024-07-14T21:14:00+00:00[UTC]",
+        );
+        insta::assert_debug_snapshot!(
+            p("2024-07-14 2114b"),
+            @"2024-07-14T21:14:00+02:00[+02:00]",
+        );
+        insta::assert_debug_snapshot!(
+            p("2024-07-14 2114R"),
+            @"2024-07-14T21:14:00-05:00[-05:00]",
+        );
+
+        // The mapping agrees with the one used by RFC 2822 lenient parsing.
+        for letter in b'A'..=b'Z' {
+            let abbrev = [letter];
+            let abbrev = core::str::from_utf8(&abbrev).unwrap();
+            let got = TimeZoneAbbreviations::MILITARY.resolve(abbrev).ok();
+            let expected = crate::fmt::util::military_time_zone_offset(letter)
+                .map(AbbreviationTarget::Offset);
+            assert_eq!(got, expected, "letter {abbrev}");
+        }
+    }
+
+    #[test]
+    fn err_parse_tzabbrev() {
+        static ABBREVS: TimeZoneAbbreviations = TimeZoneAbbreviations::new(&[
+            ("CST", AbbreviationTarget::Offset(crate::tz::offset(-6))),
+            ("CST", AbbreviationTarget::Offset(crate::tz::offset(8))),
+            ("EDT", AbbreviationTarget::Offset(crate::tz::offset(-4))),
+            ("ET", AbbreviationTarget::TimeZone("America/New_York")),
+        ]);
+        let p = |fmt: &str, input: &str| {
+            BrokenDownTime::parse_mono(fmt.as_bytes(), input.as_bytes())
+                .unwrap_err()
+                .to_string()
+        };
+        let r = |fmt: &str, input: &str| {
+            let mut tm =
+                BrokenDownTime::parse_mono(fmt.as_bytes(), input.as_bytes())
+                    .unwrap();
+            tm.resolve_tz_abbreviation(&ABBREVS).unwrap_err().to_string()
+        };
+
+        insta::assert_snapshot!(p("%Z", ""), @"strptime parsing failed: expected non-empty input for directive %Z, but found end of input");
+        insta::assert_snapshot!(p("%Z", " EST"), @r###"strptime parsing failed: %Z failed: expected to find time zone abbreviation, but found " " instead"###);
+        insta::assert_snapshot!(
+            p("%Z", "ABCDEFGHIJKLMNOPQRSTUVWXYZABCDE"),
+            @r###"strptime parsing failed: %Z failed: time zone abbreviation "ABCDEFGHIJKLMNOPQRSTUVWXYZABCDE" is too long (maximum length is 30)"###,
+        );
+        insta::assert_snapshot!(r("%Z", "XYZ"), @r###"unrecognized time zone abbreviation "XYZ""###);
+        insta::assert_snapshot!(r("%Z", "CST"), @r###"time zone abbreviation "CST" is ambiguous, since it could refer to offset -06 or offset +08"###);
+        insta::assert_snapshot!(r("%Z %z", "EDT -0500"), @r###"time zone abbreviation "EDT" resolved to offset -04, but parsed offset -05 is different"###);
+        insta::assert_snapshot!(r("%Z %Q", "ET America/Chicago"), @r###"time zone abbreviation "ET" resolved to IANA time zone identifier "America/New_York", but parsed identifier "America/Chicago" is different"###);
+
+        let tm = BrokenDownTime::parse_mono(
+            b"%F %T %Z",
+            b"2024-07-14 21:14:00 EDT",
+        )
+        .unwrap();
+        insta::assert_snapshot!(tm.to_zoned().unwrap_err(), @r###"either offset (from %z) or IANA time zone identifier (from %Q) is required for parsing zoned datetime, but only time zone abbreviation "EDT" (from %Z) was found, which must be resolved to an offset or time zone first"###);
+        insta::assert_snapshot!(tm.to_timestamp().unwrap_err(), @r###"offset required to parse timestamp: parsing format did not include time zone offset directive, and time zone abbreviation "EDT" (from %Z) was not resolved to an offset"###);
+    }
+
+    /// A locale whose names are prefixes of one another, to check that the
+    /// longest name is used.
+    struct Prefixes;
+
+    impl Locale for Prefixes {
+        fn month_name_full(&self, month: i8) -> &str {
+            match month {
+                1 => "Jän",
+                2 => "Jänner",
+                3 => "",
+                _ => "Sonst",
+            }
+        }
+
+        fn month_name_abbrev(&self, month: i8) -> &str {
+            match month {
+                6 => "Jun",
+                7 => "Jul",
+                _ => "Ju",
+            }
+        }
+
+        fn weekday_name_full(&self, weekday: Weekday) -> &str {
+            match weekday {
+                Weekday::Sunday => "So",
+                Weekday::Monday => "Sonntag",
+                _ => "Tag",
+            }
+        }
+
+        fn weekday_name_abbrev(&self, weekday: Weekday) -> &str {
+            CLocale.weekday_name_abbrev(weekday)
+        }
+
+        fn meridiem(&self, meridiem: Meridiem) -> &str {
+            match meridiem {
+                Meridiem::AM => "a.m.",
+                Meridiem::PM => "p.m.",
+            }
+        }
+    }
+
+    #[test]
+    fn ok_parse_locale() {
+        let config = Config::new().locale(Prefixes);
+        let p = |fmt: &str, input: &str| {
+            BrokenDownTime::parse_with_config(&config, fmt, input).unwrap()
+        };
+
+        assert_eq!(p("%B", "Jän").month(), Some(1));
+        assert_eq!(p("%B", "jän").month(), Some(1));
+        assert_eq!(p("%B", "JäNNER").month(), Some(2));
+        assert_eq!(p("%b", "jul").month(), Some(7));
+        assert_eq!(p("%b %d", "ju 5").month(), Some(1));
+        assert_eq!(p("%A", "so").weekday(), Some(Weekday::Sunday));
+        assert_eq!(p("%A", "sonntag").weekday(), Some(Weekday::Monday));
+        assert_eq!(p("%a", "WED").weekday(), Some(Weekday::Wednesday));
+        let time = |tm: BrokenDownTime| tm.to_time().unwrap().to_string();
+        assert_eq!(time(p("%I %p", "9 P.M.")), "21:00:00");
+        assert_eq!(time(p("%I %P", "9 a.m.")), "09:00:00");
+        assert_eq!(p("%10B", "    Jänner").month(), Some(2));
+
+        let (tm, offset) =
+            BrokenDownTime::parse_prefix_with_config(&config, "%B", "Jännerx")
+                .unwrap();
+        assert_eq!(tm.month(), Some(2));
+        assert_eq!(offset, 7);
+
+        // The default configuration accepts the same names as not using one.
+        let config = Config::new();
+        let p = |fmt: &str, input: &str| {
+            BrokenDownTime::parse_with_config(&config, fmt, input).unwrap()
+        };
+        assert_eq!(p("%A %B", "Tuesday july").month(), Some(7));
+        assert_eq!(p("%a %b %p", "tue JUL pm").meridiem(), Some(Meridiem::PM));
+    }
+
+    #[test]
+    fn ok_parse_two_digit_year_base() {
+        let year = |base: i16, input: &str| {
+            let config = Config::new().two_digit_year_base(base);
+            BrokenDownTime::parse_with_config(&config, "%y", input)
+                .unwrap()
+                .year()
+                .unwrap()
+        };
+        assert_eq!(year(1969, "69"), 1969);
+        assert_eq!(year(1969, "68"), 2068);
+        assert_eq!(year(1900, "00"), 1900);
+        assert_eq!(year(1900, "99"), 1999);
+        assert_eq!(year(1950, "49"), 2049);
+        assert_eq!(year(1950, "50"), 1950);
+        assert_eq!(year(-150, "49"), -51);
+        assert_eq!(year(-150, "50"), -150);
+        // Bases are clamped so that the entire window is valid.
+        assert_eq!(year(9950, "00"), 9900);
+        assert_eq!(year(9950, "99"), 9999);
+        assert_eq!(year(i16::MIN, "00"), -9900);
+        assert_eq!(year(i16::MIN, "01"), -9999);
+
+        // The base applies to `%D` and `%g` too.
+        let config = Config::new().two_digit_year_base(1900);
+        let p = |fmt: &str, input: &str| {
+            BrokenDownTime::parse_with_config(&config, fmt, input).unwrap()
+        };
+        assert_eq!(p("%D", "07/14/24").year(), Some(1924));
+        assert_eq!(p("%g", "24").iso_week_year(), Some(1924));
+        let fmt = CompiledFormat::new("%y").unwrap();
+        assert_eq!(
+            fmt.parse_with_config(&config, "24").unwrap().year(),
+            Some(1924)
+        );
+        // And changing the locale preserves it.
+        let config = config.locale(Prefixes);
+        assert_eq!(
+            BrokenDownTime::parse_with_config(&config, "%y", "24")
+                .unwrap()
+                .year(),
+            Some(1924),
+        );
+    }
+
+    #[test]
+    fn err_parse_locale() {
+        let config = Config::new().locale(Prefixes);
+        let p = |fmt: &str, input: &str| {
+            BrokenDownTime::parse_with_config(&config, fmt, input)
+                .unwrap_err()
+                .to_string()
+        };
+
+        insta::assert_snapshot!(p("%B", "March"), @r###"strptime parsing failed: %B failed: expected to find month name from the configured locale at the beginning of "March", but none matched"###);
+        insta::assert_snapshot!(p("%b", "J"), @r###"strptime parsing failed: %b failed: expected to find month name abbreviation from the configured locale at the beginning of "J", but none matched"###);
+        insta::assert_snapshot!(p("%A", "Sunday"), @r###"strptime parsing failed: %A failed: expected to find weekday name from the configured locale at the beginning of "Sunday", but none matched"###);
+        insta::assert_snapshot!(p("%p", "PM"), @r###"strptime parsing failed: %p failed: expected to find AM or PM from the configured locale at the beginning of "PM", but none matched"###);
+    }
+
     #[test]
     fn err_parse() {
         let p = |fmt: &str, input: &str| {
@@ -2056,4 +2529,44 @@ mod tests {
             @"parsing format did not include minute directive, but did include second directive (cannot have smaller time units with bigger time units missing)",
         );
     }
+
+    #[test]
+    fn leap_second_policy() {
+        use crate::{fmt::LeapSecond, tai::LeapSecondTable};
+
+        let parse = |policy: LeapSecond, input: &str| {
+            let config = Config::new().leap_second(policy);
+            BrokenDownTime::parse_with_config(
+                &config,
+                "%Y-%m-%dT%H:%M:%S%z",
+                input,
+            )
+        };
+        let input = "2016-12-31T23:59:60+0000";
+
+        let tm = parse(LeapSecond::Constrain, input).unwrap();
+        assert_eq!(tm.second(), Some(59));
+        insta::assert_snapshot!(tm.to_timestamp().unwrap(), @"2016-12-31T23:59:59Z");
+
+        let tm = parse(LeapSecond::Clamp, input).unwrap();
+        assert_eq!(tm.second(), Some(59));
+        insta::assert_snapshot!(tm.to_timestamp().unwrap(), @"2016-12-31T23:59:59.999999999Z");
+
+        let err = parse(LeapSecond::Reject, input).unwrap_err();
+        insta::assert_snapshot!(err, @"strptime parsing failed: %S failed: leap second is not allowed (found second value of 60)");
+
+        let table = LeapSecondTable::builtin();
+        let tm = parse(LeapSecond::Preserve, input).unwrap();
+        assert_eq!(tm.second(), Some(60));
+        insta::assert_snapshot!(tm.to_time().unwrap_err(), @"cannot preserve parsed leap second since it cannot be represented by a civil time or Unix timestamp (convert it to a TAI timestamp instead)");
+        insta::assert_snapshot!(tm.to_tai_timestamp(&table).unwrap(), @"2017-01-01T00:00:36 TAI");
+        insta::assert_snapshot!(
+            tm.to_string("%H:%M:%S").unwrap(),
+            @"23:59:60",
+        );
+
+        let tm =
+            parse(LeapSecond::Preserve, "2017-12-31T23:59:60+0000").unwrap();
+        insta::assert_snapshot!(tm.to_tai_timestamp(&table).unwrap_err(), @"found leap second after 2017-12-31T23:59:59Z, but there is no leap second at that time in the leap second table");
+    }
 }
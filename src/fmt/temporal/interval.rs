@@ -0,0 +1,342 @@
+use crate::{error::Error, span::Span, Unit, Zoned};
+
+/// An ISO 8601 time interval.
+///
+/// An interval is made up of two parts separated by a `/`. Each part is either
+/// a datetime or a duration, but at most one part may be a duration. That is,
+/// the following forms are supported:
+///
+/// * `start/end`, e.g., `2024-01-01T00:00Z/2024-01-02T00:00Z`.
+/// * `start/duration`, e.g., `2024-01-01T00:00Z/P1D`.
+/// * `duration/end`, e.g., `P1D/2024-01-02T00:00Z`.
+///
+/// An interval may also be prefixed with `R/` or `Rn/` (where `n` is a
+/// non-negative integer) to indicate that the interval repeats. The number of
+/// repetitions is available via [`Interval::repetitions`].
+///
+/// Each datetime in an interval must correspond to a precise instant in
+/// time. That is, each datetime must either have a UTC offset or an RFC 9557
+/// time zone annotation (or both). When a datetime has a time zone
+/// annotation, it is parsed into a [`Zoned`] in that time zone. Otherwise, a
+/// datetime with a `Z` offset uses [`TimeZone::UTC`](crate::tz::TimeZone::UTC)
+/// and a datetime with any other offset uses a fixed offset time zone.
+///
+/// Abbreviated end datetimes, like `2024-01-01T00:00Z/02T00:00`, are not
+/// supported.
+///
+/// # Example
+///
+/// This shows how to parse an interval with a start and a duration, and then
+/// compute its end:
+///
+/// ```
+/// use jiff::{civil::date, fmt::temporal::Interval, tz::TimeZone, ToSpan};
+///
+/// let interval: Interval = "2024-01-01T00:00Z/P1D".parse()?;
+/// let (start, span) = interval.to_start_span()?;
+/// assert_eq!(start, date(2024, 1, 1).at(0, 0, 0, 0).to_zoned(TimeZone::UTC)?);
+/// assert_eq!(span, 1.day().fieldwise());
+/// assert_eq!(
+///     interval.to_end()?,
+///     date(2024, 1, 2).at(0, 0, 0, 0).to_zoned(TimeZone::UTC)?,
+/// );
+/// assert_eq!(interval.to_string(), "2024-01-01T00:00:00Z/P1D");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Example: repeating intervals
+///
+/// ```
+/// use jiff::fmt::temporal::{Interval, Repetitions};
+///
+/// let interval: Interval = "R5/2024-03-10T00:00[America/New_York]/PT6H".parse()?;
+/// assert_eq!(interval.repetitions(), Some(Repetitions::Count(5)));
+///
+/// let interval: Interval = "R/P1W/2024-03-10T00:00-05".parse()?;
+/// assert_eq!(interval.repetitions(), Some(Repetitions::Unbounded));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Interval {
+    repetitions: Option<Repetitions>,
+    kind: IntervalKind,
+}
+
+/// The parts that make up an interval.
+#[derive(Clone, Debug)]
+pub(super) enum IntervalKind {
+    StartEnd { start: Zoned, end: Zoned },
+    StartSpan { start: Zoned, span: Span },
+    SpanEnd { span: Span, end: Zoned },
+}
+
+impl Interval {
+    /// Create a new interval from a start and an end datetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::Interval, ToSpan};
+    ///
+    /// let start = date(2024, 3, 9).at(0, 0, 0, 0).in_tz("America/New_York")?;
+    /// let end = date(2024, 3, 11).at(0, 0, 0, 0).in_tz("America/New_York")?;
+    /// let interval = Interval::from_start_end(start, end);
+    /// assert_eq!(interval.to_span()?, 2.days().fieldwise());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_start_end(start: Zoned, end: Zoned) -> Interval {
+        Interval {
+            repetitions: None,
+            kind: IntervalKind::StartEnd { start, end },
+        }
+    }
+
+    /// Create a new interval from a start datetime and a span.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::Interval, tz::TimeZone, ToSpan};
+    ///
+    /// let start = date(2024, 1, 31).at(0, 0, 0, 0).to_zoned(TimeZone::UTC)?;
+    /// let interval = Interval::from_start_span(start, 1.month());
+    /// assert_eq!(interval.to_string(), "2024-01-31T00:00:00Z/P1M");
+    /// assert_eq!(interval.to_end()?.date(), date(2024, 2, 29));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_start_span(start: Zoned, span: Span) -> Interval {
+        Interval {
+            repetitions: None,
+            kind: IntervalKind::StartSpan { start, span },
+        }
+    }
+
+    /// Create a new interval from a span and an end datetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::Interval, tz::TimeZone, ToSpan};
+    ///
+    /// let end = date(2024, 1, 1).at(0, 0, 0, 0).to_zoned(TimeZone::UTC)?;
+    /// let interval = Interval::from_span_end(36.hours(), end);
+    /// assert_eq!(interval.to_string(), "PT36H/2024-01-01T00:00:00Z");
+    /// assert_eq!(
+    ///     interval.to_start()?.datetime(),
+    ///     date(2023, 12, 30).at(12, 0, 0, 0),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_span_end(span: Span, end: Zoned) -> Interval {
+        Interval {
+            repetitions: None,
+            kind: IntervalKind::SpanEnd { span, end },
+        }
+    }
+
+    /// Set the number of times this interval repeats.
+    ///
+    /// When set, the interval is printed with an `R/` or `Rn/` prefix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     civil::date,
+    ///     fmt::temporal::{Interval, Repetitions},
+    ///     tz::TimeZone,
+    ///     ToSpan,
+    /// };
+    ///
+    /// let start = date(2024, 1, 1).at(9, 0, 0, 0).to_zoned(TimeZone::UTC)?;
+    /// let interval = Interval::from_start_span(start, 1.week())
+    ///     .with_repetitions(Some(Repetitions::Count(10)));
+    /// assert_eq!(interval.to_string(), "R10/2024-01-01T09:00:00Z/P1W");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn with_repetitions(
+        self,
+        repetitions: Option<Repetitions>,
+    ) -> Interval {
+        Interval { repetitions, ..self }
+    }
+
+    /// Returns the number of times this interval repeats, if a recurrence
+    /// prefix was given.
+    #[inline]
+    pub fn repetitions(&self) -> Option<Repetitions> {
+        self.repetitions
+    }
+
+    /// Returns the start datetime of this interval, if it was given
+    /// explicitly.
+    ///
+    /// To get the start of an interval even when it is made up of a span
+    /// and an end, use [`Interval::to_start`].
+    #[inline]
+    pub fn start(&self) -> Option<&Zoned> {
+        match self.kind {
+            IntervalKind::StartEnd { ref start, .. } => Some(start),
+            IntervalKind::StartSpan { ref start, .. } => Some(start),
+            IntervalKind::SpanEnd { .. } => None,
+        }
+    }
+
+    /// Returns the end datetime of this interval, if it was given explicitly.
+    ///
+    /// To get the end of an interval even when it is made up of a start and
+    /// a span, use [`Interval::to_end`].
+    #[inline]
+    pub fn end(&self) -> Option<&Zoned> {
+        match self.kind {
+            IntervalKind::StartEnd { ref end, .. } => Some(end),
+            IntervalKind::StartSpan { .. } => None,
+            IntervalKind::SpanEnd { ref end, .. } => Some(end),
+        }
+    }
+
+    /// Returns the span of this interval, if it was given explicitly.
+    ///
+    /// To get the span of an interval even when it is made up of a start and
+    /// an end, use [`Interval::to_span`].
+    #[inline]
+    pub fn span(&self) -> Option<Span> {
+        match self.kind {
+            IntervalKind::StartEnd { .. } => None,
+            IntervalKind::StartSpan { span, .. } => Some(span),
+            IntervalKind::SpanEnd { span, .. } => Some(span),
+        }
+    }
+
+    /// Returns the start datetime of this interval.
+    ///
+    /// When this interval was created from a span and an end, the start is
+    /// computed by subtracting the span from the end.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if subtracting the span from the end overflows.
+    #[inline]
+    pub fn to_start(&self) -> Result<Zoned, Error> {
+        match self.kind {
+            IntervalKind::StartEnd { ref start, .. } => Ok(start.clone()),
+            IntervalKind::StartSpan { ref start, .. } => Ok(start.clone()),
+            IntervalKind::SpanEnd { span, ref end } => end.checked_sub(span),
+        }
+    }
+
+    /// Returns the end datetime of this interval.
+    ///
+    /// When this interval was created from a start and a span, the end is
+    /// computed by adding the span to the start.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if adding the span to the start overflows.
+    #[inline]
+    pub fn to_end(&self) -> Result<Zoned, Error> {
+        match self.kind {
+            IntervalKind::StartEnd { ref end, .. } => Ok(end.clone()),
+            IntervalKind::StartSpan { ref start, span } => {
+                start.checked_add(span)
+            }
+            IntervalKind::SpanEnd { ref end, .. } => Ok(end.clone()),
+        }
+    }
+
+    /// Returns the span of this interval.
+    ///
+    /// When this interval was created from a start and an end, the span is
+    /// computed with calendar units up to years via [`Zoned::until`]. If
+    /// the start and end are in different time zones, then the span is
+    /// computed in the time zone of the start. The span is negative when the
+    /// end precedes the start.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the span between the start and the end could
+    /// not be computed.
+    #[inline]
+    pub fn to_span(&self) -> Result<Span, Error> {
+        match self.kind {
+            IntervalKind::StartEnd { ref start, ref end } => {
+                let end = end.with_time_zone(start.time_zone().clone());
+                start.until((Unit::Year, &end))
+            }
+            IntervalKind::StartSpan { span, .. } => Ok(span),
+            IntervalKind::SpanEnd { span, .. } => Ok(span),
+        }
+    }
+
+    /// Returns the parts that make up this interval.
+    pub(super) fn kind(&self) -> &IntervalKind {
+        &self.kind
+    }
+
+    /// Returns the start datetime and span of this interval.
+    ///
+    /// This is a convenience routine for calling [`Interval::to_start`] and
+    /// [`Interval::to_span`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same circumstances as
+    /// `Interval::to_start` and `Interval::to_span`.
+    #[inline]
+    pub fn to_start_span(&self) -> Result<(Zoned, Span), Error> {
+        Ok((self.to_start()?, self.to_span()?))
+    }
+}
+
+impl core::fmt::Display for Interval {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use crate::fmt::StdFmtWrite;
+
+        super::DateTimePrinter::new()
+            .print_interval(self, StdFmtWrite(f))
+            .map_err(|_| core::fmt::Error)
+    }
+}
+
+impl core::str::FromStr for Interval {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Interval, Error> {
+        super::DEFAULT_DATETIME_PARSER.parse_interval(s)
+    }
+}
+
+/// The number of times an [`Interval`] repeats.
+///
+/// This corresponds to the `R/` or `Rn/` prefix of an ISO 8601 repeating
+/// interval.
+///
+/// # Example
+///
+/// ```
+/// use jiff::fmt::temporal::{Interval, Repetitions};
+///
+/// let interval: Interval = "R3/2024-01-01T00:00Z/PT1H".parse()?;
+/// assert_eq!(interval.repetitions(), Some(Repetitions::Count(3)));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Repetitions {
+    /// The interval repeats an unbounded number of times. This corresponds
+    /// to the `R/` prefix.
+    Unbounded,
+    /// The interval repeats the given number of times. This corresponds to
+    /// the `Rn/` prefix.
+    Count(u64),
+}
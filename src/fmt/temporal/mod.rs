@@ -16,11 +16,14 @@ The specific format supported depends on what kind of type you're trying to
 parse into. Here are some examples to give a general idea:
 
 * `02:21:58` parses into a [`civil::Time`].
-* `2020-08-21` parses into a [`civil::Date`].
+* `2020-08-21` parses into a [`civil::Date`]. So does the ISO 8601 ordinal
+  date `2020-234`, where `234` is the day of the year, and the ISO 8601 week
+  date `2020-W34-5`.
 * `2020-08-21T02:21:58` and `2020-08-21 02:21:58` both parse into a
   [`civil::DateTime`].
 * `2020-08-21T02:21:58-04` parses into an [`Timestamp`].
 * `2020-08-21T02:21:58-04[America/New_York]` parses into a [`Zoned`].
+* `2020-08-21T02:21:58Z/P1D` parses into an [`Interval`].
 
 Smaller types can generally be parsed from strings representing a bigger type.
 For example, a `civil::Date` can be parsed from `2020-08-21T02:21:58`.
@@ -51,6 +54,16 @@ datetime string has a `Z` (Zulu) offset. It's an error since interpreting such
 strings as civil time is usually a bug.
 * In all cases, the `T` designator separating the date and time may be an ASCII
 space instead.
+* The ISO 8601 "basic" format, which omits the `-` and `:` separators, is
+  always accepted. For example, `20240401T123000Z` parses into a `Timestamp`
+  and `20240401` parses into a `civil::Date`. The date, time and offset may
+  each independently use either the basic or the "extended" format, e.g.,
+  `2024-04-01T123000+0530`. The printer uses the extended format unless
+  [`DateTimePrinter::basic`] is enabled. Note that a `civil::Time` in the basic
+  format may need a `T` prefix (e.g., `T123000`) to avoid ambiguity with other
+  formats. There is no option to disable basic format parsing: it has always
+  been accepted, and no extended format input can be mistaken for a basic
+  format one.
 
 The complete datetime format supported is described by the
 [Temporal ISO 8601 grammar].
@@ -73,8 +86,12 @@ designators, but the unit designators can be configured to use lowercase with
 [`SpanPrinter::lowercase`]. For example, `P3y1m10dT5h` instead of
 `P3Y1M10DT5H`. You might prefer lowercase since you may find it easier to read.
 However, it is an extension to ISO 8601 and isn't as broadly supported.
-* Hours, minutes or seconds may be fractional. And the only units that may be
-fractional are the lowest units.
+* Any unit except for months may be fractional, but only the lowest unit
+  written may be fractional. Fractional units are converted to smaller units.
+  For example, `PT1.5h` is `1h 30m` and `P1.5w` is `1w 3d 12h`. Fractional
+  days (and weeks) are converted to time units by assuming 24-hour days.
+  Since months don't have a fixed length, fractional years must correspond to
+  a whole number of months (e.g., `P1.5y` is `1y 6mo`).
 * A span like `P99999999999y` is invalid because it exceeds the allowable range
 of time representable by a [`Span`].
 
@@ -97,6 +114,8 @@ let spans = [
     ("P1w4d", 1.week().days(4)),
     ("PT1m", 1.minute()),
     ("PT0.0021s", 2.milliseconds().microseconds(100)),
+    ("P1.5w", 1.week().days(3).hours(12)),
+    ("P1.25y", 1.year().months(3)),
     ("PT0s", 0.seconds()),
     ("P0d", 0.seconds()),
     (
@@ -142,6 +161,27 @@ for (string, duration) in durations {
 The complete span format supported is described by the [Temporal ISO 8601
 grammar].
 
+In addition to Temporal's grammar, the "alternative" duration format from
+ISO 8601-1 (section 5.5.2.4) is also supported when parsing. This format uses
+`PYYYY-MM-DDTHH:MM:SS` instead of unit designators. The time component is
+optional, and seconds may have a fractional component. Use
+[`SpanPrinter::alternative`] to print spans in this format.
+
+```
+use jiff::{Span, ToSpan};
+
+let span: Span = "P0001-02-03T04:05:06.5".parse()?;
+assert_eq!(
+    span.fieldwise(),
+    1.year().months(2).days(3).hours(4).minutes(5).seconds(6).milliseconds(500),
+);
+
+let span: Span = "P0000-00-10".parse()?;
+assert_eq!(span.fieldwise(), 10.days());
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
 # Differences with Temporal
 
 Jiff implements Temporal's grammar pretty closely, but there are a few
@@ -173,20 +213,30 @@ There is some more [background on Temporal's format] available.
 use crate::{
     civil,
     error::Error,
-    fmt::Write,
+    fmt::{LeapSecond, Write},
     span::Span,
+    tai::{self, LeapSecondTable},
     tz::{Disambiguation, Offset, OffsetConflict, TimeZone, TimeZoneDatabase},
-    SignedDuration, Timestamp, Zoned,
+    RoundMode, SignedDuration, Timestamp, Zoned,
 };
 
+pub use self::interval::{Interval, Repetitions};
 pub use self::pieces::{
     Pieces, PiecesNumericOffset, PiecesOffset, TimeZoneAnnotation,
     TimeZoneAnnotationKind, TimeZoneAnnotationName,
 };
 
+pub use self::printer::SubminuteOffset;
+#[cfg(feature = "std")]
+pub use self::stream::{ChunkReader, StreamReader};
+
+mod interval;
 mod parser;
 mod pieces;
 mod printer;
+#[cfg(feature = "std")]
+mod stream;
+mod swar;
 
 /// The default date time parser that we use throughout Jiff.
 pub(crate) static DEFAULT_DATETIME_PARSER: DateTimeParser =
@@ -508,6 +558,141 @@ impl DateTimeParser {
         DateTimeParser { disambiguation: strategy, ..self }
     }
 
+    /// When enabled, sign-prefixed years may be written with more than six
+    /// digits.
+    ///
+    /// ISO 8601 permits an "expanded representation" of years by mutual
+    /// agreement, where years are written with a leading sign and more than
+    /// four digits. By default, this parser follows Temporal and only accepts
+    /// exactly six digits after a sign (e.g., `+002024` or `-000500`). When
+    /// this option is enabled, any number of digits beyond six is accepted,
+    /// e.g., `+0002024-01-01`. This is useful for parsing archival or
+    /// astronomical datasets that consistently use wider years.
+    ///
+    /// Extra digits are only recognized in the extended format, i.e., when
+    /// the year is followed by a `-`. Otherwise, they would be ambiguous with
+    /// the basic format (`+0020240115`), which is parsed as it would be
+    /// without this option enabled.
+    ///
+    /// Note that the year must still be within Jiff's supported range of
+    /// `-9999` to `9999`. Expanded years outside of that range, such as
+    /// `+0100000`, result in an error.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimeParser};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new().expanded_years(true);
+    ///
+    /// let d = PARSER.parse_date("+0002024-03-01")?;
+    /// assert_eq!(d, date(2024, 3, 1));
+    /// let d = PARSER.parse_date("-00000500-03-01")?;
+    /// assert_eq!(d, date(-500, 3, 1));
+    /// // Six digit years continue to work.
+    /// let d = PARSER.parse_date("-000500-03-01")?;
+    /// assert_eq!(d, date(-500, 3, 1));
+    ///
+    /// // Years outside of Jiff's supported range are rejected.
+    /// assert!(PARSER.parse_date("+0100000-01-01").is_err());
+    /// // And wide years are rejected when this option isn't enabled.
+    /// assert!(DateTimeParser::new().parse_date("+0002024-03-01").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn expanded_years(self, yes: bool) -> DateTimeParser {
+        DateTimeParser { p: self.p.expanded_years(yes), ..self }
+    }
+
+    /// Enable or disable lenient parsing.
+    ///
+    /// When enabled, this parser tolerates some common deviations from the
+    /// Temporal grammar that show up in messy user data:
+    ///
+    /// * Months and days may be written with a single digit, e.g.,
+    ///   `2024-6-5`. This is only supported when the date uses `-`
+    ///   separators.
+    /// * The hours component of an offset may be written with a single digit,
+    ///   e.g., `-4`, `+5:30` or `-4:00`.
+    /// * Trailing whitespace is ignored.
+    ///
+    /// Note that some other deviations are always accepted, even when this
+    /// option is disabled. Namely, seconds may be omitted (`2024-06-05T07:30`)
+    /// and a space may be used instead of a `T` to separate the date and time
+    /// (`2024-06-05 07:30`).
+    ///
+    /// Lenient parsing never changes the interpretation of a string that
+    /// parses successfully without it.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimeParser};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new().lenient(true);
+    ///
+    /// let ts = PARSER.parse_timestamp("2024-6-5 07:30-4 \n")?;
+    /// assert_eq!(ts.to_string(), "2024-06-05T11:30:00Z");
+    ///
+    /// let dt = PARSER.parse_datetime("2024-6-15 07:30\t")?;
+    /// assert_eq!(dt, date(2024, 6, 15).at(7, 30, 0, 0));
+    ///
+    /// // None of these deviations are allowed by default.
+    /// let strict = DateTimeParser::new();
+    /// assert!(strict.parse_datetime("2024-6-15 07:30").is_err());
+    /// assert!(strict.parse_timestamp("2024-06-05 07:30-4").is_err());
+    /// assert!(strict.parse_datetime("2024-06-15 07:30 ").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn lenient(self, yes: bool) -> DateTimeParser {
+        DateTimeParser { p: self.p.lenient(yes), ..self }
+    }
+
+    /// Set how a leap second, i.e., a seconds value of `60`, is handled.
+    ///
+    /// By default, [`LeapSecond::Constrain`] is used, which parses a leap
+    /// second as if it were the 59th second. See [`LeapSecond`] for the other
+    /// policies that are available.
+    ///
+    /// # Example
+    ///
+    /// This shows the effect of each policy when parsing a timestamp:
+    ///
+    /// ```
+    /// use jiff::fmt::{temporal::DateTimeParser, LeapSecond};
+    ///
+    /// let input = "2016-12-31T23:59:60.5Z";
+    ///
+    /// let p = DateTimeParser::new().leap_second(LeapSecond::Constrain);
+    /// let ts = p.parse_timestamp(input)?;
+    /// assert_eq!(ts.to_string(), "2016-12-31T23:59:59.5Z");
+    ///
+    /// let p = DateTimeParser::new().leap_second(LeapSecond::Clamp);
+    /// let ts = p.parse_timestamp(input)?;
+    /// assert_eq!(ts.to_string(), "2016-12-31T23:59:59.999999999Z");
+    ///
+    /// let p = DateTimeParser::new().leap_second(LeapSecond::Reject);
+    /// assert!(p.parse_timestamp(input).is_err());
+    ///
+    /// // A `Timestamp` can't represent a leap second, so it can't be
+    /// // preserved.
+    /// let p = DateTimeParser::new().leap_second(LeapSecond::Preserve);
+    /// assert!(p.parse_timestamp(input).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn leap_second(self, policy: LeapSecond) -> DateTimeParser {
+        DateTimeParser { p: self.p.leap_second(policy), ..self }
+    }
+
     /// Parse a datetime string with a time zone annotation into a [`Zoned`]
     /// value using the system time zone database.
     ///
@@ -653,7 +838,7 @@ impl DateTimeParser {
         db: &TimeZoneDatabase,
         input: I,
     ) -> Result<Zoned, Error> {
-        let input = input.as_ref();
+        let input = self.p.trim(input.as_ref());
         let parsed = self.p.parse_temporal_datetime(input)?;
         let dt = parsed.into_full()?;
         let zoned =
@@ -707,13 +892,127 @@ impl DateTimeParser {
         &self,
         input: I,
     ) -> Result<Timestamp, Error> {
-        let input = input.as_ref();
+        let input = self.p.trim(input.as_ref());
         let parsed = self.p.parse_temporal_datetime(input)?;
         let dt = parsed.into_full()?;
         let timestamp = dt.to_timestamp()?;
         Ok(timestamp)
     }
 
+    /// Parse a datetime string into a [`Timestamp`] along with its offset
+    /// to local time, if it's known.
+    ///
+    /// This is like [`DateTimeParser::parse_timestamp`], except it also
+    /// returns the offset parsed. When the offset is `Z` or `-00:00`, then
+    /// `None` is returned for the offset. These indicate that the offset to
+    /// local time is unknown, which is distinct from an offset of `+00:00`.
+    /// See [`PiecesOffset::is_unknown`] for more details.
+    ///
+    /// This is the inverse of
+    /// [`DateTimePrinter::print_timestamp_with_offset`] (for known offsets)
+    /// and [`DateTimePrinter::print_timestamp`] (for unknown offsets).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`DateTimeParser::parse_timestamp`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::DateTimeParser, tz::Offset};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let (ts, offset) =
+    ///     PARSER.parse_timestamp_with_offset("2024-06-15T07:00:00-04:00")?;
+    /// assert_eq!(ts.to_string(), "2024-06-15T11:00:00Z");
+    /// assert_eq!(offset, Some(Offset::constant(-4)));
+    ///
+    /// let (_, offset) =
+    ///     PARSER.parse_timestamp_with_offset("2024-06-15T11:00:00+00:00")?;
+    /// assert_eq!(offset, Some(Offset::UTC));
+    ///
+    /// // Both `Z` and `-00:00` mean the offset to local time is unknown.
+    /// let (_, offset) =
+    ///     PARSER.parse_timestamp_with_offset("2024-06-15T11:00:00Z")?;
+    /// assert_eq!(offset, None);
+    /// let (_, offset) =
+    ///     PARSER.parse_timestamp_with_offset("2024-06-15T11:00:00-00:00")?;
+    /// assert_eq!(offset, None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_timestamp_with_offset<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<(Timestamp, Option<Offset>), Error> {
+        let input = self.p.trim(input.as_ref());
+        let parsed = self.p.parse_temporal_datetime(input)?;
+        let dt = parsed.into_full()?;
+        let timestamp = dt.to_timestamp()?;
+        let offset = dt.to_known_offset()?;
+        Ok((timestamp, offset))
+    }
+
+    /// Parse a UTC datetime string into a [`tai::Timestamp`] using the given
+    /// leap second table.
+    ///
+    /// This parses the same strings as [`DateTimeParser::parse_timestamp`],
+    /// and then converts the timestamp to TAI via
+    /// [`LeapSecondTable::utc_to_tai`].
+    ///
+    /// When this parser is configured with [`LeapSecond::Preserve`], a leap
+    /// second in the string is preserved, since a TAI timestamp can represent
+    /// it. In that case, the leap second table is used to check that a leap
+    /// second actually occurred at the instant parsed. With any other leap
+    /// second policy, a leap second is handled as it is for
+    /// [`DateTimeParser::parse_timestamp`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`DateTimeParser::parse_timestamp`], or if the timestamp couldn't be
+    /// converted to TAI. This also returns an error when a leap second is
+    /// preserved, but the leap second table doesn't have a leap second at
+    /// the instant parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     fmt::{temporal::DateTimeParser, LeapSecond},
+    ///     tai::LeapSecondTable,
+    /// };
+    ///
+    /// static PARSER: DateTimeParser =
+    ///     DateTimeParser::new().leap_second(LeapSecond::Preserve);
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// let tai = PARSER.parse_tai_timestamp("2016-12-31T23:59:60.5Z", &table)?;
+    /// assert_eq!(tai.to_string(), "2017-01-01T00:00:36.5 TAI");
+    ///
+    /// // Leap seconds occur at the same instant in every time zone.
+    /// let tai = PARSER.parse_tai_timestamp("2017-01-01T08:59:60+09", &table)?;
+    /// assert_eq!(tai.to_string(), "2017-01-01T00:00:36 TAI");
+    ///
+    /// // There was no leap second at the end of 2017.
+    /// let result = PARSER.parse_tai_timestamp("2017-12-31T23:59:60Z", &table);
+    /// assert!(result.is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_tai_timestamp<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+        table: &LeapSecondTable,
+    ) -> Result<tai::Timestamp, Error> {
+        let input = self.p.trim(input.as_ref());
+        let parsed = self.p.parse_temporal_datetime(input)?;
+        let dt = parsed.into_full()?;
+        dt.to_tai_timestamp(table)
+    }
+
     /// Parse a civil datetime string into a [`civil::DateTime`].
     ///
     /// A civil datetime can be parsed from anything that contains a datetime.
@@ -766,7 +1065,7 @@ impl DateTimeParser {
         &self,
         input: I,
     ) -> Result<civil::DateTime, Error> {
-        let input = input.as_ref();
+        let input = self.p.trim(input.as_ref());
         let parsed = self.p.parse_temporal_datetime(input)?;
         let dt = parsed.into_full()?;
         let datetime = dt.to_datetime()?;
@@ -825,13 +1124,54 @@ impl DateTimeParser {
         &self,
         input: I,
     ) -> Result<civil::Date, Error> {
-        let input = input.as_ref();
+        let input = self.p.trim(input.as_ref());
         let parsed = self.p.parse_temporal_datetime(input)?;
         let dt = parsed.into_full()?;
         let date = dt.to_date()?;
         Ok(date)
     }
 
+    /// Parse a date string into a [`civil::ISOWeekDate`].
+    ///
+    /// This accepts the same inputs as [`DateTimeParser::parse_date`],
+    /// which includes ISO 8601 week dates like `2024-W15-3`. Week dates
+    /// can also appear as the date component of bigger types, e.g.,
+    /// `2024-W15-3T12:00:00Z` can be parsed into a [`Timestamp`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the date string given is invalid or if it
+    /// is valid but doesn't fit in the date range supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     civil::{date, ISOWeekDate, Weekday},
+    ///     fmt::temporal::DateTimeParser,
+    /// };
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let wd = PARSER.parse_iso_week_date("2024-W15-3")?;
+    /// assert_eq!(wd, ISOWeekDate::new(2024, 15, Weekday::Wednesday)?);
+    /// assert_eq!(wd.date(), date(2024, 4, 10));
+    ///
+    /// // The ISO week year can differ from the calendar year.
+    /// let wd = PARSER.parse_iso_week_date("2024-12-30")?;
+    /// assert_eq!(wd.to_string(), "2025-W01-1");
+    /// // And week dates can be parsed into a `civil::Date` too.
+    /// assert_eq!(PARSER.parse_date("2025W011")?, date(2024, 12, 30));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_iso_week_date<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<civil::ISOWeekDate, Error> {
+        self.parse_date(input).map(civil::ISOWeekDate::from)
+    }
+
     /// Parse a civil time string into a [`civil::Time`].
     ///
     /// A civil time can be parsed from anything that contains a time.
@@ -884,10 +1224,10 @@ impl DateTimeParser {
         &self,
         input: I,
     ) -> Result<civil::Time, Error> {
-        let input = input.as_ref();
+        let input = self.p.trim(input.as_ref());
         let parsed = self.p.parse_temporal_time(input)?;
         let parsed_time = parsed.into_full()?;
-        let time = parsed_time.to_time();
+        let time = parsed_time.to_time()?;
         Ok(time)
     }
 
@@ -983,7 +1323,7 @@ impl DateTimeParser {
         db: &TimeZoneDatabase,
         input: I,
     ) -> Result<TimeZone, Error> {
-        let input = input.as_ref();
+        let input = self.p.trim(input.as_ref());
         let parsed = self.p.parse_time_zone(input)?.into_full()?;
         parsed.into_time_zone(db)
     }
@@ -1105,133 +1445,621 @@ impl DateTimeParser {
         &self,
         input: &'i I,
     ) -> Result<Pieces<'i>, Error> {
-        let input = input.as_ref();
+        let input = self.p.trim(input.as_ref());
         let parsed = self.p.parse_temporal_datetime(input)?.into_full()?;
         let pieces = parsed.to_pieces()?;
         Ok(pieces)
     }
-}
-
-/// A printer for Temporal datetimes.
-///
-/// This printer converts an in memory representation of a datetime related
-/// type to a machine (but also human) readable format. Using this printer, one
-/// can convert [`Zoned`], [`Timestamp`], [`civil::DateTime`], [`civil::Date`]
-/// or [`civil::Time`] values to a string. Note that all of those types provide
-/// [`Diplay`](core::fmt::Display) implementations that utilize the default
-/// configuration of this printer. However, this printer can be configured to
-/// behave differently and can also print directly to anything that implements
-/// the [`fmt::Write`](Write) trait.
-///
-/// See the [`fmt::temporal`](crate::fmt::temporal) module documentation for
-/// more information on the specific format used. Note that the Temporal
-/// datetime parser is strictly more flexible than what is supported by this
-/// printer. For example, parsing `2024-06-15T07:00-04[America/New_York]` will
-/// work just fine, even though the seconds are omitted. However, this printer
-/// provides no way to write a datetime without the second component.
-///
-/// # Example
-///
-/// This example shows how to print a `Zoned` value with a space separating
-/// the date and time instead of the more standard `T` separator.
-///
-/// ```
-/// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
-///
-/// // A printer can be created in a const context.
-/// const PRINTER: DateTimePrinter = DateTimePrinter::new().separator(b' ');
-///
-/// let zdt = date(2024, 6, 15).at(7, 0, 0, 123456789).in_tz("America/New_York")?;
-///
-/// let mut buf = String::new();
-/// // Printing to a `String` can never fail.
-/// PRINTER.print_zoned(&zdt, &mut buf).unwrap();
-/// assert_eq!(buf, "2024-06-15 07:00:00.123456789-04:00[America/New_York]");
-///
-/// # Ok::<(), Box<dyn std::error::Error>>(())
-/// ```
-///
-/// # Example: using adapters with `std::io::Write` and `std::fmt::Write`
-///
-/// By using the [`StdIoWrite`](super::StdIoWrite) and
-/// [`StdFmtWrite`](super::StdFmtWrite) adapters, one can print datetimes
-/// directly to implementations of `std::io::Write` and `std::fmt::Write`,
-/// respectively. The example below demonstrates writing to anything
-/// that implements `std::io::Write`. Similar code can be written for
-/// `std::fmt::Write`.
-///
-/// ```no_run
-/// use std::{fs::File, io::{BufWriter, Write}, path::Path};
-///
-/// use jiff::{civil::date, fmt::{StdIoWrite, temporal::DateTimePrinter}};
-///
-/// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("America/New_York")?;
-///
-/// let path = Path::new("/tmp/output");
-/// let mut file = BufWriter::new(File::create(path)?);
-/// DateTimePrinter::new().print_zoned(&zdt, StdIoWrite(&mut file)).unwrap();
-/// file.flush()?;
-/// assert_eq!(
-///     std::fs::read_to_string(path)?,
-///     "2024-06-15T07:00:00-04:00[America/New_York]",
-/// );
-///
-/// # Ok::<(), Box<dyn std::error::Error>>(())
-/// ```
-#[derive(Debug)]
-pub struct DateTimePrinter {
-    p: printer::DateTimePrinter,
-}
-
-impl DateTimePrinter {
-    /// Create a new Temporal datetime printer with the default configuration.
-    pub const fn new() -> DateTimePrinter {
-        DateTimePrinter { p: printer::DateTimePrinter::new() }
-    }
 
-    /// Use lowercase for the datetime separator and the `Z` (Zulu) UTC offset.
+    /// Parse an ISO 8601 time interval string into an [`Interval`].
     ///
-    /// This is disabled by default.
+    /// An interval is made up of two parts separated by a `/`: either a
+    /// start and an end datetime, a start datetime and a duration or a
+    /// duration and an end datetime. An interval may also be prefixed with
+    /// `R/` or `Rn/` to indicate that it repeats.
     ///
-    /// # Example
+    /// Each datetime in the interval must have either a UTC offset or
+    /// a time zone annotation. Datetimes with a time zone annotation are
+    /// resolved in the same way as [`DateTimeParser::parse_zoned`], so the
+    /// [`DateTimeParser::offset_conflict`] and
+    /// [`DateTimeParser::disambiguation`] settings apply to them.
     ///
-    /// This example shows how to print a `Zoned` value with a lowercase
-    /// datetime separator.
+    /// # Errors
     ///
-    /// ```
-    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    /// This returns an error if the interval string given is invalid, if
+    /// either datetime doesn't correspond to a precise instant in time or if
+    /// both parts of the interval are durations.
     ///
-    /// const PRINTER: DateTimePrinter = DateTimePrinter::new().lowercase(true);
+    /// # Example
     ///
-    /// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("America/New_York")?;
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimeParser, ToSpan};
     ///
-    /// let mut buf = String::new();
-    /// // Printing to a `String` can never fail.
-    /// PRINTER.print_zoned(&zdt, &mut buf).unwrap();
-    /// assert_eq!(buf, "2024-06-15t07:00:00-04:00[America/New_York]");
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let interval = PARSER.parse_interval(
+    ///     "2024-03-09T12:00[America/New_York]/2024-03-10T12:00-04",
+    /// )?;
+    /// // The fixed offset of the end is preserved.
+    /// assert_eq!(interval.end().unwrap().time_zone().iana_name(), None);
+    /// // And the span is computed from the start and end. Since the span
+    /// // is computed in the time zone of the start, it's one day even
+    /// // though only 23 hours elapsed (because of the DST transition).
+    /// let (start, span) = interval.to_start_span()?;
+    /// assert_eq!(start.datetime(), date(2024, 3, 9).at(12, 0, 0, 0));
+    /// assert_eq!(span, 1.day().fieldwise());
+    ///
+    /// // A datetime without an offset or time zone is not an instant,
+    /// // and so can't be used in an interval.
+    /// assert!(PARSER.parse_interval("2024-03-09T12:00/P1D").is_err());
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    #[inline]
-    pub const fn lowercase(mut self, yes: bool) -> DateTimePrinter {
-        self.p = self.p.lowercase(yes);
-        self
+    pub fn parse_interval<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<Interval, Error> {
+        let input = self.p.trim(input.as_ref());
+        let parsed = self.p.parse_temporal_interval(input)?.into_full()?;
+        let interval = parsed.to_interval(
+            crate::tz::db(),
+            self.offset_conflict,
+            self.disambiguation,
+        )?;
+        Ok(interval)
     }
 
-    /// Use the given ASCII character to separate the date and time when
-    /// printing [`Zoned`], [`Timestamp`] or [`civil::DateTime`] values.
+    /// Parse many datetime strings with time zone annotations into [`Zoned`]
+    /// values.
     ///
-    /// This is set to `T` by default.
+    /// This returns an iterator that yields the result of parsing each input
+    /// in order, as if by [`DateTimeParser::parse_zoned`]. An error parsing
+    /// one input does not prevent parsing the inputs that follow it.
     ///
-    /// # Example
+    /// When consecutive inputs use the same IANA time zone identifier in
+    /// their annotation, the time zone is only looked up in the time zone
+    /// database once. This can make parsing large batches of datetimes
+    /// from the same time zone considerably faster than calling
+    /// [`DateTimeParser::parse_zoned`] repeatedly.
     ///
-    /// This example shows how to print a `Zoned` value with a different
-    /// datetime separator.
+    /// # Example
     ///
     /// ```
-    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    /// use jiff::fmt::temporal::DateTimeParser;
     ///
-    /// // We use a weird non-standard character here, but typically one would
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let inputs = [
+    ///     "2024-06-15T07:00-04[America/New_York]",
+    ///     "2024-06-15T08:00-04[America/New_York]",
+    ///     "2024-06-15T09:00-04[America/Nowhere]",
+    /// ];
+    /// let results: Vec<_> = PARSER.parse_many_zoned(&inputs).collect();
+    /// assert_eq!(results.len(), 3);
+    /// assert_eq!(
+    ///     results[1].as_ref().unwrap().to_string(),
+    ///     "2024-06-15T08:00:00-04:00[America/New_York]",
+    /// );
+    /// assert!(results[2].is_err());
+    /// ```
+    pub fn parse_many_zoned<'p, I>(
+        &'p self,
+        inputs: I,
+    ) -> ParseMany<'p, I::IntoIter, Zoned>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        self.parse_many_zoned_with(crate::tz::db(), inputs)
+    }
+
+    /// Parse many datetime strings with time zone annotations into [`Zoned`]
+    /// values using the time zone database given.
+    ///
+    /// This is like [`DateTimeParser::parse_many_zoned`], but uses the time
+    /// zone database given instead of the global one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::DateTimeParser, tz};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let inputs = vec![
+    ///     "2024-06-15T07:00[Asia/Tokyo]".to_string(),
+    ///     "2024-12-15T07:00[Asia/Tokyo]".to_string(),
+    /// ];
+    /// let zdts = PARSER
+    ///     .parse_many_zoned_with(tz::db(), &inputs)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(zdts[0].time_zone(), zdts[1].time_zone());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_many_zoned_with<'p, I>(
+        &'p self,
+        db: &'p TimeZoneDatabase,
+        inputs: I,
+    ) -> ParseMany<'p, I::IntoIter, Zoned>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        ParseMany::new(self, db, inputs.into_iter(), |many, input| {
+            let parser = many.parser;
+            let input = parser.p.trim(input);
+            let parsed = parser.p.parse_temporal_datetime(input)?;
+            let dt = parsed.into_full()?;
+            dt.to_zoned_with(
+                |ann| many.resolve(ann),
+                parser.offset_conflict,
+                parser.disambiguation,
+            )
+        })
+    }
+
+    /// Parse many datetime strings into [`Timestamp`] values.
+    ///
+    /// This returns an iterator that yields the result of parsing each input
+    /// in order, as if by [`DateTimeParser::parse_timestamp`]. An error
+    /// parsing one input does not prevent parsing the inputs that follow it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::DateTimeParser, Timestamp};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let inputs = ["2024-06-15T07:00:00Z", "2024-06-15T07:00:01Z"];
+    /// let timestamps = PARSER
+    ///     .parse_many_timestamps(inputs)
+    ///     .collect::<Result<Vec<Timestamp>, _>>()?;
+    /// assert_eq!(timestamps[0].as_second() + 1, timestamps[1].as_second());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_many_timestamps<'p, I>(
+        &'p self,
+        inputs: I,
+    ) -> ParseMany<'p, I::IntoIter, Timestamp>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        ParseMany::new(
+            self,
+            crate::tz::db(),
+            inputs.into_iter(),
+            |many, input| many.parser.parse_timestamp(input),
+        )
+    }
+
+    /// Parse many datetime strings into [`civil::DateTime`] values.
+    ///
+    /// This returns an iterator that yields the result of parsing each input
+    /// in order, as if by [`DateTimeParser::parse_datetime`]. An error
+    /// parsing one input does not prevent parsing the inputs that follow it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimeParser};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let lines = "2024-06-15T07:00\n2024-06-15 08:30:01";
+    /// let datetimes = PARSER
+    ///     .parse_many_datetimes(lines.lines())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(datetimes, vec![
+    ///     date(2024, 6, 15).at(7, 0, 0, 0),
+    ///     date(2024, 6, 15).at(8, 30, 1, 0),
+    /// ]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_many_datetimes<'p, I>(
+        &'p self,
+        inputs: I,
+    ) -> ParseMany<'p, I::IntoIter, civil::DateTime>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        ParseMany::new(
+            self,
+            crate::tz::db(),
+            inputs.into_iter(),
+            |many, input| many.parser.parse_datetime(input),
+        )
+    }
+
+    /// Parse many date strings into [`civil::Date`] values.
+    ///
+    /// This returns an iterator that yields the result of parsing each input
+    /// in order, as if by [`DateTimeParser::parse_date`]. An error parsing
+    /// one input does not prevent parsing the inputs that follow it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimeParser};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let inputs: &[&[u8]] = &[b"2024-06-15", b"2024-02-30", b"20240616"];
+    /// let results: Vec<_> = PARSER.parse_many_dates(inputs).collect();
+    /// assert_eq!(results[0].as_ref().unwrap(), &date(2024, 6, 15));
+    /// assert!(results[1].is_err());
+    /// assert_eq!(results[2].as_ref().unwrap(), &date(2024, 6, 16));
+    /// ```
+    pub fn parse_many_dates<'p, I>(
+        &'p self,
+        inputs: I,
+    ) -> ParseMany<'p, I::IntoIter, civil::Date>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        ParseMany::new(
+            self,
+            crate::tz::db(),
+            inputs.into_iter(),
+            |many, input| many.parser.parse_date(input),
+        )
+    }
+
+    /// Parse many time strings into [`civil::Time`] values.
+    ///
+    /// This returns an iterator that yields the result of parsing each input
+    /// in order, as if by [`DateTimeParser::parse_time`]. An error parsing
+    /// one input does not prevent parsing the inputs that follow it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, fmt::temporal::DateTimeParser};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let times = PARSER
+    ///     .parse_many_times(["07:00", "T0830", "23:59:59.5"])
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(times, vec![
+    ///     time(7, 0, 0, 0),
+    ///     time(8, 30, 0, 0),
+    ///     time(23, 59, 59, 500_000_000),
+    /// ]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_many_times<'p, I>(
+        &'p self,
+        inputs: I,
+    ) -> ParseMany<'p, I::IntoIter, civil::Time>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        ParseMany::new(
+            self,
+            crate::tz::db(),
+            inputs.into_iter(),
+            |many, input| many.parser.parse_time(input),
+        )
+    }
+}
+
+/// An iterator over the results of parsing many datetime strings.
+///
+/// This is created by [`DateTimeParser::parse_many_zoned`],
+/// [`DateTimeParser::parse_many_timestamps`] and similar methods. It yields
+/// one result for each input, in order.
+///
+/// The lifetime parameter `'p` refers to the lifetime of the parser (and
+/// time zone database) used. `I` is the type of the underlying iterator of
+/// inputs and `T` is the type of value parsed.
+pub struct ParseMany<'p, I, T> {
+    state: ParseManyState<'p>,
+    inputs: I,
+    parse: fn(&mut ParseManyState<'p>, &[u8]) -> Result<T, Error>,
+}
+
+/// The state shared across every input parsed by [`ParseMany`].
+struct ParseManyState<'p> {
+    parser: &'p DateTimeParser,
+    db: &'p TimeZoneDatabase,
+    /// The time zone most recently resolved from an IANA time zone
+    /// identifier, if any.
+    tz: Option<TimeZone>,
+}
+
+impl<'p, I, T> ParseMany<'p, I, T> {
+    fn new(
+        parser: &'p DateTimeParser,
+        db: &'p TimeZoneDatabase,
+        inputs: I,
+        parse: fn(&mut ParseManyState<'p>, &[u8]) -> Result<T, Error>,
+    ) -> ParseMany<'p, I, T> {
+        ParseMany {
+            state: ParseManyState { parser, db, tz: None },
+            inputs,
+            parse,
+        }
+    }
+}
+
+impl<'p> ParseManyState<'p> {
+    /// Resolves the given annotation into a time zone, reusing the previous
+    /// lookup when the IANA time zone identifier hasn't changed.
+    fn resolve(
+        &mut self,
+        ann: &TimeZoneAnnotation<'_>,
+    ) -> Result<TimeZone, Error> {
+        let TimeZoneAnnotationKind::Named(ref name) = *ann.kind() else {
+            return ann.to_time_zone_with(self.db);
+        };
+        if let Some(ref tz) = self.tz {
+            if tz.iana_name() == Some(name.as_str()) {
+                return Ok(tz.clone());
+            }
+        }
+        let tz = ann.to_time_zone_with(self.db)?;
+        self.tz = Some(tz.clone());
+        Ok(tz)
+    }
+}
+
+impl<'p, I, T> Iterator for ParseMany<'p, I, T>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item = Result<T, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T, Error>> {
+        let input = self.inputs.next()?;
+        Some((self.parse)(&mut self.state, input.as_ref()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inputs.size_hint()
+    }
+}
+
+impl<'p, I, T> core::iter::FusedIterator for ParseMany<'p, I, T>
+where
+    I: core::iter::FusedIterator,
+    I::Item: AsRef<[u8]>,
+{
+}
+
+impl<'p, I: core::fmt::Debug, T> core::fmt::Debug for ParseMany<'p, I, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("ParseMany")
+            .field("parser", &self.state.parser)
+            .field("inputs", &self.inputs)
+            .finish()
+    }
+}
+
+/// A printer for Temporal datetimes.
+///
+/// This printer converts an in memory representation of a datetime related
+/// type to a machine (but also human) readable format. Using this printer, one
+/// can convert [`Zoned`], [`Timestamp`], [`civil::DateTime`], [`civil::Date`]
+/// or [`civil::Time`] values to a string. Note that all of those types provide
+/// [`Diplay`](core::fmt::Display) implementations that utilize the default
+/// configuration of this printer. However, this printer can be configured to
+/// behave differently and can also print directly to anything that implements
+/// the [`fmt::Write`](Write) trait.
+///
+/// See the [`fmt::temporal`](crate::fmt::temporal) module documentation for
+/// more information on the specific format used. Note that the Temporal
+/// datetime parser is strictly more flexible than what is supported by this
+/// printer. For example, parsing `2024-06-15T07:00-04[America/New_York]` will
+/// work just fine, even though the seconds are omitted. However, this printer
+/// provides no way to write a datetime without the second component.
+///
+/// # Example
+///
+/// This example shows how to print a `Zoned` value with a space separating
+/// the date and time instead of the more standard `T` separator.
+///
+/// ```
+/// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+///
+/// // A printer can be created in a const context.
+/// const PRINTER: DateTimePrinter = DateTimePrinter::new().separator(b' ');
+///
+/// let zdt = date(2024, 6, 15).at(7, 0, 0, 123456789).in_tz("America/New_York")?;
+///
+/// let mut buf = String::new();
+/// // Printing to a `String` can never fail.
+/// PRINTER.print_zoned(&zdt, &mut buf).unwrap();
+/// assert_eq!(buf, "2024-06-15 07:00:00.123456789-04:00[America/New_York]");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Example: using adapters with `std::io::Write` and `std::fmt::Write`
+///
+/// By using the [`StdIoWrite`](super::StdIoWrite) and
+/// [`StdFmtWrite`](super::StdFmtWrite) adapters, one can print datetimes
+/// directly to implementations of `std::io::Write` and `std::fmt::Write`,
+/// respectively. The example below demonstrates writing to anything
+/// that implements `std::io::Write`. Similar code can be written for
+/// `std::fmt::Write`.
+///
+/// ```no_run
+/// use std::{fs::File, io::{BufWriter, Write}, path::Path};
+///
+/// use jiff::{civil::date, fmt::{StdIoWrite, temporal::DateTimePrinter}};
+///
+/// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("America/New_York")?;
+///
+/// let path = Path::new("/tmp/output");
+/// let mut file = BufWriter::new(File::create(path)?);
+/// DateTimePrinter::new().print_zoned(&zdt, StdIoWrite(&mut file)).unwrap();
+/// file.flush()?;
+/// assert_eq!(
+///     std::fs::read_to_string(path)?,
+///     "2024-06-15T07:00:00-04:00[America/New_York]",
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct DateTimePrinter {
+    p: printer::DateTimePrinter,
+}
+
+impl DateTimePrinter {
+    /// Create a new Temporal datetime printer with the default configuration.
+    pub const fn new() -> DateTimePrinter {
+        DateTimePrinter { p: printer::DateTimePrinter::new() }
+    }
+
+    /// Create a new Temporal datetime printer that prints [`Zoned`] values
+    /// in the [RFC 9557] format, with both an offset and a bracketed time
+    /// zone annotation.
+    ///
+    /// This is the same as [`DateTimePrinter::new`]. It exists to make the
+    /// choice of format explicit alongside
+    /// [`DateTimePrinter::rfc3339_only`] and [`DateTimePrinter::civil_only`].
+    ///
+    /// [RFC 9557]: https://datatracker.ietf.org/doc/rfc9557/
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::rfc9557();
+    ///
+    /// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(
+    ///     PRINTER.zoned_to_string(&zdt),
+    ///     "2024-06-15T07:00:00-04:00[America/New_York]",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn rfc9557() -> DateTimePrinter {
+        DateTimePrinter::new()
+    }
+
+    /// Create a new Temporal datetime printer that prints [`Zoned`] values
+    /// in the [RFC 3339] format, without a bracketed time zone annotation.
+    ///
+    /// This is useful for interoperating with systems that only accept
+    /// RFC 3339 timestamps. Note that the time zone is lost, so parsing the
+    /// output as a `Zoned` will fail. It can instead be parsed as a
+    /// [`Timestamp`], or as a `Zoned` in a fixed offset time zone with
+    /// [`Pieces`].
+    ///
+    /// A `Zoned` in the special `Etc/Unknown` time zone is printed with a
+    /// `Z` offset.
+    ///
+    /// Printing other types is unaffected by this setting.
+    ///
+    /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter, Timestamp};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::rfc3339_only();
+    ///
+    /// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("America/New_York")?;
+    /// let string = PRINTER.zoned_to_string(&zdt);
+    /// assert_eq!(string, "2024-06-15T07:00:00-04:00");
+    /// assert_eq!(string.parse::<Timestamp>()?, zdt.timestamp());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn rfc3339_only() -> DateTimePrinter {
+        DateTimePrinter { p: printer::DateTimePrinter::new().rfc9557(false) }
+    }
+
+    /// Create a new Temporal datetime printer that prints only the civil
+    /// datetime of [`Zoned`] values, without an offset or a bracketed time
+    /// zone annotation.
+    ///
+    /// This is the same as printing the result of [`Zoned::datetime`]. The
+    /// output can be parsed as a [`civil::DateTime`], but does not identify
+    /// a precise instant in time.
+    ///
+    /// Printing other types is unaffected by this setting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::civil_only();
+    ///
+    /// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(PRINTER.zoned_to_string(&zdt), "2024-06-15T07:00:00");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn civil_only() -> DateTimePrinter {
+        DateTimePrinter {
+            p: printer::DateTimePrinter::new()
+                .rfc9557(false)
+                .zoned_offset(false),
+        }
+    }
+
+    /// Use lowercase for the datetime separator and the `Z` (Zulu) UTC offset.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// This example shows how to print a `Zoned` value with a lowercase
+    /// datetime separator.
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new().lowercase(true);
+    ///
+    /// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("America/New_York")?;
+    ///
+    /// let mut buf = String::new();
+    /// // Printing to a `String` can never fail.
+    /// PRINTER.print_zoned(&zdt, &mut buf).unwrap();
+    /// assert_eq!(buf, "2024-06-15t07:00:00-04:00[America/New_York]");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn lowercase(mut self, yes: bool) -> DateTimePrinter {
+        self.p = self.p.lowercase(yes);
+        self
+    }
+
+    /// Use the given ASCII character to separate the date and time when
+    /// printing [`Zoned`], [`Timestamp`] or [`civil::DateTime`] values.
+    ///
+    /// This is set to `T` by default.
+    ///
+    /// # Example
+    ///
+    /// This example shows how to print a `Zoned` value with a different
+    /// datetime separator.
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// // We use a weird non-standard character here, but typically one would
     /// // use this method with an ASCII space.
     /// const PRINTER: DateTimePrinter = DateTimePrinter::new().separator(b'~');
     ///
@@ -1250,6 +2078,46 @@ impl DateTimePrinter {
         self
     }
 
+    /// Whether to use `Z` when printing a [`Timestamp`] without an offset.
+    ///
+    /// When disabled, `+00:00` is printed instead. This is useful when
+    /// the output needs to match what some other system produces, since
+    /// many databases and log formats always write a numeric offset.
+    ///
+    /// This is enabled by default. Note that when printing a [`Zoned`] in
+    /// UTC, a numeric offset is always used regardless of this setting.
+    ///
+    /// # Example
+    ///
+    /// Combined with [`DateTimePrinter::separator`] and
+    /// [`DateTimePrinter::lowercase`], this can be used to mimic a variety
+    /// of formats:
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::DateTimePrinter, Timestamp};
+    ///
+    /// let ts = Timestamp::from_second(1_718_434_800)?;
+    ///
+    /// let printer = DateTimePrinter::new();
+    /// assert_eq!(printer.timestamp_to_string(&ts), "2024-06-15T07:00:00Z");
+    ///
+    /// let printer = DateTimePrinter::new().zulu(false).separator(b' ');
+    /// assert_eq!(
+    ///     printer.timestamp_to_string(&ts),
+    ///     "2024-06-15 07:00:00+00:00",
+    /// );
+    ///
+    /// let printer = DateTimePrinter::new().lowercase(true);
+    /// assert_eq!(printer.timestamp_to_string(&ts), "2024-06-15t07:00:00z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn zulu(mut self, yes: bool) -> DateTimePrinter {
+        self.p = self.p.zulu(yes);
+        self
+    }
+
     /// Set the precision to use for formatting the fractional second component
     /// of a time.
     ///
@@ -1260,6 +2128,9 @@ impl DateTimePrinter {
     /// digits after a decimal point (unless `N==0`, then no fractional
     /// component is printed), even if they are `0`.
     ///
+    /// By default, any digits beyond the precision are truncated. Use
+    /// [`DateTimePrinter::round_mode`] to round them instead.
+    ///
     /// # Example
     ///
     /// ```
@@ -1315,6 +2186,415 @@ impl DateTimePrinter {
         self
     }
 
+    /// Set the minimum number of digits to use for formatting the fractional
+    /// second component of a time.
+    ///
+    /// This only applies when no [precision](DateTimePrinter::precision) is
+    /// set. In that case, the number of digits is automatically determined
+    /// from the value, but is zero-padded to at least `digits` digits. This
+    /// includes printing a fractional second component of zero, unless
+    /// [`DateTimePrinter::omit_zero_fraction`] is enabled.
+    ///
+    /// The default is `0`. Values greater than `9` are clamped to `9`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter =
+    ///     DateTimePrinter::new().min_precision(3);
+    ///
+    /// let t = time(7, 0, 0, 0);
+    /// assert_eq!(PRINTER.time_to_string(&t), "07:00:00.000");
+    /// let t = time(7, 0, 0, 120_000_000);
+    /// assert_eq!(PRINTER.time_to_string(&t), "07:00:00.120");
+    /// // More precision than the minimum is printed when necessary.
+    /// let t = time(7, 0, 0, 123_456_000);
+    /// assert_eq!(PRINTER.time_to_string(&t), "07:00:00.123456");
+    /// ```
+    #[inline]
+    pub const fn min_precision(mut self, digits: u8) -> DateTimePrinter {
+        self.p = self.p.min_precision(digits);
+        self
+    }
+
+    /// Set how digits beyond the configured
+    /// [precision](DateTimePrinter::precision) are handled.
+    ///
+    /// The default is [`RoundMode::Trunc`], which drops the excess digits.
+    /// Any other mode rounds the datetime being printed to the precision.
+    /// Rounding may carry into the larger units. For example, printing
+    /// `23:59:59.9999` with a precision of `3` and [`RoundMode::HalfExpand`]
+    /// results in midnight of the following day. When printing a
+    /// [`civil::Time`] on its own, this wraps around to `00:00:00.000`.
+    ///
+    /// Rounding is always done on the digits as they are printed. So for
+    /// example, [`RoundMode::Floor`] never results in printing a time that
+    /// is later than what would have been printed with truncation, even
+    /// for datetimes before the Unix epoch.
+    ///
+    /// This has no effect when no precision is set, since in that case, all
+    /// digits are printed.
+    ///
+    /// In the rare case that rounding would result in a datetime outside of
+    /// Jiff's supported range, the excess digits are truncated instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter, RoundMode};
+    ///
+    /// let dt = date(2024, 6, 15).at(23, 59, 59, 999_600_000);
+    ///
+    /// let printer = DateTimePrinter::new().precision(Some(3));
+    /// assert_eq!(printer.datetime_to_string(&dt), "2024-06-15T23:59:59.999");
+    ///
+    /// let printer = printer.round_mode(RoundMode::HalfExpand);
+    /// assert_eq!(printer.datetime_to_string(&dt), "2024-06-16T00:00:00.000");
+    /// ```
+    #[inline]
+    pub const fn round_mode(mut self, mode: RoundMode) -> DateTimePrinter {
+        self.p = self.p.round_mode(mode);
+        self
+    }
+
+    /// When enabled, the fractional second component is omitted entirely
+    /// when it is zero, even when a [precision](DateTimePrinter::precision)
+    /// or a [minimum precision](DateTimePrinter::min_precision) is set.
+    ///
+    /// This has no effect when neither is set, since in that case, a
+    /// fractional second component of zero is never printed.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::rfc3339_only()
+    ///     .precision(Some(3))
+    ///     .omit_zero_fraction(true);
+    ///
+    /// let zdt = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(PRINTER.zoned_to_string(&zdt), "2024-06-15T07:00:00-04:00");
+    ///
+    /// let zdt = zdt.with().millisecond(120).build()?;
+    /// assert_eq!(
+    ///     PRINTER.zoned_to_string(&zdt),
+    ///     "2024-06-15T07:00:00.120-04:00",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn omit_zero_fraction(mut self, yes: bool) -> DateTimePrinter {
+        self.p = self.p.omit_zero_fraction(yes);
+        self
+    }
+
+    /// Set how offsets with a non-zero seconds component are printed.
+    ///
+    /// By default, such offsets are rounded to the nearest minute (via
+    /// [`SubminuteOffset::Round`]), since RFC 3339 requires offsets to be an
+    /// integral number of minutes. Offsets with seconds are rare for modern
+    /// datetimes, but are common for datetimes before about 1900, when
+    /// local mean time was in use in most places.
+    ///
+    /// This also applies to offsets in an RFC 9557 time zone annotation,
+    /// which are used when a time zone has no IANA time zone identifier
+    /// (like a fixed offset time zone). Otherwise, the annotation wouldn't
+    /// agree with the offset before it. Note that the Temporal grammar only
+    /// permits offsets with minute precision in annotations, but Jiff's
+    /// parser accepts seconds too. This means a [`Zoned`] with a fixed offset
+    /// time zone round-trips when printed with [`SubminuteOffset::Full`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     civil::date,
+    ///     fmt::temporal::{DateTimePrinter, SubminuteOffset},
+    ///     tz,
+    /// };
+    ///
+    /// const PRINTER: DateTimePrinter =
+    ///     DateTimePrinter::new().subminute_offset(SubminuteOffset::Full);
+    ///
+    /// let zdt = date(1850, 1, 1).in_tz("America/Los_Angeles")?;
+    /// let mut buf = String::new();
+    /// // Printing to a `String` can never fail.
+    /// PRINTER.print_zoned(&zdt, &mut buf).unwrap();
+    /// assert_eq!(buf, "1850-01-01T00:00:00-07:52:58[America/Los_Angeles]");
+    ///
+    /// // The exact instant is preserved even when
+    /// // printing a timestamp with an offset.
+    /// let ts = zdt.timestamp();
+    /// let mut buf = String::new();
+    /// PRINTER.print_timestamp_with_offset(&ts, zdt.offset(), &mut buf).unwrap();
+    /// assert_eq!(buf, "1850-01-01T00:00:00-07:52:58");
+    /// assert_eq!(buf.parse::<jiff::Timestamp>()?, ts);
+    ///
+    /// // Offsets without seconds are unaffected.
+    /// let mut buf = String::new();
+    /// PRINTER.print_timestamp_with_offset(&ts, tz::offset(-8), &mut buf).unwrap();
+    /// assert_eq!(buf, "1849-12-31T23:52:58-08:00");
+    ///
+    /// // A fixed offset time zone round-trips, including its seconds.
+    /// let zdt = zdt.with_time_zone(tz::TimeZone::fixed(zdt.offset()));
+    /// let mut buf = String::new();
+    /// PRINTER.print_zoned(&zdt, &mut buf).unwrap();
+    /// assert_eq!(buf, "1850-01-01T00:00:00-07:52:58[-07:52:58]");
+    /// assert_eq!(buf.parse::<jiff::Zoned>()?, zdt);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn subminute_offset(
+        mut self,
+        kind: SubminuteOffset,
+    ) -> DateTimePrinter {
+        self.p = self.p.subminute_offset(kind);
+        self
+    }
+
+    /// When enabled, an RFC 9557 annotation is added whenever the printed
+    /// offset was rounded to the nearest minute.
+    ///
+    /// The annotation is written as `[_jiff-rounded-offset=true]`, and
+    /// appears after any time zone annotation. Since it uses an experimental
+    /// key and isn't marked as critical, parsers conforming to RFC 9557
+    /// (including Jiff's) will ignore it. This is useful when the printed
+    /// datetime is shown to humans and you want to indicate that the offset
+    /// shown isn't exact.
+    ///
+    /// Rounding only occurs when the offset has a non-zero seconds component
+    /// and [`SubminuteOffset::Round`] is used (which is the default).
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter =
+    ///     DateTimePrinter::new().annotate_rounded_offset(true);
+    ///
+    /// let zdt = date(1850, 1, 1).in_tz("America/Los_Angeles")?;
+    /// assert_eq!(
+    ///     PRINTER.zoned_to_string(&zdt),
+    ///     "1850-01-01T00:00:00-07:53[America/Los_Angeles]\
+    ///      [_jiff-rounded-offset=true]",
+    /// );
+    /// // The annotation is ignored when parsing.
+    /// assert_eq!(
+    ///     PRINTER.zoned_to_string(&zdt).parse::<jiff::Zoned>()?,
+    ///     zdt,
+    /// );
+    ///
+    /// // When no rounding occurs, no annotation is added.
+    /// let zdt = date(2024, 6, 15).in_tz("America/Los_Angeles")?;
+    /// assert_eq!(
+    ///     PRINTER.zoned_to_string(&zdt),
+    ///     "2024-06-15T00:00:00-07:00[America/Los_Angeles]",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn annotate_rounded_offset(
+        mut self,
+        yes: bool,
+    ) -> DateTimePrinter {
+        self.p = self.p.annotate_rounded_offset(yes);
+        self
+    }
+
+    /// When enabled, years are always written in the ISO 8601 expanded
+    /// representation: a sign followed by six digits.
+    ///
+    /// By default, years in the range `0000` to `9999` are written with four
+    /// digits and no sign, and only negative years use the sign-prefixed six
+    /// digit form. Enabling this option makes the output uniform, which some
+    /// archival and astronomical datasets require. Regardless of this
+    /// setting, the output can be parsed by Jiff's default parser.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new().expanded_years(true);
+    ///
+    /// assert_eq!(PRINTER.date_to_string(&date(2024, 3, 1)), "+002024-03-01");
+    /// assert_eq!(PRINTER.date_to_string(&date(-500, 3, 1)), "-000500-03-01");
+    /// assert_eq!(
+    ///     PRINTER.datetime_to_string(&date(0, 1, 1).at(12, 0, 0, 0)),
+    ///     "+000000-01-01T12:00:00",
+    /// );
+    /// ```
+    #[inline]
+    pub const fn expanded_years(mut self, yes: bool) -> DateTimePrinter {
+        self.p = self.p.expanded_years(yes);
+        self
+    }
+
+    /// When enabled, datetimes are printed in the ISO 8601 "basic" format.
+    ///
+    /// The basic format omits the `-` separators between date components
+    /// and the `:` separators between time and offset components. For
+    /// example, `20240401T123000Z` instead of `2024-04-01T12:30:00Z`. This
+    /// is useful for compact identifiers, such as file names or object
+    /// storage keys. Jiff's parser accepts both the basic and the extended
+    /// format, so output in either format can be parsed back.
+    ///
+    /// Other parts of the output, such as fractional seconds and RFC 9557
+    /// time zone annotations with an IANA identifier, are unaffected. Note
+    /// that the default separator between the date and the time, `T`, can be
+    /// changed with [`DateTimePrinter::separator`].
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter, Timestamp};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new().basic(true);
+    ///
+    /// let ts: Timestamp = "2024-04-01T12:30:00Z".parse()?;
+    /// assert_eq!(PRINTER.timestamp_to_string(&ts), "20240401T123000Z");
+    ///
+    /// let zdt = date(2024, 4, 1).at(12, 30, 0, 500_000_000).in_tz("Asia/Kolkata")?;
+    /// let got = PRINTER.zoned_to_string(&zdt);
+    /// assert_eq!(got, "20240401T123000.5+0530[Asia/Kolkata]");
+    /// assert_eq!(got.parse::<jiff::Zoned>()?, zdt);
+    ///
+    /// assert_eq!(PRINTER.date_to_string(&zdt.date()), "20240401");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn basic(mut self, yes: bool) -> DateTimePrinter {
+        self.p = self.p.basic(yes);
+        self
+    }
+
+    /// When enabled, a comma is used instead of a period to separate the
+    /// fractional part of a second.
+    ///
+    /// ISO 8601 permits either separator, and this is useful for
+    /// interoperating with systems that expect the comma, as is common in
+    /// data generated in many European locales. Jiff's parser always accepts
+    /// both separators, so output with this option enabled can be parsed
+    /// back.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::{date, Time}, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter =
+    ///     DateTimePrinter::new().decimal_comma(true);
+    ///
+    /// let t = date(2024, 6, 15).at(12, 30, 45, 500_000_000);
+    /// assert_eq!(PRINTER.datetime_to_string(&t), "2024-06-15T12:30:45,5");
+    /// assert_eq!("12:30:45,5".parse::<Time>()?, t.time());
+    ///
+    /// // Nothing changes when there is no fractional second.
+    /// let t = date(2024, 6, 15).at(12, 30, 45, 0);
+    /// assert_eq!(PRINTER.datetime_to_string(&t), "2024-06-15T12:30:45");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn decimal_comma(mut self, yes: bool) -> DateTimePrinter {
+        self.p = self.p.decimal_comma(yes);
+        self
+    }
+
+    /// When enabled, dates are printed as ISO 8601 ordinal dates.
+    ///
+    /// An ordinal date is written as a year followed by the day of the year,
+    /// e.g., `2024-366` instead of `2024-12-31`. This applies to every
+    /// date printed, including the date component of datetimes. Jiff's parser
+    /// always accepts ordinal dates, so output with this option enabled can
+    /// be parsed back.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::{date, Date}, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new().ordinal(true);
+    ///
+    /// let d = date(2024, 12, 31);
+    /// assert_eq!(PRINTER.date_to_string(&d), "2024-366");
+    /// assert_eq!("2024-366".parse::<Date>()?, d);
+    ///
+    /// let dt = date(2024, 2, 1).at(12, 30, 0, 0);
+    /// assert_eq!(PRINTER.datetime_to_string(&dt), "2024-032T12:30:00");
+    ///
+    /// // It composes with the basic format.
+    /// let printer = PRINTER.basic(true);
+    /// assert_eq!(printer.datetime_to_string(&dt), "2024032T123000");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn ordinal(mut self, yes: bool) -> DateTimePrinter {
+        self.p = self.p.ordinal(yes);
+        self
+    }
+
+    /// When enabled, dates are printed as ISO 8601 week dates.
+    ///
+    /// A week date is written as an ISO week year, followed by the week
+    /// number and the day of the week (where Monday is `1` and Sunday is
+    /// `7`), e.g., `2024-W15-3` instead of `2024-04-10`. This applies to
+    /// every date printed, including the date component of datetimes. Jiff's
+    /// parser always accepts week dates, so output with this option enabled
+    /// can be parsed back.
+    ///
+    /// Enabling this option overrides [`DateTimePrinter::ordinal`], and vice
+    /// versa.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::{date, Date}, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new().week_date(true);
+    ///
+    /// let d = date(2024, 12, 30);
+    /// assert_eq!(PRINTER.date_to_string(&d), "2025-W01-1");
+    /// assert_eq!("2025-W01-1".parse::<Date>()?, d);
+    ///
+    /// let dt = date(2024, 4, 10).at(12, 30, 0, 0);
+    /// assert_eq!(PRINTER.datetime_to_string(&dt), "2024-W15-3T12:30:00");
+    ///
+    /// // It composes with the basic format.
+    /// let printer = PRINTER.basic(true);
+    /// assert_eq!(printer.datetime_to_string(&dt), "2024W153T123000");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn week_date(mut self, yes: bool) -> DateTimePrinter {
+        self.p = self.p.week_date(yes);
+        self
+    }
+
     /// Format a `Zoned` datetime into a string.
     ///
     /// This is a convenience routine for [`DateTimePrinter::print_zoned`] with
@@ -1496,6 +2776,32 @@ impl DateTimePrinter {
         buf
     }
 
+    /// Format a `civil::ISOWeekDate` into a string.
+    ///
+    /// This is a convenience routine for
+    /// [`DateTimePrinter::print_iso_week_date`] with a `String`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new();
+    ///
+    /// let wd = date(2024, 6, 15).iso_week_date();
+    /// assert_eq!(PRINTER.iso_week_date_to_string(&wd), "2024-W24-6");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn iso_week_date_to_string(
+        &self,
+        weekdate: &civil::ISOWeekDate,
+    ) -> alloc::string::String {
+        let mut buf = alloc::string::String::with_capacity(4);
+        // OK because writing to `String` never fails.
+        self.print_iso_week_date(weekdate, &mut buf).unwrap();
+        buf
+    }
+
     /// Format a `civil::Time` into a string.
     ///
     /// This is a convenience routine for [`DateTimePrinter::print_time`]
@@ -1573,31 +2879,64 @@ impl DateTimePrinter {
     ///
     /// const PRINTER: DateTimePrinter = DateTimePrinter::new();
     ///
-    /// let pieces = Pieces::from(Timestamp::UNIX_EPOCH);
-    /// assert_eq!(
-    ///     PRINTER.pieces_to_string(&pieces),
-    ///     "1970-01-01T00:00:00Z",
-    /// );
+    /// let pieces = Pieces::from(Timestamp::UNIX_EPOCH);
+    /// assert_eq!(
+    ///     PRINTER.pieces_to_string(&pieces),
+    ///     "1970-01-01T00:00:00Z",
+    /// );
+    ///
+    /// let pieces = Pieces::from((Timestamp::UNIX_EPOCH, offset(0)));
+    /// assert_eq!(
+    ///     PRINTER.pieces_to_string(&pieces),
+    ///     "1970-01-01T00:00:00+00:00",
+    /// );
+    ///
+    /// let pieces = Pieces::from((Timestamp::UNIX_EPOCH, offset(-5)));
+    /// assert_eq!(
+    ///     PRINTER.pieces_to_string(&pieces),
+    ///     "1969-12-31T19:00:00-05:00",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn pieces_to_string(&self, pieces: &Pieces) -> alloc::string::String {
+        let mut buf = alloc::string::String::with_capacity(4);
+        // OK because writing to `String` never fails.
+        self.print_pieces(pieces, &mut buf).unwrap();
+        buf
+    }
+
+    /// Format an [`Interval`] into a string.
+    ///
+    /// This is a convenience routine for [`DateTimePrinter::print_interval`]
+    /// with a `String`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::{DateTimePrinter, Interval}};
     ///
-    /// let pieces = Pieces::from((Timestamp::UNIX_EPOCH, offset(0)));
-    /// assert_eq!(
-    ///     PRINTER.pieces_to_string(&pieces),
-    ///     "1970-01-01T00:00:00+00:00",
-    /// );
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new();
     ///
-    /// let pieces = Pieces::from((Timestamp::UNIX_EPOCH, offset(-5)));
+    /// let start = date(2024, 6, 15).at(7, 0, 0, 0).in_tz("America/New_York")?;
+    /// let end = start.timestamp().to_zoned(jiff::tz::TimeZone::UTC);
+    /// let interval = Interval::from_start_end(start, end);
     /// assert_eq!(
-    ///     PRINTER.pieces_to_string(&pieces),
-    ///     "1969-12-31T19:00:00-05:00",
+    ///     PRINTER.interval_to_string(&interval),
+    ///     "2024-06-15T07:00:00-04:00[America/New_York]/2024-06-15T11:00:00Z",
     /// );
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     #[cfg(feature = "alloc")]
-    pub fn pieces_to_string(&self, pieces: &Pieces) -> alloc::string::String {
+    pub fn interval_to_string(
+        &self,
+        interval: &Interval,
+    ) -> alloc::string::String {
         let mut buf = alloc::string::String::with_capacity(4);
         // OK because writing to `String` never fails.
-        self.print_pieces(pieces, &mut buf).unwrap();
+        self.print_interval(interval, &mut buf).unwrap();
         buf
     }
 
@@ -1819,6 +3158,43 @@ impl DateTimePrinter {
         self.p.print_date(date, wtr)
     }
 
+    /// Print a `civil::ISOWeekDate` to the given writer.
+    ///
+    /// The week date is always printed as an ISO 8601 week date, e.g.,
+    /// `2024-W24-6`, regardless of whether [`DateTimePrinter::week_date`] is
+    /// enabled.
+    ///
+    /// # Errors
+    ///
+    /// This only returns an error when writing to the given [`Write`]
+    /// implementation would fail. Some such implementations, like for `String`
+    /// and `Vec<u8>`, never fail (unless memory allocation fails). In such
+    /// cases, it would be appropriate to call `unwrap()` on the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new();
+    ///
+    /// let wd = date(-1, 1, 1).iso_week_date();
+    ///
+    /// let mut buf = String::new();
+    /// // Printing to a `String` can never fail.
+    /// PRINTER.print_iso_week_date(&wd, &mut buf).unwrap();
+    /// assert_eq!(buf, "-000002-W53-5");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn print_iso_week_date<W: Write>(
+        &self,
+        weekdate: &civil::ISOWeekDate,
+        wtr: W,
+    ) -> Result<(), Error> {
+        self.p.print_iso_week_date(weekdate, wtr)
+    }
+
     /// Print a `civil::Time` to the given writer.
     ///
     /// # Errors
@@ -1964,6 +3340,55 @@ impl DateTimePrinter {
     ) -> Result<(), Error> {
         self.p.print_pieces(pieces, wtr)
     }
+
+    /// Print an ISO 8601 time interval to the given writer.
+    ///
+    /// Datetimes in the interval whose time zone is UTC are printed with a
+    /// `Z` offset, and datetimes whose time zone is a fixed offset are
+    /// printed with that offset and no time zone annotation. All other
+    /// datetimes are printed in the same way as
+    /// [`DateTimePrinter::print_zoned`]. Spans are printed in the same way as
+    /// [`SpanPrinter::print_span`].
+    ///
+    /// # Errors
+    ///
+    /// This only returns an error when writing to the given [`Write`]
+    /// implementation would fail. Some such implementations, like for `String`
+    /// and `Vec<u8>`, never fail (unless memory allocation fails). In such
+    /// cases, it would be appropriate to call `unwrap()` on the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{
+    ///     civil::date,
+    ///     fmt::temporal::{DateTimePrinter, Interval, Repetitions},
+    ///     tz,
+    ///     ToSpan,
+    /// };
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new();
+    ///
+    /// let end = date(2024, 6, 15)
+    ///     .at(7, 0, 0, 0)
+    ///     .to_zoned(tz::TimeZone::fixed(tz::offset(5)))?;
+    /// let interval = Interval::from_span_end(90.minutes(), end)
+    ///     .with_repetitions(Some(Repetitions::Unbounded));
+    ///
+    /// let mut buf = String::new();
+    /// // Printing to a `String` can never fail.
+    /// PRINTER.print_interval(&interval, &mut buf).unwrap();
+    /// assert_eq!(buf, "R/PT90M/2024-06-15T07:00:00+05:00");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn print_interval<W: Write>(
+        &self,
+        interval: &Interval,
+        wtr: W,
+    ) -> Result<(), Error> {
+        self.p.print_interval(interval, wtr)
+    }
 }
 
 /// A parser for Temporal durations.
@@ -2187,6 +3612,96 @@ impl SpanPrinter {
         SpanPrinter { p: self.p.lowercase(yes) }
     }
 
+    /// Use the "alternative" duration format from ISO 8601.
+    ///
+    /// Instead of using unit designators, this format writes a duration
+    /// like a datetime: `PYYYY-MM-DDTHH:MM:SS`. Some data interchange formats
+    /// require this representation. All fields are always written, and if
+    /// there are any non-zero units smaller than seconds, then they are
+    /// written as a fractional second.
+    ///
+    /// Since this format has no field for weeks, any weeks in a [`Span`] are
+    /// written as 7 days each. And while ISO 8601 says that the value of
+    /// each field should not exceed its "carry-over point" (e.g., 12 months
+    /// or 24 hours), this printer writes each unit value in a `Span` as-is.
+    /// When a value is too big for its field, it is written using as many
+    /// digits as needed. A [`SignedDuration`] is always written with zero
+    /// date fields and a number of hours that may exceed 24.
+    ///
+    /// The [`SpanPrinter::lowercase`] setting has no effect when this is
+    /// enabled.
+    ///
+    /// Jiff's ISO 8601 duration parser automatically recognizes this format,
+    /// so no configuration is required to parse it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::SpanPrinter, SignedDuration, Span, ToSpan};
+    ///
+    /// const PRINTER: SpanPrinter = SpanPrinter::new().alternative(true);
+    ///
+    /// let span = 1.year().months(2).days(3).hours(4).minutes(5).seconds(6);
+    /// assert_eq!(PRINTER.span_to_string(&span), "P0001-02-03T04:05:06");
+    /// assert_eq!(PRINTER.span_to_string(&-span), "-P0001-02-03T04:05:06");
+    ///
+    /// let span = 2.weeks().days(1).milliseconds(500);
+    /// assert_eq!(PRINTER.span_to_string(&span), "P0000-00-15T00:00:00.5");
+    ///
+    /// let dur = SignedDuration::new(86_525, 123_000_789);
+    /// assert_eq!(
+    ///     PRINTER.duration_to_string(&dur),
+    ///     "P0000-00-00T24:02:05.123000789",
+    /// );
+    ///
+    /// // Parsing the alternative format works automatically.
+    /// let span: Span = "P0001-02-03T04:05:06".parse()?;
+    /// assert_eq!(
+    ///     span.fieldwise(),
+    ///     1.year().months(2).days(3).hours(4).minutes(5).seconds(6),
+    /// );
+    /// let parsed: SignedDuration = "P0000-00-00T24:02:05.123000789".parse()?;
+    /// assert_eq!(parsed, dur);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn alternative(self, yes: bool) -> SpanPrinter {
+        SpanPrinter { p: self.p.alternative(yes) }
+    }
+
+    /// Use a comma instead of a period to separate the fractional part of a
+    /// second.
+    ///
+    /// ISO 8601 permits either separator. Jiff's parser always accepts
+    /// both, so output with this option enabled can be parsed back.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::SpanPrinter, SignedDuration, Span, ToSpan};
+    ///
+    /// const PRINTER: SpanPrinter = SpanPrinter::new().decimal_comma(true);
+    ///
+    /// let span = 1.hour().milliseconds(1_500);
+    /// assert_eq!(PRINTER.span_to_string(&span), "PT1H1,5S");
+    ///
+    /// let dur = SignedDuration::from_millis(90_250);
+    /// assert_eq!(PRINTER.duration_to_string(&dur), "PT1M30,25S");
+    ///
+    /// // Fractional units are parsed with either separator.
+    /// let span: Span = "PT1,5H".parse()?;
+    /// assert_eq!(span, 1.hour().minutes(30).fieldwise());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn decimal_comma(self, yes: bool) -> SpanPrinter {
+        SpanPrinter { p: self.p.decimal_comma(yes) }
+    }
+
     /// Format a `Span` into a string.
     ///
     /// This is a convenience routine for [`SpanPrinter::print_span`] with
@@ -2367,4 +3882,497 @@ mod tests {
             span2.total(Unit::Hour).unwrap()
         );
     }
+
+    #[test]
+    fn ok_interval_roundtrip() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let p = |input: &str| {
+            DateTimeParser::new().parse_interval(input).unwrap().to_string()
+        };
+
+        insta::assert_snapshot!(p("2024-01-01T00:00Z/P1D"), @"2024-01-01T00:00:00Z/P1D");
+        insta::assert_snapshot!(p("P1D/2024-01-01T00:00Z"), @"P1D/2024-01-01T00:00:00Z");
+        insta::assert_snapshot!(p("-P1D/2024-01-01T00:00Z"), @"-P1D/2024-01-01T00:00:00Z");
+        insta::assert_snapshot!(
+            p("2024-01-01T00:00+05:30/2024-01-02T00:00-05"),
+            @"2024-01-01T00:00:00+05:30/2024-01-02T00:00:00-05:00",
+        );
+        insta::assert_snapshot!(
+            p("2024-01-01T00:00[America/New_York]/pt1h"),
+            @"2024-01-01T00:00:00-05:00[America/New_York]/PT1H",
+        );
+        insta::assert_snapshot!(p("R/2024-01-01T00:00Z/P1D"), @"R/2024-01-01T00:00:00Z/P1D");
+        insta::assert_snapshot!(p("R0/2024-01-01T00:00Z/P1D"), @"R0/2024-01-01T00:00:00Z/P1D");
+        insta::assert_snapshot!(p("R12/P1M/2024-01-01T00:00Z"), @"R12/P1M/2024-01-01T00:00:00Z");
+        insta::assert_snapshot!(p("+002024-01-01T00:00Z/P1D"), @"2024-01-01T00:00:00Z/P1D");
+    }
+
+    #[test]
+    fn err_interval() {
+        let p = |input: &str| {
+            DateTimeParser::new().parse_interval(input).unwrap_err()
+        };
+
+        insta::assert_snapshot!(p("2024-01-01T00:00Z"), @r###"expected '/' after first part of interval "2024-01-01T00:00Z", but found "" instead"###);
+        insta::assert_snapshot!(p("2024-01-01T00:00Z/"), @r###"failed to parse year in date "": expected four digit year (or leading sign for six digit year), but found end of input"###);
+        insta::assert_snapshot!(p("2024-01-01T00:00/P1D"), @r###"failed to find offset or time zone annotation in "2024-01-01T00:00", which is required for datetimes in an interval"###);
+        insta::assert_snapshot!(p("2024-01-01/P1D"), @r###"failed to find offset or time zone annotation in "2024-01-01", which is required for datetimes in an interval"###);
+        insta::assert_snapshot!(p("P1D/P1D"), @r###"found two durations in interval "P1D/P1D", but at least one part of an interval must be a datetime"###);
+        insta::assert_snapshot!(p("R5P1D/2024-01-01T00:00Z"), @r###"expected '/' after repeating interval prefix, but found "P1D/2024-01-01T00:00Z" instead"###);
+        insta::assert_snapshot!(p("R99999999999999999999/P1D/2024-01-01T00:00Z"), @r###"failed to parse "99999999999999999999" as number of repetitions: number '99999999999999999999' too big to parse into 64-bit integer"###);
+        insta::assert_snapshot!(p("2024-01-01T00:00Z/P1D/P1D"), @r###"parsed value '2024-01-01T00:00Z/P1D', but unparsed input "/P1D" remains (expected no unparsed input)"###);
+        insta::assert_snapshot!(p("2024-01-01T00:00Z--P1D"), @r###"expected '/' after first part of interval "2024-01-01T00:00Z", but found "--P1D" instead"###);
+    }
+
+    #[test]
+    fn ok_expanded_years() {
+        const PARSER: DateTimeParser =
+            DateTimeParser::new().expanded_years(true);
+        const PRINTER: DateTimePrinter =
+            DateTimePrinter::new().expanded_years(true);
+
+        let p = |input: &str| {
+            PRINTER.datetime_to_string(&PARSER.parse_datetime(input).unwrap())
+        };
+
+        insta::assert_snapshot!(p("2024-01-15"), @"+002024-01-15T00:00:00");
+        insta::assert_snapshot!(p("+002024-01-15"), @"+002024-01-15T00:00:00");
+        insta::assert_snapshot!(p("+0002024-01-15T12:00"), @"+002024-01-15T12:00:00");
+        insta::assert_snapshot!(p("-000000000500-03-01"), @"-000500-03-01T00:00:00");
+        insta::assert_snapshot!(p("+0000000-01-01"), @"+000000-01-01T00:00:00");
+        insta::assert_snapshot!(p("+0020240115"), @"+002024-01-15T00:00:00");
+        insta::assert_snapshot!(p("+0020240115T01"), @"+002024-01-15T01:00:00");
+        insta::assert_snapshot!(p("-009999-01-01"), @"-009999-01-01T00:00:00");
+    }
+
+    #[test]
+    fn err_expanded_years() {
+        const PARSER: DateTimeParser =
+            DateTimeParser::new().expanded_years(true);
+
+        let p = |input: &str| PARSER.parse_date(input).unwrap_err();
+
+        insta::assert_snapshot!(p("+0100000-01-01"), @r###"failed to parse year in date "+0100000-01-01": year is not valid: parameter 'year' with value 100000 is not in the required range of -9999..=9999"###);
+        insta::assert_snapshot!(p("-0010000-01-01"), @r###"failed to parse year in date "-0010000-01-01": year is not valid: parameter 'year' with value 10000 is not in the required range of -9999..=9999"###);
+        insta::assert_snapshot!(p("-0000000-01-01"), @r###"failed to parse year in date "-0000000-01-01": year zero must be written without a sign or a positive sign, but not a negative sign"###);
+        insta::assert_snapshot!(p("+99999999999999999999-01-01"), @r###"failed to parse year in date "+99999999999999999999-01-01": expanded year with 20 digits is too big"###);
+        insta::assert_snapshot!(p("+00020240115"), @r###"failed to parse month in date "+00020240115": month is not valid: parameter 'month' with value 40 is not in the required range of 1..=12"###);
+        insta::assert_snapshot!(
+            DateTimeParser::new().parse_date("+0002024-01-15").unwrap_err(),
+            @r###"failed to parse month in date "+0002024-01-15": failed to parse "4-" as month (a two digit integer): invalid digit, expected 0-9 but got -"###,
+        );
+    }
+
+    #[test]
+    fn ok_basic_format() {
+        let ts = |input: &str| {
+            DateTimeParser::new().parse_timestamp(input).unwrap()
+        };
+        let dt =
+            |input: &str| DateTimeParser::new().parse_datetime(input).unwrap();
+        let d = |input: &str| DateTimeParser::new().parse_date(input).unwrap();
+        let t = |input: &str| DateTimeParser::new().parse_time(input).unwrap();
+
+        insta::assert_snapshot!(ts("20240401T123000Z"), @"2024-04-01T12:30:00Z");
+        insta::assert_snapshot!(ts("20240401t123000z"), @"2024-04-01T12:30:00Z");
+        insta::assert_snapshot!(ts("20240401T123000.123+0530"), @"2024-04-01T07:00:00.123Z");
+        insta::assert_snapshot!(ts("20240401T1230-05"), @"2024-04-01T17:30:00Z");
+        insta::assert_snapshot!(ts("2024-04-01T123000+0530"), @"2024-04-01T07:00:00Z");
+        insta::assert_snapshot!(ts("20240401T12:30:00+05:30"), @"2024-04-01T07:00:00Z");
+        insta::assert_snapshot!(dt("20240401T123000"), @"2024-04-01T12:30:00");
+        insta::assert_snapshot!(dt("20240401 12"), @"2024-04-01T12:00:00");
+        insta::assert_snapshot!(d("20240401"), @"2024-04-01");
+        insta::assert_snapshot!(d("-0010000401"), @"-001000-04-01");
+        insta::assert_snapshot!(t("235959"), @"23:59:59");
+        insta::assert_snapshot!(t("T123000.5"), @"12:30:00.5");
+    }
+
+    #[test]
+    fn err_basic_format() {
+        let p = |input: &str| {
+            DateTimeParser::new().parse_timestamp(input).unwrap_err()
+        };
+
+        insta::assert_snapshot!(p("2024-0401T123000Z"), @r###"failed to parse separator after month: expected '-' separator, but found "0" instead"###);
+        insta::assert_snapshot!(p("202404-01T123000Z"), @"failed to parse separator after month: expected no separator after month since none was found after the year, but found a '-' separator");
+        insta::assert_snapshot!(p("20240401T12:3000Z"), @r###"parsed value '20240401T12:30', but unparsed input "00Z" remains (expected no unparsed input)"###);
+        insta::assert_snapshot!(p("20240401T1230:00Z"), @r###"parsed value '20240401T1230', but unparsed input ":00Z" remains (expected no unparsed input)"###);
+        insta::assert_snapshot!(p("20240401T123000"), @r###"failed to find offset component in "20240401T123000", which is required for parsing a timestamp"###);
+    }
+
+    #[test]
+    fn ok_lenient() {
+        const PARSER: DateTimeParser = DateTimeParser::new().lenient(true);
+
+        let ts = |input: &str| PARSER.parse_timestamp(input).unwrap();
+        let dt = |input: &str| PARSER.parse_datetime(input).unwrap();
+        let d = |input: &str| PARSER.parse_date(input).unwrap();
+        let t = |input: &str| PARSER.parse_time(input).unwrap();
+
+        insta::assert_snapshot!(ts("2024-4-1T12:30Z"), @"2024-04-01T12:30:00Z");
+        insta::assert_snapshot!(ts("2024-04-01 12:30-5"), @"2024-04-01T17:30:00Z");
+        insta::assert_snapshot!(ts("2024-04-01T12:30+5:30"), @"2024-04-01T07:00:00Z");
+        insta::assert_snapshot!(ts("2024-04-01T12:30-5:00:30"), @"2024-04-01T17:30:30Z");
+        insta::assert_snapshot!(ts("2024-04-01T12:30:00+05:30  "), @"2024-04-01T07:00:00Z");
+        insta::assert_snapshot!(ts("20240401T1230-5"), @"2024-04-01T17:30:00Z");
+        insta::assert_snapshot!(dt("2024-4-1 12:30\r\n"), @"2024-04-01T12:30:00");
+        insta::assert_snapshot!(dt("2024-12-9T00"), @"2024-12-09T00:00:00");
+        insta::assert_snapshot!(d("2024-1-31"), @"2024-01-31");
+        insta::assert_snapshot!(d("2024-01-1\t"), @"2024-01-01");
+        insta::assert_snapshot!(d("-000001-1-1"), @"-000001-01-01");
+        insta::assert_snapshot!(t("2024-4-1T12:30"), @"12:30:00");
+        insta::assert_snapshot!(t("12:30 "), @"12:30:00");
+        insta::assert_snapshot!(
+            PARSER.parse_zoned("2024-3-9T02:30-5[America/New_York] ").unwrap(),
+            @"2024-03-09T02:30:00-05:00[America/New_York]",
+        );
+    }
+
+    #[test]
+    fn err_lenient() {
+        const PARSER: DateTimeParser = DateTimeParser::new().lenient(true);
+
+        let p = |input: &str| PARSER.parse_datetime(input).unwrap_err();
+        let ts = |input: &str| PARSER.parse_timestamp(input).unwrap_err();
+
+        insta::assert_snapshot!(p("202441"), @r###"failed to parse month in date "202441": month is not valid: parameter 'month' with value 41 is not in the required range of 1..=12"###);
+        insta::assert_snapshot!(p("2024-4-1T1:30"), @r###"failed to parse hour in time "1:30": failed to parse "1:" as hour (a two digit integer): invalid digit, expected 0-9 but got :"###);
+        insta::assert_snapshot!(p("2024-123-1"), @r###"parsed value '2024-123', but unparsed input "-1" remains (expected no unparsed input)"###);
+        insta::assert_snapshot!(p(" 2024-04-01"), @r###"failed to parse year in date " 2024-04-01": failed to parse " 202" as year (a four digit integer): invalid digit, expected 0-9 but got"###);
+        insta::assert_snapshot!(ts("2024-04-01T12:30+530"), @r###"failed to parse hours in UTC numeric offset "+530": offset hours are not valid: parameter 'hours' with value 53 is not in the required range of 0..=25"###);
+    }
+
+    #[test]
+    fn print_basic_format() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        const PRINTER: DateTimePrinter = DateTimePrinter::new().basic(true);
+
+        let p = |input: &str| {
+            let pieces = Pieces::parse(input).unwrap();
+            PRINTER.pieces_to_string(&pieces)
+        };
+        insta::assert_snapshot!(p("2024-04-01"), @"20240401");
+        insta::assert_snapshot!(p("2024-04-01T12:30:00.123"), @"20240401T123000.123");
+        insta::assert_snapshot!(p("2024-04-01T12:30:00-00:00"), @"20240401T123000-0000");
+        insta::assert_snapshot!(p("2024-04-01T12:30:00+05:30[+05:30]"), @"20240401T123000+0530[+0530]");
+        insta::assert_snapshot!(p("-002024-04-01T12:30:00Z[America/New_York]"), @"-0020240401T123000Z[America/New_York]");
+
+        let zdt: Zoned =
+            "1850-01-01T00:00[America/Los_Angeles]".parse().unwrap();
+        insta::assert_snapshot!(PRINTER.zoned_to_string(&zdt), @"18500101T000000-0753[America/Los_Angeles]");
+        let full = PRINTER.subminute_offset(SubminuteOffset::Full);
+        insta::assert_snapshot!(full.zoned_to_string(&zdt), @"18500101T000000-075258[America/Los_Angeles]");
+        let tz = TimeZone::fixed(zdt.offset());
+        insta::assert_snapshot!(full.time_zone_to_string(&tz).unwrap(), @"-075258");
+        let expanded = PRINTER.expanded_years(true);
+        insta::assert_snapshot!(expanded.zoned_to_string(&zdt), @"+0018500101T000000-0753[America/Los_Angeles]");
+        insta::assert_snapshot!(
+            PRINTER.time_to_string(&zdt.time()),
+            @"000000",
+        );
+
+        // Everything printed in the basic format should roundtrip.
+        for zdt in [
+            zdt.clone(),
+            "2024-04-01T12:30:00.5[Asia/Kolkata]".parse().unwrap(),
+            "2024-04-01T12:30:00[+05:30]".parse().unwrap(),
+        ] {
+            let got: Zoned = PRINTER.zoned_to_string(&zdt).parse().unwrap();
+            assert_eq!(got, zdt);
+            let got: Zoned = full.zoned_to_string(&zdt).parse().unwrap();
+            assert_eq!(got, zdt);
+        }
+    }
+
+    #[test]
+    fn roundtrip_fixed_offset_annotation() {
+        const FULL: DateTimePrinter =
+            DateTimePrinter::new().subminute_offset(SubminuteOffset::Full);
+
+        let dt = civil::date(1850, 1, 1).at(12, 30, 0, 0);
+        for seconds in [19800, -28378, 1, -1800, 93599] {
+            let offset = Offset::from_seconds(seconds).unwrap();
+            let zdt = dt.to_zoned(TimeZone::fixed(offset)).unwrap();
+            let printed = FULL.zoned_to_string(&zdt);
+            let got: Zoned = printed.parse().unwrap();
+            assert_eq!(got, zdt, "{printed}");
+            assert_eq!(got.time_zone().iana_name(), None, "{printed}");
+            assert_eq!(got.to_string(), zdt.to_string(), "{printed}");
+
+            let pieces = Pieces::parse(&printed).unwrap();
+            assert_eq!(FULL.pieces_to_string(&pieces), printed);
+        }
+
+        let zdt: Zoned =
+            "1850-01-01T12:30-07:52:58[-07:52:58]".parse().unwrap();
+        insta::assert_snapshot!(
+            FULL.zoned_to_string(&zdt),
+            @"1850-01-01T12:30:00-07:52:58[-07:52:58]",
+        );
+        insta::assert_snapshot!(
+            DateTimePrinter::new().zoned_to_string(&zdt),
+            @"1850-01-01T12:30:00-07:53[-07:53]",
+        );
+        insta::assert_snapshot!(
+            "1850-01-01T12:30-07:52:58[-07:53]".parse::<Zoned>().unwrap_err(),
+            @r###"parsing "1850-01-01T12:30-07:52:58[-07:53]" failed: datetime 1850-01-01T12:30:00 could not resolve to a timestamp since 'reject' conflict resolution was chosen, and because datetime has offset -07:52:58, but the time zone -07:53 for the given datetime unambiguously has offset -07:53"###,
+        );
+    }
+
+    #[test]
+    fn ok_ordinal_date() {
+        let p = |input: &str| {
+            DateTimeParser::new().parse_datetime(input).unwrap().to_string()
+        };
+
+        insta::assert_snapshot!(p("2024-001"), @"2024-01-01T00:00:00");
+        insta::assert_snapshot!(p("2024-366"), @"2024-12-31T00:00:00");
+        insta::assert_snapshot!(p("2023-365T12:30"), @"2023-12-31T12:30:00");
+        insta::assert_snapshot!(p("2024060"), @"2024-02-29T00:00:00");
+        insta::assert_snapshot!(p("2024060T123000"), @"2024-02-29T12:30:00");
+        insta::assert_snapshot!(p("-000004-366"), @"-000004-12-31T00:00:00");
+        insta::assert_snapshot!(p("+002024-100 12"), @"2024-04-09T12:00:00");
+
+        let ts = |input: &str| {
+            DateTimeParser::new().parse_timestamp(input).unwrap().to_string()
+        };
+        insta::assert_snapshot!(ts("2024-366T23:59:59Z"), @"2024-12-31T23:59:59Z");
+    }
+
+    #[test]
+    fn err_ordinal_date() {
+        let p =
+            |input: &str| DateTimeParser::new().parse_date(input).unwrap_err();
+
+        insta::assert_snapshot!(p("2024-000"), @r###"ordinal date parsed from "2024-000" is not valid: day-of-year=0 is out of range for year=2024, must be in range 1..=366"###);
+        insta::assert_snapshot!(p("2023-366"), @r###"ordinal date parsed from "2023-366" is not valid: day-of-year=366 is out of range for year=2023, must be in range 1..=365"###);
+        insta::assert_snapshot!(p("2024-367"), @r###"ordinal date parsed from "2024-367" is not valid: day-of-year=367 is out of range for year=2024, must be in range 1..=366"###);
+        insta::assert_snapshot!(p("2024-3666"), @r###"failed to parse month in date "2024-3666": month is not valid: parameter 'month' with value 36 is not in the required range of 1..=12"###);
+        insta::assert_snapshot!(p("2024-36"), @r###"failed to parse month in date "2024-36": month is not valid: parameter 'month' with value 36 is not in the required range of 1..=12"###);
+    }
+
+    #[test]
+    fn print_ordinal_date() {
+        const PRINTER: DateTimePrinter = DateTimePrinter::new().ordinal(true);
+
+        let p = |input: &str| {
+            let pieces = Pieces::parse(input).unwrap();
+            PRINTER.pieces_to_string(&pieces)
+        };
+        insta::assert_snapshot!(p("2024-01-01"), @"2024-001");
+        insta::assert_snapshot!(p("2024-04-10T12:30:00Z"), @"2024-101T12:30:00Z");
+        insta::assert_snapshot!(p("-000001-12-31"), @"-000001-365");
+
+        for input in
+            ["2024-01-01", "2024-12-31", "2023-12-31", "-009999-01-01"]
+        {
+            let date: civil::Date = input.parse().unwrap();
+            let got: civil::Date =
+                PRINTER.date_to_string(&date).parse().unwrap();
+            assert_eq!(got, date);
+        }
+    }
+
+    #[test]
+    fn ok_week_date() {
+        let p = |input: &str| {
+            DateTimeParser::new().parse_datetime(input).unwrap().to_string()
+        };
+
+        insta::assert_snapshot!(p("2024-W15-3"), @"2024-04-10T00:00:00");
+        insta::assert_snapshot!(p("2024w153"), @"2024-04-10T00:00:00");
+        insta::assert_snapshot!(p("2020-W53-7T12:30"), @"2021-01-03T12:30:00");
+        insta::assert_snapshot!(p("2025-W01-1"), @"2024-12-30T00:00:00");
+        insta::assert_snapshot!(p("-000001-W01-1"), @"-000001-01-04T00:00:00");
+        insta::assert_snapshot!(p("+002024-W15-3 12"), @"2024-04-10T12:00:00");
+
+        let ts = |input: &str| {
+            DateTimeParser::new().parse_timestamp(input).unwrap().to_string()
+        };
+        insta::assert_snapshot!(ts("2024W153T123000Z"), @"2024-04-10T12:30:00Z");
+    }
+
+    #[test]
+    fn err_week_date() {
+        let p =
+            |input: &str| DateTimeParser::new().parse_date(input).unwrap_err();
+
+        insta::assert_snapshot!(p("2024-W00-1"), @r###"failed to parse week date "2024-W00-1": week date is not valid: parameter 'week' with value 0 is not in the required range of 1..=53"###);
+        insta::assert_snapshot!(p("2024-W53-1"), @r###"failed to parse week date "2024-W53-1": week date is not valid: ISO week number `53` is invalid for year `2024`"###);
+        insta::assert_snapshot!(p("2024-W15-0"), @r###"failed to parse week date "2024-W15-0": weekday is not valid: parameter 'weekday' with value 0 is not in the required range of 1..=7"###);
+        insta::assert_snapshot!(p("2024-W15-8"), @r###"failed to parse week date "2024-W15-8": weekday is not valid: parameter 'weekday' with value 8 is not in the required range of 1..=7"###);
+        insta::assert_snapshot!(p("2024-W15"), @r###"failed to parse week date "2024-W15": failed to parse separator after week: expected '-' separator, but found end of input"###);
+        insta::assert_snapshot!(p("2024-W153"), @r###"failed to parse week date "2024-W153": failed to parse separator after week: expected '-' separator, but found "3" instead"###);
+        insta::assert_snapshot!(p("2024W15-3"), @r###"failed to parse week date "2024W15-3": expected no separator after week since none was found after the year, but found a '-' separator"###);
+        insta::assert_snapshot!(p("2024-W1-3"), @r###"failed to parse week date "2024-W1-3": failed to parse "1-" as week (a two digit integer): invalid digit, expected 0-9 but got -"###);
+        insta::assert_snapshot!(p("9999-W52-6"), @r###"failed to parse week date "9999-W52-6": week date is not valid: parameter 'weekday' with value 6 is not in the required range of 1..=5"###);
+    }
+
+    #[test]
+    fn print_week_date() {
+        const PRINTER: DateTimePrinter =
+            DateTimePrinter::new().week_date(true);
+
+        let p = |input: &str| {
+            let pieces = Pieces::parse(input).unwrap();
+            PRINTER.pieces_to_string(&pieces)
+        };
+        insta::assert_snapshot!(p("2024-01-01"), @"2024-W01-1");
+        insta::assert_snapshot!(p("2024-04-10T12:30:00Z"), @"2024-W15-3T12:30:00Z");
+        insta::assert_snapshot!(p("2021-01-03"), @"2020-W53-7");
+        insta::assert_snapshot!(
+            PRINTER.ordinal(true).date_to_string(&civil::date(2024, 4, 10)),
+            @"2024-101",
+        );
+        insta::assert_snapshot!(
+            PRINTER.ordinal(false).date_to_string(&civil::date(2024, 4, 10)),
+            @"2024-W15-3",
+        );
+        insta::assert_snapshot!(
+            PRINTER.week_date(false).date_to_string(&civil::date(2024, 4, 10)),
+            @"2024-04-10",
+        );
+
+        for input in
+            ["2024-01-01", "2024-12-31", "2020-12-31", "-009999-12-31"]
+        {
+            let date: civil::Date = input.parse().unwrap();
+            let got: civil::Date =
+                PRINTER.date_to_string(&date).parse().unwrap();
+            assert_eq!(got, date);
+            let got: civil::ISOWeekDate =
+                date.iso_week_date().to_string().parse().unwrap();
+            assert_eq!(got, date.iso_week_date());
+        }
+    }
+
+    #[test]
+    fn decimal_comma() {
+        const PRINTER: DateTimePrinter =
+            DateTimePrinter::new().decimal_comma(true);
+        const SPAN_PRINTER: SpanPrinter =
+            SpanPrinter::new().decimal_comma(true);
+
+        let p = |input: &str| {
+            let pieces = Pieces::parse(input).unwrap();
+            PRINTER.pieces_to_string(&pieces)
+        };
+        insta::assert_snapshot!(p("2024-04-10T12:30:45.5Z"), @"2024-04-10T12:30:45,5Z");
+        insta::assert_snapshot!(p("2024-04-10T12:30:45,123456789+02:00"), @"2024-04-10T12:30:45,123456789+02:00");
+        insta::assert_snapshot!(p("2024-04-10T12:30:45Z"), @"2024-04-10T12:30:45Z");
+        insta::assert_snapshot!(
+            PRINTER.basic(true).precision(Some(3)).time_to_string(
+                &civil::time(12, 30, 45, 0),
+            ),
+            @"123045,000",
+        );
+
+        let ps = |input: &str| {
+            let span: Span = input.parse().unwrap();
+            SPAN_PRINTER.span_to_string(&span)
+        };
+        insta::assert_snapshot!(ps("PT1,5H"), @"PT1H30M");
+        insta::assert_snapshot!(ps("PT1.5S"), @"PT1,5S");
+        insta::assert_snapshot!(ps("PT0,000000001S"), @"PT0,000000001S");
+        insta::assert_snapshot!(
+            SPAN_PRINTER
+                .alternative(true)
+                .span_to_string(&"PT1.25S".parse().unwrap()),
+            @"P0000-00-00T00:00:01,25",
+        );
+        let pd = |input: &str| {
+            let dur: SignedDuration = input.parse().unwrap();
+            SPAN_PRINTER.duration_to_string(&dur)
+        };
+        insta::assert_snapshot!(pd("PT1,5H"), @"PT1H30M");
+        insta::assert_snapshot!(pd("-PT0,5S"), @"-PT0,5S");
+    }
+
+    #[test]
+    fn parse_many_matches_parse() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let inputs = [
+            "2024-06-15T07:00-04[America/New_York]",
+            "2024-06-15T07:00-04[America/New_York]",
+            "2024-06-15T07:00-04[america/new_york]",
+            "2024-06-15T07:00-07[America/New_York]",
+            "2024-06-15T07:00+09[Asia/Tokyo]",
+            "2024-06-15T07:00[Nowhere/Special]",
+            "2024-06-15T07:00-04[-04]",
+            "2024-06-15T07:00-04",
+            "2024-06-15T07:00-04[America/New_York]",
+        ];
+        let p = DateTimeParser::new();
+        let got: alloc::vec::Vec<_> = p
+            .parse_many_zoned(inputs)
+            .map(|r| r.map_err(|e| e.to_string()))
+            .collect();
+        let want: alloc::vec::Vec<_> = inputs
+            .iter()
+            .map(|i| p.parse_zoned(i).map_err(|e| e.to_string()))
+            .collect();
+        assert_eq!(got, want);
+
+        let got: alloc::vec::Vec<_> = p
+            .parse_many_timestamps(inputs)
+            .map(|r| r.map_err(|e| e.to_string()))
+            .collect();
+        let want: alloc::vec::Vec<_> = inputs
+            .iter()
+            .map(|i| p.parse_timestamp(i).map_err(|e| e.to_string()))
+            .collect();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn leap_second_policy() {
+        use crate::{fmt::LeapSecond, tai::LeapSecondTable};
+
+        let input = "2016-12-31T23:59:60.5Z";
+        let p = |policy: LeapSecond| DateTimeParser::new().leap_second(policy);
+
+        insta::assert_snapshot!(
+            p(LeapSecond::Constrain).parse_timestamp(input).unwrap(),
+            @"2016-12-31T23:59:59.5Z",
+        );
+        insta::assert_snapshot!(
+            p(LeapSecond::Clamp).parse_timestamp(input).unwrap(),
+            @"2016-12-31T23:59:59.999999999Z",
+        );
+        insta::assert_snapshot!(
+            p(LeapSecond::Reject).parse_timestamp(input).unwrap_err(),
+            @"failed to parse second in time \"23:59:60.5Z\": leap second is not allowed (found second value of 60)",
+        );
+        insta::assert_snapshot!(
+            p(LeapSecond::Preserve).parse_timestamp(input).unwrap_err(),
+            @"cannot preserve leap second in \"23:59:60.5\" since it cannot be represented by a civil time or Unix timestamp (parse it into a TAI timestamp instead)",
+        );
+
+        let table = LeapSecondTable::builtin();
+        insta::assert_snapshot!(
+            p(LeapSecond::Preserve)
+                .parse_tai_timestamp(input, &table)
+                .unwrap(),
+            @"2017-01-01T00:00:36.5 TAI",
+        );
+        insta::assert_snapshot!(
+            p(LeapSecond::Clamp).parse_tai_timestamp(input, &table).unwrap(),
+            @"2017-01-01T00:00:35.999999999 TAI",
+        );
+        insta::assert_snapshot!(
+            p(LeapSecond::Preserve)
+                .parse_tai_timestamp("2017-12-31T23:59:60Z", &table)
+                .unwrap_err(),
+            @"found leap second after 2017-12-31T23:59:59Z, but there is no leap second at that time in the leap second table",
+        );
+    }
 }
@@ -1,17 +1,18 @@
 use crate::{
-    civil::{Date, DateTime, Time},
+    civil::{Date, DateTime, ISOWeekDate, Time, Weekday},
     error::{err, Error, ErrorContext},
     fmt::{
         offset::{self, ParsedOffset},
         rfc9557::{self, ParsedAnnotations},
-        temporal::Pieces,
+        temporal::{swar, Interval, Pieces, Repetitions, TimeZoneAnnotation},
         util::{
             fractional_time_to_duration, fractional_time_to_span,
             parse_temporal_fraction,
         },
-        Parsed,
+        LeapSecond, Parsed,
     },
     span::Span,
+    tai::{self, LeapSecondTable},
     tz::{
         AmbiguousZoned, Disambiguation, Offset, OffsetConflict, TimeZone,
         TimeZoneDatabase,
@@ -46,7 +47,7 @@ impl<'i> ParsedDateTime<'i> {
     pub(super) fn to_pieces(&self) -> Result<Pieces<'i>, Error> {
         let mut pieces = Pieces::from(self.date.date);
         if let Some(ref time) = self.time {
-            pieces = pieces.with_time(time.time);
+            pieces = pieces.with_time(time.to_time()?);
         }
         if let Some(ref offset) = self.offset {
             pieces = pieces.with_offset(offset.to_pieces_offset()?);
@@ -64,18 +65,41 @@ impl<'i> ParsedDateTime<'i> {
         offset_conflict: OffsetConflict,
         disambiguation: Disambiguation,
     ) -> Result<Zoned, Error> {
-        self.to_ambiguous_zoned(db, offset_conflict)?
+        self.to_zoned_with(
+            |ann| ann.to_time_zone_with(db),
+            offset_conflict,
+            disambiguation,
+        )
+    }
+
+    /// Like `to_zoned`, but resolves the time zone annotation with the
+    /// function given instead of a time zone database.
+    ///
+    /// This is useful for callers that want to cache time zone lookups.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(super) fn to_zoned_with<F>(
+        &self,
+        resolve: F,
+        offset_conflict: OffsetConflict,
+        disambiguation: Disambiguation,
+    ) -> Result<Zoned, Error>
+    where
+        F: FnOnce(&TimeZoneAnnotation<'i>) -> Result<TimeZone, Error>,
+    {
+        self.to_ambiguous_zoned(resolve, offset_conflict)?
             .disambiguate(disambiguation)
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
-    pub(super) fn to_ambiguous_zoned(
+    fn to_ambiguous_zoned<F>(
         &self,
-        db: &TimeZoneDatabase,
+        resolve: F,
         offset_conflict: OffsetConflict,
-    ) -> Result<AmbiguousZoned, Error> {
-        let time = self.time.as_ref().map_or(Time::midnight(), |p| p.time);
-        let dt = DateTime::from_parts(self.date.date, time);
+    ) -> Result<AmbiguousZoned, Error>
+    where
+        F: FnOnce(&TimeZoneAnnotation<'i>) -> Result<TimeZone, Error>,
+    {
+        let dt = DateTime::from_parts(self.date.date, self.time()?);
 
         // We always require a time zone when parsing a zoned instant.
         let tz_annotation =
@@ -86,7 +110,7 @@ impl<'i> ParsedDateTime<'i> {
                     self.input,
                 )
             })?;
-        let tz = tz_annotation.to_time_zone_with(db)?;
+        let tz = resolve(&tz_annotation)?;
 
         // If there's no offset, then our only choice, regardless of conflict
         // resolution preference, is to use the time zone. That is, there is no
@@ -134,15 +158,74 @@ impl<'i> ParsedDateTime<'i> {
         )
     }
 
+    /// Converts this datetime into a zoned datetime for use as one of the
+    /// endpoints of an interval.
+    ///
+    /// If a time zone annotation is present, then this is like
+    /// `ParsedDateTime::to_zoned`. Otherwise, the offset is used as a fixed
+    /// offset time zone (or UTC, in the case of `Z`). If neither is present,
+    /// then this returns an error since the datetime isn't an instant.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(super) fn to_interval_endpoint(
+        &self,
+        db: &TimeZoneDatabase,
+        offset_conflict: OffsetConflict,
+        disambiguation: Disambiguation,
+    ) -> Result<Zoned, Error> {
+        if self.annotations.to_time_zone_annotation()?.is_some() {
+            return self.to_zoned(db, offset_conflict, disambiguation);
+        }
+        let Some(ref parsed_offset) = self.offset else {
+            return Err(err!(
+                "failed to find offset or time zone annotation in {:?}, \
+                 which is required for datetimes in an interval",
+                self.input,
+            ));
+        };
+        let timestamp = self.to_timestamp()?;
+        let tz = if parsed_offset.is_zulu() {
+            TimeZone::UTC
+        } else {
+            TimeZone::fixed(parsed_offset.to_offset()?)
+        };
+        Ok(timestamp.to_zoned(tz))
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     pub(super) fn to_timestamp(&self) -> Result<Timestamp, Error> {
-        let time = self.time.as_ref().map(|p| p.time).ok_or_else(|| {
+        let time = self.required_time()?.to_time()?;
+        self.to_timestamp_at(time)
+    }
+
+    /// Converts this datetime into a TAI timestamp, preserving a leap second
+    /// if one was parsed.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(super) fn to_tai_timestamp(
+        &self,
+        table: &LeapSecondTable,
+    ) -> Result<tai::Timestamp, Error> {
+        let parsed_time = self.required_time()?;
+        let timestamp = self.to_timestamp_at(parsed_time.time)?;
+        if parsed_time.leap {
+            table.leap_second_to_tai(timestamp)
+        } else {
+            table.utc_to_tai(timestamp)
+        }
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn required_time(&self) -> Result<&ParsedTime<'i>, Error> {
+        self.time.as_ref().ok_or_else(|| {
             err!(
                 "failed to find time component in {:?}, \
                  which is required for parsing a timestamp",
                 self.input,
             )
-        })?;
+        })
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn to_timestamp_at(&self, time: Time) -> Result<Timestamp, Error> {
         let parsed_offset = self.offset.as_ref().ok_or_else(|| {
             err!(
                 "failed to find offset component in {:?}, \
@@ -161,6 +244,18 @@ impl<'i> ParsedDateTime<'i> {
         Ok(timestamp)
     }
 
+    /// Returns the parsed offset, unless it indicates that the offset to
+    /// local time is unknown (`Z` or `-00:00`) or if there is no offset.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(super) fn to_known_offset(&self) -> Result<Option<Offset>, Error> {
+        let Some(ref parsed_offset) = self.offset else { return Ok(None) };
+        let offset = parsed_offset.to_pieces_offset()?;
+        if offset.is_unknown() {
+            return Ok(None);
+        }
+        Ok(Some(offset.to_numeric_offset()))
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     pub(super) fn to_datetime(&self) -> Result<DateTime, Error> {
         if self.offset.as_ref().map_or(false, |o| o.is_zulu()) {
@@ -170,7 +265,7 @@ impl<'i> ParsedDateTime<'i> {
                  datetime instead",
             ));
         }
-        Ok(DateTime::from_parts(self.date.date, self.time()))
+        Ok(DateTime::from_parts(self.date.date, self.time()?))
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -186,8 +281,8 @@ impl<'i> ParsedDateTime<'i> {
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
-    fn time(&self) -> Time {
-        self.time.as_ref().map(|p| p.time).unwrap_or(Time::midnight())
+    fn time(&self) -> Result<Time, Error> {
+        self.time.as_ref().map_or(Ok(Time::midnight()), |p| p.to_time())
     }
 }
 
@@ -197,6 +292,68 @@ impl<'i> core::fmt::Display for ParsedDateTime<'i> {
     }
 }
 
+/// The components of an ISO 8601 interval parsed from a string.
+#[derive(Debug)]
+pub(super) struct ParsedInterval<'i> {
+    /// The original input that the interval was parsed from.
+    input: escape::Bytes<'i>,
+    /// An optional recurrence prefix, i.e., `R/` or `Rn/`.
+    repetitions: Option<Repetitions>,
+    /// The part before the `/`.
+    start: ParsedIntervalPart<'i>,
+    /// The part after the `/`.
+    end: ParsedIntervalPart<'i>,
+}
+
+impl<'i> ParsedInterval<'i> {
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(super) fn to_interval(
+        &self,
+        db: &TimeZoneDatabase,
+        offset_conflict: OffsetConflict,
+        disambiguation: Disambiguation,
+    ) -> Result<Interval, Error> {
+        let to_zoned = |dt: &ParsedDateTime<'i>| {
+            dt.to_interval_endpoint(db, offset_conflict, disambiguation)
+        };
+        let interval = match (&self.start, &self.end) {
+            (
+                ParsedIntervalPart::DateTime(start),
+                ParsedIntervalPart::DateTime(end),
+            ) => Interval::from_start_end(to_zoned(start)?, to_zoned(end)?),
+            (
+                ParsedIntervalPart::DateTime(start),
+                &ParsedIntervalPart::Span(span),
+            ) => Interval::from_start_span(to_zoned(start)?, span),
+            (
+                &ParsedIntervalPart::Span(span),
+                ParsedIntervalPart::DateTime(end),
+            ) => Interval::from_span_end(span, to_zoned(end)?),
+            (ParsedIntervalPart::Span(_), ParsedIntervalPart::Span(_)) => {
+                return Err(err!(
+                    "found two durations in interval {:?}, but at least \
+                     one part of an interval must be a datetime",
+                    self.input,
+                ));
+            }
+        };
+        Ok(interval.with_repetitions(self.repetitions))
+    }
+}
+
+impl<'i> core::fmt::Display for ParsedInterval<'i> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.input, f)
+    }
+}
+
+/// One side of an ISO 8601 interval.
+#[derive(Debug)]
+enum ParsedIntervalPart<'i> {
+    DateTime(ParsedDateTime<'i>),
+    Span(Span),
+}
+
 /// The result of parsing a Gregorian calendar civil date.
 #[derive(Debug)]
 pub(super) struct ParsedDate<'i> {
@@ -221,11 +378,22 @@ pub(super) struct ParsedTime<'i> {
     time: Time,
     /// Whether the time was parsed in extended format or not.
     extended: bool,
+    /// Whether a leap second was parsed and should be preserved. When true,
+    /// `time` has a seconds value of `59`.
+    leap: bool,
 }
 
 impl<'i> ParsedTime<'i> {
-    pub(super) fn to_time(&self) -> Time {
-        self.time
+    pub(super) fn to_time(&self) -> Result<Time, Error> {
+        if self.leap {
+            return Err(err!(
+                "cannot preserve leap second in {input:?} since it \
+                 cannot be represented by a civil time or Unix timestamp \
+                 (parse it into a TAI timestamp instead)",
+                input = self.input,
+            ));
+        }
+        Ok(self.time)
     }
 }
 
@@ -295,14 +463,59 @@ impl<'i> ParsedTimeZone<'i> {
 /// A parser for Temporal datetimes.
 #[derive(Debug)]
 pub(super) struct DateTimeParser {
-    /// There are currently no configuration options for this parser.
-    _priv: (),
+    expanded_years: bool,
+    lenient: bool,
+    leap_second: LeapSecond,
 }
 
 impl DateTimeParser {
     /// Create a new Temporal datetime parser with the default configuration.
     pub(super) const fn new() -> DateTimeParser {
-        DateTimeParser { _priv: () }
+        DateTimeParser {
+            expanded_years: false,
+            lenient: false,
+            leap_second: LeapSecond::Constrain,
+        }
+    }
+
+    /// Whether to accept sign-prefixed years with more than six digits.
+    pub(super) const fn expanded_years(self, yes: bool) -> DateTimeParser {
+        DateTimeParser { expanded_years: yes, ..self }
+    }
+
+    /// Whether to accept common deviations from the Temporal grammar.
+    ///
+    /// When enabled, single digit months and days are accepted in the
+    /// extended date format, offset hours may be a single digit and trailing
+    /// whitespace is ignored by [`DateTimeParser::trim`].
+    pub(super) const fn lenient(self, yes: bool) -> DateTimeParser {
+        DateTimeParser { lenient: yes, ..self }
+    }
+
+    /// How to handle a seconds value of `60`.
+    pub(super) const fn leap_second(
+        self,
+        policy: LeapSecond,
+    ) -> DateTimeParser {
+        DateTimeParser { leap_second: policy, ..self }
+    }
+
+    /// Returns the input that should be parsed in full.
+    ///
+    /// When lenient parsing is enabled, this strips trailing ASCII
+    /// whitespace. Otherwise, the input is returned unchanged.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(super) fn trim<'i>(&self, mut input: &'i [u8]) -> &'i [u8] {
+        if !self.lenient {
+            return input;
+        }
+        while let Some((last, rest)) = input.split_last() {
+            if !last.is_ascii_whitespace() {
+                break;
+            }
+            input = rest;
+        }
+        input
     }
 
     // TemporalDateTimeString[Zoned] :::
@@ -356,6 +569,43 @@ impl DateTimeParser {
         Ok(Parsed { value, input })
     }
 
+    // Interval :::
+    //   RecurrencePrefix[opt] IntervalPart / IntervalPart
+    //
+    // RecurrencePrefix :::
+    //   R DecimalDigits[opt] /
+    //
+    // IntervalPart :::
+    //   TemporalDateTimeString
+    //   TemporalDurationString
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(super) fn parse_temporal_interval<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, ParsedInterval<'i>>, Error> {
+        let mkslice = parse::slicer(input);
+        let Parsed { value: repetitions, input } =
+            self.parse_recurrence_prefix(input)?;
+        let Parsed { value: start, input } =
+            self.parse_interval_part(input)?;
+        let Some((&b'/', input)) = input.split_first() else {
+            return Err(err!(
+                "expected '/' after first part of interval {:?}, \
+                 but found {:?} instead",
+                escape::Bytes(mkslice(input)),
+                escape::Bytes(input),
+            ));
+        };
+        let Parsed { value: end, input } = self.parse_interval_part(input)?;
+        let value = ParsedInterval {
+            input: escape::Bytes(mkslice(input)),
+            repetitions,
+            start,
+            end,
+        };
+        Ok(Parsed { value, input })
+    }
+
     // TemporalTimeString :::
     //   AnnotatedTime
     //   AnnotatedDateTimeTimeRequired
@@ -554,6 +804,60 @@ impl DateTimeParser {
     // Date :::
     //   DateYear - DateMonth - DateDay
     //   DateYear DateMonth DateDay
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn parse_recurrence_prefix<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, Option<Repetitions>>, Error> {
+        let Some(input) = input.strip_prefix(b"R") else {
+            return Ok(Parsed { value: None, input });
+        };
+        let end = input
+            .iter()
+            .position(|&b| !b.is_ascii_digit())
+            .unwrap_or(input.len());
+        let (digits, input) = input.split_at(end);
+        let Some(input) = input.strip_prefix(b"/") else {
+            return Err(err!(
+                "expected '/' after repeating interval prefix, \
+                 but found {:?} instead",
+                escape::Bytes(input),
+            ));
+        };
+        if digits.is_empty() {
+            return Ok(Parsed { value: Some(Repetitions::Unbounded), input });
+        }
+        let count = parse::i64(digits).with_context(|| {
+            err!(
+                "failed to parse {digits:?} as number of repetitions",
+                digits = escape::Bytes(digits),
+            )
+        })?;
+        // OK because `parse::i64` never returns a negative integer.
+        let count = u64::try_from(count).unwrap();
+        Ok(Parsed { value: Some(Repetitions::Count(count)), input })
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn parse_interval_part<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, ParsedIntervalPart<'i>>, Error> {
+        let is_duration = matches!(
+            input,
+            [b'P' | b'p', ..] | [b'+' | b'-', b'P' | b'p', ..],
+        );
+        if is_duration {
+            let Parsed { value: span, input } =
+                SpanParser::new().parse_temporal_duration(input)?;
+            let value = ParsedIntervalPart::Span(span);
+            return Ok(Parsed { value, input });
+        }
+        let Parsed { value: dt, input } =
+            self.parse_temporal_datetime(input)?;
+        Ok(Parsed { value: ParsedIntervalPart::DateTime(dt), input })
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn parse_date_spec<'i>(
         &self,
@@ -562,6 +866,15 @@ impl DateTimeParser {
         let mkslice = parse::slicer(input);
         let original = escape::Bytes(input);
 
+        // Fast path for the overwhelmingly common `YYYY-MM-DD` format. If
+        // anything about it doesn't fit, we fall back to the general parser
+        // below, which also reports errors.
+        if let Some((date, input)) = parse_date_fixed(input) {
+            let value =
+                ParsedDate { input: escape::Bytes(mkslice(input)), date };
+            return Ok(Parsed { value, input });
+        }
+
         // Parse year component.
         let Parsed { value: year, input } =
             self.parse_year(input).with_context(|| {
@@ -574,9 +887,41 @@ impl DateTimeParser {
             .parse_date_separator(input, extended)
             .context("failed to parse separator after year")?;
 
+        // Parse week date components, if present.
+        if matches!(input.first(), Some(&(b'W' | b'w'))) {
+            let Parsed { value: date, input } = self
+                .parse_week_date(year, &input[1..], extended)
+                .with_context(|| {
+                    err!("failed to parse week date {original:?}")
+                })?;
+            let value =
+                ParsedDate { input: escape::Bytes(mkslice(input)), date };
+            return Ok(Parsed { value, input });
+        }
+
+        // Parse ordinal day component, if present.
+        if let Some(Parsed { value: day, input }) =
+            self.parse_day_of_year(input).with_context(|| {
+                err!("failed to parse day of year in date {original:?}")
+            })?
+        {
+            let date = Date::new_ranged(
+                year,
+                t::Month::N::<1>(),
+                t::Day::N::<1>(),
+            )
+            .and_then(|date| date.with().day_of_year(day).build())
+            .with_context(|| {
+                err!("ordinal date parsed from {original:?} is not valid")
+            })?;
+            let value =
+                ParsedDate { input: escape::Bytes(mkslice(input)), date };
+            return Ok(Parsed { value, input });
+        }
+
         // Parse month component.
         let Parsed { value: month, input } =
-            self.parse_month(input).with_context(|| {
+            self.parse_month(input, extended).with_context(|| {
                 err!("failed to parse month in date {original:?}")
             })?;
 
@@ -587,7 +932,7 @@ impl DateTimeParser {
 
         // Parse day component.
         let Parsed { value: day, input } =
-            self.parse_day(input).with_context(|| {
+            self.parse_day(input, extended).with_context(|| {
                 err!("failed to parse day in date {original:?}")
             })?;
 
@@ -612,6 +957,32 @@ impl DateTimeParser {
         let mkslice = parse::slicer(input);
         let original = escape::Bytes(input);
 
+        // Fast path for the overwhelmingly common `HH:MM:SS` format. If
+        // anything about it doesn't fit, we fall back to the general parser
+        // below, which also reports errors.
+        if let Some((hour, minute, second, input)) = parse_time_fixed(input) {
+            let Parsed { value: nanosecond, input } =
+                parse_temporal_fraction(input).with_context(|| {
+                    err!(
+                        "failed to parse fractional nanoseconds \
+                         in time {original:?}",
+                    )
+                })?;
+            let time = Time::new_ranged(
+                hour,
+                minute,
+                second,
+                nanosecond.unwrap_or(t::SubsecNanosecond::N::<0>()),
+            );
+            let value = ParsedTime {
+                input: escape::Bytes(mkslice(input)),
+                time,
+                extended: true,
+                leap: false,
+            };
+            return Ok(Parsed { value, input });
+        }
+
         // Parse hour component.
         let Parsed { value: hour, input } =
             self.parse_hour(input).with_context(|| {
@@ -633,6 +1004,7 @@ impl DateTimeParser {
                 input: escape::Bytes(mkslice(input)),
                 time,
                 extended,
+                leap: false,
             };
             return Ok(Parsed { value, input });
         }
@@ -655,10 +1027,11 @@ impl DateTimeParser {
                 input: escape::Bytes(mkslice(input)),
                 time,
                 extended,
+                leap: false,
             };
             return Ok(Parsed { value, input });
         }
-        let Parsed { value: second, input } =
+        let Parsed { value: (second, leap), input } =
             self.parse_second(input).with_context(|| {
                 err!("failed to parse second in time {original:?}")
             })?;
@@ -672,16 +1045,17 @@ impl DateTimeParser {
                 )
             })?;
 
-        let time = Time::new_ranged(
-            hour,
-            minute,
-            second,
-            nanosecond.unwrap_or(t::SubsecNanosecond::N::<0>()),
-        );
+        let mut nanosecond =
+            nanosecond.unwrap_or(t::SubsecNanosecond::N::<0>());
+        if leap && self.leap_second == LeapSecond::Clamp {
+            nanosecond = t::SubsecNanosecond::MAX_SELF;
+        }
+        let time = Time::new_ranged(hour, minute, second, nanosecond);
         let value = ParsedTime {
             input: escape::Bytes(mkslice(input)),
             time,
             extended,
+            leap: leap && self.leap_second == LeapSecond::Preserve,
         };
         Ok(Parsed { value, input })
     }
@@ -707,7 +1081,7 @@ impl DateTimeParser {
 
         // Parse month component.
         let Parsed { value: month, mut input } =
-            self.parse_month(input).with_context(|| {
+            self.parse_month(input, false).with_context(|| {
                 err!("failed to parse month in month-day {original:?}")
             })?;
 
@@ -718,7 +1092,7 @@ impl DateTimeParser {
 
         // Parse day component.
         let Parsed { value: day, input } =
-            self.parse_day(input).with_context(|| {
+            self.parse_day(input, false).with_context(|| {
                 err!("failed to parse day in month-day {original:?}")
             })?;
 
@@ -761,7 +1135,7 @@ impl DateTimeParser {
 
         // Parse month component.
         let Parsed { value: month, input } =
-            self.parse_month(input).with_context(|| {
+            self.parse_month(input, false).with_context(|| {
                 err!("failed to parse month in month-day {original:?}")
             })?;
 
@@ -781,6 +1155,13 @@ impl DateTimeParser {
     //   DecimalDigit DecimalDigit DecimalDigit DecimalDigit
     //   TemporalSign DecimalDigit DecimalDigit DecimalDigit DecimalDigit DecimalDigit DecimalDigit
     //
+    // When expanded years are enabled, the sign-prefixed variant may have
+    // more than six digits. This is an ISO 8601 "expanded representation,"
+    // where the number of extra digits is usually agreed upon out of band.
+    // Since we have no such agreement, we just accept any number of digits
+    // beyond six, but only in the extended format (i.e., with a `-` after
+    // the year). The year must still be in Jiff's supported range.
+    //
     // NOTE: I don't really like the fact that in order to write a negative
     // year, you need to use the six digit variant. Like, why not allow
     // `-0001`? I'm not sure why, so for Chesterton's fence reasons, I'm
@@ -806,6 +1187,8 @@ impl DateTimeParser {
                     year = escape::Bytes(year),
                 )
             })?;
+            let Parsed { value: year, input } =
+                self.parse_expanded_year_digits(year, input)?;
             let year =
                 t::Year::try_new("year", year).context("year is not valid")?;
             if year == C(0) && sign < C(0) {
@@ -843,10 +1226,12 @@ impl DateTimeParser {
     fn parse_month<'i>(
         &self,
         input: &'i [u8],
+        extended: bool,
     ) -> Result<Parsed<'i, t::Month>, Error> {
-        let (month, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit month, but found end of input")
-        })?;
+        let (month, input) =
+            self.split_date_component(input, extended).ok_or_else(|| {
+                err!("expected two digit month, but found end of input")
+            })?;
         let month = parse::i64(month).with_context(|| {
             err!(
                 "failed to parse {month:?} as month (a two digit integer)",
@@ -858,6 +1243,101 @@ impl DateTimeParser {
         Ok(Parsed { value: month, input })
     }
 
+    // DateWeek :::
+    //   W WeekOfYear WeekDay
+    //   W WeekOfYear - WeekDay
+    //
+    // WeekOfYear :::
+    //   0 NonZeroDigit
+    //   1 DecimalDigit
+    //   2 DecimalDigit
+    //   3 DecimalDigit
+    //   4 DecimalDigit
+    //   5 0 through 3
+    //
+    // WeekDay ::: one of
+    //   1 2 3 4 5 6 7
+    //
+    // This isn't part of the Temporal grammar. It's an ISO 8601 week date,
+    // e.g., `2024-W15-3` or `2024W153`. The given input should start
+    // just after the `W`, and the year given is interpreted as an ISO week
+    // year. As with other dates, the separator before the weekday must be
+    // present if and only if the date is in the extended format.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn parse_week_date<'i>(
+        &self,
+        year: t::Year,
+        input: &'i [u8],
+        extended: bool,
+    ) -> Result<Parsed<'i, Date>, Error> {
+        let (week, input) = parse::split(input, 2).ok_or_else(|| {
+            err!("expected two digit week, but found end of input")
+        })?;
+        let week = parse::i64(week).with_context(|| {
+            err!(
+                "failed to parse {week:?} as week (a two digit integer)",
+                week = escape::Bytes(week),
+            )
+        })?;
+        if !extended && input.starts_with(b"-") {
+            return Err(err!(
+                "expected no separator after week since none was \
+                 found after the year, but found a '-' separator",
+            ));
+        }
+        let Parsed { input, .. } = self
+            .parse_date_separator(input, extended)
+            .context("failed to parse separator after week")?;
+        let (weekday, input) = parse::split(input, 1).ok_or_else(|| {
+            err!("expected one digit weekday, but found end of input")
+        })?;
+        let weekday = parse::i64(weekday).with_context(|| {
+            err!(
+                "failed to parse {weekday:?} as weekday (a one digit integer)",
+                weekday = escape::Bytes(weekday),
+            )
+        })?;
+        // OK because one or two digits always fit in an `i8`.
+        let (week, weekday) =
+            (i8::try_from(week).unwrap(), i8::try_from(weekday).unwrap());
+        let weekday = Weekday::from_monday_one_offset(weekday)
+            .context("weekday is not valid")?;
+        let date = ISOWeekDate::new(year.get(), week, weekday)
+            .context("week date is not valid")?
+            .date();
+        Ok(Parsed { value: date, input })
+    }
+
+    // DateDayOfYear :::
+    //   DecimalDigit DecimalDigit DecimalDigit
+    //
+    // This isn't part of the Temporal grammar. It's the day component of an
+    // ISO 8601 ordinal date, e.g., `2024-366` or `2024366`. To avoid any
+    // ambiguity with the month component, it is only recognized when exactly
+    // three digits are present. Otherwise, this returns `None` and doesn't
+    // consume any input.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn parse_day_of_year<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Option<Parsed<'i, i16>>, Error> {
+        let digits = input.iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits != 3 {
+            return Ok(None);
+        }
+        let (day, input) = input.split_at(3);
+        let day = parse::i64(day).with_context(|| {
+            err!(
+                "failed to parse {day:?} as day of year \
+                 (a three digit integer)",
+                day = escape::Bytes(day),
+            )
+        })?;
+        // OK because three digits always fit in an `i16`.
+        let day = i16::try_from(day).unwrap();
+        Ok(Some(Parsed { value: day, input }))
+    }
+
     // DateDay :::
     //   0 NonZeroDigit
     //   1 DecimalDigit
@@ -868,10 +1348,12 @@ impl DateTimeParser {
     fn parse_day<'i>(
         &self,
         input: &'i [u8],
+        extended: bool,
     ) -> Result<Parsed<'i, t::Day>, Error> {
-        let (day, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit day, but found end of input")
-        })?;
+        let (day, input) =
+            self.split_date_component(input, extended).ok_or_else(|| {
+                err!("expected two digit day, but found end of input")
+            })?;
         let day = parse::i64(day).with_context(|| {
             err!(
                 "failed to parse {day:?} as day (a two digit integer)",
@@ -951,11 +1433,16 @@ impl DateTimeParser {
     //   3 DecimalDigit
     //   4 DecimalDigit
     //   5 DecimalDigit
+    //
+    // A leap second (`60`) is always returned as `59`, along with a flag
+    // indicating whether a leap second was found. Callers are responsible for
+    // applying the leap second policy, except for rejecting leap seconds,
+    // which is done here.
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn parse_second<'i>(
         &self,
         input: &'i [u8],
-    ) -> Result<Parsed<'i, t::Second>, Error> {
+    ) -> Result<Parsed<'i, (t::Second, bool)>, Error> {
         let (second, input) = parse::split(input, 2).ok_or_else(|| {
             err!("expected two digit second, but found end of input",)
         })?;
@@ -965,14 +1452,18 @@ impl DateTimeParser {
                 second = escape::Bytes(second),
             )
         })?;
-        // NOTE: I believe Temporal allows one to make this configurable. That
-        // is, to reject it. But for now, we just always clamp a leap second.
-        if second == 60 {
+        let leap = second == 60;
+        if leap {
+            if self.leap_second == LeapSecond::Reject {
+                return Err(err!(
+                    "leap second is not allowed (found second value of 60)",
+                ));
+            }
             second = 59;
         }
         let second = t::Second::try_new("second", second)
             .context("second is not valid")?;
-        Ok(Parsed { value: second, input })
+        Ok(Parsed { value: (second, leap), input })
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -982,7 +1473,12 @@ impl DateTimeParser {
     ) -> Result<Parsed<'i, Option<ParsedOffset>>, Error> {
         const P: offset::Parser =
             offset::Parser::new().zulu(true).subminute(true);
-        P.parse_optional(input)
+        const LENIENT: offset::Parser = P.single_digit_hours(true);
+        if self.lenient {
+            LENIENT.parse_optional(input)
+        } else {
+            P.parse_optional(input)
+        }
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -1063,6 +1559,63 @@ impl DateTimeParser {
         Parsed { value: is_separator, input }
     }
 
+    /// Splits off the digits of a month or day component.
+    ///
+    /// This is normally exactly two digits. But in lenient mode, when the
+    /// date is in the extended format, a single digit that isn't followed by
+    /// another digit is accepted too. (Single digits are never accepted in
+    /// the basic format, since they'd be ambiguous with the next component.)
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn split_date_component<'i>(
+        &self,
+        input: &'i [u8],
+        extended: bool,
+    ) -> Option<(&'i [u8], &'i [u8])> {
+        let single = self.lenient
+            && extended
+            && input.first().is_some_and(u8::is_ascii_digit)
+            && !input.get(1).is_some_and(u8::is_ascii_digit);
+        parse::split(input, if single { 1 } else { 2 })
+    }
+
+    /// Parses any digits beyond the first six of a sign-prefixed year.
+    ///
+    /// `year` should be the value of the first six digits. When expanded
+    /// years are disabled, this always returns `year` and consumes nothing.
+    ///
+    /// Extra digits are only consumed when they are followed by a `-`.
+    /// Otherwise, they would be ambiguous with the basic format (no
+    /// separators) of a date, e.g., `+0020240115`.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn parse_expanded_year_digits<'i>(
+        &self,
+        year: i64,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, i64>, Error> {
+        if !self.expanded_years {
+            return Ok(Parsed { value: year, input });
+        }
+        let extra =
+            input.iter().take_while(|byte| byte.is_ascii_digit()).count();
+        if extra == 0 || input.get(extra) != Some(&b'-') {
+            return Ok(Parsed { value: year, input });
+        }
+        let (digits, input) = input.split_at(extra);
+        let mut year = year;
+        for &digit in digits {
+            year = year
+                .checked_mul(10)
+                .and_then(|year| year.checked_add(i64::from(digit - b'0')))
+                .ok_or_else(|| {
+                    err!(
+                        "expanded year with {len} digits is too big",
+                        len = 6 + extra,
+                    )
+                })?;
+        }
+        Ok(Parsed { value: year, input })
+    }
+
     // TemporalSign :::
     //   ASCIISign
     //   <MINUS>
@@ -1095,6 +1648,44 @@ impl DateTimeParser {
     }
 }
 
+/// Parses a date in the fixed width `YYYY-MM-DD` format from the beginning
+/// of `input`.
+///
+/// This returns `None` whenever the input isn't in precisely this format or
+/// if the date is invalid. In that case, the caller should fall back to the
+/// general date parser. When this returns a date, it is always the same date
+/// that the general parser would return.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+fn parse_date_fixed(input: &[u8]) -> Option<(Date, &[u8])> {
+    let century = swar::parse_pair(input)?;
+    let [year, month, day] = swar::parse_triple(input.get(2..)?, b'-')?;
+    let year = i16::from(century) * 100 + i16::from(year);
+    // This does range checking on the month and day for us.
+    let date = Date::new(year, month as i8, day as i8).ok()?;
+    Some((date, &input[10..]))
+}
+
+/// Parses a time in the fixed width `HH:MM:SS` format from the beginning of
+/// `input`.
+///
+/// This returns `None` whenever the input isn't in precisely this format or
+/// if any of the components are out of range. In that case, the caller
+/// should fall back to the general time parser. This includes a leap
+/// second (`60`), which is handled by the general parser according to the
+/// configured leap second policy.
+///
+/// Note that this doesn't parse any fractional component.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+fn parse_time_fixed(
+    input: &[u8],
+) -> Option<(t::Hour, t::Minute, t::Second, &[u8])> {
+    let [hour, minute, second] = swar::parse_triple(input, b':')?;
+    let hour = t::Hour::new(hour)?;
+    let minute = t::Minute::new(minute)?;
+    let second = t::Second::new(second)?;
+    Some((hour, minute, second, &input[8..]))
+}
+
 /// A parser for Temporal spans.
 ///
 /// Note that in Temporal, a "span" is called a "duration."
@@ -1140,6 +1731,15 @@ impl SpanParser {
         let original = escape::Bytes(input);
         let Parsed { value: sign, input } = self.parse_sign(input);
         let Parsed { input, .. } = self.parse_duration_designator(input)?;
+        if is_alternative(input) {
+            let Parsed { value: alt, input } =
+                self.parse_alternative(input)?;
+            let mut span = alt.to_span()?;
+            if sign < C(0) {
+                span = span.negate();
+            }
+            return Ok(Parsed { value: span, input });
+        }
         let Parsed { value: (mut span, parsed_any_date), input } =
             self.parse_date_units(input, Span::new())?;
         let Parsed { value: has_time, mut input } =
@@ -1176,6 +1776,12 @@ impl SpanParser {
     ) -> Result<Parsed<'i, SignedDuration>, Error> {
         let Parsed { value: sign, input } = self.parse_sign(input);
         let Parsed { input, .. } = self.parse_duration_designator(input)?;
+        if is_alternative(input) {
+            let Parsed { value: alt, input } =
+                self.parse_alternative(input)?;
+            let dur = alt.to_duration(sign < C(0))?;
+            return Ok(Parsed { value: dur, input });
+        }
         let Parsed { value: has_time, input } =
             self.parse_time_designator(input);
         if !has_time {
@@ -1190,12 +1796,114 @@ impl SpanParser {
         Ok(Parsed { value: dur, input })
     }
 
+    /// Parses the ISO 8601 alternative format of a duration, i.e.,
+    /// `YYYY-MM-DD[THH:MM:SS[.fraction]]`, that follows the `P` designator.
+    ///
+    /// The year must have at least 4 digits and all other components must
+    /// have at least 2 digits. We are lenient and allow values to exceed
+    /// their "carry-over points" (e.g., 25 hours), since a `Span` can
+    /// represent them.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn parse_alternative<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, AlternativeDuration>, Error> {
+        let Parsed { value: years, input } =
+            self.parse_alternative_value(input, Unit::Year, 4)?;
+        let input = self.parse_alternative_separator(input, b'-')?;
+        let Parsed { value: months, input } =
+            self.parse_alternative_value(input, Unit::Month, 2)?;
+        let input = self.parse_alternative_separator(input, b'-')?;
+        let Parsed { value: days, input } =
+            self.parse_alternative_value(input, Unit::Day, 2)?;
+        let mut alt = AlternativeDuration {
+            years,
+            months,
+            days,
+            hours: t::NoUnits::N::<0>(),
+            minutes: t::NoUnits::N::<0>(),
+            seconds: t::NoUnits::N::<0>(),
+            fraction: t::SubsecNanosecond::N::<0>(),
+        };
+
+        let Parsed { value: has_time, input } =
+            self.parse_time_designator(input);
+        if !has_time {
+            return Ok(Parsed { value: alt, input });
+        }
+        let Parsed { value: hours, input } =
+            self.parse_alternative_value(input, Unit::Hour, 2)?;
+        let input = self.parse_alternative_separator(input, b':')?;
+        let Parsed { value: minutes, input } =
+            self.parse_alternative_value(input, Unit::Minute, 2)?;
+        let input = self.parse_alternative_separator(input, b':')?;
+        let Parsed { value: seconds, input } =
+            self.parse_alternative_value(input, Unit::Second, 2)?;
+        let Parsed { value: fraction, input } =
+            parse_temporal_fraction(input)?;
+        alt.hours = hours;
+        alt.minutes = minutes;
+        alt.seconds = seconds;
+        alt.fraction = fraction.unwrap_or(t::SubsecNanosecond::N::<0>());
+        Ok(Parsed { value: alt, input })
+    }
+
+    /// Parses a single component of the ISO 8601 alternative duration
+    /// format, which must have at least `min_digits` digits.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn parse_alternative_value<'i>(
+        &self,
+        input: &'i [u8],
+        unit: Unit,
+        min_digits: usize,
+    ) -> Result<Parsed<'i, t::NoUnits>, Error> {
+        let len = input.len();
+        let Parsed { value, input } = self.parse_unit_value(input)?;
+        let digits = len - input.len();
+        match value {
+            Some(value) if digits >= min_digits => Ok(Parsed { value, input }),
+            _ => Err(err!(
+                "expected at least {min_digits} digits for {unit} \
+                 component of ISO 8601 alternative duration format, \
+                 but found {digits}",
+                unit = unit.plural(),
+            )),
+        }
+    }
+
+    /// Parses the given separator from the ISO 8601 alternative duration
+    /// format.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn parse_alternative_separator<'i>(
+        &self,
+        input: &'i [u8],
+        separator: u8,
+    ) -> Result<&'i [u8], Error> {
+        match input.first() {
+            Some(&byte) if byte == separator => Ok(&input[1..]),
+            Some(&byte) => Err(err!(
+                "expected {expected:?} separator in ISO 8601 alternative \
+                 duration format, but found {found:?} instead",
+                expected = escape::Byte(separator),
+                found = escape::Byte(byte),
+            )),
+            None => Err(err!(
+                "expected {expected:?} separator in ISO 8601 alternative \
+                 duration format, but found end of input",
+                expected = escape::Byte(separator),
+            )),
+        }
+    }
+
     /// Parses consecutive date units from an ISO 8601 duration string into the
     /// span given.
     ///
     /// If 1 or more units were found, then `true` is also returned. Otherwise,
     /// `false` indicates that no units were parsed. (Which the caller may want
     /// to treat as an error.)
+    ///
+    /// As with time units, the last date unit may be fractional. In which
+    /// case, no other units (including time units) may follow it.
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn parse_date_units<'i>(
         &self,
@@ -1209,6 +1917,10 @@ impl SpanParser {
             input = parsed.input;
             let Some(value) = parsed.value else { break };
 
+            let parsed = parse_temporal_fraction(input)?;
+            input = parsed.input;
+            let fraction = parsed.value;
+
             let parsed = self.parse_unit_date_designator(input)?;
             input = parsed.input;
             let unit = parsed.value;
@@ -1233,6 +1945,22 @@ impl SpanParser {
                 )
             })?;
             parsed_any = true;
+
+            if let Some(fraction) = fraction {
+                span = fractional_date_to_span(unit, fraction, span)?;
+                // Once we see a fraction, we are done. Since time units
+                // are always smaller than date units, this also means we
+                // can't have any time units.
+                if matches!(input.first(), Some(&b'T') | Some(&b't')) {
+                    return Err(err!(
+                        "found a time designator (T or t) after fractional \
+                         {unit} unit, but a fraction may only occur on \
+                         the last unit of an ISO 8601 duration",
+                        unit = unit.singular(),
+                    ));
+                }
+                break;
+            }
         }
         Ok(Parsed { value: (span, parsed_any), input })
     }
@@ -1569,6 +2297,204 @@ impl SpanParser {
     }
 }
 
+/// Applies the fractional part of a date unit to the span given, and returns
+/// the new span.
+///
+/// The integral value must already be set on the span for `unit`. The
+/// fraction is then converted into smaller units. That is, fractional years
+/// are converted to months, fractional weeks are converted to days (and then
+/// time units) and fractional days are converted to time units. Days are
+/// always assumed to be 24 hours long for this conversion.
+///
+/// Since months don't have a fixed length, fractional months are not
+/// supported. And for the same reason, fractional years must correspond to
+/// a whole number of months.
+fn fractional_date_to_span(
+    unit: Unit,
+    fraction: t::SubsecNanosecond,
+    mut span: Span,
+) -> Result<Span, Error> {
+    let fraction = i64::from(fraction.get());
+    if fraction == 0 {
+        return Ok(span);
+    }
+    let nanos_per_unit = t::NANOS_PER_SECOND.value();
+    let nanos = match unit {
+        Unit::Year => {
+            let months = fraction * t::MONTHS_PER_YEAR.value();
+            if months % nanos_per_unit != 0 {
+                return Err(err!(
+                    "fractional years must correspond to a whole number \
+                     of months, since months cannot be fractional",
+                ));
+            }
+            let months = t::NoUnits::new_unchecked(months / nanos_per_unit);
+            return span.try_units_ranged(Unit::Month, months).with_context(
+                || {
+                    err!(
+                        "failed to set value {months:?} as month unit \
+                         on span from fractional years",
+                    )
+                },
+            );
+        }
+        Unit::Month => {
+            return Err(err!(
+                "fractional months are not allowed since months \
+                 don't have a fixed length",
+            ));
+        }
+        Unit::Week => {
+            let nanos = fraction * t::SECONDS_PER_CIVIL_WEEK.value();
+            let days = nanos / t::NANOS_PER_CIVIL_DAY.value();
+            // OK because a fraction of a week is always less than 7 days.
+            span = span
+                .try_units_ranged(Unit::Day, t::NoUnits::new_unchecked(days))
+                .unwrap();
+            nanos % t::NANOS_PER_CIVIL_DAY.value()
+        }
+        Unit::Day => fraction * t::SECONDS_PER_CIVIL_DAY.value(),
+        // Guaranteed not to be here since `parse_unit_date_designator`
+        // always returns years, months, weeks or days.
+        _ => unreachable!(),
+    };
+    // At this point, we have less than one day of nanoseconds. So the hours,
+    // minutes and seconds are all guaranteed to be in range.
+    let hours = t::NoUnits::new_unchecked(nanos / t::NANOS_PER_HOUR.value());
+    let minutes =
+        t::NoUnits::new_unchecked((nanos / t::NANOS_PER_MINUTE.value()) % 60);
+    let seconds = t::NoUnits::new_unchecked((nanos / nanos_per_unit) % 60);
+    // OK because the remainder is always less than one second.
+    let subsec = t::SubsecNanosecond::new_unchecked(
+        i32::try_from(nanos % nanos_per_unit).unwrap(),
+    );
+    // OK because the hours and minutes are in range, as explained above.
+    span = span
+        .try_units_ranged(Unit::Hour, hours)
+        .and_then(|span| span.try_units_ranged(Unit::Minute, minutes))
+        .unwrap();
+    fractional_time_to_span(Unit::Second, seconds, subsec, span)
+}
+
+/// The components of a duration parsed from the ISO 8601 alternative format.
+///
+/// All values are non-negative. The sign is handled separately.
+#[derive(Debug)]
+struct AlternativeDuration {
+    years: t::NoUnits,
+    months: t::NoUnits,
+    days: t::NoUnits,
+    hours: t::NoUnits,
+    minutes: t::NoUnits,
+    seconds: t::NoUnits,
+    fraction: t::SubsecNanosecond,
+}
+
+impl AlternativeDuration {
+    /// Converts these components into a (non-negative) span.
+    fn to_span(&self) -> Result<Span, Error> {
+        let mut span = Span::new();
+        for (unit, value) in [
+            (Unit::Year, self.years),
+            (Unit::Month, self.months),
+            (Unit::Day, self.days),
+        ] {
+            span = span.try_units_ranged(unit, value).with_context(|| {
+                err!(
+                    "failed to set value {value:?} as {unit} unit on span",
+                    unit = unit.singular(),
+                )
+            })?;
+        }
+        let result = span
+            .try_units_ranged(Unit::Hour, self.hours)
+            .and_then(|span| span.try_units_ranged(Unit::Minute, self.minutes))
+            .and_then(|span| {
+                fractional_time_to_span(
+                    Unit::Second,
+                    self.seconds,
+                    self.fraction,
+                    span,
+                )
+            });
+        match result {
+            Ok(span) => Ok(span),
+            // As with `parse_time_units`, we need to be prepared for time
+            // units that are bigger than what a `Span` supports. In which
+            // case, we combine them into a number of seconds, which are in
+            // turn balanced into smaller units as needed. This results in
+            // an unbalanced span, but the total elapsed time is preserved.
+            Err(_) => {
+                let secs = self.time_seconds();
+                let secs = i64::try_from(secs).map_err(|_| {
+                    err!(
+                        "time components of ISO 8601 alternative duration \
+                         format overflowed when converted to seconds",
+                    )
+                })?;
+                // OK because t::NoUnits permits all possible i64 values.
+                let secs = t::NoUnits::new(secs).unwrap();
+                fractional_time_to_span(
+                    Unit::Second,
+                    secs,
+                    self.fraction,
+                    span,
+                )
+            }
+        }
+    }
+
+    /// Converts these components into a signed duration, negating it when
+    /// `negative` is true.
+    ///
+    /// This returns an error if any of the date components are non-zero or
+    /// if the duration overflows the limits of a `SignedDuration`.
+    fn to_duration(&self, negative: bool) -> Result<SignedDuration, Error> {
+        if self.years != C(0) || self.months != C(0) || self.days != C(0) {
+            return Err(err!(
+                "parsing ISO 8601 duration into SignedDuration requires \
+                 that the duration contain no components of days or greater",
+            ));
+        }
+        let mut secs = self.time_seconds();
+        let mut nanos = self.fraction.get();
+        if negative {
+            secs = -secs;
+            nanos = -nanos;
+        }
+        let secs = i64::try_from(secs).map_err(|_| {
+            err!(
+                "ISO 8601 alternative duration format overflowed \
+                 the limits of a `SignedDuration`",
+            )
+        })?;
+        // OK because `secs` and `nanos` have the same sign and
+        // `nanos` is less than one second in magnitude.
+        Ok(SignedDuration::new(secs, nanos))
+    }
+
+    /// Returns the hours, minutes and seconds combined into a total number of
+    /// seconds. This cannot overflow since each component is an `i64`.
+    fn time_seconds(&self) -> i128 {
+        i128::from(self.hours.get()) * 3_600
+            + i128::from(self.minutes.get()) * 60
+            + i128::from(self.seconds.get())
+    }
+}
+
+/// Returns true if the given input (immediately following the `P` duration
+/// designator) looks like the ISO 8601 alternative duration format. That is,
+/// `YYYY-MM-DD` instead of something like `1Y2M3D`.
+///
+/// In the designator format, a unit value is always followed by a unit
+/// designator letter. So if a `-` follows the first run of digits instead,
+/// then we assume the alternative format.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+fn is_alternative(input: &[u8]) -> bool {
+    let digits = input.iter().take_while(|b| b.is_ascii_digit()).count();
+    digits > 0 && input.get(digits) == Some(&b'-')
+}
+
 #[cfg(feature = "alloc")]
 #[cfg(test)]
 mod tests {
@@ -1881,6 +2807,94 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn ok_temporal_duration_fractional_date() {
+        let p =
+            |input| SpanParser::new().parse_temporal_duration(input).unwrap();
+
+        insta::assert_debug_snapshot!(p(b"P0.5D"), @r###"
+        Parsed {
+            value: 12h,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(p(b"-P1.5D"), @r###"
+        Parsed {
+            value: 1d 12h ago,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(p(b"P0.123456789D"), @r###"
+        Parsed {
+            value: 2h 57m 46s 666ms 569µs 600ns,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(p(b"P1.5W"), @r###"
+        Parsed {
+            value: 1w 3d 12h,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(p(b"P1Y0.999999999W"), @r###"
+        Parsed {
+            value: 1y 6d 23h 59m 59s 999ms 395µs 200ns,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(p(b"P1M2.25D"), @r###"
+        Parsed {
+            value: 1mo 2d 6h,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(p(b"P1.25Y"), @r###"
+        Parsed {
+            value: 1y 3mo,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(p(b"P1,5Y"), @r###"
+        Parsed {
+            value: 1y 6mo,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(p(b"P1Y2.0M"), @r###"
+        Parsed {
+            value: 1y 2mo,
+            input: "",
+        }
+        "###);
+        // Units after a fractional unit are not parsed.
+        insta::assert_debug_snapshot!(p(b"P1.5W2D"), @r###"
+        Parsed {
+            value: 1w 3d 12h,
+            input: "2D",
+        }
+        "###);
+    }
+
+    #[test]
+    fn err_temporal_duration_fractional_date() {
+        let p = |input| {
+            SpanParser::new().parse_temporal_duration(input).unwrap_err()
+        };
+
+        insta::assert_snapshot!(
+            p(b"P1.1Y"),
+            @"failed to parse ISO 8601 duration string into `Span`: fractional years must correspond to a whole number of months, since months cannot be fractional",
+        );
+        insta::assert_snapshot!(
+            p(b"P1.5M"),
+            @"failed to parse ISO 8601 duration string into `Span`: fractional months are not allowed since months don't have a fixed length",
+        );
+        insta::assert_snapshot!(
+            p(b"P1.5DT1H"),
+            @"failed to parse ISO 8601 duration string into `Span`: found a time designator (T or t) after fractional day unit, but a fraction may only occur on the last unit of an ISO 8601 duration",
+        );
+    }
+
     #[test]
     fn ok_temporal_duration_unbalanced() {
         let p =
@@ -1909,6 +2923,103 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn ok_temporal_duration_alternative() {
+        let p =
+            |input| SpanParser::new().parse_temporal_duration(input).unwrap();
+
+        insta::assert_debug_snapshot!(
+            p(b"P0001-02-03T04:05:06.789"), @r###"
+        Parsed {
+            value: 1y 2mo 3d 4h 5m 6s 789ms,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(
+            p(b"-P0001-02-03"), @r###"
+        Parsed {
+            value: 1y 2mo 3d ago,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(
+            p(b"p0000-00-00t00:00:00,5"), @r###"
+        Parsed {
+            value: 500ms,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(
+            p(b"P00001-13-40T25:61:61"), @r###"
+        Parsed {
+            value: 1y 13mo 40d 25h 61m 61s,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(
+            p(b"P0000-00-00T175307616:10518456960:1774446656760"), @r###"
+        Parsed {
+            value: 175307616h 10518456960m 631107417600s 631107417600000ms 512231821560000000µs,
+            input: "",
+        }
+        "###);
+        insta::assert_debug_snapshot!(
+            p(b"P0000-00-00T175307617:00:00"), @r###"
+        Parsed {
+            value: 631107417600s 3600000ms,
+            input: "",
+        }
+        "###);
+
+        let p = |input| {
+            SpanParser::new().parse_signed_duration(input).unwrap().value
+        };
+        insta::assert_snapshot!(
+            p(b"P0000-00-00T24:02:05.5"),
+            @"PT24H2M5.5S",
+        );
+        insta::assert_snapshot!(
+            p(b"-P0000-00-00T2562047788015215:30:08.999999999"),
+            @"-PT2562047788015215H30M8.999999999S",
+        );
+    }
+
+    #[test]
+    fn err_temporal_duration_alternative() {
+        let p = |input| {
+            SpanParser::new().parse_temporal_duration(input).unwrap_err()
+        };
+
+        insta::assert_snapshot!(
+            p(b"P01-02-03"),
+            @"failed to parse ISO 8601 duration string into `Span`: expected at least 4 digits for years component of ISO 8601 alternative duration format, but found 2",
+        );
+        insta::assert_snapshot!(
+            p(b"P0001-2-03"),
+            @"failed to parse ISO 8601 duration string into `Span`: expected at least 2 digits for months component of ISO 8601 alternative duration format, but found 1",
+        );
+        insta::assert_snapshot!(
+            p(b"P0001-02-03T"),
+            @"failed to parse ISO 8601 duration string into `Span`: expected at least 2 digits for hours component of ISO 8601 alternative duration format, but found 0",
+        );
+        insta::assert_snapshot!(
+            p(b"P0001-02-03T04:05"),
+            @r#"failed to parse ISO 8601 duration string into `Span`: expected ":" separator in ISO 8601 alternative duration format, but found end of input"#,
+        );
+        insta::assert_snapshot!(
+            p(b"P0001-02-03T04-05-06"),
+            @r#"failed to parse ISO 8601 duration string into `Span`: expected ":" separator in ISO 8601 alternative duration format, but found "-" instead"#,
+        );
+
+        let p = |input| {
+            SpanParser::new().parse_signed_duration(input).unwrap_err()
+        };
+        insta::assert_snapshot!(
+            p(b"P0000-00-01T00:00:00"),
+            @"failed to parse ISO 8601 duration string into `SignedDuration`: parsing ISO 8601 duration into SignedDuration requires that the duration contain no components of days or greater",
+        );
+    }
+
     #[test]
     fn ok_temporal_datetime_basic() {
         let p = |input| {
@@ -1969,6 +3080,7 @@ mod tests {
                         input: "01:02:03",
                         time: 01:02:03,
                         extended: true,
+                        leap: false,
                     },
                 ),
                 offset: None,
@@ -1993,6 +3105,7 @@ mod tests {
                         input: "01:02:03",
                         time: 01:02:03,
                         extended: true,
+                        leap: false,
                     },
                 ),
                 offset: Some(
@@ -2023,6 +3136,7 @@ mod tests {
                         input: "01:02:03",
                         time: 01:02:03,
                         extended: true,
+                        leap: false,
                     },
                 ),
                 offset: Some(
@@ -2058,6 +3172,7 @@ mod tests {
                         input: "01:02:03",
                         time: 01:02:03,
                         extended: true,
+                        leap: false,
                     },
                 ),
                 offset: Some(
@@ -2091,6 +3206,7 @@ mod tests {
                         input: "01:02:03",
                         time: 01:02:03,
                         extended: true,
+                        leap: false,
                     },
                 ),
                 offset: Some(
@@ -2134,6 +3250,7 @@ mod tests {
                         input: "01",
                         time: 01:00:00,
                         extended: false,
+                        leap: false,
                     },
                 ),
                 offset: None,
@@ -2158,6 +3275,7 @@ mod tests {
                         input: "0102",
                         time: 01:02:00,
                         extended: false,
+                        leap: false,
                     },
                 ),
                 offset: None,
@@ -2182,6 +3300,7 @@ mod tests {
                         input: "01:02",
                         time: 01:02:00,
                         extended: true,
+                        leap: false,
                     },
                 ),
                 offset: None,
@@ -2214,6 +3333,7 @@ mod tests {
                         input: "01:02:03",
                         time: 01:02:03,
                         extended: true,
+                        leap: false,
                     },
                 ),
                 offset: None,
@@ -2238,6 +3358,7 @@ mod tests {
                         input: "01:02:03",
                         time: 01:02:03,
                         extended: true,
+                        leap: false,
                     },
                 ),
                 offset: None,
@@ -2262,6 +3383,7 @@ mod tests {
                 input: "01:02:03",
                 time: 01:02:03,
                 extended: true,
+                leap: false,
             },
             input: "",
         }
@@ -2272,6 +3394,7 @@ mod tests {
                 input: "130113",
                 time: 13:01:13,
                 extended: false,
+                leap: false,
             },
             input: "",
         }
@@ -2282,6 +3405,7 @@ mod tests {
                 input: "01:02:03",
                 time: 01:02:03,
                 extended: true,
+                leap: false,
             },
             input: "",
         }
@@ -2292,6 +3416,7 @@ mod tests {
                 input: "010203",
                 time: 01:02:03,
                 extended: false,
+                leap: false,
             },
             input: "",
         }
@@ -2309,6 +3434,7 @@ mod tests {
                 input: "01:02:03",
                 time: 01:02:03,
                 extended: true,
+                leap: false,
             },
             input: "",
         }
@@ -2319,6 +3445,7 @@ mod tests {
                 input: "01:02:03.123",
                 time: 01:02:03.123,
                 extended: true,
+                leap: false,
             },
             input: "",
         }
@@ -2329,6 +3456,7 @@ mod tests {
                 input: "01",
                 time: 01:00:00,
                 extended: false,
+                leap: false,
             },
             input: "",
         }
@@ -2339,6 +3467,7 @@ mod tests {
                 input: "0102",
                 time: 01:02:00,
                 extended: false,
+                leap: false,
             },
             input: "",
         }
@@ -2349,6 +3478,7 @@ mod tests {
                 input: "010203",
                 time: 01:02:03,
                 extended: false,
+                leap: false,
             },
             input: "",
         }
@@ -2359,6 +3489,7 @@ mod tests {
                 input: "010203",
                 time: 01:02:03,
                 extended: false,
+                leap: false,
             },
             input: "",
         }
@@ -2370,6 +3501,7 @@ mod tests {
                 input: "010203",
                 time: 01:02:03,
                 extended: false,
+                leap: false,
             },
             input: "",
         }
@@ -2381,6 +3513,7 @@ mod tests {
                 input: "010203",
                 time: 01:02:03,
                 extended: false,
+                leap: false,
             },
             input: "",
         }
@@ -2504,6 +3637,57 @@ mod tests {
         "###);
     }
 
+    // Checks that the fixed width fast path for dates agrees with the general
+    // parser, which is used for the basic format.
+    #[test]
+    fn date_fixed_matches_general() {
+        let p = DateTimeParser::new();
+        for year in [0, 1, 99, 100, 1900, 1969, 2000, 2024, 2100, 9999] {
+            for month in 0..=13 {
+                for day in 0..=32 {
+                    let extended =
+                        alloc::format!("{year:04}-{month:02}-{day:02}");
+                    let basic = alloc::format!("{year:04}{month:02}{day:02}");
+                    let got = p
+                        .parse_date_spec(extended.as_bytes())
+                        .map(|parsed| parsed.value.date);
+                    let want = p
+                        .parse_date_spec(basic.as_bytes())
+                        .map(|parsed| parsed.value.date);
+                    assert_eq!(got.ok(), want.ok(), "{extended}");
+                }
+            }
+        }
+    }
+
+    // Checks that the fixed width fast path for times agrees with the general
+    // parser, which is used for the basic format.
+    #[test]
+    fn time_fixed_matches_general() {
+        let p = DateTimeParser::new();
+        for hour in 0..=25 {
+            for minute in 0..=61 {
+                for second in 0..=61 {
+                    for fraction in ["", ".5", ",123456789"] {
+                        let extended = alloc::format!(
+                            "{hour:02}:{minute:02}:{second:02}{fraction}"
+                        );
+                        let basic = alloc::format!(
+                            "{hour:02}{minute:02}{second:02}{fraction}"
+                        );
+                        let got = p
+                            .parse_time_spec(extended.as_bytes())
+                            .map(|parsed| parsed.value.time);
+                        let want = p
+                            .parse_time_spec(basic.as_bytes())
+                            .map(|parsed| parsed.value.time);
+                        assert_eq!(got.ok(), want.ok(), "{extended}");
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn err_date_empty() {
         insta::assert_snapshot!(
@@ -2623,6 +3807,7 @@ mod tests {
                 input: "01:02:03",
                 time: 01:02:03,
                 extended: true,
+                leap: false,
             },
             input: "",
         }
@@ -2633,6 +3818,7 @@ mod tests {
                 input: "010203",
                 time: 01:02:03,
                 extended: false,
+                leap: false,
             },
             input: "",
         }
@@ -2649,6 +3835,7 @@ mod tests {
                 input: "01:02:03.123456789",
                 time: 01:02:03.123456789,
                 extended: true,
+                leap: false,
             },
             input: "",
         }
@@ -2659,6 +3846,7 @@ mod tests {
                 input: "010203.123456789",
                 time: 01:02:03.123456789,
                 extended: false,
+                leap: false,
             },
             input: "",
         }
@@ -2670,6 +3858,7 @@ mod tests {
                 input: "01:02:03.9",
                 time: 01:02:03.9,
                 extended: true,
+                leap: false,
             },
             input: "",
         }
@@ -2686,6 +3875,7 @@ mod tests {
                 input: "01:02",
                 time: 01:02:00,
                 extended: true,
+                leap: false,
             },
             input: ".123456789",
         }
@@ -2702,6 +3892,7 @@ mod tests {
                 input: "01:02:60",
                 time: 01:02:59,
                 extended: true,
+                leap: false,
             },
             input: "",
         }
@@ -2718,6 +3909,7 @@ mod tests {
                 input: "01:02",
                 time: 01:02:00,
                 extended: true,
+                leap: false,
             },
             input: "03",
         }
@@ -2728,6 +3920,7 @@ mod tests {
                 input: "0102",
                 time: 01:02:00,
                 extended: false,
+                leap: false,
             },
             input: ":03",
         }
@@ -1253,6 +1253,51 @@ impl PiecesOffset {
             PiecesOffset::Numeric(ref noffset) => noffset.offset(),
         }
     }
+
+    /// Returns true when this offset indicates that the offset to local time
+    /// is unknown.
+    ///
+    /// This is the case for both `Z` and `-00:00`. In both cases, the
+    /// datetime is known in UTC, but the offset to the local time of wherever
+    /// the datetime originated is not. This is in contrast to `+00:00`,
+    /// which indicates that UTC is the preferred reference point for the
+    /// datetime. See [RFC 3339 section 4.3] and [RFC 9557].
+    ///
+    /// Note that [`PiecesOffset::to_numeric_offset`] returns a zero offset in
+    /// all three cases. This routine lets callers preserve the distinction,
+    /// e.g., when reserializing a datetime.
+    ///
+    /// [RFC 3339 section 4.3]: https://www.rfc-editor.org/rfc/rfc3339#section-4.3
+    /// [RFC 9557]: https://www.rfc-editor.org/rfc/rfc9557
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::temporal::Pieces;
+    ///
+    /// let pieces = Pieces::parse("1970-01-01T00:00:00Z")?;
+    /// assert!(pieces.offset().unwrap().is_unknown());
+    ///
+    /// let pieces = Pieces::parse("1970-01-01T00:00:00-00:00")?;
+    /// assert!(pieces.offset().unwrap().is_unknown());
+    ///
+    /// let pieces = Pieces::parse("1970-01-01T00:00:00+00:00")?;
+    /// assert!(!pieces.offset().unwrap().is_unknown());
+    ///
+    /// let pieces = Pieces::parse("1970-01-01T00:00:00-05:00")?;
+    /// assert!(!pieces.offset().unwrap().is_unknown());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn is_unknown(&self) -> bool {
+        match *self {
+            PiecesOffset::Zulu => true,
+            PiecesOffset::Numeric(ref noffset) => {
+                noffset.is_negative() && noffset.offset().is_zero()
+            }
+        }
+    }
 }
 
 impl From<Offset> for PiecesOffset {
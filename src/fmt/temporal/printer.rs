@@ -1,8 +1,11 @@
 use crate::{
-    civil::{Date, DateTime, Time},
+    civil::{Date, DateTime, DateTimeRound, ISOWeekDate, Time, TimeRound},
     error::{err, Error},
     fmt::{
-        temporal::{Pieces, PiecesOffset, TimeZoneAnnotationKind},
+        temporal::{
+            interval::IntervalKind, swar, Interval, Pieces, PiecesOffset,
+            Repetitions, TimeZoneAnnotationKind,
+        },
         util::{DecimalFormatter, FractionalFormatter},
         Write, WriteExt,
     },
@@ -12,15 +15,26 @@ use crate::{
         rangeint::RFrom,
         t::{self, C},
     },
-    SignedDuration, Timestamp, Zoned,
+    RoundMode, SignedDuration, Timestamp, Unit, Zoned,
 };
 
 #[derive(Clone, Debug)]
 pub(super) struct DateTimePrinter {
     lowercase: bool,
     separator: u8,
+    zulu: bool,
     rfc9557: bool,
+    zoned_offset: bool,
     precision: Option<u8>,
+    min_precision: u8,
+    round_mode: RoundMode,
+    omit_zero_fraction: bool,
+    subminute_offset: SubminuteOffset,
+    annotate_rounded_offset: bool,
+    expanded_years: bool,
+    basic: bool,
+    decimal_comma: bool,
+    date_kind: DateKind,
 }
 
 impl DateTimePrinter {
@@ -28,8 +42,19 @@ impl DateTimePrinter {
         DateTimePrinter {
             lowercase: false,
             separator: b'T',
+            zulu: true,
             rfc9557: true,
+            zoned_offset: true,
             precision: None,
+            min_precision: 0,
+            round_mode: RoundMode::Trunc,
+            omit_zero_fraction: false,
+            subminute_offset: SubminuteOffset::Round,
+            annotate_rounded_offset: false,
+            expanded_years: false,
+            basic: false,
+            decimal_comma: false,
+            date_kind: DateKind::Calendar,
         }
     }
 
@@ -42,6 +67,18 @@ impl DateTimePrinter {
         DateTimePrinter { separator: ascii_char, ..self }
     }
 
+    pub(super) const fn zulu(self, yes: bool) -> DateTimePrinter {
+        DateTimePrinter { zulu: yes, ..self }
+    }
+
+    pub(super) const fn rfc9557(self, yes: bool) -> DateTimePrinter {
+        DateTimePrinter { rfc9557: yes, ..self }
+    }
+
+    pub(super) const fn zoned_offset(self, yes: bool) -> DateTimePrinter {
+        DateTimePrinter { zoned_offset: yes, ..self }
+    }
+
     pub(super) const fn precision(
         self,
         precision: Option<u8>,
@@ -49,21 +86,90 @@ impl DateTimePrinter {
         DateTimePrinter { precision, ..self }
     }
 
+    pub(super) const fn min_precision(self, digits: u8) -> DateTimePrinter {
+        DateTimePrinter { min_precision: digits, ..self }
+    }
+
+    pub(super) const fn round_mode(self, mode: RoundMode) -> DateTimePrinter {
+        DateTimePrinter { round_mode: mode, ..self }
+    }
+
+    pub(super) const fn omit_zero_fraction(
+        self,
+        yes: bool,
+    ) -> DateTimePrinter {
+        DateTimePrinter { omit_zero_fraction: yes, ..self }
+    }
+
+    pub(super) const fn subminute_offset(
+        self,
+        kind: SubminuteOffset,
+    ) -> DateTimePrinter {
+        DateTimePrinter { subminute_offset: kind, ..self }
+    }
+
+    pub(super) const fn annotate_rounded_offset(
+        self,
+        yes: bool,
+    ) -> DateTimePrinter {
+        DateTimePrinter { annotate_rounded_offset: yes, ..self }
+    }
+
+    pub(super) const fn expanded_years(self, yes: bool) -> DateTimePrinter {
+        DateTimePrinter { expanded_years: yes, ..self }
+    }
+
+    pub(super) const fn basic(self, yes: bool) -> DateTimePrinter {
+        DateTimePrinter { basic: yes, ..self }
+    }
+
+    pub(super) const fn decimal_comma(self, yes: bool) -> DateTimePrinter {
+        DateTimePrinter { decimal_comma: yes, ..self }
+    }
+
+    pub(super) const fn ordinal(self, yes: bool) -> DateTimePrinter {
+        let date_kind = match (yes, self.date_kind) {
+            (true, _) => DateKind::Ordinal,
+            (false, DateKind::Ordinal) => DateKind::Calendar,
+            (false, kind) => kind,
+        };
+        DateTimePrinter { date_kind, ..self }
+    }
+
+    pub(super) const fn week_date(self, yes: bool) -> DateTimePrinter {
+        let date_kind = match (yes, self.date_kind) {
+            (true, _) => DateKind::Week,
+            (false, DateKind::Week) => DateKind::Calendar,
+            (false, kind) => kind,
+        };
+        DateTimePrinter { date_kind, ..self }
+    }
+
     pub(super) fn print_zoned<W: Write>(
         &self,
         zdt: &Zoned,
         mut wtr: W,
     ) -> Result<(), Error> {
-        let timestamp = zdt.timestamp();
+        let timestamp = self.round_timestamp(zdt.timestamp());
         let tz = zdt.time_zone();
         let offset = tz.to_offset(timestamp);
         let dt = offset.to_datetime(timestamp);
         self.print_datetime(&dt, &mut wtr)?;
+        if !self.zoned_offset {
+            // Without an offset, a time zone annotation would be a lie
+            // about what the civil datetime was resolved with. So when
+            // the offset is omitted, we only ever print the civil datetime.
+            return Ok(());
+        }
         if tz.is_unknown() {
-            wtr.write_str("Z[Etc/Unknown]")?;
+            self.print_zulu(&mut wtr)?;
+            if self.rfc9557 {
+                wtr.write_str("[Etc/Unknown]")?;
+            }
         } else {
-            self.print_offset_rounded(&offset, &mut wtr)?;
+            let rounded = self.print_offset(&offset, &mut wtr)?;
             self.print_time_zone_annotation(&tz, &offset, &mut wtr)?;
+            self.print_rounded_offset_annotation(rounded, &mut wtr)?;
         }
         Ok(())
     }
@@ -74,31 +180,106 @@ impl DateTimePrinter {
         offset: Option<Offset>,
         mut wtr: W,
     ) -> Result<(), Error> {
+        let timestamp = &self.round_timestamp(*timestamp);
         let Some(offset) = offset else {
             let dt = TimeZone::UTC.to_datetime(*timestamp);
             self.print_datetime(&dt, &mut wtr)?;
-            self.print_zulu(&mut wtr)?;
+            if self.zulu {
+                self.print_zulu(&mut wtr)?;
+            } else {
+                self.print_offset(&Offset::UTC, &mut wtr)?;
+            }
             return Ok(());
         };
         let dt = offset.to_datetime(*timestamp);
         self.print_datetime(&dt, &mut wtr)?;
-        self.print_offset_rounded(&offset, &mut wtr)?;
+        let rounded = self.print_offset(&offset, &mut wtr)?;
+        self.print_rounded_offset_annotation(rounded, &mut wtr)?;
+        Ok(())
+    }
+
+    pub(super) fn print_interval<W: Write>(
+        &self,
+        interval: &Interval,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        static FMT_INT: DecimalFormatter = DecimalFormatter::new();
+
+        match interval.repetitions() {
+            None => {}
+            Some(Repetitions::Unbounded) => {
+                wtr.write_str("R/")?;
+            }
+            Some(Repetitions::Count(count)) => {
+                wtr.write_str("R")?;
+                // OK because no one is going to repeat an interval more than
+                // `i64::MAX` times. And if they do, saturating is fine.
+                let count = i64::try_from(count).unwrap_or(i64::MAX);
+                wtr.write_int(&FMT_INT, count)?;
+                wtr.write_str("/")?;
+            }
+        }
+        let span_printer = SpanPrinter::new().lowercase(self.lowercase);
+        match *interval.kind() {
+            IntervalKind::StartEnd { ref start, ref end } => {
+                self.print_interval_endpoint(start, &mut wtr)?;
+                wtr.write_str("/")?;
+                self.print_interval_endpoint(end, &mut wtr)?;
+            }
+            IntervalKind::StartSpan { ref start, ref span } => {
+                self.print_interval_endpoint(start, &mut wtr)?;
+                wtr.write_str("/")?;
+                span_printer.print_span(span, &mut wtr)?;
+            }
+            IntervalKind::SpanEnd { ref span, ref end } => {
+                span_printer.print_span(span, &mut wtr)?;
+                wtr.write_str("/")?;
+                self.print_interval_endpoint(end, &mut wtr)?;
+            }
+        }
         Ok(())
     }
 
+    /// Prints one of the datetimes in an interval.
+    ///
+    /// Datetimes in UTC or a fixed offset time zone are printed as
+    /// timestamps (with a `Z` or an offset, respectively), since that's how
+    /// they are parsed. Everything else is printed as a zoned datetime.
+    fn print_interval_endpoint<W: Write>(
+        &self,
+        zdt: &Zoned,
+        wtr: W,
+    ) -> Result<(), Error> {
+        let tz = zdt.time_zone();
+        if *tz == TimeZone::UTC {
+            return self.print_timestamp(&zdt.timestamp(), None, wtr);
+        }
+        if tz.iana_name().is_none() && !tz.is_unknown() {
+            if let Ok(offset) = tz.to_fixed_offset() {
+                return self.print_timestamp(
+                    &zdt.timestamp(),
+                    Some(offset),
+                    wtr,
+                );
+            }
+        }
+        self.print_zoned(zdt, wtr)
+    }
+
     /// Formats the given datetime into the writer given.
     pub(super) fn print_datetime<W: Write>(
         &self,
         dt: &DateTime,
         mut wtr: W,
     ) -> Result<(), Error> {
+        let dt = self.round_datetime(*dt);
         self.print_date(&dt.date(), &mut wtr)?;
         wtr.write_char(char::from(if self.lowercase {
             self.separator.to_ascii_lowercase()
         } else {
             self.separator
         }))?;
-        self.print_time(&dt.time(), &mut wtr)?;
+        self.print_time_unrounded(&dt.time(), &mut wtr)?;
         Ok(())
     }
 
@@ -107,46 +288,140 @@ impl DateTimePrinter {
         &self,
         date: &Date,
         mut wtr: W,
+    ) -> Result<(), Error> {
+        static FMT_TWO: DecimalFormatter = DecimalFormatter::new().padding(2);
+        static FMT_THREE: DecimalFormatter =
+            DecimalFormatter::new().padding(3);
+
+        if let DateKind::Week = self.date_kind {
+            return self.print_iso_week_date(&date.iso_week_date(), wtr);
+        }
+        // Fast path for the overwhelmingly common `YYYY-MM-DD` format.
+        if let DateKind::Calendar = self.date_kind {
+            if !self.basic && !self.expanded_years && date.year() >= 0 {
+                // OK because years are never greater than 9999 and months
+                // and days are always positive.
+                let year = date.year().unsigned_abs();
+                let mut buf = [0; 10];
+                buf[..2].copy_from_slice(&swar::format_pair(
+                    u8::try_from(year / 100).unwrap(),
+                ));
+                buf[2..].copy_from_slice(&swar::format_triple(
+                    [
+                        u8::try_from(year % 100).unwrap(),
+                        date.month().unsigned_abs(),
+                        date.day().unsigned_abs(),
+                    ],
+                    b'-',
+                ));
+                return write_ascii(&buf, wtr);
+            }
+        }
+        self.print_year(date.year(), &mut wtr)?;
+        self.print_separator("-", &mut wtr)?;
+        if let DateKind::Ordinal = self.date_kind {
+            wtr.write_int(&FMT_THREE, date.day_of_year())?;
+            return Ok(());
+        }
+        wtr.write_int(&FMT_TWO, date.month())?;
+        self.print_separator("-", &mut wtr)?;
+        wtr.write_int(&FMT_TWO, date.day())?;
+        Ok(())
+    }
+
+    /// Formats the given ISO 8601 week date into the writer given.
+    pub(super) fn print_iso_week_date<W: Write>(
+        &self,
+        weekdate: &ISOWeekDate,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        static FMT_ONE: DecimalFormatter = DecimalFormatter::new();
+        static FMT_TWO: DecimalFormatter = DecimalFormatter::new().padding(2);
+
+        self.print_year(weekdate.year(), &mut wtr)?;
+        self.print_separator("-", &mut wtr)?;
+        wtr.write_str("W")?;
+        wtr.write_int(&FMT_TWO, weekdate.week())?;
+        self.print_separator("-", &mut wtr)?;
+        wtr.write_int(&FMT_ONE, weekdate.weekday().to_monday_one_offset())?;
+        Ok(())
+    }
+
+    /// Formats the year component of a date into the writer given.
+    fn print_year<W: Write>(
+        &self,
+        year: i16,
+        mut wtr: W,
     ) -> Result<(), Error> {
         static FMT_YEAR_POSITIVE: DecimalFormatter =
             DecimalFormatter::new().padding(4);
         static FMT_YEAR_NEGATIVE: DecimalFormatter =
             DecimalFormatter::new().padding(6);
-        static FMT_TWO: DecimalFormatter = DecimalFormatter::new().padding(2);
+        static FMT_YEAR_EXPANDED: DecimalFormatter =
+            DecimalFormatter::new().padding(6);
 
-        if date.year() >= 0 {
-            wtr.write_int(&FMT_YEAR_POSITIVE, date.year())?;
+        if year >= 0 && self.expanded_years {
+            wtr.write_str("+")?;
+            wtr.write_int(&FMT_YEAR_EXPANDED, year)?;
+        } else if year >= 0 {
+            wtr.write_int(&FMT_YEAR_POSITIVE, year)?;
         } else {
-            wtr.write_int(&FMT_YEAR_NEGATIVE, date.year())?;
+            wtr.write_int(&FMT_YEAR_NEGATIVE, year)?;
         }
-        wtr.write_str("-")?;
-        wtr.write_int(&FMT_TWO, date.month())?;
-        wtr.write_str("-")?;
-        wtr.write_int(&FMT_TWO, date.day())?;
         Ok(())
     }
 
     /// Formats the given time into the writer given.
     pub(super) fn print_time<W: Write>(
+        &self,
+        time: &Time,
+        wtr: W,
+    ) -> Result<(), Error> {
+        self.print_time_unrounded(&self.round_time(*time), wtr)
+    }
+
+    /// Like `print_time`, but never rounds the time given.
+    ///
+    /// This is used when printing a datetime, which is rounded as a whole
+    /// beforehand. (Rounding just the time would wrap around at midnight.)
+    fn print_time_unrounded<W: Write>(
         &self,
         time: &Time,
         mut wtr: W,
     ) -> Result<(), Error> {
         static FMT_TWO: DecimalFormatter = DecimalFormatter::new().padding(2);
-        static FMT_FRACTION: FractionalFormatter = FractionalFormatter::new();
 
-        wtr.write_int(&FMT_TWO, time.hour())?;
-        wtr.write_str(":")?;
-        wtr.write_int(&FMT_TWO, time.minute())?;
-        wtr.write_str(":")?;
-        wtr.write_int(&FMT_TWO, time.second())?;
+        if self.basic {
+            wtr.write_int(&FMT_TWO, time.hour())?;
+            wtr.write_int(&FMT_TWO, time.minute())?;
+            wtr.write_int(&FMT_TWO, time.second())?;
+        } else {
+            // OK because all of these are always in the range `0..=59`.
+            let buf = swar::format_triple(
+                [
+                    time.hour().unsigned_abs(),
+                    time.minute().unsigned_abs(),
+                    time.second().unsigned_abs(),
+                ],
+                b':',
+            );
+            write_ascii(&buf, &mut wtr)?;
+        }
         let fractional_nanosecond = time.subsec_nanosecond();
-        if self.precision.map_or(fractional_nanosecond != 0, |p| p > 0) {
-            wtr.write_str(".")?;
-            wtr.write_fraction(
-                &FMT_FRACTION.precision(self.precision),
-                fractional_nanosecond,
-            )?;
+        let print_fraction = match self.precision {
+            None => {
+                fractional_nanosecond != 0
+                    || (self.min_precision > 0 && !self.omit_zero_fraction)
+            }
+            Some(0) => false,
+            Some(_) => fractional_nanosecond != 0 || !self.omit_zero_fraction,
+        };
+        if print_fraction {
+            wtr.write_str(decimal_separator(self.decimal_comma))?;
+            let fmt = FractionalFormatter::new()
+                .precision(self.precision)
+                .minimum(self.min_precision);
+            wtr.write_fraction(&fmt, fractional_nanosecond)?;
         }
         Ok(())
     }
@@ -211,11 +486,12 @@ impl DateTimePrinter {
         pieces: &Pieces,
         mut wtr: W,
     ) -> Result<(), Error> {
+        let mut rounded = false;
         if let Some(time) = pieces.time() {
             let dt = DateTime::from_parts(pieces.date(), time);
             self.print_datetime(&dt, &mut wtr)?;
             if let Some(poffset) = pieces.offset() {
-                self.print_pieces_offset(&poffset, &mut wtr)?;
+                rounded = self.print_pieces_offset(&poffset, &mut wtr)?;
             }
         } else if let Some(poffset) = pieces.offset() {
             // In this case, we have an offset but no time component. Since
@@ -223,7 +499,7 @@ impl DateTimePrinter {
             // default time (which is what would be assumed anyway).
             let dt = DateTime::from_parts(pieces.date(), Time::midnight());
             self.print_datetime(&dt, &mut wtr)?;
-            self.print_pieces_offset(&poffset, &mut wtr)?;
+            rounded = self.print_pieces_offset(&poffset, &mut wtr)?;
         } else {
             // We have no time and no offset, so we can just write the date.
             // It's okay to write this followed by an annotation, e.g.,
@@ -236,8 +512,7 @@ impl DateTimePrinter {
         // annotation.
         if let Some(ann) = pieces.time_zone_annotation() {
             // Note that we explicitly ignore `self.rfc9557` here, since with
-            // `Pieces`, the annotation has been explicitly provided. Also,
-            // at time of writing, `self.rfc9557` is always enabled anyway.
+            // `Pieces`, the annotation has been explicitly provided.
             wtr.write_str("[")?;
             if ann.is_critical() {
                 wtr.write_str("!")?;
@@ -247,32 +522,84 @@ impl DateTimePrinter {
                     wtr.write_str(name.as_str())?
                 }
                 TimeZoneAnnotationKind::Offset(offset) => {
-                    self.print_offset_rounded(&offset, &mut wtr)?
+                    self.print_offset_annotation(&offset, &mut wtr)?
                 }
             }
             wtr.write_str("]")?;
         }
+        self.print_rounded_offset_annotation(rounded, &mut wtr)?;
         Ok(())
     }
 
     /// Formats the given "pieces" offset into the writer given.
+    ///
+    /// This returns true when the offset printed was rounded.
     fn print_pieces_offset<W: Write>(
         &self,
         poffset: &PiecesOffset,
         mut wtr: W,
-    ) -> Result<(), Error> {
+    ) -> Result<bool, Error> {
         match *poffset {
-            PiecesOffset::Zulu => self.print_zulu(wtr),
+            PiecesOffset::Zulu => {
+                self.print_zulu(wtr)?;
+                Ok(false)
+            }
             PiecesOffset::Numeric(ref noffset) => {
                 if noffset.offset().is_zero() && noffset.is_negative() {
-                    wtr.write_str("-00:00")
+                    wtr.write_str(if self.basic {
+                        "-0000"
+                    } else {
+                        "-00:00"
+                    })?;
+                    Ok(false)
                 } else {
-                    self.print_offset_rounded(&noffset.offset(), wtr)
+                    self.print_offset(&noffset.offset(), wtr)
                 }
             }
         }
     }
 
+    /// Formats the given offset into the writer given according to the
+    /// configured [`SubminuteOffset`] strategy.
+    ///
+    /// This returns true when the offset printed was rounded. That is, when
+    /// the offset has non-zero seconds and they weren't printed.
+    fn print_offset<W: Write>(
+        &self,
+        offset: &Offset,
+        wtr: W,
+    ) -> Result<bool, Error> {
+        match self.subminute_offset {
+            SubminuteOffset::Round => {
+                self.print_offset_rounded(offset, wtr)?;
+                Ok(offset.part_seconds_ranged() != C(0))
+            }
+            SubminuteOffset::Full => {
+                self.print_offset_full_precision(offset, wtr)?;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Formats the given offset into the writer given as the contents of an
+    /// RFC 9557 time zone annotation.
+    ///
+    /// This is like `print_offset`, except it never reports rounding. When
+    /// offsets are printed in full, the annotation has to be printed in full
+    /// too, or else the annotation would disagree with the offset before it.
+    fn print_offset_annotation<W: Write>(
+        &self,
+        offset: &Offset,
+        wtr: W,
+    ) -> Result<(), Error> {
+        match self.subminute_offset {
+            SubminuteOffset::Round => self.print_offset_rounded(offset, wtr),
+            SubminuteOffset::Full => {
+                self.print_offset_full_precision(offset, wtr)
+            }
+        }
+    }
+
     /// Formats the given offset into the writer given.
     ///
     /// If the given offset has non-zero seconds, then they are rounded to
@@ -302,7 +629,7 @@ impl DateTimePrinter {
             }
         }
         wtr.write_int(&FMT_TWO, hours)?;
-        wtr.write_str(":")?;
+        self.print_separator(":", &mut wtr)?;
         wtr.write_int(&FMT_TWO, minutes)?;
         Ok(())
     }
@@ -324,15 +651,104 @@ impl DateTimePrinter {
         let minutes = offset.part_minutes_ranged().abs().get();
         let seconds = offset.part_seconds_ranged().abs().get();
         wtr.write_int(&FMT_TWO, hours)?;
-        wtr.write_str(":")?;
+        self.print_separator(":", &mut wtr)?;
         wtr.write_int(&FMT_TWO, minutes)?;
         if seconds > 0 {
-            wtr.write_str(":")?;
+            self.print_separator(":", &mut wtr)?;
             wtr.write_int(&FMT_TWO, seconds)?;
         }
         Ok(())
     }
 
+    /// Returns the unit and increment to round to when printing fractional
+    /// seconds, along with the increment in units of nanoseconds.
+    ///
+    /// This returns `None` when no rounding should occur. That is, when
+    /// there is no fixed precision or when excess precision is truncated.
+    fn rounding(&self) -> Option<(Unit, i64, i32)> {
+        if self.round_mode == RoundMode::Trunc {
+            return None;
+        }
+        let (unit, increment) = match self.precision? {
+            0 => (Unit::Second, 1),
+            p @ 1..=3 => (Unit::Millisecond, 10i64.pow(3 - u32::from(p))),
+            p @ 4..=6 => (Unit::Microsecond, 10i64.pow(6 - u32::from(p))),
+            p @ 7..=8 => (Unit::Nanosecond, 10i64.pow(9 - u32::from(p))),
+            _ => return None,
+        };
+        let nanos = 10i32.pow(9 - u32::from(self.precision?));
+        Some((unit, increment, nanos))
+    }
+
+    /// Rounds the given timestamp according to the configured precision and
+    /// rounding mode.
+    ///
+    /// Rounding is done on the civil datetime in UTC, so that the rounding
+    /// mode applies to the digits as they are printed (which are the same in
+    /// every offset, since offsets never have fractional seconds).
+    fn round_timestamp(&self, ts: Timestamp) -> Timestamp {
+        let Some((_, _, nanos)) = self.rounding() else { return ts };
+        if ts.subsec_nanosecond().rem_euclid(nanos) == 0 {
+            return ts;
+        }
+        let dt = self.round_datetime(Offset::UTC.to_datetime(ts));
+        Offset::UTC.to_timestamp(dt).unwrap_or(ts)
+    }
+
+    /// Rounds the given datetime according to the configured precision and
+    /// rounding mode.
+    ///
+    /// Rounding up can fail at the boundaries of the supported range. Since
+    /// printing is otherwise infallible, we fall back to truncation instead
+    /// of returning an error in that case.
+    fn round_datetime(&self, dt: DateTime) -> DateTime {
+        let Some((unit, increment, nanos)) = self.rounding() else {
+            return dt;
+        };
+        if dt.subsec_nanosecond() % nanos == 0 {
+            return dt;
+        }
+        let options = DateTimeRound::new()
+            .smallest(unit)
+            .increment(increment)
+            .mode(self.round_mode);
+        dt.round(options).unwrap_or(dt)
+    }
+
+    /// Rounds the given time according to the configured precision and
+    /// rounding mode.
+    ///
+    /// Like [`Time::round`], this wraps around when rounding up past
+    /// midnight.
+    fn round_time(&self, time: Time) -> Time {
+        let Some((unit, increment, nanos)) = self.rounding() else {
+            return time;
+        };
+        if time.subsec_nanosecond() % nanos == 0 {
+            return time;
+        }
+        let options = TimeRound::new()
+            .smallest(unit)
+            .increment(increment)
+            .mode(self.round_mode);
+        // Rounding a time wraps around, so this can never fail.
+        time.round(options).unwrap_or(time)
+    }
+
+    /// Prints the given separator between date, time or offset components.
+    ///
+    /// This is a no-op when the ISO 8601 basic format is enabled.
+    fn print_separator<W: Write>(
+        &self,
+        separator: &str,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        if self.basic {
+            return Ok(());
+        }
+        wtr.write_str(separator)
+    }
+
     /// Prints the "zulu" indicator.
     ///
     /// This should only be used when the offset is not known. For example,
@@ -362,11 +778,27 @@ impl DateTimePrinter {
         if let Some(iana_name) = time_zone.iana_name() {
             wtr.write_str(iana_name)?;
         } else {
-            self.print_offset_rounded(offset, &mut wtr)?;
+            self.print_offset_annotation(offset, &mut wtr)?;
         }
         wtr.write_str("]")?;
         Ok(())
     }
+
+    /// Writes an experimental RFC 9557 annotation indicating that the offset
+    /// printed was rounded to the nearest minute.
+    ///
+    /// This is a no-op when `rounded` is false, when annotating rounded
+    /// offsets isn't enabled or when RFC 9557 support isn't enabled.
+    fn print_rounded_offset_annotation<W: Write>(
+        &self,
+        rounded: bool,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        if !rounded || !self.annotate_rounded_offset || !self.rfc9557 {
+            return Ok(());
+        }
+        wtr.write_str("[_jiff-rounded-offset=true]")
+    }
 }
 
 impl Default for DateTimePrinter {
@@ -375,6 +807,83 @@ impl Default for DateTimePrinter {
     }
 }
 
+/// Writes the given ASCII bytes to the writer given.
+///
+/// This is used to write the output of the routines in the `swar` module,
+/// which always produce ASCII.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+fn write_ascii<W: Write>(bytes: &[u8], mut wtr: W) -> Result<(), Error> {
+    let s = core::str::from_utf8(bytes)
+        .map_err(|_| err!("expected ASCII, but got invalid UTF-8"))?;
+    wtr.write_str(s)
+}
+
+/// The representation used when printing dates.
+#[derive(Clone, Copy, Debug)]
+enum DateKind {
+    /// A calendar date, e.g., `2024-04-10`.
+    Calendar,
+    /// An ordinal date, e.g., `2024-101`.
+    Ordinal,
+    /// An ISO 8601 week date, e.g., `2024-W15-3`.
+    Week,
+}
+
+/// Configuration for how offsets with a non-zero seconds component are
+/// printed.
+///
+/// Offsets with seconds are common for datetimes before 1900 or so, when
+/// most locations in a time zone database used local mean time (LMT). For
+/// example, the LMT offset for `America/Los_Angeles` is `-07:52:58`.
+///
+/// This is used by
+/// [`DateTimePrinter::subminute_offset`](super::DateTimePrinter::subminute_offset).
+///
+/// # Example
+///
+/// ```
+/// use jiff::{
+///     civil::date,
+///     fmt::temporal::{DateTimePrinter, SubminuteOffset},
+/// };
+///
+/// let zdt = date(1850, 1, 1).in_tz("America/Los_Angeles")?;
+///
+/// let printer = DateTimePrinter::new();
+/// assert_eq!(
+///     printer.zoned_to_string(&zdt),
+///     "1850-01-01T00:00:00-07:53[America/Los_Angeles]",
+/// );
+///
+/// let printer = DateTimePrinter::new().subminute_offset(SubminuteOffset::Full);
+/// assert_eq!(
+///     printer.zoned_to_string(&zdt),
+///     "1850-01-01T00:00:00-07:52:58[America/Los_Angeles]",
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub enum SubminuteOffset {
+    /// Rounds the offset to the nearest minute, as required by RFC 3339.
+    ///
+    /// The civil datetime printed is always the actual civil datetime, and
+    /// only the offset is rounded. When printing a `Zoned`, the time zone
+    /// annotation ensures the exact instant is recovered when parsing. But
+    /// when printing a `Timestamp` with an offset, the instant parsed back
+    /// may differ by up to 30 seconds.
+    #[default]
+    Round,
+    /// Prints the seconds component of the offset when it is non-zero, e.g.,
+    /// `-07:52:58`.
+    ///
+    /// This always preserves the exact instant. While this isn't valid RFC
+    /// 3339, it is supported by the Temporal ISO 8601 grammar (and thus Jiff's
+    /// parser).
+    Full,
+}
+
 /// A printer for Temporal spans.
 ///
 /// Note that in Temporal, a "span" is called a "duration."
@@ -382,19 +891,41 @@ impl Default for DateTimePrinter {
 pub(super) struct SpanPrinter {
     /// Whether to use lowercase unit designators.
     lowercase: bool,
+    /// Whether to use the ISO 8601 "alternative" format, e.g.,
+    /// `P0001-02-03T04:05:06`.
+    alternative: bool,
+    /// Whether to use a comma instead of a period to separate the fractional
+    /// part of a second.
+    decimal_comma: bool,
 }
 
 impl SpanPrinter {
     /// Create a new Temporal span printer with the default configuration.
     pub(super) const fn new() -> SpanPrinter {
-        SpanPrinter { lowercase: false }
+        SpanPrinter {
+            lowercase: false,
+            alternative: false,
+            decimal_comma: false,
+        }
     }
 
     /// Use lowercase for unit designator labels.
     ///
     /// By default, unit designator labels are written in uppercase.
     pub(super) const fn lowercase(self, yes: bool) -> SpanPrinter {
-        SpanPrinter { lowercase: yes }
+        SpanPrinter { lowercase: yes, ..self }
+    }
+
+    /// Use the ISO 8601 alternative format, `PYYYY-MM-DDTHH:MM:SS`, instead
+    /// of unit designators.
+    pub(super) const fn alternative(self, yes: bool) -> SpanPrinter {
+        SpanPrinter { alternative: yes, ..self }
+    }
+
+    /// Use a comma instead of a period as the decimal separator for
+    /// fractional seconds.
+    pub(super) const fn decimal_comma(self, yes: bool) -> SpanPrinter {
+        SpanPrinter { decimal_comma: yes, ..self }
     }
 
     /// Print the given span to the writer given.
@@ -408,6 +939,9 @@ impl SpanPrinter {
         static FMT_INT: DecimalFormatter = DecimalFormatter::new();
         static FMT_FRACTION: FractionalFormatter = FractionalFormatter::new();
 
+        if self.alternative {
+            return self.print_span_alternative(span, wtr);
+        }
         if span.is_negative() {
             wtr.write_str("-")?;
         }
@@ -479,28 +1013,10 @@ impl SpanPrinter {
             if !printed_time_prefix {
                 wtr.write_str("T")?;
             }
-            // We want to combine our seconds, milliseconds, microseconds and
-            // nanoseconds into one single value in terms of nanoseconds. Then
-            // we can "balance" that out so that we have a number of seconds
-            // and a number of nanoseconds not greater than 1 second. (Which is
-            // our fraction.)
-            let combined_as_nanos =
-                t::SpanSecondsOrLowerNanoseconds::rfrom(nanos)
-                    + (t::SpanSecondsOrLowerNanoseconds::rfrom(micros)
-                        * t::NANOS_PER_MICRO)
-                    + (t::SpanSecondsOrLowerNanoseconds::rfrom(millis)
-                        * t::NANOS_PER_MILLI)
-                    + (t::SpanSecondsOrLowerNanoseconds::rfrom(seconds)
-                        * t::NANOS_PER_SECOND);
-            let fraction_second = t::SpanSecondsOrLower::rfrom(
-                combined_as_nanos / t::NANOS_PER_SECOND,
-            );
-            let fraction_nano = t::SubsecNanosecond::rfrom(
-                combined_as_nanos % t::NANOS_PER_SECOND,
-            );
+            let (fraction_second, fraction_nano) = span_seconds_fraction(span);
             wtr.write_int(&FMT_INT, fraction_second.get())?;
             if fraction_nano != C(0) {
-                wtr.write_str(".")?;
+                wtr.write_str(decimal_separator(self.decimal_comma))?;
                 wtr.write_fraction(&FMT_FRACTION, fraction_nano.get())?;
             }
             wtr.write_char(self.label('S'))?;
@@ -508,6 +1024,73 @@ impl SpanPrinter {
         Ok(())
     }
 
+    /// Print the given span to the writer given in the ISO 8601 alternative
+    /// format.
+    ///
+    /// Since this format has no place for weeks, they are folded into days.
+    /// And as with the designator format, sub-second units are written as a
+    /// fractional second.
+    fn print_span_alternative<W: Write>(
+        &self,
+        span: &Span,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        static FMT_FRACTION: FractionalFormatter = FractionalFormatter::new();
+
+        if span.is_negative() {
+            wtr.write_str("-")?;
+        }
+        // OK because the number of weeks and days in a span are limited such
+        // that they can't overflow an `i64`.
+        let days = i64::from(span.get_weeks_ranged().get().abs()) * 7
+            + i64::from(span.get_days_ranged().get().abs());
+        let (second, nano) = span_seconds_fraction(span);
+        self.print_alternative(
+            &mut wtr,
+            [
+                span.get_years_ranged().get().abs().into(),
+                span.get_months_ranged().get().abs().into(),
+                days,
+                span.get_hours_ranged().get().abs().into(),
+                span.get_minutes_ranged().get().abs(),
+            ],
+            second.get(),
+        )?;
+        if nano != C(0) {
+            wtr.write_str(decimal_separator(self.decimal_comma))?;
+            wtr.write_fraction(&FMT_FRACTION, nano.get())?;
+        }
+        Ok(())
+    }
+
+    /// Writes `PYYYY-MM-DDTHH:MM:SS` (without any sign or fractional second)
+    /// from the given units.
+    ///
+    /// Values that exceed the width of their field are written in full.
+    fn print_alternative<W: Write>(
+        &self,
+        mut wtr: W,
+        [years, months, days, hours, minutes]: [i64; 5],
+        seconds: i64,
+    ) -> Result<(), Error> {
+        static FMT_YEAR: DecimalFormatter = DecimalFormatter::new().padding(4);
+        static FMT_TWO: DecimalFormatter = DecimalFormatter::new().padding(2);
+
+        wtr.write_str("P")?;
+        wtr.write_int(&FMT_YEAR, years)?;
+        wtr.write_str("-")?;
+        wtr.write_int(&FMT_TWO, months)?;
+        wtr.write_str("-")?;
+        wtr.write_int(&FMT_TWO, days)?;
+        wtr.write_str("T")?;
+        wtr.write_int(&FMT_TWO, hours)?;
+        wtr.write_str(":")?;
+        wtr.write_int(&FMT_TWO, minutes)?;
+        wtr.write_str(":")?;
+        wtr.write_int(&FMT_TWO, seconds)?;
+        Ok(())
+    }
+
     /// Print the given signed duration to the writer given.
     ///
     /// This only returns an error when the given writer returns an error.
@@ -523,7 +1106,6 @@ impl SpanPrinter {
         if dur.is_negative() {
             wtr.write_str("-")?;
         }
-        wtr.write_str("PT")?;
 
         let mut secs = dur.as_secs();
         // OK because subsec_nanos -999_999_999<=nanos<=999_999_999.
@@ -535,6 +1117,16 @@ impl SpanPrinter {
         let minutes = (secs / 60).abs();
         // OK because guaranteed to be bigger than i64::MIN.
         secs = (secs % 60).abs();
+        if self.alternative {
+            self.print_alternative(&mut wtr, [0, 0, 0, hours, minutes], secs)?;
+            if nanos != 0 {
+                wtr.write_str(decimal_separator(self.decimal_comma))?;
+                wtr.write_fraction(&FMT_FRACTION, nanos)?;
+            }
+            return Ok(());
+        }
+
+        wtr.write_str("PT")?;
         if hours != 0 {
             wtr.write_int(&FMT_INT, hours)?;
             wtr.write_char(self.label('H'))?;
@@ -550,7 +1142,7 @@ impl SpanPrinter {
             wtr.write_char(self.label('S'))?;
         } else if nanos != 0 {
             wtr.write_int(&FMT_INT, secs)?;
-            wtr.write_str(".")?;
+            wtr.write_str(decimal_separator(self.decimal_comma))?;
             wtr.write_fraction(&FMT_FRACTION, nanos)?;
             wtr.write_char(self.label('S'))?;
         }
@@ -570,12 +1162,62 @@ impl SpanPrinter {
     }
 }
 
+/// Returns the separator to write before a fractional second.
+///
+/// ISO 8601 permits either a comma or a period, and actually prefers the
+/// comma.
+fn decimal_separator(comma: bool) -> &'static str {
+    if comma {
+        ","
+    } else {
+        "."
+    }
+}
+
+/// Returns the seconds, milliseconds, microseconds and nanoseconds of the
+/// given span combined into a single (absolute) number of seconds and a
+/// fractional number of nanoseconds.
+///
+/// ISO 8601 (and Temporal) don't support writing out milliseconds,
+/// microseconds or nanoseconds as separate components like for all the other
+/// units. Instead, they must be incorporated as fractional seconds.
+fn span_seconds_fraction(
+    span: &Span,
+) -> (t::SpanSecondsOrLower, t::SubsecNanosecond) {
+    let (seconds, millis, micros, nanos) = (
+        span.get_seconds_ranged().abs(),
+        span.get_milliseconds_ranged().abs(),
+        span.get_microseconds_ranged().abs(),
+        span.get_nanoseconds_ranged().abs(),
+    );
+    // We want to combine our seconds, milliseconds, microseconds and
+    // nanoseconds into one single value in terms of nanoseconds. Then
+    // we can "balance" that out so that we have a number of seconds
+    // and a number of nanoseconds not greater than 1 second. (Which is
+    // our fraction.)
+    let combined_as_nanos = t::SpanSecondsOrLowerNanoseconds::rfrom(nanos)
+        + (t::SpanSecondsOrLowerNanoseconds::rfrom(micros)
+            * t::NANOS_PER_MICRO)
+        + (t::SpanSecondsOrLowerNanoseconds::rfrom(millis)
+            * t::NANOS_PER_MILLI)
+        + (t::SpanSecondsOrLowerNanoseconds::rfrom(seconds)
+            * t::NANOS_PER_SECOND);
+    let fraction_second =
+        t::SpanSecondsOrLower::rfrom(combined_as_nanos / t::NANOS_PER_SECOND);
+    let fraction_nano =
+        t::SubsecNanosecond::rfrom(combined_as_nanos % t::NANOS_PER_SECOND);
+    (fraction_second, fraction_nano)
+}
+
 #[cfg(feature = "alloc")]
 #[cfg(test)]
 mod tests {
     use alloc::string::String;
 
-    use crate::{civil::date, span::ToSpan};
+    use crate::{
+        civil::{date, time},
+        span::ToSpan,
+    };
 
     use super::*;
 
@@ -599,6 +1241,65 @@ mod tests {
         assert_eq!(buf, "2024-03-10T09:34:45+00:00[UTC]");
     }
 
+    #[test]
+    fn print_zoned_presets() {
+        let zoned = date(2024, 3, 10)
+            .at(5, 34, 45, 0)
+            .to_zoned(TimeZone::fixed(crate::tz::offset(-4)))
+            .unwrap();
+        let p = |printer: DateTimePrinter, zdt: &Zoned| {
+            let mut buf = String::new();
+            printer.print_zoned(zdt, &mut buf).unwrap();
+            buf
+        };
+
+        let rfc3339 = DateTimePrinter::new().rfc9557(false);
+        let civil = DateTimePrinter::new().rfc9557(false).zoned_offset(false);
+        insta::assert_snapshot!(p(DateTimePrinter::new(), &zoned), @"2024-03-10T05:34:45-04:00[-04:00]");
+        insta::assert_snapshot!(p(rfc3339.clone(), &zoned), @"2024-03-10T05:34:45-04:00");
+        insta::assert_snapshot!(p(civil.clone(), &zoned), @"2024-03-10T05:34:45");
+
+        let unknown = zoned.with_time_zone(TimeZone::unknown());
+        insta::assert_snapshot!(p(DateTimePrinter::new(), &unknown), @"2024-03-10T09:34:45Z[Etc/Unknown]");
+        insta::assert_snapshot!(p(rfc3339.clone(), &unknown), @"2024-03-10T09:34:45Z");
+        insta::assert_snapshot!(p(civil, &unknown), @"2024-03-10T09:34:45");
+
+        let lmt = date(1850, 1, 1)
+            .to_zoned(TimeZone::fixed(
+                crate::tz::Offset::from_seconds(-(7 * 3600 + 52 * 60 + 58))
+                    .unwrap(),
+            ))
+            .unwrap();
+        let annotated = DateTimePrinter::new().annotate_rounded_offset(true);
+        insta::assert_snapshot!(p(annotated.clone(), &lmt), @"1850-01-01T00:00:00-07:53[-07:53][_jiff-rounded-offset=true]");
+        insta::assert_snapshot!(p(annotated.rfc9557(false), &lmt), @"1850-01-01T00:00:00-07:53");
+    }
+
+    #[test]
+    fn print_omit_zero_fraction() {
+        let p = |printer: DateTimePrinter, time: Time| {
+            let mut buf = String::new();
+            printer.print_time(&time, &mut buf).unwrap();
+            buf
+        };
+
+        let omit = DateTimePrinter::new().omit_zero_fraction(true);
+        let zero = Time::midnight();
+        let nonzero = Time::midnight().with().millisecond(5).build().unwrap();
+        insta::assert_snapshot!(p(omit.clone(), zero), @"00:00:00");
+        insta::assert_snapshot!(p(omit.clone(), nonzero), @"00:00:00.005");
+        insta::assert_snapshot!(p(omit.clone().precision(Some(3)), zero), @"00:00:00");
+        insta::assert_snapshot!(
+            p(omit.clone().precision(Some(3)), nonzero),
+            @"00:00:00.005",
+        );
+        insta::assert_snapshot!(p(omit.precision(Some(0)), nonzero), @"00:00:00");
+        insta::assert_snapshot!(
+            p(DateTimePrinter::new().precision(Some(3)), zero),
+            @"00:00:00.000",
+        );
+    }
+
     #[test]
     fn print_timestamp() {
         if crate::tz::db().is_definitively_empty() {
@@ -622,6 +1323,166 @@ mod tests {
         assert_eq!(buf, "-002024-03-10T10:30:47Z");
     }
 
+    #[test]
+    fn print_timestamp_separator_and_case() {
+        let dt = date(2024, 3, 10).at(5, 34, 45, 0);
+        let ts = dt.to_zoned(TimeZone::UTC).unwrap().timestamp();
+        let p = |printer: DateTimePrinter| -> String {
+            let mut buf = String::new();
+            printer.print_timestamp(&ts, None, &mut buf).unwrap();
+            buf
+        };
+
+        let printer = DateTimePrinter::new();
+        insta::assert_snapshot!(p(printer.clone()), @"2024-03-10T05:34:45Z");
+        insta::assert_snapshot!(
+            p(printer.clone().lowercase(true)),
+            @"2024-03-10t05:34:45z",
+        );
+        insta::assert_snapshot!(
+            p(printer.clone().separator(b' ')),
+            @"2024-03-10 05:34:45Z",
+        );
+        insta::assert_snapshot!(
+            p(printer.clone().zulu(false)),
+            @"2024-03-10T05:34:45+00:00",
+        );
+        insta::assert_snapshot!(
+            p(printer.clone().zulu(false).basic(true)),
+            @"20240310T053445+0000",
+        );
+        insta::assert_snapshot!(
+            p(printer.clone().lowercase(true).separator(b' ').zulu(false)),
+            @"2024-03-10 05:34:45+00:00",
+        );
+    }
+
+    #[test]
+    fn print_precision_rounding() {
+        let p = |printer: &DateTimePrinter, dt: DateTime| -> String {
+            let mut buf = String::new();
+            printer.print_datetime(&dt, &mut buf).unwrap();
+            buf
+        };
+        let ts = |printer: &DateTimePrinter, ts: Timestamp| -> String {
+            let mut buf = String::new();
+            printer.print_timestamp(&ts, None, &mut buf).unwrap();
+            buf
+        };
+        let t = |printer: &DateTimePrinter, time: Time| -> String {
+            let mut buf = String::new();
+            printer.print_time(&time, &mut buf).unwrap();
+            buf
+        };
+
+        let min = DateTimePrinter::new().min_precision(3);
+        insta::assert_snapshot!(t(&min, Time::midnight()), @"00:00:00.000");
+        insta::assert_snapshot!(
+            t(&min, time(1, 2, 3, 500_000_000)),
+            @"01:02:03.500",
+        );
+        insta::assert_snapshot!(
+            t(&min, time(1, 2, 3, 1)),
+            @"01:02:03.000000001",
+        );
+        insta::assert_snapshot!(
+            t(&min.clone().omit_zero_fraction(true), Time::midnight()),
+            @"00:00:00",
+        );
+        insta::assert_snapshot!(
+            t(&min.clone().min_precision(20), Time::midnight()),
+            @"00:00:00.000000000",
+        );
+
+        let dt = date(2024, 12, 31).at(23, 59, 59, 999_500_000);
+        let prec = DateTimePrinter::new().precision(Some(3));
+        insta::assert_snapshot!(p(&prec, dt), @"2024-12-31T23:59:59.999");
+        let half = prec.clone().round_mode(RoundMode::HalfExpand);
+        insta::assert_snapshot!(p(&half, dt), @"2025-01-01T00:00:00.000");
+        insta::assert_snapshot!(
+            p(&half.clone().precision(Some(0)), dt),
+            @"2025-01-01T00:00:00",
+        );
+        insta::assert_snapshot!(
+            p(&half.clone().precision(Some(8)), dt),
+            @"2024-12-31T23:59:59.99950000",
+        );
+        let below = dt.with().subsec_nanosecond(999_499_999).build().unwrap();
+        insta::assert_snapshot!(p(&half, below), @"2024-12-31T23:59:59.999");
+        insta::assert_snapshot!(
+            p(&prec.clone().round_mode(RoundMode::Ceil), dt),
+            @"2025-01-01T00:00:00.000",
+        );
+        // Time on its own wraps around.
+        insta::assert_snapshot!(t(&half, dt.time()), @"00:00:00.000");
+        // Rounding past the maximum falls back to truncation.
+        insta::assert_snapshot!(
+            p(&half, DateTime::MAX),
+            @"9999-12-31T23:59:59.999",
+        );
+
+        // Timestamps before the Unix epoch round based on their digits.
+        let before = Timestamp::new(-1, -400_000_000).unwrap();
+        let trunc = prec.clone().precision(Some(0));
+        insta::assert_snapshot!(ts(&trunc, before), @"1969-12-31T23:59:58Z");
+        let floor = trunc.clone().round_mode(RoundMode::Floor);
+        insta::assert_snapshot!(ts(&floor, before), @"1969-12-31T23:59:58Z");
+        let ceil = trunc.clone().round_mode(RoundMode::Ceil);
+        insta::assert_snapshot!(ts(&ceil, before), @"1969-12-31T23:59:59Z");
+    }
+
+    #[test]
+    fn print_subminute_offset() {
+        let offset = Offset::from_seconds(-(7 * 3600 + 52 * 60 + 58)).unwrap();
+        let zoned: Zoned = date(1850, 1, 1)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::fixed(offset))
+            .unwrap();
+        let p = |printer: DateTimePrinter| -> (String, String, String) {
+            let mut zbuf = String::new();
+            printer.print_zoned(&zoned, &mut zbuf).unwrap();
+            let mut tbuf = String::new();
+            printer
+                .print_timestamp(&zoned.timestamp(), Some(offset), &mut tbuf)
+                .unwrap();
+            let pieces = Pieces::from(&zoned);
+            let mut pbuf = String::new();
+            printer.print_pieces(&pieces, &mut pbuf).unwrap();
+            (zbuf, tbuf, pbuf)
+        };
+
+        let (z, t, p0) = p(DateTimePrinter::new());
+        assert_eq!(z, "1850-01-01T00:00:00-07:53[-07:53]");
+        assert_eq!(t, "1850-01-01T00:00:00-07:53");
+        assert_eq!(p0, "1850-01-01T00:00:00-07:53[-07:53]");
+
+        let printer =
+            DateTimePrinter::new().subminute_offset(SubminuteOffset::Full);
+        let (z, t, p0) = p(printer);
+        assert_eq!(z, "1850-01-01T00:00:00-07:52:58[-07:52:58]");
+        assert_eq!(t, "1850-01-01T00:00:00-07:52:58");
+        assert_eq!(p0, "1850-01-01T00:00:00-07:52:58[-07:52:58]");
+
+        let printer = DateTimePrinter::new().annotate_rounded_offset(true);
+        let (z, t, p0) = p(printer);
+        assert_eq!(
+            z,
+            "1850-01-01T00:00:00-07:53[-07:53][_jiff-rounded-offset=true]",
+        );
+        assert_eq!(t, "1850-01-01T00:00:00-07:53[_jiff-rounded-offset=true]");
+        assert_eq!(
+            p0,
+            "1850-01-01T00:00:00-07:53[-07:53][_jiff-rounded-offset=true]",
+        );
+
+        let printer = DateTimePrinter::new()
+            .subminute_offset(SubminuteOffset::Full)
+            .annotate_rounded_offset(true);
+        let (z, t, _) = p(printer);
+        assert_eq!(z, "1850-01-01T00:00:00-07:52:58[-07:52:58]");
+        assert_eq!(t, "1850-01-01T00:00:00-07:52:58");
+    }
+
     #[test]
     fn print_span_basic() {
         let p = |span: Span| -> String {
@@ -651,6 +1512,70 @@ mod tests {
         ), @"-P1Y1M1W1DT1H1M1.001001001S");
     }
 
+    #[test]
+    fn print_span_alternative() {
+        let p = |span: Span| -> String {
+            let mut buf = String::new();
+            SpanPrinter::new()
+                .alternative(true)
+                .print_span(&span, &mut buf)
+                .unwrap();
+            buf
+        };
+
+        insta::assert_snapshot!(p(Span::new()), @"P0000-00-00T00:00:00");
+        insta::assert_snapshot!(p(1.second()), @"P0000-00-00T00:00:01");
+        insta::assert_snapshot!(p(-1.second()), @"-P0000-00-00T00:00:01");
+        insta::assert_snapshot!(p(
+            1.year().months(1).weeks(1).days(1)
+            .hours(1).minutes(1).seconds(1)
+            .milliseconds(1).microseconds(1).nanoseconds(1),
+        ), @"P0001-01-08T01:01:01.001001001");
+        insta::assert_snapshot!(p(
+            -1.year().months(1).weeks(1).days(1)
+            .hours(1).minutes(1).seconds(1)
+            .milliseconds(1).microseconds(1).nanoseconds(1),
+        ), @"-P0001-01-08T01:01:01.001001001");
+        insta::assert_snapshot!(p(100.hours()), @"P0000-00-00T100:00:00");
+        insta::assert_snapshot!(
+            p(19_998.years()),
+            @"P19998-00-00T00:00:00",
+        );
+        insta::assert_snapshot!(
+            p(1_500.milliseconds()),
+            @"P0000-00-00T00:00:01.5",
+        );
+    }
+
+    #[test]
+    fn print_duration_alternative() {
+        let p = |dur: SignedDuration| -> String {
+            let mut buf = String::new();
+            SpanPrinter::new()
+                .alternative(true)
+                .print_duration(&dur, &mut buf)
+                .unwrap();
+            buf
+        };
+
+        insta::assert_snapshot!(
+            p(SignedDuration::ZERO),
+            @"P0000-00-00T00:00:00",
+        );
+        insta::assert_snapshot!(
+            p(SignedDuration::new(3_661, 1)),
+            @"P0000-00-00T01:01:01.000000001",
+        );
+        insta::assert_snapshot!(
+            p(SignedDuration::new(-3_661, -1)),
+            @"-P0000-00-00T01:01:01.000000001",
+        );
+        insta::assert_snapshot!(
+            p(SignedDuration::MIN),
+            @"-P0000-00-00T2562047788015215:30:08.999999999",
+        );
+    }
+
     #[test]
     fn print_span_subsecond_positive() {
         let p = |span: Span| -> String {
@@ -0,0 +1,867 @@
+use std::io::{self, BufRead, Read};
+
+use crate::{
+    civil,
+    error::Error,
+    fmt::temporal::{DateTimeParser, SpanParser},
+    tz::TimeZoneDatabase,
+    util::escape,
+    SignedDuration, Span, Timestamp, Zoned,
+};
+
+/// The maximum number of bytes a [`StreamReader`] will look at in order to
+/// parse a single value.
+///
+/// This is far bigger than any datetime or duration Jiff will emit. It is
+/// also big enough for the vast majority of time zone annotations.
+const LOOKAHEAD: usize = 256;
+
+/// The number of bytes following a parsed value that must be seen (absent a
+/// terminator or the end of the stream) before the value is considered
+/// complete.
+///
+/// This bounds how far the Temporal parsers might backtrack when an optional
+/// component is only partially present.
+const TRAILING_CONTEXT: usize = 16;
+
+/// The span parser used by every [`StreamReader`]. It has no configuration.
+static SPAN_PARSER: SpanParser = SpanParser::new();
+
+/// An adaptor for incrementally parsing Temporal datetimes and durations out
+/// of an [`io::BufRead`] implementation.
+///
+/// Each `parse_*` method parses a single value from the *beginning* of the
+/// remaining input and consumes only the bytes that make up that value. The
+/// number of bytes consumed is returned alongside the value. Whatever follows
+/// the value is left in the stream, and can be read through this adaptor's
+/// own [`io::Read`] and [`io::BufRead`] implementations. This makes it
+/// possible to, for example, parse the timestamp at the start of each line in
+/// a log without first reading the entire line into memory.
+///
+/// This adaptor never allocates. When a value is entirely contained within
+/// the underlying reader's buffer, it is parsed directly out of that buffer.
+/// When a value straddles the boundary between two reads, its bytes are
+/// copied into a small fixed size buffer owned by this adaptor. Consequently,
+/// a value longer than 256 bytes cannot be parsed by this adaptor.
+///
+/// When parsing fails, nothing is consumed. Callers may then skip over the
+/// offending input, e.g., by reading through to the end of the line.
+///
+/// In order to know where a value ends, this adaptor may need to see a few
+/// of the bytes following it. It reads until it sees a line terminator (or
+/// other non-space ASCII whitespace), 16 bytes beyond the value or the end of
+/// the stream, whichever comes first. This only matters for streams that
+/// block, e.g., a pipe from which only part of a line is available.
+///
+/// Iterators of byte chunks can be parsed by wrapping them in a
+/// [`ChunkReader`] first.
+///
+/// This type is only available when the `std` feature is enabled.
+///
+/// # Example
+///
+/// This shows how to parse the timestamp at the start of each line in a log.
+///
+/// ```
+/// use std::io::BufRead;
+///
+/// use jiff::{fmt::temporal::StreamReader, Timestamp};
+///
+/// let log = "\
+/// 2024-06-15T07:00:00Z starting up
+/// 2024-06-15T07:00:05.123Z ready
+/// ";
+/// let mut rdr = StreamReader::new(log.as_bytes());
+/// let mut got = vec![];
+/// while let Some((ts, _)) = rdr.parse_timestamp()? {
+///     let mut message = String::new();
+///     rdr.read_line(&mut message)?;
+///     got.push((ts, message.trim().to_string()));
+/// }
+/// assert_eq!(got, vec![
+///     ("2024-06-15T07:00:00Z".parse::<Timestamp>()?, "starting up".into()),
+///     ("2024-06-15T07:00:05.123Z".parse::<Timestamp>()?, "ready".into()),
+/// ]);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Example: recovering from errors
+///
+/// Since nothing is consumed when parsing fails, the caller decides how to
+/// proceed. In this example, lines that don't start with a timestamp are
+/// skipped.
+///
+/// ```
+/// use std::io::BufRead;
+///
+/// use jiff::fmt::temporal::StreamReader;
+///
+/// let log = "\
+/// 2024-06-15T07:00:00Z starting up
+/// garbage
+/// 2024-06-15T07:00:05Z ready
+/// ";
+/// let mut rdr = StreamReader::new(log.as_bytes());
+/// let (mut ok, mut skipped) = (0, 0);
+/// loop {
+///     match rdr.parse_timestamp() {
+///         Ok(None) => break,
+///         Ok(Some(_)) => ok += 1,
+///         Err(_) => skipped += 1,
+///     }
+///     rdr.read_line(&mut String::new())?;
+/// }
+/// assert_eq!((ok, skipped), (2, 1));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct StreamReader<R> {
+    src: Lookahead<R>,
+    parser: DateTimeParser,
+}
+
+impl<R: BufRead> StreamReader<R> {
+    /// Create a new streaming adaptor over the reader given.
+    ///
+    /// Datetimes are parsed with the default configuration of
+    /// [`DateTimeParser`]. Use [`StreamReader::parser`] to change it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::StreamReader};
+    ///
+    /// let mut rdr = StreamReader::new(&b"2024-06-15,and more"[..]);
+    /// assert_eq!(rdr.parse_date()?, Some((date(2024, 6, 15), 10)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(rdr: R) -> StreamReader<R> {
+        StreamReader {
+            src: Lookahead { rdr, buf: [0; LOOKAHEAD], start: 0, end: 0 },
+            parser: DateTimeParser::new(),
+        }
+    }
+
+    /// Set the parser used to parse datetimes.
+    ///
+    /// This permits configuring things like the offset conflict resolution
+    /// strategy used by [`StreamReader::parse_zoned`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::temporal::{DateTimeParser, StreamReader};
+    ///
+    /// let input = "+0002024-06-15";
+    ///
+    /// let mut rdr = StreamReader::new(input.as_bytes());
+    /// assert!(rdr.parse_date().is_err());
+    ///
+    /// let parser = DateTimeParser::new().expanded_years(true);
+    /// let mut rdr = StreamReader::new(input.as_bytes()).parser(parser);
+    /// assert!(rdr.parse_date().is_ok());
+    /// ```
+    pub fn parser(self, parser: DateTimeParser) -> StreamReader<R> {
+        StreamReader { parser, ..self }
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// Note that the underlying reader may have been advanced beyond what
+    /// has been consumed from this adaptor.
+    pub fn get_ref(&self) -> &R {
+        &self.src.rdr
+    }
+
+    /// Parse a [`Zoned`] from the beginning of this stream.
+    ///
+    /// On success, this returns the value parsed along with the number of
+    /// bytes consumed. If the stream is empty, then `None` is returned.
+    ///
+    /// This uses the global time zone database. See
+    /// [`StreamReader::parse_zoned_with`] for providing a different one.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`DateTimeParser::parse_zoned`], except that trailing input is not an
+    /// error. An error is also returned if reading from the underlying stream
+    /// fails. No input is consumed when an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::temporal::StreamReader;
+    ///
+    /// let input = "2024-06-15T07[America/New_York] hello";
+    /// let mut rdr = StreamReader::new(input.as_bytes());
+    /// let (zdt, n) = rdr.parse_zoned()?.unwrap();
+    /// assert_eq!(
+    ///     zdt.to_string(),
+    ///     "2024-06-15T07:00:00-04:00[America/New_York]",
+    /// );
+    /// assert_eq!(n, 31);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_zoned(&mut self) -> Result<Option<(Zoned, usize)>, Error> {
+        self.parse_zoned_with(crate::tz::db())
+    }
+
+    /// Parse a [`Zoned`] from the beginning of this stream using the time
+    /// zone database given.
+    ///
+    /// This is like [`StreamReader::parse_zoned`], but permits providing
+    /// the time zone database used to resolve time zone annotations.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`StreamReader::parse_zoned`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::StreamReader, tz};
+    ///
+    /// let db = tz::db();
+    /// let mut rdr = StreamReader::new(&b"2024-06-15T07[Asia/Tokyo]"[..]);
+    /// let (zdt, _) = rdr.parse_zoned_with(db)?.unwrap();
+    /// assert_eq!(zdt.time_zone().iana_name(), Some("Asia/Tokyo"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_zoned_with(
+        &mut self,
+        db: &TimeZoneDatabase,
+    ) -> Result<Option<(Zoned, usize)>, Error> {
+        let p = &self.parser;
+        self.src.read_with(|input| {
+            let parsed = p.p.parse_temporal_datetime(input)?;
+            let zdt =
+                parsed.value.to_zoned(db, p.offset_conflict, p.disambiguation);
+            Ok((zdt, input.len() - parsed.input.len()))
+        })
+    }
+
+    /// Parse a [`Timestamp`] from the beginning of this stream.
+    ///
+    /// On success, this returns the value parsed along with the number of
+    /// bytes consumed. If the stream is empty, then `None` is returned.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`DateTimeParser::parse_timestamp`], except that trailing input is not
+    /// an error. An error is also returned if reading from the underlying
+    /// stream fails. No input is consumed when an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::temporal::StreamReader;
+    ///
+    /// let mut rdr = StreamReader::new(&b"2024-06-15T07:00:00Z INFO"[..]);
+    /// let (ts, n) = rdr.parse_timestamp()?.unwrap();
+    /// assert_eq!(ts.to_string(), "2024-06-15T07:00:00Z");
+    /// assert_eq!(n, 20);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_timestamp(
+        &mut self,
+    ) -> Result<Option<(Timestamp, usize)>, Error> {
+        let p = &self.parser.p;
+        self.src.read_with(|input| {
+            let parsed = p.parse_temporal_datetime(input)?;
+            let ts = parsed.value.to_timestamp();
+            Ok((ts, input.len() - parsed.input.len()))
+        })
+    }
+
+    /// Parse a [`civil::DateTime`] from the beginning of this stream.
+    ///
+    /// On success, this returns the value parsed along with the number of
+    /// bytes consumed. If the stream is empty, then `None` is returned.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`DateTimeParser::parse_datetime`], except that trailing input is not
+    /// an error. An error is also returned if reading from the underlying
+    /// stream fails. No input is consumed when an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::StreamReader};
+    ///
+    /// let mut rdr = StreamReader::new(&b"2024-06-15 07:30,GET"[..]);
+    /// let (dt, n) = rdr.parse_datetime()?.unwrap();
+    /// assert_eq!(dt, date(2024, 6, 15).at(7, 30, 0, 0));
+    /// assert_eq!(n, 16);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_datetime(
+        &mut self,
+    ) -> Result<Option<(civil::DateTime, usize)>, Error> {
+        let p = &self.parser.p;
+        self.src.read_with(|input| {
+            let parsed = p.parse_temporal_datetime(input)?;
+            let dt = parsed.value.to_datetime();
+            Ok((dt, input.len() - parsed.input.len()))
+        })
+    }
+
+    /// Parse a [`civil::Date`] from the beginning of this stream.
+    ///
+    /// On success, this returns the value parsed along with the number of
+    /// bytes consumed. If the stream is empty, then `None` is returned.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`DateTimeParser::parse_date`], except that trailing input is not an
+    /// error. An error is also returned if reading from the underlying stream
+    /// fails. No input is consumed when an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::StreamReader};
+    ///
+    /// let mut rdr = StreamReader::new(&b"2024-06-15|2024-06-16"[..]);
+    /// assert_eq!(rdr.parse_date()?, Some((date(2024, 6, 15), 10)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_date(
+        &mut self,
+    ) -> Result<Option<(civil::Date, usize)>, Error> {
+        let p = &self.parser.p;
+        self.src.read_with(|input| {
+            let parsed = p.parse_temporal_datetime(input)?;
+            let date = parsed.value.to_date();
+            Ok((date, input.len() - parsed.input.len()))
+        })
+    }
+
+    /// Parse a [`civil::Time`] from the beginning of this stream.
+    ///
+    /// On success, this returns the value parsed along with the number of
+    /// bytes consumed. If the stream is empty, then `None` is returned.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`DateTimeParser::parse_time`], except that trailing input is not an
+    /// error. An error is also returned if reading from the underlying stream
+    /// fails. No input is consumed when an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, fmt::temporal::StreamReader};
+    ///
+    /// let mut rdr = StreamReader::new(&b"07:30:01.5 done"[..]);
+    /// let (t, n) = rdr.parse_time()?.unwrap();
+    /// assert_eq!(t, time(7, 30, 1, 500_000_000));
+    /// assert_eq!(n, 10);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_time(
+        &mut self,
+    ) -> Result<Option<(civil::Time, usize)>, Error> {
+        let p = &self.parser.p;
+        self.src.read_with(|input| {
+            let parsed = p.parse_temporal_time(input)?;
+            let time = parsed.value.to_time();
+            Ok((time, input.len() - parsed.input.len()))
+        })
+    }
+
+    /// Parse an ISO 8601 duration into a [`Span`] from the beginning of this
+    /// stream.
+    ///
+    /// On success, this returns the value parsed along with the number of
+    /// bytes consumed. If the stream is empty, then `None` is returned.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`SpanParser::parse_span`], except that trailing input is not an
+    /// error. An error is also returned if reading from the underlying stream
+    /// fails. No input is consumed when an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::StreamReader, ToSpan};
+    ///
+    /// let mut rdr = StreamReader::new(&b"PT1h30m elapsed"[..]);
+    /// let (span, n) = rdr.parse_span()?.unwrap();
+    /// assert_eq!(span, 1.hour().minutes(30).fieldwise());
+    /// assert_eq!(n, 7);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_span(&mut self) -> Result<Option<(Span, usize)>, Error> {
+        self.src.read_with(|input| {
+            let parsed = SPAN_PARSER.p.parse_temporal_duration(input)?;
+            Ok((Ok(parsed.value), input.len() - parsed.input.len()))
+        })
+    }
+
+    /// Parse an ISO 8601 duration into a [`SignedDuration`] from the
+    /// beginning of this stream.
+    ///
+    /// On success, this returns the value parsed along with the number of
+    /// bytes consumed. If the stream is empty, then `None` is returned.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`SpanParser::parse_duration`], except that trailing input is not an
+    /// error. An error is also returned if reading from the underlying stream
+    /// fails. No input is consumed when an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::StreamReader, SignedDuration};
+    ///
+    /// let mut rdr = StreamReader::new(&b"PT0.25S;"[..]);
+    /// let (dur, n) = rdr.parse_duration()?.unwrap();
+    /// assert_eq!(dur, SignedDuration::from_millis(250));
+    /// assert_eq!(n, 7);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_duration(
+        &mut self,
+    ) -> Result<Option<(SignedDuration, usize)>, Error> {
+        self.src.read_with(|input| {
+            let parsed = SPAN_PARSER.p.parse_signed_duration(input)?;
+            Ok((Ok(parsed.value), input.len() - parsed.input.len()))
+        })
+    }
+}
+
+impl<R: BufRead> Read for StreamReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<R: BufRead> BufRead for StreamReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let src = &mut self.src;
+        if src.start < src.end {
+            return Ok(&src.buf[src.start..src.end]);
+        }
+        src.rdr.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let src = &mut self.src;
+        if src.start < src.end {
+            src.start += amt.min(src.end - src.start);
+        } else {
+            src.rdr.consume(amt);
+        }
+    }
+}
+
+impl<R: core::fmt::Debug> core::fmt::Debug for StreamReader<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let src = &self.src;
+        f.debug_struct("StreamReader")
+            .field("rdr", &src.rdr)
+            .field("parser", &self.parser)
+            .field("pending", &escape::Bytes(&src.buf[src.start..src.end]))
+            .finish()
+    }
+}
+
+/// The state shared by all parsing routines on a [`StreamReader`].
+///
+/// This is split out from `StreamReader` so that the parser configuration
+/// can be borrowed while the lookahead buffer is mutated.
+struct Lookahead<R> {
+    rdr: R,
+    /// Bytes that have been read from `rdr` but not yet consumed. Only bytes
+    /// in `buf[start..end]` are valid. When this range is empty, parsing
+    /// happens directly on the buffer of `rdr`.
+    buf: [u8; LOOKAHEAD],
+    start: usize,
+    end: usize,
+}
+
+impl<R: BufRead> Lookahead<R> {
+    /// Parse a single value from the beginning of the stream.
+    ///
+    /// The closure given is called with a prefix of the stream. It should
+    /// return an error when the prefix is syntactically invalid. Otherwise,
+    /// it returns the result of converting what was parsed into the final
+    /// value along with the number of bytes consumed.
+    ///
+    /// The closure may be called multiple times with progressively longer
+    /// prefixes until its result can no longer change by seeing more input.
+    fn read_with<T, F>(
+        &mut self,
+        mut parse: F,
+    ) -> Result<Option<(T, usize)>, Error>
+    where
+        F: FnMut(&[u8]) -> Result<(Result<T, Error>, usize), Error>,
+    {
+        let mut eof = false;
+        loop {
+            if self.start == self.end {
+                let available = fill_buf(&mut self.rdr)?;
+                if available.is_empty() {
+                    return Ok(None);
+                }
+                let window = &available[..available.len().min(LOOKAHEAD)];
+                let len = window.len();
+                match attempt(window, len == LOOKAHEAD, &mut parse) {
+                    Some(Ok((value, n))) => {
+                        self.rdr.consume(n);
+                        return Ok(Some((value, n)));
+                    }
+                    Some(Err(err)) => return Err(err),
+                    None => {}
+                }
+                // The value might straddle this buffer and the next one
+                // returned by `rdr`, so we need to start copying.
+                self.buf[..len].copy_from_slice(window);
+                self.start = 0;
+                self.end = len;
+                self.rdr.consume(len);
+            } else {
+                let window = &self.buf[self.start..self.end];
+                let last = eof || window.len() == LOOKAHEAD;
+                match attempt(window, last, &mut parse) {
+                    Some(Ok((value, n))) => {
+                        self.start += n;
+                        return Ok(Some((value, n)));
+                    }
+                    Some(Err(err)) => return Err(err),
+                    None => {}
+                }
+            }
+
+            self.buf.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+            let available = fill_buf(&mut self.rdr)?;
+            if available.is_empty() {
+                eof = true;
+                continue;
+            }
+            let n = available.len().min(LOOKAHEAD - self.end);
+            self.buf[self.end..][..n].copy_from_slice(&available[..n]);
+            self.end += n;
+            self.rdr.consume(n);
+        }
+    }
+}
+
+/// An adaptor for treating an iterator of byte chunks as an
+/// [`io::BufRead`] implementation.
+///
+/// This is useful for parsing datetimes and durations out of input that
+/// arrives in pieces, e.g., from a network socket, with a [`StreamReader`].
+/// Values may be split across any number of chunks. No allocation is
+/// performed.
+///
+/// Empty chunks are skipped. The stream ends when the iterator is exhausted.
+///
+/// This type is only available when the `std` feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// use jiff::fmt::temporal::{ChunkReader, StreamReader};
+///
+/// let chunks = ["2024-06-1", "5T07:0", "0Z", "", "\n"];
+/// let mut rdr = StreamReader::new(ChunkReader::new(chunks));
+/// let (ts, n) = rdr.parse_timestamp()?.unwrap();
+/// assert_eq!(ts.to_string(), "2024-06-15T07:00:00Z");
+/// assert_eq!(n, 17);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct ChunkReader<I: Iterator> {
+    chunks: I,
+    chunk: Option<I::Item>,
+    pos: usize,
+}
+
+impl<I> ChunkReader<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    /// Create a new reader over the given chunks.
+    pub fn new<C: IntoIterator<IntoIter = I>>(chunks: C) -> ChunkReader<I> {
+        ChunkReader { chunks: chunks.into_iter(), chunk: None, pos: 0 }
+    }
+}
+
+impl<I> Read for ChunkReader<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<I> BufRead for ChunkReader<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        loop {
+            let len = self.chunk.as_ref().map_or(0, |c| c.as_ref().len());
+            if self.pos < len {
+                break;
+            }
+            self.pos = 0;
+            self.chunk = self.chunks.next();
+            if self.chunk.is_none() {
+                break;
+            }
+        }
+        Ok(self.chunk.as_ref().map_or(&[], |c| &c.as_ref()[self.pos..]))
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt;
+    }
+}
+
+/// Tries to parse a value from the given window of input.
+///
+/// When `last` is true, there is no more input beyond `window` that could
+/// change the result. Otherwise, `None` is returned when the result is not
+/// yet final.
+fn attempt<T>(
+    window: &[u8],
+    last: bool,
+    parse: &mut impl FnMut(&[u8]) -> Result<(Result<T, Error>, usize), Error>,
+) -> Option<Result<(T, usize), Error>> {
+    // Once we've seen a byte that can never appear in a datetime or duration,
+    // no amount of additional input can change what the parser does. This
+    // prevents us from reading all the way up to our lookahead limit for a
+    // line that doesn't start with a valid value.
+    let terminated = |bytes: &[u8]| bytes.iter().any(|&b| is_terminator(b));
+    match parse(window) {
+        // The parser may stop before the end of the window in the middle of
+        // an optional component, e.g., `T07` followed by `3`. In that case,
+        // more input could make the value longer. So we also require either
+        // a terminator or a few bytes of trailing context.
+        Ok((result, n))
+            if last
+                || window.len() - n >= TRAILING_CONTEXT
+                || terminated(&window[n..]) =>
+        {
+            Some(result.map(|value| (value, n)))
+        }
+        Err(err) if last || terminated(window) => Some(Err(err)),
+        _ => None,
+    }
+}
+
+/// Returns true for bytes that never appear within a Temporal datetime or
+/// duration.
+fn is_terminator(byte: u8) -> bool {
+    byte != b' ' && byte.is_ascii_whitespace()
+}
+
+/// Like `BufRead::fill_buf`, but retries on interruption.
+fn fill_buf<R: BufRead>(rdr: &mut R) -> Result<&[u8], Error> {
+    loop {
+        match rdr.fill_buf() {
+            Ok(_) => break,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(Error::io(err)),
+        }
+    }
+    rdr.fill_buf().map_err(Error::io)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    use super::*;
+
+    type Chunks = ChunkReader<vec::IntoIter<&'static [u8]>>;
+
+    /// A routine that parses a value from a stream reader, like
+    /// `StreamReader::parse_timestamp`.
+    type ParseFn<T> =
+        fn(&mut StreamReader<Chunks>) -> Result<Option<(T, usize)>, Error>;
+
+    /// Parses a value from the beginning of `input` and returns a
+    /// description of the result along with the unconsumed input.
+    ///
+    /// This also asserts that the result is the same regardless of how
+    /// `input` is split into chunks.
+    fn check<T: core::fmt::Debug>(
+        input: &'static str,
+        parse: ParseFn<T>,
+    ) -> String {
+        let run = |chunks: Vec<&'static [u8]>| {
+            let mut rdr = StreamReader::new(ChunkReader::new(chunks));
+            let result = match parse(&mut rdr) {
+                Ok(Some((value, n))) => format!("{value:?} ({n})"),
+                Ok(None) => "none".to_string(),
+                Err(err) => format!("error: {err}"),
+            };
+            let mut rest = String::new();
+            rdr.read_to_string(&mut rest).unwrap();
+            (result, rest)
+        };
+
+        // Error messages quote the input seen by the parser, which depends
+        // on how the input is split. So only compare whether there was an
+        // error.
+        let normalize = |(result, rest): (String, String)| {
+            if result.starts_with("error") {
+                ("error".to_string(), rest)
+            } else {
+                (result, rest)
+            }
+        };
+
+        let input = input.as_bytes();
+        let expected = run(vec![input]);
+        let normal = normalize(expected.clone());
+        for i in 0..=input.len() {
+            let (a, b) = input.split_at(i);
+            assert_eq!(normalize(run(vec![a, b])), normal, "split at {i}");
+        }
+        let bytewise = normalize(run(input.chunks(1).collect()));
+        assert_eq!(bytewise, normal, "bytewise");
+        format!("{} / rest: {:?}", expected.0, expected.1)
+    }
+
+    #[test]
+    fn ok_timestamp() {
+        let p = |input| check(input, StreamReader::parse_timestamp);
+
+        insta::assert_snapshot!(p("2024-06-15T07:00:00Z"), @r###"2024-06-15T07:00:00Z (20) / rest: """###);
+        insta::assert_snapshot!(p("2024-06-15T07:00:00.123456789Z tail"), @r###"2024-06-15T07:00:00.123456789Z (30) / rest: " tail""###);
+        insta::assert_snapshot!(p("2024-06-15T07:00:00+05:30\nnext"), @r###"2024-06-15T01:30:00Z (25) / rest: "\nnext""###);
+        insta::assert_snapshot!(p("20240615T070000Z"), @r###"2024-06-15T07:00:00Z (16) / rest: """###);
+        insta::assert_snapshot!(p("+002024-06-15T07Z"), @r###"2024-06-15T07:00:00Z (17) / rest: """###);
+        insta::assert_snapshot!(
+            p("2024-06-15T07:00:00-04[America/New_York][u-ca=iso8601]"),
+            @r###"2024-06-15T11:00:00Z (54) / rest: """###,
+        );
+    }
+
+    #[test]
+    fn err_timestamp() {
+        let p = |input| check(input, StreamReader::parse_timestamp);
+
+        insta::assert_snapshot!(p(""), @r###"none / rest: """###);
+        insta::assert_snapshot!(p("2024-06-15T07:0 oops"), @r###"error: failed to parse minute in time "07:0 oops": failed to parse "0 " as minute (a two digit integer): invalid digit, expected 0-9 but got   / rest: "2024-06-15T07:0 oops""###);
+        insta::assert_snapshot!(p("2024-06-15T07:00:00 oops"), @r###"error: failed to find offset component in "2024-06-15T07:00:00", which is required for parsing a timestamp / rest: "2024-06-15T07:00:00 oops""###);
+        insta::assert_snapshot!(p("2024-06-15x"), @r###"error: failed to find time component in "2024-06-15", which is required for parsing a timestamp / rest: "2024-06-15x""###);
+        insta::assert_snapshot!(p("garbage\n2024-06-15T07Z"), @r###"error: failed to parse year in date "garbage\n2024-06-15T07Z": failed to parse "garb" as year (a four digit integer): invalid digit, expected 0-9 but got g / rest: "garbage\n2024-06-15T07Z""###);
+    }
+
+    #[test]
+    fn civil() {
+        let date = |input| check(input, StreamReader::parse_date);
+        let datetime = |input| check(input, StreamReader::parse_datetime);
+        let time = |input| check(input, StreamReader::parse_time);
+
+        insta::assert_snapshot!(date("2024-06-15"), @r###"2024-06-15 (10) / rest: """###);
+        insta::assert_snapshot!(date("2024-06-15T07:00 foo"), @r###"2024-06-15 (16) / rest: " foo""###);
+        insta::assert_snapshot!(datetime("2024-06-15 07:30,GET"), @r###"2024-06-15T07:30:00 (16) / rest: ",GET""###);
+        insta::assert_snapshot!(datetime("2024-06-15T07:30:00.5Z\n"), @r###"error: cannot parse civil date from string with a Zulu offset, parse as a `Timestamp` and convert to a civil datetime instead / rest: "2024-06-15T07:30:00.5Z\n""###);
+        insta::assert_snapshot!(time("07:30:01.5 done"), @r###"07:30:01.5 (10) / rest: " done""###);
+        insta::assert_snapshot!(time("T0730|"), @r###"07:30:00 (5) / rest: "|""###);
+    }
+
+    #[test]
+    fn zoned() {
+        let p = |input| check(input, StreamReader::parse_zoned);
+
+        insta::assert_snapshot!(
+            p("2024-06-15T07[America/New_York] hello"),
+            @r###"2024-06-15T07:00:00-04:00[America/New_York] (31) / rest: " hello""###,
+        );
+        insta::assert_snapshot!(p("2024-06-15T07:00:00Z"), @r###"error: failed to find time zone in square brackets in "2024-06-15T07:00:00Z", which is required for parsing a zoned instant / rest: "2024-06-15T07:00:00Z""###);
+    }
+
+    #[test]
+    fn durations() {
+        let span = |input| check(input, StreamReader::parse_span);
+        let dur = |input| check(input, StreamReader::parse_duration);
+
+        insta::assert_snapshot!(span("P1Y2M3DT4H5M6.7S rest"), @r###"1y 2mo 3d 4h 5m 6s 700ms (16) / rest: " rest""###);
+        insta::assert_snapshot!(span("-PT1h30m"), @r###"1h 30m ago (8) / rest: """###);
+        insta::assert_snapshot!(span("PT1H3"), @r###"error: failed to parse ISO 8601 duration string into `Span`: expected to find time unit designator suffix (H, M or S), but found end of input / rest: "PT1H3""###);
+        insta::assert_snapshot!(span("P\n"), @r###"error: failed to parse ISO 8601 duration string into `Span`: found the start of a ISO 8601 duration string in "P\n", but did not find any units / rest: "P\n""###);
+        insta::assert_snapshot!(dur("PT0.25S;"), @r###"250ms (7) / rest: ";""###);
+        insta::assert_snapshot!(dur("P1D"), @r###"error: failed to parse ISO 8601 duration string into `SignedDuration`: parsing ISO 8601 duration into SignedDuration requires that the duration contain a time component and no components of days or greater / rest: "P1D""###);
+    }
+
+    #[test]
+    fn sequence() {
+        let input = "2024-06-15T07:00:00Z a\n2024-06-15T07:00:05Z b\n";
+        for chunk_len in 1..=input.len() {
+            let chunks = input.as_bytes().chunks(chunk_len);
+            let mut rdr = StreamReader::new(ChunkReader::new(chunks));
+            let mut got = vec![];
+            while let Some((ts, _)) = rdr.parse_timestamp().unwrap() {
+                let mut line = String::new();
+                rdr.read_line(&mut line).unwrap();
+                got.push((ts.to_string(), line));
+            }
+            assert_eq!(
+                got,
+                vec![
+                    ("2024-06-15T07:00:00Z".to_string(), " a\n".to_string()),
+                    ("2024-06-15T07:00:05Z".to_string(), " b\n".to_string()),
+                ],
+                "chunk length {chunk_len}",
+            );
+        }
+    }
+
+    #[test]
+    fn lookahead_limit() {
+        let input = format!("2024-06-15T07Z[{}]", "a".repeat(LOOKAHEAD));
+        let mut rdr = StreamReader::new(input.as_bytes());
+        assert!(rdr.parse_timestamp().is_err());
+        let mut rest = String::new();
+        rdr.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, input);
+    }
+}
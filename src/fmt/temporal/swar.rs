@@ -0,0 +1,166 @@
+/*!
+Word-at-a-time ("SWAR") routines for the fixed width parts of the Temporal
+format.
+
+The vast majority of datetimes parsed and printed in practice look like
+`2024-06-15T07:00:00Z`. The date and time components of such strings are
+each made up of three two digit numbers separated by a single byte, i.e.,
+`YY-MM-DD` (preceded by the century) and `HH:MM:SS`. Both of these fit
+exactly in a `u64`, which lets us validate and convert all of the digits
+with a handful of arithmetic operations instead of a byte-at-a-time loop.
+
+These routines are portable and don't require any target specific SIMD
+support. Callers must fall back to the general purpose parser or printer
+whenever these return `None`, which also takes care of producing good error
+messages.
+*/
+
+/// `0x01` repeated in every byte.
+const LO: u64 = 0x0101_0101_0101_0101;
+
+/// An ASCII `0` repeated in every byte.
+const ZEROS: u64 = 0x3030_3030_3030_3030;
+
+/// A mask selecting the bytes at offsets 2 and 5 in a little endian `u64`.
+///
+/// These are the separator positions in `YY-MM-DD` and `HH:MM:SS`.
+const SEPARATORS: u64 = 0x0000_FF00_00FF_0000;
+
+/// A mask selecting the low nibble of the bytes at offsets 0, 3 and 6.
+///
+/// These are the positions of the "tens" digit of each two digit number
+/// when formatting with `format_triple`.
+const TENS: u64 = 0x000F_0000_0F00_000F;
+
+/// Parses three two digit numbers separated by `sep` from the first eight
+/// bytes of `input`.
+///
+/// For example, `07:30:59` with a `sep` of `:` returns `[7, 30, 59]`. No
+/// range checks are performed on the numbers returned, other than each being
+/// in the range `0..=99`.
+///
+/// This returns `None` if `input` is too short, if any of the digits are not
+/// ASCII digits or if either separator is not `sep`.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+pub(super) fn parse_triple(input: &[u8], sep: u8) -> Option<[u8; 3]> {
+    let chunk: [u8; 8] = input.get(..8)?.try_into().ok()?;
+    let word = u64::from_le_bytes(chunk);
+    if word & SEPARATORS != (u64::from(sep) * LO) & SEPARATORS {
+        return None;
+    }
+    // Replace both separators with `0` so that we can check every byte in
+    // the word for being an ASCII digit at once.
+    let word = (word & !SEPARATORS) | (ZEROS & SEPARATORS);
+    if !all_digits(word) {
+        return None;
+    }
+    let d = (word - ZEROS).to_le_bytes();
+    Some([d[0] * 10 + d[1], d[3] * 10 + d[4], d[6] * 10 + d[7]])
+}
+
+/// Parses a two digit number from the first two bytes of `input`.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+pub(super) fn parse_pair(input: &[u8]) -> Option<u8> {
+    let &[tens, ones] = input.get(..2)? else { return None };
+    if !tens.is_ascii_digit() || !ones.is_ascii_digit() {
+        return None;
+    }
+    Some((tens - b'0') * 10 + (ones - b'0'))
+}
+
+/// Formats three numbers, each in the range `0..=99`, as zero padded two
+/// digit numbers separated by `sep`.
+///
+/// For example, `[7, 30, 59]` with a `sep` of `:` returns `07:30:59`.
+///
+/// Callers must ensure each number is less than `100`. Otherwise, the
+/// output is unspecified (but memory safe).
+#[cfg_attr(feature = "perf-inline", inline(always))]
+pub(super) fn format_triple(nums: [u8; 3], sep: u8) -> [u8; 8] {
+    debug_assert!(nums.iter().all(|&n| n < 100));
+    // Put each number in the byte where its tens digit should go. Since each
+    // number is less than 100, its product with 103 below fits in 14 bits,
+    // and so never overlaps with its neighbors.
+    let word = u64::from(nums[0])
+        | (u64::from(nums[1]) << 24)
+        | (u64::from(nums[2]) << 48);
+    // For all `n` in `0..=99`, `(n * 103) >> 10 == n / 10`. Shifting the
+    // entire word to the right moves the "fractional" bits of each product
+    // into the bytes below it, which `TENS` then masks away.
+    let tens = ((word * 103) >> 10) & TENS;
+    let ones = word - tens * 10;
+    let digits = tens | (ones << 8);
+    let ascii = digits | (ZEROS & !SEPARATORS);
+    (ascii | ((u64::from(sep) * LO) & SEPARATORS)).to_le_bytes()
+}
+
+/// Formats a number in the range `0..=99` as a zero padded two digit number.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+pub(super) fn format_pair(num: u8) -> [u8; 2] {
+    debug_assert!(num < 100);
+    [b'0' + num / 10, b'0' + num % 10]
+}
+
+/// Returns true when every byte in `word` is an ASCII digit.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+fn all_digits(word: u64) -> bool {
+    const HI: u64 = 0xF0F0_F0F0_F0F0_F0F0;
+    // Every byte must be in `0x30..=0x3F`, and adding 6 must not carry
+    // into the high nibble (which would mean the byte is above `0x39`).
+    // Since the high nibble of each byte is known to be `3` before adding 6,
+    // the addition can't carry into the next byte.
+    word & HI == ZEROS && (word + 6 * LO) & HI == ZEROS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_triple_exhaustive_numbers() {
+        for a in 0..=99u8 {
+            for b in 0..=99u8 {
+                for c in [0, 1, 9, 10, 59, 60, 99] {
+                    let s = format_triple([a, b, c], b':');
+                    assert_eq!(parse_triple(&s, b':'), Some([a, b, c]));
+                    let expected = alloc::format!("{a:02}:{b:02}:{c:02}");
+                    assert_eq!(&s[..], expected.as_bytes());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_triple_rejects() {
+        assert_eq!(parse_triple(b"07:30:5", b':'), None);
+        assert_eq!(parse_triple(b"07-30:59", b':'), None);
+        assert_eq!(parse_triple(b"07:30-59", b':'), None);
+        assert_eq!(parse_triple(b"07:30:59", b'-'), None);
+        assert_eq!(parse_triple(b"0730:59:", b':'), None);
+        assert_eq!(parse_triple(b"07:3a:59", b':'), None);
+        assert_eq!(parse_triple(b"07:30:5:", b':'), None);
+        assert_eq!(parse_triple(b"/7:30:59", b':'), None);
+        assert_eq!(parse_triple(b"07:30:5\xFF", b':'), None);
+
+        // Every single byte substitution of a non-digit for a digit must be
+        // rejected.
+        for i in [0, 1, 3, 4, 6, 7] {
+            for byte in 0..=255u8 {
+                let mut s = *b"12:34:56";
+                s[i] = byte;
+                let got = parse_triple(&s, b':');
+                assert_eq!(got.is_some(), byte.is_ascii_digit(), "{s:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn pair() {
+        for n in 0..=99u8 {
+            assert_eq!(parse_pair(&format_pair(n)), Some(n));
+        }
+        assert_eq!(parse_pair(b"1"), None);
+        assert_eq!(parse_pair(b"1:"), None);
+        assert_eq!(parse_pair(b":1"), None);
+    }
+}
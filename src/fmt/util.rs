@@ -1,6 +1,7 @@
 use crate::{
     error::{err, ErrorContext},
     fmt::Parsed,
+    tz::Offset,
     util::{
         escape, parse,
         rangeint::RFrom,
@@ -176,12 +177,13 @@ impl Decimal {
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct FractionalFormatter {
     precision: Option<u8>,
+    minimum: u8,
 }
 
 impl FractionalFormatter {
     /// Creates a new fractional formatter using the given precision settings.
     pub(crate) const fn new() -> FractionalFormatter {
-        FractionalFormatter { precision: None }
+        FractionalFormatter { precision: None, minimum: 0 }
     }
 
     /// Format the given value using this configuration as a decimal ASCII
@@ -208,13 +210,26 @@ impl FractionalFormatter {
         FractionalFormatter { precision, ..self }
     }
 
+    /// Set the minimum number of digits to write when the precision is not
+    /// set.
+    ///
+    /// If the `minimum` is greater than `9`, then it is clamped to `9`.
+    ///
+    /// This has no effect when the precision is set, since that always
+    /// determines the number of digits written exactly.
+    pub(crate) const fn minimum(self, minimum: u8) -> FractionalFormatter {
+        let minimum = if minimum > 9 { 9 } else { minimum };
+        FractionalFormatter { minimum, ..self }
+    }
+
     /// Returns true if and only if at least one digit will be written for the
     /// given value.
     ///
     /// This is useful for callers that need to know whether to write
     /// a decimal separator, e.g., `.`, before the digits.
     pub(crate) fn will_write_digits(self, value: i64) -> bool {
-        self.precision.map_or_else(|| value != 0, |p| p > 0)
+        self.precision
+            .map_or_else(|| value != 0 || self.minimum > 0, |p| p > 0)
     }
 
     /// Returns true if and only if this formatter has an explicit non-zero
@@ -250,9 +265,9 @@ impl Fractional {
     ///
     /// Note that the fractional number returned *may* expand to an empty
     /// slice of bytes. This occurs whenever the precision is set to `0`, or
-    /// when the precision is not set and the value is `0`. Any non-zero
-    /// explicitly set precision guarantees that the slice returned is not
-    /// empty.
+    /// when neither the precision nor a minimum is set and the value is `0`.
+    /// Any non-zero explicitly set precision (or minimum, when the precision
+    /// isn't set) guarantees that the slice returned is not empty.
     ///
     /// This panics if the value given isn't in the range `0..=999_999_999`.
     pub(crate) const fn new(
@@ -278,7 +293,7 @@ impl Fractional {
         if let Some(precision) = formatter.precision {
             fractional.end = precision;
         } else {
-            while fractional.end > 0
+            while fractional.end > formatter.minimum
                 && fractional.buf[fractional.end as usize - 1] == b'0'
             {
                 fractional.end -= 1;
@@ -574,6 +589,28 @@ pub(crate) fn fractional_time_to_duration(
     Ok(SignedDuration::from_nanos(nanos.get()))
 }
 
+/// Returns the offset for a single letter military time zone.
+///
+/// The letter is matched without regard to ASCII case. `Z` is UTC, `A`
+/// through `I` are `+01` through `+09`, `K` through `M` are `+10` through
+/// `+12` and `N` through `Y` are `-01` through `-12`. `J` (local time) and
+/// anything else return `None`.
+///
+/// These are the definitions originally given in RFC 822. Note that RFC 2822
+/// recommends treating them as `-0000` since RFC 822 got the signs backwards
+/// relative to common practice, so callers should only use this when the
+/// original definitions are known to be intended.
+pub(crate) fn military_time_zone_offset(letter: u8) -> Option<Offset> {
+    let hours = match letter.to_ascii_uppercase() {
+        b'Z' => 0,
+        letter @ b'A'..=b'I' => (letter - b'A' + 1) as i8,
+        letter @ b'K'..=b'M' => (letter - b'K' + 10) as i8,
+        letter @ b'N'..=b'Y' => -((letter - b'N' + 1) as i8),
+        _ => return None,
+    };
+    Some(Offset::constant(hours))
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
@@ -659,4 +696,20 @@ mod tests {
         assert_eq!(f(2, 889_000_000), "88");
         assert_eq!(f(2, 999_000_000), "99");
     }
+
+    #[test]
+    fn military_time_zones() {
+        let offset = military_time_zone_offset;
+
+        assert_eq!(offset(b'Z'), Some(Offset::UTC));
+        assert_eq!(offset(b'z'), Some(Offset::UTC));
+        assert_eq!(offset(b'A'), Some(Offset::constant(1)));
+        assert_eq!(offset(b'I'), Some(Offset::constant(9)));
+        assert_eq!(offset(b'K'), Some(Offset::constant(10)));
+        assert_eq!(offset(b'm'), Some(Offset::constant(12)));
+        assert_eq!(offset(b'N'), Some(Offset::constant(-1)));
+        assert_eq!(offset(b'Y'), Some(Offset::constant(-12)));
+        assert_eq!(offset(b'J'), None);
+        assert_eq!(offset(b'1'), None);
+    }
 }
@@ -0,0 +1,546 @@
+/*!
+Fast validity checks for datetime and duration strings.
+
+The routines in this module report whether a string is valid in a particular
+format. They don't construct a Jiff datetime or duration, and they don't
+construct an error when the string is invalid. This makes them useful in
+contexts where only a yes or no answer is needed and where invalid inputs may
+be common, such as schema validators.
+
+Each routine checks both syntax and ranges. For example, `2023-02-29` is
+rejected since 2023 isn't a leap year. Moreover, whenever a routine returns
+`true`, the corresponding Jiff parser is guaranteed to succeed on the same
+input. Each routine documents which parser that is.
+
+If you need the parsed value anyway, then you should parse it directly instead
+of validating it first.
+
+# Example
+
+```
+use jiff::fmt::validate;
+
+assert!(validate::is_valid_rfc3339("2024-06-15T07:00:00-04:00"));
+assert!(!validate::is_valid_rfc3339("2024-06-31T07:00:00-04:00"));
+assert!(!validate::is_valid_rfc3339("2024-06-15T07:00:00"));
+
+assert!(validate::is_valid_rfc3339_date("2024-02-29"));
+assert!(!validate::is_valid_rfc3339_date("2023-02-29"));
+
+assert!(validate::is_valid_iso8601_duration("P1Y2M3DT4H5M6.789S"));
+assert!(!validate::is_valid_iso8601_duration("P1D2Y"));
+```
+*/
+
+use crate::{civil, shared::util::itime, tz::Offset, util::t};
+
+/// Returns true if the given input is a valid [RFC 3339] timestamp.
+///
+/// That is, a date, a `T` (or `t`, or a space) separator, a time with
+/// seconds and an optional fraction, and an offset, like
+/// `2024-06-15T07:00:00.123-04:00` or `2024-06-15T11:00:00Z`.
+///
+/// When this returns true, parsing the input as a [`Timestamp`] succeeds.
+/// Accordingly, this returns false for timestamps that RFC 3339 permits but
+/// Jiff doesn't support. Namely, fractional seconds with more than 9 digits,
+/// and timestamps after [`Timestamp::MAX`]. Leap seconds (a seconds value
+/// of `60`) are accepted, since Jiff parses them by clamping them to `59`.
+///
+/// Unlike Jiff's [Temporal](crate::fmt::temporal) parser, this doesn't
+/// accept any extensions to RFC 3339, such as RFC 9557 annotations, the ISO
+/// 8601 basic format or omitting the seconds.
+///
+/// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+/// [`Timestamp`]: crate::Timestamp
+/// [`Timestamp::MAX`]: crate::Timestamp::MAX
+///
+/// # Example
+///
+/// ```
+/// use jiff::fmt::validate::is_valid_rfc3339;
+///
+/// assert!(is_valid_rfc3339("2024-06-15T07:00:00-04:00"));
+/// assert!(is_valid_rfc3339("2024-06-15 11:00:00.123456789Z"));
+/// assert!(is_valid_rfc3339("2016-12-31T23:59:60Z"));
+///
+/// // An offset is required.
+/// assert!(!is_valid_rfc3339("2024-06-15T07:00:00"));
+/// // Seconds are required.
+/// assert!(!is_valid_rfc3339("2024-06-15T07:00Z"));
+/// // The date must exist.
+/// assert!(!is_valid_rfc3339("2024-06-31T07:00:00Z"));
+/// // And the offset must be less than 24 hours.
+/// assert!(!is_valid_rfc3339("2024-06-15T07:00:00+24:00"));
+/// ```
+pub fn is_valid_rfc3339<I: AsRef<[u8]>>(input: I) -> bool {
+    rfc3339(input.as_ref()).is_some()
+}
+
+/// Returns true if the given input is a valid [RFC 3339] date.
+///
+/// That is, a date like `2024-06-15`. RFC 3339 calls this a `full-date`.
+///
+/// When this returns true, parsing the input as a [`civil::Date`]
+/// succeeds.
+///
+/// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+///
+/// # Example
+///
+/// ```
+/// use jiff::fmt::validate::is_valid_rfc3339_date;
+///
+/// assert!(is_valid_rfc3339_date("2024-06-15"));
+/// assert!(is_valid_rfc3339_date("2024-02-29"));
+///
+/// assert!(!is_valid_rfc3339_date("2023-02-29"));
+/// assert!(!is_valid_rfc3339_date("20240615"));
+/// assert!(!is_valid_rfc3339_date("2024-06-15T00:00:00Z"));
+/// ```
+pub fn is_valid_rfc3339_date<I: AsRef<[u8]>>(input: I) -> bool {
+    matches!(full_date(input.as_ref()), Some((_, rest)) if rest.is_empty())
+}
+
+/// Returns true if the given input is a valid [ISO 8601] duration.
+///
+/// That is, an optional sign, a `P` designator and then one or more units
+/// written from largest to smallest, like `P1Y2M3DT4H5M6.789S`, `P2W` or
+/// `-PT30M`. The last unit may have a fractional component, like `PT1.5H`
+/// or `P1.5D`. Fractional years must correspond to a whole number of months
+/// (like `P1.5Y`), and months can't be fractional. Designators are case
+/// insensitive.
+///
+/// When this returns true, parsing the input as a [`Span`] with
+/// [`SpanParser::parse_span`] succeeds. Accordingly, each unit value must be
+/// within the limits of a `Span`. For example, `P19999Y` is rejected since a
+/// span can have at most `19,998` years.
+///
+/// This doesn't accept some extensions to ISO 8601 durations that Jiff's
+/// parser supports. Namely, time units that exceed the limits of a `Span`
+/// on their own (which Jiff parses by spreading them over smaller units),
+/// and the "alternative" format (like `P0001-02-03T04:05:06`).
+///
+/// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+/// [`Span`]: crate::Span
+/// [`SpanParser::parse_span`]: crate::fmt::temporal::SpanParser::parse_span
+///
+/// # Example
+///
+/// ```
+/// use jiff::fmt::validate::is_valid_iso8601_duration;
+///
+/// assert!(is_valid_iso8601_duration("P1Y2M3DT4H5M6S"));
+/// assert!(is_valid_iso8601_duration("pt1.5h"));
+/// assert!(is_valid_iso8601_duration("-P1W2D"));
+/// assert!(is_valid_iso8601_duration("P1.5D"));
+///
+/// // At least one unit is required.
+/// assert!(!is_valid_iso8601_duration("P"));
+/// assert!(!is_valid_iso8601_duration("P1DT"));
+/// // Units must be written from largest to smallest.
+/// assert!(!is_valid_iso8601_duration("PT1S1M"));
+/// // Only the last unit may be fractional.
+/// assert!(!is_valid_iso8601_duration("PT1.5H30M"));
+/// assert!(!is_valid_iso8601_duration("P0.5DT1H"));
+/// // Months can't be fractional.
+/// assert!(!is_valid_iso8601_duration("P1.5M"));
+/// // And units must fit in a span.
+/// assert!(!is_valid_iso8601_duration("P19999Y"));
+/// ```
+pub fn is_valid_iso8601_duration<I: AsRef<[u8]>>(input: I) -> bool {
+    duration(input.as_ref()).is_some()
+}
+
+/// Validates an RFC 3339 timestamp.
+///
+/// This returns `None` when the input is invalid, which permits the use of
+/// `?`. Otherwise, it returns `Some(())`.
+fn rfc3339(input: &[u8]) -> Option<()> {
+    let (date, input) = full_date(input)?;
+    let input = expect(input, |b| matches!(b, b'T' | b't' | b' '))?;
+    let (hour, input) = two_digits(input, 23)?;
+    let input = expect(input, |b| b == b':')?;
+    let (minute, input) = two_digits(input, 59)?;
+    let input = expect(input, |b| b == b':')?;
+    let (second, input) = two_digits(input, 60)?;
+    let (nanosecond, input) = match input.first() {
+        Some(&b'.') => fraction(&input[1..])?,
+        _ => (0, input),
+    };
+    let (&first, input) = input.split_first()?;
+    let (offset_seconds, input) = match first {
+        b'Z' | b'z' => (0, input),
+        b'+' | b'-' => {
+            let (hours, input) = two_digits(input, 23)?;
+            let input = expect(input, |b| b == b':')?;
+            let (minutes, input) = two_digits(input, 59)?;
+            let seconds = i32::from(hours) * 3600 + i32::from(minutes) * 60;
+            (if first == b'-' { -seconds } else { seconds }, input)
+        }
+        _ => return None,
+    };
+    if !input.is_empty() {
+        return None;
+    }
+    // Every RFC 3339 timestamp is within Jiff's supported range, except for
+    // those at the very end of the year 9999. So only pay for the precise
+    // check when it might matter.
+    if date.year() == 9999 {
+        let dt = date.at(hour, minute, second.min(59), nanosecond);
+        let offset = Offset::from_seconds(offset_seconds).ok()?;
+        offset.to_timestamp(dt).ok()?;
+    }
+    Some(())
+}
+
+/// Validates an RFC 3339 `full-date` at the beginning of the input.
+///
+/// On success, this returns the date along with the remaining input.
+fn full_date(input: &[u8]) -> Option<(civil::Date, &[u8])> {
+    let (hi, input) = two_digits(input, 99)?;
+    let (lo, input) = two_digits(input, 99)?;
+    let year = i16::from(hi) * 100 + i16::from(lo);
+    let input = expect(input, |b| b == b'-')?;
+    let (month, input) = two_digits(input, 12)?;
+    let input = expect(input, |b| b == b'-')?;
+    let (day, input) = two_digits(input, 31)?;
+    if month == 0 || day == 0 || day > itime::days_in_month(year, month) {
+        return None;
+    }
+    Some((civil::date(year, month, day), input))
+}
+
+/// Validates a duration in the ISO 8601 designator format.
+fn duration(input: &[u8]) -> Option<()> {
+    let input = match input.first() {
+        Some(&(b'+' | b'-')) => &input[1..],
+        _ => input,
+    };
+    let mut input = expect(input, |b| matches!(b, b'P' | b'p'))?;
+
+    // The largest unit permitted next. Units are numbered from smallest to
+    // largest, so that each unit must be strictly smaller than the last.
+    let mut next = 4;
+    let mut any = false;
+    while let Some(&first) = input.first() {
+        if matches!(first, b'T' | b't') {
+            break;
+        }
+        let (value, rest) = unit_value(input)?;
+        let (fraction, rest) = match rest.first() {
+            Some(&(b'.' | b',')) => {
+                let (nanos, rest) = fraction(&rest[1..])?;
+                (Some(nanos), rest)
+            }
+            _ => (None, rest),
+        };
+        let (&designator, rest) = rest.split_first()?;
+        let (unit, max) = match designator {
+            b'Y' | b'y' => (4, t::SpanYears::MAX),
+            b'M' | b'm' => (3, t::SpanMonths::MAX),
+            b'W' | b'w' => (2, t::SpanWeeks::MAX),
+            b'D' | b'd' => (1, t::SpanDays::MAX),
+            _ => return None,
+        };
+        if unit > next || i128::from(value) > max {
+            return None;
+        }
+        next = unit - 1;
+        any = true;
+        input = rest;
+        if let Some(nanos) = fraction {
+            // Like the parser, a fraction may only occur on the last unit,
+            // which rules out any time units too. Fractional years must
+            // correspond to a whole number of months, and months can't be
+            // fractional at all since they don't have a fixed length.
+            let ok = match unit {
+                4 => (i64::from(nanos) * 12) % 1_000_000_000 == 0,
+                3 => nanos == 0,
+                _ => true,
+            };
+            return (ok && input.is_empty()).then_some(());
+        }
+    }
+    let Some((_, mut input)) = input.split_first() else {
+        return any.then_some(());
+    };
+
+    let mut next = 3;
+    let mut any = false;
+    while !input.is_empty() {
+        let (value, rest) = unit_value(input)?;
+        let (fractional, rest) = match rest.first() {
+            Some(&(b'.' | b',')) => (true, fraction(&rest[1..])?.1),
+            _ => (false, rest),
+        };
+        let (&designator, rest) = rest.split_first()?;
+        let (unit, max) = match designator {
+            b'H' | b'h' => (3, t::SpanHours::MAX),
+            b'M' | b'm' => (2, t::SpanMinutes::MAX),
+            b'S' | b's' => (1, t::SpanSeconds::MAX),
+            _ => return None,
+        };
+        if unit > next || i128::from(value) > max {
+            return None;
+        }
+        next = unit - 1;
+        any = true;
+        input = rest;
+        // A fraction may only occur on the last unit.
+        if fractional && !input.is_empty() {
+            return None;
+        }
+    }
+    any.then_some(())
+}
+
+/// Parses the integer value of a duration unit.
+///
+/// Like Jiff's duration parser, this accepts at most 20 digits. The value
+/// returned saturates, which is fine since every unit's limit is far smaller
+/// than `u64::MAX`.
+fn unit_value(input: &[u8]) -> Option<(u64, &[u8])> {
+    let len = input.iter().take_while(|b| b.is_ascii_digit()).count();
+    if len == 0 || len > 20 {
+        return None;
+    }
+    let value = input[..len].iter().fold(0u64, |acc, &b| {
+        acc.saturating_mul(10).saturating_add(u64::from(b - b'0'))
+    });
+    Some((value, &input[len..]))
+}
+
+/// Parses the digits of a fraction following a decimal separator.
+///
+/// Between 1 and 9 digits are required. On success, the fraction is returned
+/// in units of nanoseconds.
+fn fraction(input: &[u8]) -> Option<(i32, &[u8])> {
+    let len = input.iter().take_while(|b| b.is_ascii_digit()).count();
+    if len == 0 || len > 9 {
+        return None;
+    }
+    let mut nanos = 0;
+    for i in 0..9 {
+        let digit = input.get(i).filter(|_| i < len).map_or(0, |&b| b - b'0');
+        nanos = nanos * 10 + i32::from(digit);
+    }
+    Some((nanos, &input[len..]))
+}
+
+/// Parses exactly two ASCII digits whose value is at most `max`.
+fn two_digits(input: &[u8], max: i8) -> Option<(i8, &[u8])> {
+    let &[hi, lo] = input.get(..2)? else { return None };
+    if !hi.is_ascii_digit() || !lo.is_ascii_digit() {
+        return None;
+    }
+    // Can't overflow since the value is at most 99.
+    let value = ((hi - b'0') * 10 + (lo - b'0')) as i8;
+    if value > max {
+        return None;
+    }
+    Some((value, &input[2..]))
+}
+
+/// Consumes a single byte matching the given predicate.
+fn expect(input: &[u8], predicate: impl Fn(u8) -> bool) -> Option<&[u8]> {
+    match input.split_first() {
+        Some((&b, rest)) if predicate(b) => Some(rest),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use crate::{civil::Date, fmt::temporal::SpanParser, Timestamp};
+
+    use super::*;
+
+    #[test]
+    fn ok_rfc3339() {
+        let inputs = [
+            "2024-06-15T07:00:00Z",
+            "2024-06-15t07:00:00z",
+            "2024-06-15 07:00:00-04:00",
+            "2024-06-15T07:00:00.1+05:30",
+            "2024-06-15T07:00:00.123456789-23:59",
+            "2016-12-31T23:59:60Z",
+            "0000-01-01T00:00:00+23:59",
+            "9999-12-30T22:00:00.999999999Z",
+            "9999-12-31T21:59:00+23:59",
+        ];
+        for input in inputs {
+            assert!(is_valid_rfc3339(input), "input: {input:?}");
+            assert!(input.parse::<Timestamp>().is_ok(), "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn err_rfc3339() {
+        let inputs = [
+            "",
+            "2024-06-15",
+            "2024-06-15T07:00Z",
+            "2024-06-15T07:00:00",
+            "2024-06-15T07:00:00.Z",
+            "2024-06-15T07:00:00.1234567890Z",
+            "2024-06-15T07:00:00,1Z",
+            "2024-06-15T07:00:00+05",
+            "2024-06-15T07:00:00+0530",
+            "2024-06-15T07:00:00+24:00",
+            "2024-06-15T07:00:00-04:00[America/New_York]",
+            "2024-06-15T07:00:00Z ",
+            "2024-06-15T24:00:00Z",
+            "2024-06-15T07:60:00Z",
+            "2024-06-15T07:00:61Z",
+            "2024-06-15X07:00:00Z",
+            "2024-6-15T07:00:00Z",
+            "20240615T070000Z",
+            "+002024-06-15T07:00:00Z",
+            "2024-02-30T07:00:00Z",
+            "9999-12-30T22:00:01Z",
+            "9999-12-31T23:59:59Z",
+            "9999-12-31T23:59:59+23:59",
+        ];
+        for input in inputs {
+            assert!(!is_valid_rfc3339(input), "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn rfc3339_date_matches_parser() {
+        for year in [0, 1, 1900, 2000, 2023, 2024, 9999] {
+            for month in 0..=13 {
+                for day in 0..=32 {
+                    let input = format!("{year:04}-{month:02}-{day:02}");
+                    assert_eq!(
+                        is_valid_rfc3339_date(&input),
+                        input.parse::<Date>().is_ok(),
+                        "input: {input:?}",
+                    );
+                    let input = format!("{input}T12:34:56.7+01:00");
+                    assert_eq!(
+                        is_valid_rfc3339(&input),
+                        input.parse::<Timestamp>().is_ok(),
+                        "input: {input:?}",
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ok_iso8601_duration() {
+        let inputs = [
+            "P1Y",
+            "p1y",
+            "+P1Y",
+            "-P1Y2M3W4DT5H6M7S",
+            "P0D",
+            "PT0S",
+            "PT1.5H",
+            "PT1H1.5M",
+            "PT1,5S",
+            "PT0.123456789S",
+            "P1YT1S",
+            "P1Y1D",
+            "P19998Y",
+            "P239976M",
+            "P1043497W",
+            "P7304484D",
+            "PT175307616H",
+            "PT175307616.999999999H",
+            "PT10518456960M",
+            "PT631107417600S",
+            "P00000000000000000001D",
+            "P1.5D",
+            "P1.25Y",
+            "P1M2,25D",
+        ];
+        for input in inputs {
+            assert!(is_valid_iso8601_duration(input), "input: {input:?}");
+            assert!(
+                SpanParser::new().parse_span(input).is_ok(),
+                "input: {input:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn err_iso8601_duration() {
+        let inputs = [
+            "",
+            "P",
+            "PT",
+            "P1DT",
+            "1D",
+            "P1",
+            "P1X",
+            "PD",
+            "P-1D",
+            "P1D1D",
+            "P1D1Y",
+            "P1W1M",
+            "PT1S1H",
+            "PT1M1M",
+            "P1H",
+            "PT1D",
+            "PT1.H",
+            "PT1.5H1M",
+            "PT0.1234567890S",
+            "P19999Y",
+            "P239977M",
+            "P1043498W",
+            "P7304485D",
+            "PT175307617H",
+            "PT10518456961M",
+            "PT631107417601S",
+            "P000000000000000000001D",
+            "P0001-02-03",
+            "P1D ",
+            "--P1D",
+        ];
+        for input in inputs {
+            assert!(!is_valid_iso8601_duration(input), "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn iso8601_duration_fractions_match_parser() {
+        let inputs = [
+            "P1.5Y",
+            "P1.25Y",
+            "P1.1Y",
+            "P1.0833333333Y",
+            "P19998.5Y",
+            "P1.5M",
+            "P1.0M",
+            "P1Y2,0M",
+            "P1.5W",
+            "P1.999999999W",
+            "P1043497.5W",
+            "P1.5D",
+            "-P0.123456789D",
+            "P1M2.25D",
+            "P1.D",
+            "P.5D",
+            "P1.5W2D",
+            "P1.5DT1H",
+            "P0.5DT1H",
+            "P1.5DT",
+            "P1.5D1H",
+            "PT1.5H",
+            "PT1.5H30M",
+            "PT1H30.5M",
+            "P1DT1.5S",
+        ];
+        for input in inputs {
+            assert_eq!(
+                is_valid_iso8601_duration(input),
+                SpanParser::new().parse_span(input).is_ok(),
+                "input: {input:?}",
+            );
+        }
+    }
+}
@@ -110,7 +110,8 @@ a link to a relevant issue (if one exists).
 
 * [Leap seconds]. (Jiff will automatically constrain times like `23:59:60` to
 `23:59:59`.)
-* Time scales other than Unix.
+* Time scales other than Unix. (The exception is the [`tai`] module, which
+provides opt-in conversions between UTC and TAI.)
 * [Calendars other than Gregorian].
 * [Localization].
 * [Changing the representation size, precision or limits on the minimum and
@@ -606,17 +607,71 @@ For more, see the [`fmt::serde`] sub-module. (This requires enabling Jiff's
   significantly degraded. Also, the sizes of some types get bigger. If you
   have use cases for Jiff in a no-std and no-alloc context, I would love
   feedback on the issue tracker about your use cases.
+* **arbitrary** -
+  When enabled, Jiff's datetime, span, offset and duration types implement
+  the [`arbitrary`](https://docs.rs/arbitrary) crate's `Arbitrary` trait.
+  Only valid values within Jiff's supported ranges are generated, which makes
+  it possible for fuzz targets to take Jiff types directly.
+* **chrono** -
+  When enabled, [`civil::Date`], [`civil::Time`], [`civil::DateTime`],
+  [`Timestamp`], [`Zoned`] and [`SignedDuration`] have `From` or `TryFrom`
+  conversions to and from `NaiveDate`, `NaiveTime`, `NaiveDateTime`,
+  `DateTime<Utc>`, `DateTime<FixedOffset>` and `TimeDelta` (also known as
+  `chrono::Duration`) from the [`chrono`](https://docs.rs/chrono) crate,
+  respectively. This is intended to make it easier to migrate a large
+  codebase from `chrono` to Jiff incrementally. Note that converting a
+  `Zoned` to a `DateTime<FixedOffset>` discards its time zone rules, and
+  that leap seconds in `chrono` values are clamped to the 59th second, just
+  like when Jiff parses a leap second.
 * **logging** -
   When enabled, the `log` crate is used to emit messages where appropriate.
   Generally speaking, this is reserved for system interaction points, such as
   finding the system copy of the Time Zone Database or finding the system's
   default time zone.
+* **prost** -
+  When enabled, [`Timestamp`] and [`SignedDuration`] have `TryFrom`
+  conversions to and from the protobuf well-known `Timestamp` and `Duration`
+  types provided by the [`prost-types`](https://docs.rs/prost-types) crate.
+  The conversions check the ranges and sign conventions required by the
+  protobuf specification.
 * **serde** -
   When enabled, all of the datetime and span types in Jiff implement
   serde's `Serialize` and `Deserialize` traits. The format used is specified by
   Temporal, but it's a mix of the "best" parts of RFC 3339, RFC 9557 and
   ISO 8601. See the [`fmt::temporal`] module for more details on the format
   used.
+* **rand** -
+  When enabled, [`Timestamp`], [`civil::Date`] and [`Zoned`] implement
+  `SampleUniform` from the [`rand`](https://docs.rs/rand) crate. This makes
+  it possible to sample values uniformly at random from a range, for example
+  with `rand::RngExt::random_range`. A sampled `Zoned` is always in the time
+  zone of the range's lower bound. Note that `rand` requires a newer version
+  of Rust than Jiff's minimum supported Rust version.
+* **rkyv** -
+  When enabled, Jiff's datetime, span and offset types implement `rkyv`'s
+  `Archive`, `Serialize` and `Deserialize` traits for zero-copy
+  serialization. See the [`archive`] module for more details. Note that
+  `rkyv` requires a newer version of Rust than Jiff's minimum supported Rust
+  version.
+* **time** -
+  When enabled, [`civil::Date`], [`civil::Time`], [`civil::DateTime`],
+  [`Timestamp`], [`Zoned`] and [`SignedDuration`] have `From` or `TryFrom`
+  conversions to and from `Date`, `Time`, `PrimitiveDateTime`,
+  `OffsetDateTime` and `Duration` from the [`time`](https://docs.rs/time)
+  crate. Since `OffsetDateTime` only carries a fixed offset, converting a
+  `Zoned` to it discards its time zone rules.
+* **utoipa** -
+  When enabled, the datetime, span and duration types that implement serde's
+  traits also implement `utoipa`'s `PartialSchema` and `ToSchema` traits.
+  This lets them be used directly in types deriving `utoipa::ToSchema`. The
+  schemas describe the string formats used by the serde integration. Note
+  that `utoipa` requires a newer version of Rust than Jiff's minimum
+  supported Rust version.
+* **testing** -
+  When enabled, the [`testing`] module becomes available. It provides
+  reusable invariant checks (like round-trip and rounding checks) that crates
+  wrapping or converting Jiff's types can use in their own test suites. This
+  also enables `alloc`.
 * **js** -
   On _only_ the `wasm32-unknown-unknown` and `wasm64-unknown-unknown` targets,
   the `js` feature will add dependencies on `js-sys` and `wasm-bindgen`.
@@ -629,6 +684,15 @@ For more, see the [`fmt::serde`] sub-module. (This requires enabling Jiff's
   (for example, when using `wasm-pack`). Only binary, tests and benchmarks
   should enable this feature. See
   [Platform support](crate::_documentation::platform) for more details.
+* **wasm** -
+  On _only_ the `wasm32-unknown-unknown` and `wasm64-unknown-unknown` targets,
+  the `wasm` feature enables the `jiff::wasm` module. It provides
+  conversions between [`Timestamp`] and [`Zoned`] and JavaScript's `Date`,
+  a way to get the time zone reported by the host's `Intl` API and
+  conversions to and from objects in JavaScript's Temporal API (where the
+  host supports it). Unlike the `js` feature, this doesn't change how Jiff
+  determines the current datetime or time zone, so it's fine for libraries
+  to enable this feature.
 
 ### Time zone features
 
@@ -743,20 +807,38 @@ pub use crate::{
     error::Error,
     signed_duration::{SignedDuration, SignedDurationRound},
     span::{
-        Span, SpanArithmetic, SpanCompare, SpanFieldwise, SpanRelativeTo,
-        SpanRound, SpanTotal, ToSpan, Unit,
+        Span, SpanArithmetic, SpanCompare, SpanDebugVerbose, SpanFieldwise,
+        SpanRelativeTo, SpanRound, SpanTotal, ToSpan, Unit,
     },
     timestamp::{
         Timestamp, TimestampArithmetic, TimestampDifference,
-        TimestampDisplayWithOffset, TimestampRound, TimestampSeries,
+        TimestampDisplayWithOffset, TimestampMillis, TimestampRound,
+        TimestampSeries,
     },
     util::round::mode::RoundMode,
-    zoned::{Zoned, ZonedArithmetic, ZonedDifference, ZonedRound, ZonedWith},
+    zoned::{
+        Zoned, ZonedArithmetic, ZonedDebugVerbose, ZonedDifference,
+        ZonedRound, ZonedWith,
+    },
 };
 
+#[cfg(feature = "alloc")]
+pub use crate::business::BusinessCalendar;
+#[cfg(feature = "std")]
+pub use crate::uptime::{Uptime, UptimeDisplay};
+#[cfg(feature = "rand")]
+pub use crate::{timestamp::TimestampUniform, zoned::ZonedUniform};
+
 #[macro_use]
 mod logging;
 
+#[cfg(feature = "__testing")]
+#[doc(hidden)]
+pub mod __testing;
+#[cfg(feature = "rkyv")]
+pub mod archive;
+#[cfg(feature = "alloc")]
+mod business;
 pub mod civil;
 mod duration;
 mod error;
@@ -767,9 +849,20 @@ mod now;
 pub mod shared;
 mod signed_duration;
 mod span;
+pub mod tai;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod timestamp;
 pub mod tz;
+#[cfg(feature = "std")]
+mod uptime;
 mod util;
+#[cfg(all(
+    feature = "wasm",
+    any(target_arch = "wasm32", target_arch = "wasm64"),
+    target_os = "unknown"
+))]
+pub mod wasm;
 mod zoned;
 
 /// Longer form documentation for Jiff.
@@ -132,6 +132,16 @@ pub struct TzifFixed<STR, ABBREV> {
     pub checksum: u32,
     pub designations: STR,
     pub posix_tz: Option<PosixTimeZone<ABBREV>>,
+    /// The Unix timestamp of the last transition recorded in the TZif data
+    /// itself, i.e., before any transitions were generated from the POSIX
+    /// time zone when fattening.
+    pub last_recorded_transition: Option<i64>,
+    /// The Unix timestamp at which the leap second table expires.
+    ///
+    /// This is only present in version 4 (or later) TZif data, where the
+    /// last leap second record may indicate an expiration time instead of a
+    /// leap second.
+    pub expiration: Option<i64>,
 }
 
 #[derive(Clone, Copy, Debug)]
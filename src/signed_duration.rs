@@ -1716,6 +1716,44 @@ impl SignedDuration {
         Duration::new(self.secs.unsigned_abs(), self.nanos.unsigned_abs())
     }
 
+    /// Converts this signed duration to a [`std::time::Duration`], where
+    /// negative durations are clamped to zero.
+    ///
+    /// This is useful for passing a duration as a timeout to APIs that only
+    /// accept a `std::time::Duration`, where a negative timeout is
+    /// semantically the same as a zero timeout. Unlike the `TryFrom`
+    /// conversion, this never fails: every non-negative signed duration is
+    /// representable as a `std::time::Duration`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use jiff::SignedDuration;
+    ///
+    /// let duration = SignedDuration::new(5, 123_456_789);
+    /// let expected = Duration::new(5, 123_456_789);
+    /// assert_eq!(duration.to_std_saturating(), expected);
+    ///
+    /// let duration = SignedDuration::from_millis(-1);
+    /// assert_eq!(duration.to_std_saturating(), Duration::ZERO);
+    ///
+    /// assert_eq!(
+    ///     SignedDuration::MAX.to_std_saturating(),
+    ///     Duration::new(i64::MAX as u64, 999_999_999),
+    /// );
+    /// assert_eq!(SignedDuration::MIN.to_std_saturating(), Duration::ZERO);
+    /// ```
+    #[inline]
+    pub const fn to_std_saturating(self) -> Duration {
+        if self.is_negative() {
+            Duration::ZERO
+        } else {
+            self.unsigned_abs()
+        }
+    }
+
     /// Returns this duration with its sign flipped.
     ///
     /// If this duration is zero, then this returns the duration unchanged.
@@ -1812,6 +1850,252 @@ impl SignedDuration {
     pub const fn is_negative(&self) -> bool {
         self.secs.is_negative() || self.nanos.is_negative()
     }
+
+    /// Returns the memory representation of this duration as a byte array in
+    /// big-endian (network) byte order.
+    ///
+    /// The layout is 12 bytes: the number of whole seconds as an `i64`
+    /// followed by the fractional nanoseconds as an `i32`, each in big-endian
+    /// byte order. That is, the values returned by
+    /// [`SignedDuration::as_secs`] and [`SignedDuration::subsec_nanos`].
+    /// Since the sign of both components always agrees, negative durations
+    /// have both components negative.
+    ///
+    /// This layout is stable and will not change in semver compatible
+    /// releases. It is intended for binary protocols. Use
+    /// [`SignedDuration::from_be_bytes`] to decode it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::SignedDuration;
+    ///
+    /// let duration = SignedDuration::new(1, 2);
+    /// assert_eq!(
+    ///     duration.to_be_bytes(),
+    ///     [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2],
+    /// );
+    /// assert_eq!(
+    ///     (-duration).to_be_bytes(),
+    ///     [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254],
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 12] {
+        join_bytes(self.secs.to_be_bytes(), self.nanos.to_be_bytes())
+    }
+
+    /// Returns the memory representation of this duration as a byte array in
+    /// little-endian byte order.
+    ///
+    /// The layout is 12 bytes: the number of whole seconds as an `i64`
+    /// followed by the fractional nanoseconds as an `i32`, each in
+    /// little-endian byte order. Note that only the bytes within each
+    /// component are reversed relative to [`SignedDuration::to_be_bytes`].
+    /// The seconds always come first.
+    ///
+    /// This layout is stable and will not change in semver compatible
+    /// releases. Use [`SignedDuration::from_le_bytes`] to decode it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::SignedDuration;
+    ///
+    /// let duration = SignedDuration::new(1, 2);
+    /// assert_eq!(
+    ///     duration.to_le_bytes(),
+    ///     [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0],
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 12] {
+        join_bytes(self.secs.to_le_bytes(), self.nanos.to_le_bytes())
+    }
+
+    /// Creates a signed duration from its memory representation as a byte
+    /// array in big-endian (network) byte order.
+    ///
+    /// This is the inverse of [`SignedDuration::to_be_bytes`]. See its
+    /// documentation for a description of the layout.
+    ///
+    /// If the signs of the seconds and nanoseconds differ, then they are
+    /// balanced in the same way as [`SignedDuration::new`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the absolute value of the nanoseconds is
+    /// greater than or equal to 1 second.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::SignedDuration;
+    ///
+    /// let bytes = [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2];
+    /// assert_eq!(
+    ///     SignedDuration::from_be_bytes(bytes)?,
+    ///     SignedDuration::new(1, 2),
+    /// );
+    ///
+    /// // Nanoseconds must be less than 1 second.
+    /// let bytes = [0, 0, 0, 0, 0, 0, 0, 1, 0x3B, 0x9A, 0xCA, 0x00];
+    /// assert!(SignedDuration::from_be_bytes(bytes).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_be_bytes(bytes: [u8; 12]) -> Result<SignedDuration, Error> {
+        let (secs, nanos) = split_bytes(bytes);
+        SignedDuration::from_byte_parts(
+            i64::from_be_bytes(secs),
+            i32::from_be_bytes(nanos),
+        )
+    }
+
+    /// Creates a signed duration from its memory representation as a byte
+    /// array in little-endian byte order.
+    ///
+    /// This is the inverse of [`SignedDuration::to_le_bytes`]. See its
+    /// documentation for a description of the layout.
+    ///
+    /// If the signs of the seconds and nanoseconds differ, then they are
+    /// balanced in the same way as [`SignedDuration::new`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the absolute value of the nanoseconds is
+    /// greater than or equal to 1 second.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::SignedDuration;
+    ///
+    /// let duration = SignedDuration::new(-5, -123_456_789);
+    /// let bytes = duration.to_le_bytes();
+    /// assert_eq!(SignedDuration::from_le_bytes(bytes)?, duration);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 12]) -> Result<SignedDuration, Error> {
+        let (secs, nanos) = split_bytes(bytes);
+        SignedDuration::from_byte_parts(
+            i64::from_le_bytes(secs),
+            i32::from_le_bytes(nanos),
+        )
+    }
+
+    /// Returns an order preserving encoding of this duration as a byte array.
+    ///
+    /// Comparing two encodings byte-wise (i.e., lexicographically) gives the
+    /// same result as comparing the durations themselves. This makes the
+    /// encoding suitable for use as a key in ordered key-value stores like
+    /// LMDB or RocksDB. Neither [`SignedDuration::to_be_bytes`] nor
+    /// [`SignedDuration::to_le_bytes`] have this property, since they encode
+    /// negative durations with their sign bit set.
+    ///
+    /// The layout is 12 bytes: the number of whole seconds as an `i64`
+    /// followed by the fractional nanoseconds as an `i32`, each in big-endian
+    /// byte order with its sign bit flipped. Big-endian byte order is required
+    /// because it puts the most significant byte first, which is what a
+    /// byte-wise comparison looks at first. (A little-endian encoding cannot
+    /// be sorted byte-wise.) Flipping the sign bit makes negative values sort
+    /// before positive values.
+    ///
+    /// This layout is stable and will not change in semver compatible
+    /// releases. Use [`SignedDuration::from_sortable_bytes`] to decode it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::SignedDuration;
+    ///
+    /// let duration = SignedDuration::new(1, 2);
+    /// assert_eq!(
+    ///     duration.to_sortable_bytes(),
+    ///     [0x80, 0, 0, 0, 0, 0, 0, 1, 0x80, 0, 0, 2],
+    /// );
+    ///
+    /// // Byte-wise order matches the order of durations.
+    /// let durations = [
+    ///     SignedDuration::MIN,
+    ///     SignedDuration::new(-1, -500_000_000),
+    ///     SignedDuration::new(0, -1),
+    ///     SignedDuration::ZERO,
+    ///     SignedDuration::new(0, 1),
+    ///     SignedDuration::new(1, 0),
+    ///     SignedDuration::MAX,
+    /// ];
+    /// for pair in durations.windows(2) {
+    ///     let (a, b) = (pair[0], pair[1]);
+    ///     assert!(a.to_sortable_bytes() < b.to_sortable_bytes());
+    /// }
+    /// ```
+    #[inline]
+    pub const fn to_sortable_bytes(self) -> [u8; 12] {
+        join_bytes(
+            (self.secs ^ i64::MIN).to_be_bytes(),
+            (self.nanos ^ i32::MIN).to_be_bytes(),
+        )
+    }
+
+    /// Creates a signed duration from its order preserving encoding as a byte
+    /// array.
+    ///
+    /// This is the inverse of [`SignedDuration::to_sortable_bytes`]. See its
+    /// documentation for a description of the layout.
+    ///
+    /// If the signs of the seconds and nanoseconds differ, then they are
+    /// balanced in the same way as [`SignedDuration::new`]. Note that such
+    /// values are never produced by [`SignedDuration::to_sortable_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the absolute value of the nanoseconds is
+    /// greater than or equal to 1 second.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::SignedDuration;
+    ///
+    /// let duration = SignedDuration::new(-5, -123_456_789);
+    /// let bytes = duration.to_sortable_bytes();
+    /// assert_eq!(SignedDuration::from_sortable_bytes(bytes)?, duration);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_sortable_bytes(
+        bytes: [u8; 12],
+    ) -> Result<SignedDuration, Error> {
+        let (secs, nanos) = split_bytes(bytes);
+        SignedDuration::from_byte_parts(
+            i64::from_be_bytes(secs) ^ i64::MIN,
+            i32::from_be_bytes(nanos) ^ i32::MIN,
+        )
+    }
+
+    /// Creates a signed duration from decoded seconds and nanoseconds,
+    /// returning an error if the nanoseconds don't fit in a single second.
+    #[inline]
+    fn from_byte_parts(
+        secs: i64,
+        nanos: i32,
+    ) -> Result<SignedDuration, Error> {
+        if !(-NANOS_PER_SEC < nanos && nanos < NANOS_PER_SEC) {
+            return Err(err!(
+                "failed to decode signed duration from bytes: \
+                 nanoseconds {nanos} must be in the range \
+                 -999999999..=999999999",
+            ));
+        }
+        // This never panics since the absolute value of `nanos` is less than
+        // a second.
+        Ok(SignedDuration::new(secs, nanos))
+    }
 }
 
 /// Additional APIs for computing the duration between date and time values.
@@ -2066,6 +2350,30 @@ impl SignedDuration {
         let options: SignedDurationRound = options.into();
         options.round(self)
     }
+
+    /// Format this duration as an [ISO 8601] duration string.
+    ///
+    /// This is equivalent to using the [`std::fmt::Display`] trait
+    /// implementation on `SignedDuration`, but makes the format explicit.
+    /// To configure how the duration is printed, use
+    /// [`temporal::SpanPrinter`].
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::SignedDuration;
+    ///
+    /// let dur = SignedDuration::new(5 * 60 * 60 + 30 * 60, 500_000_000);
+    /// assert_eq!(dur.to_iso8601(), "PT5H30M0.5S");
+    /// assert_eq!((-dur).to_iso8601(), "-PT5H30M0.5S");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_iso8601(&self) -> alloc::string::String {
+        temporal::DEFAULT_SPAN_PRINTER.duration_to_string(self)
+    }
 }
 
 impl core::fmt::Display for SignedDuration {
@@ -2146,6 +2454,123 @@ impl TryFrom<SignedDuration> for Duration {
     }
 }
 
+/// The largest absolute number of seconds permitted by the protobuf
+/// `Duration` type. This corresponds to approximately 10,000 years.
+#[cfg(feature = "prost")]
+const PROTOBUF_MAX_SECONDS: i64 = 315_576_000_000;
+
+/// Converts a Jiff signed duration to a protobuf duration.
+///
+/// This returns an error when the duration's magnitude exceeds the maximum
+/// supported by protobuf, which is `315,576,000,000.999999999` seconds.
+///
+/// Both `SignedDuration` and protobuf's `Duration` require the seconds and
+/// nanoseconds to have the same sign (or for one of them to be zero), so no
+/// adjustment of the components is needed.
+#[cfg(feature = "prost")]
+impl TryFrom<SignedDuration> for prost_types::Duration {
+    type Error = Error;
+
+    fn try_from(sd: SignedDuration) -> Result<prost_types::Duration, Error> {
+        let (seconds, nanos) = (sd.as_secs(), sd.subsec_nanos());
+        if !(-PROTOBUF_MAX_SECONDS..=PROTOBUF_MAX_SECONDS).contains(&seconds) {
+            return Err(err!(
+                "signed duration {sd:?} is outside the range supported by \
+                 protobuf durations",
+            ));
+        }
+        Ok(prost_types::Duration { seconds, nanos })
+    }
+}
+
+/// Converts a protobuf duration to a Jiff signed duration.
+///
+/// This returns an error when the protobuf duration is invalid. That is,
+/// when its nanoseconds are not in the range `-999_999_999..=999_999_999`,
+/// when its seconds and nanoseconds have different signs or when its
+/// magnitude exceeds `315,576,000,000.999999999` seconds.
+#[cfg(feature = "prost")]
+impl TryFrom<prost_types::Duration> for SignedDuration {
+    type Error = Error;
+
+    fn try_from(d: prost_types::Duration) -> Result<SignedDuration, Error> {
+        let prost_types::Duration { seconds, nanos } = d;
+        if !(-999_999_999..=999_999_999).contains(&nanos) {
+            return Err(err!(
+                "protobuf duration has invalid nanoseconds {nanos}, \
+                 expected a value in the range -999999999..=999999999",
+            ));
+        }
+        if (seconds < 0 && nanos > 0) || (seconds > 0 && nanos < 0) {
+            return Err(err!(
+                "protobuf duration has seconds {seconds} and nanoseconds \
+                 {nanos} with different signs",
+            ));
+        }
+        if !(-PROTOBUF_MAX_SECONDS..=PROTOBUF_MAX_SECONDS).contains(&seconds) {
+            return Err(err!(
+                "protobuf duration has seconds {seconds} outside the \
+                 supported range \
+                 -{PROTOBUF_MAX_SECONDS}..={PROTOBUF_MAX_SECONDS}",
+            ));
+        }
+        Ok(SignedDuration::new_unchecked(seconds, nanos))
+    }
+}
+
+/// Converts a Jiff signed duration to a `chrono` duration.
+///
+/// This returns an error when the duration's magnitude exceeds what is
+/// supported by `chrono::TimeDelta`, which is about `i64::MAX`
+/// milliseconds.
+#[cfg(feature = "chrono")]
+impl TryFrom<SignedDuration> for chrono::TimeDelta {
+    type Error = Error;
+
+    fn try_from(sd: SignedDuration) -> Result<chrono::TimeDelta, Error> {
+        let nanos = chrono::TimeDelta::nanoseconds(sd.subsec_nanos().into());
+        chrono::TimeDelta::try_seconds(sd.as_secs())
+            .and_then(|secs| secs.checked_add(&nanos))
+            .ok_or_else(|| {
+                err!(
+                    "signed duration {sd:?} is outside the range supported \
+                     by chrono durations",
+                )
+            })
+    }
+}
+
+/// Converts a `chrono` duration to a Jiff signed duration.
+///
+/// This never fails since `SignedDuration` supports a superset of the
+/// durations supported by `chrono::TimeDelta`.
+#[cfg(feature = "chrono")]
+impl From<chrono::TimeDelta> for SignedDuration {
+    fn from(td: chrono::TimeDelta) -> SignedDuration {
+        SignedDuration::new(td.num_seconds(), td.subsec_nanos())
+    }
+}
+
+/// Converts a Jiff signed duration to a `time` duration.
+///
+/// This never fails since both types have the same representation and range.
+#[cfg(feature = "time")]
+impl From<SignedDuration> for ::time::Duration {
+    fn from(sd: SignedDuration) -> ::time::Duration {
+        ::time::Duration::new(sd.as_secs(), sd.subsec_nanos())
+    }
+}
+
+/// Converts a `time` duration to a Jiff signed duration.
+///
+/// This never fails since both types have the same representation and range.
+#[cfg(feature = "time")]
+impl From<::time::Duration> for SignedDuration {
+    fn from(d: ::time::Duration) -> SignedDuration {
+        SignedDuration::new(d.whole_seconds(), d.subsec_nanoseconds())
+    }
+}
+
 impl From<Offset> for SignedDuration {
     fn from(offset: Offset) -> SignedDuration {
         SignedDuration::from_secs(i64::from(offset.seconds()))
@@ -2310,6 +2735,40 @@ impl<'de> serde::Deserialize<'de> for SignedDuration {
     }
 }
 
+#[cfg(feature = "utoipa")]
+impl utoipa::PartialSchema for SignedDuration {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::util::schema::string(
+            Some(utoipa::openapi::schema::KnownFormat::Duration),
+            "A signed duration, as an ISO 8601 duration in units of hours \
+             or smaller.",
+            "PT2H30M",
+        )
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl utoipa::ToSchema for SignedDuration {}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SignedDuration {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<SignedDuration> {
+        let secs: i64 = u.arbitrary()?;
+        let nanos: i32 = u.int_in_range(0..=NANOS_PER_SEC - 1)?;
+        // The nanoseconds must have the same sign as the seconds. When the
+        // seconds are zero, the sign is picked separately.
+        let negative = secs < 0 || (secs == 0 && bool::arbitrary(u)?);
+        let nanos = if negative { -nanos } else { nanos };
+        Ok(SignedDuration::new_unchecked(secs, nanos))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(i64, i32, bool)>::size_hint(depth)
+    }
+}
+
 /// Options for [`SignedDuration::round`].
 ///
 /// This type provides a way to configure the rounding of a duration. This
@@ -2469,6 +2928,12 @@ impl SignedDurationRound {
         self.smallest
     }
 
+    /// Returns the `increment` configuration.
+    #[cfg(feature = "testing")]
+    pub(crate) fn get_increment(&self) -> i64 {
+        self.increment
+    }
+
     /// Does the actual duration rounding.
     fn round(&self, dur: SignedDuration) -> Result<SignedDuration, Error> {
         if self.smallest > Unit::Hour {
@@ -2523,6 +2988,22 @@ impl From<(Unit, i64)> for SignedDurationRound {
     }
 }
 
+/// Joins the seconds and nanoseconds of an encoded signed duration into a
+/// single array.
+#[inline]
+const fn join_bytes(secs: [u8; 8], nanos: [u8; 4]) -> [u8; 12] {
+    let [s0, s1, s2, s3, s4, s5, s6, s7] = secs;
+    let [n0, n1, n2, n3] = nanos;
+    [s0, s1, s2, s3, s4, s5, s6, s7, n0, n1, n2, n3]
+}
+
+/// Splits an encoded signed duration into its seconds and nanoseconds.
+#[inline]
+const fn split_bytes(bytes: [u8; 12]) -> ([u8; 8], [u8; 4]) {
+    let [s0, s1, s2, s3, s4, s5, s6, s7, n0, n1, n2, n3] = bytes;
+    ([s0, s1, s2, s3, s4, s5, s6, s7], [n0, n1, n2, n3])
+}
+
 /// A common parsing function that works in bytes.
 ///
 /// Specifically, this parses either an ISO 8601 duration into a
@@ -2675,6 +3156,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bytes() {
+        for d in [
+            SignedDuration::ZERO,
+            SignedDuration::MIN,
+            SignedDuration::MAX,
+            SignedDuration::new(-1, -1),
+            SignedDuration::new(1, 999_999_999),
+        ] {
+            assert_eq!(
+                SignedDuration::from_be_bytes(d.to_be_bytes()).unwrap(),
+                d
+            );
+            assert_eq!(
+                SignedDuration::from_le_bytes(d.to_le_bytes()).unwrap(),
+                d
+            );
+            assert_eq!(
+                SignedDuration::from_sortable_bytes(d.to_sortable_bytes())
+                    .unwrap(),
+                d
+            );
+        }
+
+        // Mixed signs are balanced.
+        let bytes = join_bytes(5i64.to_be_bytes(), (-1i32).to_be_bytes());
+        assert_eq!(
+            SignedDuration::from_be_bytes(bytes).unwrap(),
+            SignedDuration::new(4, 999_999_999),
+        );
+
+        let bytes = join_bytes(0i64.to_le_bytes(), i32::MIN.to_le_bytes());
+        insta::assert_snapshot!(
+            SignedDuration::from_le_bytes(bytes).unwrap_err(),
+            @"failed to decode signed duration from bytes: nanoseconds -2147483648 must be in the range -999999999..=999999999",
+        );
+    }
+
     #[test]
     fn add_overflow() {
         let add = |(secs1, nanos1): (i64, i32),
@@ -2909,4 +3428,66 @@ mod tests {
         assert_eq!(x, y);
         assert_eq!(y.fract(), 0.0f32);
     }
+
+    #[cfg(feature = "prost")]
+    #[test]
+    fn prost_duration() {
+        let to = |sd: SignedDuration| prost_types::Duration::try_from(sd);
+        let from = |seconds, nanos| {
+            SignedDuration::try_from(prost_types::Duration { seconds, nanos })
+        };
+
+        let sd = SignedDuration::new(-1, -500_000_000);
+        let pd = to(sd).unwrap();
+        assert_eq!((pd.seconds, pd.nanos), (-1, -500_000_000));
+        assert_eq!(from(-1, -500_000_000).unwrap(), sd);
+        assert_eq!(from(0, -1).unwrap(), SignedDuration::from_nanos(-1));
+
+        let max = SignedDuration::new(315_576_000_000, 999_999_999);
+        assert_eq!(SignedDuration::try_from(to(max).unwrap()).unwrap(), max);
+        assert_eq!(SignedDuration::try_from(to(-max).unwrap()).unwrap(), -max);
+        assert!(to(SignedDuration::from_secs(315_576_000_001)).is_err());
+        assert!(to(SignedDuration::MIN).is_err());
+
+        assert!(from(1, -1).is_err());
+        assert!(from(-1, 1).is_err());
+        assert!(from(0, 1_000_000_000).is_err());
+        assert!(from(315_576_000_001, 0).is_err());
+        assert!(from(-315_576_000_001, 0).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_duration() {
+        let to = |sd: SignedDuration| chrono::TimeDelta::try_from(sd);
+
+        let sd = SignedDuration::new(-1, -500_000_000);
+        let td = to(sd).unwrap();
+        assert_eq!(td, chrono::TimeDelta::milliseconds(-1_500));
+        assert_eq!(SignedDuration::from(td), sd);
+
+        let max = SignedDuration::from(chrono::TimeDelta::MAX);
+        assert_eq!(to(max).unwrap(), chrono::TimeDelta::MAX);
+        let min = SignedDuration::from(chrono::TimeDelta::MIN);
+        assert_eq!(to(min).unwrap(), chrono::TimeDelta::MIN);
+        assert!(to(max + SignedDuration::from_nanos(1)).is_err());
+        assert!(to(min - SignedDuration::from_nanos(1)).is_err());
+        assert!(to(SignedDuration::MAX).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_duration() {
+        let sd = SignedDuration::new(-1, -500_000_000);
+        let d = ::time::Duration::from(sd);
+        assert_eq!(d, ::time::Duration::milliseconds(-1_500));
+        assert_eq!(SignedDuration::from(d), sd);
+
+        let d = ::time::Duration::from(SignedDuration::MAX);
+        assert_eq!(d, ::time::Duration::MAX);
+        assert_eq!(SignedDuration::from(d), SignedDuration::MAX);
+        let d = ::time::Duration::from(SignedDuration::MIN);
+        assert_eq!(d, ::time::Duration::MIN);
+        assert_eq!(SignedDuration::from(d), SignedDuration::MIN);
+    }
 }
@@ -5,7 +5,6 @@ use crate::{
     duration::{Duration, SDuration},
     error::{err, Error, ErrorContext},
     fmt::{friendly, temporal},
-    tz::TimeZone,
     util::{
         borrow::DumbCow,
         escape,
@@ -13,7 +12,7 @@ use crate::{
         round::increment,
         t::{self, Constant, NoUnits, NoUnits128, Sign, C},
     },
-    RoundMode, SignedDuration, Timestamp, Zoned,
+    RoundMode, SignedDuration, Zoned,
 };
 
 /// A macro helper, only used in tests, for comparing spans for equality.
@@ -37,7 +36,7 @@ pub(crate) use span_eq;
 /// Spans are used to as inputs to routines like
 /// [`Zoned::checked_add`] and [`Date::saturating_sub`],
 /// and are also outputs from routines like
-/// [`Timestamp::since`] and [`DateTime::until`].
+/// [`Timestamp::since`](crate::Timestamp::since) and [`DateTime::until`].
 ///
 /// # Range of spans
 ///
@@ -2328,8 +2327,8 @@ impl Span {
     /// convert a `Span` to a `SignedDuration`. Namely, by default:
     ///
     /// * [`Zoned::until`] guarantees that the biggest non-zero unit is hours.
-    /// * [`Timestamp::until`] guarantees that the biggest non-zero unit is
-    /// seconds.
+    /// * [`Timestamp::until`](crate::Timestamp::until) guarantees that the
+    /// biggest non-zero unit is seconds.
     /// * [`DateTime::until`] guarantees that the biggest non-zero unit is
     /// days.
     /// * [`Date::until`] guarantees that the biggest non-zero unit is days.
@@ -2422,6 +2421,74 @@ impl Span {
         Ok(relspan.span.to_duration_invariant())
     }
 
+    /// Converts a `Span` to a [`std::time::Duration`] suitable for use as a
+    /// timeout, relative to the date given.
+    ///
+    /// This is like [`Span::to_duration`], except negative spans are clamped
+    /// to zero and spans that would overflow Jiff's supported range when
+    /// added to the relative date given are clamped to
+    /// [`Duration::MAX`](std::time::Duration::MAX) (or zero, when the span is
+    /// negative). This is useful for passing a span to APIs that accept a
+    /// timeout, where a negative timeout is semantically the same as a zero
+    /// timeout and where a far away deadline is semantically the same as
+    /// waiting forever.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same circumstances as
+    /// [`Span::to_duration`] when the error isn't caused by overflow. For
+    /// example, when the span has non-zero units of months and
+    /// [`SpanRelativeTo::days_are_24_hours`] is given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use jiff::{civil::date, SpanRelativeTo, ToSpan};
+    ///
+    /// let day24 = SpanRelativeTo::days_are_24_hours();
+    ///
+    /// let span = 1.day().minutes(30);
+    /// let timeout = span.to_std_timeout(day24)?;
+    /// assert_eq!(timeout, Duration::from_secs(24 * 60 * 60 + 30 * 60));
+    ///
+    /// // Negative spans are clamped to zero.
+    /// let timeout = (-5).seconds().to_std_timeout(date(2024, 1, 1))?;
+    /// assert_eq!(timeout, Duration::ZERO);
+    ///
+    /// // Spans that go past the maximum supported datetime are clamped to
+    /// // the maximum duration.
+    /// let timeout = 19_998.years().to_std_timeout(date(2024, 1, 1))?;
+    /// assert_eq!(timeout, Duration::MAX);
+    ///
+    /// // Calendar units still require a relative date.
+    /// let span = 1.month();
+    /// assert!(span.to_std_timeout(day24).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_std_timeout<'a, R: Into<SpanRelativeTo<'a>>>(
+        &self,
+        relative: R,
+    ) -> Result<UnsignedDuration, Error> {
+        let max_unit = self.largest_unit();
+        let relative: SpanRelativeTo<'a> = relative.into();
+        let Some(relative) = relative.to_relative(max_unit)? else {
+            return Ok(self.to_duration_invariant().to_std_saturating());
+        };
+        // Once we have a relative datetime, the only way for this to fail is
+        // if adding this span to it overflows Jiff's supported range.
+        match relative.into_relative_span(Unit::Second, *self) {
+            Ok(relspan) => {
+                Ok(relspan.span.to_duration_invariant().to_std_saturating())
+            }
+            Err(_) if self.is_negative() => Ok(UnsignedDuration::ZERO),
+            Err(_) => Ok(UnsignedDuration::MAX),
+        }
+    }
+
     /// Converts an entirely invariant span to a `SignedDuration`.
     ///
     /// Callers must ensure that this span has no units greater than weeks.
@@ -2477,6 +2544,94 @@ impl Span {
         // 1_000_000_000.
         SignedDuration::new(seconds, subsec_nanos)
     }
+
+    /// Creates a span from the signed value of each of its units, from years
+    /// down to nanoseconds.
+    ///
+    /// This is the inverse of reading every unit via its getter, and is used
+    /// to reconstruct spans from compact binary representations.
+    ///
+    /// This returns an error if any value is out of range for its unit, or
+    /// if the values don't all have the same sign (ignoring zero values).
+    #[cfg(any(feature = "serde", feature = "rkyv"))]
+    pub(crate) fn try_from_units(units: [i64; 10]) -> Result<Span, Error> {
+        if units.iter().any(|&v| v > 0) && units.iter().any(|&v| v < 0) {
+            return Err(err!("units of a span must all have the same sign"));
+        }
+        let [y, mo, w, d, h, m, s, ms, us, ns] = units;
+        Span::new()
+            .try_years(y)?
+            .try_months(mo)?
+            .try_weeks(w)?
+            .try_days(d)?
+            .try_hours(h)?
+            .try_minutes(m)?
+            .try_seconds(s)?
+            .try_milliseconds(ms)?
+            .try_microseconds(us)?
+            .try_nanoseconds(ns)
+    }
+
+    /// Format this span as an [ISO 8601] duration string.
+    ///
+    /// This is equivalent to using the [`std::fmt::Display`] trait
+    /// implementation on `Span`, but makes the format explicit. To configure
+    /// how the span is printed, use [`temporal::SpanPrinter`]. For the
+    /// "friendly" format, use the alternate `Display` implementation (i.e.,
+    /// `{:#}`) or [`friendly::SpanPrinter`].
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::ToSpan;
+    ///
+    /// let span = 1.day().hours(5).minutes(30).milliseconds(500);
+    /// assert_eq!(span.to_iso8601(), "P1DT5H30M0.5S");
+    /// assert_eq!((-span).to_iso8601(), "-P1DT5H30M0.5S");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_iso8601(&self) -> alloc::string::String {
+        temporal::DEFAULT_SPAN_PRINTER.span_to_string(self)
+    }
+
+    /// Returns a value whose `Debug` implementation shows the value of every
+    /// unit on this span.
+    ///
+    /// The `Debug` implementation on `Span` uses the
+    /// ["friendly" duration format](crate::fmt::friendly), which omits units
+    /// with a zero value. This is instead a structured view that includes
+    /// every unit, which can be useful when debugging span arithmetic in
+    /// tests.
+    ///
+    /// The precise output of this routine is not a semver guarantee.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::ToSpan;
+    ///
+    /// let span = -1.day().hours(5).minutes(30);
+    /// assert_eq!(format!("{:#?}", span.debug_verbose()), r#"Span {
+    ///     sign: -1,
+    ///     years: 0,
+    ///     months: 0,
+    ///     weeks: 0,
+    ///     days: -1,
+    ///     hours: -5,
+    ///     minutes: -30,
+    ///     seconds: 0,
+    ///     milliseconds: 0,
+    ///     microseconds: 0,
+    ///     nanoseconds: 0,
+    /// }"#);
+    /// ```
+    #[inline]
+    pub fn debug_verbose(&self) -> SpanDebugVerbose {
+        SpanDebugVerbose { span: *self }
+    }
 }
 
 /// Crate internal APIs that operate on ranged integer types.
@@ -3648,6 +3803,20 @@ impl<'de> serde::Deserialize<'de> for Span {
     }
 }
 
+#[cfg(feature = "utoipa")]
+impl utoipa::PartialSchema for Span {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::util::schema::string(
+            Some(utoipa::openapi::schema::KnownFormat::Duration),
+            "A span of time, as an ISO 8601 duration.",
+            "P1Y2M3DT4H5M6.789S",
+        )
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl utoipa::ToSchema for Span {}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for Span {
     fn arbitrary(g: &mut quickcheck::Gen) -> Span {
@@ -3709,6 +3878,57 @@ impl quickcheck::Arbitrary for Span {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Span {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Span> {
+        // Every unit is generated independently within its own range, but
+        // all units in a span must have the same sign. So we generate their
+        // absolute values and then pick a sign for the entire span.
+        let span = Span::new()
+            .years_ranged(t::SpanYears::arbitrary(u)?.abs())
+            .months_ranged(t::SpanMonths::arbitrary(u)?.abs())
+            .weeks_ranged(t::SpanWeeks::arbitrary(u)?.abs())
+            .days_ranged(t::SpanDays::arbitrary(u)?.abs())
+            .hours_ranged(t::SpanHours::arbitrary(u)?.abs())
+            .minutes_ranged(t::SpanMinutes::arbitrary(u)?.abs())
+            .seconds_ranged(t::SpanSeconds::arbitrary(u)?.abs())
+            .milliseconds_ranged(t::SpanMilliseconds::arbitrary(u)?.abs())
+            .microseconds_ranged(t::SpanMicroseconds::arbitrary(u)?.abs())
+            .nanoseconds_ranged(t::SpanNanoseconds::arbitrary(u)?.abs());
+        Ok(if bool::arbitrary(u)? { span.negate() } else { span })
+    }
+}
+
+/// A structured `Debug` representation of a [`Span`] showing every unit.
+///
+/// This type is returned by [`Span::debug_verbose`]. See its documentation
+/// for more details.
+#[derive(Clone, Copy)]
+pub struct SpanDebugVerbose {
+    span: Span,
+}
+
+impl core::fmt::Debug for SpanDebugVerbose {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let span = &self.span;
+        f.debug_struct("Span")
+            .field("sign", &span.signum())
+            .field("years", &span.get_years())
+            .field("months", &span.get_months())
+            .field("weeks", &span.get_weeks())
+            .field("days", &span.get_days())
+            .field("hours", &span.get_hours())
+            .field("minutes", &span.get_minutes())
+            .field("seconds", &span.get_seconds())
+            .field("milliseconds", &span.get_milliseconds())
+            .field("microseconds", &span.get_microseconds())
+            .field("nanoseconds", &span.get_nanoseconds())
+            .finish()
+    }
+}
+
 /// A wrapper for [`Span`] that implements the `Hash`, `Eq` and `PartialEq`
 /// traits.
 ///
@@ -5247,7 +5467,10 @@ impl<'a> SpanRound<'a> {
     /// * `From<civil::DateTime> for SpanRelativeTo` uses a civil datetime. In
     /// this case, all days will be considered 24 hours long.
     /// * `From<civil::Date> for SpanRelativeTo` uses a civil date. In this
-    /// case, all days will be considered 24 hours long.
+    ///   case, all days will be considered 24 hours long. The date is treated
+    ///   as midnight on that day, but for spans and rounding that only use
+    ///   units of days or bigger, this is the same as doing date arithmetic.
+    ///   This works for any `civil::Date`, including the minimum and maximum.
     ///
     /// Note that one can impose 24-hour days without providing a reference
     /// date via [`SpanRelativeTo::days_are_24_hours`].
@@ -5258,6 +5481,29 @@ impl<'a> SpanRound<'a> {
     /// relative datetime is provided, then this configuration will lead to
     /// an error when used with [`Span::round`].
     ///
+    /// # Example: date-only rounding
+    ///
+    /// When a span only has units of days or bigger, a `civil::Date` is all
+    /// that is needed as a reference point:
+    ///
+    /// ```
+    /// use jiff::{civil::date, SpanRound, ToSpan, Unit};
+    ///
+    /// let span = 1.year().months(5).days(20);
+    /// let round = SpanRound::new()
+    ///     .smallest(Unit::Month)
+    ///     .relative(date(2024, 1, 15));
+    /// assert_eq!(span.round(round)?, 1.year().months(6).fieldwise());
+    ///
+    /// // This works even at the boundaries of what `Date` supports.
+    /// let round = SpanRound::new()
+    ///     .largest(Unit::Day)
+    ///     .relative(date(9999, 11, 30));
+    /// assert_eq!(1.month().round(round)?, 30.days().fieldwise());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
     /// # Example
     ///
     /// This example shows very precisely how a DST transition can impact
@@ -5519,9 +5765,10 @@ impl<'a> SpanRelativeTo<'a> {
     /// The purpose of the marker is two-fold:
     ///
     /// * Requiring the marker is important for improving the consistency of
-    /// `Span` APIs. Previously, some APIs (like [`Timestamp::checked_add`])
-    /// would always return an error if the `Span` given had non-zero
-    /// units of days or greater. On the other hand, other APIs (like
+    /// `Span` APIs. Previously, some APIs (like
+    /// [`Timestamp::checked_add`](crate::Timestamp::checked_add)) would
+    /// always return an error if the `Span` given had non-zero units of days
+    /// or greater. On the other hand, other APIs (like
     /// [`Span::checked_add`]) would autoamtically assume days were always
     /// 24 hours if no relative reference time was given and either span had
     /// non-zero units of days. With this marker, APIs _never_ assume days are
@@ -5687,7 +5934,7 @@ impl<'a> SpanRelativeTo<'a> {
         }
         match self.kind {
             SpanRelativeToKind::Civil(dt) => {
-                Ok(Some(Relative::Civil(RelativeCivil::new(dt)?)))
+                Ok(Some(Relative::Civil(RelativeCivil::new(dt))))
             }
             SpanRelativeToKind::Zoned(zdt) => {
                 Ok(Some(Relative::Zoned(RelativeZoned {
@@ -5947,7 +6194,7 @@ impl<'a> Relative<'a> {
     /// civil datetime minimum and maximum values.
     fn to_nanosecond(&self) -> NoUnits128 {
         match *self {
-            Relative::Civil(dt) => dt.timestamp.as_nanosecond_ranged().rinto(),
+            Relative::Civil(dt) => dt.nanosecond,
             Relative::Zoned(ref zdt) => {
                 zdt.zoned.timestamp().as_nanosecond_ranged().rinto()
             }
@@ -6027,10 +6274,9 @@ impl<'a> Relative<'a> {
                         mode,
                     )?
                 } else {
-                    let relative_end = end.timestamp.as_nanosecond_ranged();
                     Nudge::relative_invariant(
                         relspan.span,
-                        relative_end.rinto(),
+                        end.nanosecond,
                         smallest,
                         largest,
                         increment,
@@ -6137,46 +6383,36 @@ impl<'a> RelativeSpanKind<'a> {
     }
 }
 
-/// A wrapper around a civil datetime and a timestamp corresponding to that
-/// civil datetime in UTC.
-///
-/// Haphazardly interpreting a civil datetime in UTC is an odd and *usually*
-/// incorrect thing to do. But the way we use it here is basically just to give
-/// it an "anchoring" point such that we can represent it using a single
-/// integer for rounding purposes. It is only used in a context *relative* to
-/// another civil datetime interpreted in UTC. In this fashion, the selection
-/// of UTC specifically doesn't really matter. We could use any time zone.
-/// (Although, it must be a time zone without any transitions, otherwise we
-/// could wind up with time zone aware results in a context where that would
-/// be unexpected since this is civil time.)
+/// A wrapper around a civil datetime and a nanosecond count corresponding to
+/// that civil datetime, where every day is exactly 24 hours.
+///
+/// The nanosecond count is what you'd get by interpreting the civil datetime
+/// in UTC, which is an odd and *usually* incorrect thing to do. But the way
+/// we use it here is basically just to give it an "anchoring" point such that
+/// we can represent it using a single integer for rounding purposes. It is
+/// only used in a context *relative* to another civil datetime anchored in
+/// the same way. In this fashion, the selection of UTC specifically doesn't
+/// really matter.
+///
+/// Note that we specifically don't use a `Timestamp` here, since the range
+/// of a `Timestamp` is smaller than the range of a civil datetime interpreted
+/// in UTC. Otherwise, using a civil date near the minimum or maximum as a
+/// relative reference would fail even when only date arithmetic is needed.
 #[derive(Clone, Copy, Debug)]
 struct RelativeCivil {
     datetime: DateTime,
-    timestamp: Timestamp,
+    nanosecond: NoUnits128,
 }
 
 impl RelativeCivil {
     /// Creates a new relative wrapper around the given civil datetime.
     ///
-    /// This wrapper bundles a timestamp for the given datetime by interpreting
-    /// it as being in UTC. This is an "odd" thing to do, but it's only used
-    /// in the context of determining the length of time between two civil
-    /// datetimes. So technically, any time zone without transitions could be
-    /// used.
-    ///
-    /// # Errors
-    ///
-    /// This returns an error if the datetime could not be converted to a
-    /// timestamp. This only occurs near the minimum and maximum civil datetime
-    /// values.
-    fn new(datetime: DateTime) -> Result<RelativeCivil, Error> {
-        let timestamp = datetime
-            .to_zoned(TimeZone::UTC)
-            .with_context(|| {
-                err!("failed to convert {datetime} to timestamp")
-            })?
-            .timestamp();
-        Ok(RelativeCivil { datetime, timestamp })
+    /// This wrapper bundles a nanosecond count for the given datetime by
+    /// treating every day as 24 hours long. This is an "odd" thing to do,
+    /// but it's only used in the context of determining the length of time
+    /// between two civil datetimes.
+    fn new(datetime: DateTime) -> RelativeCivil {
+        RelativeCivil { datetime, nanosecond: datetime.to_nanosecond() }
     }
 
     /// Returns the result of [`DateTime::checked_add`].
@@ -6185,21 +6421,11 @@ impl RelativeCivil {
     ///
     /// Returns an error in the same cases as `DateTime::checked_add`. That is,
     /// when adding the span to this zoned datetime would overflow.
-    ///
-    /// This also returns an error if the resulting datetime could not be
-    /// converted to a timestamp in UTC. This only occurs near the minimum and
-    /// maximum datetime values.
     fn checked_add(&self, span: Span) -> Result<RelativeCivil, Error> {
         let datetime = self.datetime.checked_add(span).with_context(|| {
             err!("failed to add {span} to {dt}", dt = self.datetime)
         })?;
-        let timestamp = datetime
-            .to_zoned(TimeZone::UTC)
-            .with_context(|| {
-                err!("failed to convert {datetime} to timestamp")
-            })?
-            .timestamp();
-        Ok(RelativeCivil { datetime, timestamp })
+        Ok(RelativeCivil::new(datetime))
     }
 
     /// Returns the result of [`DateTime::checked_add`] with an absolute
@@ -6209,10 +6435,6 @@ impl RelativeCivil {
     ///
     /// Returns an error in the same cases as `DateTime::checked_add`. That is,
     /// when adding the span to this zoned datetime would overflow.
-    ///
-    /// This also returns an error if the resulting datetime could not be
-    /// converted to a timestamp in UTC. This only occurs near the minimum and
-    /// maximum datetime values.
     fn checked_add_duration(
         &self,
         duration: SignedDuration,
@@ -6221,13 +6443,7 @@ impl RelativeCivil {
             self.datetime.checked_add(duration).with_context(|| {
                 err!("failed to add {duration:?} to {dt}", dt = self.datetime)
             })?;
-        let timestamp = datetime
-            .to_zoned(TimeZone::UTC)
-            .with_context(|| {
-                err!("failed to convert {datetime} to timestamp")
-            })?
-            .timestamp();
-        Ok(RelativeCivil { datetime, timestamp })
+        Ok(RelativeCivil::new(datetime))
     }
 
     /// Returns the result of [`DateTime::until`].
@@ -6581,16 +6797,18 @@ impl Nudge {
                         unit = unit.plural(),
                     )
                 })?;
-            let threshold = match relative.kind {
+            let threshold: NoUnits128 = match relative.kind {
                 RelativeSpanKind::Civil { ref start, .. } => {
-                    start.checked_add(span_end)?.timestamp
-                }
-                RelativeSpanKind::Zoned { ref start, .. } => {
-                    start.checked_add(span_end)?.zoned.timestamp()
+                    start.checked_add(span_end)?.nanosecond
                 }
+                RelativeSpanKind::Zoned { ref start, .. } => start
+                    .checked_add(span_end)?
+                    .zoned
+                    .timestamp()
+                    .as_nanosecond_ranged()
+                    .rinto(),
             };
-            let beyond =
-                self.rounded_relative_end - threshold.as_nanosecond_ranged();
+            let beyond = self.rounded_relative_end - threshold;
             if beyond == C(0) || beyond.signum() == sign {
                 balanced = span_end;
             } else {
@@ -6753,6 +6971,60 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn arbitrary_spans_are_valid() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        // A simple xorshift generator so that the inputs are deterministic.
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut data = [0u8; 64];
+        let mut negative = false;
+        for _ in 0..1_000 {
+            for chunk in data.chunks_mut(8) {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                chunk.copy_from_slice(&state.to_le_bytes());
+            }
+            let span = Span::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            negative |= span.is_negative();
+            let units = [
+                i64::from(span.get_years()),
+                i64::from(span.get_months()),
+                i64::from(span.get_weeks()),
+                i64::from(span.get_days()),
+                i64::from(span.get_hours()),
+                span.get_minutes(),
+                span.get_seconds(),
+                span.get_milliseconds(),
+                span.get_microseconds(),
+                span.get_nanoseconds(),
+            ];
+            let signum = i64::from(span.signum());
+            assert!(units.iter().all(|&v| v == 0 || v.signum() == signum));
+            // Rebuilding the span with the fallible setters checks that every
+            // unit is in range.
+            let got = Span::new()
+                .try_years(units[0])
+                .and_then(|s| s.try_months(units[1]))
+                .and_then(|s| s.try_weeks(units[2]))
+                .and_then(|s| s.try_days(units[3]))
+                .and_then(|s| s.try_hours(units[4]))
+                .and_then(|s| s.try_minutes(units[5]))
+                .and_then(|s| s.try_seconds(units[6]))
+                .and_then(|s| s.try_milliseconds(units[7]))
+                .and_then(|s| s.try_microseconds(units[8]))
+                .and_then(|s| s.try_nanoseconds(units[9]))
+                .unwrap();
+            assert_eq!(got.fieldwise(), span);
+        }
+        assert!(negative);
+
+        // Exhausted input is fine too.
+        assert!(Span::arbitrary(&mut Unstructured::new(&[])).is_ok());
+        assert!(Span::arbitrary(&mut Unstructured::new(&[0xFF; 64])).is_ok());
+    }
+
     #[test]
     fn test_total() {
         if crate::tz::db().is_definitively_empty() {
@@ -6805,6 +7077,48 @@ mod tests {
         assert_eq!(array, [span1, span3, span2].map(SpanFieldwise));
     }
 
+    #[test]
+    fn test_to_std_timeout() {
+        let day24 = SpanRelativeTo::days_are_24_hours();
+        let secs = |n| UnsignedDuration::from_secs(n);
+
+        assert_eq!(90.minutes().to_std_timeout(day24).unwrap(), secs(5400));
+        assert_eq!(
+            (-90).minutes().to_std_timeout(day24).unwrap(),
+            UnsignedDuration::ZERO,
+        );
+        // The largest invariant span doesn't need to saturate.
+        let span = Span::new().weeks(t::SpanWeeks::MAX_REPR);
+        assert_eq!(
+            span.to_std_timeout(day24).unwrap(),
+            span.to_duration(day24).unwrap().unsigned_abs(),
+        );
+
+        // Months depend on the relative date.
+        let span = 1.month();
+        assert_eq!(
+            span.to_std_timeout(date(2024, 2, 1)).unwrap(),
+            secs(29 * 24 * 60 * 60),
+        );
+        assert!(span.to_std_timeout(day24).is_err());
+
+        // Overflow saturates in the direction of the span.
+        let start = date(9999, 12, 1);
+        assert_eq!(
+            1.month().to_std_timeout(start).unwrap(),
+            UnsignedDuration::MAX,
+        );
+        assert_eq!(
+            (-1).month().to_std_timeout(date(-9999, 1, 15)).unwrap(),
+            UnsignedDuration::ZERO,
+        );
+        // But going backwards from near the maximum is fine.
+        assert_eq!(
+            (-1).month().to_std_timeout(start).unwrap(),
+            UnsignedDuration::ZERO,
+        );
+    }
+
     #[test]
     fn test_checked_add() {
         let span1 = 1.hour();
@@ -7084,6 +7398,30 @@ mod tests {
         span_eq!(rounded, 3.hours());
     }
 
+    #[test]
+    fn test_round_relative_date_boundaries() {
+        let options =
+            SpanRound::new().largest(Unit::Month).relative(date(9999, 12, 30));
+        span_eq!(1.day().round(options).unwrap(), 1.day());
+
+        let options =
+            SpanRound::new().largest(Unit::Day).relative(date(-9999, 1, 1));
+        span_eq!(1.month().round(options).unwrap(), 31.days());
+
+        let options =
+            SpanRound::new().smallest(Unit::Month).relative(date(-9999, 1, 1));
+        span_eq!(1.month().days(16).round(options).unwrap(), 2.months());
+
+        let options =
+            SpanRound::new().smallest(Unit::Month).relative(date(9999, 10, 1));
+        span_eq!(1.month().days(10).round(options).unwrap(), 1.month());
+
+        let total = 1.month().total((Unit::Day, date(-9999, 1, 1))).unwrap();
+        assert_eq!(total, 31.0);
+        let total = 7.days().total((Unit::Week, date(9999, 12, 10))).unwrap();
+        assert_eq!(total, 1.0);
+    }
+
     #[test]
     fn span_sign() {
         assert_eq!(Span::new().get_sign_ranged(), C(0));
@@ -0,0 +1,1853 @@
+/*!
+Support for the International Atomic Time (TAI) time scale.
+
+Jiff's [`Timestamp`](crate::Timestamp) type, like most datetime libraries,
+uses the Unix time scale. In the Unix time scale, every day has exactly
+86,400 seconds, and so leap seconds are ignored. This is usually what you
+want, but it means that the duration between two Unix timestamps is not the
+actual elapsed time between them when a leap second occurs in between.
+
+TAI is a continuous time scale that does not have leap seconds. UTC is
+defined as an offset from TAI, where the offset is changed by inserting
+(or removing) a leap second. For example, after `2017-01-01T00:00:00Z`, TAI
+is ahead of UTC by 37 seconds. This module provides a [`Timestamp`] type
+for representing instants in TAI, and conversions between UTC and TAI that
+are driven by a [`LeapSecondTable`]. Since TAI has no leap seconds, the
+duration between two TAI timestamps is always the exact elapsed time.
+
+Conversions between UTC and TAI are opt-in. Nothing else in Jiff is aware of
+leap seconds.
+
+# Example
+
+This shows how to compute the exact elapsed time between two UTC
+timestamps that straddle a leap second:
+
+```
+use jiff::{tai, SignedDuration, Timestamp};
+
+let start: Timestamp = "2016-12-31T23:59:59Z".parse()?;
+let end: Timestamp = "2017-01-01T00:00:00Z".parse()?;
+// In the Unix time scale, the leap second doesn't exist.
+assert_eq!(end.duration_since(start), SignedDuration::from_secs(1));
+
+let start = tai::Timestamp::from_utc(start)?;
+let end = tai::Timestamp::from_utc(end)?;
+assert_eq!(end.duration_since(start), SignedDuration::from_secs(2));
+assert_eq!(end.to_string(), "2017-01-01T00:00:37 TAI");
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+# Before 1972
+
+Before `1972-01-01T00:00:00Z`, the offset between UTC and TAI was not an
+integral number of seconds, and UTC seconds were not the same length as TAI
+seconds. Leap second tables start on this date, and so conversions between
+UTC and TAI before it return an error.
+
+# Leap smears
+
+Some systems avoid leap seconds entirely by "smearing" them. That is, the
+clock is slowed down (or sped up) by a tiny amount over a period of time
+around a leap second, such that the clock never shows `23:59:60`. A smeared
+clock disagrees with UTC by up to one second during the smear. The
+[`LeapSmear`] type describes a smear, and [`LeapSecondTable`] provides
+conversions between smeared timestamps and TAI or UTC.
+
+```
+use jiff::{tai::{LeapSecondTable, LeapSmear}, Timestamp};
+
+let table = LeapSecondTable::builtin();
+// Just after the leap second at the end of 2016, a clock using a 24 hour
+// smear is about half a second ahead of UTC.
+let utc: Timestamp = "2017-01-01T00:00:00Z".parse()?;
+let smeared = table.utc_to_smeared(LeapSmear::Linear24Hour, utc)?;
+assert_eq!(smeared.to_string(), "2017-01-01T00:00:00.499994213Z");
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+# Other time scales
+
+GPS time is a continuous time scale that is always exactly 19 seconds behind
+TAI, with its epoch at `1980-01-06T00:00:00Z`. So for example, a GPS
+timestamp can be obtained by subtracting 19 seconds from the
+[`Timestamp::to_datetime`] of a TAI timestamp.
+*/
+
+use crate::{
+    civil::{Date, DateTime, Time},
+    error::{err, Error, ErrorContext},
+    tz::Offset,
+    SignedDuration,
+};
+
+#[cfg(feature = "alloc")]
+use {crate::util::sync::Arc, alloc::vec::Vec};
+
+/// An instant in the International Atomic Time (TAI) time scale.
+///
+/// A TAI timestamp is represented as a duration since the TAI epoch,
+/// `1970-01-01T00:00:00 TAI`. Since TAI has no leap seconds, every TAI day has
+/// exactly 86,400 seconds, and so a TAI timestamp also has an unambiguous
+/// civil datetime in the TAI time scale. (This is the representation used
+/// by its `Display` implementation.) The range of a TAI timestamp is the same
+/// as the range of a [`crate::Timestamp`].
+///
+/// Since TAI is continuous, the [`Timestamp::duration_since`] between two
+/// TAI timestamps is always the exact elapsed time between them, even when
+/// a leap second occurs in between.
+///
+/// TAI timestamps are usually obtained by converting from a UTC
+/// [`crate::Timestamp`] via [`Timestamp::from_utc`] or
+/// [`LeapSecondTable::utc_to_tai`].
+///
+/// # Example
+///
+/// ```
+/// use jiff::{tai, SignedDuration};
+///
+/// let tai = tai::Timestamp::from_utc("2025-01-01T00:00:00Z".parse()?)?;
+/// assert_eq!(tai.to_string(), "2025-01-01T00:00:37 TAI");
+///
+/// let later = tai.checked_add(SignedDuration::from_hours(1))?;
+/// assert_eq!(later.to_utc()?.to_string(), "2025-01-01T01:00:00Z");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timestamp {
+    /// The TAI timestamp, labeled as if it were a Unix timestamp. That is,
+    /// the duration since the Unix epoch of this label is the duration since
+    /// the TAI epoch.
+    label: crate::Timestamp,
+}
+
+impl Timestamp {
+    /// Converts a UTC timestamp to TAI using the
+    /// [built-in leap second table](LeapSecondTable::builtin).
+    ///
+    /// This is a convenience routine for
+    /// `LeapSecondTable::builtin().utc_to_tai(timestamp)`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the given timestamp is before
+    /// `1972-01-01T00:00:00Z`, or when the TAI timestamp would be outside
+    /// the range supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai, Timestamp};
+    ///
+    /// let ts: Timestamp = "1999-01-01T00:00:00Z".parse()?;
+    /// let tai = tai::Timestamp::from_utc(ts)?;
+    /// assert_eq!(tai.to_string(), "1999-01-01T00:00:32 TAI");
+    ///
+    /// assert!(tai::Timestamp::from_utc(Timestamp::UNIX_EPOCH).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_utc(timestamp: crate::Timestamp) -> Result<Timestamp, Error> {
+        LeapSecondTable::builtin().utc_to_tai(timestamp)
+    }
+
+    /// Converts this TAI timestamp to UTC using the
+    /// [built-in leap second table](LeapSecondTable::builtin).
+    ///
+    /// This is a convenience routine for
+    /// `LeapSecondTable::builtin().tai_to_utc(self)`. See
+    /// [`LeapSecondTable::tai_to_utc`] for how TAI timestamps during a leap
+    /// second are converted.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when this timestamp is before
+    /// `1972-01-01T00:00:10 TAI`, which corresponds to
+    /// `1972-01-01T00:00:00Z`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tai};
+    ///
+    /// let dt = date(2025, 1, 1).at(0, 0, 37, 0);
+    /// let tai = tai::Timestamp::from_datetime(dt)?;
+    /// assert_eq!(tai.to_utc()?.to_string(), "2025-01-01T00:00:00Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_utc(self) -> Result<crate::Timestamp, Error> {
+        LeapSecondTable::builtin().tai_to_utc(self)
+    }
+
+    /// Creates a TAI timestamp from a civil datetime in the TAI time scale.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the datetime is outside the range of a TAI
+    /// timestamp. This can only happen for datetimes near the minimum or
+    /// maximum datetimes supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tai};
+    ///
+    /// let dt = date(2017, 1, 1).at(0, 0, 37, 0);
+    /// let tai = tai::Timestamp::from_datetime(dt)?;
+    /// assert_eq!(tai.to_datetime(), dt);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_datetime(dt: DateTime) -> Result<Timestamp, Error> {
+        let label = Offset::UTC.to_timestamp(dt)?;
+        Ok(Timestamp { label })
+    }
+
+    /// Returns the civil datetime of this TAI timestamp in the TAI time
+    /// scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tai};
+    ///
+    /// let tai = tai::Timestamp::from_utc("2017-01-01T00:00:00Z".parse()?)?;
+    /// assert_eq!(tai.to_datetime(), date(2017, 1, 1).at(0, 0, 37, 0));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_datetime(self) -> DateTime {
+        Offset::UTC.to_datetime(self.label)
+    }
+
+    /// Creates a TAI timestamp from a duration since the TAI epoch,
+    /// `1970-01-01T00:00:00 TAI`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the duration is outside the range of a TAI
+    /// timestamp.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai, SignedDuration};
+    ///
+    /// let dur = SignedDuration::from_secs(86_400);
+    /// let tai = tai::Timestamp::from_duration(dur)?;
+    /// assert_eq!(tai.to_string(), "1970-01-02T00:00:00 TAI");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_duration(
+        duration: SignedDuration,
+    ) -> Result<Timestamp, Error> {
+        let label = crate::Timestamp::from_duration(duration)?;
+        Ok(Timestamp { label })
+    }
+
+    /// Returns the duration since the TAI epoch, `1970-01-01T00:00:00 TAI`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai, SignedDuration};
+    ///
+    /// let tai = tai::Timestamp::from_utc("2017-01-01T00:00:00Z".parse()?)?;
+    /// let dur = SignedDuration::from_secs(1_483_228_837);
+    /// assert_eq!(tai.as_duration(), dur);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn as_duration(self) -> SignedDuration {
+        self.label.as_duration()
+    }
+
+    /// Adds the given duration to this TAI timestamp.
+    ///
+    /// Since TAI has no leap seconds, this always corresponds to exactly the
+    /// given amount of elapsed time.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the result would be outside the range of a
+    /// TAI timestamp.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai, SignedDuration};
+    ///
+    /// let tai = tai::Timestamp::from_utc("2016-12-31T23:59:59Z".parse()?)?;
+    /// let later = tai.checked_add(SignedDuration::from_secs(2))?;
+    /// assert_eq!(later.to_utc()?.to_string(), "2017-01-01T00:00:00Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn checked_add(
+        self,
+        duration: SignedDuration,
+    ) -> Result<Timestamp, Error> {
+        let label = self.label.checked_add(duration)?;
+        Ok(Timestamp { label })
+    }
+
+    /// Subtracts the given duration from this TAI timestamp.
+    ///
+    /// Since TAI has no leap seconds, this always corresponds to exactly the
+    /// given amount of elapsed time.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the result would be outside the range of a
+    /// TAI timestamp.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai, SignedDuration};
+    ///
+    /// let tai = tai::Timestamp::from_utc("2017-01-01T00:00:00Z".parse()?)?;
+    /// let earlier = tai.checked_sub(SignedDuration::from_secs(2))?;
+    /// assert_eq!(earlier.to_utc()?.to_string(), "2016-12-31T23:59:59Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn checked_sub(
+        self,
+        duration: SignedDuration,
+    ) -> Result<Timestamp, Error> {
+        let label = self.label.checked_sub(duration)?;
+        Ok(Timestamp { label })
+    }
+
+    /// Returns the exact elapsed time from `other` until this TAI timestamp.
+    ///
+    /// When `other` is after this timestamp, the duration returned is
+    /// negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai, SignedDuration};
+    ///
+    /// let start = tai::Timestamp::from_utc("2015-06-30T00:00:00Z".parse()?)?;
+    /// let end = tai::Timestamp::from_utc("2015-07-01T00:00:00Z".parse()?)?;
+    /// // One more second than usual, because of the leap second.
+    /// let dur = SignedDuration::from_secs(86_401);
+    /// assert_eq!(end.duration_since(start), dur);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn duration_since(self, other: Timestamp) -> SignedDuration {
+        self.label.duration_since(other.label)
+    }
+
+    /// Returns the exact elapsed time from this TAI timestamp until `other`.
+    ///
+    /// When `other` is before this timestamp, the duration returned is
+    /// negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai, SignedDuration};
+    ///
+    /// let start = tai::Timestamp::from_utc("2015-06-30T00:00:00Z".parse()?)?;
+    /// let end = tai::Timestamp::from_utc("2015-07-01T00:00:00Z".parse()?)?;
+    /// let dur = SignedDuration::from_secs(86_401);
+    /// assert_eq!(start.duration_until(end), dur);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn duration_until(self, other: Timestamp) -> SignedDuration {
+        self.label.duration_until(other.label)
+    }
+}
+
+impl core::fmt::Debug for Timestamp {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Formats a TAI timestamp as its civil datetime in the TAI time scale,
+/// followed by ` TAI`.
+///
+/// The precision of the fractional seconds can be set via the standard
+/// library's formatting precision, just like for a [`DateTime`].
+impl core::fmt::Display for Timestamp {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.to_datetime(), f)?;
+        f.write_str(" TAI")
+    }
+}
+
+/// A table of leap seconds, used to convert between UTC and TAI.
+///
+/// Each entry in the table records the UTC instant at which the offset
+/// between TAI and UTC changed (that is, the instant immediately following a
+/// leap second), along with the new offset. The offset is the number of
+/// seconds that TAI is ahead of UTC.
+///
+/// A table may also record when it was last updated and when it expires.
+/// Since leap seconds are announced by the IERS only about six months in
+/// advance, a table is only known to be complete up to its expiration. Past
+/// that, a table assumes that no further leap seconds have occurred.
+///
+/// Besides the [built-in table](LeapSecondTable::builtin), a table can be
+/// parsed from the `leap-seconds.list` or `leapseconds` files distributed
+/// with the Time Zone Database. When the `std` feature is enabled, a table
+/// can also be loaded from the system copy of the Time Zone Database via
+/// [`LeapSecondTable::from_env`].
+///
+/// # Example
+///
+/// ```
+/// use jiff::{tai::LeapSecondTable, Timestamp};
+///
+/// let table = LeapSecondTable::builtin();
+/// let ts: Timestamp = "2009-01-01T00:00:00Z".parse()?;
+/// let tai = table.utc_to_tai(ts)?;
+/// assert_eq!(tai.to_string(), "2009-01-01T00:00:34 TAI");
+/// assert_eq!(table.tai_to_utc(tai)?, ts);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct LeapSecondTable {
+    entries: Entries,
+    updated: Option<crate::Timestamp>,
+    expires: Option<crate::Timestamp>,
+}
+
+impl LeapSecondTable {
+    /// Returns the leap second table that is built into Jiff.
+    ///
+    /// This table contains every leap second announced by the IERS as of
+    /// the release of this version of Jiff. The most recent leap second
+    /// occurred at the end of `2016-12-31`, after which TAI is ahead of UTC
+    /// by 37 seconds. For instants after the last entry in the table, the
+    /// table assumes that no further leap seconds have occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai::LeapSecondTable, Timestamp};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// let tai = table.utc_to_tai("1972-01-01T00:00:00Z".parse()?)?;
+    /// assert_eq!(tai.to_string(), "1972-01-01T00:00:10 TAI");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn builtin() -> LeapSecondTable {
+        LeapSecondTable {
+            entries: Entries::Static(BUILTIN),
+            updated: Some(BUILTIN_UPDATED),
+            expires: Some(BUILTIN_EXPIRES),
+        }
+    }
+
+    /// Parses a leap second table in the format of the `leap-seconds.list`
+    /// file.
+    ///
+    /// This file is published by the IERS and distributed with the Time
+    /// Zone Database. Each entry is a line containing an NTP timestamp (that
+    /// is, the number of seconds since `1900-01-01T00:00:00Z`) and the
+    /// number of seconds that TAI is ahead of UTC from that instant onwards.
+    /// The special comment lines starting with `#$` and `#@` record when the
+    /// file was last updated and when it expires, respectively. Other
+    /// comments are ignored. In particular, the hash recorded on the `#h`
+    /// line is not verified.
+    ///
+    /// This is only available when the `alloc` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the data is not valid UTF-8, if any line is
+    /// invalid, or if the entries are not a valid leap second table. That
+    /// is, there must be at least one entry, entries must be in strictly
+    /// increasing order and each offset must differ from the previous one
+    /// by exactly one second.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai::LeapSecondTable, SignedDuration, Timestamp};
+    ///
+    /// let data = "\
+    /// #$ 3676924800
+    /// #@ 3707596800
+    /// 2272060800 10 # 1 Jan 1972
+    /// 2287785600 11 # 1 Jul 1972
+    /// 2303683200 12 # 1 Jan 1973
+    /// ";
+    /// let table = LeapSecondTable::from_leap_seconds_list(data)?;
+    /// assert_eq!(
+    ///     table.updated(),
+    ///     Some("2016-07-08T00:00:00Z".parse::<Timestamp>()?),
+    /// );
+    /// assert_eq!(
+    ///     table.expires(),
+    ///     Some("2017-06-28T00:00:00Z".parse::<Timestamp>()?),
+    /// );
+    /// assert_eq!(
+    ///     table.utc_tai_offset("1972-10-01T00:00:00Z".parse()?)?,
+    ///     SignedDuration::from_secs(11),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_leap_seconds_list<D: AsRef<[u8]>>(
+        data: D,
+    ) -> Result<LeapSecondTable, Error> {
+        parse_leap_seconds_list(data.as_ref())
+            .context("failed to parse leap-seconds.list data")
+    }
+
+    /// Parses a leap second table in the format of the `leapseconds` file.
+    ///
+    /// This file is distributed with the Time Zone Database and is the
+    /// input given to `zic`. Each leap second is recorded on a line like
+    /// `Leap 2016 Dec 31 23:59:60 + S`, where `+` indicates a positive leap
+    /// second and `-` indicates a negative leap second. An `Expires` line
+    /// records when the table expires. Since this line is typically
+    /// commented out for compatibility with older versions of `zic`, the
+    /// `#updated` and `#expires` comment lines (which contain a Unix
+    /// timestamp) are also recognized.
+    ///
+    /// Unlike `leap-seconds.list`, this format doesn't record the initial
+    /// offset between TAI and UTC. The table returned always starts at
+    /// `1972-01-01T00:00:00Z` with TAI ahead of UTC by 10 seconds.
+    ///
+    /// This is only available when the `alloc` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the data is not valid UTF-8, if any line
+    /// is invalid or if the leap seconds are not in strictly increasing
+    /// order. This also returns an error for "rolling" leap seconds, i.e.,
+    /// those with an `R` in the last field, since these are given in local
+    /// time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai::LeapSecondTable, SignedDuration, Timestamp};
+    ///
+    /// let data = "\
+    /// Leap 1972 Jun 30 23:59:60 + S
+    /// Leap 1972 Dec 31 23:59:60 + S
+    /// Expires 1973 Jun 28 00:00:00
+    /// ";
+    /// let table = LeapSecondTable::from_leapseconds(data)?;
+    /// assert_eq!(
+    ///     table.expires(),
+    ///     Some("1973-06-28T00:00:00Z".parse::<Timestamp>()?),
+    /// );
+    /// assert_eq!(
+    ///     table.utc_tai_offset("1973-01-01T00:00:00Z".parse()?)?,
+    ///     SignedDuration::from_secs(12),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_leapseconds<D: AsRef<[u8]>>(
+        data: D,
+    ) -> Result<LeapSecondTable, Error> {
+        parse_leapseconds(data.as_ref())
+            .context("failed to parse leapseconds data")
+    }
+
+    /// Loads a leap second table from the given Time Zone Database
+    /// directory.
+    ///
+    /// This looks for a `leap-seconds.list` file in the directory given,
+    /// and falls back to a `leapseconds` file if it doesn't exist. See
+    /// [`LeapSecondTable::from_leap_seconds_list`] and
+    /// [`LeapSecondTable::from_leapseconds`] for details on the formats.
+    ///
+    /// This is only available when the `std` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if neither file could be read, or if the file
+    /// read could not be parsed.
+    #[cfg(feature = "std")]
+    pub fn from_dir<P: AsRef<std::path::Path>>(
+        dir: P,
+    ) -> Result<LeapSecondTable, Error> {
+        let dir = dir.as_ref();
+        let path = dir.join("leap-seconds.list");
+        match std::fs::read(&path) {
+            Ok(data) => {
+                return LeapSecondTable::from_leap_seconds_list(data)
+                    .map_err(|e| e.path(path));
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                trace!(
+                    "could not find {}, trying leapseconds",
+                    path.display()
+                );
+            }
+            Err(err) => return Err(Error::io(err).path(path)),
+        }
+        let path = dir.join("leapseconds");
+        let data =
+            std::fs::read(&path).map_err(|e| Error::io(e).path(&path))?;
+        LeapSecondTable::from_leapseconds(data).map_err(|e| e.path(path))
+    }
+
+    /// Loads a leap second table from the system copy of the Time Zone
+    /// Database.
+    ///
+    /// When the `TZDIR` environment variable is set, this will first attempt
+    /// to load a table from the directory it names via
+    /// [`LeapSecondTable::from_dir`]. Otherwise (or if that fails), this
+    /// tries the same standard directories that Jiff searches for the
+    /// Time Zone Database, like `/usr/share/zoneinfo`.
+    ///
+    /// Callers may want to fall back to [`LeapSecondTable::builtin`] when
+    /// this fails, or when the table returned has expired before the
+    /// built-in table.
+    ///
+    /// This is only available when the `std` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if a leap second table could not be loaded from
+    /// any of the directories searched.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jiff::{tai::LeapSecondTable, Timestamp};
+    ///
+    /// let table = LeapSecondTable::from_env()
+    ///     .unwrap_or_else(|_| LeapSecondTable::builtin());
+    /// let now = Timestamp::now();
+    /// if table.expires().map_or(false, |expires| expires < now) {
+    ///     eprintln!("leap second table has expired");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Result<LeapSecondTable, Error> {
+        if let Some(tzdir) = std::env::var_os("TZDIR") {
+            let tzdir = std::path::PathBuf::from(tzdir);
+            trace!("loading leap second table from TZDIR={}", tzdir.display());
+            match LeapSecondTable::from_dir(&tzdir) {
+                Ok(table) => return Ok(table),
+                Err(_err) => {
+                    warn!(
+                        "failed loading leap second table from \
+                         TZDIR={}: {_err}",
+                        tzdir.display(),
+                    );
+                }
+            }
+        }
+        for dir in ZONEINFO_DIRECTORIES {
+            trace!("loading leap second table from {dir}");
+            match LeapSecondTable::from_dir(dir) {
+                Ok(table) => return Ok(table),
+                Err(_err) => {
+                    trace!(
+                        "failed loading leap second table from {dir}: {_err}"
+                    );
+                }
+            }
+        }
+        Err(err!(
+            "could not find leap second table at any of the following \
+             paths: {}",
+            ZONEINFO_DIRECTORIES.join(", "),
+        ))
+    }
+
+    /// Returns the time at which this table was last updated, if known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::tai::LeapSecondTable;
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// assert_eq!(
+    ///     table.updated().map(|ts| ts.to_string()).as_deref(),
+    ///     Some("2025-07-07T00:00:00Z"),
+    /// );
+    /// ```
+    #[inline]
+    pub fn updated(&self) -> Option<crate::Timestamp> {
+        self.updated
+    }
+
+    /// Returns the time at which this table expires, if known.
+    ///
+    /// The IERS announces leap seconds about six months in advance. The
+    /// expiration of a table is the instant up to which the table is known
+    /// to be complete. Conversions after this instant still succeed, but
+    /// they assume that no leap seconds occurred after the last entry in
+    /// the table, which may not be true.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::tai::LeapSecondTable;
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// assert_eq!(
+    ///     table.expires().map(|ts| ts.to_string()).as_deref(),
+    ///     Some("2026-06-28T00:00:00Z"),
+    /// );
+    /// ```
+    #[inline]
+    pub fn expires(&self) -> Option<crate::Timestamp> {
+        self.expires
+    }
+
+    /// Returns the number of seconds that TAI is ahead of UTC at the given
+    /// UTC instant.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the given timestamp is before the first
+    /// entry in this table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai::LeapSecondTable, SignedDuration};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// assert_eq!(
+    ///     table.utc_tai_offset("2016-12-31T23:59:59Z".parse()?)?,
+    ///     SignedDuration::from_secs(36),
+    /// );
+    /// assert_eq!(
+    ///     table.utc_tai_offset("2017-01-01T00:00:00Z".parse()?)?,
+    ///     SignedDuration::from_secs(37),
+    /// );
+    /// let ts = "1971-12-31T00:00:00Z".parse()?;
+    /// assert!(table.utc_tai_offset(ts).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn utc_tai_offset(
+        &self,
+        timestamp: crate::Timestamp,
+    ) -> Result<SignedDuration, Error> {
+        let second = timestamp.as_second();
+        let Some(entry) =
+            self.entries().iter().rev().find(|e| e.utc_second <= second)
+        else {
+            return Err(self.before_first_entry(timestamp));
+        };
+        Ok(SignedDuration::from_secs(entry.tai_minus_utc))
+    }
+
+    /// Converts a UTC timestamp to TAI.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the given timestamp is before the first
+    /// entry in this table, or when the TAI timestamp would be outside the
+    /// range supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai::LeapSecondTable, Timestamp};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// let ts: Timestamp = "2016-12-31T23:59:59.5Z".parse()?;
+    /// let tai = table.utc_to_tai(ts)?;
+    /// assert_eq!(tai.to_string(), "2017-01-01T00:00:35.5 TAI");
+    /// let ts: Timestamp = "2017-01-01T00:00:00Z".parse()?;
+    /// let tai = table.utc_to_tai(ts)?;
+    /// assert_eq!(tai.to_string(), "2017-01-01T00:00:37 TAI");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn utc_to_tai(
+        &self,
+        timestamp: crate::Timestamp,
+    ) -> Result<Timestamp, Error> {
+        let offset = self.utc_tai_offset(timestamp)?;
+        let label = timestamp.checked_add(offset).with_context(|| {
+            err!("failed to convert UTC timestamp {timestamp} to TAI")
+        })?;
+        Ok(Timestamp { label })
+    }
+
+    /// Converts a TAI timestamp to UTC.
+    ///
+    /// The UTC time scale, as represented by a [`crate::Timestamp`], cannot
+    /// represent instants during a positive leap second (such as
+    /// `2016-12-31T23:59:60Z`). TAI timestamps during a leap second are
+    /// converted to the last instant representable before it, i.e., with a
+    /// time of `23:59:59.999999999`. This ensures that converting a
+    /// sequence of increasing TAI timestamps to UTC never goes backwards in
+    /// time.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the given timestamp is before the first
+    /// entry in this table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tai::{self, LeapSecondTable}};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// let leap = tai::Timestamp::from_datetime(
+    ///     date(2017, 1, 1).at(0, 0, 36, 500_000_000),
+    /// )?;
+    /// assert_eq!(
+    ///     table.tai_to_utc(leap)?.to_string(),
+    ///     "2016-12-31T23:59:59.999999999Z",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tai_to_utc(
+        &self,
+        timestamp: Timestamp,
+    ) -> Result<crate::Timestamp, Error> {
+        let second = timestamp.label.as_second();
+        let entries = self.entries();
+        let Some(i) = entries
+            .iter()
+            .rposition(|e| e.utc_second + e.tai_minus_utc <= second)
+        else {
+            return Err(err!(
+                "TAI timestamp {timestamp} is before the first entry in the \
+                 leap second table",
+            ));
+        };
+        let entry = &entries[i];
+        let mut utc = timestamp
+            .label
+            .checked_sub(SignedDuration::from_secs(entry.tai_minus_utc))
+            .with_context(|| {
+                err!("failed to convert TAI timestamp {timestamp} to UTC")
+            })?;
+        // During a positive leap second, the UTC timestamp computed above
+        // is at or after the start of the next entry. So clamp it.
+        if let Some(next) = entries.get(i + 1) {
+            if utc.as_second() >= next.utc_second {
+                // OK because the next entry is after `utc`, which is valid.
+                utc = crate::Timestamp::new(next.utc_second - 1, 999_999_999)
+                    .unwrap();
+            }
+        }
+        Ok(utc)
+    }
+
+    /// Converts a TAI timestamp to a timestamp on a clock that uses the
+    /// given leap smear.
+    ///
+    /// Outside of a smear, this is equivalent to
+    /// [`LeapSecondTable::tai_to_utc`]. During a smear, the timestamp
+    /// returned is rounded down to the nearest nanosecond.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the given timestamp is before the first
+    /// entry in this table, or when the smeared timestamp would be outside
+    /// the range supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tai::{self, LeapSecondTable, LeapSmear}};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// // The middle of the leap second at the end of 2016.
+    /// let tai = tai::Timestamp::from_datetime(
+    ///     date(2017, 1, 1).at(0, 0, 36, 500_000_000),
+    /// )?;
+    /// let smeared = table.tai_to_smeared(LeapSmear::UtcSls, tai)?;
+    /// assert_eq!(smeared.to_string(), "2016-12-31T23:59:59.5004995Z");
+    /// // A 24 hour smear is centered on the leap second.
+    /// let smeared = table.tai_to_smeared(LeapSmear::Linear24Hour, tai)?;
+    /// assert_eq!(smeared.to_string(), "2017-01-01T00:00:00Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tai_to_smeared(
+        &self,
+        smear: LeapSmear,
+        timestamp: Timestamp,
+    ) -> Result<crate::Timestamp, Error> {
+        let tai = timestamp.label.as_nanosecond();
+        let Some(window) = self.smear_windows(smear).find(|w| w.has_tai(tai))
+        else {
+            return self.tai_to_utc(timestamp);
+        };
+        crate::Timestamp::from_nanosecond(window.tai_to_smeared(tai))
+            .with_context(|| {
+                err!("failed to convert TAI timestamp {timestamp} to smeared")
+            })
+    }
+
+    /// Converts a timestamp from a clock that uses the given leap smear to
+    /// TAI.
+    ///
+    /// Outside of a smear, this is equivalent to
+    /// [`LeapSecondTable::utc_to_tai`]. During a smear, the timestamp
+    /// returned is rounded up to the nearest nanosecond. This guarantees that
+    /// converting the result back with [`LeapSecondTable::tai_to_smeared`]
+    /// returns the original timestamp for positive leap seconds.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the given timestamp is before the first
+    /// entry in this table, or when the TAI timestamp would be outside the
+    /// range supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai::{LeapSecondTable, LeapSmear}, Timestamp};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// let smeared: Timestamp = "2016-12-31T23:59:59.9Z".parse()?;
+    /// let tai = table.smeared_to_tai(LeapSmear::UtcSls, smeared)?;
+    /// assert_eq!(tai.to_string(), "2017-01-01T00:00:36.8999 TAI");
+    /// assert_eq!(table.tai_to_smeared(LeapSmear::UtcSls, tai)?, smeared);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn smeared_to_tai(
+        &self,
+        smear: LeapSmear,
+        timestamp: crate::Timestamp,
+    ) -> Result<Timestamp, Error> {
+        let smeared = timestamp.as_nanosecond();
+        let Some(window) =
+            self.smear_windows(smear).find(|w| w.has_smeared(smeared))
+        else {
+            return self.utc_to_tai(timestamp);
+        };
+        let label =
+            crate::Timestamp::from_nanosecond(window.smeared_to_tai(smeared))
+                .with_context(|| {
+                    err!(
+                        "failed to convert smeared timestamp {timestamp} \
+                         to TAI"
+                    )
+                })?;
+        Ok(Timestamp { label })
+    }
+
+    /// Converts a UTC timestamp to a timestamp on a clock that uses the
+    /// given leap smear.
+    ///
+    /// This is like [`LeapSecondTable::tai_to_smeared`], but starts from a
+    /// UTC timestamp. Note that a UTC timestamp cannot represent an instant
+    /// during a positive leap second. Use
+    /// [`LeapSecondTable::tai_to_smeared`] for those instants instead.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`LeapSecondTable::utc_to_tai`] and
+    /// [`LeapSecondTable::tai_to_smeared`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai::{LeapSecondTable, LeapSmear}, Timestamp};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// let utc: Timestamp = "2016-12-31T12:00:00Z".parse()?;
+    /// let smeared = table.utc_to_smeared(LeapSmear::Linear24Hour, utc)?;
+    /// assert_eq!(smeared, utc);
+    ///
+    /// // At midnight, the smeared clock is half a second ahead of UTC,
+    /// // since the leap second has already occurred.
+    /// let utc: Timestamp = "2017-01-01T00:00:00Z".parse()?;
+    /// let smeared = table.utc_to_smeared(LeapSmear::Linear24Hour, utc)?;
+    /// assert_eq!(smeared.to_string(), "2017-01-01T00:00:00.499994213Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn utc_to_smeared(
+        &self,
+        smear: LeapSmear,
+        timestamp: crate::Timestamp,
+    ) -> Result<crate::Timestamp, Error> {
+        self.tai_to_smeared(smear, self.utc_to_tai(timestamp)?)
+    }
+
+    /// Converts a timestamp from a clock that uses the given leap smear to
+    /// UTC.
+    ///
+    /// This is like [`LeapSecondTable::smeared_to_tai`], but returns a UTC
+    /// timestamp. Smeared timestamps that correspond to an instant during a
+    /// positive leap second are clamped in the same way as
+    /// [`LeapSecondTable::tai_to_utc`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error for the same reasons as
+    /// [`LeapSecondTable::smeared_to_tai`] and
+    /// [`LeapSecondTable::tai_to_utc`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tai::{LeapSecondTable, LeapSmear}, Timestamp};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// // UTC-SLS ends at midnight, so a smeared clock is in sync with UTC
+    /// // from then on.
+    /// let smeared: Timestamp = "2017-01-01T00:00:00Z".parse()?;
+    /// let utc = table.smeared_to_utc(LeapSmear::UtcSls, smeared)?;
+    /// assert_eq!(utc, smeared);
+    ///
+    /// let smeared: Timestamp = "2016-12-31T23:50:00Z".parse()?;
+    /// let utc = table.smeared_to_utc(LeapSmear::UtcSls, smeared)?;
+    /// assert_eq!(utc.to_string(), "2016-12-31T23:50:00.4Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn smeared_to_utc(
+        &self,
+        smear: LeapSmear,
+        timestamp: crate::Timestamp,
+    ) -> Result<crate::Timestamp, Error> {
+        self.tai_to_utc(self.smeared_to_tai(smear, timestamp)?)
+    }
+
+    /// Returns the smear windows for every leap second in this table.
+    fn smear_windows(
+        &self,
+        smear: LeapSmear,
+    ) -> impl Iterator<Item = SmearWindow> + '_ {
+        let (before, after) = smear.window();
+        self.entries().windows(2).map(move |pair| {
+            let (prev, next) = (&pair[0], &pair[1]);
+            let (start, end) =
+                (next.utc_second - before, next.utc_second + after);
+            SmearWindow {
+                smeared_start: i128::from(start) * NANOS_PER_SECOND,
+                smeared_end: i128::from(end) * NANOS_PER_SECOND,
+                tai_start: i128::from(start + prev.tai_minus_utc)
+                    * NANOS_PER_SECOND,
+                tai_end: i128::from(end + next.tai_minus_utc)
+                    * NANOS_PER_SECOND,
+            }
+        })
+    }
+
+    /// Converts a UTC timestamp during a leap second to TAI.
+    ///
+    /// Since a [`crate::Timestamp`] can't represent a leap second, callers
+    /// give the timestamp one second before the instant they want. That is,
+    /// with a seconds value of `59` instead of `60`. This returns an error
+    /// when this table doesn't have a leap second immediately after the
+    /// second of the timestamp given.
+    pub(crate) fn leap_second_to_tai(
+        &self,
+        constrained: crate::Timestamp,
+    ) -> Result<Timestamp, Error> {
+        let next = constrained.as_second() + 1;
+        let is_leap = self.entries().windows(2).any(|pair| {
+            pair[1].utc_second == next
+                && pair[1].tai_minus_utc > pair[0].tai_minus_utc
+        });
+        if !is_leap {
+            return Err(err!(
+                "found leap second after {constrained}, but there is no \
+                 leap second at that time in the leap second table",
+            ));
+        }
+        self.utc_to_tai(constrained)?.checked_add(SignedDuration::from_secs(1))
+    }
+
+    /// Builds a table from parsed entries, after checking that they form a
+    /// valid leap second table.
+    #[cfg(feature = "alloc")]
+    fn from_entries(
+        entries: Vec<LeapEntry>,
+        updated: Option<crate::Timestamp>,
+        expires: Option<crate::Timestamp>,
+    ) -> Result<LeapSecondTable, Error> {
+        if entries.is_empty() {
+            return Err(err!("leap second table has no entries"));
+        }
+        for pair in entries.windows(2) {
+            let (prev, next) = (&pair[0], &pair[1]);
+            // OK because every entry was parsed from a valid timestamp.
+            let at = crate::Timestamp::from_second(next.utc_second).unwrap();
+            if prev.utc_second >= next.utc_second {
+                return Err(err!(
+                    "leap second table entries must be in strictly \
+                     increasing order, but entry at {at} is not after \
+                     the previous entry",
+                ));
+            }
+            if (next.tai_minus_utc - prev.tai_minus_utc).abs() != 1 {
+                return Err(err!(
+                    "leap second table entry at {at} changes TAI-UTC \
+                     offset from {prev} to {next}, but it must change \
+                     by exactly one second",
+                    prev = prev.tai_minus_utc,
+                    next = next.tai_minus_utc,
+                ));
+            }
+        }
+        let entries = Entries::Owned(Arc::from(entries));
+        Ok(LeapSecondTable { entries, updated, expires })
+    }
+
+    fn entries(&self) -> &[LeapEntry] {
+        match self.entries {
+            Entries::Static(entries) => entries,
+            #[cfg(feature = "alloc")]
+            Entries::Owned(ref entries) => entries,
+        }
+    }
+
+    fn before_first_entry(&self, timestamp: crate::Timestamp) -> Error {
+        match self.entries().first() {
+            None => err!(
+                "cannot convert UTC timestamp {timestamp} to TAI because \
+                 the leap second table is empty",
+            ),
+            Some(first) => err!(
+                "cannot convert UTC timestamp {timestamp} to TAI because it \
+                 is before the first entry in the leap second table at \
+                 {first}",
+                // OK because every entry is a valid timestamp.
+                first =
+                    crate::Timestamp::from_second(first.utc_second).unwrap(),
+            ),
+        }
+    }
+}
+
+/// A strategy for smearing a leap second over a period of time.
+///
+/// A clock that smears leap seconds runs slightly slower (for a positive
+/// leap second) or faster (for a negative leap second) than TAI during the
+/// smear, such that the leap second is absorbed without the clock ever
+/// showing `23:59:60`. Outside of a smear, a smeared clock agrees with UTC.
+///
+/// Smeared timestamps are represented by a [`crate::Timestamp`], since a
+/// smeared clock never needs to represent a leap second. Conversions between
+/// smeared timestamps and TAI or UTC are provided by [`LeapSecondTable`].
+///
+/// # Example
+///
+/// ```
+/// use jiff::{tai::{LeapSecondTable, LeapSmear}, Timestamp};
+///
+/// let table = LeapSecondTable::builtin();
+/// let smeared: Timestamp = "2016-12-31T18:00:00Z".parse()?;
+/// assert_eq!(
+///     table.smeared_to_utc(LeapSmear::Linear24Hour, smeared)?.to_string(),
+///     "2016-12-31T18:00:00.25Z",
+/// );
+/// assert_eq!(table.smeared_to_utc(LeapSmear::UtcSls, smeared)?, smeared);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum LeapSmear {
+    /// UTC with Smoothed Leap Seconds, as proposed by Markus Kuhn.
+    ///
+    /// The leap second is smeared linearly over the last 1,000 seconds
+    /// before it, i.e., from `23:43:20` to midnight. So a smeared clock
+    /// agrees with UTC again immediately after the leap second.
+    UtcSls,
+    /// A linear smear over the 24 hours from noon to noon UTC around the
+    /// leap second.
+    ///
+    /// This is the smear used by Google's and Amazon's public NTP servers.
+    /// At the instant of the leap second (midnight UTC), the smeared clock
+    /// is half a second away from UTC.
+    Linear24Hour,
+}
+
+impl LeapSmear {
+    /// Returns the number of seconds before and after the instant following
+    /// a leap second over which the smear occurs.
+    fn window(self) -> (i64, i64) {
+        match self {
+            LeapSmear::UtcSls => (1_000, 0),
+            LeapSmear::Linear24Hour => (43_200, 43_200),
+        }
+    }
+}
+
+/// The entries in a leap second table.
+#[derive(Clone, Debug)]
+enum Entries {
+    Static(&'static [LeapEntry]),
+    #[cfg(feature = "alloc")]
+    Owned(Arc<[LeapEntry]>),
+}
+
+/// A single entry in a leap second table.
+#[derive(Clone, Copy, Debug)]
+struct LeapEntry {
+    /// The Unix timestamp, in seconds, at which this offset begins.
+    utc_second: i64,
+    /// The number of seconds that TAI is ahead of UTC from `utc_second`
+    /// onwards.
+    tai_minus_utc: i64,
+}
+
+impl LeapEntry {
+    const fn new(utc_second: i64, tai_minus_utc: i64) -> LeapEntry {
+        LeapEntry { utc_second, tai_minus_utc }
+    }
+}
+
+/// A single leap smear, as nanosecond ranges in both time scales.
+///
+/// The start of each range is inclusive and the end is exclusive. The
+/// smeared range is always non-empty, and the TAI range is one second longer
+/// (for a positive leap second) or shorter (for a negative leap second).
+struct SmearWindow {
+    smeared_start: i128,
+    smeared_end: i128,
+    tai_start: i128,
+    tai_end: i128,
+}
+
+impl SmearWindow {
+    fn has_smeared(&self, smeared: i128) -> bool {
+        self.smeared_start <= smeared && smeared < self.smeared_end
+    }
+
+    fn has_tai(&self, tai: i128) -> bool {
+        self.tai_start <= tai && tai < self.tai_end
+    }
+
+    /// Converts TAI to smeared, rounding down.
+    fn tai_to_smeared(&self, tai: i128) -> i128 {
+        let smeared_len = self.smeared_end - self.smeared_start;
+        let tai_len = self.tai_end - self.tai_start;
+        self.smeared_start + (tai - self.tai_start) * smeared_len / tai_len
+    }
+
+    /// Converts smeared to TAI, rounding up.
+    fn smeared_to_tai(&self, smeared: i128) -> i128 {
+        let smeared_len = self.smeared_end - self.smeared_start;
+        let tai_len = self.tai_end - self.tai_start;
+        let numerator = (smeared - self.smeared_start) * tai_len;
+        self.tai_start + (numerator + smeared_len - 1) / smeared_len
+    }
+}
+
+/// The number of nanoseconds in a second.
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// When the built-in table was last updated, as of Bulletin C 70.
+static BUILTIN_UPDATED: crate::Timestamp =
+    crate::Timestamp::constant(1751846400, 0); // 2025-07-07
+
+/// When the built-in table expires, as of Bulletin C 70.
+static BUILTIN_EXPIRES: crate::Timestamp =
+    crate::Timestamp::constant(1782604800, 0); // 2026-06-28
+
+/// The leap seconds announced by the IERS, as of Bulletin C 70.
+///
+/// This corresponds to the entries in the `leap-seconds.list` file
+/// distributed with the Time Zone Database.
+static BUILTIN: &[LeapEntry] = &[
+    LeapEntry::new(63072000, 10),   // 1972-01-01
+    LeapEntry::new(78796800, 11),   // 1972-07-01
+    LeapEntry::new(94694400, 12),   // 1973-01-01
+    LeapEntry::new(126230400, 13),  // 1974-01-01
+    LeapEntry::new(157766400, 14),  // 1975-01-01
+    LeapEntry::new(189302400, 15),  // 1976-01-01
+    LeapEntry::new(220924800, 16),  // 1977-01-01
+    LeapEntry::new(252460800, 17),  // 1978-01-01
+    LeapEntry::new(283996800, 18),  // 1979-01-01
+    LeapEntry::new(315532800, 19),  // 1980-01-01
+    LeapEntry::new(362793600, 20),  // 1981-07-01
+    LeapEntry::new(394329600, 21),  // 1982-07-01
+    LeapEntry::new(425865600, 22),  // 1983-07-01
+    LeapEntry::new(489024000, 23),  // 1985-07-01
+    LeapEntry::new(567993600, 24),  // 1988-01-01
+    LeapEntry::new(631152000, 25),  // 1990-01-01
+    LeapEntry::new(662688000, 26),  // 1991-01-01
+    LeapEntry::new(709948800, 27),  // 1992-07-01
+    LeapEntry::new(741484800, 28),  // 1993-07-01
+    LeapEntry::new(773020800, 29),  // 1994-07-01
+    LeapEntry::new(820454400, 30),  // 1996-01-01
+    LeapEntry::new(867715200, 31),  // 1997-07-01
+    LeapEntry::new(915148800, 32),  // 1999-01-01
+    LeapEntry::new(1136073600, 33), // 2006-01-01
+    LeapEntry::new(1230768000, 34), // 2009-01-01
+    LeapEntry::new(1341100800, 35), // 2012-07-01
+    LeapEntry::new(1435708800, 36), // 2015-07-01
+    LeapEntry::new(1483228800, 37), // 2017-01-01
+];
+
+/// The directories searched by [`LeapSecondTable::from_env`].
+///
+/// These are the same directories searched for the Time Zone Database.
+#[cfg(feature = "std")]
+static ZONEINFO_DIRECTORIES: &[&str] =
+    &["/usr/share/zoneinfo", "/usr/share/lib/zoneinfo", "/etc/zoneinfo"];
+
+/// The number of seconds between the NTP epoch, `1900-01-01T00:00:00Z`, and
+/// the Unix epoch.
+#[cfg(feature = "alloc")]
+const NTP_UNIX_EPOCH_DIFF: i64 = 2_208_988_800;
+
+/// The UTC instant at which leap second tables start, `1972-01-01`, along
+/// with the offset between TAI and UTC at that instant.
+#[cfg(feature = "alloc")]
+const FIRST_ENTRY: LeapEntry = LeapEntry::new(63072000, 10);
+
+/// Parses the `leap-seconds.list` format.
+///
+/// See `LeapSecondTable::from_leap_seconds_list` for details.
+#[cfg(feature = "alloc")]
+fn parse_leap_seconds_list(data: &[u8]) -> Result<LeapSecondTable, Error> {
+    let data = core::str::from_utf8(data)
+        .map_err(|_| err!("leap second table data is not valid UTF-8"))?;
+    let mut entries = Vec::new();
+    let (mut updated, mut expires) = (None, None);
+    for (i, line) in data.lines().enumerate() {
+        let number = i + 1;
+        if let Some(rest) = line.strip_prefix("#$") {
+            updated =
+                Some(parse_ntp_timestamp(rest.trim()).with_context(|| {
+                    err!("invalid update time on line {number}")
+                })?);
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("#@") {
+            expires =
+                Some(parse_ntp_timestamp(rest.trim()).with_context(|| {
+                    err!("invalid expiration on line {number}")
+                })?);
+            continue;
+        }
+        let line = line.split('#').next().unwrap_or("");
+        let mut fields = line.split_whitespace();
+        let Some(ntp) = fields.next() else { continue };
+        let (Some(offset), None) = (fields.next(), fields.next()) else {
+            return Err(err!(
+                "expected exactly two fields on line {number}, \
+                 but found {line:?}",
+            ));
+        };
+        let utc_second = parse_ntp_timestamp(ntp)
+            .with_context(|| err!("invalid leap second on line {number}"))?
+            .as_second();
+        let tai_minus_utc = offset.parse::<i64>().map_err(|_| {
+            err!("invalid TAI-UTC offset {offset:?} on line {number}")
+        })?;
+        entries.push(LeapEntry::new(utc_second, tai_minus_utc));
+    }
+    LeapSecondTable::from_entries(entries, updated, expires)
+}
+
+/// Parses the `leapseconds` format.
+///
+/// See `LeapSecondTable::from_leapseconds` for details.
+#[cfg(feature = "alloc")]
+fn parse_leapseconds(data: &[u8]) -> Result<LeapSecondTable, Error> {
+    let data = core::str::from_utf8(data)
+        .map_err(|_| err!("leap second table data is not valid UTF-8"))?;
+    let mut entries = alloc::vec![FIRST_ENTRY];
+    let (mut updated, mut expires, mut expires_comment) = (None, None, None);
+    for (i, line) in data.lines().enumerate() {
+        let number = i + 1;
+        if let Some(rest) = line.strip_prefix("#updated") {
+            updated = Some(parse_unix_comment(rest).with_context(|| {
+                err!("invalid update time on line {number}")
+            })?);
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("#expires") {
+            expires_comment =
+                Some(parse_unix_comment(rest).with_context(|| {
+                    err!("invalid expiration on line {number}")
+                })?);
+            continue;
+        }
+        let line = line.split('#').next().unwrap_or("");
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields.first().copied() {
+            None => continue,
+            Some("Leap") => {
+                let &[_, year, month, day, time, corr, rs] = &*fields else {
+                    return Err(err!(
+                        "expected 7 fields for leap second on line {number}, \
+                         but found {line:?}",
+                    ));
+                };
+                let at = parse_zic_datetime(year, month, day, time)
+                    .with_context(|| {
+                        err!("invalid leap second on line {number}")
+                    })?;
+                let change = match corr {
+                    "+" => 1,
+                    "-" => -1,
+                    _ => {
+                        return Err(err!(
+                            "expected correction of `+` or `-` on line \
+                             {number}, but found {corr:?}",
+                        ))
+                    }
+                };
+                if rs != "S" {
+                    return Err(err!(
+                        "only stationary leap seconds (`S`) are supported, \
+                         but found {rs:?} on line {number}",
+                    ));
+                }
+                // OK because `entries` always starts with `FIRST_ENTRY`.
+                let last = entries.last().unwrap();
+                // The correction is made at the time given, so the new offset
+                // begins one second later. For a positive leap second, this
+                // is `23:59:60` (parsed as `23:59:59`) plus one second. For a
+                // negative leap second, `23:59:59` is skipped.
+                entries.push(LeapEntry::new(
+                    at.as_second() + 1,
+                    last.tai_minus_utc + change,
+                ));
+            }
+            Some("Expires") => {
+                let &[_, year, month, day, time] = &*fields else {
+                    return Err(err!(
+                        "expected 5 fields for expiration on line {number}, \
+                         but found {line:?}",
+                    ));
+                };
+                expires = Some(
+                    parse_zic_datetime(year, month, day, time).with_context(
+                        || err!("invalid expiration on line {number}"),
+                    )?,
+                );
+            }
+            Some(unknown) => {
+                return Err(err!(
+                    "unrecognized line type {unknown:?} on line {number}",
+                ));
+            }
+        }
+    }
+    LeapSecondTable::from_entries(
+        entries,
+        updated,
+        expires.or(expires_comment),
+    )
+}
+
+/// Parses an NTP timestamp, in seconds, into a Unix timestamp.
+#[cfg(feature = "alloc")]
+fn parse_ntp_timestamp(field: &str) -> Result<crate::Timestamp, Error> {
+    let ntp = field
+        .parse::<i64>()
+        .map_err(|_| err!("invalid NTP timestamp {field:?}"))?;
+    crate::Timestamp::from_second(ntp - NTP_UNIX_EPOCH_DIFF)
+}
+
+/// Parses the Unix timestamp at the beginning of a `#updated` or `#expires`
+/// comment in the `leapseconds` format.
+#[cfg(feature = "alloc")]
+fn parse_unix_comment(rest: &str) -> Result<crate::Timestamp, Error> {
+    let field = rest.split_whitespace().next().unwrap_or("");
+    let second = field
+        .parse::<i64>()
+        .map_err(|_| err!("invalid Unix timestamp {field:?}"))?;
+    crate::Timestamp::from_second(second)
+}
+
+/// Parses a UTC datetime in the `leapseconds` format, e.g.,
+/// `2016 Dec 31 23:59:60`.
+///
+/// A seconds value of `60` is parsed as `59`.
+#[cfg(feature = "alloc")]
+fn parse_zic_datetime(
+    year: &str,
+    month: &str,
+    day: &str,
+    time: &str,
+) -> Result<crate::Timestamp, Error> {
+    static MONTHS: &[&str] = &[
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+
+    let year =
+        year.parse::<i16>().map_err(|_| err!("invalid year {year:?}"))?;
+    let month_lower = month.to_ascii_lowercase();
+    let Some(index) = MONTHS.iter().position(|name| {
+        month_lower.len() >= 3 && name.starts_with(&*month_lower)
+    }) else {
+        return Err(err!("invalid month name {month:?}"));
+    };
+    let day = day.parse::<i8>().map_err(|_| err!("invalid day {day:?}"))?;
+    let date = Date::new(year, (index + 1) as i8, day)?;
+
+    let mut parts = time.split(':');
+    let (Some(h), Some(m), Some(s), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(err!(
+            "expected time in HH:MM:SS format, but found {time:?}"
+        ));
+    };
+    let parse = |part: &str| {
+        part.parse::<i8>().map_err(|_| err!("invalid time {time:?}"))
+    };
+    let (h, m, s) = (parse(h)?, parse(m)?, parse(s)?);
+    let time = Time::new(h, m, if s == 60 { 59 } else { s }, 0)?;
+    Offset::UTC.to_timestamp(date.to_datetime(time))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use crate::civil::date;
+
+    use super::*;
+
+    #[test]
+    fn builtin_is_sorted() {
+        let table = LeapSecondTable::builtin();
+        for pair in table.entries().windows(2) {
+            assert!(pair[0].utc_second < pair[1].utc_second);
+            assert_eq!(pair[0].tai_minus_utc + 1, pair[1].tai_minus_utc);
+        }
+        for entry in table.entries() {
+            let ts = crate::Timestamp::from_second(entry.utc_second).unwrap();
+            let dt = Offset::UTC.to_datetime(ts);
+            assert_eq!(dt.time(), crate::civil::Time::midnight());
+            assert!(dt.day() == 1 && (dt.month() == 1 || dt.month() == 7));
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let ts = |s: &str| s.parse::<crate::Timestamp>().unwrap();
+
+        for s in [
+            "1972-01-01T00:00:00Z",
+            "1972-06-30T23:59:59.999999999Z",
+            "1972-07-01T00:00:00Z",
+            "2016-12-31T23:59:59Z",
+            "2017-01-01T00:00:00Z",
+            "2025-06-30T12:34:56.789Z",
+            "9999-12-30T21:00:00Z",
+        ] {
+            let tai = Timestamp::from_utc(ts(s)).unwrap();
+            assert_eq!(tai.to_utc().unwrap(), ts(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn leap_second_is_clamped() {
+        let tai = |dt: DateTime| Timestamp::from_datetime(dt).unwrap();
+        let utc = |dt: DateTime| tai(dt).to_utc().unwrap().to_string();
+
+        // The leap second at the end of 2016-12-31 spans
+        // [2017-01-01T00:00:36, 2017-01-01T00:00:37) in TAI.
+        let d = date(2017, 1, 1);
+        assert_eq!(
+            utc(d.at(0, 0, 35, 999_999_999)),
+            "2016-12-31T23:59:59.999999999Z"
+        );
+        assert_eq!(utc(d.at(0, 0, 36, 0)), "2016-12-31T23:59:59.999999999Z");
+        assert_eq!(
+            utc(d.at(0, 0, 36, 999_999_999)),
+            "2016-12-31T23:59:59.999999999Z"
+        );
+        assert_eq!(utc(d.at(0, 0, 37, 0)), "2017-01-01T00:00:00Z");
+
+        // And the first leap second ever.
+        let d = date(1972, 7, 1);
+        assert_eq!(utc(d.at(0, 0, 10, 0)), "1972-06-30T23:59:59.999999999Z");
+        assert_eq!(utc(d.at(0, 0, 11, 0)), "1972-07-01T00:00:00Z");
+    }
+
+    #[test]
+    fn elapsed() {
+        let tai = |s: &str| {
+            Timestamp::from_utc(s.parse::<crate::Timestamp>().unwrap())
+                .unwrap()
+        };
+        // Every leap second since 1972, plus the 10 second initial offset.
+        let start = tai("1972-01-01T00:00:00Z");
+        let end = tai("2025-01-01T00:00:00Z");
+        let unix = SignedDuration::from_secs(1_735_689_600 - 63_072_000);
+        assert_eq!(
+            end.duration_since(start) - unix,
+            SignedDuration::from_secs(27)
+        );
+        assert_eq!(
+            start.duration_until(end) - unix,
+            SignedDuration::from_secs(27)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let ts = |s: &str| s.parse::<crate::Timestamp>().unwrap();
+
+        insta::assert_snapshot!(
+            Timestamp::from_utc(ts("1971-12-31T23:59:59.9Z")).unwrap_err(),
+            @"cannot convert UTC timestamp 1971-12-31T23:59:59.9Z to TAI because it is before the first entry in the leap second table at 1972-01-01T00:00:00Z",
+        );
+        insta::assert_snapshot!(
+            Timestamp::from_utc(crate::Timestamp::MAX).unwrap_err(),
+            @"failed to convert UTC timestamp 9999-12-30T22:00:00.999999999Z to TAI: parameter 'second' with value 253402207237 is not in the required range of -377705023201..=253402207200",
+        );
+        let tai =
+            Timestamp::from_datetime(date(1972, 1, 1).at(0, 0, 9, 0)).unwrap();
+        insta::assert_snapshot!(
+            tai.to_utc().unwrap_err(),
+            @"TAI timestamp 1972-01-01T00:00:09 TAI is before the first entry in the leap second table",
+        );
+        insta::assert_snapshot!(
+            LeapSecondTable {
+                entries: Entries::Static(&[]),
+                updated: None,
+                expires: None,
+            }
+                .utc_to_tai(ts("2025-01-01T00:00:00Z"))
+                .unwrap_err(),
+            @"cannot convert UTC timestamp 2025-01-01T00:00:00Z to TAI because the leap second table is empty",
+        );
+    }
+
+    #[test]
+    fn parse_leap_seconds_list() {
+        let builtin = LeapSecondTable::builtin();
+        let mut data = String::from("#\n#$\t3960835200\n#@\t3991593600\n#\n");
+        for entry in builtin.entries() {
+            let ntp = entry.utc_second + NTP_UNIX_EPOCH_DIFF;
+            data.push_str(&format!(
+                "{ntp}\t{}\t# comment\n",
+                entry.tai_minus_utc
+            ));
+        }
+        data.push_str("#h\tabcdef01 23456789 abcdef01 23456789 abcdef01\n");
+
+        let table = LeapSecondTable::from_leap_seconds_list(&data).unwrap();
+        assert_eq!(table.updated(), builtin.updated());
+        assert_eq!(table.expires(), builtin.expires());
+        let pairs = |t: &LeapSecondTable| {
+            t.entries()
+                .iter()
+                .map(|e| (e.utc_second, e.tai_minus_utc))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(pairs(&table), pairs(&builtin));
+    }
+
+    #[test]
+    fn parse_leapseconds() {
+        let builtin = LeapSecondTable::builtin();
+        let mut data = String::from(
+            "# comment\n\
+             #Expires 2026\tJun\t28\t00:00:00\n\
+             #updated 1751846400 (2025-07-07 00:00:00 UTC)\n\
+             #expires 1782604800 (2026-06-28 00:00:00 UTC)\n",
+        );
+        for pair in builtin.entries().windows(2) {
+            let ts =
+                crate::Timestamp::from_second(pair[1].utc_second - 1).unwrap();
+            let dt = Offset::UTC.to_datetime(ts);
+            let month = ["Jun", "Dec"][usize::from(dt.month() == 12)];
+            data.push_str(&format!(
+                "Leap\t{}\t{month}\t{}\t23:59:60\t+\tS\n",
+                dt.year(),
+                dt.day(),
+            ));
+        }
+
+        let table = LeapSecondTable::from_leapseconds(&data).unwrap();
+        assert_eq!(table.updated(), builtin.updated());
+        assert_eq!(table.expires(), builtin.expires());
+        let pairs = |t: &LeapSecondTable| {
+            t.entries()
+                .iter()
+                .map(|e| (e.utc_second, e.tai_minus_utc))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(pairs(&table), pairs(&builtin));
+
+        // An uncommented `Expires` line takes precedence, and negative leap
+        // seconds skip `23:59:59`.
+        let table = LeapSecondTable::from_leapseconds(
+            "#expires 1782604800\n\
+             Leap 2030 December 31 23:59:59 - S\n\
+             Expires 2031 Jun 28 00:00:00\n",
+        )
+        .unwrap();
+        insta::assert_snapshot!(table.expires().unwrap(), @"2031-06-28T00:00:00Z");
+        let ts = |s: &str| s.parse::<crate::Timestamp>().unwrap();
+        assert_eq!(
+            table.utc_tai_offset(ts("2030-12-31T23:59:58Z")).unwrap(),
+            SignedDuration::from_secs(10),
+        );
+        assert_eq!(
+            table.utc_tai_offset(ts("2031-01-01T00:00:00Z")).unwrap(),
+            SignedDuration::from_secs(9),
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let list = |data: &str| {
+            LeapSecondTable::from_leap_seconds_list(data)
+                .unwrap_err()
+                .to_string()
+        };
+        let leaps = |data: &str| {
+            LeapSecondTable::from_leapseconds(data).unwrap_err().to_string()
+        };
+
+        insta::assert_snapshot!(list("# empty\n"), @"failed to parse leap-seconds.list data: leap second table has no entries");
+        insta::assert_snapshot!(list("2272060800\n"), @"failed to parse leap-seconds.list data: expected exactly two fields on line 1, but found \"2272060800\"");
+        insta::assert_snapshot!(list("2272060800 10 11\n"), @"failed to parse leap-seconds.list data: expected exactly two fields on line 1, but found \"2272060800 10 11\"");
+        insta::assert_snapshot!(list("foo 10\n"), @"failed to parse leap-seconds.list data: invalid leap second on line 1: invalid NTP timestamp \"foo\"");
+        insta::assert_snapshot!(list("2272060800 ten\n"), @"failed to parse leap-seconds.list data: invalid TAI-UTC offset \"ten\" on line 1");
+        insta::assert_snapshot!(list("#$ foo\n2272060800 10\n"), @"failed to parse leap-seconds.list data: invalid update time on line 1: invalid NTP timestamp \"foo\"");
+        insta::assert_snapshot!(
+            list("2287785600 11\n2272060800 10\n"),
+            @"failed to parse leap-seconds.list data: leap second table entries must be in strictly increasing order, but entry at 1972-01-01T00:00:00Z is not after the previous entry",
+        );
+        insta::assert_snapshot!(
+            list("2272060800 10\n2287785600 12\n"),
+            @"failed to parse leap-seconds.list data: leap second table entry at 1972-07-01T00:00:00Z changes TAI-UTC offset from 10 to 12, but it must change by exactly one second",
+        );
+
+        insta::assert_snapshot!(leaps("Leap 1972 Jun 30 23:59:60 +\n"), @"failed to parse leapseconds data: expected 7 fields for leap second on line 1, but found \"Leap 1972 Jun 30 23:59:60 +\"");
+        insta::assert_snapshot!(leaps("Leap 1972 Foo 30 23:59:60 + S\n"), @"failed to parse leapseconds data: invalid leap second on line 1: invalid month name \"Foo\"");
+        insta::assert_snapshot!(leaps("Leap 1972 Jun 31 23:59:60 + S\n"), @"failed to parse leapseconds data: invalid leap second on line 1: parameter 'day' with value 31 is not in the required range of 1..=30");
+        insta::assert_snapshot!(leaps("Leap 1972 Jun 30 23:59 + S\n"), @"failed to parse leapseconds data: invalid leap second on line 1: expected time in HH:MM:SS format, but found \"23:59\"");
+        insta::assert_snapshot!(leaps("Leap 1972 Jun 30 23:59:60 * S\n"), @"failed to parse leapseconds data: expected correction of `+` or `-` on line 1, but found \"*\"");
+        insta::assert_snapshot!(leaps("Leap 1972 Jun 30 23:59:60 + R\n"), @"failed to parse leapseconds data: only stationary leap seconds (`S`) are supported, but found \"R\" on line 1");
+        insta::assert_snapshot!(leaps("Link foo bar\n"), @"failed to parse leapseconds data: unrecognized line type \"Link\" on line 1");
+        insta::assert_snapshot!(
+            leaps("Leap 1972 Dec 31 23:59:60 + S\nLeap 1972 Jun 30 23:59:60 + S\n"),
+            @"failed to parse leapseconds data: leap second table entries must be in strictly increasing order, but entry at 1972-07-01T00:00:00Z is not after the previous entry",
+        );
+    }
+
+    #[test]
+    fn leap_smear() {
+        let table = LeapSecondTable::builtin();
+        let utc = |s: &str| s.parse::<crate::Timestamp>().unwrap();
+        for smear in [LeapSmear::UtcSls, LeapSmear::Linear24Hour] {
+            // Smeared clocks agree with UTC at the edges of a smear.
+            for s in ["2016-12-31T11:59:59Z", "2017-01-01T12:00:00Z"] {
+                let smeared = table.utc_to_smeared(smear, utc(s)).unwrap();
+                assert_eq!(smeared, utc(s), "{smear:?} {s}");
+                assert_eq!(
+                    table.smeared_to_utc(smear, smeared).unwrap(),
+                    utc(s),
+                    "{smear:?} {s}",
+                );
+            }
+
+            // Smeared time never goes backwards, and round trips.
+            let start = Timestamp::from_utc(utc("2016-12-31T11:59:58Z"))
+                .unwrap()
+                .as_duration();
+            let mut prev = None;
+            for i in 0..=(86_405 * 2) {
+                let tai = Timestamp::from_duration(
+                    start + SignedDuration::from_millis(500 * i),
+                )
+                .unwrap();
+                let smeared = table.tai_to_smeared(smear, tai).unwrap();
+                assert!(prev < Some(smeared), "{smear:?} {tai}");
+                let back = table.smeared_to_tai(smear, smeared).unwrap();
+                assert_eq!(
+                    table.tai_to_smeared(smear, back).unwrap(),
+                    smeared,
+                    "{smear:?} {tai}",
+                );
+                assert!(
+                    tai.duration_since(back).abs()
+                        < SignedDuration::from_nanos(2),
+                    "{smear:?} {tai}",
+                );
+                prev = Some(smeared);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn leap_smear_negative() {
+        let table = LeapSecondTable::from_leapseconds(
+            "Leap 2030 Dec 31 23:59:59 - S\n",
+        )
+        .unwrap();
+        let utc = |s: &str| s.parse::<crate::Timestamp>().unwrap();
+
+        // With a negative leap second, the smeared clock runs fast.
+        let smear = LeapSmear::Linear24Hour;
+        insta::assert_snapshot!(
+            table.utc_to_smeared(smear, utc("2030-12-31T23:59:58Z")).unwrap(),
+            @"2030-12-31T23:59:58.499982638Z",
+        );
+        insta::assert_snapshot!(
+            table.utc_to_smeared(smear, utc("2031-01-01T00:00:00Z")).unwrap(),
+            @"2030-12-31T23:59:59.499994212Z",
+        );
+        let smear = LeapSmear::UtcSls;
+        insta::assert_snapshot!(
+            table.utc_to_smeared(smear, utc("2030-12-31T23:59:58Z")).unwrap(),
+            @"2030-12-31T23:59:58.998998998Z",
+        );
+        let smeared = utc("2030-12-31T23:59:59.5Z");
+        insta::assert_snapshot!(
+            table.smeared_to_utc(smear, smeared).unwrap(),
+            @"2030-12-31T23:59:58.5005Z",
+        );
+    }
+}
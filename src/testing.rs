@@ -0,0 +1,535 @@
+/*!
+Reusable invariant checks for Jiff's datetime and duration types.
+
+The routines in this module check properties that Jiff itself relies on in
+its own test suite. For example, that printing a [`Timestamp`] and parsing it
+back results in the same value, or that adding and then subtracting a
+duration is an identity operation. They are exposed so that crates which
+wrap or convert Jiff types can run the same checks against their own
+conversions, typically from within a property based testing harness like
+[`quickcheck`] or [`proptest`].
+
+Each check returns `Ok(())` when the invariant holds and an [`Error`]
+describing the violation otherwise. If the operation being checked fails
+for a legitimate reason (for example, adding a duration to a timestamp
+overflows), then the invariant is considered vacuously true and `Ok(())` is
+returned. This makes it easy to feed arbitrary inputs to these checks without
+having to filter them first.
+
+This module also provides a few sets of "edge" values (like
+[`edge_timestamps`]) that tend to be good at finding bugs. These are the same
+sorts of values that Jiff uses in its own tests.
+
+This module is only available when the `testing` crate feature is enabled.
+
+# Example
+
+This shows how one might check that a custom conversion to and from a
+`Timestamp` is lossless:
+
+```
+use jiff::{testing, Timestamp};
+
+// Imagine this is a type defined in a different crate.
+#[derive(Debug)]
+struct MyTimestamp {
+    seconds: i64,
+    nanos: i32,
+}
+
+for ts in testing::edge_timestamps() {
+    testing::roundtrip(
+        &ts,
+        |ts| MyTimestamp { seconds: ts.as_second(), nanos: ts.subsec_nanosecond() },
+        |my| Timestamp::new(my.seconds, my.nanos),
+    )?;
+}
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+[`quickcheck`]: https://docs.rs/quickcheck
+[`proptest`]: https://docs.rs/proptest
+*/
+
+use alloc::string::ToString;
+
+use crate::{
+    error::err, fmt::friendly::SpanPrinter, util::t, Error, SignedDuration,
+    SignedDurationRound, Span, Timestamp, TimestampRound, Unit, Zoned,
+};
+
+/// Checks that converting `value` with `forward` and then converting the
+/// result back with `backward` produces a value equal to `value`.
+///
+/// This is the most general check in this module. All of the other
+/// round-trip checks are implemented in terms of it.
+///
+/// # Errors
+///
+/// This returns an error when `backward` fails or when the value it returns
+/// is not equal to `value`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::Date, testing};
+///
+/// let date = jiff::civil::date(2024, 2, 29);
+/// testing::roundtrip(&date, |d| d.to_string(), |s| s.parse::<Date>())?;
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn roundtrip<T, U, E>(
+    value: &T,
+    forward: impl FnOnce(&T) -> U,
+    backward: impl FnOnce(U) -> Result<T, E>,
+) -> Result<(), Error>
+where
+    T: core::fmt::Debug + PartialEq,
+    U: core::fmt::Debug,
+    E: core::fmt::Display,
+{
+    let intermediate = forward(value);
+    let intermediate_debug = alloc::format!("{intermediate:?}");
+    let got = backward(intermediate).map_err(|e| {
+        err!(
+            "round trip of {value:?} failed: converting \
+             {intermediate_debug} back failed: {e}",
+        )
+    })?;
+    if &got != value {
+        return Err(err!(
+            "round trip of {value:?} failed: converting \
+             {intermediate_debug} back resulted in {got:?}",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that printing a timestamp in the [`fmt::temporal`] format and
+/// parsing it back results in the same timestamp.
+///
+/// [`fmt::temporal`]: crate::fmt::temporal
+///
+/// # Example
+///
+/// ```
+/// use jiff::{testing, Timestamp};
+///
+/// testing::timestamp_roundtrip(Timestamp::MAX)?;
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn timestamp_roundtrip(timestamp: Timestamp) -> Result<(), Error> {
+    roundtrip(&timestamp, |ts| ts.to_string(), |s| s.parse::<Timestamp>())
+}
+
+/// Checks that printing a zoned datetime in the [`fmt::temporal`] format and
+/// parsing it back results in the same instant in the same time zone.
+///
+/// Since parsing a zoned datetime requires looking up its time zone, this
+/// check is only reliable for zoned datetimes with time zones that can be
+/// found in the default time zone database (or that are fixed offsets).
+///
+/// [`fmt::temporal`]: crate::fmt::temporal
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil, testing};
+///
+/// let zdt = civil::date(2024, 11, 3).at(1, 30, 0, 0).in_tz("America/New_York")?;
+/// testing::zoned_roundtrip(&zdt)?;
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn zoned_roundtrip(zdt: &Zoned) -> Result<(), Error> {
+    let printed = zdt.to_string();
+    let got = printed.parse::<Zoned>().map_err(|e| {
+        err!("round trip of {zdt} failed: parsing {printed:?} failed: {e}")
+    })?;
+    if &got != zdt || got.time_zone() != zdt.time_zone() {
+        return Err(err!(
+            "round trip of {zdt} failed: parsing {printed:?} \
+             resulted in {got}",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that printing a span in the [`fmt::friendly`] format and parsing it
+/// back results in a span with exactly the same unit values.
+///
+/// The friendly format is used because, unlike the ISO 8601 duration format,
+/// it can losslessly represent every possible `Span`.
+///
+/// [`fmt::friendly`]: crate::fmt::friendly
+///
+/// # Example
+///
+/// ```
+/// use jiff::{testing, ToSpan};
+///
+/// testing::span_roundtrip(1.year().milliseconds(1_500))?;
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn span_roundtrip(span: Span) -> Result<(), Error> {
+    static PRINTER: SpanPrinter = SpanPrinter::new();
+    roundtrip(
+        &span.fieldwise(),
+        |span| PRINTER.span_to_string(&span.0),
+        |s| s.parse::<Span>().map(|span| span.fieldwise()),
+    )
+}
+
+/// Checks that printing a signed duration in both the ISO 8601 and
+/// [`fmt::friendly`] formats, and parsing each back, results in the same
+/// duration.
+///
+/// [`fmt::friendly`]: crate::fmt::friendly
+///
+/// # Example
+///
+/// ```
+/// use jiff::{testing, SignedDuration};
+///
+/// testing::signed_duration_roundtrip(SignedDuration::MIN)?;
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn signed_duration_roundtrip(dur: SignedDuration) -> Result<(), Error> {
+    roundtrip(&dur, |dur| dur.to_string(), |s| s.parse::<SignedDuration>())?;
+    roundtrip(
+        &dur,
+        |dur| alloc::format!("{dur:#}"),
+        |s| s.parse::<SignedDuration>(),
+    )
+}
+
+/// Checks that adding `dur` to `timestamp` and then subtracting `dur` from
+/// the result gives back `timestamp`.
+///
+/// If the addition overflows, then this returns `Ok(())`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{testing, SignedDuration, Timestamp};
+///
+/// for ts in testing::edge_timestamps() {
+///     for dur in testing::edge_signed_durations() {
+///         testing::timestamp_add_sub_inverse(ts, dur)?;
+///     }
+/// }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn timestamp_add_sub_inverse(
+    timestamp: Timestamp,
+    dur: SignedDuration,
+) -> Result<(), Error> {
+    let Ok(sum) = timestamp.checked_add(dur) else { return Ok(()) };
+    let got = sum.checked_sub(dur).map_err(|e| {
+        err!(
+            "subtracting {dur:?} from {sum} (which is {timestamp} + {dur:?}) \
+             failed: {e}",
+        )
+    })?;
+    if got != timestamp {
+        return Err(err!(
+            "adding {dur:?} to {timestamp} gave {sum}, \
+             but subtracting {dur:?} from {sum} gave {got}",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that adding `dur` to `zdt` and then subtracting `dur` from the
+/// result gives back a zoned datetime equal to `zdt`.
+///
+/// Unlike adding a [`Span`] with calendar units, adding an absolute duration
+/// is always reversible, even across time zone transitions.
+///
+/// If the addition overflows, then this returns `Ok(())`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil, testing, SignedDuration};
+///
+/// let zdt = civil::date(2024, 3, 10).at(1, 59, 59, 0).in_tz("America/New_York")?;
+/// testing::zoned_add_sub_inverse(&zdt, SignedDuration::from_secs(1))?;
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn zoned_add_sub_inverse(
+    zdt: &Zoned,
+    dur: SignedDuration,
+) -> Result<(), Error> {
+    let Ok(sum) = zdt.checked_add(dur) else { return Ok(()) };
+    let got = sum.checked_sub(dur).map_err(|e| {
+        err!(
+            "subtracting {dur:?} from {sum} (which is {zdt} + {dur:?}) \
+             failed: {e}",
+        )
+    })?;
+    if &got != zdt || got.time_zone() != zdt.time_zone() {
+        return Err(err!(
+            "adding {dur:?} to {zdt} gave {sum}, \
+             but subtracting {dur:?} from {sum} gave {got}",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that rounding `timestamp` with the given options results in a
+/// timestamp that is a multiple of the rounding increment and that is less
+/// than one increment away from `timestamp`.
+///
+/// If rounding fails (for example, because of overflow or an invalid
+/// configuration), then this returns `Ok(())`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{testing, RoundMode, TimestampRound, Unit};
+///
+/// let options = TimestampRound::new()
+///     .smallest(Unit::Minute)
+///     .increment(15)
+///     .mode(RoundMode::Ceil);
+/// for ts in testing::edge_timestamps() {
+///     testing::timestamp_round_bounds(ts, options)?;
+/// }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn timestamp_round_bounds(
+    timestamp: Timestamp,
+    options: TimestampRound,
+) -> Result<(), Error> {
+    let Ok(rounded) = timestamp.round(options) else { return Ok(()) };
+    let increment =
+        increment_nanoseconds(options.get_smallest(), options.get_increment());
+    round_bounds(
+        timestamp,
+        rounded,
+        timestamp.as_nanosecond(),
+        rounded.as_nanosecond(),
+        increment,
+    )
+}
+
+/// Checks that rounding `dur` with the given options results in a duration
+/// that is a multiple of the rounding increment and that is less than one
+/// increment away from `dur`.
+///
+/// If rounding fails (for example, because of overflow or an invalid
+/// configuration), then this returns `Ok(())`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{testing, SignedDurationRound, Unit};
+///
+/// let options = SignedDurationRound::new().smallest(Unit::Second);
+/// for dur in testing::edge_signed_durations() {
+///     testing::signed_duration_round_bounds(dur, options)?;
+/// }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn signed_duration_round_bounds(
+    dur: SignedDuration,
+    options: SignedDurationRound,
+) -> Result<(), Error> {
+    let Ok(rounded) = dur.round(options) else { return Ok(()) };
+    let increment =
+        increment_nanoseconds(options.get_smallest(), options.get_increment());
+    round_bounds(dur, rounded, dur.as_nanos(), rounded.as_nanos(), increment)
+}
+
+/// Returns a sequence of timestamps that are useful for finding bugs.
+///
+/// This includes the minimum and maximum timestamps, the Unix epoch and
+/// values adjacent to each, along with some values near interesting
+/// calendar boundaries like leap days.
+pub fn edge_timestamps() -> impl Iterator<Item = Timestamp> + Clone {
+    const SECONDS: &[(i64, i32)] = &[
+        (0, 0),
+        (0, 1),
+        (-1, 999_999_999),
+        (-1, 0),
+        (1, 0),
+        (86_399, 999_999_999),
+        (86_400, 0),
+        // 2000-02-29T00:00:00Z
+        (951_782_400, 0),
+        // 2038-01-19T03:14:07Z
+        (2_147_483_647, 0),
+        // 2038-01-19T03:14:08Z
+        (2_147_483_648, 0),
+        // 1901-12-13T20:45:52Z
+        (-2_147_483_648, 0),
+        // 0000-01-01T00:00:00Z
+        (-62_167_219_200, 0),
+        // -000001-12-31T23:59:59.999999999Z
+        (-62_167_219_201, 999_999_999),
+    ];
+    let extremes = [
+        Timestamp::MIN,
+        Timestamp::MAX,
+        Timestamp::MIN + SignedDuration::from_nanos(1),
+        Timestamp::MAX - SignedDuration::from_nanos(1),
+    ];
+    SECONDS
+        .iter()
+        // OK because every value above is in range.
+        .map(|&(secs, nanos)| Timestamp::new(secs, nanos).unwrap())
+        .chain(extremes)
+}
+
+/// Returns a sequence of signed durations that are useful for finding bugs.
+///
+/// This includes zero, the minimum and maximum durations, and values near
+/// unit boundaries.
+pub fn edge_signed_durations() -> impl Iterator<Item = SignedDuration> + Clone
+{
+    const DURATIONS: &[(i64, i32)] = &[
+        (0, 0),
+        (0, 1),
+        (0, -1),
+        (0, 999_999_999),
+        (0, -999_999_999),
+        (1, 0),
+        (-1, 0),
+        (59, 999_999_999),
+        (3_599, 999_999_999),
+        (-3_600, 0),
+        (86_400, 0),
+        (-86_400, -1),
+        (i64::MAX, 999_999_999),
+        (i64::MIN, -999_999_999),
+    ];
+    DURATIONS.iter().map(|&(secs, nanos)| SignedDuration::new(secs, nanos))
+}
+
+/// Returns a sequence of spans that are useful for finding bugs.
+///
+/// This includes the zero span, spans with only one unit set to its minimum
+/// or maximum value and spans with a mix of calendar and time units.
+pub fn edge_spans() -> impl Iterator<Item = Span> + Clone {
+    let maxes = [
+        Span::new().years(t::SpanYears::MAX_REPR),
+        Span::new().months(t::SpanMonths::MAX_REPR),
+        Span::new().weeks(t::SpanWeeks::MAX_REPR),
+        Span::new().days(t::SpanDays::MAX_REPR),
+        Span::new().hours(t::SpanHours::MAX_REPR),
+        Span::new().minutes(t::SpanMinutes::MAX_REPR),
+        Span::new().seconds(t::SpanSeconds::MAX_REPR),
+        Span::new().milliseconds(t::SpanMilliseconds::MAX_REPR),
+        Span::new().microseconds(t::SpanMicroseconds::MAX_REPR),
+        Span::new().nanoseconds(t::SpanNanoseconds::MAX_REPR),
+    ];
+    let mixed = [
+        Span::new(),
+        Span::new().years(1).months(1).days(1),
+        Span::new().days(1).hours(24),
+        Span::new().hours(1).minutes(59).seconds(59).nanoseconds(999_999_999),
+        Span::new().months(1).milliseconds(1_500),
+        Span::new().weeks(1).days(-1),
+    ];
+    maxes
+        .into_iter()
+        .flat_map(|span| [span, span.negate()])
+        .chain(mixed.into_iter().flat_map(|span| [span, span.negate()]))
+}
+
+/// Returns the size of the given rounding increment in nanoseconds.
+///
+/// This saturates when the increment is too big, which is fine because
+/// rounding with such an increment would have already failed.
+fn increment_nanoseconds(unit: Unit, increment: i64) -> i128 {
+    let unit = unit.nanoseconds().get();
+    unit.saturating_mul(i128::from(increment))
+}
+
+/// The shared implementation of the rounding bounds checks.
+fn round_bounds<T: core::fmt::Debug>(
+    original: T,
+    rounded: T,
+    original_nanos: i128,
+    rounded_nanos: i128,
+    increment: i128,
+) -> Result<(), Error> {
+    if rounded_nanos % increment != 0 {
+        return Err(err!(
+            "rounding {original:?} gave {rounded:?}, which is not a \
+             multiple of the rounding increment of {increment}ns",
+        ));
+    }
+    if (rounded_nanos - original_nanos).abs() >= increment {
+        return Err(err!(
+            "rounding {original:?} gave {rounded:?}, which is not within \
+             the rounding increment of {increment}ns",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RoundMode, ToSpan};
+
+    use super::*;
+
+    #[test]
+    fn edge_values_pass() {
+        for ts in edge_timestamps() {
+            timestamp_roundtrip(ts).unwrap();
+            for dur in edge_signed_durations() {
+                timestamp_add_sub_inverse(ts, dur).unwrap();
+            }
+            for mode in
+                [RoundMode::Ceil, RoundMode::Floor, RoundMode::HalfEven]
+            {
+                let options = TimestampRound::new()
+                    .smallest(Unit::Hour)
+                    .increment(2)
+                    .mode(mode);
+                timestamp_round_bounds(ts, options).unwrap();
+            }
+        }
+        for dur in edge_signed_durations() {
+            signed_duration_roundtrip(dur).unwrap();
+            let options = SignedDurationRound::new()
+                .smallest(Unit::Millisecond)
+                .increment(250);
+            signed_duration_round_bounds(dur, options).unwrap();
+        }
+        for span in edge_spans() {
+            span_roundtrip(span).unwrap();
+        }
+    }
+
+    #[test]
+    fn violations_are_reported() {
+        let result = roundtrip(&5, |&n| n + 1, Ok::<_, Error>);
+        insta::assert_snapshot!(
+            result.unwrap_err(),
+            @"round trip of 5 failed: converting 6 back resulted in 6",
+        );
+
+        let result = roundtrip(
+            &1.hour().fieldwise(),
+            |span| span.0.to_string(),
+            |s| s.parse::<Span>().map(|_| 60.minutes().fieldwise()),
+        );
+        insta::assert_snapshot!(
+            result.unwrap_err(),
+            @r#"round trip of SpanFieldwise(1h) failed: converting "PT1H" back resulted in SpanFieldwise(60m)"#,
+        );
+    }
+}
@@ -1,5 +1,10 @@
 use core::time::Duration as UnsignedDuration;
 
+#[cfg(feature = "rand")]
+use rand::distr::uniform::{
+    SampleBorrow, SampleUniform, UniformInt, UniformSampler,
+};
+
 use crate::{
     duration::{Duration, SDuration},
     error::{err, Error, ErrorContext},
@@ -10,7 +15,7 @@ use crate::{
     shared::util::itime::ITimestamp,
     tz::{Offset, TimeZone},
     util::{
-        rangeint::{self, Composite, RFrom, RInto},
+        rangeint::{self, Composite, RFrom, RInto, TryRFrom},
         round::increment,
         t::{
             self, FractionalNanosecond, NoUnits, NoUnits128, UnixMicroseconds,
@@ -1257,6 +1262,498 @@ impl Timestamp {
         self.as_second() == 0 && self.subsec_nanosecond() == 0
     }
 
+    /// Returns the memory representation of this timestamp as a byte array
+    /// in big-endian (network) byte order.
+    ///
+    /// The layout is 12 bytes: the number of whole seconds since the Unix
+    /// epoch as an `i64` followed by the fractional nanoseconds as an `i32`,
+    /// each in big-endian byte order. That is, the values returned by
+    /// [`Timestamp::as_second`] and [`Timestamp::subsec_nanosecond`]. Since
+    /// the sign of both components always agrees, timestamps before the Unix
+    /// epoch have both components negative.
+    ///
+    /// This is the same layout used by [`SignedDuration::to_be_bytes`] for
+    /// the duration since the Unix epoch. It is stable and will not change
+    /// in semver compatible releases. Use [`Timestamp::from_be_bytes`] to
+    /// decode it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts = Timestamp::new(1_700_000_000, 123)?;
+    /// assert_eq!(
+    ///     ts.to_be_bytes(),
+    ///     [0, 0, 0, 0, 0x65, 0x53, 0xF1, 0x00, 0, 0, 0, 123],
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_be_bytes(self) -> [u8; 12] {
+        self.as_duration().to_be_bytes()
+    }
+
+    /// Returns the memory representation of this timestamp as a byte array
+    /// in little-endian byte order.
+    ///
+    /// The layout is 12 bytes: the number of whole seconds since the Unix
+    /// epoch as an `i64` followed by the fractional nanoseconds as an `i32`,
+    /// each in little-endian byte order. Note that only the bytes within each
+    /// component are reversed relative to [`Timestamp::to_be_bytes`]. The
+    /// seconds always come first.
+    ///
+    /// This layout is stable and will not change in semver compatible
+    /// releases. Use [`Timestamp::from_le_bytes`] to decode it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts = Timestamp::new(1_700_000_000, 123)?;
+    /// assert_eq!(
+    ///     ts.to_le_bytes(),
+    ///     [0x00, 0xF1, 0x53, 0x65, 0, 0, 0, 0, 123, 0, 0, 0],
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_le_bytes(self) -> [u8; 12] {
+        self.as_duration().to_le_bytes()
+    }
+
+    /// Creates a timestamp from its memory representation as a byte array in
+    /// big-endian (network) byte order.
+    ///
+    /// This is the inverse of [`Timestamp::to_be_bytes`]. See its
+    /// documentation for a description of the layout.
+    ///
+    /// If the signs of the seconds and nanoseconds differ, then they are
+    /// balanced in the same way as [`Timestamp::new`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the absolute value of the nanoseconds is
+    /// greater than or equal to 1 second, or when the decoded timestamp is
+    /// outside the range supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let bytes = [0, 0, 0, 0, 0x65, 0x53, 0xF1, 0x00, 0, 0, 0, 123];
+    /// let ts = Timestamp::from_be_bytes(bytes)?;
+    /// assert_eq!(ts.to_string(), "2023-11-14T22:13:20.000000123Z");
+    ///
+    /// // Values outside of Jiff's supported range are rejected.
+    /// let bytes = [0x7F, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    /// assert!(Timestamp::from_be_bytes(bytes).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_be_bytes(bytes: [u8; 12]) -> Result<Timestamp, Error> {
+        Timestamp::from_duration(SignedDuration::from_be_bytes(bytes)?)
+    }
+
+    /// Creates a timestamp from its memory representation as a byte array in
+    /// little-endian byte order.
+    ///
+    /// This is the inverse of [`Timestamp::to_le_bytes`]. See its
+    /// documentation for a description of the layout.
+    ///
+    /// If the signs of the seconds and nanoseconds differ, then they are
+    /// balanced in the same way as [`Timestamp::new`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the absolute value of the nanoseconds is
+    /// greater than or equal to 1 second, or when the decoded timestamp is
+    /// outside the range supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts = Timestamp::new(-5, -123_456_789)?;
+    /// assert_eq!(Timestamp::from_le_bytes(ts.to_le_bytes())?, ts);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 12]) -> Result<Timestamp, Error> {
+        Timestamp::from_duration(SignedDuration::from_le_bytes(bytes)?)
+    }
+
+    /// Returns an order preserving encoding of this timestamp as a byte
+    /// array.
+    ///
+    /// Comparing two encodings byte-wise (i.e., lexicographically) gives the
+    /// same result as comparing the timestamps themselves. This makes the
+    /// encoding suitable for use as a key in ordered key-value stores like
+    /// LMDB or RocksDB. Neither [`Timestamp::to_be_bytes`] nor
+    /// [`Timestamp::to_le_bytes`] have this property, since they encode
+    /// timestamps before the Unix epoch with their sign bit set.
+    ///
+    /// The layout is 12 bytes: the number of whole seconds since the Unix
+    /// epoch as an `i64` followed by the fractional nanoseconds as an `i32`,
+    /// each in big-endian byte order with its sign bit flipped. Big-endian
+    /// byte order is required because a little-endian encoding cannot be
+    /// sorted byte-wise. This is the same layout used by
+    /// [`SignedDuration::to_sortable_bytes`] for the duration since the Unix
+    /// epoch.
+    ///
+    /// This layout is stable and will not change in semver compatible
+    /// releases. Use [`Timestamp::from_sortable_bytes`] to decode it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts = Timestamp::new(1_700_000_000, 123)?;
+    /// assert_eq!(
+    ///     ts.to_sortable_bytes(),
+    ///     [0x80, 0, 0, 0, 0x65, 0x53, 0xF1, 0x00, 0x80, 0, 0, 123],
+    /// );
+    ///
+    /// // Timestamps before the Unix epoch sort before it.
+    /// let before = Timestamp::new(-1, -1)?;
+    /// let epoch = Timestamp::UNIX_EPOCH;
+    /// assert!(before.to_sortable_bytes() < epoch.to_sortable_bytes());
+    /// let min = Timestamp::MIN;
+    /// assert!(min.to_sortable_bytes() < before.to_sortable_bytes());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_sortable_bytes(self) -> [u8; 12] {
+        self.as_duration().to_sortable_bytes()
+    }
+
+    /// Creates a timestamp from its order preserving encoding as a byte
+    /// array.
+    ///
+    /// This is the inverse of [`Timestamp::to_sortable_bytes`]. See its
+    /// documentation for a description of the layout.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the absolute value of the nanoseconds is
+    /// greater than or equal to 1 second, or when the decoded timestamp is
+    /// outside the range supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts: Timestamp = "1969-12-31T23:59:58.5Z".parse()?;
+    /// let bytes = ts.to_sortable_bytes();
+    /// assert_eq!(Timestamp::from_sortable_bytes(bytes)?, ts);
+    ///
+    /// // Values outside of Jiff's supported range are rejected.
+    /// let bytes = [0xFF, 0, 0, 0, 0, 0, 0, 0, 0x80, 0, 0, 0];
+    /// assert!(Timestamp::from_sortable_bytes(bytes).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_sortable_bytes(bytes: [u8; 12]) -> Result<Timestamp, Error> {
+        Timestamp::from_duration(SignedDuration::from_sortable_bytes(bytes)?)
+    }
+
+    /// Creates a timestamp from a number of .NET ticks.
+    ///
+    /// A tick is 100 nanoseconds, and .NET ticks count from
+    /// `0001-01-01T00:00:00Z`. This corresponds to the `Ticks` property on
+    /// .NET's `DateTime` and `DateTimeOffset` types. Note that the ticks of a
+    /// `DateTime` are relative to its `Kind`, so they only correspond to a
+    /// UTC instant when the `DateTime` is in UTC (e.g., after calling
+    /// `ToUniversalTime()`). For a `DateTimeOffset`, use its `UtcTicks`
+    /// property.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the ticks are outside the range supported
+    /// by .NET, which is `0..=3_155_378_975_999_999_999` (that is,
+    /// `0001-01-01T00:00:00Z` to `9999-12-31T23:59:59.9999999Z`).
+    ///
+    /// This also returns an error when the ticks are within .NET's range but
+    /// outside of Jiff's. Since Jiff's maximum timestamp is a little over a
+    /// day before .NET's, this happens for the last day or so of .NET's
+    /// range. In particular, it happens for `DateTime.MaxValue`, which is
+    /// sometimes used as a sentinel value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts = Timestamp::from_dotnet_ticks(638_712_864_000_000_000)?;
+    /// assert_eq!(ts.to_string(), "2025-01-01T00:00:00Z");
+    ///
+    /// let ts = Timestamp::from_dotnet_ticks(0)?;
+    /// assert_eq!(ts.to_string(), "0001-01-01T00:00:00Z");
+    ///
+    /// assert!(Timestamp::from_dotnet_ticks(-1).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Example: clamping `DateTime.MaxValue`
+    ///
+    /// Callers that need to accept every .NET `DateTime` can clamp ticks
+    /// beyond Jiff's range to [`Timestamp::MAX`]:
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// fn from_dotnet_ticks_clamped(
+    ///     ticks: i64,
+    /// ) -> Result<Timestamp, jiff::Error> {
+    ///     let max = Timestamp::MAX.to_dotnet_ticks()?;
+    ///     Timestamp::from_dotnet_ticks(ticks.min(max))
+    /// }
+    ///
+    /// // `DateTime.MaxValue.Ticks`
+    /// let ticks = 3_155_378_975_999_999_999;
+    /// assert!(Timestamp::from_dotnet_ticks(ticks).is_err());
+    /// assert_eq!(
+    ///     from_dotnet_ticks_clamped(ticks)?.to_string(),
+    ///     "9999-12-30T22:00:00.9999999Z",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_dotnet_ticks(ticks: i64) -> Result<Timestamp, Error> {
+        if !(0..=DOTNET_MAX_TICKS).contains(&ticks) {
+            return Err(err!(
+                ".NET ticks {ticks} are not in the required range \
+                 of 0..={DOTNET_MAX_TICKS}",
+            ));
+        }
+        let nanosecond = i128::from(ticks - DOTNET_UNIX_EPOCH_TICKS) * 100;
+        Timestamp::from_nanosecond(nanosecond)
+            .with_context(|| err!("failed to convert .NET ticks {ticks}"))
+    }
+
+    /// Returns this timestamp as a number of .NET ticks.
+    ///
+    /// A tick is 100 nanoseconds, and .NET ticks count from
+    /// `0001-01-01T00:00:00Z`. The value returned can be used to construct
+    /// a .NET `DateTime` with `DateTimeKind.Utc` or a `DateTimeOffset` with
+    /// a zero offset.
+    ///
+    /// Any precision finer than 100 nanoseconds is truncated. That is, the
+    /// timestamp is rounded down to the nearest tick.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the timestamp is before
+    /// `0001-01-01T00:00:00Z`, since .NET doesn't support negative ticks.
+    /// Every timestamp on or after that is within .NET's range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts: Timestamp = "2025-01-01T00:00:00.123456789Z".parse()?;
+    /// assert_eq!(ts.to_dotnet_ticks()?, 638_712_864_001_234_567);
+    ///
+    /// let ts: Timestamp = "0000-12-31T23:59:59.999999999Z".parse()?;
+    /// assert!(ts.to_dotnet_ticks().is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_dotnet_ticks(self) -> Result<i64, Error> {
+        let ticks = self.as_nanosecond().div_euclid(100)
+            + i128::from(DOTNET_UNIX_EPOCH_TICKS);
+        if ticks < 0 {
+            return Err(err!(
+                "timestamp {self} is before 0001-01-01T00:00:00Z, \
+                 and cannot be represented as .NET ticks",
+            ));
+        }
+        // OK because Jiff's maximum timestamp is before .NET's.
+        Ok(i64::try_from(ticks).unwrap())
+    }
+
+    /// Returns the astronomical Julian Date (JD) of this timestamp.
+    ///
+    /// The Julian Date is the number of days, including a fractional part,
+    /// since noon UTC on `-4713-11-24` in the proleptic Gregorian calendar
+    /// (which is January 1, 4713 BC in the proleptic Julian calendar). For
+    /// example, the Unix epoch has a Julian Date of `2_440_587.5`, since it
+    /// is at midnight, which is half a day after the Julian day `2_440_587`
+    /// started.
+    ///
+    /// This treats the timestamp as a UTC instant and, like the rest of
+    /// Jiff, ignores leap seconds. Astronomical applications that need a
+    /// Julian Date in a different time scale (like TT or TAI) should adjust
+    /// for the difference themselves.
+    ///
+    /// Since an `f64` only has about 15 significant decimal digits, the
+    /// Julian Date of a timestamp near the present is only precise to about
+    /// 50 microseconds.
+    ///
+    /// The integral Julian Day Number of a civil date is available via
+    /// [`civil::Date::to_julian_day`](crate::civil::Date::to_julian_day).
+    /// The Modified Julian Date, which is more commonly used by satellite
+    /// data formats, is available via [`Timestamp::to_mjd`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// assert_eq!(Timestamp::UNIX_EPOCH.to_julian_date(), 2_440_587.5);
+    ///
+    /// // The J2000.0 epoch (ignoring the difference between TT and UTC).
+    /// let ts: Timestamp = "2000-01-01T12:00:00Z".parse()?;
+    /// assert_eq!(ts.to_julian_date(), 2_451_545.0);
+    ///
+    /// let ts: Timestamp = "2000-01-01T18:00:00Z".parse()?;
+    /// assert_eq!(ts.to_julian_date(), 2_451_545.25);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_julian_date(self) -> f64 {
+        let days = self.as_duration().as_secs_f64() / SECONDS_PER_DAY;
+        days + UNIX_EPOCH_JULIAN_DATE
+    }
+
+    /// Creates a timestamp from an astronomical Julian Date (JD).
+    ///
+    /// This is the inverse of [`Timestamp::to_julian_date`]. See its
+    /// documentation for more details on Julian Dates.
+    ///
+    /// The timestamp returned is rounded to the nearest nanosecond. But since
+    /// an `f64` only has about 15 significant decimal digits, the precision
+    /// of the timestamp returned for a Julian Date near the present is only
+    /// about 50 microseconds.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the given Julian Date isn't finite, or when
+    /// it corresponds to a timestamp outside the range supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{Timestamp, Unit};
+    ///
+    /// let ts = Timestamp::from_julian_date(2_451_545.0)?;
+    /// assert_eq!(ts.to_string(), "2000-01-01T12:00:00Z");
+    ///
+    /// let ts = Timestamp::from_julian_date(2_460_677.25)?;
+    /// assert_eq!(ts.to_string(), "2025-01-01T18:00:00Z");
+    ///
+    /// // Fractions that aren't exactly representable in an `f64` round
+    /// // trip to within a millisecond or so.
+    /// let ts: Timestamp = "2025-01-01T12:34:56.789Z".parse()?;
+    /// let got = Timestamp::from_julian_date(ts.to_julian_date())?;
+    /// assert_eq!(got.round(Unit::Millisecond)?, ts);
+    ///
+    /// assert!(Timestamp::from_julian_date(f64::NAN).is_err());
+    /// assert!(Timestamp::from_julian_date(-1e9).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_julian_date(jd: f64) -> Result<Timestamp, Error> {
+        let seconds = (jd - UNIX_EPOCH_JULIAN_DATE) * SECONDS_PER_DAY;
+        SignedDuration::try_from_secs_f64(seconds)
+            .and_then(Timestamp::from_duration)
+            .with_context(|| err!("failed to convert Julian Date {jd}"))
+    }
+
+    /// Returns the Modified Julian Date (MJD) of this timestamp.
+    ///
+    /// The Modified Julian Date is the number of days, including a fractional
+    /// part, since midnight UTC on `1858-11-17`. It is equivalent to the
+    /// [Julian Date](Timestamp::to_julian_date) minus `2_400_000.5`, and is
+    /// commonly used in satellite and astronomy formats like SP3 and RINEX.
+    /// Unlike Julian Dates, Modified Julian Days start at midnight.
+    ///
+    /// As with [`Timestamp::to_julian_date`], this treats the timestamp as
+    /// a UTC instant and ignores leap seconds. Since the Modified Julian Date
+    /// is computed directly (instead of by subtracting from the Julian Date),
+    /// its precision for timestamps near the present is about 1 microsecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// assert_eq!(Timestamp::UNIX_EPOCH.to_mjd(), 40_587.0);
+    ///
+    /// let ts: Timestamp = "1858-11-17T00:00:00Z".parse()?;
+    /// assert_eq!(ts.to_mjd(), 0.0);
+    ///
+    /// let ts: Timestamp = "2025-01-01T18:00:00Z".parse()?;
+    /// assert_eq!(ts.to_mjd(), 60_676.75);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_mjd(self) -> f64 {
+        let days = self.as_duration().as_secs_f64() / SECONDS_PER_DAY;
+        days + UNIX_EPOCH_MJD
+    }
+
+    /// Creates a timestamp from a Modified Julian Date (MJD).
+    ///
+    /// This is the inverse of [`Timestamp::to_mjd`]. See its documentation
+    /// for more details on Modified Julian Dates.
+    ///
+    /// The timestamp returned is rounded to the nearest nanosecond. But since
+    /// an `f64` only has about 15 significant decimal digits, the precision
+    /// of the timestamp returned for a Modified Julian Date near the present
+    /// is only about 1 microsecond.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the given Modified Julian Date isn't
+    /// finite, or when it corresponds to a timestamp outside the range
+    /// supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{Timestamp, Unit};
+    ///
+    /// let ts = Timestamp::from_mjd(60_676.75)?;
+    /// assert_eq!(ts.to_string(), "2025-01-01T18:00:00Z");
+    ///
+    /// let ts: Timestamp = "2025-01-01T12:34:56.789012Z".parse()?;
+    /// let got = Timestamp::from_mjd(ts.to_mjd())?;
+    /// assert_eq!(got.round(Unit::Microsecond)?, ts);
+    ///
+    /// assert!(Timestamp::from_mjd(f64::INFINITY).is_err());
+    /// assert!(Timestamp::from_mjd(1e9).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_mjd(mjd: f64) -> Result<Timestamp, Error> {
+        let seconds = (mjd - UNIX_EPOCH_MJD) * SECONDS_PER_DAY;
+        SignedDuration::try_from_secs_f64(seconds)
+            .and_then(Timestamp::from_duration)
+            .with_context(|| {
+                err!("failed to convert Modified Julian Date {mjd}")
+            })
+    }
+
     /// Creates a [`Zoned`] value by attaching a time zone for the given name
     /// to this instant in time.
     ///
@@ -2092,33 +2589,87 @@ impl Timestamp {
         options.round(self)
     }
 
-    /// Return an iterator of periodic timestamps determined by the given span.
+    /// Truncates this timestamp to millisecond precision.
     ///
-    /// The given span may be negative, in which case, the iterator will move
-    /// backwards through time. The iterator won't stop until either the span
-    /// itself overflows, or it would otherwise exceed the minimum or maximum
-    /// `Timestamp` value.
+    /// Any fractional milliseconds are discarded, which rounds toward the
+    /// Unix epoch. This is equivalent to
+    /// `Timestamp::from_millisecond(ts.as_millisecond())`, but never fails.
     ///
-    /// # Example: when to check a glucose monitor
+    /// This is useful when comparing a timestamp with one that has been
+    /// persisted somewhere that only stores milliseconds. See also
+    /// [`TimestampMillis`], which does this automatically.
     ///
-    /// When my cat had diabetes, my veterinarian installed a glucose monitor
-    /// and instructed me to scan it about every 5 hours. This example lists
-    /// all of the times I need to scan it for the 2 days following its
-    /// installation:
+    /// # Example
     ///
     /// ```
-    /// use jiff::{Timestamp, ToSpan};
+    /// use jiff::Timestamp;
     ///
-    /// let start: Timestamp = "2023-07-15 16:30:00-04".parse()?;
-    /// let end = start.checked_add(48.hours())?;
-    /// let mut scan_times = vec![];
-    /// for ts in start.series(5.hours()).take_while(|&ts| ts <= end) {
-    ///     scan_times.push(ts);
-    /// }
-    /// assert_eq!(scan_times, vec![
-    ///     "2023-07-15 16:30:00-04:00".parse::<Timestamp>()?,
-    ///     "2023-07-15 21:30:00-04:00".parse::<Timestamp>()?,
-    ///     "2023-07-16 02:30:00-04:00".parse::<Timestamp>()?,
+    /// let ts = Timestamp::new(5, 123_456_789)?;
+    /// assert_eq!(ts.trunc_to_millis(), Timestamp::new(5, 123_000_000)?);
+    /// let ts = Timestamp::new(-5, -123_456_789)?;
+    /// assert_eq!(ts.trunc_to_millis(), Timestamp::new(-5, -123_000_000)?);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn trunc_to_millis(self) -> Timestamp {
+        let nanosecond = self.subsec_millisecond() * 1_000_000;
+        // OK because truncating toward zero can't leave the supported range.
+        Timestamp::new(self.as_second(), nanosecond).unwrap()
+    }
+
+    /// Truncates this timestamp to microsecond precision.
+    ///
+    /// Any fractional microseconds are discarded, which rounds toward the
+    /// Unix epoch. This is equivalent to
+    /// `Timestamp::from_microsecond(ts.as_microsecond())`, but never fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts = Timestamp::new(5, 123_456_789)?;
+    /// assert_eq!(ts.trunc_to_micros(), Timestamp::new(5, 123_456_000)?);
+    /// let ts = Timestamp::new(-5, -123_456_789)?;
+    /// assert_eq!(ts.trunc_to_micros(), Timestamp::new(-5, -123_456_000)?);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn trunc_to_micros(self) -> Timestamp {
+        let nanosecond = self.subsec_microsecond() * 1_000;
+        // OK because truncating toward zero can't leave the supported range.
+        Timestamp::new(self.as_second(), nanosecond).unwrap()
+    }
+
+    /// Return an iterator of periodic timestamps determined by the given span.
+    ///
+    /// The given span may be negative, in which case, the iterator will move
+    /// backwards through time. The iterator won't stop until either the span
+    /// itself overflows, or it would otherwise exceed the minimum or maximum
+    /// `Timestamp` value.
+    ///
+    /// # Example: when to check a glucose monitor
+    ///
+    /// When my cat had diabetes, my veterinarian installed a glucose monitor
+    /// and instructed me to scan it about every 5 hours. This example lists
+    /// all of the times I need to scan it for the 2 days following its
+    /// installation:
+    ///
+    /// ```
+    /// use jiff::{Timestamp, ToSpan};
+    ///
+    /// let start: Timestamp = "2023-07-15 16:30:00-04".parse()?;
+    /// let end = start.checked_add(48.hours())?;
+    /// let mut scan_times = vec![];
+    /// for ts in start.series(5.hours()).take_while(|&ts| ts <= end) {
+    ///     scan_times.push(ts);
+    /// }
+    /// assert_eq!(scan_times, vec![
+    ///     "2023-07-15 16:30:00-04:00".parse::<Timestamp>()?,
+    ///     "2023-07-15 21:30:00-04:00".parse::<Timestamp>()?,
+    ///     "2023-07-16 02:30:00-04:00".parse::<Timestamp>()?,
     ///     "2023-07-16 07:30:00-04:00".parse::<Timestamp>()?,
     ///     "2023-07-16 12:30:00-04:00".parse::<Timestamp>()?,
     ///     "2023-07-16 17:30:00-04:00".parse::<Timestamp>()?,
@@ -2248,6 +2799,70 @@ impl Timestamp {
     ) -> TimestampDisplayWithOffset {
         TimestampDisplayWithOffset { timestamp: *self, offset }
     }
+
+    /// Format this timestamp as an [RFC 3339] string using the given Temporal
+    /// printer.
+    ///
+    /// This is a convenience routine for
+    /// [`temporal::DateTimePrinter::timestamp_to_string`]. It is useful for
+    /// one-off formatting with a non-default configuration. When no
+    /// configuration is needed, use the [`std::fmt::Display`] trait
+    /// implementation on `Timestamp` instead.
+    ///
+    /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::DateTimePrinter, Timestamp};
+    ///
+    /// let ts = Timestamp::new(1_720_000_000, 123_456_789)?;
+    /// let printer = DateTimePrinter::new().precision(Some(3)).zulu(false);
+    /// assert_eq!(
+    ///     ts.to_rfc3339_with(&printer),
+    ///     "2024-07-03T09:46:40.123+00:00",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_rfc3339_with(
+        &self,
+        printer: &temporal::DateTimePrinter,
+    ) -> alloc::string::String {
+        printer.timestamp_to_string(self)
+    }
+
+    /// Format this timestamp as an [RFC 2822] string.
+    ///
+    /// Since a timestamp has no offset, this always uses `-0000`, which
+    /// RFC 2822 defines to mean that the local offset is unknown. To
+    /// configure how the timestamp is printed, use
+    /// [`fmt::rfc2822::DateTimePrinter`].
+    ///
+    /// [RFC 2822]: https://datatracker.ietf.org/doc/html/rfc2822
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the year is outside the range supported by
+    /// RFC 2822 (i.e., negative).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts = Timestamp::from_second(1_720_000_000)?;
+    /// assert_eq!(ts.to_rfc2822()?, "Wed, 3 Jul 2024 09:46:40 -0000");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_rfc2822(&self) -> Result<alloc::string::String, Error> {
+        fmt::rfc2822::DEFAULT_DATETIME_PRINTER.timestamp_to_string(self)
+    }
 }
 
 /// Internal APIs using Jiff ranged integers.
@@ -2832,6 +3447,250 @@ impl TryFrom<std::time::SystemTime> for Timestamp {
     }
 }
 
+/// The number of seconds in a (Unix) day, as used by Julian Dates.
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// The astronomical Julian Date of the Unix epoch, `1970-01-01T00:00:00Z`.
+const UNIX_EPOCH_JULIAN_DATE: f64 = 2_440_587.5;
+
+/// The Modified Julian Date of the Unix epoch, `1970-01-01T00:00:00Z`.
+const UNIX_EPOCH_MJD: f64 = 40_587.0;
+
+/// The number of .NET ticks (100 nanosecond intervals) from
+/// `0001-01-01T00:00:00Z` to the Unix epoch.
+const DOTNET_UNIX_EPOCH_TICKS: i64 = 621_355_968_000_000_000;
+
+/// The largest number of ticks supported by .NET, corresponding to
+/// `9999-12-31T23:59:59.9999999Z`.
+const DOTNET_MAX_TICKS: i64 = 3_155_378_975_999_999_999;
+
+/// The smallest Unix second permitted by the protobuf `Timestamp` type,
+/// corresponding to `0001-01-01T00:00:00Z`.
+#[cfg(feature = "prost")]
+const PROTOBUF_MIN_SECOND: i64 = -62_135_596_800;
+
+/// The largest Unix second permitted by the protobuf `Timestamp` type,
+/// corresponding to `9999-12-31T23:59:59Z`.
+#[cfg(feature = "prost")]
+const PROTOBUF_MAX_SECOND: i64 = 253_402_300_799;
+
+/// Converts a Jiff timestamp to a protobuf timestamp.
+///
+/// This returns an error when the timestamp is outside the range supported
+/// by protobuf, which is `0001-01-01T00:00:00Z` to
+/// `9999-12-31T23:59:59.999999999Z`, inclusive.
+///
+/// Unlike Jiff, protobuf requires the fractional nanoseconds to always be
+/// non-negative. So for timestamps before the Unix epoch, the seconds are
+/// rounded down and the nanoseconds count forward from there.
+#[cfg(feature = "prost")]
+impl TryFrom<Timestamp> for prost_types::Timestamp {
+    type Error = Error;
+
+    fn try_from(ts: Timestamp) -> Result<prost_types::Timestamp, Error> {
+        let (mut seconds, mut nanos) =
+            (ts.as_second(), ts.subsec_nanosecond());
+        if nanos < 0 {
+            seconds -= 1;
+            nanos += 1_000_000_000;
+        }
+        if !(PROTOBUF_MIN_SECOND..=PROTOBUF_MAX_SECOND).contains(&seconds) {
+            return Err(err!(
+                "timestamp {ts} is outside the range supported by \
+                 protobuf timestamps",
+            ));
+        }
+        Ok(prost_types::Timestamp { seconds, nanos })
+    }
+}
+
+/// Converts a protobuf timestamp to a Jiff timestamp.
+///
+/// This returns an error when the protobuf timestamp is invalid. That is,
+/// when its nanoseconds are not in the range `0..=999_999_999` or when it is
+/// outside the range `0001-01-01T00:00:00Z` to
+/// `9999-12-31T23:59:59.999999999Z`, inclusive.
+#[cfg(feature = "prost")]
+impl TryFrom<prost_types::Timestamp> for Timestamp {
+    type Error = Error;
+
+    fn try_from(ts: prost_types::Timestamp) -> Result<Timestamp, Error> {
+        if !(0..=999_999_999).contains(&ts.nanos) {
+            return Err(err!(
+                "protobuf timestamp has invalid nanoseconds {nanos}, \
+                 expected a value in the range 0..=999999999",
+                nanos = ts.nanos,
+            ));
+        }
+        if !(PROTOBUF_MIN_SECOND..=PROTOBUF_MAX_SECOND).contains(&ts.seconds) {
+            return Err(err!(
+                "protobuf timestamp has seconds {seconds} outside the \
+                 supported range {PROTOBUF_MIN_SECOND}..={PROTOBUF_MAX_SECOND}",
+                seconds = ts.seconds,
+            ));
+        }
+        Timestamp::new(ts.seconds, ts.nanos)
+    }
+}
+
+/// Converts a Jiff timestamp to a `chrono` datetime in UTC.
+///
+/// This never fails since `chrono` supports a superset of the instants
+/// supported by Jiff.
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    #[inline]
+    fn from(ts: Timestamp) -> chrono::DateTime<chrono::Utc> {
+        let (mut second, mut nanosecond) =
+            (ts.as_second(), ts.subsec_nanosecond());
+        // `chrono` requires the fractional part to be non-negative.
+        if nanosecond < 0 {
+            second -= 1;
+            nanosecond += 1_000_000_000;
+        }
+        // OK because every Jiff timestamp is a valid `chrono` datetime.
+        chrono::DateTime::from_timestamp(second, nanosecond.unsigned_abs())
+            .unwrap()
+    }
+}
+
+/// Converts a `chrono` datetime in UTC to a Jiff timestamp.
+///
+/// This returns an error when the datetime is outside of Jiff's supported
+/// range. A leap second is handled the same way as when converting from a
+/// `chrono::NaiveTime` to a [`civil::Time`](crate::civil::Time).
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(
+        dt: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Timestamp, Error> {
+        let second = dt.timestamp();
+        // OK because `chrono` guarantees that this is less than 2 seconds.
+        let nanosecond = (dt.timestamp_subsec_nanos() % 1_000_000_000) as i32;
+        Timestamp::new(second, nanosecond)
+    }
+}
+
+/// Converts a Jiff timestamp to a `time` datetime with a UTC offset.
+///
+/// This never fails since `time` supports a superset of the instants
+/// supported by Jiff.
+#[cfg(feature = "time")]
+impl From<Timestamp> for ::time::OffsetDateTime {
+    #[inline]
+    fn from(ts: Timestamp) -> ::time::OffsetDateTime {
+        // OK because every Jiff timestamp is a valid `time` datetime.
+        ::time::OffsetDateTime::from_unix_timestamp_nanos(ts.as_nanosecond())
+            .unwrap()
+    }
+}
+
+/// Converts a `time` datetime with an offset to a Jiff timestamp.
+///
+/// The offset of the `time` datetime is only used to determine the instant
+/// it refers to. It is otherwise discarded.
+///
+/// This returns an error when the instant is outside of Jiff's supported
+/// range.
+#[cfg(feature = "time")]
+impl TryFrom<::time::OffsetDateTime> for Timestamp {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(dt: ::time::OffsetDateTime) -> Result<Timestamp, Error> {
+        Timestamp::from_nanosecond(dt.unix_timestamp_nanos())
+    }
+}
+
+/// Samples timestamps uniformly at random from a range.
+///
+/// See [`TimestampUniform`] for more details.
+#[cfg(feature = "rand")]
+impl SampleUniform for Timestamp {
+    type Sampler = TimestampUniform;
+}
+
+/// A sampler for picking timestamps uniformly at random from a range.
+///
+/// This is the [`UniformSampler`](rand::distr::uniform::UniformSampler)
+/// implementation for [`Timestamp`]. It is available when the `rand` crate
+/// feature is enabled. Callers usually don't need to use it directly. Instead,
+/// use `rand::distr::Uniform` or `rand::RngExt::random_range` with a range of
+/// timestamps.
+///
+/// Every nanosecond in the range is equally likely to be picked.
+///
+/// # Example
+///
+/// ```
+/// use jiff::Timestamp;
+/// use rand::{rngs::StdRng, RngExt, SeedableRng};
+///
+/// let start: Timestamp = "2025-01-01T00:00:00Z".parse()?;
+/// let end: Timestamp = "2026-01-01T00:00:00Z".parse()?;
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// for _ in 0..100 {
+///     let ts = rng.random_range(start..end);
+///     assert!(start <= ts && ts < end);
+/// }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "rand")]
+#[derive(Clone, Copy, Debug)]
+pub struct TimestampUniform {
+    nanoseconds: UniformInt<i128>,
+}
+
+#[cfg(feature = "rand")]
+impl UniformSampler for TimestampUniform {
+    type X = Timestamp;
+
+    #[inline]
+    fn new<B1, B2>(
+        low: B1,
+        high: B2,
+    ) -> Result<TimestampUniform, rand::distr::uniform::Error>
+    where
+        B1: SampleBorrow<Timestamp> + Sized,
+        B2: SampleBorrow<Timestamp> + Sized,
+    {
+        let nanoseconds = UniformInt::new(
+            low.borrow().as_nanosecond(),
+            high.borrow().as_nanosecond(),
+        )?;
+        Ok(TimestampUniform { nanoseconds })
+    }
+
+    #[inline]
+    fn new_inclusive<B1, B2>(
+        low: B1,
+        high: B2,
+    ) -> Result<TimestampUniform, rand::distr::uniform::Error>
+    where
+        B1: SampleBorrow<Timestamp> + Sized,
+        B2: SampleBorrow<Timestamp> + Sized,
+    {
+        let nanoseconds = UniformInt::new_inclusive(
+            low.borrow().as_nanosecond(),
+            high.borrow().as_nanosecond(),
+        )?;
+        Ok(TimestampUniform { nanoseconds })
+    }
+
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Timestamp {
+        let nanosecond = self.nanoseconds.sample(rng);
+        // OK because the sampled value is always between two valid
+        // timestamps.
+        Timestamp::from_nanosecond(nanosecond).unwrap()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for Timestamp {
     #[inline]
@@ -2886,6 +3745,20 @@ impl<'de> serde::Deserialize<'de> for Timestamp {
     }
 }
 
+#[cfg(feature = "utoipa")]
+impl utoipa::PartialSchema for Timestamp {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::util::schema::string(
+            Some(utoipa::openapi::schema::KnownFormat::DateTime),
+            "An instant in time, as an RFC 3339 timestamp in UTC.",
+            "2024-06-15T07:00:00Z",
+        )
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl utoipa::ToSchema for Timestamp {}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for Timestamp {
     fn arbitrary(g: &mut quickcheck::Gen) -> Timestamp {
@@ -2916,6 +3789,28 @@ impl quickcheck::Arbitrary for Timestamp {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Timestamp {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Timestamp> {
+        let second: UnixSeconds = u.arbitrary()?;
+        let mut nanosecond: FractionalNanosecond = u.arbitrary()?;
+        // The nanoseconds must not be negative for the minimum second value.
+        if second == UnixSeconds::MIN_SELF && nanosecond < C(0) {
+            nanosecond = -nanosecond;
+        }
+        // OK because the signs of the seconds and nanoseconds are balanced
+        // by `new_ranged`, and the only case that would otherwise be out of
+        // range is handled above.
+        Ok(Timestamp::new_ranged(second, nanosecond).unwrap())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(UnixSeconds, FractionalNanosecond)>::size_hint(depth)
+    }
+}
+
 /// A type for formatting a [`Timestamp`] with a specific offset.
 ///
 /// This type is created by the [`Timestamp::display_with_offset`] method.
@@ -2979,6 +3874,117 @@ impl core::fmt::Display for TimestampDisplayWithOffset {
     }
 }
 
+/// A timestamp with millisecond precision.
+///
+/// This wraps a [`Timestamp`] that has been truncated to millisecond
+/// precision via [`Timestamp::trunc_to_millis`]. Since the sub-millisecond
+/// component is always zero, two values of this type are equal (and hash the
+/// same) precisely when they refer to the same millisecond. This makes it
+/// suitable as a key in a map when the source of truth only stores
+/// milliseconds, such as many databases and JSON APIs.
+///
+/// Converting from a `Timestamp` truncates it, and converting back to a
+/// `Timestamp` is lossless.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use jiff::{Timestamp, TimestampMillis};
+///
+/// let ts: Timestamp = "2024-07-14T21:14:00.123456789Z".parse()?;
+/// let mut map = HashMap::new();
+/// map.insert(TimestampMillis::from(ts), "launch");
+///
+/// // A value read back from a millisecond precision store still matches.
+/// let stored = TimestampMillis::from_millisecond(ts.as_millisecond())?;
+/// assert_eq!(map.get(&stored), Some(&"launch"));
+/// assert_eq!(stored.to_string(), "2024-07-14T21:14:00.123Z");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(
+    Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd,
+)]
+pub struct TimestampMillis(Timestamp);
+
+impl TimestampMillis {
+    /// Creates a new millisecond precision timestamp from a number of
+    /// milliseconds since the Unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error under the same conditions as
+    /// [`Timestamp::from_millisecond`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::TimestampMillis;
+    ///
+    /// let ts = TimestampMillis::from_millisecond(1_720_991_640_123)?;
+    /// assert_eq!(ts.to_string(), "2024-07-14T21:14:00.123Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_millisecond(
+        millisecond: i64,
+    ) -> Result<TimestampMillis, Error> {
+        Timestamp::from_millisecond(millisecond).map(TimestampMillis)
+    }
+
+    /// Returns this timestamp as a number of milliseconds since the Unix
+    /// epoch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{Timestamp, TimestampMillis};
+    ///
+    /// let ts = TimestampMillis::from(Timestamp::new(5, 123_456_789)?);
+    /// assert_eq!(ts.as_millisecond(), 5_123);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn as_millisecond(self) -> i64 {
+        self.0.as_millisecond()
+    }
+
+    /// Returns the underlying timestamp.
+    ///
+    /// Its sub-millisecond component is always zero.
+    #[inline]
+    pub fn timestamp(self) -> Timestamp {
+        self.0
+    }
+}
+
+impl core::fmt::Display for TimestampMillis {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Converts a timestamp to millisecond precision by truncating any
+/// fractional milliseconds.
+impl From<Timestamp> for TimestampMillis {
+    #[inline]
+    fn from(ts: Timestamp) -> TimestampMillis {
+        TimestampMillis(ts.trunc_to_millis())
+    }
+}
+
+impl From<TimestampMillis> for Timestamp {
+    #[inline]
+    fn from(ts: TimestampMillis) -> Timestamp {
+        ts.0
+    }
+}
+
 /// An iterator over periodic timestamps, created by [`Timestamp::series`].
 ///
 /// It is exhausted when the next value would exceed a [`Span`] or
@@ -3640,6 +4646,18 @@ impl TimestampRound {
         TimestampRound { increment, ..self }
     }
 
+    /// Returns the `smallest` unit configuration.
+    #[cfg(feature = "testing")]
+    pub(crate) fn get_smallest(&self) -> Unit {
+        self.smallest
+    }
+
+    /// Returns the `increment` configuration.
+    #[cfg(feature = "testing")]
+    pub(crate) fn get_increment(&self) -> i64 {
+        self.increment
+    }
+
     /// Does the actual rounding.
     pub(crate) fn round(
         &self,
@@ -3653,7 +4671,7 @@ impl TimestampRound {
             self.smallest,
             increment,
         );
-        let nanosecond = UnixNanoseconds::rfrom(rounded);
+        let nanosecond = UnixNanoseconds::try_rfrom("timestamp", rounded)?;
         Ok(Timestamp::from_nanosecond_ranged(nanosecond))
     }
 }
@@ -3697,6 +4715,38 @@ mod tests {
         Timestamp::new(seconds, nanos).unwrap()
     }
 
+    #[test]
+    fn arbitrary_extremes() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        use crate::civil::DateTime;
+
+        // Fuzzers like to feed inputs made up of a single repeated byte, so
+        // make sure the extremes produce valid values.
+        for data in [&[][..], &[0x00; 64], &[0x7F; 64], &[0xFF; 64]] {
+            let ts =
+                Timestamp::arbitrary(&mut Unstructured::new(data)).unwrap();
+            assert_eq!(
+                Timestamp::from_duration(ts.as_duration()).unwrap(),
+                ts
+            );
+            let dt =
+                DateTime::arbitrary(&mut Unstructured::new(data)).unwrap();
+            assert_eq!(dt.to_string().parse::<DateTime>().unwrap(), dt);
+            let dur = SignedDuration::arbitrary(&mut Unstructured::new(data))
+                .unwrap();
+            assert!(dur.subsec_nanos().unsigned_abs() < 1_000_000_000);
+            assert!(!(dur.as_secs() < 0 && dur.subsec_nanos() > 0));
+            assert!(!(dur.as_secs() > 0 && dur.subsec_nanos() < 0));
+            let offset =
+                Offset::arbitrary(&mut Unstructured::new(data)).unwrap();
+            assert_eq!(
+                Offset::from_seconds(offset.seconds()).unwrap(),
+                offset
+            );
+        }
+    }
+
     fn mkdt(
         year: i16,
         month: i8,
@@ -3711,6 +4761,19 @@ mod tests {
         civil::DateTime::from_parts(date, time)
     }
 
+    #[test]
+    fn round_out_of_range_errors() {
+        let options = TimestampRound::new()
+            .smallest(Unit::Hour)
+            .increment(2)
+            .mode(RoundMode::Ceil);
+        assert!(Timestamp::MAX.round(options).is_err());
+        let options = options.mode(RoundMode::Floor);
+        assert!(Timestamp::MIN.round(options).is_err());
+        let options = options.mode(RoundMode::Trunc);
+        assert!(Timestamp::MAX.round(options).is_ok());
+    }
+
     #[test]
     fn to_datetime_specific_examples() {
         let tests = [
@@ -3829,6 +4892,116 @@ mod tests {
         assert_eq!(inst, got);
     }
 
+    #[test]
+    fn dotnet_ticks() {
+        let ts = |s: &str| s.parse::<Timestamp>().unwrap();
+
+        assert_eq!(ts("0001-01-01T00:00:00Z").to_dotnet_ticks().unwrap(), 0);
+        assert_eq!(
+            Timestamp::UNIX_EPOCH.to_dotnet_ticks().unwrap(),
+            621_355_968_000_000_000,
+        );
+        // Truncation always rounds down, even before the Unix epoch.
+        assert_eq!(
+            ts("1969-12-31T23:59:59.999999999Z").to_dotnet_ticks().unwrap(),
+            621_355_967_999_999_999,
+        );
+        assert_eq!(
+            ts("1970-01-01T00:00:00.000000199Z").to_dotnet_ticks().unwrap(),
+            621_355_968_000_000_001,
+        );
+
+        let max = Timestamp::MAX.to_dotnet_ticks().unwrap();
+        assert_eq!(
+            Timestamp::from_dotnet_ticks(max).unwrap(),
+            ts("9999-12-30T22:00:00.9999999Z"),
+        );
+        for s in ["0001-01-01T00:00:00Z", "1601-01-01T00:00:00.1234567Z"] {
+            let ticks = ts(s).to_dotnet_ticks().unwrap();
+            assert_eq!(Timestamp::from_dotnet_ticks(ticks).unwrap(), ts(s));
+        }
+
+        insta::assert_snapshot!(
+            Timestamp::MIN.to_dotnet_ticks().unwrap_err(),
+            @"timestamp -009999-01-02T01:59:59Z is before 0001-01-01T00:00:00Z, and cannot be represented as .NET ticks",
+        );
+        insta::assert_snapshot!(
+            Timestamp::from_dotnet_ticks(-1).unwrap_err(),
+            @".NET ticks -1 are not in the required range of 0..=3155378975999999999",
+        );
+        insta::assert_snapshot!(
+            Timestamp::from_dotnet_ticks(max + 1).unwrap_err(),
+            @"failed to convert .NET ticks 3155378040010000000: parameter 'nanosecond timestamp' with value 253402207201000000000 is not in the required range of -377705023201000000000..=253402207200999999999",
+        );
+    }
+
+    #[test]
+    fn julian_date() {
+        let ts = |s: &str| s.parse::<Timestamp>().unwrap();
+
+        assert_eq!(Timestamp::UNIX_EPOCH.to_julian_date(), 2_440_587.5);
+        assert_eq!(ts("-004713-11-24T12:00:00Z").to_julian_date(), 0.0);
+        assert_eq!(ts("-004713-11-24T00:00:00Z").to_julian_date(), -0.5);
+        assert_eq!(ts("1858-11-17T00:00:00Z").to_julian_date(), 2_400_000.5);
+
+        for s in [
+            "-004713-11-24T12:00:00Z",
+            "1970-01-01T00:00:00Z",
+            "2000-01-01T12:00:00Z",
+            "2025-06-30T06:00:00Z",
+        ] {
+            let jd = ts(s).to_julian_date();
+            assert_eq!(Timestamp::from_julian_date(jd).unwrap(), ts(s));
+        }
+        let jd = Timestamp::MAX.to_julian_date();
+        let got = Timestamp::from_julian_date(jd).unwrap();
+        assert!(got.duration_until(Timestamp::MAX).abs().as_millis() < 1);
+
+        insta::assert_snapshot!(
+            Timestamp::from_julian_date(f64::NAN).unwrap_err(),
+            @"failed to convert Julian Date NaN: could not convert non-finite seconds NaN to signed duration",
+        );
+        insta::assert_snapshot!(
+            Timestamp::from_julian_date(1e9).unwrap_err(),
+            @"failed to convert Julian Date 1000000000: parameter 'second' with value 86189133240000 is not in the required range of -377705023201..=253402207200",
+        );
+    }
+
+    #[test]
+    fn mjd() {
+        let ts = |s: &str| s.parse::<Timestamp>().unwrap();
+
+        assert_eq!(Timestamp::UNIX_EPOCH.to_mjd(), 40_587.0);
+        assert_eq!(ts("1858-11-17T00:00:00Z").to_mjd(), 0.0);
+        assert_eq!(ts("1858-11-16T12:00:00Z").to_mjd(), -0.5);
+        // GPS epoch.
+        assert_eq!(ts("1980-01-06T00:00:00Z").to_mjd(), 44_244.0);
+
+        for s in [
+            "1858-11-17T00:00:00Z",
+            "1980-01-06T00:00:00Z",
+            "2025-06-30T06:00:00Z",
+        ] {
+            let ts = ts(s);
+            assert_eq!(Timestamp::from_mjd(ts.to_mjd()).unwrap(), ts);
+            let jd = ts.to_julian_date();
+            assert_eq!(ts.to_mjd(), jd - 2_400_000.5);
+        }
+        // Sub-second precision near the present is about a microsecond.
+        let ts = ts("2025-06-30T06:00:00.5Z");
+        let got = Timestamp::from_mjd(ts.to_mjd()).unwrap();
+        assert!(got.duration_until(ts).abs().as_micros() < 1);
+
+        insta::assert_snapshot!(
+            Timestamp::from_mjd(f64::NAN).unwrap_err(),
+            @"failed to convert Modified Julian Date NaN: could not convert non-finite seconds NaN to signed duration",
+        );
+        insta::assert_snapshot!(
+            Timestamp::from_mjd(-1e9).unwrap_err(),
+            @"failed to convert Modified Julian Date -1000000000: parameter 'second' with value -86403506716800 is not in the required range of -377705023201..=253402207200",
+        );
+    }
+
     #[test]
     fn timestamp_saturating_add() {
         insta::assert_snapshot!(
@@ -3866,6 +5039,35 @@ mod tests {
             t == got
         }
 
+        fn prop_bytes_roundtrip(t: Timestamp) -> bool {
+            let be = Timestamp::from_be_bytes(t.to_be_bytes()).unwrap();
+            let le = Timestamp::from_le_bytes(t.to_le_bytes()).unwrap();
+            t == be && t == le
+        }
+
+        fn prop_sortable_bytes_roundtrip(t: Timestamp) -> bool {
+            t == Timestamp::from_sortable_bytes(t.to_sortable_bytes()).unwrap()
+        }
+
+        fn prop_sortable_bytes_order(t1: Timestamp, t2: Timestamp) -> bool {
+            t1.cmp(&t2) == t1.to_sortable_bytes().cmp(&t2.to_sortable_bytes())
+        }
+
+        fn prop_trunc_to_millis(t: Timestamp) -> bool {
+            let expected =
+                Timestamp::from_millisecond(t.as_millisecond()).unwrap();
+            let millis = TimestampMillis::from(t);
+            t.trunc_to_millis() == expected
+                && millis.timestamp() == expected
+                && millis.as_millisecond() == t.as_millisecond()
+        }
+
+        fn prop_trunc_to_micros(t: Timestamp) -> bool {
+            let expected =
+                Timestamp::from_microsecond(t.as_microsecond()).unwrap();
+            t.trunc_to_micros() == expected
+        }
+
         fn timestamp_constant_and_new_are_same1(t: Timestamp) -> bool {
             let got = Timestamp::constant(t.as_second(), t.subsec_nanosecond());
             t == got
@@ -3925,4 +5127,130 @@ mod tests {
         assert_eq!(ts2.to_string(), "2025-01-25T18:32:22.783444592Z");
         assert_eq!(ts1, ts2 - span, "should be reversible");
     }
+
+    #[cfg(feature = "prost")]
+    #[test]
+    fn prost_timestamp() {
+        let to = |ts: Timestamp| prost_types::Timestamp::try_from(ts);
+        let from = |seconds, nanos| {
+            Timestamp::try_from(prost_types::Timestamp { seconds, nanos })
+        };
+
+        let ts = Timestamp::new(-1, -500_000_000).unwrap();
+        let pts = to(ts).unwrap();
+        assert_eq!((pts.seconds, pts.nanos), (-2, 500_000_000));
+        assert_eq!(from(-2, 500_000_000).unwrap(), ts);
+
+        let ts = Timestamp::new(1_737_833_541, 783_444_592).unwrap();
+        let pts = to(ts).unwrap();
+        assert_eq!((pts.seconds, pts.nanos), (1_737_833_541, 783_444_592));
+        assert_eq!(from(1_737_833_541, 783_444_592).unwrap(), ts);
+
+        // Jiff's maximum timestamp is within the protobuf range, but the
+        // protobuf maximum is not within Jiff's range.
+        let min: Timestamp = "0001-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(Timestamp::try_from(to(min).unwrap()).unwrap(), min);
+        let max = Timestamp::MAX;
+        assert_eq!(Timestamp::try_from(to(max).unwrap()).unwrap(), max);
+        assert!(to(min - SignedDuration::from_nanos(1)).is_err());
+        assert!(to(Timestamp::MIN).is_err());
+
+        assert!(from(0, -1).is_err());
+        assert!(from(0, 1_000_000_000).is_err());
+        assert!(from(min.as_second() - 1, 999_999_999).is_err());
+        assert!(from(PROTOBUF_MAX_SECOND, 0).is_err());
+        assert!(from(PROTOBUF_MAX_SECOND + 1, 0).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_timestamp() {
+        type Utc = chrono::DateTime<chrono::Utc>;
+
+        let ts = Timestamp::new(-1, -500_000_000).unwrap();
+        let dt = Utc::from(ts);
+        assert_eq!(
+            (dt.timestamp(), dt.timestamp_subsec_nanos()),
+            (-2, 500_000_000)
+        );
+        assert_eq!(Timestamp::try_from(dt).unwrap(), ts);
+
+        assert_eq!(
+            Timestamp::try_from(Utc::from(Timestamp::MIN)).unwrap(),
+            Timestamp::MIN
+        );
+        assert_eq!(
+            Timestamp::try_from(Utc::from(Timestamp::MAX)).unwrap(),
+            Timestamp::MAX
+        );
+        assert!(Timestamp::try_from(Utc::MIN_UTC).is_err());
+        assert!(Timestamp::try_from(Utc::MAX_UTC).is_err());
+
+        // Leap seconds are clamped to the 59th second.
+        let leap = chrono::NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_000_000_000)
+            .unwrap()
+            .and_utc();
+        let ts = Timestamp::try_from(leap).unwrap();
+        assert_eq!(ts.to_string(), "2016-12-31T23:59:59Z");
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_timestamp() {
+        use ::time::{macros::datetime as tdatetime, OffsetDateTime};
+
+        let ts = Timestamp::new(-1, -500_000_000).unwrap();
+        let odt = OffsetDateTime::from(ts);
+        assert_eq!(odt, tdatetime!(1969-12-31 23:59:58.5 UTC));
+        assert_eq!(Timestamp::try_from(odt).unwrap(), ts);
+
+        // The offset only determines the instant.
+        let odt = tdatetime!(1970-01-01 05:30:00 +05:30);
+        assert_eq!(Timestamp::try_from(odt).unwrap(), Timestamp::UNIX_EPOCH);
+
+        let odt = OffsetDateTime::from(Timestamp::MIN);
+        assert_eq!(Timestamp::try_from(odt).unwrap(), Timestamp::MIN);
+        let odt = OffsetDateTime::from(Timestamp::MAX);
+        assert_eq!(Timestamp::try_from(odt).unwrap(), Timestamp::MAX);
+        let odt = tdatetime!(-9999-01-01 00:00:00 UTC);
+        assert!(Timestamp::try_from(odt).is_err());
+        let odt = tdatetime!(9999-12-31 23:59:59.999_999_999 UTC);
+        assert!(Timestamp::try_from(odt).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn rand_timestamp() {
+        use rand::{
+            distr::{Distribution, Uniform},
+            rngs::StdRng,
+            RngExt, SeedableRng,
+        };
+
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let start = Timestamp::new(1_700_000_000, 999_999_999).unwrap();
+        let end = Timestamp::new(1_700_000_001, 1).unwrap();
+        let (mut seen_min, mut seen_max) = (false, false);
+        for _ in 0..100 {
+            let ts = rng.random_range(start..end);
+            assert!(start <= ts && ts < end);
+            seen_min |= ts == start;
+            seen_max |= ts == Timestamp::new(1_700_000_001, 0).unwrap();
+        }
+        assert!(seen_min && seen_max);
+
+        let ts = rng.random_range(start..=start);
+        assert_eq!(ts, start);
+        assert!(Uniform::new(start, start).is_err());
+        assert!(Uniform::new(end, start).is_err());
+
+        let dist = Uniform::new_inclusive(Timestamp::MIN, Timestamp::MAX);
+        let dist = dist.unwrap();
+        for _ in 0..100 {
+            let _ = dist.sample(&mut rng);
+        }
+    }
 }
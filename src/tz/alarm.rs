@@ -0,0 +1,547 @@
+use crate::{
+    civil::{Date, Time},
+    error::{err, Error, ErrorContext},
+    tz::{self, AmbiguousOffset, TimeZone},
+    Timestamp,
+};
+
+/// A daily alarm that fires at a civil time in a particular time zone.
+///
+/// An alarm fires once per day, except on days where its civil time doesn't
+/// exist or occurs twice. More complex recurrence rules, such as only firing
+/// on weekdays, can be built by filtering [`Alarm::firings_after`].
+///
+/// An alarm is the classic source of daylight saving time bugs. When the
+/// clocks move forward, the alarm time may not exist on that day. And when
+/// the clocks move backward, the alarm time may occur twice. An `Alarm` makes
+/// the behavior in both cases explicit via [`AlarmGap`] and [`AlarmFold`].
+///
+/// An alarm doesn't keep track of any state about previous firings. Every
+/// call to [`Alarm::next_after`] computes the next firing from scratch using
+/// the alarm's civil time and time zone. This means an alarm is never thrown
+/// off by a wall clock change. It also means that picking up changes to the
+/// time zone database only requires swapping in a fresh [`TimeZone`], which
+/// [`Alarm::reload`] does for time zones from the database.
+///
+/// # Example
+///
+/// This shows an alarm at `02:30` in New York around the transitions into
+/// and out of daylight saving time in 2024. With the default policies, the
+/// alarm is shifted forward when `02:30` doesn't exist and only fires once
+/// when `01:30` happens twice.
+///
+/// ```
+/// use jiff::{civil::time, tz::Alarm, Timestamp};
+///
+/// let tz = jiff::tz::db().get("America/New_York")?;
+/// let alarm = Alarm::new(time(2, 30, 0, 0), tz.clone());
+///
+/// let start: Timestamp = "2024-03-09T12:00Z".parse()?;
+/// let got: Vec<String> = alarm
+///     .firings_after(start)
+///     .take(3)
+///     .map(|ts| ts.to_zoned(tz.clone()).to_string())
+///     .collect();
+/// assert_eq!(got, vec![
+///     "2024-03-10T03:30:00-04:00[America/New_York]",
+///     "2024-03-11T02:30:00-04:00[America/New_York]",
+///     "2024-03-12T02:30:00-04:00[America/New_York]",
+/// ]);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Alarm {
+    time: Time,
+    tz: TimeZone,
+    gap: AlarmGap,
+    fold: AlarmFold,
+}
+
+impl Alarm {
+    /// Creates a new alarm that fires every day at the given civil time in
+    /// the given time zone.
+    ///
+    /// The alarm uses [`AlarmGap::Shift`] and [`AlarmFold::First`] by
+    /// default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, tz::{Alarm, TimeZone}, Timestamp};
+    ///
+    /// let alarm = Alarm::new(time(7, 0, 0, 0), TimeZone::UTC);
+    /// let start: Timestamp = "2024-06-15T07:00Z".parse()?;
+    /// let next = alarm.next_after(start)?;
+    /// assert_eq!(next.to_string(), "2024-06-16T07:00:00Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn new(time: Time, tz: TimeZone) -> Alarm {
+        Alarm { time, tz, gap: AlarmGap::Shift, fold: AlarmFold::First }
+    }
+
+    /// Sets what happens when the alarm time falls into a gap, i.e., when
+    /// it doesn't exist on a particular day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, tz::{Alarm, AlarmGap}, Timestamp};
+    ///
+    /// let tz = jiff::tz::db().get("America/New_York")?;
+    /// let alarm = Alarm::new(time(2, 30, 0, 0), tz).gap(AlarmGap::Skip);
+    ///
+    /// // 2024-03-10T02:30 doesn't exist in New York, so the alarm
+    /// // doesn't fire until the next day.
+    /// let start: Timestamp = "2024-03-09T12:00Z".parse()?;
+    /// let next = alarm.next_after(start)?;
+    /// assert_eq!(next.to_string(), "2024-03-11T06:30:00Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn gap(self, gap: AlarmGap) -> Alarm {
+        Alarm { gap, ..self }
+    }
+
+    /// Sets what happens when the alarm time falls into a fold, i.e., when
+    /// it occurs twice on a particular day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, tz::{Alarm, AlarmFold}, Timestamp};
+    ///
+    /// let tz = jiff::tz::db().get("America/New_York")?;
+    /// let alarm = Alarm::new(time(1, 30, 0, 0), tz).fold(AlarmFold::Both);
+    ///
+    /// // 2024-11-03T01:30 occurs twice in New York.
+    /// let start: Timestamp = "2024-11-02T12:00Z".parse()?;
+    /// let got: Vec<String> = alarm
+    ///     .firings_after(start)
+    ///     .take(3)
+    ///     .map(|ts| ts.to_string())
+    ///     .collect();
+    /// assert_eq!(got, vec![
+    ///     "2024-11-03T05:30:00Z",
+    ///     "2024-11-03T06:30:00Z",
+    ///     "2024-11-04T06:30:00Z",
+    /// ]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn fold(self, fold: AlarmFold) -> Alarm {
+        Alarm { fold, ..self }
+    }
+
+    /// Returns the civil time at which this alarm fires.
+    #[inline]
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    /// Returns the time zone in which this alarm's civil time is
+    /// interpreted.
+    #[inline]
+    pub fn time_zone(&self) -> &TimeZone {
+        &self.tz
+    }
+
+    /// Returns this alarm with its time zone replaced by the given one.
+    ///
+    /// The civil time and the gap and fold policies are unchanged.
+    #[inline]
+    pub fn with_time_zone(self, tz: TimeZone) -> Alarm {
+        Alarm { tz, ..self }
+    }
+
+    /// Returns this alarm with its time zone reloaded from the global time
+    /// zone database.
+    ///
+    /// Since an alarm computes every firing from scratch, this is all that
+    /// is needed for an alarm to respect updates to the time zone database.
+    /// For example, a long running process might call this once a day.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the alarm's time zone has no IANA time zone
+    /// identifier (for example, a fixed offset time zone) or if the time
+    /// zone could not be found in the database.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, tz::Alarm};
+    ///
+    /// let tz = jiff::tz::db().get("Europe/Berlin")?;
+    /// let alarm = Alarm::new(time(6, 45, 0, 0), tz).reload()?;
+    /// assert_eq!(alarm.time_zone().iana_name(), Some("Europe/Berlin"));
+    ///
+    /// let alarm = Alarm::new(time(6, 45, 0, 0), jiff::tz::TimeZone::UTC);
+    /// assert!(alarm.reload().is_ok());
+    /// let tz = jiff::tz::offset(2).to_time_zone();
+    /// let alarm = Alarm::new(time(6, 45, 0, 0), tz);
+    /// assert!(alarm.reload().is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reload(&self) -> Result<Alarm, Error> {
+        let name = self.tz.iana_name().ok_or_else(|| {
+            err!(
+                "cannot reload alarm time zone {tz:?} because it has no \
+                 IANA time zone identifier",
+                tz = self.tz,
+            )
+        })?;
+        let tz = tz::db().get(name)?;
+        Ok(self.clone().with_time_zone(tz))
+    }
+
+    /// Returns the first time this alarm fires strictly after the given
+    /// timestamp.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the next firing would be outside of Jiff's
+    /// supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, tz::Alarm, Timestamp};
+    ///
+    /// let tz = jiff::tz::db().get("Australia/Sydney")?;
+    /// let alarm = Alarm::new(time(9, 0, 0, 0), tz);
+    ///
+    /// let start: Timestamp = "2024-06-15T00:00Z".parse()?;
+    /// // 2024-06-15T00:00Z is 10:00 in Sydney, so the next firing is
+    /// // tomorrow morning.
+    /// let next = alarm.next_after(start)?;
+    /// assert_eq!(next.to_string(), "2024-06-15T23:00:00Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn next_after(&self, after: Timestamp) -> Result<Timestamp, Error> {
+        // We start the day before the civil date of `after` because a
+        // backward transition across midnight can make a later instant have
+        // an earlier civil date.
+        let fail = || {
+            err!(
+                "failed to find next firing of alarm at {time} after {after}",
+                time = self.time,
+            )
+        };
+        let start_dt = self.tz.to_datetime(after);
+        let start = start_dt.date();
+        let mut date = start.yesterday().unwrap_or(start);
+        let mut found: Option<(Date, Timestamp)> = None;
+        loop {
+            let candidates = match self.candidates(date) {
+                Ok(candidates) => candidates,
+                // Near the minimum supported timestamp, the alarm time on
+                // the first days we look at may not be representable. Since
+                // such a firing would precede `after` anyway, we skip it.
+                Err(_) if date.to_datetime(self.time) < start_dt => {
+                    Candidates::default()
+                }
+                Err(err) => return Err(err.context(fail())),
+            };
+            for ts in candidates {
+                if ts <= after {
+                    continue;
+                }
+                match found {
+                    Some((_, min)) if min <= ts => {}
+                    Some((day, _)) => found = Some((day, ts)),
+                    None => found = Some((date, ts)),
+                }
+            }
+            // Once a firing has been found, we still look at the following
+            // day in case a backward transition puts an earlier firing there.
+            if let Some((day, ts)) = found {
+                if day < date {
+                    return Ok(ts);
+                }
+            }
+            date = match date.tomorrow() {
+                Ok(date) => date,
+                Err(err) => {
+                    return found
+                        .map(|(_, ts)| ts)
+                        .ok_or_else(|| err.context(fail()));
+                }
+            };
+        }
+    }
+
+    /// Returns an iterator over the times this alarm fires strictly after
+    /// the given timestamp.
+    ///
+    /// The iterator is exhausted when the next firing would be outside of
+    /// Jiff's supported range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, tz::{Alarm, TimeZone}, Timestamp};
+    ///
+    /// let alarm = Alarm::new(time(23, 0, 0, 0), TimeZone::UTC);
+    /// let start: Timestamp = "2024-02-28T00:00Z".parse()?;
+    /// let got: Vec<String> = alarm
+    ///     .firings_after(start)
+    ///     .take(3)
+    ///     .map(|ts| ts.to_string())
+    ///     .collect();
+    /// assert_eq!(got, vec![
+    ///     "2024-02-28T23:00:00Z",
+    ///     "2024-02-29T23:00:00Z",
+    ///     "2024-03-01T23:00:00Z",
+    /// ]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn firings_after(&self, after: Timestamp) -> AlarmFirings {
+        AlarmFirings { alarm: self.clone(), last: Some(after) }
+    }
+
+    /// Returns the times at which this alarm fires for the given civil date,
+    /// in ascending order.
+    ///
+    /// This returns at most two timestamps.
+    fn candidates(&self, date: Date) -> Result<Candidates, Error> {
+        let dt = date.to_datetime(self.time);
+        let amb = self.tz.to_ambiguous_timestamp(dt);
+        let mut candidates = Candidates::default();
+        match amb.offset() {
+            AmbiguousOffset::Unambiguous { .. } => {
+                candidates.push(amb.compatible()?);
+            }
+            AmbiguousOffset::Gap { .. } => match self.gap {
+                AlarmGap::Skip => {}
+                AlarmGap::Shift => candidates.push(amb.later()?),
+            },
+            AmbiguousOffset::Fold { .. } => {
+                candidates.push(amb.earlier()?);
+                if matches!(self.fold, AlarmFold::Both) {
+                    candidates.push(amb.later()?);
+                }
+            }
+        }
+        Ok(candidates)
+    }
+}
+
+/// What an [`Alarm`] does when its civil time doesn't exist on a particular
+/// day.
+///
+/// This happens when the clocks move forward, for example, at the start of
+/// daylight saving time. In `America/New_York`, `02:30` didn't exist on
+/// `2024-03-10` because the clocks moved from `02:00` directly to `03:00`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum AlarmGap {
+    /// Don't fire on days where the alarm time doesn't exist.
+    Skip,
+    /// Fire at the alarm time shifted forward by the length of the gap. For
+    /// example, an alarm at `02:30` fires at `03:30` when the clocks move
+    /// forward by one hour at `02:00`.
+    ///
+    /// This matches [`Disambiguation::Compatible`](super::Disambiguation).
+    ///
+    /// This is the default.
+    #[default]
+    Shift,
+}
+
+/// What an [`Alarm`] does when its civil time occurs twice on a particular
+/// day.
+///
+/// This happens when the clocks move backward, for example, at the end of
+/// daylight saving time. In `America/New_York`, `01:30` occurred twice on
+/// `2024-11-03` because the clocks moved from `02:00` back to `01:00`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum AlarmFold {
+    /// Fire only at the first occurrence of the alarm time.
+    ///
+    /// This is the default.
+    #[default]
+    First,
+    /// Fire at both occurrences of the alarm time.
+    Both,
+}
+
+/// An iterator over the times an [`Alarm`] fires.
+///
+/// This iterator is created by [`Alarm::firings_after`].
+#[derive(Clone, Debug)]
+pub struct AlarmFirings {
+    alarm: Alarm,
+    last: Option<Timestamp>,
+}
+
+impl Iterator for AlarmFirings {
+    type Item = Timestamp;
+
+    #[inline]
+    fn next(&mut self) -> Option<Timestamp> {
+        let last = self.last?;
+        self.last = self.alarm.next_after(last).ok();
+        self.last
+    }
+}
+
+impl core::iter::FusedIterator for AlarmFirings {}
+
+/// The firings of an alarm on a single civil date.
+#[derive(Default)]
+struct Candidates {
+    timestamps: [Option<Timestamp>; 2],
+}
+
+impl Candidates {
+    fn push(&mut self, ts: Timestamp) {
+        let slot = self
+            .timestamps
+            .iter_mut()
+            .find(|slot| slot.is_none())
+            .expect("at most two alarm candidates per day");
+        *slot = Some(ts);
+    }
+}
+
+impl IntoIterator for Candidates {
+    type Item = Timestamp;
+    type IntoIter =
+        core::iter::Flatten<core::array::IntoIter<Option<Timestamp>, 2>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.timestamps.into_iter().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use crate::civil::time;
+
+    use super::*;
+
+    fn firings(alarm: &Alarm, start: &str, count: usize) -> Vec<String> {
+        let start: Timestamp = start.parse().unwrap();
+        alarm
+            .firings_after(start)
+            .take(count)
+            .map(|ts| ts.to_zoned(alarm.time_zone().clone()).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn gap() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+        let tz = crate::tz::db().get("America/New_York").unwrap();
+        let alarm = Alarm::new(time(2, 30, 0, 0), tz);
+        insta::assert_debug_snapshot!(
+            firings(&alarm, "2024-03-09T00:00Z", 3),
+            @r###"[
+    "2024-03-09T02:30:00-05:00[America/New_York]",
+    "2024-03-10T03:30:00-04:00[America/New_York]",
+    "2024-03-11T02:30:00-04:00[America/New_York]",
+]"###,
+        );
+        let alarm = alarm.gap(AlarmGap::Skip);
+        insta::assert_debug_snapshot!(
+            firings(&alarm, "2024-03-09T00:00Z", 3),
+            @r###"[
+    "2024-03-09T02:30:00-05:00[America/New_York]",
+    "2024-03-11T02:30:00-04:00[America/New_York]",
+    "2024-03-12T02:30:00-04:00[America/New_York]",
+]"###,
+        );
+    }
+
+    #[test]
+    fn fold() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+        let tz = crate::tz::db().get("America/New_York").unwrap();
+        let alarm = Alarm::new(time(1, 30, 0, 0), tz);
+        insta::assert_debug_snapshot!(
+            firings(&alarm, "2024-11-02T00:00Z", 3),
+            @r###"[
+    "2024-11-02T01:30:00-04:00[America/New_York]",
+    "2024-11-03T01:30:00-04:00[America/New_York]",
+    "2024-11-04T01:30:00-05:00[America/New_York]",
+]"###,
+        );
+        let alarm = alarm.fold(AlarmFold::Both);
+        insta::assert_debug_snapshot!(
+            firings(&alarm, "2024-11-02T00:00Z", 4),
+            @r###"[
+    "2024-11-02T01:30:00-04:00[America/New_York]",
+    "2024-11-03T01:30:00-04:00[America/New_York]",
+    "2024-11-03T01:30:00-05:00[America/New_York]",
+    "2024-11-04T01:30:00-05:00[America/New_York]",
+]"###,
+        );
+        // Starting between the two occurrences only fires once more on the
+        // day of the fold.
+        insta::assert_debug_snapshot!(
+            firings(&alarm, "2024-11-03T05:30Z", 2),
+            @r###"[
+    "2024-11-03T01:30:00-05:00[America/New_York]",
+    "2024-11-04T01:30:00-05:00[America/New_York]",
+]"###,
+        );
+    }
+
+    /// Tests a time zone where a backward transition at midnight makes the
+    /// civil date go backwards. In 2014, São Paulo's clocks went from
+    /// 2014-02-16T00:00 back to 2014-02-15T23:00.
+    #[test]
+    fn fold_across_midnight() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+        let tz = crate::tz::db().get("America/Sao_Paulo").unwrap();
+        let alarm = Alarm::new(time(23, 30, 0, 0), tz).fold(AlarmFold::Both);
+        insta::assert_debug_snapshot!(
+            firings(&alarm, "2014-02-15T00:00Z", 4),
+            @r###"[
+    "2014-02-14T23:30:00-02:00[America/Sao_Paulo]",
+    "2014-02-15T23:30:00-02:00[America/Sao_Paulo]",
+    "2014-02-15T23:30:00-03:00[America/Sao_Paulo]",
+    "2014-02-16T23:30:00-03:00[America/Sao_Paulo]",
+]"###,
+        );
+    }
+
+    #[test]
+    fn boundaries() {
+        let alarm = Alarm::new(time(12, 0, 0, 0), TimeZone::UTC);
+        let max = Timestamp::MAX;
+        insta::assert_snapshot!(
+            alarm.next_after(max).unwrap_err(),
+            @"failed to find next firing of alarm at 12:00:00 after 9999-12-30T22:00:00.999999999Z: converting 9999-12-31T12:00:00 with offset +00 to timestamp overflowed: parameter 'unix-seconds' with value 253402257600 is not in the required range of -377705023201..=253402207200",
+        );
+        assert_eq!(alarm.firings_after(max).next(), None);
+
+        let alarm = Alarm::new(time(0, 0, 0, 0), TimeZone::UTC);
+        let min = Timestamp::MIN;
+        insta::assert_snapshot!(
+            alarm.next_after(min).unwrap(),
+            @"-009999-01-03T00:00:00Z",
+        );
+    }
+}
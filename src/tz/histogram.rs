@@ -0,0 +1,476 @@
+use crate::{
+    civil::Weekday,
+    tz::{Offset, TimeZone},
+    SignedDuration, Timestamp,
+};
+
+/// Counts of timestamps by the local weekday and hour they fall on.
+///
+/// This is a common analytics kernel: given a set of timestamps, say, the
+/// times at which orders were placed, how many of them fall on each hour of
+/// the day (or each day of the week) in some time zone?
+///
+/// The straight-forward way to compute this is to convert every timestamp
+/// to a [`Zoned`](crate::Zoned) and ask for its hour and weekday. But this
+/// clones the time zone and looks up its offset from scratch for every
+/// timestamp. Instead, [`LocalHistogram::extend`] remembers the range of time
+/// over which the most recent offset applies, and only looks up a new offset
+/// when a timestamp falls outside of that range. Since offsets usually change
+/// at most a couple times per year, this means that the vast majority of
+/// timestamps are counted with nothing more than a bit of arithmetic. This
+/// works best when timestamps are sorted or clustered in time, but is correct
+/// for timestamps in any order.
+///
+/// A histogram doesn't allocate and doesn't remember the time zone used to
+/// compute it. Histograms can be combined with [`LocalHistogram::merge`],
+/// which makes it possible to, for example, compute histograms for records
+/// with different time zones separately and then combine them.
+///
+/// # Example
+///
+/// This counts the local hours of a few timestamps in New York. Notice that
+/// the offset changes from `-05` to `-04` in the middle of the timestamps,
+/// but all of them still land at `09:00` in local time.
+///
+/// ```
+/// use jiff::{civil::Weekday, tz::{LocalHistogram, TimeZone}, Timestamp};
+///
+/// let tz = TimeZone::get("America/New_York")?;
+/// let timestamps: Vec<Timestamp> = [
+///     "2024-03-08T14:00Z",
+///     "2024-03-09T14:30Z",
+///     "2024-03-11T13:15Z",
+///     "2024-03-12T13:45Z",
+///     "2024-03-12T18:00Z",
+/// ].iter().map(|s| s.parse()).collect::<Result<_, _>>()?;
+///
+/// let mut histogram = LocalHistogram::new();
+/// histogram.extend(&tz, timestamps);
+///
+/// assert_eq!(histogram.total(), 5);
+/// assert_eq!(histogram.hours()[9], 4);
+/// assert_eq!(histogram.hours()[14], 1);
+/// assert_eq!(histogram.weekday_hours(Weekday::Tuesday)[9], 1);
+/// assert_eq!(histogram.weekdays(), [1, 2, 0, 0, 1, 1, 0]);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LocalHistogram {
+    /// Counts indexed by the weekday (as an offset from Monday) and then by
+    /// the hour.
+    counts: [[u64; 24]; 7],
+}
+
+impl LocalHistogram {
+    /// Creates a new empty histogram.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::tz::LocalHistogram;
+    ///
+    /// let histogram = LocalHistogram::new();
+    /// assert_eq!(histogram.total(), 0);
+    /// ```
+    #[inline]
+    pub const fn new() -> LocalHistogram {
+        LocalHistogram { counts: [[0; 24]; 7] }
+    }
+
+    /// Counts a single timestamp at its local time in the time zone given.
+    ///
+    /// This looks up the offset of the time zone from scratch. When counting
+    /// many timestamps, prefer [`LocalHistogram::extend`] or
+    /// [`LocalHistogram::extend_zoned`], which share offset lookups between
+    /// timestamps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::Weekday, tz::{LocalHistogram, TimeZone}, Timestamp};
+    ///
+    /// let tz = TimeZone::get("Asia/Tokyo")?;
+    /// let ts: Timestamp = "2024-06-30T20:00Z".parse()?;
+    ///
+    /// let mut histogram = LocalHistogram::new();
+    /// histogram.add(&tz, ts);
+    /// // It's already Monday morning in Tokyo.
+    /// assert_eq!(histogram.weekday_hours(Weekday::Monday)[5], 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn add(&mut self, tz: &TimeZone, timestamp: Timestamp) {
+        self.add_with_offset(timestamp, tz.to_offset(timestamp));
+    }
+
+    /// Counts every timestamp given at its local time in the time zone
+    /// given.
+    ///
+    /// Offset lookups are shared between timestamps whenever the offset of
+    /// the time zone hasn't changed between them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tz::{LocalHistogram, TimeZone}, Timestamp, ToSpan};
+    ///
+    /// // One timestamp every 15 minutes for a year.
+    /// let start: Timestamp = "2024-01-01T00:00Z".parse()?;
+    /// let timestamps = (0..366 * 24 * 4).map(|i| start + (i * 15).minutes());
+    ///
+    /// let mut histogram = LocalHistogram::new();
+    /// histogram.extend(&TimeZone::get("Europe/Berlin")?, timestamps);
+    ///
+    /// // The clocks in Berlin skipped from 02:00 to 03:00 on one day, and
+    /// // repeated 02:00 on another day.
+    /// let hours = histogram.hours();
+    /// assert_eq!(hours[1], 366 * 4);
+    /// assert_eq!(hours[2], 366 * 4);
+    /// assert_eq!(hours[3], 366 * 4);
+    /// assert_eq!(histogram.total(), 366 * 24 * 4);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = Timestamp>>(
+        &mut self,
+        tz: &TimeZone,
+        timestamps: I,
+    ) {
+        let mut cache = OffsetCache::new();
+        for ts in timestamps {
+            let offset = cache.get(tz, ts);
+            self.add_with_offset(ts, offset);
+        }
+    }
+
+    /// Counts every timestamp given at its local time in the time zone
+    /// paired with it.
+    ///
+    /// This is like [`LocalHistogram::extend`], but for records that each
+    /// carry their own time zone. Offset lookups are shared between
+    /// consecutive records that refer to the same `TimeZone` value in memory,
+    /// so this works best when records are grouped by time zone. (Records
+    /// referring to distinct but equivalent `TimeZone` values are still
+    /// counted correctly, but without sharing offset lookups.)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tz::{LocalHistogram, TimeZone}, Timestamp};
+    ///
+    /// let paris = TimeZone::get("Europe/Paris")?;
+    /// let tokyo = TimeZone::get("Asia/Tokyo")?;
+    /// let records: Vec<(Timestamp, &TimeZone)> = vec![
+    ///     ("2024-07-01T07:00Z".parse()?, &paris),
+    ///     ("2024-07-02T07:30Z".parse()?, &paris),
+    ///     ("2024-07-01T00:00Z".parse()?, &tokyo),
+    /// ];
+    ///
+    /// let mut histogram = LocalHistogram::new();
+    /// histogram.extend_zoned(records);
+    /// assert_eq!(histogram.hours()[9], 3);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn extend_zoned<'z, I>(&mut self, records: I)
+    where
+        I: IntoIterator<Item = (Timestamp, &'z TimeZone)>,
+    {
+        let mut last: Option<&TimeZone> = None;
+        let mut cache = OffsetCache::new();
+        for (ts, tz) in records {
+            if !last.is_some_and(|last| core::ptr::eq(last, tz)) {
+                last = Some(tz);
+                cache = OffsetCache::new();
+            }
+            let offset = cache.get(tz, ts);
+            self.add_with_offset(ts, offset);
+        }
+    }
+
+    /// Adds all of the counts in the histogram given to this one.
+    ///
+    /// Counts saturate at `u64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tz::{LocalHistogram, TimeZone}, Timestamp};
+    ///
+    /// let ts: Timestamp = "2024-07-01T12:00Z".parse()?;
+    ///
+    /// let mut utc = LocalHistogram::new();
+    /// utc.add(&TimeZone::UTC, ts);
+    /// let mut india = LocalHistogram::new();
+    /// india.add(&TimeZone::get("Asia/Kolkata")?, ts);
+    ///
+    /// utc.merge(&india);
+    /// assert_eq!(utc.hours()[12], 1);
+    /// assert_eq!(utc.hours()[17], 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn merge(&mut self, other: &LocalHistogram) {
+        for (ours, theirs) in self.counts.iter_mut().zip(other.counts.iter()) {
+            for (ours, theirs) in ours.iter_mut().zip(theirs.iter()) {
+                *ours = ours.saturating_add(*theirs);
+            }
+        }
+    }
+
+    /// Returns the counts for each hour on the given weekday, indexed by
+    /// hour.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::Weekday, tz::{LocalHistogram, TimeZone}, Timestamp};
+    ///
+    /// let ts: Timestamp = "2024-07-06T23:59:59.999Z".parse()?;
+    /// let mut histogram = LocalHistogram::new();
+    /// histogram.add(&TimeZone::UTC, ts);
+    /// assert_eq!(histogram.weekday_hours(Weekday::Saturday)[23], 1);
+    /// assert_eq!(histogram.weekday_hours(Weekday::Sunday)[0], 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn weekday_hours(&self, weekday: Weekday) -> [u64; 24] {
+        self.counts[weekday.to_monday_zero_offset() as usize]
+    }
+
+    /// Returns the counts for each hour of the day, regardless of weekday,
+    /// indexed by hour.
+    ///
+    /// Counts saturate at `u64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tz::{LocalHistogram, TimeZone}, Timestamp};
+    ///
+    /// let ts1: Timestamp = "2024-07-06T08:00Z".parse()?;
+    /// let ts2: Timestamp = "2024-07-09T08:30Z".parse()?;
+    /// let mut histogram = LocalHistogram::new();
+    /// histogram.extend(&TimeZone::UTC, [ts1, ts2]);
+    /// assert_eq!(histogram.hours()[8], 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn hours(&self) -> [u64; 24] {
+        let mut hours = [0u64; 24];
+        for day in self.counts.iter() {
+            for (total, count) in hours.iter_mut().zip(day.iter()) {
+                *total = total.saturating_add(*count);
+            }
+        }
+        hours
+    }
+
+    /// Returns the counts for each weekday, regardless of hour.
+    ///
+    /// The counts are indexed by the weekday's offset from Monday. That is,
+    /// index `0` is Monday and index `6` is Sunday, which corresponds to
+    /// [`Weekday::to_monday_zero_offset`].
+    ///
+    /// Counts saturate at `u64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::Weekday, tz::{LocalHistogram, TimeZone}, Timestamp};
+    ///
+    /// let ts: Timestamp = "2024-07-07T12:00Z".parse()?;
+    /// let mut histogram = LocalHistogram::new();
+    /// histogram.add(&TimeZone::UTC, ts);
+    ///
+    /// let index = Weekday::Sunday.to_monday_zero_offset() as usize;
+    /// assert_eq!(histogram.weekdays()[index], 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn weekdays(&self) -> [u64; 7] {
+        let mut weekdays = [0u64; 7];
+        for (total, day) in weekdays.iter_mut().zip(self.counts.iter()) {
+            *total = day.iter().fold(0u64, |sum, &n| sum.saturating_add(n));
+        }
+        weekdays
+    }
+
+    /// Returns the total number of timestamps counted.
+    ///
+    /// This saturates at `u64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tz::{LocalHistogram, TimeZone}, Timestamp};
+    ///
+    /// let mut histogram = LocalHistogram::new();
+    /// histogram.extend(&TimeZone::UTC, [Timestamp::MIN, Timestamp::MAX]);
+    /// assert_eq!(histogram.total(), 2);
+    /// ```
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.weekdays().iter().fold(0u64, |sum, &n| sum.saturating_add(n))
+    }
+
+    /// Counts the given timestamp with the offset given.
+    #[inline]
+    fn add_with_offset(&mut self, timestamp: Timestamp, offset: Offset) {
+        // The seconds of a timestamp are truncated toward zero, but we want
+        // the civil second containing the timestamp, which requires flooring.
+        let mut second = timestamp.as_second();
+        if timestamp.subsec_nanosecond() < 0 {
+            second -= 1;
+        }
+        // This can't overflow since timestamps and offsets are limited to
+        // ranges that are much smaller than `i64`.
+        let local = second + i64::from(offset.seconds());
+        let day = local.div_euclid(86_400);
+        let hour = local.rem_euclid(86_400) / 3_600;
+        // 1970-01-01 was a Thursday, which is `3` days after Monday.
+        let weekday = (day + 3).rem_euclid(7);
+        let count = &mut self.counts[weekday as usize][hour as usize];
+        *count = count.saturating_add(1);
+    }
+}
+
+/// Remembers the offset of a time zone for a range of time.
+#[derive(Debug)]
+struct OffsetCache {
+    /// The inclusive start of the range over which `offset` applies.
+    start: Timestamp,
+    /// The exclusive end of the range over which `offset` applies. `None`
+    /// means the offset applies forever.
+    end: Option<Timestamp>,
+    offset: Offset,
+}
+
+impl OffsetCache {
+    /// Creates a new empty cache.
+    ///
+    /// The range of this cache is empty, which forces the first lookup to
+    /// consult the time zone.
+    #[inline]
+    fn new() -> OffsetCache {
+        OffsetCache {
+            start: Timestamp::MAX,
+            end: Some(Timestamp::MIN),
+            offset: Offset::UTC,
+        }
+    }
+
+    /// Returns the offset of the time zone at the given timestamp.
+    ///
+    /// The time zone given must be the same one used for every other call
+    /// on this cache.
+    #[inline]
+    fn get(&mut self, tz: &TimeZone, timestamp: Timestamp) -> Offset {
+        let in_range = self.start <= timestamp
+            && self.end.map_or(true, |end| timestamp < end);
+        if !in_range {
+            self.refresh(tz, timestamp);
+        }
+        self.offset
+    }
+
+    /// Looks up the offset of the time zone at the given timestamp, along
+    /// with the range of time over which that offset applies.
+    #[cold]
+    #[inline(never)]
+    fn refresh(&mut self, tz: &TimeZone, timestamp: Timestamp) {
+        // A transition at precisely `timestamp` is in effect at `timestamp`,
+        // but `preceding` only returns transitions strictly before the
+        // timestamp given. Transitions always occur on a whole second, so
+        // when this saturates at `Timestamp::MAX`, nothing is missed.
+        let after = timestamp
+            .checked_add(SignedDuration::from_nanos(1))
+            .unwrap_or(timestamp);
+        self.offset = tz.to_offset(timestamp);
+        self.start = tz
+            .preceding(after)
+            .next()
+            .map_or(Timestamp::MIN, |trans| trans.timestamp());
+        self.end =
+            tz.following(timestamp).next().map(|trans| trans.timestamp());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToSpan;
+
+    use super::*;
+
+    /// Checks that a histogram computed with shared offset lookups matches
+    /// one computed by converting each timestamp to a civil datetime, in both
+    /// sorted and unsorted order.
+    fn check(tz: &TimeZone, timestamps: &[Timestamp]) {
+        let mut expected = LocalHistogram::new();
+        for &ts in timestamps {
+            let dt = tz.to_datetime(ts);
+            let weekday = dt.weekday().to_monday_zero_offset() as usize;
+            expected.counts[weekday][dt.hour() as usize] += 1;
+        }
+
+        let mut got = LocalHistogram::new();
+        got.extend(tz, timestamps.iter().copied());
+        assert_eq!(got, expected);
+
+        let mut got = LocalHistogram::new();
+        got.extend(tz, timestamps.iter().rev().copied());
+        assert_eq!(got, expected);
+
+        let mut got = LocalHistogram::new();
+        got.extend_zoned(timestamps.iter().map(|&ts| (ts, tz)));
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn matches_civil() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+        let start: Timestamp = "2023-12-31T12:34:56.789Z".parse().unwrap();
+        let timestamps: alloc::vec::Vec<Timestamp> =
+            (0..3 * 366 * 24).map(|i| start + (i * 23).minutes()).collect();
+        for name in [
+            "America/New_York",
+            "Europe/London",
+            "Australia/Lord_Howe",
+            "Asia/Kolkata",
+            "Pacific/Chatham",
+        ] {
+            let tz = crate::tz::db().get(name).unwrap();
+            check(&tz, &timestamps);
+        }
+        check(&TimeZone::fixed(crate::tz::offset(-9)), &timestamps);
+        check(&TimeZone::UTC, &timestamps);
+    }
+
+    #[test]
+    fn boundaries() {
+        let timestamps = [
+            Timestamp::MIN,
+            Timestamp::MAX,
+            "-000001-12-31T23:59:59.999999999Z".parse().unwrap(),
+            "1969-12-31T23:59:59.5Z".parse().unwrap(),
+            Timestamp::UNIX_EPOCH,
+        ];
+        check(&TimeZone::UTC, &timestamps);
+        check(&TimeZone::fixed(Offset::MAX), &timestamps[2..]);
+        check(&TimeZone::fixed(Offset::MIN), &timestamps[2..]);
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+        check(&crate::tz::db().get("America/New_York").unwrap(), &timestamps);
+    }
+}
@@ -124,10 +124,12 @@ compiled binary.
 */
 
 pub use self::{
+    alarm::{Alarm, AlarmFirings, AlarmFold, AlarmGap},
     ambiguous::{
         AmbiguousOffset, AmbiguousTimestamp, AmbiguousZoned, Disambiguation,
     },
     db::{db, TimeZoneDatabase, TimeZoneName, TimeZoneNameIter},
+    histogram::LocalHistogram,
     offset::{Dst, Offset, OffsetArithmetic, OffsetConflict, OffsetRound},
     timezone::{
         TimeZone, TimeZoneFollowingTransitions, TimeZoneOffsetInfo,
@@ -135,12 +137,19 @@ pub use self::{
     },
 };
 
+#[cfg(feature = "alloc")]
+pub use self::rules::TimeZoneRules;
+
+mod alarm;
 mod ambiguous;
 #[cfg(feature = "tzdb-concatenated")]
 mod concatenated;
 mod db;
+mod histogram;
 mod offset;
 pub(crate) mod posix;
+#[cfg(feature = "alloc")]
+mod rules;
 #[cfg(feature = "tz-system")]
 mod system;
 #[cfg(all(test, feature = "alloc"))]
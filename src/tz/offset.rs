@@ -1355,6 +1355,20 @@ impl TryFrom<SignedDuration> for Offset {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Offset {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Offset> {
+        let seconds: t::SpanZoneOffset = u.arbitrary()?;
+        Ok(Offset::from_seconds_ranged(seconds))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        t::SpanZoneOffset::size_hint(depth)
+    }
+}
+
 /// Options for [`Offset::checked_add`] and [`Offset::checked_sub`].
 ///
 /// This type provides a way to ergonomically add one of a few different
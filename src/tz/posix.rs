@@ -304,6 +304,14 @@ impl<ABBREV: AsRef<str>> PosixTimeZone<ABBREV> {
         AmbiguousOffset::from_iambiguous_offset_const(iamoff)
     }
 
+    /// Returns true if and only if this time zone never has any transitions.
+    ///
+    /// This is the case when there is no DST rule, or when the DST rule
+    /// indicates that DST is in effect all year.
+    pub(crate) fn is_fixed(&self) -> bool {
+        self.inner.is_fixed()
+    }
+
     /// Returns the timestamp of the most recent time zone transition prior
     /// to the timestamp given. If one doesn't exist, `None` is returned.
     pub(crate) fn previous_transition(
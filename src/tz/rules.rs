@@ -0,0 +1,441 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    error::{err, Error},
+    shared::{self, util::array_str::Abbreviation},
+    tz::{posix::PosixTimeZone, tzif, Dst, Offset, TimeZone},
+    Timestamp,
+};
+
+/// A description of the rules of a time zone, used to construct a bespoke
+/// [`TimeZone`](crate::tz::TimeZone) at runtime.
+///
+/// Time zone rules consist of an initial offset, a sequence of transitions
+/// to new offsets and an optional [POSIX TZ] string that describes the
+/// rules for all times after the last transition. This is the same data that
+/// is stored in the TZif files of the [IANA Time Zone Database], but without
+/// needing to encode it as TZif binary data.
+///
+/// This is useful for simulation and testing, for example, to check how an
+/// application behaves if a region were to change its rules. In most other
+/// cases, time zones should be looked up in the time zone database with
+/// [`TimeZone::get`](crate::tz::TimeZone::get) instead.
+///
+/// Rules are turned into a time zone with
+/// [`TimeZone::from_transitions`](crate::tz::TimeZone::from_transitions),
+/// which is where they are validated.
+///
+/// [POSIX TZ]: https://pubs.opengroup.org/onlinepubs/9799919799/basedefs/V1_chap08.html
+/// [IANA Time Zone Database]: https://en.wikipedia.org/wiki/Tz_database
+///
+/// # Example
+///
+/// This shows how to build a time zone for a hypothetical rule change, where
+/// `America/New_York` stays on daylight saving time permanently after the
+/// transition into DST in 2026. For brevity, all of the earlier transitions
+/// are left out:
+///
+/// ```
+/// use jiff::{civil::date, tz::{self, Dst, TimeZone, TimeZoneRules}};
+///
+/// let ny = TimeZone::get("America/New_York")?;
+/// let last = date(2026, 3, 8).at(2, 0, 0, 0).to_zoned(ny)?.timestamp();
+/// let rules = TimeZoneRules::new(tz::offset(-5), "EST")
+///     .transition(last, tz::offset(-4), Dst::Yes, "EDT");
+/// let tz = TimeZone::from_transitions("Custom/New_York", &rules)?;
+///
+/// let zdt = date(2026, 12, 1).at(12, 0, 0, 0).to_zoned(tz.clone())?;
+/// assert_eq!(zdt.offset(), tz::offset(-4));
+/// let zdt = date(2026, 1, 1).at(12, 0, 0, 0).to_zoned(tz)?;
+/// assert_eq!(zdt.offset(), tz::offset(-5));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct TimeZoneRules {
+    initial: Rule,
+    transitions: Vec<(Timestamp, Rule)>,
+    posix: Option<String>,
+}
+
+/// A single local time type, as given by the caller.
+#[derive(Clone, Debug)]
+struct Rule {
+    offset: Offset,
+    dst: Dst,
+    abbreviation: String,
+}
+
+impl TimeZoneRules {
+    /// Creates new time zone rules with the given offset and abbreviation
+    /// in effect before the first transition.
+    ///
+    /// The initial offset is never considered to be daylight saving time.
+    ///
+    /// # Example
+    ///
+    /// Rules without any transitions or POSIX TZ string describe a time zone
+    /// with a fixed offset, but with a name and abbreviation:
+    ///
+    /// ```
+    /// use jiff::{tz::{self, TimeZone, TimeZoneRules}, Timestamp};
+    ///
+    /// let rules = TimeZoneRules::new(tz::offset(10), "AEST");
+    /// let tz = TimeZone::from_transitions("Custom/Brisbane", &rules)?;
+    /// let info = tz.to_offset_info(Timestamp::UNIX_EPOCH);
+    /// assert_eq!(info.offset(), tz::offset(10));
+    /// assert_eq!(info.abbreviation(), "AEST");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(offset: Offset, abbreviation: &str) -> TimeZoneRules {
+        let abbreviation = abbreviation.to_string();
+        let initial = Rule { offset, dst: Dst::No, abbreviation };
+        TimeZoneRules { initial, transitions: Vec::new(), posix: None }
+    }
+
+    /// Adds a transition to the given offset at the given instant in time.
+    ///
+    /// Transitions must be added in increasing order of their timestamps,
+    /// and their timestamps must not have a fractional second component.
+    /// Otherwise, [`TimeZone::from_transitions`] returns an error.
+    ///
+    /// [`TimeZone::from_transitions`]: crate::tz::TimeZone::from_transitions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tz::{self, Dst, TimeZone, TimeZoneRules}, Timestamp};
+    ///
+    /// let rules = TimeZoneRules::new(tz::offset(1), "CET")
+    ///     .transition(Timestamp::from_second(0)?, tz::offset(2), Dst::Yes, "CEST")
+    ///     .transition(Timestamp::from_second(3600)?, tz::offset(1), Dst::No, "CET");
+    /// let tz = TimeZone::from_transitions("Custom/Test", &rules)?;
+    ///
+    /// let info = tz.to_offset_info(Timestamp::from_second(1800)?);
+    /// assert_eq!(info.offset(), tz::offset(2));
+    /// assert_eq!(info.abbreviation(), "CEST");
+    /// assert!(info.dst().is_dst());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn transition(
+        mut self,
+        timestamp: Timestamp,
+        offset: Offset,
+        dst: Dst,
+        abbreviation: &str,
+    ) -> TimeZoneRules {
+        let abbreviation = abbreviation.to_string();
+        self.transitions.push((timestamp, Rule { offset, dst, abbreviation }));
+        self
+    }
+
+    /// Sets the [POSIX TZ] string that describes the rules for all times
+    /// after the last transition.
+    ///
+    /// As required by [RFC 9636], when there is at least one transition, the
+    /// POSIX TZ string must be consistent with the last one. That is, the
+    /// offset, DST status and abbreviation of the last transition must be
+    /// what the POSIX TZ string says is in effect at that time. Otherwise,
+    /// [`TimeZone::from_transitions`] returns an error.
+    ///
+    /// [POSIX TZ]: https://pubs.opengroup.org/onlinepubs/9799919799/basedefs/V1_chap08.html
+    /// [RFC 9636]: https://datatracker.ietf.org/doc/rfc9636/
+    /// [`TimeZone::from_transitions`]: crate::tz::TimeZone::from_transitions
+    ///
+    /// # Example
+    ///
+    /// This shows a time zone that observes a fixed offset until 2030, at
+    /// which point it starts observing daylight saving time:
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::{self, Dst, TimeZone, TimeZoneRules}};
+    ///
+    /// let start = date(2030, 1, 1).at(0, 0, 0, 0).to_zoned(TimeZone::fixed(tz::offset(-3)))?;
+    /// let rules = TimeZoneRules::new(tz::offset(-3), "-03")
+    ///     .transition(start.timestamp(), tz::offset(-3), Dst::No, "-03")
+    ///     .posix("<-03>3<-02>,M3.2.0,M11.1.0");
+    /// let tz = TimeZone::from_transitions("Custom/Atlantis", &rules)?;
+    ///
+    /// let zdt = date(2029, 12, 1).at(12, 0, 0, 0).to_zoned(tz.clone())?;
+    /// assert_eq!(zdt.offset(), tz::offset(-3));
+    /// let zdt = date(2030, 7, 1).at(12, 0, 0, 0).to_zoned(tz)?;
+    /// assert_eq!(zdt.offset(), tz::offset(-2));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn posix(mut self, posix_tz_string: &str) -> TimeZoneRules {
+        self.posix = Some(posix_tz_string.to_string());
+        self
+    }
+
+    /// Returns rules that follow the given time zone up until `start`, and
+    /// these rules from `start` onwards.
+    ///
+    /// At `start`, there is a transition to the initial offset of these
+    /// rules. Every transition in these rules must come after `start`, which
+    /// is checked when the rules returned are encoded.
+    pub(crate) fn overlay(
+        &self,
+        tz: &TimeZone,
+        start: Timestamp,
+    ) -> TimeZoneRules {
+        let rule = |offset: Offset, dst: Dst, abbreviation: &str| Rule {
+            offset,
+            dst,
+            abbreviation: abbreviation.to_string(),
+        };
+        let info = tz.to_offset_info(Timestamp::MIN);
+        let initial = rule(info.offset(), info.dst(), info.abbreviation());
+        let mut transitions: Vec<(Timestamp, Rule)> = tz
+            .preceding(start)
+            .map(|t| {
+                (t.timestamp(), rule(t.offset(), t.dst(), t.abbreviation()))
+            })
+            .collect();
+        transitions.reverse();
+        transitions.push((start, self.initial.clone()));
+        transitions.extend(self.transitions.iter().cloned());
+        TimeZoneRules { initial, transitions, posix: self.posix.clone() }
+    }
+
+    /// Validates these rules and encodes them as TZif binary data.
+    ///
+    /// Encoding to TZif (instead of building the parsed representation
+    /// directly) means that custom time zones get exactly the same
+    /// post-processing and consistency checks as time zones read from the
+    /// time zone database.
+    pub(crate) fn to_tzif_bytes(&self) -> Result<Vec<u8>, Error> {
+        if let Some(ref posix) = self.posix {
+            PosixTimeZone::parse(posix)?;
+        }
+        let mut types: Vec<shared::TzifLocalTimeType> = Vec::new();
+        let mut designations = String::new();
+        let mut timestamps = Vec::with_capacity(self.transitions.len());
+        let mut type_indices = Vec::with_capacity(self.transitions.len());
+        add_type(&mut types, &mut designations, &self.initial)?;
+        let mut prev: Option<Timestamp> = None;
+        for &(timestamp, ref rule) in self.transitions.iter() {
+            if timestamp.subsec_nanosecond() != 0 {
+                return Err(err!(
+                    "time zone transition at {timestamp} has a fractional \
+                     second component, but transitions must occur on \
+                     whole seconds",
+                ));
+            }
+            if let Some(prev) = prev {
+                if timestamp <= prev {
+                    return Err(err!(
+                        "time zone transition at {timestamp} must come \
+                         after the previous transition at {prev}",
+                    ));
+                }
+            }
+            prev = Some(timestamp);
+            timestamps.push(timestamp.as_second());
+            type_indices.push(add_type(&mut types, &mut designations, rule)?);
+        }
+        Ok(tzif::encode(
+            &types,
+            &timestamps,
+            &type_indices,
+            &designations,
+            self.posix.as_deref(),
+            None,
+        ))
+    }
+}
+
+/// Returns the index of the local time type for the given rule, adding it
+/// (and its abbreviation) if it doesn't exist yet.
+fn add_type(
+    types: &mut Vec<shared::TzifLocalTimeType>,
+    designations: &mut String,
+    rule: &Rule,
+) -> Result<u8, Error> {
+    let abbrev = rule.abbreviation.as_str();
+    if abbrev.is_empty()
+        || abbrev.contains('\x00')
+        || Abbreviation::new(abbrev).is_none()
+    {
+        return Err(err!(
+            "time zone abbreviation {abbrev:?} is invalid, it must be \
+             non-empty, at most 30 bytes long and must not contain NUL",
+        ));
+    }
+    let designation = match designations
+        .split_terminator('\x00')
+        .scan(0, |start, name| {
+            let range = (*start, *start + name.len());
+            *start += name.len() + 1;
+            Some((name, range))
+        })
+        .find(|&(name, _)| name == abbrev)
+    {
+        Some((_, range)) => range,
+        None => {
+            let start = designations.len();
+            designations.push_str(abbrev);
+            designations.push('\x00');
+            (start, start + abbrev.len())
+        }
+    };
+    let (Ok(start), Ok(end)) =
+        (u8::try_from(designation.0), u8::try_from(designation.1))
+    else {
+        return Err(err!(
+            "time zone abbreviations are too long in total, \
+             at most 255 bytes are supported",
+        ));
+    };
+    let typ = shared::TzifLocalTimeType {
+        offset: rule.offset.seconds(),
+        is_dst: rule.dst.is_dst(),
+        designation: (start, end),
+        indicator: shared::TzifIndicator::LocalWall,
+    };
+    let existing = types.iter().position(|t| {
+        t.offset == typ.offset
+            && t.is_dst == typ.is_dst
+            && t.designation == typ.designation
+    });
+    if let Some(index) = existing {
+        // OK because we never add more than 256 local time types.
+        return Ok(u8::try_from(index).unwrap());
+    }
+    let Ok(index) = u8::try_from(types.len()) else {
+        return Err(err!(
+            "time zone rules have too many distinct combinations of \
+             offset, DST status and abbreviation, at most 256 are supported",
+        ));
+    };
+    types.push(typ);
+    Ok(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tz::{self, TimeZone};
+
+    use super::*;
+
+    #[test]
+    fn ok_roundtrip_transitions() {
+        let tz = TimeZone::get("America/New_York").unwrap();
+        let start = Timestamp::from_second(0).unwrap();
+        let end = Timestamp::from_second(2_000_000_000).unwrap();
+        let mut rules = TimeZoneRules::new(tz::offset(-5), "EST");
+        for t in tz.following(start).take_while(|t| t.timestamp() < end) {
+            rules = rules.transition(
+                t.timestamp(),
+                t.offset(),
+                t.dst(),
+                t.abbreviation(),
+            );
+        }
+        let rules = rules.posix("EST5EDT,M3.2.0,M11.1.0");
+        let custom = TimeZone::from_transitions("Custom/NY", &rules).unwrap();
+        assert_eq!(custom.iana_name(), Some("Custom/NY"));
+
+        let mut ts = start;
+        let step = crate::SignedDuration::from_hours(7);
+        while ts < Timestamp::from_second(2_500_000_000).unwrap() {
+            assert_eq!(tz.to_offset(ts), custom.to_offset(ts), "{ts}");
+            ts = ts.checked_add(step).unwrap();
+        }
+    }
+
+    #[test]
+    fn ok_overlay() {
+        let ts = |s: i64| Timestamp::from_second(s).unwrap();
+        let start = ts(1_800_000_000);
+        let rules = TimeZoneRules::new(tz::offset(-5), "EST").posix("EST5");
+
+        let tz = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        let got = tz.with_rules_after(start, &rules).unwrap();
+        assert_eq!(got.iana_name(), None);
+        for t in tz.preceding(start).take(500) {
+            let before = ts(t.timestamp().as_second() - 1);
+            assert_eq!(tz.to_offset(before), got.to_offset(before));
+            assert_eq!(
+                tz.to_offset(t.timestamp()),
+                got.to_offset(t.timestamp())
+            );
+        }
+        assert_eq!(got.to_offset(ts(1_900_000_000)), tz::offset(-5));
+        assert!(got.following(start).next().is_none());
+
+        let tz = TimeZone::fixed(tz::offset(2));
+        let got = tz.with_rules_after(start, &rules).unwrap();
+        assert_eq!(got.to_offset(ts(1_799_999_999)), tz::offset(2));
+        assert_eq!(got.to_offset(start), tz::offset(-5));
+    }
+
+    #[test]
+    fn err_overlay() {
+        let tz = TimeZone::get("America/New_York").unwrap();
+        let start = Timestamp::from_second(1_800_000_000).unwrap();
+        let rules = TimeZoneRules::new(tz::offset(-5), "EST").transition(
+            Timestamp::from_second(1_700_000_000).unwrap(),
+            tz::offset(-4),
+            Dst::Yes,
+            "EDT",
+        );
+        insta::assert_snapshot!(
+            tz.with_rules_after(start, &rules).unwrap_err(),
+            @"time zone transition at 2023-11-14T22:13:20Z must come after the previous transition at 2027-01-15T08:00:00Z",
+        );
+    }
+
+    #[test]
+    fn err_invalid_rules() {
+        let t = |rules: TimeZoneRules| {
+            TimeZone::from_transitions("Custom/Test", &rules).unwrap_err()
+        };
+        let ts = |s: i64| Timestamp::from_second(s).unwrap();
+
+        insta::assert_snapshot!(
+            t(TimeZoneRules::new(tz::offset(1), "")),
+            @r###"time zone abbreviation "" is invalid, it must be non-empty, at most 30 bytes long and must not contain NUL"###,
+        );
+        insta::assert_snapshot!(
+            t(TimeZoneRules::new(tz::offset(1), "CET")
+                .transition(ts(10), tz::offset(2), Dst::Yes, "CEST")
+                .transition(ts(10), tz::offset(1), Dst::No, "CET")),
+            @"time zone transition at 1970-01-01T00:00:10Z must come after the previous transition at 1970-01-01T00:00:10Z",
+        );
+        insta::assert_snapshot!(
+            t(TimeZoneRules::new(tz::offset(1), "CET").transition(
+                Timestamp::new(10, 1).unwrap(),
+                tz::offset(2),
+                Dst::Yes,
+                "CEST",
+            )),
+            @"time zone transition at 1970-01-01T00:00:10.000000001Z has a fractional second component, but transitions must occur on whole seconds",
+        );
+        insta::assert_snapshot!(
+            t(TimeZoneRules::new(tz::offset(1), "CET").posix("CET-1CEST,")),
+            @r###"invalid POSIX TZ string "CET-1CEST,": after parsing DST offset in POSIX time zone string, found end of string after a trailing ','"###,
+        );
+        insta::assert_snapshot!(
+            t(TimeZoneRules::new(tz::offset(1), "CET")
+                .transition(ts(10), tz::offset(2), Dst::Yes, "CEST")
+                .posix("CET-1")),
+            @"expected last transition to have DST offset of 7200, but got 3600 according to POSIX TZ string CET-1",
+        );
+        let mut rules = TimeZoneRules::new(tz::offset(0), "A");
+        for i in 0..300 {
+            rules = rules.transition(
+                ts(i),
+                tz::Offset::from_seconds(i32::try_from(i).unwrap()).unwrap(),
+                Dst::No,
+                "A",
+            );
+        }
+        insta::assert_snapshot!(t(rules), @"time zone rules have too many distinct combinations of offset, DST status and abbreviation, at most 256 are supported");
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
         offset::{Dst, Offset},
     },
     util::{array_str::ArrayStr, sync::Arc},
-    Timestamp, Zoned,
+    SignedDuration, Timestamp, Zoned,
 };
 
 #[cfg(feature = "alloc")]
@@ -535,6 +535,133 @@ impl TimeZone {
         Ok(TimeZone { repr })
     }
 
+    /// Creates a time zone from the given rules, which are made up of
+    /// user-supplied transitions, offsets and an optional POSIX TZ string.
+    ///
+    /// This provides a way to construct bespoke time zones at runtime, for
+    /// example, to simulate a hypothetical change to a region's time zone
+    /// rules. See [`TimeZoneRules`](crate::tz::TimeZoneRules) for how to
+    /// describe them.
+    ///
+    /// The time zone returned behaves exactly like one created from TZif
+    /// data via [`TimeZone::tzif`]. The name given is reported by
+    /// [`TimeZone::iana_name`], so it should not collide with a real IANA
+    /// time zone identifier.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the rules are invalid. This occurs when:
+    ///
+    /// * A transition doesn't come after the transition preceding it.
+    /// * A transition has a fractional second component.
+    /// * An abbreviation is empty, longer than 30 bytes or contains a NUL
+    ///   byte.
+    /// * There are more than 256 distinct combinations of offset, DST
+    ///   status and abbreviation.
+    /// * The POSIX TZ string is invalid or inconsistent with the last
+    ///   transition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::{self, Dst, TimeZone, TimeZoneRules}};
+    ///
+    /// let change = date(2030, 1, 1).at(0, 0, 0, 0).in_tz("Europe/Berlin")?;
+    /// let rules = TimeZoneRules::new(tz::offset(1), "CET")
+    ///     .transition(change.timestamp(), tz::offset(2), Dst::No, "EET")
+    ///     .posix("EET-2");
+    /// let tz = TimeZone::from_transitions("Custom/Berlin", &rules)?;
+    ///
+    /// let zdt = date(2030, 6, 1).at(12, 0, 0, 0).to_zoned(tz)?;
+    /// assert_eq!(zdt.to_string(), "2030-06-01T12:00:00+02:00[Custom/Berlin]");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_transitions(
+        name: &str,
+        rules: &crate::tz::TimeZoneRules,
+    ) -> Result<TimeZone, Error> {
+        let data = rules.to_tzif_bytes()?;
+        TimeZone::tzif(name, &data)
+    }
+
+    /// Returns a copy of this time zone where the given rules replace this
+    /// time zone's rules from `start` onwards.
+    ///
+    /// This is useful for "what if" analysis of a proposed change to a
+    /// region's time zone rules. For example, to check how scheduled events
+    /// would move if daylight saving time were abolished. All transitions
+    /// in this time zone before `start` are kept. At `start`, the time
+    /// zone transitions to the initial offset of the rules given, and the
+    /// transitions and POSIX TZ string of the rules given apply after that.
+    ///
+    /// The time zone returned has the same name as this time zone, but it
+    /// does not compare equal to it. Like with [`TimeZone::without_lmt`],
+    /// this means that printing a [`Zoned`] in the time zone returned and
+    /// parsing it back uses the time zone from the database instead, which
+    /// can result in an offset conflict.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same circumstances as
+    /// [`TimeZone::from_transitions`]. In particular, `start` must not have a
+    /// fractional second component and every transition in the rules given
+    /// must come after `start`.
+    ///
+    /// # Example
+    ///
+    /// This shows what happens to a recurring 8am meeting in New York if
+    /// the US were to abolish daylight saving time (and stay on standard
+    /// time) at the start of 2027:
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::{self, TimeZone, TimeZoneRules}};
+    ///
+    /// let current = TimeZone::get("America/New_York")?;
+    /// let start = date(2027, 1, 1).at(0, 0, 0, 0).to_zoned(current.clone())?;
+    /// let rules = TimeZoneRules::new(tz::offset(-5), "EST").posix("EST5");
+    /// let proposed = current.with_rules_after(start.timestamp(), &rules)?;
+    ///
+    /// // Before the change, nothing is different.
+    /// let meeting = date(2026, 7, 1).at(8, 0, 0, 0);
+    /// assert_eq!(
+    ///     meeting.to_zoned(current.clone())?.timestamp(),
+    ///     meeting.to_zoned(proposed.clone())?.timestamp(),
+    /// );
+    ///
+    /// // After the change, the same meeting happens an hour later in
+    /// // summer, as seen from UTC.
+    /// let meeting = date(2027, 7, 1).at(8, 0, 0, 0);
+    /// let before = meeting.to_zoned(current.clone())?.timestamp();
+    /// let after = meeting.to_zoned(proposed.clone())?.timestamp();
+    /// assert_eq!(before.to_string(), "2027-07-01T12:00:00Z");
+    /// assert_eq!(after.to_string(), "2027-07-01T13:00:00Z");
+    ///
+    /// // But in winter, it's unaffected.
+    /// let meeting = date(2027, 12, 1).at(8, 0, 0, 0);
+    /// assert_eq!(
+    ///     meeting.to_zoned(current)?.timestamp(),
+    ///     meeting.to_zoned(proposed)?.timestamp(),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn with_rules_after(
+        &self,
+        start: Timestamp,
+        rules: &crate::tz::TimeZoneRules,
+    ) -> Result<TimeZone, Error> {
+        use alloc::string::ToString;
+
+        let data = rules.overlay(self, start).to_tzif_bytes()?;
+        let name = self.iana_name().map(|name| name.to_string());
+        let tzif = crate::tz::tzif::Tzif::parse(name, &data)?;
+        let repr = Repr::arc_tzif(Arc::new(tzif));
+        Ok(TimeZone { repr })
+    }
+
     /// Returns a `TimeZone` that is specifially marked as "unknown."
     ///
     /// This corresponds to the Unicode CLDR identifier `Etc/Unknown`, which
@@ -577,6 +704,75 @@ impl TimeZone {
         TimeZone { repr }
     }
 
+    /// Returns a copy of this time zone where [local mean time] (LMT) is
+    /// replaced by the first standard offset used in this time zone.
+    ///
+    /// Most time zones in the IANA Time Zone Database begin with a period of
+    /// local mean time. That is, before a region standardized on a particular
+    /// offset from UTC, civil time was determined by the position of the
+    /// sun. This is reflected in the offsets used for those times, which are
+    /// typically not a whole number of minutes. For example, in
+    /// `America/Los_Angeles`, the offset before `1883-11-18T12:07:02` is
+    /// `-07:52:58`.
+    ///
+    /// By default, Jiff uses these offsets because they are what the time
+    /// zone database specifies, and they are more accurate for historical
+    /// applications (like genealogy). But they can be surprising in other
+    /// contexts. This routine returns a time zone that instead uses the first
+    /// standard (i.e., not DST) offset for all times before the transition
+    /// out of local mean time. All other transitions are unchanged.
+    ///
+    /// If this time zone doesn't begin with local mean time (for example,
+    /// fixed offset and POSIX time zones), then this returns a clone of this
+    /// time zone.
+    ///
+    /// Note that the time zone returned has the same name as this time zone,
+    /// but it does not compare equal to it. Moreover, when a [`Zoned`] in
+    /// the returned time zone is printed and parsed back, the parsed value
+    /// will use the time zone from the database, which still uses local mean
+    /// time. Parsing such a value back will therefore result in an offset
+    /// conflict for times before the transition out of local mean time.
+    ///
+    /// [local mean time]: https://en.wikipedia.org/wiki/Local_mean_time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::{self, TimeZone}};
+    ///
+    /// let tz = TimeZone::get("America/Los_Angeles")?;
+    /// let dt = date(1850, 1, 1).at(0, 0, 0, 0);
+    /// let zdt = dt.to_zoned(tz.clone())?;
+    /// assert_eq!(zdt.offset().to_string(), "-07:52:58");
+    ///
+    /// let tz = tz.without_lmt();
+    /// let zdt = dt.to_zoned(tz.clone())?;
+    /// assert_eq!(zdt.offset(), tz::offset(-8));
+    /// assert_eq!(zdt.to_string(), "1850-01-01T00:00:00-08:00[America/Los_Angeles]");
+    ///
+    /// // Times after standardization are unaffected.
+    /// let zdt = date(2024, 7, 1).at(0, 0, 0, 0).to_zoned(tz)?;
+    /// assert_eq!(zdt.offset(), tz::offset(-7));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn without_lmt(&self) -> TimeZone {
+        let tzif = repr::each! {
+            &self.repr,
+            UTC => None,
+            UNKNOWN => None,
+            FIXED(_offset) => None,
+            STATIC_TZIF(tzif) => tzif.without_lmt(),
+            ARC_TZIF(tzif) => tzif.without_lmt(),
+            ARC_POSIX(_posix) => None,
+        };
+        match tzif {
+            None => self.clone(),
+            Some(tzif) => TimeZone { repr: Repr::arc_tzif(Arc::new(tzif)) },
+        }
+    }
+
     /// This creates an unnamed TZif-backed `TimeZone`.
     ///
     /// At present, the only way for an unnamed TZif-backed `TimeZone` to be
@@ -603,10 +799,17 @@ impl TimeZone {
     /// backed `TimeZone` values to proliferate. If you have a legitimate use
     /// case otherwise, please file an issue. It will require API design.
     ///
+    /// This is also used when deserializing a `TimeZone` that was serialized
+    /// as TZif data without a name. (Which, typically, is a system time zone
+    /// that was created by this very routine in another process.)
+    ///
     /// # Errors
     ///
     /// This returns an error if the given TZif data is invalid.
-    #[cfg(feature = "tz-system")]
+    #[cfg(any(
+        feature = "tz-system",
+        all(feature = "serde", feature = "alloc")
+    ))]
     pub(crate) fn tzif_system(data: &[u8]) -> Result<TimeZone, Error> {
         let tzif = crate::tz::tzif::Tzif::parse(None, data)?;
         let repr = Repr::arc_tzif(Arc::new(tzif));
@@ -638,6 +841,45 @@ impl TimeZone {
         }
     }
 
+    /// Returns this time zone encoded as TZif data, if and only if it is
+    /// backed by TZif data.
+    ///
+    /// The name of the time zone isn't included in the data returned.
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    pub(crate) fn to_tzif_bytes(&self) -> Option<alloc::vec::Vec<u8>> {
+        repr::each! {
+            &self.repr,
+            UTC => None,
+            UNKNOWN => None,
+            FIXED(_offset) => None,
+            STATIC_TZIF(tzif) => Some(tzif.to_bytes()),
+            ARC_TZIF(tzif) => Some(tzif.to_bytes()),
+            ARC_POSIX(_posix) => None,
+        }
+    }
+
+    /// Returns this time zone as a POSIX time zone string, if possible.
+    ///
+    /// UTC and fixed offset time zones are always representable as POSIX
+    /// time zones. TZif-backed time zones are only representable when they
+    /// have a POSIX time zone in their footer, and in that case, only the
+    /// rule for times after the last transition is returned. The unknown
+    /// time zone is never representable as a POSIX time zone.
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    pub(crate) fn to_posix_string(&self) -> Option<alloc::string::String> {
+        use alloc::string::ToString;
+
+        repr::each! {
+            &self.repr,
+            UTC => Some("UTC0".to_string()),
+            UNKNOWN => None,
+            FIXED(offset) => Some(fixed_offset_to_posix_string(offset)),
+            STATIC_TZIF(tzif) => tzif.posix_tz().map(|tz| tz.to_string()),
+            ARC_TZIF(tzif) => tzif.posix_tz().map(|tz| tz.to_string()),
+            ARC_POSIX(posix) => Some(posix.to_string()),
+        }
+    }
+
     /// When this time zone was loaded from an IANA time zone database entry,
     /// then this returns the canonicalized name for that time zone.
     ///
@@ -695,6 +937,157 @@ impl TimeZone {
         self.repr.is_unknown()
     }
 
+    /// Returns true if and only if this time zone eventually settles on a
+    /// single offset. That is, there is some point in time after which this
+    /// time zone has no more transitions.
+    ///
+    /// This is always true for UTC, unknown and fixed offset time zones. For
+    /// time zones backed by TZif data (like those in the IANA Time Zone
+    /// Database), this is true when the rule describing future transitions
+    /// has no DST, or when it indicates that DST is in effect all year
+    /// (sometimes called "permanent DST"). For POSIX time zones, this is true
+    /// when there are never any transitions.
+    ///
+    /// This is useful for determining when projecting datetimes into the
+    /// future is trivial. When this returns true, every transition is finitely
+    /// enumerable via [`TimeZone::following`], and once that iterator is
+    /// exhausted, the offset never changes again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{tz::{self, TimeZone}, Timestamp};
+    ///
+    /// // New York observes DST, and is expected to continue doing so.
+    /// let tz = jiff::tz::db().get("America/New_York")?;
+    /// assert!(!tz.is_fixed_in_future());
+    ///
+    /// // Sao Paulo used to observe DST, but stopped doing so in 2019.
+    /// let tz = jiff::tz::db().get("America/Sao_Paulo")?;
+    /// assert!(tz.is_fixed_in_future());
+    /// let now: Timestamp = "2025-01-01T00:00Z".parse()?;
+    /// assert!(tz.following(now).next().is_none());
+    ///
+    /// // POSIX time zones with DST in effect all year are fixed too.
+    /// let tz = TimeZone::posix("XXX3EDT4,0/0,J365/23")?;
+    /// assert!(tz.is_fixed_in_future());
+    /// assert_eq!(tz.to_offset(now), tz::offset(-4));
+    ///
+    /// assert!(TimeZone::UTC.is_fixed_in_future());
+    /// assert!(TimeZone::fixed(tz::offset(-5)).is_fixed_in_future());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn is_fixed_in_future(&self) -> bool {
+        repr::each! {
+            &self.repr,
+            UTC => true,
+            UNKNOWN => true,
+            FIXED(_offset) => true,
+            STATIC_TZIF(tzif) => tzif.is_fixed_in_future(),
+            ARC_TZIF(tzif) => tzif.is_fixed_in_future(),
+            ARC_POSIX(posix) => posix.is_fixed(),
+        }
+    }
+
+    /// Returns the expiration time recorded in the TZif data backing this
+    /// time zone, if present.
+    ///
+    /// Version 4 of the TZif format permits the last leap second record to
+    /// indicate the time at which the leap second table expires, instead of
+    /// a leap second. Since the leap second table is published along with
+    /// the rest of the time zone data, this is a rough indication of how far
+    /// into the future the data was vetted when it was generated.
+    ///
+    /// This returns `None` for time zones that aren't backed by TZif data,
+    /// and for TZif data without an expiration time. Most TZif data,
+    /// including the data bundled with Jiff and the data typically found in
+    /// `/usr/share/zoneinfo`, does not have an expiration time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::tz::TimeZone;
+    ///
+    /// assert_eq!(TimeZone::UTC.expiration(), None);
+    ///
+    /// let tz = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0")?;
+    /// assert_eq!(tz.expiration(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn expiration(&self) -> Option<Timestamp> {
+        repr::each! {
+            &self.repr,
+            UTC => None,
+            UNKNOWN => None,
+            FIXED(_offset) => None,
+            STATIC_TZIF(tzif) => tzif.expiration(),
+            ARC_TZIF(tzif) => tzif.expiration(),
+            ARC_POSIX(_posix) => None,
+        }
+    }
+
+    /// Returns the point in time after which the offsets in this time zone
+    /// are projected from a rule instead of taken from recorded data.
+    ///
+    /// TZif data (like the data in the IANA Time Zone Database) records
+    /// transitions up to some point in time, and then includes a POSIX time
+    /// zone rule describing how transitions repeat after that. For example,
+    /// `America/New_York` records its transitions through 2007, after which
+    /// DST starts on the second Sunday in March and ends on the first Sunday
+    /// in November. Offsets after the timestamp returned are computed by
+    /// extrapolating that rule indefinitely. If the rule changes, then any
+    /// datetimes computed from it will be wrong.
+    ///
+    /// The timestamp returned is the later of the last transition recorded
+    /// in the TZif data and its [expiration time](TimeZone::expiration), if
+    /// present. Note that "fat" TZif data (e.g., generated by `zic -b fat`)
+    /// records transitions generated from the rule up to 2037, which are
+    /// indistinguishable from other transitions.
+    ///
+    /// This returns `None` when no rule is ever extrapolated. That is, for
+    /// UTC, unknown and fixed offset time zones, and for any time zone for
+    /// which [`TimeZone::is_fixed_in_future`] returns true. This also returns
+    /// `None` for POSIX time zones, since they are defined entirely by their
+    /// rule.
+    ///
+    /// # Example
+    ///
+    /// This shows how to warn when scheduling an event that relies on an
+    /// extrapolated rule:
+    ///
+    /// ```
+    /// use jiff::{civil::date, Timestamp};
+    ///
+    /// let tz = jiff::tz::db().get("America/New_York")?;
+    /// let until = tz.projection_reliable_until().unwrap();
+    /// assert!(until >= "2007-11-04T06:00:00Z".parse::<Timestamp>()?);
+    ///
+    /// let event = date(2070, 7, 4).at(12, 0, 0, 0).to_zoned(tz.clone())?;
+    /// assert!(event.timestamp() > until);
+    ///
+    /// // Time zones that no longer observe DST don't extrapolate anything.
+    /// let tz = jiff::tz::db().get("America/Sao_Paulo")?;
+    /// assert_eq!(tz.projection_reliable_until(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn projection_reliable_until(&self) -> Option<Timestamp> {
+        repr::each! {
+            &self.repr,
+            UTC => None,
+            UNKNOWN => None,
+            FIXED(_offset) => None,
+            STATIC_TZIF(tzif) => tzif.projection_reliable_until(),
+            ARC_TZIF(tzif) => tzif.projection_reliable_until(),
+            ARC_POSIX(_posix) => None,
+        }
+    }
+
     /// When this time zone is a POSIX time zone, return it.
     ///
     /// This doesn't attempt to convert other time zones that are representable
@@ -1344,6 +1737,92 @@ impl TimeZone {
         TimeZoneFollowingTransitions { tz: self, cur: timestamp }
     }
 
+    /// Returns a key identifying the window between time zone transitions
+    /// that contains the given timestamp.
+    ///
+    /// The key returned is the same for all timestamps between two adjacent
+    /// transitions in this time zone, and it changes precisely at each
+    /// transition. A transition occurring at exactly `timestamp` is
+    /// considered to be in effect at `timestamp`. This makes the key useful
+    /// for caching values that depend only on the rules currently in effect
+    /// for a time zone (like its offset or abbreviation), such that the
+    /// cache is invalidated exactly when those rules change.
+    ///
+    /// Keys are ordered: a later transition window always has a greater key
+    /// than an earlier one. For time zones without any transitions, like
+    /// fixed offset time zones, the key is always `0`.
+    ///
+    /// Keys are only meaningful when compared with other keys from the same
+    /// time zone. If a cache is shared across time zones, then the time zone
+    /// itself (or its name) should be included in the cache key as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    ///
+    /// let tz = TimeZone::get("America/New_York")?;
+    /// let key = |hour| {
+    ///     let zdt = date(2024, 3, 10).at(hour, 0, 0, 0).to_zoned(tz.clone());
+    ///     tz.offset_era_key(zdt.unwrap().timestamp())
+    /// };
+    /// // 00:00 and 01:00 are both before the transition to DST...
+    /// assert_eq!(key(0), key(1));
+    /// // ... while 03:00 is precisely at the transition.
+    /// assert!(key(1) < key(3));
+    /// assert_eq!(key(3), key(23));
+    ///
+    /// // Time zones without transitions always return the same key.
+    /// let tz = TimeZone::fixed(jiff::tz::offset(-5));
+    /// assert_eq!(tz.offset_era_key(jiff::Timestamp::MIN), 0);
+    /// assert_eq!(tz.offset_era_key(jiff::Timestamp::MAX), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn offset_era_key(&self, timestamp: Timestamp) -> u64 {
+        // `previous_transition` only returns transitions strictly before
+        // the timestamp given, but a transition at `timestamp` is in effect
+        // at `timestamp`. Transitions always occur on a whole second, so
+        // when this saturates at `Timestamp::MAX`, nothing is missed.
+        let cur = timestamp
+            .checked_add(SignedDuration::from_nanos(1))
+            .unwrap_or(timestamp);
+        let Some(trans) = self.previous_transition(cur) else { return 0 };
+        // Adding 1 can't overflow since Jiff's range of seconds is much
+        // smaller than `u64::MAX`.
+        trans.timestamp().as_second().abs_diff(Timestamp::MIN.as_second()) + 1
+    }
+
+    /// Returns the standard (non-DST) offset of this time zone at the given
+    /// timestamp.
+    ///
+    /// When DST is not in effect at `timestamp`, this is just the offset in
+    /// effect. Otherwise, this is the offset of the nearest period of standard
+    /// time, searching backwards first. If the time zone has never
+    /// been and never will be in standard time, then the offset in effect is
+    /// returned.
+    pub(crate) fn to_standard_offset(&self, timestamp: Timestamp) -> Offset {
+        let info = self.to_offset_info(timestamp);
+        if !info.dst().is_dst() {
+            return info.offset();
+        }
+        if let Some(trans) =
+            self.preceding(timestamp).find(|trans| !trans.dst().is_dst())
+        {
+            return trans.offset();
+        }
+        // The offset in effect before the first transition isn't itself
+        // reported as a transition.
+        let first = self.to_offset_info(Timestamp::MIN);
+        if !first.dst().is_dst() {
+            return first.offset();
+        }
+        self.following(timestamp)
+            .find(|trans| !trans.dst().is_dst())
+            .map_or(info.offset(), |trans| trans.offset())
+    }
+
     /// Used by the "preceding transitions" iterator.
     #[inline]
     fn previous_transition(
@@ -1425,6 +1904,25 @@ impl TimeZone {
     }
 }
 
+/// Returns a POSIX time zone string for a fixed offset.
+///
+/// The abbreviation used is the offset itself, without any `:` separators
+/// since they aren't allowed in POSIX time zone abbreviations. For example,
+/// `+05:30` becomes `<+0530>-5:30`.
+#[cfg(all(feature = "serde", feature = "alloc"))]
+fn fixed_offset_to_posix_string(offset: Offset) -> alloc::string::String {
+    use alloc::string::{String, ToString};
+
+    let abbrev: String =
+        offset.to_array_str().as_str().chars().filter(|&c| c != ':').collect();
+    let posix = crate::shared::PosixTimeZone {
+        std_abbrev: abbrev,
+        std_offset: crate::shared::PosixOffset { second: offset.seconds() },
+        dst: None,
+    };
+    posix.to_string()
+}
+
 impl core::fmt::Debug for TimeZone {
     #[inline]
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -3599,6 +4097,26 @@ mod tests {
                 assert_eq!(got, expected, "\nTZ: {tzname}\ngiven: {given}");
             }
         }
+
+        // TZif data without a POSIX TZ has no transitions after its last one.
+        let rules = crate::tz::TimeZoneRules::new(offset(2), "XST")
+            .transition(
+                "2000-01-01T00Z".parse().unwrap(),
+                offset(3),
+                Dst::Yes,
+                "XDT",
+            );
+        let tz = TimeZone::from_transitions("Test/NoPosix", &rules).unwrap();
+        for (given, expected) in [
+            ("1999-12-31T23:59:59.999999999Z", Some("2000-01-01T00Z")),
+            ("2000-01-01T00Z", None),
+            ("2024-01-01T00Z", None),
+        ] {
+            let given: Timestamp = given.parse().unwrap();
+            let expected = expected.map(|s| s.parse::<Timestamp>().unwrap());
+            let got = tz.next_transition(given).map(|t| t.timestamp());
+            assert_eq!(got, expected, "\ngiven: {given}");
+        }
     }
 
     #[cfg(feature = "alloc")]
@@ -3737,6 +4255,30 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_zone_offset_era_key() {
+        let tz = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        let key = |ts: &str| tz.offset_era_key(ts.parse().unwrap());
+
+        let before = key("2024-03-10 06:59:59.999999999Z");
+        assert_eq!(before, key("2023-11-05 06Z"));
+        assert!(before < key("2024-03-10 07Z"));
+        assert_eq!(key("2024-03-10 07Z"), key("2024-11-03 05:59:59Z"));
+        assert!(key("2024-03-10 07Z") < key("2024-11-03 06Z"));
+
+        // There's no transition before the first one in Jiff's range.
+        assert_eq!(tz.offset_era_key(Timestamp::MIN), 0);
+        assert_eq!(key("-009999-01-31 00Z"), 0);
+        assert_ne!(key("-009998-01-01 00Z"), 0);
+        assert_eq!(tz.offset_era_key(Timestamp::MAX), key("9999-11-07 06Z"));
+
+        let tz = TimeZone::posix("EST5").unwrap();
+        assert_eq!(tz.offset_era_key(Timestamp::MIN), 0);
+        assert_eq!(tz.offset_era_key(Timestamp::MAX), 0);
+        assert_eq!(TimeZone::UTC.offset_era_key(Timestamp::UNIX_EPOCH), 0);
+    }
+
     /// This tests that the size of a time zone is kept at a single word.
     ///
     /// This is important because every jiff::Zoned has a TimeZone inside of
@@ -3825,4 +4367,113 @@ mod tests {
             assert!(tz.to_fixed_offset().is_err());
         }
     }
+
+    #[test]
+    fn time_zone_is_fixed_in_future() {
+        assert!(TimeZone::UTC.is_fixed_in_future());
+        assert!(TimeZone::unknown().is_fixed_in_future());
+        assert!(TimeZone::fixed(offset(-5)).is_fixed_in_future());
+
+        #[cfg(feature = "alloc")]
+        {
+            for (name, expected) in [
+                ("America/New_York", false),
+                ("Australia/Tasmania", false),
+                ("America/Sao_Paulo", true),
+                ("Pacific/Honolulu", true),
+                ("UTC", true),
+            ] {
+                let test_file = TzifTestFile::get(name);
+                let tz = TimeZone::tzif(name, test_file.data).unwrap();
+                assert_eq!(tz.is_fixed_in_future(), expected, "{name}");
+            }
+
+            let tz = TimeZone::posix("EST5").unwrap();
+            assert!(tz.is_fixed_in_future());
+            let tz = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+            assert!(!tz.is_fixed_in_future());
+
+            // DST in effect all year, whether DST is ahead of or behind
+            // standard time.
+            for (posix, dst) in
+                [("XXX3EDT4,0/0,J365/23", -4), ("EST5EDT,0/0,J365/25", -4)]
+            {
+                let tz = TimeZone::posix(posix).unwrap();
+                assert!(tz.is_fixed_in_future(), "{posix}");
+                let ts = Timestamp::from_second(1_735_689_600).unwrap();
+                assert!(tz.following(ts).next().is_none(), "{posix}");
+                assert!(tz.preceding(ts).next().is_none(), "{posix}");
+                let info = tz.to_offset_info(ts);
+                assert_eq!(info.offset(), offset(dst), "{posix}");
+                assert_eq!(info.dst(), Dst::Yes, "{posix}");
+                let dt = DateTime::constant(2024, 12, 31, 23, 30, 0, 0);
+                assert_eq!(
+                    tz.to_ambiguous_timestamp(dt).offset(),
+                    AmbiguousOffset::Unambiguous { offset: offset(dst) },
+                    "{posix}",
+                );
+            }
+        }
+    }
+
+    /// This tests that removing LMT from a time zone only changes the times
+    /// before the first transition.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_zone_without_lmt() {
+        let test_file = TzifTestFile::get("America/New_York");
+        let tz = TimeZone::tzif(test_file.name, test_file.data).unwrap();
+        let clamped = tz.without_lmt();
+        assert_ne!(tz, clamped);
+        assert_eq!(clamped.iana_name(), Some("America/New_York"));
+
+        let before = Timestamp::from_second(-3_000_000_000).unwrap();
+        let info = tz.to_offset_info(before);
+        assert_eq!(info.offset(), Offset::from_seconds(-17762).unwrap());
+        assert_eq!(info.abbreviation(), "LMT");
+        let info = clamped.to_offset_info(before);
+        assert_eq!(info.offset(), offset(-5));
+        assert_eq!(info.dst(), Dst::No);
+        assert_eq!(info.abbreviation(), "EST");
+
+        // The transition out of LMT is gone, so the first transition is
+        // now the first transition into DST.
+        let first = tz.following(Timestamp::MIN).next().unwrap();
+        assert_eq!(first.abbreviation(), "EST");
+        let first = clamped.following(Timestamp::MIN).next().unwrap();
+        assert_eq!(first.abbreviation(), "EDT");
+        assert_eq!(first.timestamp().as_second(), -1_633_280_400);
+        assert_eq!(
+            clamped.preceding(first.timestamp()).next().map(|t| t.timestamp()),
+            None,
+        );
+
+        // Civil time lookups before the first transition are unambiguous
+        // and use the standard offset.
+        let dt = DateTime::constant(1850, 1, 1, 0, 0, 0, 0);
+        assert_eq!(
+            clamped.to_ambiguous_timestamp(dt).offset(),
+            AmbiguousOffset::Unambiguous { offset: offset(-5) },
+        );
+
+        // Everything after standardization is unchanged.
+        for second in [-2_717_650_800, 0, 1_720_493_204, 4_102_444_800] {
+            let ts = Timestamp::from_second(second).unwrap();
+            assert_eq!(tz.to_offset(ts), clamped.to_offset(ts));
+        }
+        let dt = DateTime::constant(2024, 11, 3, 1, 30, 0, 0);
+        assert_eq!(
+            tz.to_ambiguous_timestamp(dt).offset(),
+            clamped.to_ambiguous_timestamp(dt).offset(),
+        );
+
+        // Time zones that don't start with LMT are returned as-is.
+        let test_file = TzifTestFile::get("Antarctica/Troll");
+        let tz = TimeZone::tzif(test_file.name, test_file.data).unwrap();
+        assert_eq!(tz, tz.without_lmt());
+        let tz = TimeZone::fixed(offset(-5));
+        assert_eq!(tz, tz.without_lmt());
+        let tz = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        assert_eq!(tz, tz.without_lmt());
+    }
 }
@@ -105,6 +105,8 @@ impl TzifStatic {
     ///             checksum: 0xDEADBEEF,
     ///             designations: "ESTEDT",
     ///             posix_tz: None,
+    ///             last_recorded_transition: Some(123456789),
+    ///             expiration: None,
     ///         },
     ///         &[
     ///             shared::TzifLocalTimeType {
@@ -472,7 +474,13 @@ impl<
                 // future time zone transitions.
                 return posix_tz.next_transition(ts);
             }
-            self.timestamps().len() - 1
+            // Without a POSIX TZ, there are no transitions after the last
+            // one. So if `ts` is at or after the last transition, then there
+            // is no next transition.
+            if index >= self.timestamps().len() {
+                return None;
+            }
+            index
         } else {
             index
         };
@@ -486,6 +494,200 @@ impl<
         })
     }
 
+    /// Returns true if and only if there are no time zone transitions after
+    /// the last transition in this TZif data.
+    ///
+    /// This is true when there is no POSIX time zone, or when the POSIX time
+    /// zone has no transitions.
+    pub(crate) fn is_fixed_in_future(&self) -> bool {
+        self.posix_tz().map_or(true, |tz| tz.is_fixed())
+    }
+
+    /// Returns the expiration time of the leap second table in this TZif
+    /// data, if present.
+    pub(crate) fn expiration(&self) -> Option<Timestamp> {
+        let second = self.inner.fixed.expiration?;
+        // OK because we only record an expiration when it's in range.
+        Some(Timestamp::constant(second, 0))
+    }
+
+    /// Returns the point in time after which offsets are computed by
+    /// extrapolating the POSIX time zone beyond the data in this TZif.
+    ///
+    /// This is `None` when the time zone is fixed in the future, since
+    /// nothing is extrapolated in that case.
+    pub(crate) fn projection_reliable_until(&self) -> Option<Timestamp> {
+        if self.is_fixed_in_future() {
+            return None;
+        }
+        let last = self
+            .inner
+            .fixed
+            .last_recorded_transition
+            .map(|second| Timestamp::constant(second, 0));
+        Some(match (last, self.expiration()) {
+            (None, None) => Timestamp::MIN,
+            (Some(ts), None) | (None, Some(ts)) => ts,
+            (Some(last), Some(expiration)) => last.max(expiration),
+        })
+    }
+
+    /// Returns a copy of this TZif data where the local mean time (LMT) in
+    /// effect before the first transition is replaced with the first
+    /// standard offset used by this time zone.
+    ///
+    /// If this TZif data doesn't begin with LMT, or if it never transitions
+    /// to standard time, then this returns `None`.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn without_lmt(&self) -> Option<TzifOwned> {
+        // The first transition is always our dummy transition at
+        // `Timestamp::MIN`, so the local time type at index `0` is what
+        // applies to all times before the first "real" transition.
+        if self.designation(self.local_time_type(0)) != "LMT" {
+            return None;
+        }
+        let std_index = (1..self.timestamps().len())
+            .find(|&i| !self.local_time_type(i).is_dst)?;
+
+        let types = self.types().to_vec();
+        let mut timestamps = self.timestamps().to_vec();
+        let mut civil_starts = self.civil_starts().to_vec();
+        let mut civil_ends = self.civil_ends().to_vec();
+        let mut infos = self.infos().to_vec();
+        infos[0].type_index = infos[std_index].type_index;
+        let offset = |i: usize| types[usize::from(infos[i].type_index)].offset;
+        let mut last_recorded_transition =
+            self.inner.fixed.last_recorded_transition;
+        if offset(0) == offset(1) {
+            // The transition out of LMT no longer changes anything, so it
+            // is dropped entirely. Every subsequent transition is unaffected
+            // since its previous offset stays the same.
+            if last_recorded_transition == Some(timestamps[1]) {
+                last_recorded_transition = None;
+            }
+            timestamps.remove(1);
+            civil_starts.remove(1);
+            civil_ends.remove(1);
+            infos.remove(1);
+        } else {
+            // This only happens when the first transition out of LMT is
+            // into DST. In that case, the transition is kept, but the
+            // boundaries of its gap or fold need to be recomputed relative
+            // to the new offset preceding it.
+            let (prev, cur) = (offset(0), offset(1));
+            let ts = Timestamp::constant(timestamps[1], 0);
+            let civil = |off: i32| {
+                let dt = Offset::from_seconds_unchecked(off).to_datetime(ts);
+                shared::TzifDateTime::new(
+                    dt.year(),
+                    dt.month(),
+                    dt.day(),
+                    dt.hour(),
+                    dt.minute(),
+                    dt.second(),
+                )
+            };
+            let (kind, start, end) = if prev < cur {
+                (shared::TzifTransitionKind::Gap, civil(prev), civil(cur))
+            } else {
+                (shared::TzifTransitionKind::Fold, civil(cur), civil(prev))
+            };
+            infos[1].kind = kind;
+            civil_starts[1] = start;
+            civil_ends[1] = end;
+        }
+
+        let fixed = &self.inner.fixed;
+        let sh = shared::TzifOwned {
+            fixed: shared::TzifFixed {
+                name: fixed.name.as_ref().map(|n| String::from(n.as_ref())),
+                version: fixed.version,
+                // The checksum is what distinguishes two TZif time zones
+                // with the same name when comparing them for equality. Since
+                // the rules here are now different, we make sure the
+                // checksum is too.
+                checksum: !fixed.checksum,
+                designations: String::from(self.designations()),
+                posix_tz: fixed.posix_tz.as_ref().map(posix_to_owned),
+                last_recorded_transition,
+                expiration: fixed.expiration,
+            },
+            types,
+            transitions: shared::TzifTransitions {
+                timestamps,
+                civil_starts,
+                civil_ends,
+                infos,
+            },
+        };
+        Some(TzifOwned::from_shared_owned(sh))
+    }
+
+    /// Encodes this TZif data as a version 2 TZif binary file.
+    ///
+    /// The data written is "slim." That is, the version 1 data block is
+    /// empty, and only the transitions recorded in the original TZif data
+    /// are written to the version 2 data block. Any transitions that were
+    /// generated from the POSIX time zone when parsing are generated again
+    /// when parsing the bytes returned. The name of the time zone is not
+    /// included, since TZif has no place for it.
+    ///
+    /// When this TZif data has an expiration time, then a version 4 TZif
+    /// binary file is written instead, with a single leap second record
+    /// indicating the expiration time.
+    ///
+    /// Parsing the bytes returned always produces TZif data with the same
+    /// transitions, local time types, POSIX time zone and expiration as this
+    /// one.
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        // The first transition is always our dummy transition at the
+        // minimum timestamp, which isn't part of the TZif data. Its local
+        // time type is what applies before the first real transition, and
+        // TZif requires that to be local time type `0`. This is usually
+        // already the case, but isn't for data returned by `without_lmt`.
+        // So we reorder the local time types to make it so.
+        let first = usize::from(self.infos()[0].type_index);
+        let to_new = |old: usize| -> u8 {
+            let new = if old == first {
+                0
+            } else if old < first {
+                old + 1
+            } else {
+                old
+            };
+            // OK because there are never more than 256 local time types.
+            u8::try_from(new).unwrap()
+        };
+        let mut types: Vec<shared::TzifLocalTimeType> = self.types().to_vec();
+        let typ = types.remove(first);
+        types.insert(0, typ);
+
+        let recorded = match self.inner.fixed.last_recorded_transition {
+            None => 0,
+            Some(last) => {
+                self.timestamps()[1..].partition_point(|&ts| ts <= last)
+            }
+        };
+        let timestamps = &self.timestamps()[1..][..recorded];
+        let type_indices: Vec<u8> = self.infos()[1..][..recorded]
+            .iter()
+            .map(|info| to_new(usize::from(info.type_index)))
+            .collect();
+        let posix_tz = self.posix_tz().map(|posix_tz| {
+            use alloc::string::ToString;
+            posix_tz.to_string()
+        });
+        encode(
+            &types,
+            timestamps,
+            &type_indices,
+            self.designations(),
+            posix_tz.as_deref(),
+            self.inner.fixed.expiration,
+        )
+    }
+
     fn designation(&self, typ: &shared::TzifLocalTimeType) -> &str {
         // OK because we verify that the designation range on every local
         // time type is a valid range into `self.designations`.
@@ -508,7 +710,7 @@ impl<
         self.infos()[transition_index].kind
     }
 
-    fn posix_tz(&self) -> Option<&PosixTimeZone<ABBREV>> {
+    pub(crate) fn posix_tz(&self) -> Option<&PosixTimeZone<ABBREV>> {
         self.posix_tz.as_ref()
     }
 
@@ -552,6 +754,123 @@ impl<STR: AsRef<str>, ABBREV, TYPES, TIMESTAMPS, STARTS, ENDS, INFOS> PartialEq
     }
 }
 
+/// Encodes the given pieces as a version 2 TZif binary file.
+///
+/// The data written is "slim." That is, the version 1 data block is empty,
+/// and only the transitions given are written to the version 2 data block.
+/// Local time type `0` is the one in effect before the first transition.
+/// Each type index must refer to one of the `types` given, and the
+/// designation ranges of each type must point into `designations`.
+///
+/// When an expiration time is given, then a version 4 TZif binary file is
+/// written instead, with a single leap second record indicating the
+/// expiration time.
+#[cfg(feature = "alloc")]
+pub(crate) fn encode(
+    types: &[shared::TzifLocalTimeType],
+    timestamps: &[i64],
+    type_indices: &[u8],
+    designations: &str,
+    posix_tz: Option<&str>,
+    expiration: Option<i64>,
+) -> Vec<u8> {
+    /// Writes a TZif header. The counts are, in order, `isutcnt`,
+    /// `isstdcnt`, `leapcnt`, `timecnt`, `typecnt` and `charcnt`.
+    fn header(out: &mut Vec<u8>, version: u8, counts: [usize; 6]) {
+        out.extend_from_slice(b"TZif");
+        out.push(version);
+        out.extend_from_slice(&[0; 15]);
+        for n in counts {
+            // OK because all of these counts are bounded by the limits
+            // enforced when parsing TZif data (or by the number of
+            // transitions generated from a POSIX time zone).
+            out.extend_from_slice(&u32::try_from(n).unwrap().to_be_bytes());
+        }
+    }
+
+    assert_eq!(timestamps.len(), type_indices.len());
+    // We never write leap seconds, but we do write a single leap second
+    // record indicating the expiration time, if present. This requires
+    // version 4.
+    let (version, leapcnt) =
+        if expiration.is_some() { (b'4', 1) } else { (b'2', 0) };
+
+    let mut out = Vec::new();
+    // A "slim" version 1 data block with a single local time type
+    // corresponding to UTC and no transitions.
+    header(&mut out, version, [0, 0, 0, 0, 1, 1]);
+    out.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+    out.push(0);
+
+    let designations = designations.as_bytes();
+    header(
+        &mut out,
+        version,
+        [
+            types.len(),
+            types.len(),
+            leapcnt,
+            timestamps.len(),
+            types.len(),
+            designations.len(),
+        ],
+    );
+    for &timestamp in timestamps {
+        out.extend_from_slice(&timestamp.to_be_bytes());
+    }
+    out.extend_from_slice(type_indices);
+    for typ in types.iter() {
+        out.extend_from_slice(&typ.offset.to_be_bytes());
+        out.push(u8::from(typ.is_dst));
+        out.push(typ.designation.0);
+    }
+    out.extend_from_slice(designations);
+    if let Some(expiration) = expiration {
+        // A correction of `0` is the same as the (non-existent)
+        // previous record, which marks this as the expiration time.
+        out.extend_from_slice(&expiration.to_be_bytes());
+        out.extend_from_slice(&0i32.to_be_bytes());
+    }
+    for typ in types.iter() {
+        out.push(u8::from(!matches!(
+            typ.indicator,
+            shared::TzifIndicator::LocalWall
+        )));
+    }
+    for typ in types.iter() {
+        out.push(u8::from(matches!(
+            typ.indicator,
+            shared::TzifIndicator::UTStandard
+        )));
+    }
+    out.push(b'\n');
+    if let Some(posix_tz) = posix_tz {
+        out.extend_from_slice(posix_tz.as_bytes());
+    }
+    out.push(b'\n');
+    out
+}
+
+/// Converts a POSIX time zone with any kind of abbreviation storage into one
+/// that stores its abbreviations inline.
+#[cfg(feature = "alloc")]
+fn posix_to_owned<ABBREV: AsRef<str>>(
+    posix: &shared::PosixTimeZone<ABBREV>,
+) -> shared::PosixTimeZone<Abbreviation> {
+    // OK because every abbreviation was originally parsed into (or
+    // generated from) an `Abbreviation`, so it is guaranteed to fit.
+    let abbrev = |a: &ABBREV| Abbreviation::new(a.as_ref()).unwrap();
+    shared::PosixTimeZone {
+        std_abbrev: abbrev(&posix.std_abbrev),
+        std_offset: posix.std_offset,
+        dst: posix.dst.as_ref().map(|dst| shared::PosixDst {
+            abbrev: abbrev(&dst.abbrev),
+            offset: dst.offset,
+            rule: dst.rule,
+        }),
+    }
+}
+
 impl shared::TzifLocalTimeType {
     fn designation(&self) -> Range<usize> {
         usize::from(self.designation.0)..usize::from(self.designation.1)
@@ -676,6 +995,14 @@ mod tests {
                 .unwrap();
             }
         }
+        if let Some(last) = tzif.inner.fixed.last_recorded_transition {
+            writeln!(out, "LAST RECORDED TRANSITION").unwrap();
+            writeln!(out, "  unix={last}").unwrap();
+        }
+        if let Some(expiration) = tzif.expiration() {
+            writeln!(out, "EXPIRATION").unwrap();
+            writeln!(out, "  {expiration}").unwrap();
+        }
         if let Some(ref posix_tz) = tzif.posix_tz {
             writeln!(out, "POSIX TIME ZONE STRING").unwrap();
             writeln!(out, "  {}", posix_tz).unwrap();
@@ -730,6 +1057,92 @@ mod tests {
         }
     }
 
+    /// Builds version 2+ TZif data with no transitions, a single local time
+    /// type and the given leap second records and footer.
+    fn tzif_with_leap_seconds(
+        version: u8,
+        leaps: &[(i64, i32)],
+        footer: &str,
+    ) -> Vec<u8> {
+        let mut out = Vec::new();
+        for (time_size, leapcnt) in [(4, 0), (8, leaps.len())] {
+            out.extend_from_slice(b"TZif");
+            out.push(version);
+            out.extend_from_slice(&[0; 15]);
+            let leapcnt = u32::try_from(leapcnt).unwrap();
+            for count in [0, 0, leapcnt, 0, 1, 4] {
+                out.extend_from_slice(&u32::to_be_bytes(count));
+            }
+            out.extend_from_slice(&(-5 * 60 * 60i32).to_be_bytes());
+            out.extend_from_slice(&[0, 0]);
+            out.extend_from_slice(b"EST\0");
+            if time_size == 8 {
+                for &(occur, corr) in leaps {
+                    out.extend_from_slice(&occur.to_be_bytes());
+                    out.extend_from_slice(&corr.to_be_bytes());
+                }
+            }
+        }
+        out.push(b'\n');
+        out.extend_from_slice(footer.as_bytes());
+        out.push(b'\n');
+        out
+    }
+
+    #[test]
+    fn expiration() {
+        let parse = |version, leaps: &[(i64, i32)], footer| {
+            let data = tzif_with_leap_seconds(version, leaps, footer);
+            Tzif::parse(Some("Test".to_string()), &data).unwrap()
+        };
+        let dst = "EST5EDT,M3.2.0,M11.1.0";
+
+        // A single record with a correction of `0` is an expiration.
+        let tzif = parse(b'4', &[(1_750_000_000, 0)], dst);
+        insta::assert_snapshot!(
+            tzif.expiration().unwrap(),
+            @"2025-06-15T15:06:40Z",
+        );
+        insta::assert_snapshot!(
+            tzif.projection_reliable_until().unwrap(),
+            @"2025-06-15T15:06:40Z",
+        );
+        // Likewise for a last record whose correction is unchanged.
+        let tzif = parse(b'4', &[(78_796_800, 1), (1_750_000_000, 1)], dst);
+        assert_eq!(tzif.expiration().unwrap().as_second(), 1_750_000_000);
+        // But not when the correction changes, since that's a leap second.
+        let tzif = parse(b'4', &[(78_796_800, 1), (94_694_401, 2)], dst);
+        assert_eq!(tzif.expiration(), None);
+        assert_eq!(tzif.projection_reliable_until(), Some(Timestamp::MIN));
+        // And expiration times only exist in version 4 or later.
+        let tzif = parse(b'3', &[(1_750_000_000, 0)], dst);
+        assert_eq!(tzif.expiration(), None);
+
+        // Time zones without DST never extrapolate anything.
+        let tzif = parse(b'4', &[(1_750_000_000, 0)], "EST5");
+        assert_eq!(tzif.expiration().unwrap().as_second(), 1_750_000_000);
+        assert_eq!(tzif.projection_reliable_until(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn expiration_roundtrip() {
+        let data = tzif_with_leap_seconds(
+            b'4',
+            &[(1_750_000_000, 0)],
+            "EST5EDT,M3.2.0,M11.1.0",
+        );
+        let tzif = Tzif::parse(Some("Test".to_string()), &data).unwrap();
+        let got =
+            Tzif::parse(Some("Test".to_string()), &tzif.to_bytes()).unwrap();
+        assert_eq!(got.expiration(), tzif.expiration());
+        assert_eq!(
+            got.projection_reliable_until(),
+            tzif.projection_reliable_until(),
+        );
+        assert_eq!(got.timestamps(), tzif.timestamps());
+    }
+
     /// This tests walks the /usr/share/zoneinfo directory (if it exists) and
     /// tries to parse every TZif formatted file it can find. We don't really
     /// do much with it other than to ensure we don't panic or return an error.
@@ -0,0 +1,366 @@
+use crate::{
+    error::{err, Error},
+    fmt::friendly::{Designator, FractionalUnit, SpanPrinter},
+    SignedDuration, Timestamp,
+};
+
+/// The anchor used by [`Uptime::process`].
+///
+/// This is initialized either by the first call to `Uptime::process`, or by
+/// an explicit call to [`Uptime::set_process_anchor`].
+static PROCESS_ANCHOR: std::sync::OnceLock<Timestamp> =
+    std::sync::OnceLock::new();
+
+/// A helper for reporting how much time has elapsed since some anchor point.
+///
+/// An `Uptime` is just a [`Timestamp`] (called the "anchor") along with
+/// some convenience routines for computing the [`SignedDuration`] that has
+/// elapsed since that anchor and for formatting it in a way that is suitable
+/// for humans. This is commonly needed in health or status endpoints.
+///
+/// Since the anchor is a `Timestamp`, elapsed time is computed using the
+/// system clock. This means that elapsed time can jump forwards or backwards
+/// if the system clock is adjusted, and can even be negative. The benefit is
+/// that an anchor can be persisted and restored across process restarts.
+/// With the `serde` crate feature enabled, an `Uptime` (de)serializes as its
+/// anchor timestamp.
+///
+/// This type is only available when the `std` crate feature is enabled.
+///
+/// # Example
+///
+/// This shows how to get the uptime of the current process:
+///
+/// ```
+/// use jiff::{SignedDuration, Uptime};
+///
+/// let uptime = Uptime::process();
+/// assert!(uptime.elapsed() >= SignedDuration::ZERO);
+/// // The anchor is fixed at the first call.
+/// assert_eq!(Uptime::process().anchor(), uptime.anchor());
+/// ```
+///
+/// # Example: formatting uptime
+///
+/// ```
+/// use jiff::{Timestamp, Uptime};
+///
+/// let anchor: Timestamp = "2025-01-01T00:00:00Z".parse()?;
+/// let now: Timestamp = "2025-01-03T04:05:06.789Z".parse()?;
+/// let uptime = Uptime::from_anchor(anchor);
+/// assert_eq!(uptime.display_at(now).to_string(), "2d 4h 5m 6s");
+/// // The precision setting controls how many fractional digits
+/// // of seconds are shown.
+/// assert_eq!(format!("{:.3}", uptime.display_at(now)), "2d 4h 5m 6.789s");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Uptime {
+    anchor: Timestamp,
+}
+
+impl Uptime {
+    /// Returns an `Uptime` anchored at the current time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{Timestamp, Uptime};
+    ///
+    /// let before = Timestamp::now();
+    /// let uptime = Uptime::now();
+    /// assert!(uptime.anchor() >= before);
+    /// ```
+    #[inline]
+    pub fn now() -> Uptime {
+        Uptime::from_anchor(Timestamp::now())
+    }
+
+    /// Returns an `Uptime` anchored at the given timestamp.
+    ///
+    /// This is useful for restoring an anchor that was previously persisted,
+    /// or for anchoring at a time that was recorded by some other means.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{SignedDuration, Timestamp, Uptime};
+    ///
+    /// let uptime = Uptime::from_anchor(Timestamp::from_second(1_000)?);
+    /// let now = Timestamp::from_second(1_090)?;
+    /// assert_eq!(uptime.elapsed_at(now), SignedDuration::from_secs(90));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn from_anchor(anchor: Timestamp) -> Uptime {
+        Uptime { anchor }
+    }
+
+    /// Returns the `Uptime` of the current process.
+    ///
+    /// The anchor is set to the current time on the first call to this
+    /// routine, unless it was explicitly set beforehand via
+    /// [`Uptime::set_process_anchor`]. All subsequent calls return an
+    /// `Uptime` with the same anchor.
+    ///
+    /// Note that the anchor is set on first use, and not when the process
+    /// actually started. To get a more accurate process uptime, call this
+    /// routine early in `main`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Uptime;
+    ///
+    /// let uptime1 = Uptime::process();
+    /// let uptime2 = Uptime::process();
+    /// assert_eq!(uptime1, uptime2);
+    /// ```
+    #[inline]
+    pub fn process() -> Uptime {
+        Uptime::from_anchor(*PROCESS_ANCHOR.get_or_init(Timestamp::now))
+    }
+
+    /// Explicitly sets the anchor used by [`Uptime::process`].
+    ///
+    /// This is useful when the process anchor should survive restarts. For
+    /// example, a service might persist its anchor and restore it on start
+    /// up so that the uptime it reports covers the lifetime of the service
+    /// instead of only the current process.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the process anchor has already been set,
+    /// either by a previous call to this routine or by a call to
+    /// `Uptime::process`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{Timestamp, Uptime};
+    ///
+    /// let anchor: Timestamp = "2025-01-01T00:00:00Z".parse()?;
+    /// Uptime::set_process_anchor(anchor)?;
+    /// assert_eq!(Uptime::process().anchor(), anchor);
+    ///
+    /// // The anchor can only be set once.
+    /// assert!(Uptime::set_process_anchor(Timestamp::now()).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_process_anchor(anchor: Timestamp) -> Result<(), Error> {
+        PROCESS_ANCHOR.set(anchor).map_err(|_| {
+            err!(
+                "failed to set process uptime anchor to {anchor} \
+                 because it was already set to {existing}",
+                // OK because `set` only fails when the anchor is set.
+                existing = PROCESS_ANCHOR.get().unwrap(),
+            )
+        })
+    }
+
+    /// Returns the anchor of this `Uptime`.
+    #[inline]
+    pub fn anchor(self) -> Timestamp {
+        self.anchor
+    }
+
+    /// Returns the duration that has elapsed since the anchor of this
+    /// `Uptime`, according to the system clock.
+    ///
+    /// The duration returned is negative when the anchor is in the future.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{SignedDuration, Timestamp, Uptime};
+    ///
+    /// let uptime = Uptime::from_anchor(Timestamp::UNIX_EPOCH);
+    /// assert!(uptime.elapsed() > SignedDuration::from_hours(24 * 365 * 50));
+    /// ```
+    #[inline]
+    pub fn elapsed(self) -> SignedDuration {
+        self.elapsed_at(Timestamp::now())
+    }
+
+    /// Returns the duration that has elapsed between the anchor of this
+    /// `Uptime` and the given timestamp.
+    ///
+    /// The duration returned is negative when the given timestamp precedes
+    /// the anchor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{SignedDuration, Timestamp, Uptime};
+    ///
+    /// let uptime = Uptime::from_anchor(Timestamp::from_second(60)?);
+    /// assert_eq!(
+    ///     uptime.elapsed_at(Timestamp::UNIX_EPOCH),
+    ///     SignedDuration::from_secs(-60),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn elapsed_at(self, now: Timestamp) -> SignedDuration {
+        now.duration_since(self.anchor)
+    }
+
+    /// Returns a value that formats the time elapsed since the anchor of this
+    /// `Uptime`, as of the time this routine is called.
+    ///
+    /// See [`Uptime::display_at`] for details on the format used.
+    #[inline]
+    pub fn display(self) -> UptimeDisplay {
+        self.display_at(Timestamp::now())
+    }
+
+    /// Returns a value that formats the time elapsed between the anchor of
+    /// this `Uptime` and the given timestamp.
+    ///
+    /// The format used is Jiff's [friendly](crate::fmt::friendly) format with
+    /// compact unit designators, where days are always 24 hours long. For
+    /// example, `3d 4h 5m 6s`.
+    ///
+    /// By default, fractional seconds are truncated. A precision may be
+    /// provided via the standard formatting machinery (e.g., `{:.3}`) to
+    /// include that many fractional digits of seconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{Timestamp, Uptime};
+    ///
+    /// let uptime = Uptime::from_anchor(Timestamp::from_second(0)?);
+    /// let now = Timestamp::new(90_061, 500_000_000)?;
+    /// assert_eq!(uptime.display_at(now).to_string(), "1d 1h 1m 1s");
+    /// assert_eq!(format!("{:.1}", uptime.display_at(now)), "1d 1h 1m 1.5s");
+    ///
+    /// let now = Timestamp::new(0, 250_000_000)?;
+    /// assert_eq!(uptime.display_at(now).to_string(), "0s");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn display_at(self, now: Timestamp) -> UptimeDisplay {
+        UptimeDisplay { elapsed: self.elapsed_at(now) }
+    }
+}
+
+/// A value that formats the time elapsed since an [`Uptime`] anchor.
+///
+/// This type is created by [`Uptime::display`] and [`Uptime::display_at`].
+/// See the latter for details on the format used.
+#[derive(Clone, Copy, Debug)]
+pub struct UptimeDisplay {
+    elapsed: SignedDuration,
+}
+
+impl UptimeDisplay {
+    /// Returns the elapsed duration that this value formats.
+    #[inline]
+    pub fn elapsed(&self) -> SignedDuration {
+        self.elapsed
+    }
+}
+
+impl core::fmt::Display for UptimeDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use crate::fmt::StdFmtWrite;
+
+        const PRINTER: SpanPrinter = SpanPrinter::new()
+            .designator(Designator::Compact)
+            .duration_days(true);
+
+        let (printer, elapsed) = match f.precision() {
+            None => {
+                let whole = SignedDuration::from_secs(self.elapsed.as_secs());
+                (PRINTER, whole)
+            }
+            Some(precision) => {
+                let precision = u8::try_from(precision).unwrap_or(u8::MAX);
+                let printer = PRINTER
+                    .fractional(Some(FractionalUnit::Second))
+                    .precision(Some(precision));
+                (printer, self.elapsed)
+            }
+        };
+        printer
+            .print_duration(&elapsed, StdFmtWrite(f))
+            .map_err(|_| core::fmt::Error)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Uptime {
+    #[inline]
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        self.anchor.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Uptime {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Uptime, D::Error> {
+        Timestamp::deserialize(deserializer).map(Uptime::from_anchor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn display() {
+        let uptime = Uptime::from_anchor(Timestamp::UNIX_EPOCH);
+        let d = |secs, nanos| {
+            uptime.display_at(Timestamp::new(secs, nanos).unwrap())
+        };
+
+        insta::assert_snapshot!(d(0, 0), @"0s");
+        insta::assert_snapshot!(d(59, 999_999_999), @"59s");
+        insta::assert_snapshot!(d(3_600, 0), @"1h");
+        insta::assert_snapshot!(d(86_400 * 400 + 1, 0), @"400d 1s");
+        insta::assert_snapshot!(d(-90, 0), @"1m 30s ago");
+        insta::assert_snapshot!(
+            alloc::format!("{:.2}", d(61, 5_000_000)),
+            @"1m 1.00s",
+        );
+        insta::assert_snapshot!(
+            alloc::format!("{:.0}", d(61, 5_000_000)),
+            @"1m 1s",
+        );
+    }
+
+    #[test]
+    fn process_anchor_is_stable() {
+        let uptime = Uptime::process();
+        assert_eq!(Uptime::process().anchor(), uptime.anchor());
+        let err = Uptime::set_process_anchor(Timestamp::UNIX_EPOCH);
+        assert!(err.is_err());
+        assert_eq!(Uptime::process().anchor(), uptime.anchor());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let anchor: Timestamp = "2025-01-01T12:34:56.789Z".parse().unwrap();
+        let uptime = Uptime::from_anchor(anchor);
+        let json = serde_json::to_string(&uptime).unwrap();
+        assert_eq!(json, r#""2025-01-01T12:34:56.789Z""#);
+        let got: Uptime = serde_json::from_str(&json).unwrap();
+        assert_eq!(got, uptime);
+        assert_eq!(got.anchor().to_string(), "2025-01-01T12:34:56.789Z");
+    }
+}
@@ -15,6 +15,8 @@ pub(crate) mod libm;
 pub(crate) mod parse;
 pub(crate) mod rangeint;
 pub(crate) mod round;
+#[cfg(feature = "utoipa")]
+pub(crate) mod schema;
 pub(crate) mod sync;
 pub(crate) mod t;
 pub(crate) mod utf8;
@@ -2135,6 +2135,20 @@ macro_rules! define_ranged {
                 alloc::boxed::Box::new(self.val.shrink().filter_map(Self::new))
             }
         }
+
+        #[cfg(feature = "arbitrary")]
+        impl<'a, const MIN: i128, const MAX: i128> arbitrary::Arbitrary<'a> for $name<MIN, MAX> {
+            fn arbitrary(
+                u: &mut arbitrary::Unstructured<'a>,
+            ) -> arbitrary::Result<Self> {
+                let n = u.int_in_range(Self::MIN_REPR..=Self::MAX_REPR)?;
+                Ok(Self::new(n).unwrap())
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$repr as arbitrary::Arbitrary>::size_hint(depth)
+            }
+        }
     };
 }
 
@@ -0,0 +1,113 @@
+/*!
+Provides a helper for implementing `utoipa`'s schema traits.
+
+All of Jiff's types that implement `utoipa::PartialSchema` are serialized as
+strings by Jiff's `serde` integration (at least in human readable formats
+like JSON), so their schemas differ only in their format, description and
+example.
+*/
+
+use utoipa::openapi::{
+    schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, Type},
+    RefOr,
+};
+
+/// Returns a schema for a string with the given format, description and
+/// example.
+///
+/// When `format` is `None`, then the schema doesn't declare a format. This
+/// is used for types whose string representation doesn't correspond to one
+/// of the formats defined by JSON Schema.
+pub(crate) fn string(
+    format: Option<KnownFormat>,
+    description: &str,
+    example: &str,
+) -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .format(format.map(SchemaFormat::KnownFormat))
+        .description(Some(description))
+        .examples([example])
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    // The derive expects `format!` and `String` to be in scope, which isn't
+    // the case by default since Jiff is a `no_std` crate.
+    use alloc::{format, string::String, vec::Vec};
+
+    use utoipa::{PartialSchema, ToSchema};
+
+    use crate::{
+        civil::{Date, DateTime, Time},
+        SignedDuration, Span, Timestamp, Zoned,
+    };
+
+    #[test]
+    fn derive() {
+        #[derive(ToSchema)]
+        #[allow(dead_code)]
+        struct Event {
+            at: Timestamp,
+            zoned: Option<Zoned>,
+            date: Date,
+            time: Time,
+            datetime: DateTime,
+            span: Span,
+            duration: SignedDuration,
+        }
+
+        let json = serde_json::to_value(Event::schema()).unwrap();
+        insta::assert_snapshot!(
+            json["properties"]["at"],
+            @r###"{"$ref":"#/components/schemas/Timestamp"}"###,
+        );
+        insta::assert_snapshot!(
+            json["properties"]["zoned"],
+            @r###"{"oneOf":[{"type":"null"},{"$ref":"#/components/schemas/Zoned"}]}"###,
+        );
+
+        let mut schemas = Vec::new();
+        Event::schemas(&mut schemas);
+        let names: Vec<_> =
+            schemas.iter().map(|(name, _)| name.as_str()).collect();
+        insta::assert_snapshot!(
+            names.join(","),
+            @"Timestamp,Zoned,Date,Time,DateTime,Span,SignedDuration",
+        );
+    }
+
+    #[test]
+    fn schemas() {
+        let json = |schema| serde_json::to_string(&schema).unwrap();
+        insta::assert_snapshot!(
+            json(Timestamp::schema()),
+            @r###"{"type":"string","format":"date-time","description":"An instant in time, as an RFC 3339 timestamp in UTC.","examples":["2024-06-15T07:00:00Z"]}"###,
+        );
+        insta::assert_snapshot!(
+            json(Zoned::schema()),
+            @r###"{"type":"string","description":"A datetime in a time zone, as an RFC 9557 timestamp.","examples":["2024-06-15T07:00:00-04:00[America/New_York]"]}"###,
+        );
+        insta::assert_snapshot!(
+            json(Date::schema()),
+            @r###"{"type":"string","format":"date","description":"A civil date, as an ISO 8601 calendar date.","examples":["2024-06-15"]}"###,
+        );
+        insta::assert_snapshot!(
+            json(Time::schema()),
+            @r###"{"type":"string","description":"A civil time, as an ISO 8601 time without an offset.","examples":["07:00:00"]}"###,
+        );
+        insta::assert_snapshot!(
+            json(DateTime::schema()),
+            @r###"{"type":"string","description":"A civil datetime, as an ISO 8601 datetime without an offset.","examples":["2024-06-15T07:00:00"]}"###,
+        );
+        insta::assert_snapshot!(
+            json(Span::schema()),
+            @r###"{"type":"string","format":"duration","description":"A span of time, as an ISO 8601 duration.","examples":["P1Y2M3DT4H5M6.789S"]}"###,
+        );
+        insta::assert_snapshot!(
+            json(SignedDuration::schema()),
+            @r###"{"type":"string","format":"duration","description":"A signed duration, as an ISO 8601 duration in units of hours or smaller.","examples":["PT2H30M"]}"###,
+        );
+    }
+}
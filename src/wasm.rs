@@ -0,0 +1,265 @@
+/*!
+Interoperability with JavaScript's `Date`, `Intl` and `Temporal` APIs.
+
+This module is only available when the `wasm` crate feature is enabled and
+when compiling for the `wasm32-unknown-unknown` or `wasm64-unknown-unknown`
+targets. Unlike the `js` crate feature, enabling the `wasm` feature doesn't
+change any of Jiff's behavior, so it is fine for libraries to enable it.
+
+# `Date`
+
+[`Timestamp`] and [`Zoned`] can be converted to a [`js_sys::Date`] via
+`From`, and a [`js_sys::Date`] can be converted to a [`Timestamp`] via
+`TryFrom`. A JavaScript `Date` only has millisecond precision, so any
+sub-millisecond precision in a Jiff timestamp is truncated.
+
+```no_run
+use jiff::Timestamp;
+
+let ts: Timestamp = "2025-01-01T12:30:00.123456789Z".parse()?;
+let date = js_sys::Date::from(ts);
+assert_eq!(date.get_time(), 1_735_734_600_123.0);
+assert_eq!(
+    Timestamp::try_from(date)?.to_string(),
+    "2025-01-01T12:30:00.123Z",
+);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+# `Intl`
+
+The [`intl_time_zone`] function returns the time zone reported by the
+host's `Intl.DateTimeFormat` API. This is the same time zone that
+[`TimeZone::system`] uses when the `js` crate feature is enabled, but it can
+be used without changing how Jiff determines the system time zone.
+
+# `Temporal`
+
+Jiff's datetime types have a lot in common with the types in JavaScript's
+[Temporal API], and they use the same interchange format. This module
+provides routines like [`to_temporal_instant`] and
+[`to_temporal_zoned_date_time`] for converting Jiff values to their
+corresponding Temporal objects, and [`from_temporal`] for converting
+Temporal objects back into Jiff values. The conversions are done via
+Temporal's `from` constructors and `toString` methods, so they fail when the
+host doesn't support Temporal.
+
+```no_run
+use jiff::{wasm, Zoned};
+
+let zdt: Zoned = "2025-03-09T03:00-04:00[America/New_York]".parse()?;
+let temporal = wasm::to_temporal_zoned_date_time(&zdt)?;
+let got: Zoned = wasm::from_temporal(&temporal)?;
+assert_eq!(got, zdt);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+[Temporal API]: https://tc39.es/proposal-temporal/docs/
+*/
+
+use alloc::string::{String, ToString};
+
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::{
+    civil::{Date, DateTime, Time},
+    error::{err, Error},
+    tz::TimeZone,
+    Span, Timestamp, Zoned,
+};
+
+/// Converts a Jiff timestamp to a JavaScript `Date`.
+///
+/// Any sub-millisecond precision in the timestamp is truncated. This never
+/// fails since JavaScript `Date` values support a superset of the instants
+/// supported by Jiff.
+impl From<Timestamp> for js_sys::Date {
+    fn from(ts: Timestamp) -> js_sys::Date {
+        // OK because every millisecond in Jiff's range is exactly
+        // representable by an `f64`.
+        let millis = ts.as_millisecond() as f64;
+        js_sys::Date::new(&JsValue::from_f64(millis))
+    }
+}
+
+/// Converts a Jiff zoned datetime to a JavaScript `Date`.
+///
+/// JavaScript `Date` values don't have a time zone, so only the instant of
+/// the zoned datetime is preserved. Any sub-millisecond precision is
+/// truncated.
+impl<'a> From<&'a Zoned> for js_sys::Date {
+    fn from(zdt: &'a Zoned) -> js_sys::Date {
+        js_sys::Date::from(zdt.timestamp())
+    }
+}
+
+/// Converts a Jiff zoned datetime to a JavaScript `Date`.
+///
+/// This is the same as the conversion from a `&Zoned`.
+impl From<Zoned> for js_sys::Date {
+    fn from(zdt: Zoned) -> js_sys::Date {
+        js_sys::Date::from(zdt.timestamp())
+    }
+}
+
+/// Converts a JavaScript `Date` to a Jiff timestamp.
+///
+/// This returns an error when the `Date` is invalid (that is, when its time
+/// value is `NaN`) or when it is outside of Jiff's supported range.
+impl<'a> TryFrom<&'a js_sys::Date> for Timestamp {
+    type Error = Error;
+
+    fn try_from(date: &'a js_sys::Date) -> Result<Timestamp, Error> {
+        let millis = date.get_time();
+        if millis.is_nan() {
+            return Err(err!(
+                "cannot convert invalid JavaScript `Date` to a timestamp"
+            ));
+        }
+        // OK because JavaScript `Date` values are always a whole number of
+        // milliseconds within +/- 8.64e15, which fits in an `i64`.
+        Timestamp::from_millisecond(millis as i64)
+    }
+}
+
+/// Converts a JavaScript `Date` to a Jiff timestamp.
+///
+/// This is the same as the conversion from a `&js_sys::Date`.
+impl TryFrom<js_sys::Date> for Timestamp {
+    type Error = Error;
+
+    fn try_from(date: js_sys::Date) -> Result<Timestamp, Error> {
+        Timestamp::try_from(&date)
+    }
+}
+
+/// Returns the time zone reported by the host's `Intl.DateTimeFormat` API.
+///
+/// The IANA time zone identifier reported by `Intl` is looked up in Jiff's
+/// global time zone database.
+///
+/// # Errors
+///
+/// This returns an error when `Intl` doesn't report a time zone, or when the
+/// time zone it reports can't be found in the time zone database.
+pub fn intl_time_zone() -> Result<TimeZone, Error> {
+    let fmt = js_sys::Intl::DateTimeFormat::new(
+        &js_sys::Array::new(),
+        &js_sys::Object::new(),
+    );
+    let options = fmt.resolved_options();
+    // Documented to be an IANA tz ID:
+    // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions#timezone
+    let name = js_sys::Reflect::get(&options, &JsValue::from("timeZone"))
+        .ok()
+        .and_then(|val| val.as_string())
+        .ok_or_else(|| {
+            err!("`Intl.DateTimeFormat` did not report a time zone")
+        })?;
+    TimeZone::get(&name)
+}
+
+/// Converts a Jiff timestamp to a `Temporal.Instant`.
+///
+/// # Errors
+///
+/// This returns an error when the host doesn't support the Temporal API.
+pub fn to_temporal_instant(ts: Timestamp) -> Result<JsValue, Error> {
+    temporal_from("Instant", &ts.to_string())
+}
+
+/// Converts a Jiff zoned datetime to a `Temporal.ZonedDateTime`.
+///
+/// # Errors
+///
+/// This returns an error when the host doesn't support the Temporal API, or
+/// when the host doesn't support the zoned datetime's time zone.
+pub fn to_temporal_zoned_date_time(zdt: &Zoned) -> Result<JsValue, Error> {
+    temporal_from("ZonedDateTime", &zdt.to_string())
+}
+
+/// Converts a Jiff civil datetime to a `Temporal.PlainDateTime`.
+///
+/// # Errors
+///
+/// This returns an error when the host doesn't support the Temporal API.
+pub fn to_temporal_plain_date_time(dt: DateTime) -> Result<JsValue, Error> {
+    temporal_from("PlainDateTime", &dt.to_string())
+}
+
+/// Converts a Jiff civil date to a `Temporal.PlainDate`.
+///
+/// # Errors
+///
+/// This returns an error when the host doesn't support the Temporal API.
+pub fn to_temporal_plain_date(date: Date) -> Result<JsValue, Error> {
+    temporal_from("PlainDate", &date.to_string())
+}
+
+/// Converts a Jiff civil time to a `Temporal.PlainTime`.
+///
+/// # Errors
+///
+/// This returns an error when the host doesn't support the Temporal API.
+pub fn to_temporal_plain_time(time: Time) -> Result<JsValue, Error> {
+    temporal_from("PlainTime", &time.to_string())
+}
+
+/// Converts a Jiff span to a `Temporal.Duration`.
+///
+/// # Errors
+///
+/// This returns an error when the host doesn't support the Temporal API.
+pub fn to_temporal_duration(span: Span) -> Result<JsValue, Error> {
+    temporal_from("Duration", &span.to_string())
+}
+
+/// Converts a Temporal object to the corresponding Jiff value.
+///
+/// This works by calling `toString` on the given value and parsing the
+/// result. So for example, a `Temporal.ZonedDateTime` can be converted to a
+/// [`Zoned`] and a `Temporal.Duration` can be converted to a [`Span`]. A
+/// string in the Temporal interchange format may be given as well.
+///
+/// # Errors
+///
+/// This returns an error when the given value is not an object or a string,
+/// or when its string representation can't be parsed as a `T`.
+pub fn from_temporal<T>(value: &JsValue) -> Result<T, Error>
+where
+    T: core::str::FromStr<Err = Error>,
+{
+    let string = match value.as_string() {
+        Some(string) => string,
+        None if value.is_object() => {
+            String::from(value.unchecked_ref::<js_sys::Object>().to_string())
+        }
+        None => {
+            return Err(err!(
+                "expected a Temporal object or string, but got {value:?}"
+            ))
+        }
+    };
+    string.parse()
+}
+
+/// Calls `Temporal.{class}.from(string)`.
+fn temporal_from(class: &str, string: &str) -> Result<JsValue, Error> {
+    let temporal =
+        js_sys::Reflect::get(&js_sys::global(), &JsValue::from("Temporal"))
+            .ok()
+            .filter(|temporal| temporal.is_object())
+            .ok_or_else(|| err!("the Temporal API is not available"))?;
+    let class_obj = js_sys::Reflect::get(&temporal, &JsValue::from(class))
+        .ok()
+        .filter(|class_obj| class_obj.is_object())
+        .ok_or_else(|| err!("`Temporal.{class}` is not available"))?;
+    let from = js_sys::Reflect::get(&class_obj, &JsValue::from("from"))
+        .ok()
+        .and_then(|from| from.dyn_into::<js_sys::Function>().ok())
+        .ok_or_else(|| err!("`Temporal.{class}.from` is not available"))?;
+    from.call1(&class_obj, &JsValue::from(string))
+        .map_err(|e| err!("`Temporal.{class}.from({string:?})` failed: {e:?}"))
+}
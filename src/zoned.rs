@@ -1,5 +1,8 @@
 use core::time::Duration as UnsignedDuration;
 
+#[cfg(feature = "rand")]
+use rand::distr::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+
 use crate::{
     civil::{
         Date, DateTime, DateTimeRound, DateTimeWith, Era, ISOWeekDate, Time,
@@ -20,6 +23,9 @@ use crate::{
     RoundMode, SignedDuration, Span, SpanRound, Timestamp, Unit,
 };
 
+#[cfg(feature = "rand")]
+use crate::timestamp::TimestampUniform;
+
 /// A time zone aware instant in time.
 ///
 /// A `Zoned` value can be thought of as the combination of following types,
@@ -211,6 +217,10 @@ use crate::{
 /// // `Zoned` doesn't implement `Copy`, so we use `&start` instead of `start`.
 /// let one_week_later = &start + 1.weeks();
 /// assert_eq!(one_week_later.datetime(), date(2024, 3, 3).at(15, 45, 0, 0));
+/// // The trait implementations also accept an owned `Zoned`, so chaining
+/// // operations doesn't require borrowing each intermediate result.
+/// let later = &start + 1.weeks() + 2.days() - 3.hours();
+/// assert_eq!(later.datetime(), date(2024, 3, 5).at(12, 45, 0, 0));
 ///
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
@@ -1977,6 +1987,97 @@ impl Zoned {
         self.inner.offset
     }
 
+    /// Returns true if and only if daylight saving time is in effect for this
+    /// zoned datetime.
+    ///
+    /// This is always false for time zones that are fixed offsets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let zdt = date(2024, 2, 14).at(18, 45, 0, 0).in_tz("America/New_York")?;
+    /// assert!(!zdt.is_dst());
+    ///
+    /// let zdt = date(2024, 7, 14).at(18, 45, 0, 0).in_tz("America/New_York")?;
+    /// assert!(zdt.is_dst());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn is_dst(&self) -> bool {
+        self.time_zone().to_offset_info(self.timestamp()).dst().is_dst()
+    }
+
+    /// Returns the standard offset of this zoned datetime's time zone at this
+    /// zoned datetime's instant.
+    ///
+    /// The standard offset is the offset used when daylight saving time is
+    /// not in effect. When daylight saving time is not in effect for this
+    /// zoned datetime, then this is equivalent to [`Zoned::offset`].
+    /// Otherwise, this is the offset of the time zone's nearest period of
+    /// standard time, preferring the one that precedes this zoned datetime.
+    ///
+    /// In the rare case of a time zone that is always in daylight saving
+    /// time, this returns [`Zoned::offset`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let zdt = date(2024, 7, 14).at(18, 45, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(zdt.offset(), jiff::tz::offset(-4));
+    /// assert_eq!(zdt.standard_offset(), jiff::tz::offset(-5));
+    ///
+    /// // The standard offset of a time zone can change too. Moscow was
+    /// // permanently on "summer" time from 2011 through 2014, but as far as
+    /// // the IANA time zone database is concerned, that was standard time.
+    /// let zdt = date(2013, 7, 14).at(12, 0, 0, 0).in_tz("Europe/Moscow")?;
+    /// assert_eq!(zdt.standard_offset(), jiff::tz::offset(4));
+    /// let zdt = date(2015, 7, 14).at(12, 0, 0, 0).in_tz("Europe/Moscow")?;
+    /// assert_eq!(zdt.standard_offset(), jiff::tz::offset(3));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn standard_offset(&self) -> Offset {
+        self.time_zone().to_standard_offset(self.timestamp())
+    }
+
+    /// Returns the amount of time that this zoned datetime's offset differs
+    /// from the standard offset of its time zone.
+    ///
+    /// This is the difference between [`Zoned::offset`] and
+    /// [`Zoned::standard_offset`]. It is zero when daylight saving time is
+    /// not in effect, and is usually one hour when it is. But some time
+    /// zones use other amounts, and in some time zones (like `Europe/Dublin`)
+    /// the amount is negative because daylight saving time is observed in the
+    /// winter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, SignedDuration};
+    ///
+    /// let zdt = date(2024, 7, 14).at(18, 45, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(zdt.dst_savings(), SignedDuration::from_hours(1));
+    ///
+    /// let zdt = date(2024, 2, 14).at(18, 45, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(zdt.dst_savings(), SignedDuration::ZERO);
+    ///
+    /// // Lord Howe Island only moves its clocks by 30 minutes.
+    /// let zdt = date(2024, 1, 14).at(12, 0, 0, 0).in_tz("Australia/Lord_Howe")?;
+    /// assert_eq!(zdt.dst_savings(), SignedDuration::from_mins(30));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn dst_savings(&self) -> SignedDuration {
+        self.offset().duration_since(self.standard_offset())
+    }
+
     /// Add the given span of time to this zoned datetime. If the sum would
     /// overflow the minimum or maximum zoned datetime values, then an error is
     /// returned.
@@ -3158,6 +3259,129 @@ impl Zoned {
     ) -> fmt::strtime::Display<'f> {
         fmt::strtime::Display { fmt: format.as_ref(), tm: self.into() }
     }
+
+    /// Returns a value whose `Debug` implementation shows both the civil and
+    /// instant views of this zoned datetime, along with the context of the
+    /// offset in effect.
+    ///
+    /// The `Debug` implementation on `Zoned` emits the same string as its
+    /// `Display` implementation. This is instead a structured view that is
+    /// useful when debugging time zone issues (like DST transitions) in
+    /// tests. It includes:
+    ///
+    /// * The civil datetime and the timestamp.
+    /// * The time zone, and the offset, DST status and abbreviation in
+    /// effect.
+    /// * The previous time zone transition (i.e., the one that put the
+    /// current offset into effect), if one exists. This shows the timestamp
+    /// of the transition and the offset, DST status and abbreviation in
+    /// effect _before_ it.
+    /// * The next time zone transition, if one exists. This shows the
+    /// timestamp of the transition and the offset, DST status and
+    /// abbreviation in effect _after_ it.
+    ///
+    /// The precise output of this routine is not a semver guarantee.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let zdt = date(2024, 3, 10).at(3, 30, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(format!("{:#?}", zdt.debug_verbose()), r#"Zoned {
+    ///     datetime: 2024-03-10T03:30:00,
+    ///     timestamp: 2024-03-10T07:30:00Z,
+    ///     time_zone: America/New_York,
+    ///     offset: -04:00:00,
+    ///     dst: Yes,
+    ///     abbreviation: "EDT",
+    ///     previous_transition: Some(
+    ///         Transition {
+    ///             timestamp: 2024-03-10T07:00:00Z,
+    ///             offset_before: -05:00:00,
+    ///             dst_before: No,
+    ///             abbreviation_before: "EST",
+    ///         },
+    ///     ),
+    ///     next_transition: Some(
+    ///         Transition {
+    ///             timestamp: 2024-11-03T06:00:00Z,
+    ///             offset_after: -05:00:00,
+    ///             dst_after: No,
+    ///             abbreviation_after: "EST",
+    ///         },
+    ///     ),
+    /// }"#);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn debug_verbose(&self) -> ZonedDebugVerbose<'_> {
+        ZonedDebugVerbose { zoned: self }
+    }
+
+    /// Format this zoned datetime as an [RFC 2822] string.
+    ///
+    /// This is a convenience routine for [`fmt::rfc2822::to_string`]. To
+    /// configure how the datetime is printed, use
+    /// [`fmt::rfc2822::DateTimePrinter`].
+    ///
+    /// Note that RFC 2822 has no way of representing the time zone, so
+    /// only the offset is included in the string returned.
+    ///
+    /// [RFC 2822]: https://datatracker.ietf.org/doc/html/rfc2822
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the year is outside the range supported by
+    /// RFC 2822 (i.e., negative).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let zdt = date(2024, 7, 15).at(16, 24, 59, 0).in_tz("America/New_York")?;
+    /// assert_eq!(zdt.to_rfc2822()?, "Mon, 15 Jul 2024 16:24:59 -0400");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_rfc2822(&self) -> Result<alloc::string::String, Error> {
+        fmt::rfc2822::to_string(self)
+    }
+
+    /// Format this zoned datetime into a string using the given Temporal
+    /// printer.
+    ///
+    /// This is a convenience routine for
+    /// [`temporal::DateTimePrinter::zoned_to_string`]. It is useful for
+    /// one-off formatting with a non-default configuration. When no
+    /// configuration is needed, use the [`std::fmt::Display`] trait
+    /// implementation on `Zoned` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// let zdt = date(2024, 7, 15).at(16, 24, 59, 0).in_tz("America/New_York")?;
+    /// assert_eq!(
+    ///     zdt.to_string_with(&DateTimePrinter::new().separator(b' ')),
+    ///     "2024-07-15 16:24:59-04:00[America/New_York]",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_string_with(
+        &self,
+        printer: &temporal::DateTimePrinter,
+    ) -> alloc::string::String {
+        printer.zoned_to_string(self)
+    }
 }
 
 impl Default for Zoned {
@@ -3207,6 +3431,78 @@ impl core::fmt::Debug for Zoned {
     }
 }
 
+/// A structured `Debug` representation of a [`Zoned`] datetime.
+///
+/// This type is returned by [`Zoned::debug_verbose`]. See its documentation
+/// for more details.
+#[derive(Clone, Copy)]
+pub struct ZonedDebugVerbose<'a> {
+    zoned: &'a Zoned,
+}
+
+impl<'a> core::fmt::Debug for ZonedDebugVerbose<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use crate::tz::{TimeZoneOffsetInfo, TimeZoneTransition};
+
+        /// The previous transition, along with the offset before it.
+        struct Previous<'t> {
+            timestamp: Timestamp,
+            before: TimeZoneOffsetInfo<'t>,
+        }
+
+        impl<'t> core::fmt::Debug for Previous<'t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.debug_struct("Transition")
+                    .field("timestamp", &self.timestamp)
+                    .field("offset_before", &self.before.offset())
+                    .field("dst_before", &self.before.dst())
+                    .field("abbreviation_before", &self.before.abbreviation())
+                    .finish()
+            }
+        }
+
+        /// The next transition, which carries the offset after it.
+        struct Next<'t>(TimeZoneTransition<'t>);
+
+        impl<'t> core::fmt::Debug for Next<'t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.debug_struct("Transition")
+                    .field("timestamp", &self.0.timestamp())
+                    .field("offset_after", &self.0.offset())
+                    .field("dst_after", &self.0.dst())
+                    .field("abbreviation_after", &self.0.abbreviation())
+                    .finish()
+            }
+        }
+
+        let one = SignedDuration::from_nanos(1);
+        let tz = self.zoned.time_zone();
+        let ts = self.zoned.timestamp();
+        let info = tz.to_offset_info(ts);
+        // A transition at precisely `ts` is in effect at `ts`, but
+        // `preceding` only returns transitions strictly before the timestamp
+        // given. Transitions always occur on a whole second, so when this
+        // saturates at `Timestamp::MAX`, nothing is missed.
+        let after = ts.checked_add(one).unwrap_or(ts);
+        let previous = tz.preceding(after).next().map(|t| {
+            let timestamp = t.timestamp();
+            let before = timestamp.checked_sub(one).unwrap_or(timestamp);
+            Previous { timestamp, before: tz.to_offset_info(before) }
+        });
+        let next = tz.following(ts).next().map(Next);
+        f.debug_struct("Zoned")
+            .field("datetime", &self.zoned.datetime())
+            .field("timestamp", &ts)
+            .field("time_zone", &format_args!("{}", tz.diagnostic_name()))
+            .field("offset", &info.offset())
+            .field("dst", &info.dst())
+            .field("abbreviation", &info.abbreviation())
+            .field("previous_transition", &previous)
+            .field("next_transition", &next)
+            .finish()
+    }
+}
+
 /// Converts a `Zoned` datetime into a RFC 9557 compliant string.
 ///
 /// Options currently supported:
@@ -3344,6 +3640,22 @@ impl<'a> core::ops::Add<Span> for &'a Zoned {
     }
 }
 
+/// Adds a span of time to a zoned datetime.
+///
+/// This consumes the zoned datetime, which permits chaining operations
+/// without borrowing temporaries, e.g., `&zdt + 1.day() + 2.hours()`.
+///
+/// This uses checked arithmetic and panics on overflow. To handle overflow
+/// without panics, use [`Zoned::checked_add`].
+impl core::ops::Add<Span> for Zoned {
+    type Output = Zoned;
+
+    #[inline]
+    fn add(self, rhs: Span) -> Zoned {
+        &self + rhs
+    }
+}
+
 /// Adds a span of time to a zoned datetime in place.
 ///
 /// This uses checked arithmetic and panics on overflow. To handle overflow
@@ -3369,6 +3681,22 @@ impl<'a> core::ops::Sub<Span> for &'a Zoned {
     }
 }
 
+/// Subtracts a span of time from a zoned datetime.
+///
+/// This consumes the zoned datetime, which permits chaining operations
+/// without borrowing temporaries, e.g., `&zdt - 1.day() - 2.hours()`.
+///
+/// This uses checked arithmetic and panics on overflow. To handle overflow
+/// without panics, use [`Zoned::checked_sub`].
+impl core::ops::Sub<Span> for Zoned {
+    type Output = Zoned;
+
+    #[inline]
+    fn sub(self, rhs: Span) -> Zoned {
+        &self - rhs
+    }
+}
+
 /// Subtracts a span of time from a zoned datetime in place.
 ///
 /// This uses checked arithmetic and panics on overflow. To handle overflow
@@ -3414,6 +3742,22 @@ impl<'a> core::ops::Add<SignedDuration> for &'a Zoned {
     }
 }
 
+/// Adds a signed duration of time to a zoned datetime.
+///
+/// This consumes the zoned datetime, which permits chaining operations
+/// without borrowing temporaries, e.g., `&zdt + dur1 + dur2`.
+///
+/// This uses checked arithmetic and panics on overflow. To handle overflow
+/// without panics, use [`Zoned::checked_add`].
+impl core::ops::Add<SignedDuration> for Zoned {
+    type Output = Zoned;
+
+    #[inline]
+    fn add(self, rhs: SignedDuration) -> Zoned {
+        &self + rhs
+    }
+}
+
 /// Adds a signed duration of time to a zoned datetime in place.
 ///
 /// This uses checked arithmetic and panics on overflow. To handle overflow
@@ -3440,6 +3784,22 @@ impl<'a> core::ops::Sub<SignedDuration> for &'a Zoned {
     }
 }
 
+/// Subtracts a signed duration of time from a zoned datetime.
+///
+/// This consumes the zoned datetime, which permits chaining operations
+/// without borrowing temporaries, e.g., `&zdt - dur1 - dur2`.
+///
+/// This uses checked arithmetic and panics on overflow. To handle overflow
+/// without panics, use [`Zoned::checked_sub`].
+impl core::ops::Sub<SignedDuration> for Zoned {
+    type Output = Zoned;
+
+    #[inline]
+    fn sub(self, rhs: SignedDuration) -> Zoned {
+        &self - rhs
+    }
+}
+
 /// Subtracts a signed duration of time from a zoned datetime in place.
 ///
 /// This uses checked arithmetic and panics on overflow. To handle overflow
@@ -3465,6 +3825,22 @@ impl<'a> core::ops::Add<UnsignedDuration> for &'a Zoned {
     }
 }
 
+/// Adds an unsigned duration of time to a zoned datetime.
+///
+/// This consumes the zoned datetime, which permits chaining operations
+/// without borrowing temporaries, e.g., `&zdt + dur1 + dur2`.
+///
+/// This uses checked arithmetic and panics on overflow. To handle overflow
+/// without panics, use [`Zoned::checked_add`].
+impl core::ops::Add<UnsignedDuration> for Zoned {
+    type Output = Zoned;
+
+    #[inline]
+    fn add(self, rhs: UnsignedDuration) -> Zoned {
+        &self + rhs
+    }
+}
+
 /// Adds an unsigned duration of time to a zoned datetime in place.
 ///
 /// This uses checked arithmetic and panics on overflow. To handle overflow
@@ -3491,6 +3867,22 @@ impl<'a> core::ops::Sub<UnsignedDuration> for &'a Zoned {
     }
 }
 
+/// Subtracts an unsigned duration of time from a zoned datetime.
+///
+/// This consumes the zoned datetime, which permits chaining operations
+/// without borrowing temporaries, e.g., `&zdt - dur1 - dur2`.
+///
+/// This uses checked arithmetic and panics on overflow. To handle overflow
+/// without panics, use [`Zoned::checked_sub`].
+impl core::ops::Sub<UnsignedDuration> for Zoned {
+    type Output = Zoned;
+
+    #[inline]
+    fn sub(self, rhs: UnsignedDuration) -> Zoned {
+        &self - rhs
+    }
+}
+
 /// Subtracts an unsigned duration of time from a zoned datetime in place.
 ///
 /// This uses checked arithmetic and panics on overflow. To handle overflow
@@ -3502,6 +3894,223 @@ impl core::ops::SubAssign<UnsignedDuration> for Zoned {
     }
 }
 
+/// Converts a Jiff zoned datetime to a `chrono` datetime with a fixed
+/// offset.
+///
+/// The `chrono` datetime uses the offset of the zoned datetime at its
+/// instant. Its time zone rules are discarded, so arithmetic on the
+/// resulting `chrono` datetime won't account for time zone transitions.
+///
+/// This returns an error when the offset is 24 hours or more in magnitude,
+/// since `chrono` doesn't support such offsets.
+#[cfg(feature = "chrono")]
+impl<'a> TryFrom<&'a Zoned> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(
+        zdt: &'a Zoned,
+    ) -> Result<chrono::DateTime<chrono::FixedOffset>, Error> {
+        let offset = zdt.offset();
+        let offset = chrono::FixedOffset::east_opt(offset.seconds())
+            .ok_or_else(|| {
+                err!(
+                    "offset {offset} is outside the range supported by chrono"
+                )
+            })?;
+        let utc = chrono::DateTime::<chrono::Utc>::from(zdt.timestamp());
+        Ok(utc.with_timezone(&offset))
+    }
+}
+
+/// Converts a Jiff zoned datetime to a `chrono` datetime with a fixed
+/// offset.
+///
+/// This is the same as the conversion from a `&Zoned`.
+#[cfg(feature = "chrono")]
+impl TryFrom<Zoned> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(
+        zdt: Zoned,
+    ) -> Result<chrono::DateTime<chrono::FixedOffset>, Error> {
+        chrono::DateTime::try_from(&zdt)
+    }
+}
+
+/// Converts a `chrono` datetime with a fixed offset to a Jiff zoned
+/// datetime.
+///
+/// The zoned datetime uses a fixed offset [`TimeZone`] corresponding to the
+/// `chrono` datetime's offset.
+///
+/// This returns an error when the datetime is outside of Jiff's supported
+/// range. A leap second is handled the same way as when converting from a
+/// `chrono::NaiveTime` to a [`Time`].
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::FixedOffset>> for Zoned {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(
+        dt: chrono::DateTime<chrono::FixedOffset>,
+    ) -> Result<Zoned, Error> {
+        // OK because `chrono` offsets are always less than 24 hours in
+        // magnitude, which is within Jiff's supported range.
+        let offset =
+            Offset::from_seconds(dt.offset().local_minus_utc()).unwrap();
+        let ts = Timestamp::try_from(dt.to_utc())?;
+        Ok(ts.to_zoned(TimeZone::fixed(offset)))
+    }
+}
+
+/// Converts a Jiff zoned datetime to a `time` datetime with an offset.
+///
+/// The `time` datetime uses the offset of the zoned datetime at its instant.
+/// Its time zone rules are discarded, so arithmetic on the resulting `time`
+/// datetime won't account for time zone transitions.
+#[cfg(feature = "time")]
+impl<'a> From<&'a Zoned> for ::time::OffsetDateTime {
+    #[inline]
+    fn from(zdt: &'a Zoned) -> ::time::OffsetDateTime {
+        // OK because `time` and Jiff support the same range of offsets.
+        let offset =
+            ::time::UtcOffset::from_whole_seconds(zdt.offset().seconds())
+                .unwrap();
+        ::time::PrimitiveDateTime::from(zdt.datetime()).assume_offset(offset)
+    }
+}
+
+/// Converts a Jiff zoned datetime to a `time` datetime with an offset.
+///
+/// This is the same as the conversion from a `&Zoned`.
+#[cfg(feature = "time")]
+impl From<Zoned> for ::time::OffsetDateTime {
+    #[inline]
+    fn from(zdt: Zoned) -> ::time::OffsetDateTime {
+        ::time::OffsetDateTime::from(&zdt)
+    }
+}
+
+/// Converts a `time` datetime with an offset to a Jiff zoned datetime.
+///
+/// The zoned datetime uses a fixed offset [`TimeZone`] corresponding to the
+/// `time` datetime's offset.
+///
+/// This returns an error when the datetime is outside of Jiff's supported
+/// range.
+#[cfg(feature = "time")]
+impl TryFrom<::time::OffsetDateTime> for Zoned {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(dt: ::time::OffsetDateTime) -> Result<Zoned, Error> {
+        // OK because `time` and Jiff support the same range of offsets.
+        let offset =
+            Offset::from_seconds(dt.offset().whole_seconds()).unwrap();
+        let ts = Timestamp::try_from(dt)?;
+        Ok(ts.to_zoned(TimeZone::fixed(offset)))
+    }
+}
+
+/// Samples zoned datetimes uniformly at random from a range.
+///
+/// See [`ZonedUniform`] for more details.
+#[cfg(feature = "rand")]
+impl SampleUniform for Zoned {
+    type Sampler = ZonedUniform;
+}
+
+/// A sampler for picking zoned datetimes uniformly at random from a range.
+///
+/// This is the [`UniformSampler`](rand::distr::uniform::UniformSampler)
+/// implementation for [`Zoned`]. It is available when the `rand` crate
+/// feature is enabled. Callers usually don't need to use it directly.
+/// Instead, use `rand::distr::Uniform` or `rand::RngExt::random_range` with
+/// a range of zoned datetimes.
+///
+/// Every nanosecond between the instants of the range's bounds is equally
+/// likely to be picked. The zoned datetimes returned are always in the time
+/// zone of the range's lower bound. So to sample zoned datetimes in a
+/// particular time zone, the lower bound should be in that time zone.
+///
+/// Since sampling is uniform over instants, civil times that occur twice
+/// because of a fold in the time zone are twice as likely to be picked, and
+/// civil times in a gap are never picked.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::date;
+/// use rand::{
+///     distr::{Distribution, Uniform},
+///     rngs::StdRng,
+///     SeedableRng,
+/// };
+///
+/// let start = date(2025, 1, 1).in_tz("America/New_York")?;
+/// let end = date(2026, 1, 1).in_tz("America/New_York")?;
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// let dist = Uniform::new(&start, &end)?;
+/// for _ in 0..100 {
+///     let zdt = dist.sample(&mut rng);
+///     assert!(start <= zdt && zdt < end);
+///     assert_eq!(zdt.time_zone().iana_name(), Some("America/New_York"));
+/// }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "rand")]
+#[derive(Clone, Debug)]
+pub struct ZonedUniform {
+    timestamps: TimestampUniform,
+    time_zone: TimeZone,
+}
+
+#[cfg(feature = "rand")]
+impl UniformSampler for ZonedUniform {
+    type X = Zoned;
+
+    #[inline]
+    fn new<B1, B2>(
+        low: B1,
+        high: B2,
+    ) -> Result<ZonedUniform, rand::distr::uniform::Error>
+    where
+        B1: SampleBorrow<Zoned> + Sized,
+        B2: SampleBorrow<Zoned> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        let timestamps =
+            TimestampUniform::new(low.timestamp(), high.timestamp())?;
+        Ok(ZonedUniform { timestamps, time_zone: low.time_zone().clone() })
+    }
+
+    #[inline]
+    fn new_inclusive<B1, B2>(
+        low: B1,
+        high: B2,
+    ) -> Result<ZonedUniform, rand::distr::uniform::Error>
+    where
+        B1: SampleBorrow<Zoned> + Sized,
+        B2: SampleBorrow<Zoned> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        let timestamps = TimestampUniform::new_inclusive(
+            low.timestamp(),
+            high.timestamp(),
+        )?;
+        Ok(ZonedUniform { timestamps, time_zone: low.time_zone().clone() })
+    }
+
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Zoned {
+        self.timestamps.sample(rng).to_zoned(self.time_zone.clone())
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for Zoned {
     #[inline]
@@ -3553,6 +4162,20 @@ impl<'de> serde::Deserialize<'de> for Zoned {
     }
 }
 
+#[cfg(feature = "utoipa")]
+impl utoipa::PartialSchema for Zoned {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        crate::util::schema::string(
+            None,
+            "A datetime in a time zone, as an RFC 9557 timestamp.",
+            "2024-06-15T07:00:00-04:00[America/New_York]",
+        )
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl utoipa::ToSchema for Zoned {}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for Zoned {
     fn arbitrary(g: &mut quickcheck::Gen) -> Zoned {
@@ -5442,6 +6065,20 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn debug_verbose_without_transitions() {
+        let zdt =
+            date(2024, 3, 10).at(3, 30, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+        let got = alloc::format!("{:?}", zdt.debug_verbose());
+        assert_eq!(
+            got,
+            "Zoned { datetime: 2024-03-10T03:30:00, \
+             timestamp: 2024-03-10T03:30:00Z, time_zone: UTC, \
+             offset: 00:00:00, dst: No, abbreviation: \"UTC\", \
+             previous_transition: None, next_transition: None }",
+        );
+    }
+
     #[test]
     fn until_with_largest_unit() {
         if crate::tz::db().is_definitively_empty() {
@@ -5741,4 +6378,157 @@ mod tests {
             @"increment 2 for rounding datetime to days must be 1) less than 2, 2) divide into it evenly and 3) greater than zero"
         );
     }
+
+    #[test]
+    fn zoned_owned_operators() {
+        let zdt =
+            date(2024, 3, 9).at(12, 0, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+
+        let got = &zdt + 1.day() + SignedDuration::from_hours(1)
+            - UnsignedDuration::from_secs(60);
+        assert_eq!(got.datetime(), date(2024, 3, 10).at(12, 59, 0, 0));
+
+        let got = zdt.clone() - 1.day() - SignedDuration::from_hours(1)
+            + UnsignedDuration::from_secs(60);
+        assert_eq!(got.datetime(), date(2024, 3, 8).at(11, 1, 0, 0));
+    }
+
+    #[test]
+    fn zoned_dst_accessors() {
+        // Irish time observes "negative" DST, where standard time is in the
+        // summer and DST is in the winter.
+        let tz = TimeZone::posix("IST-1GMT0,M10.5.0,M3.5.0/1").unwrap();
+        let winter = date(2024, 1, 15).at(12, 0, 0, 0).to_zoned(tz.clone());
+        let winter = winter.unwrap();
+        assert!(winter.is_dst());
+        assert_eq!(winter.offset(), crate::tz::offset(0));
+        assert_eq!(winter.standard_offset(), crate::tz::offset(1));
+        assert_eq!(winter.dst_savings(), SignedDuration::from_hours(-1));
+
+        let summer = date(2024, 7, 15).at(12, 0, 0, 0).to_zoned(tz).unwrap();
+        assert!(!summer.is_dst());
+        assert_eq!(summer.standard_offset(), crate::tz::offset(1));
+        assert_eq!(summer.dst_savings(), SignedDuration::ZERO);
+
+        let fixed = date(2024, 7, 15)
+            .at(12, 0, 0, 0)
+            .to_zoned(TimeZone::fixed(crate::tz::offset(-3)))
+            .unwrap();
+        assert!(!fixed.is_dst());
+        assert_eq!(fixed.standard_offset(), crate::tz::offset(-3));
+        assert_eq!(fixed.dst_savings(), SignedDuration::ZERO);
+    }
+
+    /// The standard offset in effect before a time zone's first transition
+    /// is found even when there are no later periods of standard time.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn zoned_dst_accessors_initial_standard() {
+        let rules = crate::tz::TimeZoneRules::new(crate::tz::offset(2), "XST")
+            .transition(
+                "2000-01-01T00:00Z".parse().unwrap(),
+                crate::tz::offset(3),
+                crate::tz::Dst::Yes,
+                "XDT",
+            );
+        let tz = TimeZone::from_transitions("Test/Permanent", &rules).unwrap();
+
+        let zdt = date(2024, 7, 15).at(12, 0, 0, 0).to_zoned(tz).unwrap();
+        assert!(zdt.is_dst());
+        assert_eq!(zdt.standard_offset(), crate::tz::offset(2));
+        assert_eq!(zdt.dst_savings(), SignedDuration::from_hours(1));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_zoned() {
+        type Fixed = chrono::DateTime<chrono::FixedOffset>;
+
+        let zdt = date(2024, 3, 10)
+            .at(3, 30, 0, 0)
+            .to_zoned(TimeZone::fixed(tz::offset(-4)))
+            .unwrap();
+        let dt = Fixed::try_from(&zdt).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2024-03-10T03:30:00-04:00");
+        assert_eq!(Zoned::try_from(dt).unwrap(), zdt);
+
+        // The time zone rules are dropped, but the offset is kept.
+        if !crate::tz::db().is_definitively_empty() {
+            let zdt = date(2024, 3, 10)
+                .at(3, 30, 0, 0)
+                .in_tz("America/New_York")
+                .unwrap();
+            let dt = Fixed::try_from(zdt.clone()).unwrap();
+            assert_eq!(dt.to_rfc3339(), "2024-03-10T03:30:00-04:00");
+            let got = Zoned::try_from(dt).unwrap();
+            assert_eq!(got.timestamp(), zdt.timestamp());
+            assert_eq!(got.time_zone(), &TimeZone::fixed(tz::offset(-4)));
+        }
+
+        // chrono doesn't support offsets of 24 hours or more.
+        let zdt =
+            Timestamp::UNIX_EPOCH.to_zoned(TimeZone::fixed(tz::offset(25)));
+        assert!(Fixed::try_from(&zdt).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_zoned() {
+        use ::time::{macros::datetime as tdatetime, OffsetDateTime};
+
+        let zdt = date(2024, 3, 10)
+            .at(3, 30, 0, 0)
+            .to_zoned(TimeZone::fixed(tz::offset(-4)))
+            .unwrap();
+        let odt = OffsetDateTime::from(&zdt);
+        assert_eq!(odt, tdatetime!(2024-03-10 03:30:00 -04:00));
+        assert_eq!(odt.offset().whole_hours(), -4);
+        assert_eq!(Zoned::try_from(odt).unwrap(), zdt);
+
+        // Unlike chrono, `time` supports the same offsets as Jiff.
+        let offset = tz::Offset::MAX;
+        let zdt = Timestamp::UNIX_EPOCH.to_zoned(TimeZone::fixed(offset));
+        let odt = OffsetDateTime::from(zdt.clone());
+        assert_eq!(odt.offset().whole_seconds(), offset.seconds());
+        assert_eq!(Zoned::try_from(odt).unwrap(), zdt);
+
+        // The time zone rules are dropped, but the offset is kept.
+        if !crate::tz::db().is_definitively_empty() {
+            let zdt = date(2024, 3, 10)
+                .at(3, 30, 0, 0)
+                .in_tz("America/New_York")
+                .unwrap();
+            let got = Zoned::try_from(OffsetDateTime::from(&zdt)).unwrap();
+            assert_eq!(got.timestamp(), zdt.timestamp());
+            assert_eq!(got.time_zone(), &TimeZone::fixed(tz::offset(-4)));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn rand_zoned() {
+        use rand::{
+            distr::{Distribution, Uniform},
+            rngs::StdRng,
+            RngExt, SeedableRng,
+        };
+
+        let mut rng = StdRng::seed_from_u64(0);
+
+        // The time zone of the lower bound is used.
+        let start = date(2024, 3, 10)
+            .at(0, 0, 0, 0)
+            .to_zoned(TimeZone::fixed(tz::offset(-5)))
+            .unwrap();
+        let end = start.with_time_zone(TimeZone::UTC).tomorrow().unwrap();
+        let dist = Uniform::new(&start, &end).unwrap();
+        for _ in 0..100 {
+            let zdt = dist.sample(&mut rng);
+            assert!(start <= zdt && zdt < end);
+            assert_eq!(zdt.time_zone(), start.time_zone());
+        }
+
+        assert_eq!(rng.random_range(start.clone()..=start.clone()), start);
+        assert!(Uniform::new(&start, &start).is_err());
+    }
 }